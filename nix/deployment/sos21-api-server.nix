@@ -39,6 +39,11 @@ in
         type = types.str;
       };
 
+      allowedEmailDomains = mkOption {
+        type = types.listOf types.str;
+        default = [ ".tsukuba.ac.jp" ".sohosai.com" ];
+      };
+
       adminReportSlackWebhook = mkOption {
         type = types.str;
       };
@@ -92,6 +97,11 @@ in
       s3ObjectBucket = mkOption {
         type = types.str;
       };
+
+      metricsTokenFile = mkOption {
+        type = types.nullOr types.path;
+        default = null;
+      };
     };
 
   };
@@ -197,6 +207,8 @@ in
             DB_PASSWORD = cfg.databasePasswordFile;
             SOS21_API_SERVER_S3_ACCESS_KEY = cfg.s3AccessKeyFile;
             SOS21_API_SERVER_S3_ACCESS_SECRET = cfg.s3AccessSecretFile;
+          } // optionalAttrs (cfg.metricsTokenFile != null) {
+            SOS21_API_SERVER_METRICS_TOKEN = cfg.metricsTokenFile;
           };
         };
       in
@@ -223,6 +235,7 @@ in
             SOS21_API_SERVER_S3_ENDPOINT = cfg.s3Endpoint;
             SOS21_API_SERVER_S3_OBJECT_BUCKET = cfg.s3ObjectBucket;
             SOS21_API_SERVER_ADMINISTRATOR_EMAIL = cfg.administratorEmail;
+            SOS21_API_SERVER_ALLOWED_EMAIL_DOMAINS = concatStringsSep "," cfg.allowedEmailDomains;
             SOS21_API_SERVER_ADMIN_REPORT_SLACK_WEBHOOK = cfg.adminReportSlackWebhook;
             SOS21_API_SERVER_BIND = "0.0.0.0:${toString cfg.port}";
           } // mapAttrs' (n: v: nameValuePair "SOS21_API_SERVER_PROJECT_CREATION_PERIOD_${n}" v) cfg.projectCreationPeriods;
@@ -4,13 +4,14 @@
 }:
 let
   sos21-backend = import ../. { inherit pkgs; };
-  inherit (sos21-backend) sos21-api-server sos21-run-migrations;
+  inherit (sos21-backend) sos21-api-server sos21-run-migrations sos21-admin;
 in
 pkgs.dockerTools.buildImage {
   inherit name tag;
   contents = [
     sos21-api-server
     sos21-run-migrations
+    sos21-admin
     # hyper-rustls under rusoto needs the native CA certificates (rusoto/rusoto#1811)
     pkgs.cacert
   ];
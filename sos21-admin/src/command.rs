@@ -0,0 +1,66 @@
+use std::fmt::{self, Debug, Display};
+
+use crate::context::Context;
+
+use anyhow::Result;
+use sos21_domain::context::Login;
+use sos21_use_case::UseCaseError;
+use structopt::StructOpt;
+
+pub mod reassign_project_subowner;
+pub mod set_pending_project_deadline;
+pub mod set_project_category;
+pub mod set_user_role;
+
+#[derive(Debug, StructOpt)]
+pub enum Command {
+    /// Changes the category of a project
+    SetProjectCategory(set_project_category::Opt),
+    /// Replaces the subowner of a project
+    ReassignProjectSubowner(reassign_project_subowner::Opt),
+    /// Sets or clears the exceptional completion deadline of a pending project
+    SetPendingProjectDeadline(set_pending_project_deadline::Opt),
+    /// Changes the role of a user
+    SetUserRole(set_user_role::Opt),
+}
+
+impl Command {
+    /// Performs the change in `ctx` and describes what is changed.
+    pub async fn run(self, ctx: &Login<Context>) -> Result<Change> {
+        match self {
+            Command::SetProjectCategory(opt) => set_project_category::run(ctx, opt).await,
+            Command::ReassignProjectSubowner(opt) => reassign_project_subowner::run(ctx, opt).await,
+            Command::SetPendingProjectDeadline(opt) => {
+                set_pending_project_deadline::run(ctx, opt).await
+            }
+            Command::SetUserRole(opt) => set_user_role::run(ctx, opt).await,
+        }
+    }
+}
+
+/// A change of a field, which is shown before it is committed.
+#[derive(Debug, Clone)]
+pub struct Change {
+    pub target: String,
+    pub field: &'static str,
+    pub before: String,
+    pub after: String,
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}\n  {}: {} -> {}",
+            self.target, self.field, self.before, self.after
+        )
+    }
+}
+
+/// Use case errors are reported with their variant names, as they do not implement `Display`.
+fn use_case_error<E: Debug>(err: UseCaseError<E>) -> anyhow::Error {
+    match err {
+        UseCaseError::UseCase(err) => anyhow::anyhow!("{:?}", err),
+        UseCaseError::Internal(err) => err,
+    }
+}
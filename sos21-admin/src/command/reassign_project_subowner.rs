@@ -0,0 +1,44 @@
+use super::{use_case_error, Change};
+use crate::context::Context;
+
+use anyhow::Result;
+use sos21_domain::context::Login;
+use sos21_use_case::model::{project::ProjectId, user::UserId};
+use sos21_use_case::{get_project, reassign_project_subowner};
+use structopt::StructOpt;
+use uuid::Uuid;
+
+#[derive(Debug, StructOpt)]
+pub struct Opt {
+    /// ID of the project
+    project_id: Uuid,
+    /// ID of the new subowner, who must not belong to any project
+    subowner_id: String,
+}
+
+pub async fn run(ctx: &Login<Context>, opt: Opt) -> Result<Change> {
+    let before = get_project::run(ctx, ProjectId(opt.project_id))
+        .await
+        .map_err(use_case_error)?;
+
+    let input = reassign_project_subowner::Input {
+        id: ProjectId(opt.project_id),
+        subowner_id: UserId(opt.subowner_id),
+    };
+    let after = reassign_project_subowner::run(ctx, input)
+        .await
+        .map_err(use_case_error)?;
+
+    Ok(Change {
+        target: format!("project {} ({})", after.id.0, after.name),
+        field: "subowner",
+        before: format!(
+            "{} ({} {})",
+            before.subowner_id.0, before.subowner_name.last, before.subowner_name.first
+        ),
+        after: format!(
+            "{} ({} {})",
+            after.subowner_id.0, after.subowner_name.last, after.subowner_name.first
+        ),
+    })
+}
@@ -0,0 +1,50 @@
+use super::{use_case_error, Change};
+use crate::context::Context;
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use sos21_domain::context::Login;
+use sos21_use_case::model::pending_project::PendingProjectId;
+use sos21_use_case::{get_pending_project, update_pending_project_deadline};
+use structopt::StructOpt;
+use uuid::Uuid;
+
+#[derive(Debug, StructOpt)]
+pub struct Opt {
+    /// ID of the pending project
+    pending_project_id: Uuid,
+    /// The deadline in RFC 3339, such as 2021-10-01T00:00:00+09:00
+    #[structopt(required_unless = "clear")]
+    deadline: Option<DateTime<Utc>>,
+    /// Removes the exceptional deadline instead of setting it
+    #[structopt(long, conflicts_with = "deadline")]
+    clear: bool,
+}
+
+fn format_deadline(deadline: Option<DateTime<Utc>>) -> String {
+    match deadline {
+        Some(deadline) => deadline.to_rfc3339(),
+        None => "(none)".to_owned(),
+    }
+}
+
+pub async fn run(ctx: &Login<Context>, opt: Opt) -> Result<Change> {
+    let before = get_pending_project::run(ctx, PendingProjectId(opt.pending_project_id))
+        .await
+        .map_err(use_case_error)?;
+
+    let input = update_pending_project_deadline::Input {
+        id: PendingProjectId(opt.pending_project_id),
+        exceptional_complete_deadline: if opt.clear { None } else { opt.deadline },
+    };
+    let after = update_pending_project_deadline::run(ctx, input)
+        .await
+        .map_err(use_case_error)?;
+
+    Ok(Change {
+        target: format!("pending project {} ({})", after.id.0, after.name),
+        field: "exceptional_complete_deadline",
+        before: format_deadline(before.exceptional_complete_deadline),
+        after: format_deadline(after.exceptional_complete_deadline),
+    })
+}
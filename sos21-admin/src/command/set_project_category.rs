@@ -0,0 +1,45 @@
+use super::{use_case_error, Change};
+use crate::context::Context;
+
+use anyhow::Result;
+use sos21_domain::context::Login;
+use sos21_domain::model::project as entity;
+use sos21_use_case::model::project::{ProjectCategory, ProjectId};
+use sos21_use_case::{get_project, update_any_project};
+use structopt::StructOpt;
+use uuid::Uuid;
+
+#[derive(Debug, StructOpt)]
+pub struct Opt {
+    /// ID of the project
+    project_id: Uuid,
+    /// general, cooking_requiring_preparation_area, cooking, food or stage
+    category: entity::ProjectCategory,
+}
+
+pub async fn run(ctx: &Login<Context>, opt: Opt) -> Result<Change> {
+    let before = get_project::run(ctx, ProjectId(opt.project_id))
+        .await
+        .map_err(use_case_error)?;
+
+    let input = update_any_project::Input {
+        id: ProjectId(opt.project_id),
+        name: None,
+        kana_name: None,
+        group_name: None,
+        kana_group_name: None,
+        description: None,
+        category: Some(ProjectCategory::from_entity(opt.category)),
+        attributes: None,
+    };
+    let after = update_any_project::run(ctx, input)
+        .await
+        .map_err(use_case_error)?;
+
+    Ok(Change {
+        target: format!("project {} ({})", after.id.0, after.name),
+        field: "category",
+        before: format!("{:?}", before.category),
+        after: format!("{:?}", after.category),
+    })
+}
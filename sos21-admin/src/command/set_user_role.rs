@@ -0,0 +1,55 @@
+use super::{use_case_error, Change};
+use crate::context::Context;
+
+use anyhow::{bail, Result};
+use sos21_domain::context::Login;
+use sos21_use_case::model::user::{UserId, UserRole};
+use sos21_use_case::{get_user, update_any_user};
+use structopt::StructOpt;
+
+#[derive(Debug, StructOpt)]
+pub struct Opt {
+    /// ID of the user
+    user_id: String,
+    /// administrator, committee_operator, committee or general
+    #[structopt(parse(try_from_str = parse_role))]
+    role: UserRole,
+}
+
+fn parse_role(s: &str) -> Result<UserRole> {
+    match s {
+        "administrator" => Ok(UserRole::Administrator),
+        "committee_operator" => Ok(UserRole::CommitteeOperator),
+        "committee" => Ok(UserRole::Committee),
+        "general" => Ok(UserRole::General),
+        _ => bail!("invalid user role: {}", s),
+    }
+}
+
+pub async fn run(ctx: &Login<Context>, opt: Opt) -> Result<Change> {
+    let before = get_user::run(ctx, UserId(opt.user_id.clone()))
+        .await
+        .map_err(use_case_error)?;
+
+    let input = update_any_user::Input {
+        id: UserId(opt.user_id),
+        name: None,
+        kana_name: None,
+        phone_number: None,
+        role: Some(opt.role),
+        category: None,
+    };
+    let after = update_any_user::run(ctx, input)
+        .await
+        .map_err(use_case_error)?;
+
+    Ok(Change {
+        target: format!(
+            "user {} ({} {}, {})",
+            after.id.0, after.name.last, after.name.first, after.email
+        ),
+        field: "role",
+        before: format!("{:?}", before.role),
+        after: format!("{:?}", after.role),
+    })
+}
@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+
+use anyhow::{Context as _, Result};
+use chrono::{TimeZone, Utc};
+use sos21_domain::context::{FestivalRepository, SettingsRepository};
+use sos21_domain::model::{
+    date_time::DateTime,
+    festival::FestivalId,
+    project::ProjectCategory,
+    project_creation_period::ProjectCreationPeriod,
+    settings::{Settings, SettingsContent},
+    user::{email::AllowedEmailDomains, UserEmailAddress},
+};
+use sos21_gateway_database::Database;
+use sqlx::{Postgres, Transaction};
+
+/// The configuration shared with `sos21-api-server`, which is used until any settings are stored
+/// in the database.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub administrator_email: Option<String>,
+    pub allowed_email_domains: AllowedEmailDomains,
+    pub project_creation_periods: HashMap<String, String>,
+}
+
+/// The context in which a command runs, which holds a transaction on the database.
+#[derive(Debug)]
+pub struct Context {
+    database: Database,
+    settings: Settings,
+    allowed_email_domains: AllowedEmailDomains,
+}
+
+impl Context {
+    pub async fn new(connection: Transaction<'static, Postgres>, config: Config) -> Result<Self> {
+        let database = Database::new(connection);
+        let settings = match database
+            .get_settings()
+            .await
+            .context("Failed to get settings")?
+        {
+            Some(settings) => settings,
+            None => {
+                // the latest festival is active until any settings are stored
+                let festival = database
+                    .list_festivals()
+                    .await
+                    .context("Failed to list festivals")?
+                    .into_iter()
+                    .next()
+                    .context("No festival found")?;
+                default_settings(&config, festival.id())?
+            }
+        };
+        Ok(Context {
+            database,
+            settings,
+            allowed_email_domains: config.allowed_email_domains,
+        })
+    }
+
+    pub async fn commit_changes(self) -> Result<()> {
+        self.database.into_connection().commit().await?;
+        Ok(())
+    }
+
+    pub async fn rollback_changes(self) -> Result<()> {
+        self.database.into_connection().rollback().await?;
+        Ok(())
+    }
+}
+
+/// The settings used until any settings are stored in the database,
+/// which are the same as the ones of `sos21-api-server` with the same configuration.
+fn default_settings(config: &Config, active_festival_id: FestivalId) -> Result<Settings> {
+    let administrator_email = config
+        .administrator_email
+        .clone()
+        .context("No settings found in the database and no administrator email is configured")?;
+    let administrator_email = UserEmailAddress::from_string_with_domains(
+        administrator_email,
+        &config.allowed_email_domains,
+    )
+    .context("invalid administrator email")?;
+
+    let mut project_creation_periods = HashMap::new();
+    for (category, period) in &config.project_creation_periods {
+        let category: ProjectCategory = category.parse()?;
+        let period = match period.as_ref() {
+            "always" => ProjectCreationPeriod::always(),
+            "never" => ProjectCreationPeriod::never(),
+            _ => {
+                let (starts_at, ends_at) = period
+                    .split_once('-')
+                    .context("period must be delimited with '-'")?;
+                let starts_at = Utc
+                    .timestamp_millis_opt(starts_at.parse()?)
+                    .single()
+                    .context("start of the period is out of range")?;
+                let ends_at = Utc
+                    .timestamp_millis_opt(ends_at.parse()?)
+                    .single()
+                    .context("end of the period is out of range")?;
+                let starts_at = DateTime::from_utc(starts_at);
+                let ends_at = DateTime::from_utc(ends_at);
+                ProjectCreationPeriod::from_datetime(starts_at, ends_at)
+                    .context("invalid project creation period")?
+            }
+        };
+        project_creation_periods.insert(category, period);
+    }
+
+    Ok(Settings::from_content(SettingsContent {
+        updated_at: DateTime::now(),
+        author_id: None,
+        administrator_email,
+        active_festival_id,
+        project_creation_periods,
+    }))
+}
+
+sos21_domain::delegate_user_repository! {
+    impl UserRepository for Context {
+        self { &self.database }
+    }
+}
+
+sos21_domain::delegate_project_repository! {
+    impl ProjectRepository for Context {
+        self { &self.database }
+    }
+}
+
+sos21_domain::delegate_pending_project_repository! {
+    impl PendingProjectRepository for Context {
+        self { &self.database }
+    }
+}
+
+impl sos21_domain::context::ConfigContext for Context {
+    fn administrator_email(&self) -> &UserEmailAddress {
+        self.settings.administrator_email()
+    }
+
+    fn project_creation_period_for(&self, category: ProjectCategory) -> ProjectCreationPeriod {
+        self.settings.project_creation_period_for(category)
+    }
+
+    fn allowed_email_domains(&self) -> &AllowedEmailDomains {
+        &self.allowed_email_domains
+    }
+
+    fn active_festival_id(&self) -> FestivalId {
+        self.settings.active_festival_id()
+    }
+}
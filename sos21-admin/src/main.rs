@@ -0,0 +1,124 @@
+use std::io::{self, BufRead, Write};
+
+use anyhow::{Context as _, Result};
+use sos21_domain::context::Login;
+use sos21_domain::model::user::email::{AllowedEmailDomains, EmailDomainRule};
+use sqlx::postgres::PgPoolOptions;
+use structopt::StructOpt;
+use tokio::runtime;
+use tracing::{event, Level};
+
+mod command;
+mod context;
+use command::Command;
+use context::{Config, Context};
+
+#[derive(Debug, StructOpt)]
+#[structopt(name = "sos21-admin")]
+struct Opt {
+    #[structopt(short, long, env = "SOS21_ADMIN_POSTGRES_URI")]
+    postgres_uri: String,
+    /// Used only when no settings are stored in the database, as in `sos21-api-server`
+    #[structopt(long, env = "SOS21_API_SERVER_ADMINISTRATOR_EMAIL")]
+    administrator_email: Option<String>,
+    /// Comma-separated domains from which users can sign up, as in `sos21-api-server`
+    #[structopt(
+        long,
+        default_value = ".tsukuba.ac.jp,.sohosai.com",
+        use_delimiter = true,
+        env = "SOS21_API_SERVER_ALLOWED_EMAIL_DOMAINS"
+    )]
+    allowed_email_domains: Vec<String>,
+    /// Shows the change without committing it
+    #[structopt(short = "n", long)]
+    dry_run: bool,
+    /// Commits the change without confirmation
+    #[structopt(short, long)]
+    yes: bool,
+    #[structopt(subcommand)]
+    command: Command,
+}
+
+fn main() {
+    let opt = Opt::from_args();
+
+    tracing_subscriber::fmt()
+        .with_writer(io::stderr)
+        .with_max_level(Level::WARN)
+        .init();
+
+    if let Err(error) = run(opt) {
+        event!(Level::ERROR, ?error);
+        std::process::exit(1);
+    }
+}
+
+fn run(opt: Opt) -> Result<()> {
+    let runtime = runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .context("Failed to build the Tokio Runtime")?;
+
+    runtime.block_on(run_command(opt))
+}
+
+fn confirm() -> Result<bool> {
+    print!("Commit the change? [y/N] ");
+    io::stdout().flush()?;
+    let mut answer = String::new();
+    io::stdin().lock().read_line(&mut answer)?;
+    Ok(matches!(answer.trim(), "y" | "Y" | "yes"))
+}
+
+async fn run_command(opt: Opt) -> Result<()> {
+    let allowed_email_domains = opt
+        .allowed_email_domains
+        .iter()
+        .map(|rule| {
+            EmailDomainRule::from_string(rule)
+                .with_context(|| format!("invalid allowed email domain: {}", rule))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let project_creation_periods = std::env::vars()
+        .filter_map(|(key, value)| {
+            key.strip_prefix("SOS21_API_SERVER_PROJECT_CREATION_PERIOD_")
+                .map(|key| (key.to_owned(), value))
+        })
+        .collect();
+    let config = Config {
+        administrator_email: opt.administrator_email,
+        allowed_email_domains: AllowedEmailDomains::from_rules(allowed_email_domains),
+        project_creation_periods,
+    };
+
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&opt.postgres_uri)
+        .await
+        .context("Failed to connect to the database")?;
+    let connection = pool
+        .begin()
+        .await
+        .context("Failed to begin a transaction")?;
+    let ctx = Login::system_administrator(Context::new(connection, config).await?);
+
+    // the change is made in the transaction first, so that the dry run reports errors as well
+    let change = opt.command.run(&ctx).await?;
+    println!("{}", change);
+
+    let ctx = ctx.into_inner();
+    if opt.dry_run {
+        println!("Dry run; the change is not committed.");
+        return ctx.rollback_changes().await;
+    }
+    if !opt.yes && !confirm()? {
+        println!("Aborted.");
+        return ctx.rollback_changes().await;
+    }
+
+    ctx.commit_changes()
+        .await
+        .context("Failed to commit the change")?;
+    println!("Committed.");
+    Ok(())
+}
@@ -2,13 +2,21 @@ use std::collections::HashMap;
 use std::fmt::{self, Debug};
 
 use crate::config::Config;
+#[cfg(feature = "dev-auth")]
+use crate::dev_auth::DevIssuer;
+use crate::filter::RateLimiter;
 
 use anyhow::{Context as _, Result};
 use chrono::{TimeZone, Utc};
 use rusoto_s3::S3Client;
+use sos21_domain::context::{FestivalRepository, SettingsRepository};
 use sos21_domain::model::{
-    date_time::DateTime, project::ProjectCategory, project_creation_period::ProjectCreationPeriod,
-    user::UserEmailAddress,
+    date_time::DateTime,
+    festival::FestivalId,
+    project::ProjectCategory,
+    project_creation_period::ProjectCreationPeriod,
+    settings::{Settings, SettingsContent},
+    user::{email::AllowedEmailDomains, UserEmailAddress},
 };
 use sos21_gateway_database::Database;
 use sos21_gateway_s3::S3;
@@ -24,6 +32,9 @@ pub struct App {
     config: Config,
     administrator_email: UserEmailAddress,
     project_creation_periods: HashMap<ProjectCategory, ProjectCreationPeriod>,
+    rate_limiter: RateLimiter,
+    #[cfg(feature = "dev-auth")]
+    dev_issuer: Option<DevIssuer>,
 }
 
 impl Debug for App {
@@ -59,8 +70,11 @@ impl App {
         };
         let s3_client = S3Client::new_with(dispatcher, credentials, region);
 
-        let administrator_email = UserEmailAddress::from_string(config.administrator_email.clone())
-            .context("invalid administrator email")?;
+        let administrator_email = UserEmailAddress::from_string_with_domains(
+            config.administrator_email.clone(),
+            &config.allowed_email_domains,
+        )
+        .context("invalid administrator email")?;
 
         let mut project_creation_periods = HashMap::new();
         for (category, period) in &config.project_creation_periods {
@@ -72,11 +86,16 @@ impl App {
                     let (starts_at, ends_at) = period
                         .split_once('-')
                         .context("period must be delimited with '-'")?;
-                    // FIXME: 範囲外の場合にハンドリングする
-                    let starts_at =
-                        DateTime::from_utc(Utc.timestamp_millis_opt(starts_at.parse()?).unwrap());
-                    let ends_at =
-                        DateTime::from_utc(Utc.timestamp_millis_opt(ends_at.parse()?).unwrap());
+                    let starts_at = Utc
+                        .timestamp_millis_opt(starts_at.parse()?)
+                        .single()
+                        .context("start of the period is out of range")?;
+                    let ends_at = Utc
+                        .timestamp_millis_opt(ends_at.parse()?)
+                        .single()
+                        .context("end of the period is out of range")?;
+                    let starts_at = DateTime::from_utc(starts_at);
+                    let ends_at = DateTime::from_utc(ends_at);
                     ProjectCreationPeriod::from_datetime(starts_at, ends_at)
                         .context("invalid project creation period")?
                 }
@@ -84,12 +103,29 @@ impl App {
             project_creation_periods.insert(category, period);
         }
 
+        #[cfg(feature = "dev-auth")]
+        let dev_issuer = config
+            .dev_auth
+            .as_ref()
+            .map(|dev_auth| {
+                DevIssuer::new(
+                    config.jwt_issuer.clone(),
+                    config.jwt_audience.clone(),
+                    dev_auth,
+                )
+            })
+            .transpose()
+            .context("Failed to prepare the development token issuer")?;
+
         Ok(App {
             pool,
             s3_client,
             config,
             administrator_email,
             project_creation_periods,
+            rate_limiter: RateLimiter::new(),
+            #[cfg(feature = "dev-auth")]
+            dev_issuer,
         })
     }
 
@@ -101,6 +137,20 @@ impl App {
         &self.s3_client
     }
 
+    pub fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    #[cfg(feature = "dev-auth")]
+    pub fn dev_issuer(&self) -> Option<&DevIssuer> {
+        self.dev_issuer.as_ref()
+    }
+
+    /// Renders the metrics in the Prometheus text format, or `None` if metrics are not recorded.
+    pub fn render_metrics(&self) -> Option<String> {
+        crate::metrics::render(&self.pool, self.config.max_database_connections)
+    }
+
     pub async fn connection(&self) -> Result<PoolConnection<Postgres>> {
         self.pool
             .acquire()
@@ -108,6 +158,17 @@ impl App {
             .context("Failed to acquire a connection from pool")
     }
 
+    /// The settings used until any settings are stored in the database.
+    fn default_settings(&self, active_festival_id: FestivalId) -> Settings {
+        Settings::from_content(SettingsContent {
+            updated_at: DateTime::now(),
+            author_id: None,
+            administrator_email: self.administrator_email.clone(),
+            active_festival_id,
+            project_creation_periods: self.project_creation_periods.clone(),
+        })
+    }
+
     pub async fn start_context(&self) -> Result<Context> {
         let connection = self
             .pool
@@ -116,11 +177,29 @@ impl App {
             .context("Failed to acquire a connection from pool")?;
         let database = Database::new(connection);
         let s3 = S3::new(self.s3_client.clone(), self.config.s3_object_bucket.clone());
+        let settings = match database
+            .get_settings()
+            .await
+            .context("Failed to get settings")?
+        {
+            Some(settings) => settings,
+            None => {
+                // the latest festival is active until any settings are stored
+                let festival = database
+                    .list_festivals()
+                    .await
+                    .context("Failed to list festivals")?
+                    .into_iter()
+                    .next()
+                    .context("No festival found")?;
+                self.default_settings(festival.id())
+            }
+        };
         Ok(Context {
             database,
             s3,
-            administrator_email: self.administrator_email.clone(),
-            project_creation_periods: self.project_creation_periods.clone(),
+            settings,
+            allowed_email_domains: self.config.allowed_email_domains.clone(),
         })
     }
 }
@@ -129,8 +208,8 @@ impl App {
 pub struct Context {
     database: Database,
     s3: S3,
-    administrator_email: UserEmailAddress,
-    project_creation_periods: HashMap<ProjectCategory, ProjectCreationPeriod>,
+    settings: Settings,
+    allowed_email_domains: AllowedEmailDomains,
 }
 
 impl Context {
@@ -158,6 +237,12 @@ sos21_domain::delegate_form_repository! {
     }
 }
 
+sos21_domain::delegate_form_template_repository! {
+    impl FormTemplateRepository for Context {
+        self { &self.database }
+    }
+}
+
 sos21_domain::delegate_form_answer_repository! {
     impl FormAnswerRepository for Context {
         self { &self.database }
@@ -213,15 +298,44 @@ sos21_domain::delegate_user_invitation_repository! {
     }
 }
 
+sos21_domain::delegate_festival_repository! {
+    impl FestivalRepository for Context {
+        self { &self.database }
+    }
+}
+
+sos21_domain::delegate_api_key_repository! {
+    impl ApiKeyRepository for Context {
+        self { &self.database }
+    }
+}
+
+sos21_domain::delegate_custom_role_repository! {
+    impl CustomRoleRepository for Context {
+        self { &self.database }
+    }
+}
+
+sos21_domain::delegate_settings_repository! {
+    impl SettingsRepository for Context {
+        self { &self.database }
+    }
+}
+
 impl sos21_domain::context::ConfigContext for Context {
     fn administrator_email(&self) -> &UserEmailAddress {
-        &self.administrator_email
+        self.settings.administrator_email()
     }
 
     fn project_creation_period_for(&self, category: ProjectCategory) -> ProjectCreationPeriod {
-        self.project_creation_periods
-            .get(&category)
-            .copied()
-            .unwrap_or_else(ProjectCreationPeriod::never)
+        self.settings.project_creation_period_for(category)
+    }
+
+    fn allowed_email_domains(&self) -> &AllowedEmailDomains {
+        &self.allowed_email_domains
+    }
+
+    fn active_festival_id(&self) -> FestivalId {
+        self.settings.active_festival_id()
     }
 }
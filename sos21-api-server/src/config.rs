@@ -1,5 +1,8 @@
 use std::collections::HashMap;
+#[cfg(feature = "dev-auth")]
+use std::path::PathBuf;
 
+use sos21_domain::model::user::email::AllowedEmailDomains;
 use url::Url;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -15,6 +18,42 @@ pub struct Config {
     pub s3_endpoint: String,
     pub s3_object_bucket: String,
     pub administrator_email: String,
+    pub allowed_email_domains: AllowedEmailDomains,
     pub project_creation_periods: HashMap<String, String>,
     pub admin_report_slack_webhook: String,
+    /// Suspends the users signed in with the email addresses out of the allowed domains,
+    /// in addition to reporting them. The users who have signed up with the addresses are exempted.
+    pub suspend_suspicious_users: bool,
+    /// Bearer token required to read the metrics. The metrics are not served when absent.
+    pub metrics_token: Option<String>,
+    pub rate_limit: RateLimitConfig,
+    /// Replaces the keys from `jwt_keys_url` with local ones when present.
+    #[cfg(feature = "dev-auth")]
+    pub dev_auth: Option<DevAuthConfig>,
+}
+
+/// The number of requests allowed per minute, which is also the size of a burst.
+/// The limit of `0` disables the limiting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub per_user: u32,
+    pub per_ip: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitConfig {
+    pub write: RateLimit,
+    pub upload: RateLimit,
+    pub invitation: RateLimit,
+    /// Takes the client IP from `X-Forwarded-For` header set by a reverse proxy.
+    pub trust_forwarded_for: bool,
+}
+
+#[cfg(feature = "dev-auth")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DevAuthConfig {
+    /// JWKS of the additional keys to accept, in the same format as the one from `jwt_keys_url`.
+    pub jwks_file: Option<PathBuf>,
+    /// RSA private key in PEM to issue tokens with. A key pair is generated at startup if absent.
+    pub signing_key_file: Option<PathBuf>,
 }
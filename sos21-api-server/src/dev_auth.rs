@@ -0,0 +1,125 @@
+//! Authentication with tokens issued by the server itself, which replaces Firebase Authentication
+//! in local development and integration tests.
+
+use std::path::Path;
+
+use crate::config::DevAuthConfig;
+
+use anyhow::{Context as _, Result};
+use jsonwebtoken as jwt;
+use openssl::rsa::Rsa;
+use serde::Serialize;
+
+#[cfg(not(debug_assertions))]
+compile_error!("the `dev-auth` feature must not be enabled in release builds");
+
+/// The key ID of the tokens issued by [`DevIssuer`].
+pub const DEV_KEY_ID: &str = "sos21-dev";
+
+/// Lifetime of the issued tokens in seconds.
+const TOKEN_LIFETIME: i64 = 60 * 60;
+
+#[derive(Debug, Clone)]
+pub struct DevIssuer {
+    issuer: String,
+    audience: String,
+    encoding_key: jwt::EncodingKey,
+    decoding_key: jwt::DecodingKey<'static>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct DevClaims<'a> {
+    iss: &'a str,
+    aud: &'a str,
+    sub: &'a str,
+    iat: i64,
+    exp: i64,
+    email: &'a str,
+    email_verified: bool,
+}
+
+impl DevIssuer {
+    /// Issues tokens with `issuer` and `audience`, which must match the ones expected in the validation.
+    pub fn new(issuer: String, audience: String, dev_auth: &DevAuthConfig) -> Result<Self> {
+        let rsa = match &dev_auth.signing_key_file {
+            Some(path) => read_private_key(path)?,
+            None => Rsa::generate(2048).context("Failed to generate RSA key pair")?,
+        };
+        let private_pem = rsa
+            .private_key_to_pem()
+            .context("Failed to encode the private key")?;
+        let public_pem = rsa
+            .public_key_to_pem_pkcs1()
+            .context("Failed to encode the public key")?;
+        let encoding_key = jwt::EncodingKey::from_rsa_pem(&private_pem)?;
+        let decoding_key = jwt::DecodingKey::from_rsa_pem(&public_pem)?.into_static();
+
+        Ok(DevIssuer {
+            issuer,
+            audience,
+            encoding_key,
+            decoding_key,
+        })
+    }
+
+    pub fn decoding_key(&self) -> jwt::DecodingKey<'static> {
+        self.decoding_key.clone()
+    }
+
+    /// Issues a token accepted in the same way as ones from Firebase Authentication.
+    pub fn issue(&self, user_id: &str, email: &str, email_verified: bool) -> Result<String> {
+        let now = chrono::Utc::now().timestamp();
+        let claims = DevClaims {
+            iss: &self.issuer,
+            aud: &self.audience,
+            sub: user_id,
+            iat: now,
+            exp: now + TOKEN_LIFETIME,
+            email,
+            email_verified,
+        };
+        let header = jwt::Header {
+            kid: Some(DEV_KEY_ID.to_owned()),
+            ..jwt::Header::new(jwt::Algorithm::RS256)
+        };
+        jwt::encode(&header, &claims, &self.encoding_key).context("Failed to encode JWT")
+    }
+}
+
+fn read_private_key(path: &Path) -> Result<Rsa<openssl::pkey::Private>> {
+    let pem = std::fs::read(path)
+        .with_context(|| format!("Failed to read signing key file {}", path.display()))?;
+    Rsa::private_key_from_pem(&pem)
+        .with_context(|| format!("Invalid RSA private key in {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DevIssuer, DEV_KEY_ID};
+    use crate::config::DevAuthConfig;
+    use jsonwebtoken as jwt;
+
+    #[test]
+    fn test_issue_and_validate() {
+        let dev_auth = DevAuthConfig {
+            jwks_file: None,
+            signing_key_file: None,
+        };
+        let issuer = DevIssuer::new("issuer".to_owned(), "audience".to_owned(), &dev_auth).unwrap();
+        let token = issuer.issue("user", "user@example.com", true).unwrap();
+
+        let header = jwt::decode_header(&token).unwrap();
+        assert_eq!(header.kid.as_deref(), Some(DEV_KEY_ID));
+
+        let validation = jwt::Validation {
+            aud: Some(std::iter::once("audience".to_owned()).collect()),
+            iss: Some("issuer".to_owned()),
+            ..jwt::Validation::new(jwt::Algorithm::RS256)
+        };
+        let data =
+            jwt::decode::<serde_json::Value>(&token, &issuer.decoding_key(), &validation).unwrap();
+        assert_eq!(data.claims["sub"], "user");
+        assert_eq!(data.claims["email"], "user@example.com");
+        assert_eq!(data.claims["email_verified"], true);
+    }
+}
@@ -10,11 +10,16 @@ use warp::{
 
 mod authentication;
 mod error;
+mod rate_limit;
+mod request;
 
 use authentication::authenticate;
 pub use authentication::{AuthenticationInfo, KeyStore};
 pub use error::model;
 use error::{handle_cors_rejection, handle_rejection};
+use rate_limit::RateLimitGroup;
+pub use rate_limit::RateLimiter;
+pub use request::{record_user_id, RequestId};
 
 macro_rules! route {
     (@way GET) => { warp::get().and(warp::query()) };
@@ -28,194 +33,360 @@ macro_rules! route {
     (@path $name:literal) => { warp::path($name) };
     (@options $with_auth:ident, $with_app:ident, {noapp}) => { warp::any() };
     (@options $with_auth:ident, $with_app:ident, {noauth}) => { $with_app.clone() };
+    (@options $with_auth:ident, $with_app:ident, {metrics}) => {
+        authentication::authenticate_metrics($with_app.clone())
+    };
     (@options $with_auth:ident, $with_app:ident, {}) => { $with_app.clone().and($with_auth.clone()) };
+    (@options $with_auth:ident, $with_app:ident, {limit = $group:ident}) => {
+        rate_limit::limit(route!(@group $group), $with_app.clone().and($with_auth.clone()))
+    };
+    (@group write) => { RateLimitGroup::Write };
+    (@group upload) => { RateLimitGroup::Upload };
+    (@group invitation) => { RateLimitGroup::Invitation };
     ($with_auth:ident, $with_app:ident, / $name:literal { $($inner:tt)+ }) => {
         warp::path($name)
             .and(routes!{ $with_auth, $with_app, $($inner)+ })
     };
     ($with_auth:ident, $with_app:ident,
-       / $name_1:literal $(/ $name_n:literal)+ => {$($options:tt),*} $way:ident ($handler:path)
+       / $name_1:literal $(/ $name_n:literal)+ => {$($options:tt)*} $way:ident ($handler:path)
     ) => {
         warp::path($name_1)
-            .and(route!{ $with_auth, $with_app, $(/ $name_n)+ => {$($options),*} $way ($handler) })
+            .and(route!{ $with_auth, $with_app, $(/ $name_n)+ => {$($options)*} $way ($handler) })
     };
     ($with_auth:ident, $with_app:ident,
-       / $($name:literal)? => {$($options:tt),*} $way:ident ($handler:path)
+       / $($name:literal)? => {$($options:tt)*} $way:ident ($handler:path)
     ) => {
         route!(@path $($name)?)
             .and(warp::path::end()
-                .and(route!(@options $with_auth, $with_app, {$($options),*}))
-                .and(route!(@way $way))
-                .and_then($handler))
+                .and(crate::metrics::route_timer())
+                .and(request::route(
+                    route!(@options $with_auth, $with_app, {$($options)*})
+                        .and(route!(@way $way))
+                        .and_then($handler),
+                    handle_rejection,
+                ))
+                .map(crate::metrics::RouteTimer::finish))
     };
 }
 
 macro_rules! routes {
     ($with_auth:ident, $with_app:ident,
-       / $($name_1:literal)/ * $({ $($inner_1:tt)+ })? $(=> $({$($option_1:tt),*})? $way_1:ident ($handler_1:path) )?
-         $(, / $($name_n:literal)/ * $({ $($inner_n:tt)+ })? $(=> $({$($option_n:tt),*})? $way_n:ident ($handler_n:path) )? )*
+       / $($name_1:literal)/ * $({ $($inner_1:tt)+ })? $(=> $({$($option_1:tt)*})? $way_1:ident ($handler_1:path) )?
+         $(, / $($name_n:literal)/ * $({ $($inner_n:tt)+ })? $(=> $({$($option_n:tt)*})? $way_n:ident ($handler_n:path) )? )*
          $(,)?
     ) => {
-        route!{ $with_auth, $with_app, / $($name_1)/ * $({ $($inner_1)+ })? $(=> {$($($option_1),*)?} $way_1 ($handler_1))? }
+        route!{ $with_auth, $with_app, / $($name_1)/ * $({ $($inner_1)+ })? $(=> {$($($option_1)*)?} $way_1 ($handler_1))? }
             $( .or(route!{
-                    $with_auth, $with_app, / $($name_n)/ * $({ $($inner_n)+ })? $(=> {$($($option_n),*)?} $way_n ($handler_n))?
+                    $with_auth, $with_app, / $($name_n)/ * $({ $($inner_n)+ })? $(=> {$($($option_n)*)?} $way_n ($handler_n))?
                 })
                 .boxed()  // workaround for seanmonstar/warp#811
             )*
     }
 }
 
-pub fn endpoints(
-    app: App,
-    key_store: KeyStore,
-) -> impl Filter<Extract = (impl warp::Reply,), Error = Infallible> + Clone {
-    use crate::handler;
-
-    let with_auth = authenticate(key_store, app.config().clone());
-    let with_app = warp::any().map(move || app.clone());
+macro_rules! route_table {
+    (@way GET) => { crate::openapi::Way::Get };
+    (@way POST) => { crate::openapi::Way::Post };
+    (@way POST_STREAM) => { crate::openapi::Way::PostStream };
+    (@authenticated {noapp}) => { false };
+    (@authenticated {noauth}) => { false };
+    (@authenticated {metrics}) => { false };
+    (@authenticated {$($options:tt)*}) => { true };
+    (@rate_limited {limit = $group:ident}) => { true };
+    (@rate_limited {$($options:tt)*}) => { false };
+    ($table:ident, [$($prefix:literal)*]) => {};
+    ($table:ident, [$($prefix:literal)*] , $($rest:tt)*) => {
+        route_table!($table, [$($prefix)*] $($rest)*)
+    };
+    ($table:ident, [$($prefix:literal)*]
+       / $($name:literal)/ * { $($inner:tt)+ } $($rest:tt)*
+    ) => {
+        route_table!($table, [$($prefix)* $($name)*] $($inner)+);
+        route_table!($table, [$($prefix)*] $($rest)*);
+    };
+    ($table:ident, [$($prefix:literal)*]
+       / $($name:literal)/ * => $({$($options:tt)*})? $way:ident ($($handler:ident)::+) $($rest:tt)*
+    ) => {
+        $table.push(crate::openapi::Route {
+            path: concat!($("/", $prefix,)* $("/", $name,)*),
+            way: route_table!(@way $way),
+            authenticated: route_table!(@authenticated {$($($options)*)?}),
+            rate_limited: route_table!(@rate_limited {$($($options)*)?}),
+            operation: $($handler)::+::openapi_operation,
+        });
+        route_table!($table, [$($prefix)*] $($rest)*);
+    };
+}
 
-    let routes = routes! { with_auth, with_app,
-        / "meta" {
-            / "get-build-info" => {noapp} GET (handler::meta::get_build_info),
-            / "health" {
-                / "check" => {noauth} GET (handler::meta::health::check),
-                / "check-liveness" => {noapp} GET (handler::meta::health::check_liveness),
-            },
-        },
-        / "signup" => POST (handler::signup),
-        / "me" {
-            / "get" => GET (handler::me::get),
-            / "project" / "get" => GET (handler::me::project::get),
-            / "pending-project" / "get" => GET (handler::me::pending_project::get),
-            / "file" {
-                / "list" => GET (handler::me::file::list),
-                / "check-usage" => GET (handler::me::file::check_usage),
-            },
-            / "file-sharing" / "list" => GET (handler::me::file_sharing::list),
-        },
-        / "get-project-creation-availability" =>{noauth}  GET(handler::project_creation_availability::get),
-        / "project" {
-            / "prepare" => POST (handler::project::prepare),
-            / "create" => POST (handler::project::create),
-            / "get" => GET (handler::project::get),
-            / "update" => POST (handler::project::update),
-            / "update-any" => POST (handler::project::update_any),
-            / "list" => GET (handler::project::list),
-            / "export" => GET (handler::project::export),
-            / "form" {
-                / "get" => GET (handler::project::form::get),
-                / "list" => GET (handler::project::form::list),
-                / "answer" {
-                    / => POST (handler::project::form::answer),
-                    / "get" => GET (handler::project::form::answer::get),
-                    / "update" => POST (handler::project::form::answer::update),
-                    / "file-sharing" {
-                        / "get-file" => GET (handler::project::form::answer::file_sharing::get_file),
-                        / "get-file-info" => GET (handler::project::form::answer::file_sharing::get_file_info),
+/// Passes the route tree to `$callback`, after `$args`.
+/// This is shared by [`endpoints`] and [`route_table`] so that the served routes and the documented ones never diverge.
+macro_rules! with_endpoints {
+    ($callback:ident! { $($args:tt)* }) => {
+        $callback! { $($args)*
+            / "meta" {
+                / "get-build-info" => {noapp} GET (handler::meta::get_build_info),
+                / "metrics" => {metrics} GET (handler::meta::get_metrics),
+                / "openapi.json" => {noapp} GET (handler::meta::get_openapi),
+                / "health" {
+                    / "check" => {noauth} GET (handler::meta::health::check),
+                    / "check-liveness" => {noapp} GET (handler::meta::health::check_liveness),
+                },
+            },
+            / "signup" => {limit = write} POST (handler::signup),
+            / "me" {
+                / "get" => GET (handler::me::get),
+                / "project" / "get" => GET (handler::me::project::get),
+                / "pending-project" / "get" => GET (handler::me::pending_project::get),
+                / "file" {
+                    / "list" => GET (handler::me::file::list),
+                    / "check-usage" => GET (handler::me::file::check_usage),
+                },
+                / "file-sharing" / "list" => GET (handler::me::file_sharing::list),
+            },
+            / "get-project-creation-availability" =>{noauth}  GET(handler::project_creation_availability::get),
+            / "project" {
+                / "prepare" => {limit = write} POST (handler::project::prepare),
+                / "create" => {limit = write} POST (handler::project::create),
+                / "get" => GET (handler::project::get),
+                / "update" => {limit = write} POST (handler::project::update),
+                / "update-any" => {limit = write} POST (handler::project::update_any),
+                / "list" => GET (handler::project::list),
+                / "export" => GET (handler::project::export),
+                / "form" {
+                    / "get" => GET (handler::project::form::get),
+                    / "list" => GET (handler::project::form::list),
+                    / "answer" {
+                        / => {limit = write} POST (handler::project::form::answer),
+                        / "get" => GET (handler::project::form::answer::get),
+                        / "update" => {limit = write} POST (handler::project::form::answer::update),
+                        / "draft" {
+                            / "get" => GET (handler::project::form::answer::draft::get),
+                            / "save" => {limit = write} POST (handler::project::form::answer::draft::save),
+                            / "submit" => {limit = write} POST (handler::project::form::answer::draft::submit),
+                        },
+                        / "file-sharing" {
+                            / "get-file" => GET (handler::project::form::answer::file_sharing::get_file),
+                            / "get-file-info" => GET (handler::project::form::answer::file_sharing::get_file_info),
+                        }
                     }
+                },
+                / "registration-form" {
+                    / "get" => GET (handler::project::registration_form::get),
+                    / "list" => GET (handler::project::registration_form::list),
+                    / "answer" {
+                        / "get" => GET (handler::project::registration_form::answer::get),
+                        / "update" => {limit = write} POST (handler::project::registration_form::answer::update),
+                        / "file-sharing" {
+                            / "get-file" => GET (handler::project::registration_form::answer::file_sharing::get_file),
+                            / "get-file-info" => GET (handler::project::registration_form::answer::file_sharing::get_file_info),
+                        }
+                    }
+                },
+                / "file-sharing" {
+                    / "get-file" => GET (handler::project::file_sharing::get_file),
+                    / "get-file-info" => GET (handler::project::file_sharing::get_file_info),
+                },
+                / "file-distribution" {
+                    / "list" => GET (handler::project::file_distribution::list),
+                    / "get" => GET (handler::project::file_distribution::get),
                 }
             },
-            / "registration-form" {
-                / "get" => GET (handler::project::registration_form::get),
-                / "list" => GET (handler::project::registration_form::list),
-                / "answer" {
-                    / "get" => GET (handler::project::registration_form::answer::get),
-                    / "update" => POST (handler::project::registration_form::answer::update),
-                    / "file-sharing" {
-                        / "get-file" => GET (handler::project::registration_form::answer::file_sharing::get_file),
-                        / "get-file-info" => GET (handler::project::registration_form::answer::file_sharing::get_file_info),
+            / "pending-project" {
+                / "get" => GET (handler::pending_project::get),
+                / "list" => GET (handler::pending_project::list),
+                / "export" => GET (handler::pending_project::export),
+                / "update" => {limit = write} POST (handler::pending_project::update),
+                / "update-any" => {limit = write} POST (handler::pending_project::update_any),
+                / "deadline" {
+                    / "list" => GET (handler::pending_project::list_deadlines),
+                    / "update" => {limit = write} POST (handler::pending_project::update_deadline),
+                },
+                / "registration-form" {
+                    / "get" => GET (handler::pending_project::registration_form::get),
+                    / "list" => GET (handler::pending_project::registration_form::list),
+                    / "answer" {
+                        / => {limit = write} POST (handler::pending_project::registration_form::answer),
+                        / "update" => {limit = write} POST (handler::pending_project::registration_form::answer::update),
+                        / "get" => GET (handler::pending_project::registration_form::answer::get),
+                        / "draft" {
+                            / "get" => GET (handler::pending_project::registration_form::answer::draft::get),
+                            / "save" => {limit = write} POST (handler::pending_project::registration_form::answer::draft::save),
+                            / "submit" => {limit = write} POST (handler::pending_project::registration_form::answer::draft::submit),
+                        }
                     }
                 }
             },
+            / "form" {
+                / "get" => GET (handler::form::get),
+                / "list" => GET (handler::form::list),
+                / "create" => {limit = write} POST (handler::form::create),
+                / "create-from-template" => {limit = write} POST (handler::form::create_from_template),
+                / "access-control" {
+                    / "get" => GET (handler::form::access_control::get),
+                    / "update" => {limit = write} POST (handler::form::access_control::update),
+                },
+                / "definition" {
+                    / "export" => GET (handler::form::definition::export),
+                    / "import" => {limit = write} POST (handler::form::definition::import),
+                    / "validate" => {limit = write} POST (handler::form::definition::validate),
+                },
+                / "duplicate" => {limit = write} POST (handler::form::duplicate),
+                / "update" => {limit = write} POST (handler::form::update),
+                / "validate-answer" => {limit = write} POST (handler::form::validate_answer),
+                / "answer" {
+                    / "list" => GET (handler::form::answer::list),
+                    / "export" => GET (handler::form::answer::export),
+                    / "migrate" => {limit = write} POST (handler::form::answer::migrate),
+                },
+                / "revision" {
+                    / "list" => GET (handler::form::revision::list),
+                    / "diff" => GET (handler::form::revision::diff),
+                }
+            },
+            / "form-template" {
+                / "get" => GET (handler::form_template::get),
+                / "list" => GET (handler::form_template::list),
+                / "create" => {limit = write} POST (handler::form_template::create),
+            },
+            / "api-key" {
+                / "list" => GET (handler::api_key::list),
+                / "create" => {limit = write} POST (handler::api_key::create),
+                / "revoke" => {limit = write} POST (handler::api_key::revoke),
+            },
+            / "custom-role" {
+                / "list" => GET (handler::custom_role::list),
+                / "create" => {limit = write} POST (handler::custom_role::create),
+                / "update" => {limit = write} POST (handler::custom_role::update),
+                / "delete" => {limit = write} POST (handler::custom_role::delete),
+            },
+            / "form-answer" {
+                / "get" => GET (handler::form_answer::get),
+                / "file-sharing" {
+                    / "get-file" => GET (handler::form_answer::file_sharing::get_file),
+                    / "get-file-info" => GET (handler::form_answer::file_sharing::get_file_info),
+                },
+                / "review" => {limit = write} POST (handler::form_answer::review),
+            },
+            / "user" {
+                / "get" => GET (handler::user::get),
+                / "list" => GET (handler::user::list),
+                / "export" => GET (handler::user::export),
+                / "update" => {limit = write} POST (handler::user::update),
+                / "set-custom-role" => {limit = write} POST (handler::user::set_custom_role),
+                / "suspend" => {limit = write} POST (handler::user::suspend),
+                / "unsuspend" => {limit = write} POST (handler::user::unsuspend),
+                / "revoke-tokens" => {limit = write} POST (handler::user::revoke_tokens),
+            },
+            / "file" {
+                / "create" => {limit = upload} POST_STREAM (handler::file::create),
+                / "get" => GET (handler::file::get),
+                / "get-info" => GET (handler::file::get_info),
+                / "share" => {limit = write} POST (handler::file::share),
+            },
             / "file-sharing" {
-                / "get-file" => GET (handler::project::file_sharing::get_file),
-                / "get-file-info" => GET (handler::project::file_sharing::get_file_info),
+                / "get" => GET (handler::file_sharing::get),
+                / "revoke" => {limit = write} POST (handler::file_sharing::revoke),
+                / "get-file" => GET (handler::file_sharing::get_file),
+                / "get-file-info" => GET (handler::file_sharing::get_file_info),
+                / "get-public-file" => {noauth} GET (handler::file_sharing::get_public_file),
+                / "get-public-file-info" => {noauth} GET (handler::file_sharing::get_public_file_info),
             },
             / "file-distribution" {
-                / "list" => GET (handler::project::file_distribution::list),
-                / "get" => GET (handler::project::file_distribution::get),
-            }
-        },
-        / "pending-project" {
-            / "get" => GET (handler::pending_project::get),
-            / "update" => POST (handler::pending_project::update),
-            / "update-any" => POST (handler::pending_project::update_any),
+                / "create" => {limit = write} POST (handler::file_distribution::create),
+                / "list" => GET (handler::file_distribution::list),
+                / "get" => GET (handler::file_distribution::get),
+            },
             / "registration-form" {
-                / "get" => GET (handler::pending_project::registration_form::get),
-                / "list" => GET (handler::pending_project::registration_form::list),
+                / "get" => GET (handler::registration_form::get),
+                / "list" => GET (handler::registration_form::list),
+                / "create" => {limit = write} POST (handler::registration_form::create),
+                / "create-from-template" => {limit = write} POST (handler::registration_form::create_from_template),
+                / "access-control" {
+                    / "get" => GET (handler::registration_form::access_control::get),
+                    / "update" => {limit = write} POST (handler::registration_form::access_control::update),
+                },
+                / "definition" {
+                    / "export" => GET (handler::registration_form::definition::export),
+                    / "import" => {limit = write} POST (handler::registration_form::definition::import),
+                    / "validate" => {limit = write} POST (handler::registration_form::definition::validate),
+                },
+                / "duplicate" => {limit = write} POST (handler::registration_form::duplicate),
                 / "answer" {
-                    / => POST (handler::pending_project::registration_form::answer),
-                    / "update" => POST (handler::pending_project::registration_form::answer::update),
-                    / "get" => GET (handler::pending_project::registration_form::answer::get),
+                    / "list" => GET (handler::registration_form::answer::list),
+                    / "export" => GET (handler::registration_form::answer::export),
                 }
-            }
-        },
-        / "form" {
-            / "get" => GET (handler::form::get),
-            / "list" => GET (handler::form::list),
-            / "create" => POST (handler::form::create),
-            / "update" => POST (handler::form::update),
-            / "answer" {
-                / "list" => GET (handler::form::answer::list),
-                / "export" => GET (handler::form::answer::export),
-            }
-        },
-        / "form-answer" {
-            / "get" => GET (handler::form_answer::get),
-            / "file-sharing" {
-                / "get-file" => GET (handler::form_answer::file_sharing::get_file),
-                / "get-file-info" => GET (handler::form_answer::file_sharing::get_file_info),
-            }
-        },
-        / "user" {
-            / "get" => GET (handler::user::get),
-            / "list" => GET (handler::user::list),
-            / "export" => GET (handler::user::export),
-            / "update" => POST (handler::user::update),
-        },
-        / "file" {
-            / "create" => POST_STREAM (handler::file::create),
-            / "get" => GET (handler::file::get),
-            / "get-info" => GET (handler::file::get_info),
-            / "share" => POST (handler::file::share),
-        },
-        / "file-sharing" {
-            / "get" => GET (handler::file_sharing::get),
-            / "revoke" => POST (handler::file_sharing::revoke),
-            / "get-file" => GET (handler::file_sharing::get_file),
-            / "get-file-info" => GET (handler::file_sharing::get_file_info),
-            / "get-public-file" => {noauth} GET (handler::file_sharing::get_public_file),
-            / "get-public-file-info" => {noauth} GET (handler::file_sharing::get_public_file_info),
-        },
-        / "file-distribution" {
-            / "create" => POST (handler::file_distribution::create),
-            / "list" => GET (handler::file_distribution::list),
-            / "get" => GET (handler::file_distribution::get),
-        },
-        / "registration-form" {
-            / "get" => GET (handler::registration_form::get),
-            / "list" => GET (handler::registration_form::list),
-            / "create" => POST (handler::registration_form::create),
-            / "answer" {
-                / "list" => GET (handler::registration_form::answer::list),
-                / "export" => GET (handler::registration_form::answer::export),
-            }
-        },
-        / "registration-form-answer" {
-            / "get" => GET (handler::registration_form_answer::get),
-            / "file-sharing" {
-                / "get-file" => GET (handler::registration_form_answer::file_sharing::get_file),
-                / "get-file-info" => GET (handler::registration_form_answer::file_sharing::get_file_info),
-            }
-        },
-        / "invite-user" => POST (handler::invite_user),
-        / "user-invitation" {
-            / "get" => GET (handler::user_invitation::get),
-            / "list" => GET (handler::user_invitation::list),
-            / "delete" => POST (handler::user_invitation::delete),
-        },
-        / "assign-user-role-to-email" => POST (handler::assign_user_role_to_email),
+            },
+            / "registration-form-answer" {
+                / "get" => GET (handler::registration_form_answer::get),
+                / "file-sharing" {
+                    / "get-file" => GET (handler::registration_form_answer::file_sharing::get_file),
+                    / "get-file-info" => GET (handler::registration_form_answer::file_sharing::get_file_info),
+                }
+            },
+            / "invite-user" => {limit = invitation} POST (handler::invite_user),
+            / "invite-users" => {limit = invitation} POST (handler::invite_users),
+            / "user-invitation" {
+                / "get" => GET (handler::user_invitation::get),
+                / "list" => GET (handler::user_invitation::list),
+                / "delete" => {limit = write} POST (handler::user_invitation::delete),
+                / "resend" => {limit = invitation} POST (handler::user_invitation::resend),
+            },
+            / "assign-user-role-to-email" => {limit = write} POST (handler::assign_user_role_to_email),
+            / "festival" {
+                / "create" => {limit = write} POST (handler::festival::create),
+                / "get" => GET (handler::festival::get),
+                / "list" => GET (handler::festival::list),
+            },
+            / "settings" {
+                / "get" => GET (handler::settings::get),
+                / "update" => {limit = write} POST (handler::settings::update),
+                / "revision" {
+                    / "list" => GET (handler::settings::revision::list),
+                }
+            },
+        }
+    };
+}
+
+#[cfg(feature = "dev-auth")]
+macro_rules! with_dev_endpoints {
+    ($callback:ident! { $($args:tt)* }) => {
+        $callback! { $($args)*
+            / "dev" {
+                / "issue-token" => {noauth} POST (handler::dev::issue_token),
+            },
+        }
     };
+}
+
+/// Lists the routes served by [`endpoints`].
+// `route_table!` expands to a sequence of `push`
+#[allow(clippy::vec_init_then_push)]
+pub fn route_table() -> Vec<crate::openapi::Route> {
+    use crate::handler;
+
+    let mut table = Vec::new();
+    with_endpoints!(route_table! { table, [] });
+    #[cfg(feature = "dev-auth")]
+    with_dev_endpoints!(route_table! { table, [] });
+    table
+}
+
+pub fn endpoints(
+    app: App,
+    key_store: KeyStore,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = Infallible> + Clone {
+    use crate::handler;
+
+    let with_auth = authenticate(key_store, app.clone());
+    let with_app = warp::any().map(move || app.clone());
+
+    let routes = with_endpoints!(routes! { with_auth, with_app, });
+
+    #[cfg(feature = "dev-auth")]
+    let routes = routes
+        .or(with_dev_endpoints!(routes! { with_auth, with_app, }))
+        .boxed();
 
     let cors = warp::cors()
         .allow_any_origin()
@@ -226,9 +397,8 @@ pub fn endpoints(
         .expose_header(header::CONTENT_DISPOSITION)
         .max_age(std::time::Duration::from_secs(30 * 60));
 
-    routes
-        .recover(handle_rejection)
-        .with(cors)
-        .recover(handle_cors_rejection)
-        .with(warp::trace::request())
+    let routes = request::recover(routes, handle_rejection).with(cors);
+    request::recover(routes, handle_cors_rejection)
+        .map(request::record_status)
+        .with(warp::trace(request::span))
 }
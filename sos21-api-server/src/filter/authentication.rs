@@ -1,6 +1,10 @@
+use crate::app::App;
 use crate::config::Config;
 
+use std::convert::Infallible;
+
 use anyhow::Context as _;
+use chrono::{DateTime, TimeZone, Utc};
 use jsonwebtoken as jwt;
 use tracing::{event, Level};
 use warp::{Filter, Rejection};
@@ -11,13 +15,20 @@ mod key_store;
 use bearer::Bearer;
 use claim::Claims;
 pub use key_store::KeyStore;
-use sos21_domain::model::user::UserEmailAddress;
+use sos21_domain::model::{api_key::ApiKeyToken, user::UserEmailAddress};
 use sos21_gateway_slack::report_suspicious_email;
+use sos21_use_case::{model::user::UserId, suspend_suspicious_user, UseCaseError};
 
 #[derive(Debug, Clone)]
-pub struct AuthenticationInfo {
-    pub user_id: String,
-    pub email: String,
+pub enum AuthenticationInfo {
+    /// A user authenticated with a JWT.
+    User {
+        user_id: String,
+        email: String,
+        issued_at: DateTime<Utc>,
+    },
+    /// An API key, which is validated against the database on login.
+    ApiKey { token: ApiKeyToken },
 }
 
 #[tracing::instrument(skip(config, key_store), level = "debug")]
@@ -52,13 +63,49 @@ pub enum AuthenticationError {
 
 impl warp::reject::Reject for AuthenticationError {}
 
-#[tracing::instrument(skip(key_store, config), level = "debug")]
+/// Whether the user has signed up with `email`, and thus is exempted from the suspension
+/// as in `Authentication::new`.
+///
+/// This is checked without a transaction, so that the suspension context is only opened
+/// for the users to be suspended.
+async fn is_signed_up_with(app: &App, user_id: &str, email: &str) -> anyhow::Result<bool> {
+    let mut conn = app.connection().await?;
+    let user = sos21_database::query::find_user(&mut conn, user_id.to_owned())
+        .await
+        .context("Failed to get a user")?;
+    Ok(matches!(user, Some(user) if user.email == email))
+}
+
+async fn suspend_suspicious_user(
+    app: &App,
+    user_id: String,
+    email: String,
+) -> anyhow::Result<bool> {
+    let ctx = app.start_context().await?;
+    let suspended = match suspend_suspicious_user::run(&ctx, UserId(user_id), email).await {
+        Ok(suspended) => suspended,
+        Err(UseCaseError::Internal(err)) => return Err(err),
+        Err(UseCaseError::UseCase(err)) => match err {},
+    };
+    ctx.commit_changes()
+        .await
+        .context("Failed to commit the suspension")?;
+    Ok(suspended)
+}
+
+#[tracing::instrument(skip(key_store, app), level = "debug")]
 async fn handle_validation(
     key_store: KeyStore,
-    config: Config,
+    app: App,
     bearer: Bearer,
 ) -> Result<AuthenticationInfo, Rejection> {
-    let claims = match validate_token(&config, key_store, bearer).await {
+    let config = app.config();
+
+    if let Some(token) = ApiKeyToken::from_string(bearer.token.as_str()) {
+        return Ok(AuthenticationInfo::ApiKey { token });
+    }
+
+    let claims = match validate_token(config, key_store, bearer).await {
         Ok(cs) => cs,
         Err(error) => {
             event!(Level::INFO, ?error, "Invalid token");
@@ -77,25 +124,52 @@ async fn handle_validation(
         ));
     }
 
-    if UserEmailAddress::from_string(&email).is_err() {
+    // Addresses invited as exceptions to the allowed domains are reported as well,
+    // because invitations are not available here.
+    let is_exempted =
+        UserEmailAddress::from_string_with_domains(&email, &config.allowed_email_domains).is_ok()
+            || match is_signed_up_with(&app, &claims.sub, &email).await {
+                Ok(signed_up) => signed_up,
+                Err(error) => {
+                    event!(Level::ERROR, ?error, "Failed to check the signed up user");
+                    false
+                }
+            };
+    if !is_exempted {
         if let Err(e) = report_suspicious_email(&config.admin_report_slack_webhook, &email) {
             tracing::error!("Failed to deliver suspicious account report. Reason: {}", e);
         }
+
+        // The login is rejected afterwards in `Login` construction when the user is suspended here.
+        if config.suspend_suspicious_users {
+            match suspend_suspicious_user(&app, claims.sub.clone(), email.clone()).await {
+                Ok(true) => event!(Level::WARN, user_id = %claims.sub, "Suspended suspicious user"),
+                Ok(false) => {}
+                Err(error) => event!(Level::ERROR, ?error, "Failed to suspend suspicious user"),
+            }
+        }
     }
 
-    Ok(AuthenticationInfo {
+    let issued_at = match Utc.timestamp_opt(claims.iat, 0).single() {
+        Some(issued_at) => issued_at,
+        None => return Err(warp::reject::custom(AuthenticationError::InvalidToken)),
+    };
+
+    super::record_user_id(&claims.sub);
+    Ok(AuthenticationInfo::User {
         user_id: claims.sub,
         email,
+        issued_at,
     })
 }
 
 pub fn authenticate(
     key_store: KeyStore,
-    config: Config,
+    app: App,
 ) -> impl Filter<Extract = (AuthenticationInfo,), Error = Rejection> + Clone {
     warp::any()
         .map(move || key_store.clone())
-        .and(warp::any().map(move || config.clone()))
+        .and(warp::any().map(move || app.clone()))
         .and(
             warp::header::<Bearer>("authorization").or_else(|_| async {
                 Err(warp::reject::custom(AuthenticationError::Unauthorized))
@@ -103,3 +177,32 @@ pub fn authenticate(
         )
         .and_then(handle_validation)
 }
+
+/// Requires the bearer token configured with [`Config::metrics_token`],
+/// which is distinct from the user tokens so that a metrics collector does not need an account.
+pub fn authenticate_metrics<F>(
+    with_app: F,
+) -> impl Filter<Extract = (App,), Error = Rejection> + Clone
+where
+    F: Filter<Extract = (App,), Error = Infallible> + Clone,
+{
+    with_app
+        .and(warp::header::optional::<Bearer>("authorization"))
+        .and_then(|app: App, bearer: Option<Bearer>| async move {
+            let is_valid = match (&app.config().metrics_token, bearer) {
+                (Some(token), Some(bearer)) => {
+                    constant_time_eq(token.as_bytes(), bearer.token.as_bytes())
+                }
+                _ => return Err(warp::reject::custom(AuthenticationError::Unauthorized)),
+            };
+            if is_valid {
+                Ok(app)
+            } else {
+                Err(warp::reject::custom(AuthenticationError::InvalidToken))
+            }
+        })
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
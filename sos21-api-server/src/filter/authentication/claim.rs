@@ -6,9 +6,12 @@ use serde::Deserialize;
 pub struct Claims {
     pub email: Option<String>,
     pub email_verified: bool,
+    #[allow(dead_code)]
     pub phone_number: Option<String>,
+    #[allow(dead_code)]
     pub name: Option<String>,
     pub sub: String,
+    pub iat: i64,
     // pub firebase.identities
     // pub firebase.sign_in_provider
 }
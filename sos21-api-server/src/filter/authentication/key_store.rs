@@ -76,21 +76,28 @@ impl KeyStore {
             .json()
             .await
             .context("Failed to obtain keys from the response")?;
-        let keys = response
-            .keys
-            .into_iter()
-            .map(|key| {
-                (
-                    key.kid,
-                    DecodingKey::from_rsa_components(&key.n, &key.e).into_static(),
-                )
-            })
-            .collect();
-        *self.keys.write().await = keys;
+        *self.keys.write().await = decode_keys(response).collect();
 
         Ok(max_age)
     }
 
+    /// Adds the keys in a local JWKS file, which is in the same format as the one from the URL.
+    #[cfg(feature = "dev-auth")]
+    pub async fn load_file(&self, path: &std::path::Path) -> Result<()> {
+        let content = tokio::fs::read(path)
+            .await
+            .with_context(|| format!("Failed to read JWKS file {}", path.display()))?;
+        let response: Response = serde_json::from_slice(&content)
+            .with_context(|| format!("Failed to obtain keys from {}", path.display()))?;
+        self.keys.write().await.extend(decode_keys(response));
+        Ok(())
+    }
+
+    #[cfg(feature = "dev-auth")]
+    pub async fn insert(&self, kid: String, key: DecodingKey<'static>) {
+        self.keys.write().await.insert(kid, key);
+    }
+
     pub async fn get<T>(&self, kid: &T) -> Option<DecodingKey<'static>>
     where
         T: Hash + Eq,
@@ -100,6 +107,15 @@ impl KeyStore {
     }
 }
 
+fn decode_keys(response: Response) -> impl Iterator<Item = (String, DecodingKey<'static>)> {
+    response.keys.into_iter().map(|key| {
+        (
+            key.kid,
+            DecodingKey::from_rsa_components(&key.n, &key.e).into_static(),
+        )
+    })
+}
+
 // https://tools.ietf.org/html/rfc2616#section-14.9
 fn parse_max_age(v: &reqwest::header::HeaderValue) -> Option<u64> {
     let s = v.to_str().ok()?.to_ascii_lowercase();
@@ -108,9 +124,7 @@ fn parse_max_age(v: &reqwest::header::HeaderValue) -> Option<u64> {
     let after_max_age = sp.next()?;
     debug_assert!(sp.next().is_none());
     let after_equal = after_max_age.trim().strip_prefix('=')?;
-    let mut sp = after_equal
-        .trim()
-        .splitn(2, |c| matches!(c, ',' | '\n' | '\r' | ' ' | '\t'));
+    let mut sp = after_equal.trim().splitn(2, [',', '\n', '\r', ' ', '\t']);
     let delta_seconds = sp.next()?;
     delta_seconds.parse().ok()
 }
@@ -1,11 +1,13 @@
 use std::convert::Infallible;
 
 use super::authentication::AuthenticationError;
+use super::rate_limit::RateLimitExceeded;
+use super::request::RequestId;
 use crate::handler::ErasedHandlerError;
 
 use tracing::{event, Level};
 use warp::{
-    http::StatusCode,
+    http::{header, StatusCode},
     {reject::Rejection, reply::Reply},
 };
 
@@ -13,7 +15,13 @@ pub mod model;
 use model::{AuthenticationErrorId, Error, ErrorBody, RequestErrorId};
 
 // TODO: Can't we somehow type `Rejection` and detect unhandled rejections statically?
-pub async fn handle_rejection(err: Rejection) -> Result<impl Reply, Infallible> {
+pub async fn handle_rejection(
+    request_id: RequestId,
+    err: Rejection,
+) -> Result<impl Reply, Infallible> {
+    let retry_after = err
+        .find::<RateLimitExceeded>()
+        .map(RateLimitExceeded::retry_after_secs);
     let error = if err.is_not_found() {
         Error {
             error: ErrorBody::Request {
@@ -26,17 +34,35 @@ pub async fn handle_rejection(err: Rejection) -> Result<impl Reply, Infallible>
             ErasedHandlerError::Server(error) => {
                 event!(Level::ERROR, ?error, "Unexpected error in handler");
                 Error {
-                    error: ErrorBody::Internal,
+                    error: ErrorBody::Internal { request_id },
                     status: StatusCode::INTERNAL_SERVER_ERROR,
                 }
             }
             ErasedHandlerError::ServiceUnavailable(error) => {
                 event!(Level::ERROR, ?error, "Service unavailable");
                 Error {
-                    error: ErrorBody::ServiceUnavailable,
+                    error: ErrorBody::ServiceUnavailable { request_id },
                     status: StatusCode::SERVICE_UNAVAILABLE,
                 }
             }
+            ErasedHandlerError::InvalidToken => Error {
+                error: ErrorBody::Authentication {
+                    id: AuthenticationErrorId::InvalidToken,
+                },
+                status: StatusCode::UNAUTHORIZED,
+            },
+            ErasedHandlerError::RevokedToken => Error {
+                error: ErrorBody::Authentication {
+                    id: AuthenticationErrorId::RevokedToken,
+                },
+                status: StatusCode::UNAUTHORIZED,
+            },
+            ErasedHandlerError::Suspended => Error {
+                error: ErrorBody::Authentication {
+                    id: AuthenticationErrorId::SuspendedUser,
+                },
+                status: StatusCode::FORBIDDEN,
+            },
             ErasedHandlerError::InvalidEmailAddress => Error {
                 error: ErrorBody::Authentication {
                     id: AuthenticationErrorId::InvalidEmailAddress,
@@ -85,6 +111,13 @@ pub async fn handle_rejection(err: Rejection) -> Result<impl Reply, Infallible>
                 status: StatusCode::FORBIDDEN,
             },
         }
+    } else if retry_after.is_some() {
+        Error {
+            error: ErrorBody::Request {
+                id: RequestErrorId::TooManyRequests,
+            },
+            status: StatusCode::TOO_MANY_REQUESTS,
+        }
     } else if err.find::<warp::reject::UnsupportedMediaType>().is_some() {
         Error {
             error: ErrorBody::Request {
@@ -128,15 +161,23 @@ pub async fn handle_rejection(err: Rejection) -> Result<impl Reply, Infallible>
             status: StatusCode::BAD_REQUEST,
         }
     } else {
-        unhandled_rejection(err)
+        unhandled_rejection(request_id, err)
     };
-    Ok(warp::reply::with_status(
-        warp::reply::json(&error),
-        error.status,
-    ))
+    crate::metrics::record_rejection(&error.error);
+    let mut response =
+        warp::reply::with_status(warp::reply::json(&error), error.status).into_response();
+    if let Some(retry_after) = retry_after {
+        response
+            .headers_mut()
+            .insert(header::RETRY_AFTER, retry_after.into());
+    }
+    Ok(response)
 }
 
-pub async fn handle_cors_rejection(err: Rejection) -> Result<impl Reply, Infallible> {
+pub async fn handle_cors_rejection(
+    request_id: RequestId,
+    err: Rejection,
+) -> Result<impl Reply, Infallible> {
     let error = if err.find::<warp::filters::cors::CorsForbidden>().is_some() {
         Error {
             error: ErrorBody::Request {
@@ -145,8 +186,9 @@ pub async fn handle_cors_rejection(err: Rejection) -> Result<impl Reply, Infalli
             status: StatusCode::FORBIDDEN,
         }
     } else {
-        unhandled_rejection(err)
+        unhandled_rejection(request_id, err)
     };
+    crate::metrics::record_rejection(&error.error);
 
     Ok(warp::reply::with_status(
         warp::reply::json(&error),
@@ -154,10 +196,10 @@ pub async fn handle_cors_rejection(err: Rejection) -> Result<impl Reply, Infalli
     ))
 }
 
-fn unhandled_rejection(err: Rejection) -> Error {
+fn unhandled_rejection(request_id: RequestId, err: Rejection) -> Error {
     event!(Level::ERROR, rejection = ?err, "Unhandled rejection");
     Error {
-        error: ErrorBody::Internal,
+        error: ErrorBody::Internal { request_id },
         status: StatusCode::INTERNAL_SERVER_ERROR,
     }
 }
@@ -1,18 +1,23 @@
+use crate::filter::RequestId;
+
 use serde::{ser::Serializer, Serialize};
+use utoipa::ToSchema;
 use warp::http::StatusCode;
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum AuthenticationErrorId {
     Unauthorized,
     InvalidToken,
+    RevokedToken,
+    SuspendedUser,
     InvalidEmailAddress,
     UnverifiedEmailAddress,
     NotUniversityEmailAddress,
     NoEmail,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum RequestErrorId {
     NotFound,
@@ -23,23 +28,36 @@ pub enum RequestErrorId {
     InvalidQuery,
     InvalidBody,
     CorsForbidden,
+    TooManyRequests,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
 pub enum ErrorBody {
-    Api { info: serde_json::Value },
-    Authentication { id: AuthenticationErrorId },
-    Request { id: RequestErrorId },
+    Api {
+        info: serde_json::Value,
+    },
+    Authentication {
+        id: AuthenticationErrorId,
+    },
+    Request {
+        id: RequestErrorId,
+    },
     NotSignedUp,
-    ServiceUnavailable,
-    Internal,
+    /// `request_id` is included in server errors so that users can report it.
+    ServiceUnavailable {
+        request_id: RequestId,
+    },
+    Internal {
+        request_id: RequestId,
+    },
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct Error {
     pub error: ErrorBody,
     #[serde(serialize_with = "serialize_status_code")]
+    #[schema(value_type = u16)]
     pub status: StatusCode,
 }
 
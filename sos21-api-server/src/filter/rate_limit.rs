@@ -0,0 +1,376 @@
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use super::AuthenticationInfo;
+use crate::app::App;
+use crate::config::{RateLimit, RateLimitConfig};
+
+use sos21_domain::model::api_key::ApiKeyTokenDigest;
+use tracing::{event, Level};
+use warp::{Filter, Rejection};
+
+/// The number of buckets above which the buckets that have been refilled are dropped.
+const PRUNE_THRESHOLD: usize = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RateLimitGroup {
+    Write,
+    Upload,
+    Invitation,
+}
+
+impl RateLimitGroup {
+    fn limit(&self, config: &RateLimitConfig) -> RateLimit {
+        match self {
+            RateLimitGroup::Write => config.write,
+            RateLimitGroup::Upload => config.upload,
+            RateLimitGroup::Invitation => config.invitation,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct RateLimitExceeded {
+    retry_after: Duration,
+}
+
+impl RateLimitExceeded {
+    /// The value of `Retry-After` header, which is rounded up to whole seconds.
+    pub fn retry_after_secs(&self) -> u64 {
+        let secs = self.retry_after.as_secs();
+        if self.retry_after.subsec_nanos() > 0 {
+            secs + 1
+        } else {
+            secs.max(1)
+        }
+    }
+}
+
+impl warp::reject::Reject for RateLimitExceeded {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum ClientKey {
+    User(String),
+    ApiKey(ApiKeyTokenDigest),
+    Ip(IpAddr),
+}
+
+/// A token bucket which holds up to `capacity` tokens and is refilled at `capacity` tokens per minute.
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    capacity: u32,
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl Bucket {
+    fn new(capacity: u32, now: Instant) -> Self {
+        Bucket {
+            capacity,
+            tokens: capacity as f64,
+            updated_at: now,
+        }
+    }
+
+    fn tokens_per_sec(&self) -> f64 {
+        self.capacity as f64 / 60.0
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.tokens_per_sec()).min(self.capacity as f64);
+        self.updated_at = now;
+    }
+
+    fn is_full(&self) -> bool {
+        self.tokens >= self.capacity as f64
+    }
+
+    fn time_until_available(&self) -> Duration {
+        if self.tokens >= 1.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64((1.0 - self.tokens) / self.tokens_per_sec())
+        }
+    }
+}
+
+/// In-process store of the token buckets for each route group and client.
+#[derive(Debug, Clone, Default)]
+pub struct RateLimiter {
+    buckets: Arc<Mutex<HashMap<(RateLimitGroup, ClientKey), Bucket>>>,
+}
+
+impl RateLimiter {
+    pub fn new() -> Self {
+        RateLimiter::default()
+    }
+
+    /// Takes a token from each of the buckets for `clients` only if all of them have one.
+    /// Clients with the capacity of `0` are not limited.
+    fn acquire(
+        &self,
+        group: RateLimitGroup,
+        clients: &[(ClientKey, u32)],
+        now: Instant,
+    ) -> Result<(), RateLimitExceeded> {
+        let mut buckets = self.buckets.lock().unwrap();
+
+        if buckets.len() > PRUNE_THRESHOLD {
+            buckets.retain(|_, bucket| {
+                bucket.refill(now);
+                !bucket.is_full()
+            });
+        }
+
+        let mut retry_after = Duration::ZERO;
+        for (key, capacity) in clients {
+            if *capacity == 0 {
+                continue;
+            }
+            let bucket = buckets
+                .entry((group, key.clone()))
+                .or_insert_with(|| Bucket::new(*capacity, now));
+            bucket.capacity = *capacity;
+            bucket.refill(now);
+            retry_after = retry_after.max(bucket.time_until_available());
+        }
+
+        if retry_after > Duration::ZERO {
+            return Err(RateLimitExceeded { retry_after });
+        }
+
+        for (key, capacity) in clients {
+            if *capacity == 0 {
+                continue;
+            }
+            if let Some(bucket) = buckets.get_mut(&(group, key.clone())) {
+                bucket.tokens -= 1.0;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn client_ip(
+    config: &RateLimitConfig,
+    remote: Option<SocketAddr>,
+    forwarded_for: Option<String>,
+) -> Option<IpAddr> {
+    if config.trust_forwarded_for {
+        // the last entry is the one appended by the nearest proxy, which we trust
+        let forwarded = forwarded_for
+            .as_deref()
+            .and_then(|value| value.rsplit(',').next())
+            .and_then(|addr| addr.trim().parse().ok());
+        if forwarded.is_some() {
+            return forwarded;
+        }
+    }
+    remote.map(|addr| addr.ip())
+}
+
+fn check(
+    group: RateLimitGroup,
+    app: &App,
+    auth: &AuthenticationInfo,
+    ip: Option<IpAddr>,
+) -> Result<(), RateLimitExceeded> {
+    let limit = group.limit(&app.config().rate_limit);
+
+    // API keys are limited separately from the users who created them
+    let client = match auth {
+        AuthenticationInfo::User { user_id, .. } => ClientKey::User(user_id.clone()),
+        AuthenticationInfo::ApiKey { token } => ClientKey::ApiKey(token.digest()),
+    };
+    let mut clients = vec![(client.clone(), limit.per_user)];
+    if let Some(ip) = ip {
+        clients.push((ClientKey::Ip(ip), limit.per_ip));
+    }
+
+    let result = app.rate_limiter().acquire(group, &clients, Instant::now());
+    if let Err(err) = &result {
+        event!(
+            Level::INFO,
+            ?group,
+            ?client,
+            ?ip,
+            retry_after = ?err.retry_after,
+            "Rate limit exceeded"
+        );
+    }
+    result
+}
+
+/// Rejects the request with `RateLimitExceeded` when the authenticated user or the client IP
+/// runs out of the budget for `group`.
+pub fn limit<F>(
+    group: RateLimitGroup,
+    filter: F,
+) -> impl Filter<Extract = (App, AuthenticationInfo), Error = Rejection> + Clone
+where
+    F: Filter<Extract = (App, AuthenticationInfo), Error = Rejection> + Clone + Send + Sync,
+{
+    filter
+        .and(warp::addr::remote())
+        .and(warp::header::optional::<String>("x-forwarded-for"))
+        .and_then(
+            move |app: App, auth: AuthenticationInfo, remote, forwarded_for| async move {
+                let ip = client_ip(&app.config().rate_limit, remote, forwarded_for);
+                match check(group, &app, &auth, ip) {
+                    Ok(()) => Ok((app, auth)),
+                    Err(err) => Err(warp::reject::custom(err)),
+                }
+            },
+        )
+        .untuple_one()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{client_ip, ClientKey, RateLimitExceeded, RateLimitGroup, RateLimiter};
+    use crate::config::{RateLimit, RateLimitConfig};
+    use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+    use std::time::{Duration, Instant};
+
+    fn user(id: &str) -> ClientKey {
+        ClientKey::User(id.to_owned())
+    }
+
+    fn ip(last: u8) -> ClientKey {
+        ClientKey::Ip(IpAddr::V4(Ipv4Addr::new(192, 0, 2, last)))
+    }
+
+    #[test]
+    fn test_burst_then_reject() {
+        let limiter = RateLimiter::new();
+        let now = Instant::now();
+        for _ in 0..3 {
+            assert!(limiter
+                .acquire(RateLimitGroup::Write, &[(user("a"), 3)], now)
+                .is_ok());
+        }
+        let err = limiter
+            .acquire(RateLimitGroup::Write, &[(user("a"), 3)], now)
+            .unwrap_err();
+        assert_eq!(err.retry_after, Duration::from_secs(20));
+        assert_eq!(err.retry_after_secs(), 20);
+    }
+
+    #[test]
+    fn test_refill() {
+        let limiter = RateLimiter::new();
+        let now = Instant::now();
+        for _ in 0..2 {
+            assert!(limiter
+                .acquire(RateLimitGroup::Write, &[(user("a"), 2)], now)
+                .is_ok());
+        }
+        assert!(limiter
+            .acquire(
+                RateLimitGroup::Write,
+                &[(user("a"), 2)],
+                now + Duration::from_secs(10)
+            )
+            .is_err());
+        assert!(limiter
+            .acquire(
+                RateLimitGroup::Write,
+                &[(user("a"), 2)],
+                now + Duration::from_secs(30)
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn test_separate_groups_and_clients() {
+        let limiter = RateLimiter::new();
+        let now = Instant::now();
+        assert!(limiter
+            .acquire(RateLimitGroup::Write, &[(user("a"), 1)], now)
+            .is_ok());
+        assert!(limiter
+            .acquire(RateLimitGroup::Upload, &[(user("a"), 1)], now)
+            .is_ok());
+        assert!(limiter
+            .acquire(RateLimitGroup::Write, &[(user("b"), 1)], now)
+            .is_ok());
+        assert!(limiter
+            .acquire(RateLimitGroup::Write, &[(user("a"), 1)], now)
+            .is_err());
+    }
+
+    #[test]
+    fn test_ip_limit_shared_by_users() {
+        let limiter = RateLimiter::new();
+        let now = Instant::now();
+        assert!(limiter
+            .acquire(RateLimitGroup::Write, &[(user("a"), 5), (ip(1), 2)], now)
+            .is_ok());
+        assert!(limiter
+            .acquire(RateLimitGroup::Write, &[(user("b"), 5), (ip(1), 2)], now)
+            .is_ok());
+        assert!(limiter
+            .acquire(RateLimitGroup::Write, &[(user("c"), 5), (ip(1), 2)], now)
+            .is_err());
+        // the rejected request does not consume the budget of the user
+        assert!(limiter
+            .acquire(RateLimitGroup::Write, &[(user("c"), 1), (ip(2), 2)], now)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_zero_disables_limit() {
+        let limiter = RateLimiter::new();
+        let now = Instant::now();
+        for _ in 0..100 {
+            assert!(limiter
+                .acquire(RateLimitGroup::Write, &[(user("a"), 0)], now)
+                .is_ok());
+        }
+    }
+
+    #[test]
+    fn test_retry_after_secs() {
+        let err = RateLimitExceeded {
+            retry_after: Duration::from_millis(1500),
+        };
+        assert_eq!(err.retry_after_secs(), 2);
+        let err = RateLimitExceeded {
+            retry_after: Duration::from_millis(10),
+        };
+        assert_eq!(err.retry_after_secs(), 1);
+    }
+
+    #[test]
+    fn test_client_ip() {
+        let limit = RateLimit {
+            per_user: 1,
+            per_ip: 1,
+        };
+        let mut config = RateLimitConfig {
+            write: limit,
+            upload: limit,
+            invitation: limit,
+            trust_forwarded_for: false,
+        };
+        let remote: SocketAddr = "192.0.2.1:1234".parse().unwrap();
+        let forwarded = Some("198.51.100.1, 203.0.113.1".to_owned());
+        assert_eq!(
+            client_ip(&config, Some(remote), forwarded.clone()),
+            Some(remote.ip())
+        );
+        config.trust_forwarded_for = true;
+        assert_eq!(
+            client_ip(&config, Some(remote), forwarded),
+            Some("203.0.113.1".parse().unwrap())
+        );
+        assert_eq!(
+            client_ip(&config, Some(remote), Some("invalid".to_owned())),
+            Some(remote.ip())
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use std::convert::Infallible;
+use std::future::Future;
+
+use serde::Serialize;
+use tracing::{field::Empty, Span};
+use utoipa::ToSchema;
+use warp::{
+    http::{header::HeaderValue, HeaderMap, Response},
+    hyper::Body,
+    path::FullPath,
+    reject::Rejection,
+    trace::Info,
+    Filter, Reply,
+};
+
+const REQUEST_ID_HEADER: &str = "x-request-id";
+/// Longer request IDs from clients are replaced with generated ones, as they are logged and echoed back.
+const MAX_REQUEST_ID_LENGTH: usize = 128;
+
+/// The ID to correlate a request with the logs, taken from `X-Request-Id` header or generated.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(transparent)]
+pub struct RequestId(String);
+
+impl RequestId {
+    fn generate() -> Self {
+        RequestId(uuid::Uuid::new_v4().to_string())
+    }
+
+    fn from_header(value: &HeaderValue) -> Option<Self> {
+        let value = value.to_str().ok()?;
+        let is_valid = !value.is_empty()
+            && value.len() <= MAX_REQUEST_ID_LENGTH
+            && value.bytes().all(|b| b.is_ascii_graphic());
+        if is_valid {
+            Some(RequestId(value.to_owned()))
+        } else {
+            None
+        }
+    }
+
+    fn from_headers(headers: &HeaderMap) -> Self {
+        let id = headers
+            .get(REQUEST_ID_HEADER)
+            .and_then(RequestId::from_header)
+            .unwrap_or_else(RequestId::generate);
+        Span::current().record("request_id", id.as_str());
+        id
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Creates the span of a request, whose empty fields are recorded while the request is processed.
+pub fn span(info: Info<'_>) -> Span {
+    tracing::info_span!(
+        "request",
+        method = %info.method(),
+        path = %info.path(),
+        route = Empty,
+        request_id = Empty,
+        user_id = Empty,
+        status = Empty,
+    )
+}
+
+pub fn record_user_id(user_id: &str) {
+    Span::current().record("user_id", user_id);
+}
+
+/// Records the status of the response to the request span.
+pub fn record_status(reply: impl Reply) -> Response<Body> {
+    let response = reply.into_response();
+    Span::current().record("status", response.status().as_u16());
+    response
+}
+
+fn set_request_id(response: &mut Response<Body>, id: &RequestId) {
+    if let Ok(value) = HeaderValue::from_str(id.as_str()) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+}
+
+fn into_result<F, R>(
+    filter: F,
+) -> impl Filter<Extract = (Result<Response<Body>, Rejection>,), Error = Infallible> + Clone
+where
+    F: Filter<Extract = (R,), Error = Rejection> + Clone,
+    R: Reply,
+{
+    filter
+        .map(|reply: R| Ok(reply.into_response()))
+        .recover(|rejection| async move { Ok::<_, Infallible>(Err(rejection)) })
+        .unify()
+}
+
+/// Processes the request matched to a route by `filter` with the request ID,
+/// and handles its rejection by `handle`.
+pub fn route<F, R, H, Fut, E>(
+    filter: F,
+    handle: H,
+) -> impl Filter<Extract = (Response<Body>,), Error = Infallible> + Clone
+where
+    F: Filter<Extract = (R,), Error = Rejection> + Clone + Send,
+    R: Reply,
+    H: Fn(RequestId, Rejection) -> Fut + Clone + Send,
+    Fut: Future<Output = Result<E, Infallible>> + Send,
+    E: Reply,
+{
+    warp::path::full()
+        .and(warp::header::headers_cloned())
+        .map(|path: FullPath, headers: HeaderMap| {
+            Span::current().record("route", path.as_str());
+            RequestId::from_headers(&headers)
+        })
+        .and(into_result(filter))
+        .then(move |id: RequestId, result| {
+            let handle = handle.clone();
+            async move {
+                let mut response = match result {
+                    Ok(response) => response,
+                    Err(rejection) => match handle(id.clone(), rejection).await {
+                        Ok(reply) => reply.into_response(),
+                        Err(never) => match never {},
+                    },
+                };
+                set_request_id(&mut response, &id);
+                response
+            }
+        })
+}
+
+/// Handles the rejection of `filter`, which is not matched to any route, by `handle` with the request ID.
+pub fn recover<F, R, H, Fut, E>(
+    filter: F,
+    handle: H,
+) -> impl Filter<Extract = (Response<Body>,), Error = Infallible> + Clone
+where
+    F: Filter<Extract = (R,), Error = Rejection> + Clone + Send,
+    R: Reply,
+    H: Fn(RequestId, Rejection) -> Fut + Clone + Send,
+    Fut: Future<Output = Result<E, Infallible>> + Send,
+    E: Reply,
+{
+    warp::header::headers_cloned()
+        .and(into_result(filter))
+        .then(move |headers: HeaderMap, result| {
+            let handle = handle.clone();
+            async move {
+                match result {
+                    Ok(response) => response,
+                    Err(rejection) => {
+                        let id = RequestId::from_headers(&headers);
+                        let mut response = match handle(id.clone(), rejection).await {
+                            Ok(reply) => reply.into_response(),
+                            Err(never) => match never {},
+                        };
+                        set_request_id(&mut response, &id);
+                        response
+                    }
+                }
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RequestId, MAX_REQUEST_ID_LENGTH};
+    use warp::http::{header::HeaderValue, HeaderMap};
+
+    #[test]
+    fn test_from_header() {
+        let id = RequestId::from_header(&HeaderValue::from_static("abc-123")).unwrap();
+        assert_eq!(id.as_str(), "abc-123");
+        assert!(RequestId::from_header(&HeaderValue::from_static("")).is_none());
+        assert!(RequestId::from_header(&HeaderValue::from_static("a b")).is_none());
+        let long = "a".repeat(MAX_REQUEST_ID_LENGTH + 1);
+        assert!(RequestId::from_header(&HeaderValue::from_str(&long).unwrap()).is_none());
+    }
+
+    #[test]
+    fn test_from_headers_generates() {
+        let mut headers = HeaderMap::new();
+        let generated = RequestId::from_headers(&headers);
+        assert!(uuid::Uuid::parse_str(generated.as_str()).is_ok());
+
+        headers.insert("x-request-id", HeaderValue::from_static("client-id"));
+        assert_eq!(RequestId::from_headers(&headers).as_str(), "client-id");
+    }
+}
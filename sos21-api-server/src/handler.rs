@@ -12,6 +12,9 @@ pub enum ErasedHandlerError {
         info: serde_json::Value,
     },
     NotSignedUp,
+    Suspended,
+    InvalidToken,
+    RevokedToken,
     InvalidEmailAddress,
     NotUniversityEmailAddress,
     ServiceUnavailable(anyhow::Error),
@@ -33,6 +36,9 @@ where
                 ErasedHandlerError::Client { status_code, info }
             }
             HandlerError::NotSignedUp => ErasedHandlerError::NotSignedUp,
+            HandlerError::Suspended => ErasedHandlerError::Suspended,
+            HandlerError::InvalidToken => ErasedHandlerError::InvalidToken,
+            HandlerError::RevokedToken => ErasedHandlerError::RevokedToken,
             HandlerError::InvalidEmailAddress => ErasedHandlerError::InvalidEmailAddress,
             HandlerError::NotUniversityEmailAddress => {
                 ErasedHandlerError::NotUniversityEmailAddress
@@ -70,6 +76,68 @@ where
     }
 }
 
+/// Implements [`HandlerResponse::status_codes`] alongside `status_code` from the arms of its body.
+macro_rules! handler_response {
+    (impl HandlerResponse for $ty:ident {
+        fn status_code(&self) -> StatusCode {
+            StatusCode::$code:ident
+        }
+    }) => {
+        impl HandlerResponse for $ty {
+            fn status_code(&self) -> StatusCode {
+                StatusCode::$code
+            }
+
+            fn status_codes() -> Vec<(Option<&'static str>, StatusCode)> {
+                vec![(None, StatusCode::$code)]
+            }
+        }
+    };
+    (impl HandlerResponse for $ty:ident {
+        fn status_code(&self) -> StatusCode {
+            match *self {}
+        }
+    }) => {
+        impl HandlerResponse for $ty {
+            fn status_code(&self) -> StatusCode {
+                match *self {}
+            }
+
+            fn status_codes() -> Vec<(Option<&'static str>, StatusCode)> {
+                Vec::new()
+            }
+        }
+    };
+    (impl HandlerResponse for $ty:ident {
+        fn status_code(&self) -> StatusCode {
+            match self { $($arms:tt)* }
+        }
+    }) => {
+        impl HandlerResponse for $ty {
+            fn status_code(&self) -> StatusCode {
+                match self { $($arms)* }
+            }
+
+            fn status_codes() -> Vec<(Option<&'static str>, StatusCode)> {
+                handler_response!(@arms []; $($arms)*)
+            }
+        }
+    };
+    (@arms [$($codes:tt)*];) => {
+        vec![$($codes)*]
+    };
+    (@arms [$($codes:tt)*];
+        $($ty:ident :: $variant:ident $({ .. })?)|+ => { $code:expr } $($rest:tt)*
+    ) => {
+        handler_response!(@arms [$($codes)* $((Some(stringify!($variant)), $code),)+]; $($rest)*)
+    };
+    (@arms [$($codes:tt)*];
+        $($ty:ident :: $variant:ident $({ .. })?)|+ => $code:expr $(, $($rest:tt)*)?
+    ) => {
+        handler_response!(@arms [$($codes)* $((Some(stringify!($variant)), $code),)+]; $($($rest)*)?)
+    };
+}
+
 macro_rules! raw_response_handler {
     ($vis:vis async fn $name:ident (
         $ctx:ident: Authentication<Context>
@@ -79,6 +147,7 @@ macro_rules! raw_response_handler {
             @impl_authentication $vis $name($ctx, $($param: $ty),*) -> impl warp::Reply, $err, $body;
               crate::handler::handle_raw_handler_result
         }
+        raw_response_handler!(@operation $err; $($param: $ty),*);
     };
     ($vis:vis async fn $name:ident (
         $ctx:ident: Login<Context>
@@ -88,6 +157,7 @@ macro_rules! raw_response_handler {
             @impl_login $vis $name($ctx, $($param: $ty),*) -> impl warp::Reply, $err, $body;
               crate::handler::handle_raw_handler_result
         }
+        raw_response_handler!(@operation $err; $($param: $ty),*);
     };
     ($vis:vis async fn $name:ident (
         $ctx:ident: Context
@@ -109,6 +179,42 @@ macro_rules! raw_response_handler {
 
             crate::handler::handle_raw_handler_result(run(app $(, $param)*).await)
         }
+
+        raw_response_handler!(@operation $err; $($param: $ty),*);
+    };
+    ($vis:vis async fn $name:ident (
+        $app:ident: App
+        $(, $param:ident : $ty:ty)* $(,)?
+    ) -> HandlerResult<impl warp::Reply, $err:ty> $body:block) => {
+        $vis async fn $name(
+            app: App
+            $(, $param: $ty)*
+        ) -> Result<impl ::warp::reply::Reply, ::warp::reject::Rejection> {
+            async fn run(
+                $app: App
+                $(, $param: $ty)*
+            ) -> HandlerResult<impl warp::Reply, $err> {
+                $body
+            }
+            crate::handler::handle_raw_handler_result(run(app $(, $param)*).await)
+        }
+
+        raw_response_handler!(@operation $err; $($param: $ty),*);
+    };
+    (@operation $err:ty; request : $req:ty) => {
+        raw_response_handler!(@operation $err; _request : $req);
+    };
+    (@operation $err:ty; _request : $req:ty) => {
+        /// Describes this handler in the OpenAPI document.
+        pub fn openapi_operation() -> crate::openapi::Operation {
+            crate::openapi::Operation::raw::<$err>().with_request::<$req>()
+        }
+    };
+    (@operation $err:ty; $($param:ident : $ty:ty),*) => {
+        /// Describes this handler in the OpenAPI document.
+        pub fn openapi_operation() -> crate::openapi::Operation {
+            crate::openapi::Operation::raw::<$err>()
+        }
     };
 }
 
@@ -121,6 +227,7 @@ macro_rules! handler {
             @impl_authentication $vis $name($ctx, $($param: $ty),*) -> $resp, $err, $body;
               crate::handler::handle_handler_result
         }
+        handler!(@operation $resp, $err; $($param: $ty),*);
     };
     ($vis:vis async fn $name:ident (
         $ctx:ident: Login<Context>
@@ -130,6 +237,7 @@ macro_rules! handler {
                 @impl_login $vis $name($ctx, $($param: $ty),*) -> $resp, $err, $body;
               crate::handler::handle_handler_result
         }
+        handler!(@operation $resp, $err; $($param: $ty),*);
     };
     ($vis:vis async fn $name:ident (
         $ctx:ident: Context
@@ -151,6 +259,8 @@ macro_rules! handler {
 
             crate::handler::handle_handler_result(run(app $(, $param)*).await)
         }
+
+        handler!(@operation $resp, $err; $($param: $ty),*);
     };
     ($vis:vis async fn $name:ident (
         $app:ident: App
@@ -168,6 +278,8 @@ macro_rules! handler {
             }
             crate::handler::handle_handler_result(run(app $(, $param)*).await)
         }
+
+        handler!(@operation $resp, $err; $($param: $ty),*);
     };
     ($vis:vis async fn $name:ident (
         $($param:ident : $ty:ty),* $(,)?
@@ -178,6 +290,8 @@ macro_rules! handler {
             let result: HandlerResult<$resp, $err> = $body;
             crate::handler::handle_handler_result(result)
         }
+
+        handler!(@operation $resp, $err; $($param: $ty),*);
     };
     (@impl_authentication $vis:vis $name:ident (
             $ctx:ident,
@@ -186,7 +300,16 @@ macro_rules! handler {
     ) => {
         handler! {
             @impl $vis $name (
-                (auth, ctx) $ctx = Authentication::<Context>::new(ctx, auth.user_id, auth.email)?,
+                (auth, ctx) $ctx = match auth {
+                    crate::filter::AuthenticationInfo::User { user_id, email, issued_at } => {
+                        let issued_at = ::sos21_domain::model::date_time::DateTime::from_utc(issued_at);
+                        Authentication::<Context>::new(ctx, user_id, email, issued_at).await?
+                    }
+                    // API keys act on behalf of existing users and cannot be used to sign up
+                    crate::filter::AuthenticationInfo::ApiKey { .. } => {
+                        return Err(crate::handler::HandlerError::InvalidToken);
+                    }
+                },
                 $($param: $ty),*
             ) -> $resp, $err, $body; $handle
         }
@@ -198,13 +321,21 @@ macro_rules! handler {
     ) => {
         handler! {
             @impl $vis $name (
-                (auth, ctx) $ctx = {
-                    let ctx = ::sos21_domain::context::Authentication::new(
-                        ctx,
-                        auth.user_id,
-                        auth.email
-                    )?;
-                    Login::<Context>::new(ctx).await?
+                (auth, ctx) $ctx = match auth {
+                    crate::filter::AuthenticationInfo::User { user_id, email, issued_at } => {
+                        let ctx = ::sos21_domain::context::Authentication::new(
+                            ctx,
+                            user_id,
+                            email,
+                            ::sos21_domain::model::date_time::DateTime::from_utc(issued_at),
+                        ).await?;
+                        Login::<Context>::new(ctx).await?
+                    }
+                    crate::filter::AuthenticationInfo::ApiKey { token } => {
+                        let login = Login::<Context>::with_api_key(ctx, &token).await?;
+                        crate::filter::record_user_id(&login.login_user().id().0);
+                        login
+                    }
                 },
                 $($param: $ty),*
             ) -> $resp, $err, $body; $handle
@@ -235,19 +366,41 @@ macro_rules! handler {
             $handle(run(app, auth $(, $param)*).await)
         }
     };
+    (@operation $resp:ty, $err:ty; request : $req:ty) => {
+        handler!(@operation $resp, $err; _request : $req);
+    };
+    (@operation $resp:ty, $err:ty; _request : $req:ty) => {
+        /// Describes this handler in the OpenAPI document.
+        pub fn openapi_operation() -> crate::openapi::Operation {
+            crate::openapi::Operation::new::<$resp, $err>().with_request::<$req>()
+        }
+    };
+    (@operation $resp:ty, $err:ty; $($param:ident : $ty:ty),*) => {
+        /// Describes this handler in the OpenAPI document.
+        pub fn openapi_operation() -> crate::openapi::Operation {
+            crate::openapi::Operation::new::<$resp, $err>()
+        }
+    };
 }
 
+pub mod api_key;
+pub mod custom_role;
+#[cfg(feature = "dev-auth")]
+pub mod dev;
+pub mod festival;
 pub mod file;
 pub mod file_distribution;
 pub mod file_sharing;
 pub mod form;
 pub mod form_answer;
+pub mod form_template;
 pub mod me;
 pub mod meta;
 pub mod pending_project;
 pub mod project;
 pub mod registration_form;
 pub mod registration_form_answer;
+pub mod settings;
 pub mod user;
 pub mod user_invitation;
 
@@ -256,6 +409,8 @@ pub use signup::handler as signup;
 
 pub mod invite_user;
 pub use invite_user::handler as invite_user;
+pub mod invite_users;
+pub use invite_users::handler as invite_users;
 
 pub mod assign_user_role_to_email;
 pub use assign_user_role_to_email::handler as assign_user_role_to_email;
@@ -266,12 +421,19 @@ pub trait HandlerResponse: Serialize {
     /// Server errors are returned as `anyhow::Error`, not as `HandlerResponse`.
     /// Thus, it always stands that `!x.status_code().is_server_error()`.
     fn status_code(&self) -> StatusCode;
+
+    /// Lists every status code `status_code` can return, paired with the variant name for enums.
+    /// This is implemented by `handler_response!` and used to document the endpoints.
+    fn status_codes() -> Vec<(Option<&'static str>, StatusCode)>;
 }
 
 #[derive(Debug)]
 pub enum HandlerError<E> {
     Client(E),
     NotSignedUp,
+    Suspended,
+    InvalidToken,
+    RevokedToken,
     InvalidEmailAddress,
     NotUniversityEmailAddress,
     ServiceUnavailable(anyhow::Error),
@@ -306,6 +468,7 @@ impl<E> From<sos21_domain::context::authentication::AuthenticationError> for Han
             AuthenticationError::NotUniversityEmailAddress => {
                 HandlerError::NotUniversityEmailAddress
             }
+            AuthenticationError::Internal(e) => HandlerError::Server(e),
         }
     }
 }
@@ -315,6 +478,9 @@ impl<E> From<sos21_domain::context::login::LoginError> for HandlerError<E> {
         use sos21_domain::context::login::LoginError;
         match e {
             LoginError::NotSignedUp => HandlerError::NotSignedUp,
+            LoginError::InvalidApiKey => HandlerError::InvalidToken,
+            LoginError::Suspended => HandlerError::Suspended,
+            LoginError::RevokedToken => HandlerError::RevokedToken,
             LoginError::Internal(e) => HandlerError::Server(e),
         }
     }
@@ -0,0 +1,6 @@
+pub mod list;
+pub use list::handler as list;
+pub mod create;
+pub use create::handler as create;
+pub mod revoke;
+pub use revoke::handler as revoke;
@@ -0,0 +1,77 @@
+use crate::app::Context;
+use crate::handler::model::api_key::{ApiKey, ApiKeyScope};
+use crate::handler::model::date_time::DateTime;
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::create_api_key;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    pub name: String,
+    pub scope: ApiKeyScope,
+    #[serde(default)]
+    pub expires_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub api_key: ApiKey,
+    pub token: String,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::CREATED
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    InvalidField { field: &'static str },
+    UnknownPermission { name: String },
+    InsufficientPermissions,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::InvalidField { .. } => StatusCode::BAD_REQUEST,
+            Error::UnknownPermission { .. } => StatusCode::BAD_REQUEST,
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
+impl From<create_api_key::Error> for Error {
+    fn from(err: create_api_key::Error) -> Error {
+        match err {
+            create_api_key::Error::InvalidName => Error::InvalidField { field: "name" },
+            create_api_key::Error::UnknownPermission(name) => Error::UnknownPermission { name },
+            create_api_key::Error::PastExpiry => Error::InvalidField {
+                field: "expires_at",
+            },
+            create_api_key::Error::InsufficientPermissions => Error::InsufficientPermissions,
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let input = create_api_key::Input {
+        name: request.name,
+        scope: request.scope.into_use_case(),
+        expires_at: request.expires_at.map(DateTime::into_use_case),
+    };
+    let output = create_api_key::run(&ctx, input).await?;
+    Ok(Response {
+        api_key: ApiKey::from_use_case(output.key),
+        token: output.token,
+    })
+}
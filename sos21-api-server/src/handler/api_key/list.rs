@@ -0,0 +1,54 @@
+use crate::app::Context;
+use crate::handler::model::api_key::ApiKey;
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::list_api_keys;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub api_keys: Vec<ApiKey>,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::OK
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    InsufficientPermissions,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
+impl From<list_api_keys::Error> for Error {
+    fn from(err: list_api_keys::Error) -> Error {
+        match err {
+            list_api_keys::Error::InsufficientPermissions => Error::InsufficientPermissions,
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, _request: Request) -> HandlerResult<Response, Error> {
+    let api_keys = list_api_keys::run(&ctx).await?;
+    let api_keys = api_keys.into_iter().map(ApiKey::from_use_case).collect();
+    Ok(Response { api_keys })
+}
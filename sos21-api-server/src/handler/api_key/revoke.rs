@@ -0,0 +1,63 @@
+use crate::app::Context;
+use crate::handler::model::api_key::{ApiKey, ApiKeyId};
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::revoke_api_key;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    pub api_key_id: ApiKeyId,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub api_key: ApiKey,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::OK
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    ApiKeyNotFound,
+    AlreadyRevokedApiKey,
+    InsufficientPermissions,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::ApiKeyNotFound => StatusCode::NOT_FOUND,
+            Error::AlreadyRevokedApiKey => StatusCode::CONFLICT,
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
+impl From<revoke_api_key::Error> for Error {
+    fn from(err: revoke_api_key::Error) -> Error {
+        match err {
+            revoke_api_key::Error::NotFound => Error::ApiKeyNotFound,
+            revoke_api_key::Error::AlreadyRevoked => Error::AlreadyRevokedApiKey,
+            revoke_api_key::Error::InsufficientPermissions => Error::InsufficientPermissions,
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let api_key_id = request.api_key_id.into_use_case();
+    let api_key = revoke_api_key::run(&ctx, api_key_id).await?;
+    let api_key = ApiKey::from_use_case(api_key);
+    Ok(Response { api_key })
+}
@@ -6,28 +6,32 @@ use crate::handler::{HandlerResponse, HandlerResult};
 use serde::{Deserialize, Serialize};
 use sos21_domain::context::Login;
 use sos21_use_case::assign_user_role_to_email;
+use utoipa::ToSchema;
 use warp::http::StatusCode;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, ToSchema)]
 pub struct Request {
     pub email: String,
     pub role: UserInvitationRole,
+    #[serde(default)]
+    pub allows_unlisted_domain: bool,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum Response {
     Invitation(UserInvitation),
     User(User),
 }
 
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
 impl HandlerResponse for Response {
     fn status_code(&self) -> StatusCode {
         StatusCode::OK
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
 pub enum Error {
     InvalidEmailAddress,
@@ -35,6 +39,7 @@ pub enum Error {
     InsufficientPermissions,
 }
 
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
 impl HandlerResponse for Error {
     fn status_code(&self) -> StatusCode {
         match self {
@@ -64,6 +69,7 @@ pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Res
     let input = assign_user_role_to_email::Input {
         email: request.email,
         role: request.role.into_use_case(),
+        allows_unlisted_domain: request.allows_unlisted_domain,
     };
     let output = assign_user_role_to_email::run(&ctx, input).await?;
     let response = match output {
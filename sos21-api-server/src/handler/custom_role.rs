@@ -0,0 +1,8 @@
+pub mod list;
+pub use list::handler as list;
+pub mod create;
+pub use create::handler as create;
+pub mod update;
+pub use update::handler as update;
+pub mod delete;
+pub use delete::handler as delete;
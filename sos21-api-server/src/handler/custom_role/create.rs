@@ -0,0 +1,67 @@
+use crate::app::Context;
+use crate::handler::model::custom_role::CustomRole;
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::create_custom_role;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    pub name: String,
+    pub permissions: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub custom_role: CustomRole,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::CREATED
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    InvalidField { field: &'static str },
+    UnknownPermission { name: String },
+    InsufficientPermissions,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::InvalidField { .. } => StatusCode::BAD_REQUEST,
+            Error::UnknownPermission { .. } => StatusCode::BAD_REQUEST,
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
+impl From<create_custom_role::Error> for Error {
+    fn from(err: create_custom_role::Error) -> Error {
+        match err {
+            create_custom_role::Error::InvalidName => Error::InvalidField { field: "name" },
+            create_custom_role::Error::UnknownPermission(name) => Error::UnknownPermission { name },
+            create_custom_role::Error::InsufficientPermissions => Error::InsufficientPermissions,
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let input = create_custom_role::Input {
+        name: request.name,
+        permissions: request.permissions,
+    };
+    let custom_role = create_custom_role::run(&ctx, input).await?;
+    let custom_role = CustomRole::from_use_case(custom_role);
+    Ok(Response { custom_role })
+}
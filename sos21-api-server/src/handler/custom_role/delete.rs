@@ -0,0 +1,56 @@
+use crate::app::Context;
+use crate::handler::model::custom_role::CustomRoleId;
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::delete_custom_role;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    pub id: CustomRoleId,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::NO_CONTENT
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    CustomRoleNotFound,
+    InsufficientPermissions,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::CustomRoleNotFound => StatusCode::NOT_FOUND,
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
+impl From<delete_custom_role::Error> for Error {
+    fn from(err: delete_custom_role::Error) -> Error {
+        match err {
+            delete_custom_role::Error::NotFound => Error::CustomRoleNotFound,
+            delete_custom_role::Error::InsufficientPermissions => Error::InsufficientPermissions,
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    delete_custom_role::run(&ctx, request.id.into_use_case()).await?;
+    Ok(Response {})
+}
@@ -0,0 +1,65 @@
+use crate::app::Context;
+use crate::handler::model::custom_role::{BuiltinRole, CustomRole};
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::list_custom_roles;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub builtin_roles: Vec<BuiltinRole>,
+    pub custom_roles: Vec<CustomRole>,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::OK
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    InsufficientPermissions,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
+impl From<list_custom_roles::Error> for Error {
+    fn from(err: list_custom_roles::Error) -> Error {
+        match err {
+            list_custom_roles::Error::InsufficientPermissions => Error::InsufficientPermissions,
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, _request: Request) -> HandlerResult<Response, Error> {
+    let output = list_custom_roles::run(&ctx).await?;
+    Ok(Response {
+        builtin_roles: output
+            .builtin_roles
+            .into_iter()
+            .map(BuiltinRole::from_use_case)
+            .collect(),
+        custom_roles: output
+            .custom_roles
+            .into_iter()
+            .map(CustomRole::from_use_case)
+            .collect(),
+    })
+}
@@ -0,0 +1,2 @@
+pub mod issue_token;
+pub use issue_token::handler as issue_token;
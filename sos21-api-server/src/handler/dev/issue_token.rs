@@ -0,0 +1,64 @@
+use crate::app::App;
+use crate::handler::{HandlerError, HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+fn default_email_verified() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    pub email: String,
+    #[serde(default = "default_email_verified")]
+    pub email_verified: bool,
+    /// Derived from `email` if absent, so that the same user is signed in with the same email.
+    #[serde(default)]
+    pub user_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub token: String,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::OK
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    DevAuthenticationDisabled,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::DevAuthenticationDisabled => StatusCode::NOT_FOUND,
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(app: App, request: Request) -> HandlerResult<Response, Error> {
+    let issuer = app
+        .dev_issuer()
+        .ok_or(HandlerError::Client(Error::DevAuthenticationDisabled))?;
+
+    let Request {
+        email,
+        email_verified,
+        user_id,
+    } = request;
+    let user_id = user_id.unwrap_or_else(|| format!("dev:{}", email));
+    let token = issuer.issue(&user_id, &email, email_verified)?;
+
+    Ok(Response { token })
+}
@@ -0,0 +1,6 @@
+pub mod create;
+pub use create::handler as create;
+pub mod get;
+pub use get::handler as get;
+pub mod list;
+pub use list::handler as list;
@@ -0,0 +1,56 @@
+use crate::app::Context;
+use crate::handler::model::festival::{Festival, FestivalId};
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::get_festival;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    pub festival_id: FestivalId,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub festival: Festival,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::OK
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    FestivalNotFound,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::FestivalNotFound => StatusCode::NOT_FOUND,
+        }
+    }
+}
+
+impl From<get_festival::Error> for Error {
+    fn from(err: get_festival::Error) -> Error {
+        match err {
+            get_festival::Error::NotFound => Error::FestivalNotFound,
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let festival = get_festival::run(&ctx, request.festival_id.into_use_case()).await?;
+    let festival = Festival::from_use_case(festival);
+    Ok(Response { festival })
+}
@@ -0,0 +1,50 @@
+use std::convert::Infallible;
+
+use crate::app::Context;
+use crate::handler::model::festival::Festival;
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::list_festivals;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub festivals: Vec<Festival>,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::OK
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match *self {}
+    }
+}
+
+impl From<Infallible> for Error {
+    fn from(x: Infallible) -> Error {
+        match x {}
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, _request: Request) -> HandlerResult<Response, Error> {
+    let festivals = list_festivals::run(&ctx).await?;
+    let festivals = festivals.into_iter().map(Festival::from_use_case).collect();
+    Ok(Response { festivals })
+}
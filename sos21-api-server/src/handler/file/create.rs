@@ -19,26 +19,28 @@ use sos21_domain::context::Login;
 use sos21_use_case::create_file;
 use sos21_use_case::model::stream::ByteStream;
 use tokio::sync::Notify;
+use utoipa::ToSchema;
 use warp::http::StatusCode;
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct Response {
     pub files: Vec<ResponseFile>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct ResponseFile {
     pub name: String,
     pub file: File,
 }
 
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
 impl HandlerResponse for Response {
     fn status_code(&self) -> StatusCode {
         StatusCode::CREATED
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
 pub enum Error {
     InvalidFileName,
@@ -49,6 +51,7 @@ pub enum Error {
     InvalidContentTypeInPart,
 }
 
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
 impl HandlerResponse for Error {
     fn status_code(&self) -> StatusCode {
         match self {
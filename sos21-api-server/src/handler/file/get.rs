@@ -5,19 +5,21 @@ use crate::handler::{HandlerResponse, HandlerResult};
 use serde::{Deserialize, Serialize};
 use sos21_domain::context::Login;
 use sos21_use_case::get_file_object;
+use utoipa::ToSchema;
 use warp::{http::StatusCode, reply};
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, ToSchema)]
 pub struct Request {
     pub file_id: FileId,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
 pub enum Error {
     FileNotFound,
 }
 
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
 impl HandlerResponse for Error {
     fn status_code(&self) -> StatusCode {
         match self {
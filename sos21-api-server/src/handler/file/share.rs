@@ -8,16 +8,17 @@ use crate::handler::{HandlerResponse, HandlerResult};
 use serde::{Deserialize, Serialize};
 use sos21_domain::context::Login;
 use sos21_use_case::share_file;
+use utoipa::ToSchema;
 use warp::http::StatusCode;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, ToSchema)]
 pub struct Request {
     pub file_id: FileId,
     pub expires_at: Option<DateTime>,
     pub scope: RequestFileSharingScope,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case", tag = "type")]
 pub enum RequestFileSharingScope {
     ProjectQuery { query: ProjectQuery },
@@ -41,18 +42,19 @@ impl RequestFileSharingScope {
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct Response {
     pub sharing: FileSharing,
 }
 
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
 impl HandlerResponse for Response {
     fn status_code(&self) -> StatusCode {
         StatusCode::CREATED
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
 pub enum Error {
     InvalidProjectQuery,
@@ -62,6 +64,7 @@ pub enum Error {
     InvalidFileExpirationDate,
 }
 
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
 impl HandlerResponse for Error {
     fn status_code(&self) -> StatusCode {
         match self {
@@ -1,32 +1,39 @@
 use crate::app::Context;
+use crate::handler::model::festival::FestivalId;
 use crate::handler::model::file_distribution::FileDistribution;
 use crate::handler::{HandlerResponse, HandlerResult};
 
 use serde::{Deserialize, Serialize};
 use sos21_domain::context::Login;
 use sos21_use_case::list_all_file_distributions;
+use utoipa::ToSchema;
 use warp::http::StatusCode;
 
-#[derive(Debug, Clone, Deserialize)]
-pub struct Request {}
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    #[serde(default)]
+    pub festival_id: Option<FestivalId>,
+}
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct Response {
     pub distributions: Vec<FileDistribution>,
 }
 
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
 impl HandlerResponse for Response {
     fn status_code(&self) -> StatusCode {
         StatusCode::OK
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
 pub enum Error {
     InsufficientPermissions,
 }
 
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
 impl HandlerResponse for Error {
     fn status_code(&self) -> StatusCode {
         match self {
@@ -46,8 +53,10 @@ impl From<list_all_file_distributions::Error> for Error {
 }
 
 #[macro_rules_attribute::macro_rules_attribute(handler!)]
-pub async fn handler(ctx: Login<Context>, _request: Request) -> HandlerResult<Response, Error> {
-    let distributions = list_all_file_distributions::run(&ctx).await?;
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let distributions =
+        list_all_file_distributions::run(&ctx, request.festival_id.map(FestivalId::into_use_case))
+            .await?;
     let distributions = distributions
         .into_iter()
         .map(FileDistribution::from_use_case)
@@ -1,9 +1,18 @@
+pub mod access_control;
 pub mod answer;
+pub mod definition;
+pub mod revision;
 
 pub mod create;
 pub use create::handler as create;
+pub mod create_from_template;
+pub use create_from_template::handler as create_from_template;
+pub mod duplicate;
+pub use duplicate::handler as duplicate;
 pub mod update;
 pub use update::handler as update;
+pub mod validate_answer;
+pub use validate_answer::handler as validate_answer;
 pub mod get;
 pub use get::handler as get;
 pub mod list;
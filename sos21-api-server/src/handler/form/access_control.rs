@@ -0,0 +1,4 @@
+pub mod get;
+pub use get::handler as get;
+pub mod update;
+pub use update::handler as update;
@@ -0,0 +1,65 @@
+use crate::app::Context;
+use crate::handler::model::access_control::AccessControlEntry;
+use crate::handler::model::form::FormId;
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::get_form_access_control;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    pub form_id: FormId,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub entries: Vec<AccessControlEntry>,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::OK
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    FormNotFound,
+    InsufficientPermissions,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::FormNotFound => StatusCode::NOT_FOUND,
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
+impl From<get_form_access_control::Error> for Error {
+    fn from(err: get_form_access_control::Error) -> Error {
+        match err {
+            get_form_access_control::Error::NotFound => Error::FormNotFound,
+            get_form_access_control::Error::InsufficientPermissions => {
+                Error::InsufficientPermissions
+            }
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let entries = get_form_access_control::run(&ctx, request.form_id.into_use_case()).await?;
+    let entries = entries
+        .into_iter()
+        .map(AccessControlEntry::from_use_case)
+        .collect();
+    Ok(Response { entries })
+}
@@ -2,3 +2,5 @@ pub mod list;
 pub use list::handler as list;
 pub mod export;
 pub use export::handler as export;
+pub mod migrate;
+pub use migrate::handler as migrate;
@@ -6,9 +6,10 @@ use serde::{Deserialize, Serialize};
 use sos21_domain::context::Login;
 use sos21_use_case::export_form_answers;
 use uritemplate::UriTemplate;
+use utoipa::ToSchema;
 use warp::http::StatusCode;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, ToSchema)]
 pub struct Request {
     pub form_id: FormId,
     #[serde(default)]
@@ -19,16 +20,36 @@ pub struct Request {
     pub field_project_id: Option<String>,
     #[serde(default)]
     pub field_author_id: Option<String>,
+    #[serde(default)]
+    pub group_layout: GroupLayout,
     pub file_answer_template: String,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Copy, Default, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum GroupLayout {
+    #[default]
+    Columns,
+    Rows,
+}
+
+impl GroupLayout {
+    fn into_use_case(self) -> export_form_answers::InputGroupLayout {
+        match self {
+            GroupLayout::Columns => export_form_answers::InputGroupLayout::Columns,
+            GroupLayout::Rows => export_form_answers::InputGroupLayout::Rows,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
 pub enum Error {
     FormNotFound,
     InsufficientPermissions,
 }
 
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
 impl HandlerResponse for Error {
     fn status_code(&self) -> StatusCode {
         match self {
@@ -59,6 +80,7 @@ pub async fn handler(
             field_created_at,
             field_project_id,
             field_author_id,
+            group_layout,
             file_answer_template,
         } = request;
 
@@ -79,6 +101,7 @@ pub async fn handler(
         export_form_answers::Input {
             form_id: form_id.into_use_case(),
             field_names,
+            group_layout: group_layout.into_use_case(),
             render_file_answer,
         }
     };
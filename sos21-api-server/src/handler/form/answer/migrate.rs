@@ -0,0 +1,76 @@
+use crate::app::Context;
+use crate::handler::model::form::{FormId, FormItemId};
+use crate::handler::model::form_answer::FormAnswer;
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::migrate_form_answers;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    pub form_id: FormId,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct FormAnswerMigration {
+    pub answer: FormAnswer,
+    pub flagged_item_ids: Vec<FormItemId>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub migrations: Vec<FormAnswerMigration>,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::OK
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    FormNotFound,
+    InsufficientPermissions,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::FormNotFound => StatusCode::NOT_FOUND,
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
+impl From<migrate_form_answers::Error> for Error {
+    fn from(err: migrate_form_answers::Error) -> Error {
+        match err {
+            migrate_form_answers::Error::FormNotFound => Error::FormNotFound,
+            migrate_form_answers::Error::InsufficientPermissions => Error::InsufficientPermissions,
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let migrations = migrate_form_answers::run(&ctx, request.form_id.into_use_case()).await?;
+    let migrations = migrations
+        .into_iter()
+        .map(|migration| FormAnswerMigration {
+            answer: FormAnswer::from_use_case(migration.answer),
+            flagged_item_ids: migration
+                .flagged_item_ids
+                .into_iter()
+                .map(FormItemId::from_use_case)
+                .collect(),
+        })
+        .collect();
+    Ok(Response { migrations })
+}
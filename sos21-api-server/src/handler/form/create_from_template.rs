@@ -0,0 +1,84 @@
+use crate::app::Context;
+use crate::handler::model::date_time::DateTime;
+use crate::handler::model::form::Form;
+use crate::handler::model::form_template::FormTemplateId;
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::create_form_from_template;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    pub form_template_id: FormTemplateId,
+    #[serde(default)]
+    pub name: Option<String>,
+    pub starts_at: DateTime,
+    pub ends_at: DateTime,
+    pub answer_notification_webhook: Option<String>, // TODO: Type this property with appropriate URL type with validation
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub form: Form,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::CREATED
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    FormTemplateNotFound,
+    InvalidField { field: &'static str },
+    InvalidFormPeriod,
+    TooEarlyFormPeriodStart,
+    InsufficientPermissions,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::FormTemplateNotFound => StatusCode::NOT_FOUND,
+            Error::InvalidField { .. } => StatusCode::BAD_REQUEST,
+            Error::InvalidFormPeriod => StatusCode::BAD_REQUEST,
+            Error::TooEarlyFormPeriodStart => StatusCode::CONFLICT,
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
+impl From<create_form_from_template::Error> for Error {
+    fn from(err: create_form_from_template::Error) -> Error {
+        match err {
+            create_form_from_template::Error::TemplateNotFound => Error::FormTemplateNotFound,
+            create_form_from_template::Error::InvalidName => Error::InvalidField { field: "name" },
+            create_form_from_template::Error::InvalidPeriod => Error::InvalidFormPeriod,
+            create_form_from_template::Error::TooEarlyPeriodStart => Error::TooEarlyFormPeriodStart,
+            create_form_from_template::Error::InsufficientPermissions => {
+                Error::InsufficientPermissions
+            }
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let input = create_form_from_template::Input {
+        template_id: request.form_template_id.into_use_case(),
+        name: request.name,
+        starts_at: request.starts_at.into_use_case(),
+        ends_at: request.ends_at.into_use_case(),
+        answer_notification_webhook: request.answer_notification_webhook,
+    };
+    let form = create_form_from_template::run(&ctx, input).await?;
+    let form = Form::from_use_case(form);
+    Ok(Response { form })
+}
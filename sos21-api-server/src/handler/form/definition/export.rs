@@ -0,0 +1,62 @@
+use crate::app::Context;
+use crate::handler::model::form::FormId;
+use crate::handler::model::form_definition::{FormDefinition, FormDefinitionFormat};
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::get_form;
+use sos21_use_case::model::form_definition::FormDefinition as UseCaseFormDefinition;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    pub form_id: FormId,
+    #[serde(default)]
+    pub format: FormDefinitionFormat,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    FormNotFound,
+    InsufficientPermissions,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::FormNotFound => StatusCode::NOT_FOUND,
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
+impl From<get_form::Error> for Error {
+    fn from(err: get_form::Error) -> Error {
+        match err {
+            get_form::Error::NotFound => Error::FormNotFound,
+            get_form::Error::InsufficientPermissions => Error::InsufficientPermissions,
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(raw_response_handler!)]
+pub async fn handler(
+    ctx: Login<Context>,
+    request: Request,
+) -> HandlerResult<impl warp::Reply, Error> {
+    let form = get_form::run(&ctx, request.form_id.into_use_case()).await?;
+    let definition = FormDefinition::from_use_case(UseCaseFormDefinition::from_form(form));
+    let text = request.format.render(&definition)?;
+    Ok(warp::reply::with_status(
+        warp::reply::with_header(
+            text,
+            warp::http::header::CONTENT_TYPE,
+            request.format.content_type(),
+        ),
+        StatusCode::OK,
+    ))
+}
@@ -0,0 +1,76 @@
+use crate::app::Context;
+use crate::handler::model::form::Form;
+use crate::handler::model::form_definition::{FormDefinitionError, FormDefinitionFormat};
+use crate::handler::{HandlerError, HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::import_form_definition;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    #[serde(default)]
+    pub format: FormDefinitionFormat,
+    pub definition: String,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub form: Form,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::CREATED
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    InvalidDefinition { errors: Vec<FormDefinitionError> },
+    TooEarlyFormPeriodStart,
+    InsufficientPermissions,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::InvalidDefinition { .. } => StatusCode::BAD_REQUEST,
+            Error::TooEarlyFormPeriodStart => StatusCode::CONFLICT,
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
+impl From<import_form_definition::Error> for Error {
+    fn from(err: import_form_definition::Error) -> Error {
+        match err {
+            import_form_definition::Error::InvalidDefinition(errors) => Error::InvalidDefinition {
+                errors: errors
+                    .into_iter()
+                    .map(FormDefinitionError::from_use_case)
+                    .collect(),
+            },
+            import_form_definition::Error::TooEarlyPeriodStart => Error::TooEarlyFormPeriodStart,
+            import_form_definition::Error::InsufficientPermissions => {
+                Error::InsufficientPermissions
+            }
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let definition = request
+        .format
+        .parse(&request.definition)
+        .map_err(|err| HandlerError::Client(Error::InvalidDefinition { errors: vec![err] }))?;
+    let form = import_form_definition::run(&ctx, definition.into_use_case()).await?;
+    let form = Form::from_use_case(form);
+    Ok(Response { form })
+}
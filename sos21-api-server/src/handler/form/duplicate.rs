@@ -0,0 +1,79 @@
+use crate::app::Context;
+use crate::handler::model::date_time::DateTime;
+use crate::handler::model::form::{Form, FormId};
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::duplicate_form;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    pub form_id: FormId,
+    #[serde(default)]
+    pub name: Option<String>,
+    pub starts_at: DateTime,
+    pub ends_at: DateTime,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub form: Form,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::CREATED
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    FormNotFound,
+    InvalidField { field: &'static str },
+    InvalidFormPeriod,
+    TooEarlyFormPeriodStart,
+    InsufficientPermissions,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::FormNotFound => StatusCode::NOT_FOUND,
+            Error::InvalidField { .. } => StatusCode::BAD_REQUEST,
+            Error::InvalidFormPeriod => StatusCode::BAD_REQUEST,
+            Error::TooEarlyFormPeriodStart => StatusCode::CONFLICT,
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
+impl From<duplicate_form::Error> for Error {
+    fn from(err: duplicate_form::Error) -> Error {
+        match err {
+            duplicate_form::Error::NotFound => Error::FormNotFound,
+            duplicate_form::Error::InvalidName => Error::InvalidField { field: "name" },
+            duplicate_form::Error::InvalidPeriod => Error::InvalidFormPeriod,
+            duplicate_form::Error::TooEarlyPeriodStart => Error::TooEarlyFormPeriodStart,
+            duplicate_form::Error::InsufficientPermissions => Error::InsufficientPermissions,
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let input = duplicate_form::Input {
+        form_id: request.form_id.into_use_case(),
+        name: request.name,
+        starts_at: request.starts_at.into_use_case(),
+        ends_at: request.ends_at.into_use_case(),
+    };
+    let form = duplicate_form::run(&ctx, input).await?;
+    let form = Form::from_use_case(form);
+    Ok(Response { form })
+}
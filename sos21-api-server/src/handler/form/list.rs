@@ -1,32 +1,39 @@
 use crate::app::Context;
+use crate::handler::model::festival::FestivalId;
 use crate::handler::model::form::Form;
 use crate::handler::{HandlerResponse, HandlerResult};
 
 use serde::{Deserialize, Serialize};
 use sos21_domain::context::Login;
 use sos21_use_case::list_all_forms;
+use utoipa::ToSchema;
 use warp::http::StatusCode;
 
-#[derive(Debug, Clone, Deserialize)]
-pub struct Request {}
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    #[serde(default)]
+    pub festival_id: Option<FestivalId>,
+}
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct Response {
     pub forms: Vec<Form>,
 }
 
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
 impl HandlerResponse for Response {
     fn status_code(&self) -> StatusCode {
         StatusCode::OK
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
 pub enum Error {
     InsufficientPermissions,
 }
 
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
 impl HandlerResponse for Error {
     fn status_code(&self) -> StatusCode {
         match self {
@@ -44,8 +51,9 @@ impl From<list_all_forms::Error> for Error {
 }
 
 #[macro_rules_attribute::macro_rules_attribute(handler!)]
-pub async fn handler(ctx: Login<Context>, _request: Request) -> HandlerResult<Response, Error> {
-    let forms = list_all_forms::run(&ctx).await?;
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let forms =
+        list_all_forms::run(&ctx, request.festival_id.map(FestivalId::into_use_case)).await?;
     let forms = forms.into_iter().map(Form::from_use_case).collect();
     Ok(Response { forms })
 }
@@ -0,0 +1,4 @@
+pub mod list;
+pub use list::handler as list;
+pub mod diff;
+pub use diff::handler as diff;
@@ -0,0 +1,68 @@
+use crate::app::Context;
+use crate::handler::model::form::{FormId, FormRevisionDiff};
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::get_form_revision_diff;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    pub form_id: FormId,
+    pub from_version: u32,
+    pub to_version: u32,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub diff: FormRevisionDiff,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::OK
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    FormNotFound,
+    FormRevisionNotFound { version: u32 },
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::FormNotFound => StatusCode::NOT_FOUND,
+            Error::FormRevisionNotFound { .. } => StatusCode::NOT_FOUND,
+        }
+    }
+}
+
+impl From<get_form_revision_diff::Error> for Error {
+    fn from(err: get_form_revision_diff::Error) -> Error {
+        match err {
+            get_form_revision_diff::Error::NotFound => Error::FormNotFound,
+            get_form_revision_diff::Error::RevisionNotFound(version) => {
+                Error::FormRevisionNotFound { version }
+            }
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let input = get_form_revision_diff::Input {
+        form_id: request.form_id.into_use_case(),
+        from_version: request.from_version,
+        to_version: request.to_version,
+    };
+    let diff = get_form_revision_diff::run(&ctx, input).await?;
+    let diff = FormRevisionDiff::from_use_case(diff);
+    Ok(Response { diff })
+}
@@ -0,0 +1,59 @@
+use crate::app::Context;
+use crate::handler::model::form::{FormId, FormRevision};
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::list_form_revisions;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    pub form_id: FormId,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub revisions: Vec<FormRevision>,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::OK
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    FormNotFound,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::FormNotFound => StatusCode::NOT_FOUND,
+        }
+    }
+}
+
+impl From<list_form_revisions::Error> for Error {
+    fn from(err: list_form_revisions::Error) -> Error {
+        match err {
+            list_form_revisions::Error::NotFound => Error::FormNotFound,
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let revisions = list_form_revisions::run(&ctx, request.form_id.into_use_case()).await?;
+    let revisions = revisions
+        .into_iter()
+        .map(FormRevision::from_use_case)
+        .collect();
+    Ok(Response { revisions })
+}
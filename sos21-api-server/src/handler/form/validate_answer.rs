@@ -0,0 +1,174 @@
+use crate::app::Context;
+use crate::handler::model::form::{FormItem, FormItemId};
+use crate::handler::model::form_answer::item::{CheckAnswerItemError, RequestFormAnswerItem};
+use crate::handler::model::project::{ProjectAttribute, ProjectCategory};
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::{interface, validate_form_answer};
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    pub items: Vec<FormItem>,
+    pub category: ProjectCategory,
+    #[serde(default)]
+    pub attributes: Vec<ProjectAttribute>,
+    pub answer: Vec<RequestFormAnswerItem>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AnswerItemCheck {
+    pub item_id: FormItemId,
+    pub is_hidden: bool,
+    pub error: Option<CheckAnswerItemError>,
+}
+
+impl AnswerItemCheck {
+    fn from_use_case(check: validate_form_answer::AnswerItemCheck) -> Self {
+        AnswerItemCheck {
+            item_id: FormItemId::from_use_case(check.item_id),
+            is_hidden: check.is_hidden,
+            error: check.error.map(CheckAnswerItemError::from_use_case),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub items: Vec<AnswerItemCheck>,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::OK
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    InvalidField {
+        field: &'static str,
+    },
+    InvalidFormItem {
+        id: FormItemId,
+    },
+    DuplicatedFormItemId {
+        id: FormItemId,
+    },
+    DuplicatedProjectAttributes,
+    InvalidFormAnswerItem {
+        id: FormItemId,
+    },
+    MismatchedFormItemsLength,
+    MismatchedFormItemId {
+        expected: FormItemId,
+        got: FormItemId,
+    },
+    InsufficientPermissions,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::InvalidField { .. } => StatusCode::BAD_REQUEST,
+            Error::InvalidFormItem { .. } => StatusCode::BAD_REQUEST,
+            Error::DuplicatedFormItemId { .. } => StatusCode::BAD_REQUEST,
+            Error::DuplicatedProjectAttributes => StatusCode::BAD_REQUEST,
+            Error::InvalidFormAnswerItem { .. } => StatusCode::BAD_REQUEST,
+            Error::MismatchedFormItemsLength => StatusCode::BAD_REQUEST,
+            Error::MismatchedFormItemId { .. } => StatusCode::BAD_REQUEST,
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
+impl From<validate_form_answer::Error> for Error {
+    fn from(err: validate_form_answer::Error) -> Error {
+        match err {
+            validate_form_answer::Error::InvalidFormItems(err) => match err {
+                interface::form::FormItemsError::NoItems => Error::InvalidField { field: "items" },
+                interface::form::FormItemsError::TooManyItems => {
+                    Error::InvalidField { field: "items" }
+                }
+                interface::form::FormItemsError::InvalidItem(id, _) => Error::InvalidFormItem {
+                    id: FormItemId::from_use_case(id),
+                },
+                interface::form::FormItemsError::DuplicatedItemId(id) => {
+                    Error::DuplicatedFormItemId {
+                        id: FormItemId::from_use_case(id),
+                    }
+                }
+            },
+            validate_form_answer::Error::InvalidAnswerItems(err) => match err {
+                interface::form_answer::FormAnswerItemsError::NoItems => {
+                    Error::InvalidField { field: "answer" }
+                }
+                interface::form_answer::FormAnswerItemsError::TooManyItems => {
+                    Error::InvalidField { field: "answer" }
+                }
+                interface::form_answer::FormAnswerItemsError::InvalidItem(id, _) => {
+                    Error::InvalidFormAnswerItem {
+                        id: FormItemId::from_use_case(id),
+                    }
+                }
+            },
+            validate_form_answer::Error::InvalidAnswer(err) => match err {
+                interface::form::CheckAnswerError::MismatchedItemsLength => {
+                    Error::MismatchedFormItemsLength
+                }
+                interface::form::CheckAnswerError::MismatchedItemId { expected, got } => {
+                    Error::MismatchedFormItemId {
+                        expected: FormItemId::from_use_case(expected),
+                        got: FormItemId::from_use_case(got),
+                    }
+                }
+                // not returned in validation, which reports the answers to each item instead
+                interface::form::CheckAnswerError::InvalidAnswerItem { item_id, .. } => {
+                    Error::InvalidFormAnswerItem {
+                        id: FormItemId::from_use_case(item_id),
+                    }
+                }
+            },
+            validate_form_answer::Error::DuplicatedProjectAttributes => {
+                Error::DuplicatedProjectAttributes
+            }
+            validate_form_answer::Error::InsufficientPermissions => Error::InsufficientPermissions,
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let input = validate_form_answer::Input {
+        items: request
+            .items
+            .into_iter()
+            .map(FormItem::into_use_case)
+            .collect(),
+        respondent: validate_form_answer::InputRespondent {
+            category: request.category.into_use_case(),
+            attributes: request
+                .attributes
+                .into_iter()
+                .map(ProjectAttribute::into_use_case)
+                .collect(),
+        },
+        answer: request
+            .answer
+            .into_iter()
+            .map(RequestFormAnswerItem::into_use_case)
+            .collect(),
+    };
+    let checks = validate_form_answer::run(&ctx, input).await?;
+    let items = checks
+        .into_iter()
+        .map(AnswerItemCheck::from_use_case)
+        .collect();
+    Ok(Response { items })
+}
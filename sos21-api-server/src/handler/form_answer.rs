@@ -2,3 +2,6 @@ pub mod file_sharing;
 
 pub mod get;
 pub use get::handler as get;
+
+pub mod review;
+pub use review::handler as review;
@@ -0,0 +1,105 @@
+use crate::app::Context;
+use crate::handler::model::form::item::FormItemId;
+use crate::handler::model::form_answer::{FormAnswer, FormAnswerId, FormAnswerReviewStatus};
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::review_form_answer;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct RequestComment {
+    pub item_id: FormItemId,
+    pub body: String,
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    pub answer_id: FormAnswerId,
+    pub status: FormAnswerReviewStatus,
+    pub comments: Vec<RequestComment>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub answer: FormAnswer,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::OK
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    FormAnswerNotFound,
+    InsufficientPermissions,
+    InvalidReviewStatus,
+    InvalidReviewComment { id: FormItemId },
+    TooManyReviewComments,
+    DuplicatedReviewCommentItemId { id: FormItemId },
+    UnknownReviewCommentItemId { id: FormItemId },
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::FormAnswerNotFound => StatusCode::NOT_FOUND,
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
+            Error::InvalidReviewStatus
+            | Error::InvalidReviewComment { .. }
+            | Error::TooManyReviewComments
+            | Error::DuplicatedReviewCommentItemId { .. }
+            | Error::UnknownReviewCommentItemId { .. } => StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
+impl From<review_form_answer::Error> for Error {
+    fn from(err: review_form_answer::Error) -> Error {
+        match err {
+            review_form_answer::Error::FormAnswerNotFound => Error::FormAnswerNotFound,
+            review_form_answer::Error::InsufficientPermissions => Error::InsufficientPermissions,
+            review_form_answer::Error::InvalidStatus => Error::InvalidReviewStatus,
+            review_form_answer::Error::InvalidComment(id) => Error::InvalidReviewComment {
+                id: FormItemId::from_use_case(id),
+            },
+            review_form_answer::Error::TooManyComments => Error::TooManyReviewComments,
+            review_form_answer::Error::DuplicatedCommentItemId(id) => {
+                Error::DuplicatedReviewCommentItemId {
+                    id: FormItemId::from_use_case(id),
+                }
+            }
+            review_form_answer::Error::UnknownCommentItemId(id) => {
+                Error::UnknownReviewCommentItemId {
+                    id: FormItemId::from_use_case(id),
+                }
+            }
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let input = review_form_answer::Input {
+        answer_id: request.answer_id.into_use_case(),
+        status: request.status.into_use_case(),
+        comments: request
+            .comments
+            .into_iter()
+            .map(|comment| review_form_answer::InputComment {
+                item_id: comment.item_id.into_use_case(),
+                body: comment.body,
+            })
+            .collect(),
+    };
+    let answer = review_form_answer::run(&ctx, input).await?;
+    let answer = FormAnswer::from_use_case(answer);
+    Ok(Response { answer })
+}
@@ -0,0 +1,97 @@
+use crate::app::Context;
+use crate::handler::model::form::{FormItem, FormItemId};
+use crate::handler::model::form_template::FormTemplate;
+use crate::handler::model::project_query::ProjectQuery;
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::{create_form_template, interface};
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    pub name: String,
+    pub description: String,
+    pub items: Vec<FormItem>,
+    pub query: ProjectQuery,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub form_template: FormTemplate,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::CREATED
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    InvalidField { field: &'static str },
+    InvalidFormItem { id: FormItemId },
+    DuplicatedFormItemId { id: FormItemId },
+    InsufficientPermissions,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::InvalidField { .. } => StatusCode::BAD_REQUEST,
+            Error::InvalidFormItem { .. } => StatusCode::BAD_REQUEST,
+            Error::DuplicatedFormItemId { .. } => StatusCode::BAD_REQUEST,
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
+impl From<create_form_template::Error> for Error {
+    fn from(err: create_form_template::Error) -> Error {
+        match err {
+            create_form_template::Error::InvalidName => Error::InvalidField { field: "name" },
+            create_form_template::Error::InvalidDescription => Error::InvalidField {
+                field: "description",
+            },
+            create_form_template::Error::InvalidItems(err) => match err {
+                interface::form::FormItemsError::NoItems => Error::InvalidField { field: "items" },
+                interface::form::FormItemsError::TooManyItems => {
+                    Error::InvalidField { field: "items" }
+                }
+                // TODO: break down invalid item errors
+                interface::form::FormItemsError::InvalidItem(id, _) => Error::InvalidFormItem {
+                    id: FormItemId::from_use_case(id),
+                },
+                interface::form::FormItemsError::DuplicatedItemId(id) => {
+                    Error::DuplicatedFormItemId {
+                        id: FormItemId::from_use_case(id),
+                    }
+                }
+            },
+            create_form_template::Error::InvalidQuery(_) => Error::InvalidField { field: "query" },
+            create_form_template::Error::InsufficientPermissions => Error::InsufficientPermissions,
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let input = create_form_template::Input {
+        name: request.name,
+        description: request.description,
+        items: request
+            .items
+            .into_iter()
+            .map(FormItem::into_use_case)
+            .collect(),
+        query: request.query.into_use_case(),
+    };
+    let form_template = create_form_template::run(&ctx, input).await?;
+    let form_template = FormTemplate::from_use_case(form_template);
+    Ok(Response { form_template })
+}
@@ -1,39 +1,48 @@
 use crate::app::Context;
+use crate::handler::model::date_time::DateTime;
 use crate::handler::model::user_invitation::{UserInvitation, UserInvitationRole};
 use crate::handler::{HandlerResponse, HandlerResult};
 
 use serde::{Deserialize, Serialize};
 use sos21_domain::context::Login;
 use sos21_use_case::invite_user;
+use utoipa::ToSchema;
 use warp::http::StatusCode;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, ToSchema)]
 pub struct Request {
     pub email: String,
     pub role: UserInvitationRole,
+    #[serde(default)]
+    pub allows_unlisted_domain: bool,
+    #[serde(default)]
+    pub expires_at: Option<DateTime>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct Response {
     pub invitation: UserInvitation,
 }
 
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
 impl HandlerResponse for Response {
     fn status_code(&self) -> StatusCode {
         StatusCode::CREATED
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
 pub enum Error {
     InvalidEmailAddress,
     NotUniversityEmailAddress,
     AlreadyInvitedEmailAddress,
     AlreadySignedUpEmailAddress,
+    PastExpiry,
     InsufficientPermissions,
 }
 
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
 impl HandlerResponse for Error {
     fn status_code(&self) -> StatusCode {
         match self {
@@ -41,6 +50,7 @@ impl HandlerResponse for Error {
             Error::NotUniversityEmailAddress => StatusCode::BAD_REQUEST,
             Error::AlreadyInvitedEmailAddress => StatusCode::CONFLICT,
             Error::AlreadySignedUpEmailAddress => StatusCode::CONFLICT,
+            Error::PastExpiry => StatusCode::BAD_REQUEST,
             Error::InsufficientPermissions => StatusCode::FORBIDDEN,
         }
     }
@@ -53,6 +63,7 @@ impl From<invite_user::Error> for Error {
             invite_user::Error::NotUniversityEmailAddress => Error::NotUniversityEmailAddress,
             invite_user::Error::AlreadyInvitedEmailAddress => Error::AlreadyInvitedEmailAddress,
             invite_user::Error::AlreadySignedUpEmailAddress => Error::AlreadySignedUpEmailAddress,
+            invite_user::Error::PastExpiry => Error::PastExpiry,
             invite_user::Error::InsufficientPermissions => Error::InsufficientPermissions,
         }
     }
@@ -63,6 +74,8 @@ pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Res
     let input = invite_user::Input {
         email: request.email,
         role: request.role.into_use_case(),
+        allows_unlisted_domain: request.allows_unlisted_domain,
+        expires_at: request.expires_at.map(DateTime::into_use_case),
     };
     let invitation = invite_user::run(&ctx, input).await?;
     let invitation = UserInvitation::from_use_case(invitation);
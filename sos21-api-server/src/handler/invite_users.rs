@@ -0,0 +1,168 @@
+use crate::app::Context;
+use crate::handler::model::date_time::DateTime;
+use crate::handler::model::user::User;
+use crate::handler::model::user_invitation::{UserInvitation, UserInvitationRole};
+use crate::handler::{HandlerError, HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::invite_users;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    /// CSV with the header row, which has `email` and `role` columns.
+    pub csv: String,
+    #[serde(default)]
+    pub allows_unlisted_domain: bool,
+    #[serde(default)]
+    pub updates_signed_up_users: bool,
+    #[serde(default)]
+    pub expires_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct CsvRow {
+    email: String,
+    role: UserInvitationRole,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RowResult {
+    pub line: u64,
+    #[serde(flatten)]
+    pub outcome: RowOutcome,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum RowOutcome {
+    Invited { invitation: UserInvitation },
+    RoleUpdated { user: User },
+    AlreadyInvited,
+    AlreadySignedUp,
+    InvalidEmailAddress,
+    NotUniversityEmailAddress,
+    InvalidRow { message: String },
+}
+
+impl RowOutcome {
+    fn from_use_case(outcome: invite_users::RowOutcome) -> Self {
+        match outcome {
+            invite_users::RowOutcome::Invited(invitation) => RowOutcome::Invited {
+                invitation: UserInvitation::from_use_case(invitation),
+            },
+            invite_users::RowOutcome::RoleUpdated(user) => RowOutcome::RoleUpdated {
+                user: User::from_use_case(user),
+            },
+            invite_users::RowOutcome::AlreadyInvited => RowOutcome::AlreadyInvited,
+            invite_users::RowOutcome::AlreadySignedUp => RowOutcome::AlreadySignedUp,
+            invite_users::RowOutcome::InvalidEmailAddress => RowOutcome::InvalidEmailAddress,
+            invite_users::RowOutcome::NotUniversityEmailAddress => {
+                RowOutcome::NotUniversityEmailAddress
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub rows: Vec<RowResult>,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::OK
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    InvalidCsv,
+    PastExpiry,
+    InsufficientPermissions,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::InvalidCsv => StatusCode::BAD_REQUEST,
+            Error::PastExpiry => StatusCode::BAD_REQUEST,
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
+impl From<invite_users::Error> for Error {
+    fn from(err: invite_users::Error) -> Error {
+        match err {
+            invite_users::Error::PastExpiry => Error::PastExpiry,
+            invite_users::Error::InsufficientPermissions => Error::InsufficientPermissions,
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let mut reader = csv::Reader::from_reader(request.csv.as_bytes());
+    let headers = reader
+        .headers()
+        .map_err(|_| HandlerError::Client(Error::InvalidCsv))?
+        .clone();
+
+    // rows which failed to be read are reported in place, and the others are passed to the use case
+    let mut lines = Vec::new();
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let result = record.and_then(|record| {
+            let line = record.position().map(|position| position.line());
+            record
+                .deserialize::<CsvRow>(Some(&headers))
+                .map(|row| (line, row))
+        });
+        match result {
+            Ok((line, row)) => {
+                lines.push((line.unwrap_or_default(), None));
+                rows.push(invite_users::InputRow {
+                    email: row.email,
+                    role: row.role.into_use_case(),
+                });
+            }
+            Err(err) => {
+                let line = err.position().map(|position| position.line());
+                let outcome = RowOutcome::InvalidRow {
+                    message: err.to_string(),
+                };
+                lines.push((line.unwrap_or_default(), Some(outcome)));
+            }
+        }
+    }
+
+    let input = invite_users::Input {
+        rows,
+        allows_unlisted_domain: request.allows_unlisted_domain,
+        updates_signed_up_users: request.updates_signed_up_users,
+        expires_at: request.expires_at.map(DateTime::into_use_case),
+    };
+    let mut outcomes = invite_users::run(&ctx, input)
+        .await?
+        .into_iter()
+        .map(RowOutcome::from_use_case);
+
+    let mut rows = Vec::new();
+    for (line, outcome) in lines {
+        let outcome = match outcome {
+            Some(outcome) => outcome,
+            None => outcomes.next().ok_or_else(|| {
+                anyhow::anyhow!("invite_users returned fewer outcomes than the rows")
+            })?,
+        };
+        rows.push(RowResult { line, outcome });
+    }
+
+    Ok(Response { rows })
+}
@@ -1,3 +1,7 @@
 pub mod get_build_info;
 pub mod health;
 pub use get_build_info::handler as get_build_info;
+pub mod get_metrics;
+pub use get_metrics::handler as get_metrics;
+pub mod get_openapi;
+pub use get_openapi::handler as get_openapi;
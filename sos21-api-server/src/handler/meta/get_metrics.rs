@@ -0,0 +1,42 @@
+use crate::app::App;
+use crate::handler::{HandlerError, HandlerResponse, HandlerResult};
+
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match *self {}
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(raw_response_handler!)]
+pub async fn handler(app: App, _request: Request) -> HandlerResult<impl warp::Reply, Error> {
+    let metrics = match app.render_metrics() {
+        Some(metrics) => metrics,
+        None => {
+            return Err(HandlerError::ServiceUnavailable(anyhow!(
+                "The metrics recorder is not installed"
+            )))
+        }
+    };
+
+    Ok(warp::reply::with_status(
+        warp::reply::with_header(
+            metrics,
+            warp::http::header::CONTENT_TYPE,
+            "text/plain; version=0.0.4",
+        ),
+        StatusCode::OK,
+    ))
+}
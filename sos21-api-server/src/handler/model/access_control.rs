@@ -0,0 +1,85 @@
+use crate::handler::model::custom_role::CustomRoleId;
+use crate::handler::model::user::UserId;
+
+use serde::{Deserialize, Serialize};
+use sos21_use_case::model::access_control as use_case;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum AccessControlGrantee {
+    User { id: UserId },
+    CustomRole { id: CustomRoleId },
+}
+
+impl AccessControlGrantee {
+    pub fn from_use_case(grantee: use_case::AccessControlGrantee) -> Self {
+        match grantee {
+            use_case::AccessControlGrantee::User(id) => AccessControlGrantee::User {
+                id: UserId::from_use_case(id),
+            },
+            use_case::AccessControlGrantee::CustomRole(id) => AccessControlGrantee::CustomRole {
+                id: CustomRoleId::from_use_case(id),
+            },
+        }
+    }
+
+    pub fn into_use_case(self) -> use_case::AccessControlGrantee {
+        match self {
+            AccessControlGrantee::User { id } => {
+                use_case::AccessControlGrantee::User(id.into_use_case())
+            }
+            AccessControlGrantee::CustomRole { id } => {
+                use_case::AccessControlGrantee::CustomRole(id.into_use_case())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AccessControlLevel {
+    Read,
+    Review,
+    Update,
+}
+
+impl AccessControlLevel {
+    pub fn from_use_case(level: use_case::AccessControlLevel) -> Self {
+        match level {
+            use_case::AccessControlLevel::Read => AccessControlLevel::Read,
+            use_case::AccessControlLevel::Review => AccessControlLevel::Review,
+            use_case::AccessControlLevel::Update => AccessControlLevel::Update,
+        }
+    }
+
+    pub fn into_use_case(self) -> use_case::AccessControlLevel {
+        match self {
+            AccessControlLevel::Read => use_case::AccessControlLevel::Read,
+            AccessControlLevel::Review => use_case::AccessControlLevel::Review,
+            AccessControlLevel::Update => use_case::AccessControlLevel::Update,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AccessControlEntry {
+    pub grantee: AccessControlGrantee,
+    pub level: AccessControlLevel,
+}
+
+impl AccessControlEntry {
+    pub fn from_use_case(entry: use_case::AccessControlEntry) -> Self {
+        AccessControlEntry {
+            grantee: AccessControlGrantee::from_use_case(entry.grantee),
+            level: AccessControlLevel::from_use_case(entry.level),
+        }
+    }
+
+    pub fn into_use_case(self) -> use_case::AccessControlEntry {
+        use_case::AccessControlEntry {
+            grantee: self.grantee.into_use_case(),
+            level: self.level.into_use_case(),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use crate::handler::model::date_time::DateTime;
+use crate::handler::model::user::{UserId, UserRole};
+
+use serde::{Deserialize, Serialize};
+use sos21_use_case::model::api_key as use_case;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(transparent)]
+#[schema(value_type = String, format = "uuid")]
+pub struct ApiKeyId(pub Uuid);
+
+impl ApiKeyId {
+    pub fn from_use_case(id: use_case::ApiKeyId) -> Self {
+        ApiKeyId(id.0)
+    }
+
+    pub fn into_use_case(self) -> use_case::ApiKeyId {
+        use_case::ApiKeyId(self.0)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum ApiKeyScope {
+    Role { role: UserRole },
+    Permissions { permissions: Vec<String> },
+}
+
+impl ApiKeyScope {
+    pub fn from_use_case(scope: use_case::ApiKeyScope) -> Self {
+        match scope {
+            use_case::ApiKeyScope::Role(role) => ApiKeyScope::Role {
+                role: UserRole::from_use_case(role),
+            },
+            use_case::ApiKeyScope::Permissions(permissions) => {
+                ApiKeyScope::Permissions { permissions }
+            }
+        }
+    }
+
+    pub fn into_use_case(self) -> use_case::ApiKeyScope {
+        match self {
+            ApiKeyScope::Role { role } => use_case::ApiKeyScope::Role(role.into_use_case()),
+            ApiKeyScope::Permissions { permissions } => {
+                use_case::ApiKeyScope::Permissions(permissions)
+            }
+        }
+    }
+}
+
+/// A key acting on behalf of its author with the permissions in both the author's roles and
+/// the scope. It is not given the accesses based on the ownership or membership of the author,
+/// nor the ones granted by access control lists.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiKey {
+    pub id: ApiKeyId,
+    pub created_at: DateTime,
+    pub author_id: UserId,
+    pub name: String,
+    pub scope: ApiKeyScope,
+    pub expires_at: Option<DateTime>,
+    /// Updated at most once a minute.
+    pub last_used_at: Option<DateTime>,
+    pub revoked_at: Option<DateTime>,
+}
+
+impl ApiKey {
+    pub fn from_use_case(key: use_case::ApiKey) -> Self {
+        ApiKey {
+            id: ApiKeyId::from_use_case(key.id),
+            created_at: DateTime::from_use_case(key.created_at),
+            author_id: UserId::from_use_case(key.author_id),
+            name: key.name,
+            scope: ApiKeyScope::from_use_case(key.scope),
+            expires_at: key.expires_at.map(DateTime::from_use_case),
+            last_used_at: key.last_used_at.map(DateTime::from_use_case),
+            revoked_at: key.revoked_at.map(DateTime::from_use_case),
+        }
+    }
+}
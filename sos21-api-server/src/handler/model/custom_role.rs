@@ -0,0 +1,58 @@
+use crate::handler::model::date_time::DateTime;
+use crate::handler::model::user::{UserId, UserRole};
+
+use serde::{Deserialize, Serialize};
+use sos21_use_case::model::custom_role as use_case;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(transparent)]
+#[schema(value_type = String, format = "uuid")]
+pub struct CustomRoleId(pub Uuid);
+
+impl CustomRoleId {
+    pub fn from_use_case(id: use_case::CustomRoleId) -> Self {
+        CustomRoleId(id.0)
+    }
+
+    pub fn into_use_case(self) -> use_case::CustomRoleId {
+        use_case::CustomRoleId(self.0)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CustomRole {
+    pub id: CustomRoleId,
+    pub created_at: DateTime,
+    pub author_id: UserId,
+    pub name: String,
+    pub permissions: Vec<String>,
+}
+
+impl CustomRole {
+    pub fn from_use_case(role: use_case::CustomRole) -> Self {
+        CustomRole {
+            id: CustomRoleId::from_use_case(role.id),
+            created_at: DateTime::from_use_case(role.created_at),
+            author_id: UserId::from_use_case(role.author_id),
+            name: role.name,
+            permissions: role.permissions,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BuiltinRole {
+    pub role: UserRole,
+    pub permissions: Vec<String>,
+}
+
+impl BuiltinRole {
+    pub fn from_use_case(role: use_case::BuiltinRole) -> Self {
+        BuiltinRole {
+            role: UserRole::from_use_case(role.role),
+            permissions: role.permissions,
+        }
+    }
+}
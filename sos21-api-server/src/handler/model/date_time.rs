@@ -1,8 +1,10 @@
 use chrono::{serde::ts_milliseconds, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
 #[serde(transparent)]
+#[schema(value_type = i64)]
 pub struct DateTime(#[serde(with = "ts_milliseconds")] pub chrono::DateTime<Utc>);
 
 impl DateTime {
@@ -5,8 +5,9 @@ use crate::handler::model::project::ProjectId;
 
 use serde::{Deserialize, Serialize};
 use sos21_use_case::model::file_distribution as use_case;
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DistributedFile {
     pub distribution_id: FileDistributionId,
     pub distributed_at: DateTime,
@@ -0,0 +1,43 @@
+use crate::handler::model::date_time::DateTime;
+use crate::handler::model::user::UserId;
+
+use serde::{Deserialize, Serialize};
+use sos21_use_case::model::festival as use_case;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(transparent)]
+#[schema(value_type = String, format = "uuid")]
+pub struct FestivalId(pub Uuid);
+
+impl FestivalId {
+    pub fn from_use_case(id: use_case::FestivalId) -> Self {
+        FestivalId(id.0)
+    }
+
+    pub fn into_use_case(self) -> use_case::FestivalId {
+        use_case::FestivalId(self.0)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct Festival {
+    pub id: FestivalId,
+    pub created_at: DateTime,
+    pub author_id: Option<UserId>,
+    pub name: String,
+    pub is_active: bool,
+}
+
+impl Festival {
+    pub fn from_use_case(festival: use_case::Festival) -> Self {
+        Festival {
+            id: FestivalId::from_use_case(festival.id),
+            created_at: DateTime::from_use_case(festival.created_at),
+            author_id: festival.author_id.map(UserId::from_use_case),
+            name: festival.name,
+            is_active: festival.is_active,
+        }
+    }
+}
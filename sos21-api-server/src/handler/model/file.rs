@@ -8,10 +8,12 @@ use futures::stream::{BoxStream, TryStreamExt};
 use mime::Mime;
 use serde::{Deserialize, Serialize};
 use sos21_use_case::model::file as use_case;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(transparent)]
+#[schema(value_type = String, format = "uuid")]
 pub struct FileId(pub Uuid);
 
 impl FileId {
@@ -24,15 +26,17 @@ impl FileId {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct File {
     pub id: FileId,
     pub created_at: DateTime,
     pub author_id: UserId,
     pub name: Option<String>,
     #[serde(with = "crate::handler::model::serde::mime", rename = "type")]
+    #[schema(value_type = String)]
     pub type_: Mime,
     #[serde(with = "hex::serde")]
+    #[schema(value_type = String)]
     pub blake3_digest: [u8; 32],
     pub size: u64,
 }
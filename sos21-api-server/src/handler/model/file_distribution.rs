@@ -1,14 +1,17 @@
 use crate::handler::model::date_time::DateTime;
+use crate::handler::model::festival::FestivalId;
 use crate::handler::model::file_sharing::FileSharingId;
 use crate::handler::model::project::ProjectId;
 use crate::handler::model::user::UserId;
 
 use serde::{Deserialize, Serialize};
 use sos21_use_case::model::file_distribution as use_case;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(transparent)]
+#[schema(value_type = String, format = "uuid")]
 pub struct FileDistributionId(pub Uuid);
 
 impl FileDistributionId {
@@ -21,7 +24,7 @@ impl FileDistributionId {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct FileDistributionFileMapping {
     pub project_id: ProjectId,
     pub sharing_id: FileSharingId,
@@ -36,9 +39,10 @@ impl FileDistributionFileMapping {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct FileDistribution {
     pub id: FileDistributionId,
+    pub festival_id: FestivalId,
     pub created_at: DateTime,
     pub author_id: UserId,
     pub name: String,
@@ -50,6 +54,7 @@ impl FileDistribution {
     pub fn from_use_case(distribution: use_case::FileDistribution) -> Self {
         FileDistribution {
             id: FileDistributionId::from_use_case(distribution.id),
+            festival_id: FestivalId::from_use_case(distribution.festival_id),
             created_at: DateTime::from_use_case(distribution.created_at),
             author_id: UserId::from_use_case(distribution.author_id),
             name: distribution.name,
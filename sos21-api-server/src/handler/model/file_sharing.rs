@@ -9,10 +9,12 @@ use crate::handler::model::registration_form_answer::RegistrationFormAnswerRespo
 use mime::Mime;
 use serde::{Deserialize, Serialize};
 use sos21_use_case::model::file_sharing as use_case;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(transparent)]
+#[schema(value_type = String, format = "uuid")]
 pub struct FileSharingId(pub Uuid);
 
 impl FileSharingId {
@@ -25,7 +27,7 @@ impl FileSharingId {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case", tag = "type")]
 pub enum FileSharingScope {
     Project {
@@ -77,7 +79,7 @@ impl FileSharingScope {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct FileSharing {
     pub id: FileSharingId,
     pub created_at: DateTime,
@@ -87,6 +89,7 @@ pub struct FileSharing {
     pub file_id: FileId,
     pub file_name: Option<String>,
     #[serde(with = "crate::handler::model::serde::mime")]
+    #[schema(value_type = String)]
     pub file_type: Mime,
     pub file_size: u64,
 }
@@ -1,17 +1,20 @@
 use crate::handler::model::date_time::DateTime;
+use crate::handler::model::festival::FestivalId;
 use crate::handler::model::project::ProjectId;
 use crate::handler::model::project_query::ProjectQuery;
 use crate::handler::model::user::UserId;
 
 use serde::{Deserialize, Serialize};
 use sos21_use_case::model::form as use_case;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 pub mod item;
 pub use item::{FormItem, FormItemId};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(transparent)]
+#[schema(value_type = String, format = "uuid")]
 pub struct FormId(pub Uuid);
 
 impl FormId {
@@ -24,7 +27,7 @@ impl FormId {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct FormCondition {
     pub query: ProjectQuery,
     pub includes: Vec<ProjectId>,
@@ -69,9 +72,11 @@ impl FormCondition {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct Form {
     pub id: FormId,
+    pub festival_id: FestivalId,
+    pub version: u32,
     pub created_at: DateTime,
     pub author_id: UserId,
     pub name: String,
@@ -92,6 +97,8 @@ impl Form {
             .collect();
         Form {
             id: FormId::from_use_case(form.id),
+            festival_id: FestivalId::from_use_case(form.festival_id),
+            version: form.version,
             created_at: DateTime::from_use_case(form.created_at),
             author_id: UserId::from_use_case(form.author_id),
             name: form.name,
@@ -104,3 +111,71 @@ impl Form {
         }
     }
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FormRevision {
+    pub form_id: FormId,
+    pub version: u32,
+    pub created_at: DateTime,
+    pub name: String,
+    pub description: String,
+    pub starts_at: DateTime,
+    pub ends_at: DateTime,
+    pub items: Vec<FormItem>,
+    pub condition: Option<FormCondition>,
+}
+
+impl FormRevision {
+    pub fn from_use_case(revision: use_case::FormRevision) -> Self {
+        FormRevision {
+            form_id: FormId::from_use_case(revision.form_id),
+            version: revision.version,
+            created_at: DateTime::from_use_case(revision.created_at),
+            name: revision.name,
+            description: revision.description,
+            starts_at: DateTime::from_use_case(revision.starts_at),
+            ends_at: DateTime::from_use_case(revision.ends_at),
+            items: revision
+                .items
+                .into_iter()
+                .map(FormItem::from_use_case)
+                .collect(),
+            condition: revision.condition.map(FormCondition::from_use_case),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FormRevisionDiff {
+    pub name_changed: bool,
+    pub description_changed: bool,
+    pub period_changed: bool,
+    pub added_item_ids: Vec<FormItemId>,
+    pub removed_item_ids: Vec<FormItemId>,
+    pub modified_item_ids: Vec<FormItemId>,
+}
+
+impl FormRevisionDiff {
+    pub fn from_use_case(diff: use_case::FormRevisionDiff) -> Self {
+        FormRevisionDiff {
+            name_changed: diff.name_changed,
+            description_changed: diff.description_changed,
+            period_changed: diff.period_changed,
+            added_item_ids: diff
+                .added_item_ids
+                .into_iter()
+                .map(FormItemId::from_use_case)
+                .collect(),
+            removed_item_ids: diff
+                .removed_item_ids
+                .into_iter()
+                .map(FormItemId::from_use_case)
+                .collect(),
+            modified_item_ids: diff
+                .modified_item_ids
+                .into_iter()
+                .map(FormItemId::from_use_case)
+                .collect(),
+        }
+    }
+}
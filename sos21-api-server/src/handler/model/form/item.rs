@@ -1,6 +1,7 @@
 use mime::Mime;
 use serde::{Deserialize, Serialize};
 use sos21_use_case::model::form::item as use_case;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 mod checkbox;
@@ -8,14 +9,15 @@ pub use checkbox::{Checkbox, CheckboxId};
 mod radio;
 pub use radio::{Radio, RadioId};
 mod condition;
-pub use condition::FormItemCondition;
+pub use condition::{FormItemCondition, IntegerConditionOperator};
 mod grid_radio;
 pub use grid_radio::{
     GridRadioColumn, GridRadioColumnId, GridRadioRequired, GridRadioRow, GridRadioRowId,
 };
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(transparent)]
+#[schema(value_type = String, format = "uuid")]
 pub struct FormItemId(pub Uuid);
 
 impl FormItemId {
@@ -28,7 +30,7 @@ impl FormItemId {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case", tag = "type")]
 pub enum FormItemBody {
     Text {
@@ -65,10 +67,17 @@ pub enum FormItemBody {
             with = "crate::handler::model::serde::mime_vec_option",
             rename = "accepted_types"
         )]
+        #[schema(value_type = Option<Vec<String>>)]
         types: Option<Vec<Mime>>,
         accept_multiple_files: bool,
         is_required: bool,
     },
+    Group {
+        #[schema(no_recursion)]
+        items: Vec<FormItem>,
+        min_rows: Option<u64>,
+        max_rows: Option<u64>,
+    },
 }
 
 impl FormItemBody {
@@ -139,6 +148,15 @@ impl FormItemBody {
                 accept_multiple_files,
                 is_required,
             },
+            use_case::FormItemBody::Group {
+                items,
+                min_rows,
+                max_rows,
+            } => FormItemBody::Group {
+                items: items.into_iter().map(FormItem::from_use_case).collect(),
+                min_rows,
+                max_rows,
+            },
         }
     }
 
@@ -209,11 +227,20 @@ impl FormItemBody {
                 accept_multiple_files,
                 is_required,
             },
+            FormItemBody::Group {
+                items,
+                min_rows,
+                max_rows,
+            } => use_case::FormItemBody::Group {
+                items: items.into_iter().map(FormItem::into_use_case).collect(),
+                min_rows,
+                max_rows,
+            },
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct FormItem {
     pub id: FormItemId,
     pub name: String,
@@ -1,9 +1,11 @@
 use serde::{Deserialize, Serialize};
 use sos21_use_case::model::form::item as use_case;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(transparent)]
+#[schema(value_type = String, format = "uuid")]
 pub struct CheckboxId(pub Uuid);
 
 impl CheckboxId {
@@ -16,7 +18,7 @@ impl CheckboxId {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct Checkbox {
     pub id: CheckboxId,
     pub label: String,
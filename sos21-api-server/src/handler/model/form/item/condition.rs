@@ -1,9 +1,11 @@
 use serde::{Deserialize, Serialize};
 use sos21_use_case::model::form::item as use_case;
+use utoipa::ToSchema;
 
 use super::{CheckboxId, FormItemId, GridRadioColumnId, RadioId};
+use crate::handler::model::project::{ProjectAttribute, ProjectCategory};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case", tag = "type")]
 pub enum FormItemCondition {
     Checkbox {
@@ -19,6 +21,59 @@ pub enum FormItemCondition {
         item_id: FormItemId,
         column_id: GridRadioColumnId,
     },
+    Integer {
+        item_id: FormItemId,
+        operator: IntegerConditionOperator,
+        value: u64,
+    },
+    TextNonEmpty {
+        item_id: FormItemId,
+    },
+    ProjectCategory {
+        category: ProjectCategory,
+    },
+    ProjectAttribute {
+        attribute: ProjectAttribute,
+    },
+    Not {
+        #[schema(no_recursion)]
+        condition: Box<FormItemCondition>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum IntegerConditionOperator {
+    Eq,
+    Ne,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+impl IntegerConditionOperator {
+    pub fn from_use_case(operator: use_case::IntegerConditionOperator) -> Self {
+        match operator {
+            use_case::IntegerConditionOperator::Eq => IntegerConditionOperator::Eq,
+            use_case::IntegerConditionOperator::Ne => IntegerConditionOperator::Ne,
+            use_case::IntegerConditionOperator::Gt => IntegerConditionOperator::Gt,
+            use_case::IntegerConditionOperator::Ge => IntegerConditionOperator::Ge,
+            use_case::IntegerConditionOperator::Lt => IntegerConditionOperator::Lt,
+            use_case::IntegerConditionOperator::Le => IntegerConditionOperator::Le,
+        }
+    }
+
+    pub fn into_use_case(self) -> use_case::IntegerConditionOperator {
+        match self {
+            IntegerConditionOperator::Eq => use_case::IntegerConditionOperator::Eq,
+            IntegerConditionOperator::Ne => use_case::IntegerConditionOperator::Ne,
+            IntegerConditionOperator::Gt => use_case::IntegerConditionOperator::Gt,
+            IntegerConditionOperator::Ge => use_case::IntegerConditionOperator::Ge,
+            IntegerConditionOperator::Lt => use_case::IntegerConditionOperator::Lt,
+            IntegerConditionOperator::Le => use_case::IntegerConditionOperator::Le,
+        }
+    }
 }
 
 impl FormItemCondition {
@@ -45,6 +100,33 @@ impl FormItemCondition {
                     column_id: GridRadioColumnId::from_use_case(column_id),
                 }
             }
+            use_case::FormItemCondition::Integer {
+                item_id,
+                operator,
+                value,
+            } => FormItemCondition::Integer {
+                item_id: FormItemId::from_use_case(item_id),
+                operator: IntegerConditionOperator::from_use_case(operator),
+                value,
+            },
+            use_case::FormItemCondition::TextNonEmpty { item_id } => {
+                FormItemCondition::TextNonEmpty {
+                    item_id: FormItemId::from_use_case(item_id),
+                }
+            }
+            use_case::FormItemCondition::ProjectCategory { category } => {
+                FormItemCondition::ProjectCategory {
+                    category: ProjectCategory::from_use_case(category),
+                }
+            }
+            use_case::FormItemCondition::ProjectAttribute { attribute } => {
+                FormItemCondition::ProjectAttribute {
+                    attribute: ProjectAttribute::from_use_case(attribute),
+                }
+            }
+            use_case::FormItemCondition::Not { condition } => FormItemCondition::Not {
+                condition: Box::new(FormItemCondition::from_use_case(*condition)),
+            },
         }
     }
 
@@ -71,6 +153,33 @@ impl FormItemCondition {
                     column_id: column_id.into_use_case(),
                 }
             }
+            FormItemCondition::Integer {
+                item_id,
+                operator,
+                value,
+            } => use_case::FormItemCondition::Integer {
+                item_id: item_id.into_use_case(),
+                operator: operator.into_use_case(),
+                value,
+            },
+            FormItemCondition::TextNonEmpty { item_id } => {
+                use_case::FormItemCondition::TextNonEmpty {
+                    item_id: item_id.into_use_case(),
+                }
+            }
+            FormItemCondition::ProjectCategory { category } => {
+                use_case::FormItemCondition::ProjectCategory {
+                    category: category.into_use_case(),
+                }
+            }
+            FormItemCondition::ProjectAttribute { attribute } => {
+                use_case::FormItemCondition::ProjectAttribute {
+                    attribute: attribute.into_use_case(),
+                }
+            }
+            FormItemCondition::Not { condition } => use_case::FormItemCondition::Not {
+                condition: Box::new(condition.into_use_case()),
+            },
         }
     }
 }
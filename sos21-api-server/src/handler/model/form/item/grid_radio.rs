@@ -1,9 +1,11 @@
 use serde::{Deserialize, Serialize};
 use sos21_use_case::model::form::item as use_case;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(transparent)]
+#[schema(value_type = String, format = "uuid")]
 pub struct GridRadioRowId(pub Uuid);
 
 impl GridRadioRowId {
@@ -16,7 +18,7 @@ impl GridRadioRowId {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct GridRadioRow {
     pub id: GridRadioRowId,
     pub label: String,
@@ -38,8 +40,9 @@ impl GridRadioRow {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(transparent)]
+#[schema(value_type = String, format = "uuid")]
 pub struct GridRadioColumnId(pub Uuid);
 
 impl GridRadioColumnId {
@@ -52,7 +55,7 @@ impl GridRadioColumnId {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct GridRadioColumn {
     pub id: GridRadioColumnId,
     pub label: String,
@@ -74,7 +77,7 @@ impl GridRadioColumn {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum GridRadioRequired {
     All,
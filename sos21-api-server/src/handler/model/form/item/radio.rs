@@ -1,9 +1,11 @@
 use serde::{Deserialize, Serialize};
 use sos21_use_case::model::form::item as use_case;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(transparent)]
+#[schema(value_type = String, format = "uuid")]
 pub struct RadioId(pub Uuid);
 
 impl RadioId {
@@ -16,7 +18,7 @@ impl RadioId {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct Radio {
     pub id: RadioId,
     pub label: String,
@@ -1,17 +1,19 @@
 use crate::handler::model::date_time::DateTime;
-use crate::handler::model::form::FormId;
+use crate::handler::model::form::{FormId, FormItemId};
 use crate::handler::model::project::ProjectId;
 use crate::handler::model::user::UserId;
 
 use serde::{Deserialize, Serialize};
 use sos21_use_case::model::form_answer as use_case;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 pub mod item;
 pub use item::FormAnswerItem;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(transparent)]
+#[schema(value_type = String, format = "uuid")]
 pub struct FormAnswerId(pub Uuid);
 
 impl FormAnswerId {
@@ -24,14 +26,64 @@ impl FormAnswerId {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FormAnswerReviewStatus {
+    Submitted,
+    UnderReview,
+    NeedsChanges,
+    Approved,
+}
+
+impl FormAnswerReviewStatus {
+    pub fn from_use_case(status: use_case::FormAnswerReviewStatus) -> Self {
+        match status {
+            use_case::FormAnswerReviewStatus::Submitted => FormAnswerReviewStatus::Submitted,
+            use_case::FormAnswerReviewStatus::UnderReview => FormAnswerReviewStatus::UnderReview,
+            use_case::FormAnswerReviewStatus::NeedsChanges => FormAnswerReviewStatus::NeedsChanges,
+            use_case::FormAnswerReviewStatus::Approved => FormAnswerReviewStatus::Approved,
+        }
+    }
+
+    pub fn into_use_case(self) -> use_case::FormAnswerReviewStatus {
+        match self {
+            FormAnswerReviewStatus::Submitted => use_case::FormAnswerReviewStatus::Submitted,
+            FormAnswerReviewStatus::UnderReview => use_case::FormAnswerReviewStatus::UnderReview,
+            FormAnswerReviewStatus::NeedsChanges => use_case::FormAnswerReviewStatus::NeedsChanges,
+            FormAnswerReviewStatus::Approved => use_case::FormAnswerReviewStatus::Approved,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FormAnswerReviewComment {
+    pub item_id: FormItemId,
+    pub body: String,
+}
+
+impl FormAnswerReviewComment {
+    pub fn from_use_case(comment: use_case::FormAnswerReviewComment) -> Self {
+        FormAnswerReviewComment {
+            item_id: FormItemId::from_use_case(comment.item_id),
+            body: comment.body,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct FormAnswer {
     pub id: FormAnswerId,
     pub project_id: ProjectId,
     pub form_id: FormId,
+    pub form_version: u32,
     pub created_at: DateTime,
     pub author_id: UserId,
     pub items: Vec<FormAnswerItem>,
+    pub needs_resubmission: bool,
+    pub review_status: FormAnswerReviewStatus,
+    pub review_comments: Vec<FormAnswerReviewComment>,
+    pub reviewer_id: Option<UserId>,
+    pub reviewed_at: Option<DateTime>,
 }
 
 impl FormAnswer {
@@ -40,8 +92,18 @@ impl FormAnswer {
             id: FormAnswerId::from_use_case(answer.id),
             project_id: ProjectId::from_use_case(answer.project_id),
             form_id: FormId::from_use_case(answer.form_id),
+            form_version: answer.form_version,
             created_at: DateTime::from_use_case(answer.created_at),
             author_id: UserId::from_use_case(answer.author_id),
+            needs_resubmission: answer.needs_resubmission,
+            review_status: FormAnswerReviewStatus::from_use_case(answer.review_status),
+            review_comments: answer
+                .review_comments
+                .into_iter()
+                .map(FormAnswerReviewComment::from_use_case)
+                .collect(),
+            reviewer_id: answer.reviewer_id.map(UserId::from_use_case),
+            reviewed_at: answer.reviewed_at.map(DateTime::from_use_case),
             items: answer
                 .items
                 .into_iter()
@@ -50,3 +112,28 @@ impl FormAnswer {
         }
     }
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FormAnswerDraft {
+    pub project_id: ProjectId,
+    pub form_id: FormId,
+    pub updated_at: DateTime,
+    pub author_id: UserId,
+    pub items: Vec<FormAnswerItem>,
+}
+
+impl FormAnswerDraft {
+    pub fn from_use_case(draft: use_case::FormAnswerDraft) -> Self {
+        FormAnswerDraft {
+            project_id: ProjectId::from_use_case(draft.project_id),
+            form_id: FormId::from_use_case(draft.form_id),
+            updated_at: DateTime::from_use_case(draft.updated_at),
+            author_id: UserId::from_use_case(draft.author_id),
+            items: draft
+                .items
+                .into_iter()
+                .map(FormAnswerItem::from_use_case)
+                .collect(),
+        }
+    }
+}
@@ -7,8 +7,9 @@ use crate::handler::model::form::item::{
 use serde::{Deserialize, Serialize};
 use sos21_use_case::interface;
 use sos21_use_case::model::form_answer::item as use_case;
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct GridRadioRowAnswer {
     pub row_id: GridRadioRowId,
     pub value: Option<GridRadioColumnId>,
@@ -30,7 +31,7 @@ impl GridRadioRowAnswer {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case", tag = "type", content = "answer")]
 pub enum FormAnswerItemBody {
     Text(Option<String>),
@@ -39,6 +40,8 @@ pub enum FormAnswerItemBody {
     Radio(Option<RadioId>),
     GridRadio(Vec<GridRadioRowAnswer>),
     File(Vec<FileSharingId>),
+    #[schema(no_recursion)]
+    Group(Vec<Vec<FormAnswerItem>>),
 }
 
 impl FormAnswerItemBody {
@@ -64,6 +67,12 @@ impl FormAnswerItemBody {
                     .map(FileSharingId::from_use_case)
                     .collect(),
             ),
+            use_case::FormAnswerItemBody::Group(answer) => FormAnswerItemBody::Group(
+                answer
+                    .into_iter()
+                    .map(|row| row.into_iter().map(FormAnswerItem::from_use_case).collect())
+                    .collect(),
+            ),
         }
     }
 
@@ -89,11 +98,17 @@ impl FormAnswerItemBody {
                     .map(FileSharingId::into_use_case)
                     .collect(),
             ),
+            FormAnswerItemBody::Group(answer) => use_case::FormAnswerItemBody::Group(
+                answer
+                    .into_iter()
+                    .map(|row| row.into_iter().map(FormAnswerItem::into_use_case).collect())
+                    .collect(),
+            ),
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct FormAnswerItem {
     pub item_id: FormItemId,
     #[serde(flatten)]
@@ -116,7 +131,7 @@ impl FormAnswerItem {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, ToSchema)]
 pub struct RequestFormAnswerItem {
     pub item_id: FormItemId,
     #[serde(flatten)]
@@ -132,7 +147,7 @@ impl RequestFormAnswerItem {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case", tag = "type", content = "answer")]
 pub enum RequestFormAnswerItemBody {
     Text(Option<String>),
@@ -141,6 +156,8 @@ pub enum RequestFormAnswerItemBody {
     Radio(Option<RadioId>),
     GridRadio(Vec<GridRadioRowAnswer>),
     File(Vec<RequestFormAnswerItemFile>),
+    #[schema(no_recursion)]
+    Group(Vec<Vec<RequestFormAnswerItem>>),
 }
 
 impl RequestFormAnswerItemBody {
@@ -178,11 +195,23 @@ impl RequestFormAnswerItemBody {
                         .collect(),
                 )
             }
+            RequestFormAnswerItemBody::Group(answer) => {
+                interface::form_answer::InputFormAnswerItemBody::Group(
+                    answer
+                        .into_iter()
+                        .map(|row| {
+                            row.into_iter()
+                                .map(RequestFormAnswerItem::into_use_case)
+                                .collect()
+                        })
+                        .collect(),
+                )
+            }
         }
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum RequestFormAnswerItemFile {
     SharingId(FileSharingId),
@@ -201,3 +230,174 @@ impl RequestFormAnswerItemFile {
         }
     }
 }
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum CheckAnswerItemError {
+    NotAnsweredWithoutCondition,
+    NotAnsweredWithCondition,
+    UnexpectedAnswer,
+    MismatchedItemType,
+    NotAnsweredText,
+    TooLongText,
+    TooShortText,
+    NotAllowedMultipleLineText,
+    NotAnsweredInteger,
+    TooBigInteger,
+    TooSmallInteger,
+    TooManyChecks,
+    TooFewChecks,
+    NotAnsweredFile,
+    NotAllowedMultipleFiles,
+    NotAllowedFileType,
+    UnknownCheckboxId {
+        id: CheckboxId,
+    },
+    NotAnsweredRadio,
+    UnknownRadioId {
+        id: RadioId,
+    },
+    NotAnsweredGridRadioRows,
+    MismatchedGridRadioRowsLength,
+    MismatchedGridRadioRowId {
+        expected: GridRadioRowId,
+        got: GridRadioRowId,
+    },
+    UnknownGridRadioColumnId {
+        id: GridRadioColumnId,
+    },
+    NotAllowedDuplicatedGridRadioColumn {
+        id: GridRadioColumnId,
+    },
+    TooManyGroupRows,
+    TooFewGroupRows,
+    MismatchedGroupRowLength {
+        row: usize,
+    },
+    MismatchedGroupRowItemId {
+        row: usize,
+        expected: FormItemId,
+        got: FormItemId,
+    },
+    InvalidGroupRowItem {
+        row: usize,
+        id: FormItemId,
+        #[schema(no_recursion)]
+        error: Box<CheckAnswerItemError>,
+    },
+}
+
+impl CheckAnswerItemError {
+    pub fn from_use_case(err: interface::form::CheckAnswerItemError) -> Self {
+        match err {
+            interface::form::CheckAnswerItemError::NotAnsweredWithoutCondition => {
+                CheckAnswerItemError::NotAnsweredWithoutCondition
+            }
+            interface::form::CheckAnswerItemError::NotAnsweredWithCondition => {
+                CheckAnswerItemError::NotAnsweredWithCondition
+            }
+            interface::form::CheckAnswerItemError::UnexpectedAnswer => {
+                CheckAnswerItemError::UnexpectedAnswer
+            }
+            interface::form::CheckAnswerItemError::MismatchedItemType => {
+                CheckAnswerItemError::MismatchedItemType
+            }
+            interface::form::CheckAnswerItemError::NotAnsweredText => {
+                CheckAnswerItemError::NotAnsweredText
+            }
+            interface::form::CheckAnswerItemError::TooLongText => CheckAnswerItemError::TooLongText,
+            interface::form::CheckAnswerItemError::TooShortText => {
+                CheckAnswerItemError::TooShortText
+            }
+            interface::form::CheckAnswerItemError::NotAllowedMultipleLineText => {
+                CheckAnswerItemError::NotAllowedMultipleLineText
+            }
+            interface::form::CheckAnswerItemError::NotAnsweredInteger => {
+                CheckAnswerItemError::NotAnsweredInteger
+            }
+            interface::form::CheckAnswerItemError::TooBigInteger => {
+                CheckAnswerItemError::TooBigInteger
+            }
+            interface::form::CheckAnswerItemError::TooSmallInteger => {
+                CheckAnswerItemError::TooSmallInteger
+            }
+            interface::form::CheckAnswerItemError::TooManyChecks => {
+                CheckAnswerItemError::TooManyChecks
+            }
+            interface::form::CheckAnswerItemError::TooFewChecks => {
+                CheckAnswerItemError::TooFewChecks
+            }
+            interface::form::CheckAnswerItemError::NotAnsweredFile => {
+                CheckAnswerItemError::NotAnsweredFile
+            }
+            interface::form::CheckAnswerItemError::NotAllowedMultipleFiles => {
+                CheckAnswerItemError::NotAllowedMultipleFiles
+            }
+            interface::form::CheckAnswerItemError::NotAllowedFileType => {
+                CheckAnswerItemError::NotAllowedFileType
+            }
+            interface::form::CheckAnswerItemError::UnknownCheckboxId { id } => {
+                CheckAnswerItemError::UnknownCheckboxId {
+                    id: CheckboxId::from_use_case(id),
+                }
+            }
+            interface::form::CheckAnswerItemError::NotAnsweredRadio => {
+                CheckAnswerItemError::NotAnsweredRadio
+            }
+            interface::form::CheckAnswerItemError::UnknownRadioId { id } => {
+                CheckAnswerItemError::UnknownRadioId {
+                    id: RadioId::from_use_case(id),
+                }
+            }
+            interface::form::CheckAnswerItemError::NotAnsweredGridRadioRows => {
+                CheckAnswerItemError::NotAnsweredGridRadioRows
+            }
+            interface::form::CheckAnswerItemError::MismatchedGridRadioRowsLength => {
+                CheckAnswerItemError::MismatchedGridRadioRowsLength
+            }
+            interface::form::CheckAnswerItemError::MismatchedGridRadioRowId { expected, got } => {
+                CheckAnswerItemError::MismatchedGridRadioRowId {
+                    expected: GridRadioRowId::from_use_case(expected),
+                    got: GridRadioRowId::from_use_case(got),
+                }
+            }
+            interface::form::CheckAnswerItemError::UnknownGridRadioColumnId { id } => {
+                CheckAnswerItemError::UnknownGridRadioColumnId {
+                    id: GridRadioColumnId::from_use_case(id),
+                }
+            }
+            interface::form::CheckAnswerItemError::NotAllowedDuplicatedGridRadioColumn { id } => {
+                CheckAnswerItemError::NotAllowedDuplicatedGridRadioColumn {
+                    id: GridRadioColumnId::from_use_case(id),
+                }
+            }
+            interface::form::CheckAnswerItemError::TooManyGroupRows => {
+                CheckAnswerItemError::TooManyGroupRows
+            }
+            interface::form::CheckAnswerItemError::TooFewGroupRows => {
+                CheckAnswerItemError::TooFewGroupRows
+            }
+            interface::form::CheckAnswerItemError::MismatchedGroupRowLength { row } => {
+                CheckAnswerItemError::MismatchedGroupRowLength { row }
+            }
+            interface::form::CheckAnswerItemError::MismatchedGroupRowItemId {
+                row,
+                expected,
+                got,
+            } => CheckAnswerItemError::MismatchedGroupRowItemId {
+                row,
+                expected: FormItemId::from_use_case(expected),
+                got: FormItemId::from_use_case(got),
+            },
+            interface::form::CheckAnswerItemError::InvalidGroupRowItem {
+                row,
+                item_id,
+                item_error,
+            } => CheckAnswerItemError::InvalidGroupRowItem {
+                row,
+                id: FormItemId::from_use_case(item_id),
+                error: Box::new(CheckAnswerItemError::from_use_case(*item_error)),
+            },
+        }
+    }
+}
@@ -0,0 +1,283 @@
+use crate::handler::model::date_time::DateTime;
+use crate::handler::model::form::{FormItem, FormItemId};
+use crate::handler::model::project_query::ProjectQuery;
+
+use serde::{Deserialize, Serialize};
+use sos21_use_case::interface::{
+    form::{FormItemError, FormItemPath, FormItemsError},
+    form_definition::FormDefinitionError as UseCaseFormDefinitionError,
+};
+use sos21_use_case::model::form_definition as use_case;
+use utoipa::ToSchema;
+
+/// The version of the form definition format which this server reads and writes.
+pub const FORM_DEFINITION_VERSION: u64 = 1;
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FormDefinitionFormat {
+    #[default]
+    Json,
+    Yaml,
+}
+
+impl FormDefinitionFormat {
+    pub fn content_type(self) -> &'static str {
+        match self {
+            FormDefinitionFormat::Json => "application/json",
+            FormDefinitionFormat::Yaml => "application/yaml",
+        }
+    }
+
+    pub fn render(self, definition: &FormDefinition) -> anyhow::Result<String> {
+        let text = match self {
+            FormDefinitionFormat::Json => serde_json::to_string_pretty(definition)?,
+            FormDefinitionFormat::Yaml => serde_yaml::to_string(definition)?,
+        };
+        Ok(text)
+    }
+
+    pub fn parse(self, text: &str) -> Result<FormDefinition, FormDefinitionError> {
+        #[derive(Deserialize)]
+        struct Version {
+            version: u64,
+        }
+
+        fn parse<T>(format: FormDefinitionFormat, text: &str) -> Result<T, FormDefinitionError>
+        where
+            T: serde::de::DeserializeOwned,
+        {
+            let result = match format {
+                FormDefinitionFormat::Json => {
+                    serde_json::from_str(text).map_err(|err| err.to_string())
+                }
+                FormDefinitionFormat::Yaml => {
+                    serde_yaml::from_str(text).map_err(|err| err.to_string())
+                }
+            };
+            result.map_err(FormDefinitionError::invalid_syntax)
+        }
+
+        let Version { version } = parse(self, text)?;
+        if version != FORM_DEFINITION_VERSION {
+            return Err(FormDefinitionError {
+                path: Some("version".to_string()),
+                type_: "UNSUPPORTED_VERSION",
+                id: None,
+                reason: None,
+                message: None,
+            });
+        }
+
+        parse(self, text)
+    }
+}
+
+/// The versioned format to export and import forms and registration forms.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FormDefinition {
+    pub version: u64,
+    pub name: String,
+    pub description: String,
+    /// Only used in forms.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starts_at: Option<DateTime>,
+    /// Only used in forms.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ends_at: Option<DateTime>,
+    pub query: ProjectQuery,
+    pub items: Vec<FormItem>,
+}
+
+impl FormDefinition {
+    pub fn from_use_case(definition: use_case::FormDefinition) -> Self {
+        let (starts_at, ends_at) = match definition.period {
+            Some(period) => (
+                Some(DateTime::from_use_case(period.starts_at)),
+                Some(DateTime::from_use_case(period.ends_at)),
+            ),
+            None => (None, None),
+        };
+        FormDefinition {
+            version: FORM_DEFINITION_VERSION,
+            name: definition.name,
+            description: definition.description,
+            starts_at,
+            ends_at,
+            query: ProjectQuery::from_use_case(definition.query),
+            items: definition
+                .items
+                .into_iter()
+                .map(FormItem::from_use_case)
+                .collect(),
+        }
+    }
+
+    pub fn into_use_case(self) -> use_case::FormDefinition {
+        let period = match (self.starts_at, self.ends_at) {
+            (Some(starts_at), Some(ends_at)) => Some(use_case::FormDefinitionPeriod {
+                starts_at: starts_at.into_use_case(),
+                ends_at: ends_at.into_use_case(),
+            }),
+            _ => None,
+        };
+        use_case::FormDefinition {
+            name: self.name,
+            description: self.description,
+            period,
+            items: self
+                .items
+                .into_iter()
+                .map(FormItem::into_use_case)
+                .collect(),
+            query: self.query.into_use_case(),
+        }
+    }
+}
+
+/// An error in a form definition, located by `path` such as `items[2].items[0]`.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct FormDefinitionError {
+    pub path: Option<String>,
+    #[serde(rename = "type")]
+    pub type_: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<FormItemId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl FormDefinitionError {
+    fn invalid_syntax(message: String) -> Self {
+        FormDefinitionError {
+            path: None,
+            type_: "INVALID_SYNTAX",
+            id: None,
+            reason: None,
+            message: Some(message),
+        }
+    }
+
+    fn at(path: &str, type_: &'static str) -> Self {
+        FormDefinitionError {
+            path: Some(path.to_string()),
+            type_,
+            id: None,
+            reason: None,
+            message: None,
+        }
+    }
+
+    pub fn from_use_case(err: UseCaseFormDefinitionError) -> Self {
+        match err {
+            UseCaseFormDefinitionError::InvalidName => {
+                FormDefinitionError::at("name", "INVALID_NAME")
+            }
+            UseCaseFormDefinitionError::InvalidDescription => {
+                FormDefinitionError::at("description", "INVALID_DESCRIPTION")
+            }
+            UseCaseFormDefinitionError::MissingPeriod => {
+                FormDefinitionError::at("starts_at", "MISSING_PERIOD")
+            }
+            UseCaseFormDefinitionError::InvalidPeriod => {
+                FormDefinitionError::at("starts_at", "INVALID_PERIOD")
+            }
+            UseCaseFormDefinitionError::InvalidQuery(_) => {
+                FormDefinitionError::at("query", "INVALID_QUERY")
+            }
+            UseCaseFormDefinitionError::InvalidItems(err) => {
+                let path = match err.path {
+                    Some(path) => render_item_path(&path),
+                    None => "items".to_string(),
+                };
+                let (type_, id, reason) = match err.error {
+                    FormItemsError::NoItems => ("NO_ITEMS", None, None),
+                    FormItemsError::TooManyItems => ("TOO_MANY_ITEMS", None, None),
+                    FormItemsError::DuplicatedItemId(id) => (
+                        "DUPLICATED_FORM_ITEM_ID",
+                        Some(FormItemId::from_use_case(id)),
+                        None,
+                    ),
+                    FormItemsError::InvalidItem(id, err) => (
+                        "INVALID_FORM_ITEM",
+                        Some(FormItemId::from_use_case(id)),
+                        Some(form_item_error_reason(&err)),
+                    ),
+                };
+                FormDefinitionError {
+                    path: Some(path),
+                    type_,
+                    id,
+                    reason,
+                    message: None,
+                }
+            }
+        }
+    }
+}
+
+fn render_item_path(path: &FormItemPath) -> String {
+    path.0
+        .iter()
+        .map(|index| format!("items[{}]", index))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn form_item_error_reason(err: &FormItemError) -> &'static str {
+    match err {
+        FormItemError::InvalidName => "INVALID_NAME",
+        FormItemError::InvalidDescription => "INVALID_DESCRIPTION",
+        FormItemError::InvalidCondition => "INVALID_CONDITION",
+        FormItemError::InvalidTextMaxLength => "INVALID_TEXT_MAX_LENGTH",
+        FormItemError::InvalidTextMinLength => "INVALID_TEXT_MIN_LENGTH",
+        FormItemError::InvalidTextPlaceholder => "INVALID_TEXT_PLACEHOLDER",
+        FormItemError::InconsistentTextLengthLimits => "INCONSISTENT_TEXT_LENGTH_LIMITS",
+        FormItemError::InvalidIntegerMaxLimit => "INVALID_INTEGER_MAX_LIMIT",
+        FormItemError::InvalidIntegerMinLimit => "INVALID_INTEGER_MIN_LIMIT",
+        FormItemError::InvalidIntegerUnit => "INVALID_INTEGER_UNIT",
+        FormItemError::OutOfLimitsIntegerPlaceholder => "OUT_OF_LIMITS_INTEGER_PLACEHOLDER",
+        FormItemError::InconsistentIntegerLimits => "INCONSISTENT_INTEGER_LIMITS",
+        FormItemError::InvalidCheckboxMinChecks => "INVALID_CHECKBOX_MIN_CHECKS",
+        FormItemError::InvalidCheckboxMaxChecks => "INVALID_CHECKBOX_MAX_CHECKS",
+        FormItemError::InvalidCheckboxLabel => "INVALID_CHECKBOX_LABEL",
+        FormItemError::InconsistentCheckLimits => "INCONSISTENT_CHECK_LIMITS",
+        FormItemError::NoCheckboxes => "NO_CHECKBOXES",
+        FormItemError::TooManyCheckboxes => "TOO_MANY_CHECKBOXES",
+        FormItemError::InvalidRadioLabel => "INVALID_RADIO_LABEL",
+        FormItemError::NoRadioButtons => "NO_RADIO_BUTTONS",
+        FormItemError::TooManyRadioButtons => "TOO_MANY_RADIO_BUTTONS",
+        FormItemError::InvalidGridRadioRowLabel => "INVALID_GRID_RADIO_ROW_LABEL",
+        FormItemError::InvalidGridRadioColumnLabel => "INVALID_GRID_RADIO_COLUMN_LABEL",
+        FormItemError::NoGridRadioRows => "NO_GRID_RADIO_ROWS",
+        FormItemError::TooManyGridRadioRows => "TOO_MANY_GRID_RADIO_ROWS",
+        FormItemError::NoGridRadioColumns => "NO_GRID_RADIO_COLUMNS",
+        FormItemError::TooFewGridRadioColumnsWhenExclusiveAndRequired => {
+            "TOO_FEW_GRID_RADIO_COLUMNS_WHEN_EXCLUSIVE_AND_REQUIRED"
+        }
+        FormItemError::TooManyGridRadioColumns => "TOO_MANY_GRID_RADIO_COLUMNS",
+        FormItemError::TooManyFileTypes => "TOO_MANY_FILE_TYPES",
+        FormItemError::NoFileTypes => "NO_FILE_TYPES",
+        FormItemError::DuplicatedFileType => "DUPLICATED_FILE_TYPE",
+        FormItemError::InvalidGroupMinRows => "INVALID_GROUP_MIN_ROWS",
+        FormItemError::InvalidGroupMaxRows => "INVALID_GROUP_MAX_ROWS",
+        FormItemError::InconsistentGroupRowLimits => "INCONSISTENT_GROUP_ROW_LIMITS",
+        FormItemError::NoGroupItems => "NO_GROUP_ITEMS",
+        FormItemError::TooManyGroupItems => "TOO_MANY_GROUP_ITEMS",
+        FormItemError::InvalidGroupItem(_, err) => form_item_error_reason(err),
+        FormItemError::NestedGroup(_) => "NESTED_GROUP",
+        FormItemError::DuplicatedCheckboxId(_) => "DUPLICATED_CHECKBOX_ID",
+        FormItemError::DuplicatedRadioId(_) => "DUPLICATED_RADIO_ID",
+        FormItemError::DuplicatedGridRadioRowId(_) => "DUPLICATED_GRID_RADIO_ROW_ID",
+        FormItemError::DuplicatedGridRadioColumnId(_) => "DUPLICATED_GRID_RADIO_COLUMN_ID",
+        FormItemError::MismatchedConditionType(_) => "MISMATCHED_CONDITION_TYPE",
+        FormItemError::UnknownItemIdInConditions(_) => "UNKNOWN_ITEM_ID_IN_CONDITIONS",
+        FormItemError::UnknownCheckboxIdInConditions(_) => "UNKNOWN_CHECKBOX_ID_IN_CONDITIONS",
+        FormItemError::UnknownRadioIdInConditions(_) => "UNKNOWN_RADIO_ID_IN_CONDITIONS",
+        FormItemError::UnknownGridRadioColumnIdInConditions(_) => {
+            "UNKNOWN_GRID_RADIO_COLUMN_ID_IN_CONDITIONS"
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::handler::model::date_time::DateTime;
+use crate::handler::model::form::FormItem;
+use crate::handler::model::project_query::ProjectQuery;
+use crate::handler::model::user::UserId;
+
+use serde::{Deserialize, Serialize};
+use sos21_use_case::model::form_template as use_case;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(transparent)]
+#[schema(value_type = String, format = "uuid")]
+pub struct FormTemplateId(pub Uuid);
+
+impl FormTemplateId {
+    pub fn from_use_case(id: use_case::FormTemplateId) -> Self {
+        FormTemplateId(id.0)
+    }
+
+    pub fn into_use_case(self) -> use_case::FormTemplateId {
+        use_case::FormTemplateId(self.0)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FormTemplate {
+    pub id: FormTemplateId,
+    pub created_at: DateTime,
+    pub author_id: UserId,
+    pub name: String,
+    pub description: String,
+    pub items: Vec<FormItem>,
+    pub query: ProjectQuery,
+}
+
+impl FormTemplate {
+    pub fn from_use_case(template: use_case::FormTemplate) -> Self {
+        FormTemplate {
+            id: FormTemplateId::from_use_case(template.id),
+            created_at: DateTime::from_use_case(template.created_at),
+            author_id: UserId::from_use_case(template.author_id),
+            name: template.name,
+            description: template.description,
+            items: template
+                .items
+                .into_iter()
+                .map(FormItem::from_use_case)
+                .collect(),
+            query: ProjectQuery::from_use_case(template.query),
+        }
+    }
+}
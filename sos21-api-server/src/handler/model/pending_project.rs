@@ -1,13 +1,16 @@
 use crate::handler::model::date_time::DateTime;
+use crate::handler::model::festival::FestivalId;
 use crate::handler::model::project::{ProjectAttribute, ProjectCategory};
-use crate::handler::model::user::UserId;
+use crate::handler::model::user::{UserId, UserKanaName, UserName};
 
 use serde::{Deserialize, Serialize};
 use sos21_use_case::model::pending_project as use_case;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(transparent)]
+#[schema(value_type = String, format = "uuid")]
 pub struct PendingProjectId(pub Uuid);
 
 impl PendingProjectId {
@@ -20,9 +23,10 @@ impl PendingProjectId {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct PendingProject {
     pub id: PendingProjectId,
+    pub festival_id: FestivalId,
     pub created_at: DateTime,
     pub updated_at: DateTime,
     pub owner_id: UserId,
@@ -40,6 +44,7 @@ impl PendingProject {
     pub fn from_use_case(pending_project: use_case::PendingProject) -> PendingProject {
         PendingProject {
             id: PendingProjectId::from_use_case(pending_project.id),
+            festival_id: FestivalId::from_use_case(pending_project.festival_id),
             created_at: DateTime::from_use_case(pending_project.created_at),
             updated_at: DateTime::from_use_case(pending_project.updated_at),
             owner_id: UserId::from_use_case(pending_project.owner_id),
@@ -60,3 +65,24 @@ impl PendingProject {
         }
     }
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PendingProjectSummary {
+    pub pending_project: PendingProject,
+    pub owner_name: UserName,
+    pub owner_kana_name: UserKanaName,
+    pub registration_form_count: u64,
+    pub answered_registration_form_count: u64,
+}
+
+impl PendingProjectSummary {
+    pub fn from_use_case(summary: use_case::PendingProjectSummary) -> PendingProjectSummary {
+        PendingProjectSummary {
+            pending_project: PendingProject::from_use_case(summary.pending_project),
+            owner_name: UserName::from_use_case(summary.owner_name),
+            owner_kana_name: UserKanaName::from_use_case(summary.owner_kana_name),
+            registration_form_count: summary.registration_form_count,
+            answered_registration_form_count: summary.answered_registration_form_count,
+        }
+    }
+}
@@ -1,12 +1,15 @@
 use crate::handler::model::date_time::DateTime;
+use crate::handler::model::festival::FestivalId;
 use crate::handler::model::user::{UserId, UserKanaName, UserName};
 
 use serde::{Deserialize, Serialize};
 use sos21_use_case::model::project as use_case;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(transparent)]
+#[schema(value_type = String, format = "uuid")]
 pub struct ProjectId(pub Uuid);
 
 impl ProjectId {
@@ -19,7 +22,7 @@ impl ProjectId {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ProjectCategory {
     General,
@@ -55,7 +58,7 @@ impl ProjectCategory {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ProjectAttribute {
     Academic,
@@ -87,9 +90,10 @@ impl ProjectAttribute {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct Project {
     pub id: ProjectId,
+    pub festival_id: FestivalId,
     pub code: String,
     pub created_at: DateTime,
     pub updated_at: DateTime,
@@ -112,6 +116,7 @@ impl Project {
     pub fn from_use_case(project: use_case::Project) -> Project {
         Project {
             id: ProjectId::from_use_case(project.id),
+            festival_id: FestivalId::from_use_case(project.festival_id),
             code: project.code,
             created_at: DateTime::from_use_case(project.created_at),
             updated_at: DateTime::from_use_case(project.updated_at),
@@ -2,8 +2,9 @@ use crate::handler::model::project::{ProjectAttribute, ProjectCategory};
 
 use serde::{Deserialize, Serialize};
 use sos21_use_case::model::project_query as use_case;
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ProjectQueryConjunction {
     pub category: Option<ProjectCategory>,
     pub attributes: Vec<ProjectAttribute>,
@@ -37,7 +38,7 @@ impl ProjectQueryConjunction {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(transparent)]
 pub struct ProjectQuery(pub Vec<ProjectQueryConjunction>);
 
@@ -1,14 +1,17 @@
 use crate::handler::model::date_time::DateTime;
+use crate::handler::model::festival::FestivalId;
 use crate::handler::model::form::FormItem;
 use crate::handler::model::project_query::ProjectQuery;
 use crate::handler::model::user::UserId;
 
 use serde::{Deserialize, Serialize};
 use sos21_use_case::model::registration_form as use_case;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(transparent)]
+#[schema(value_type = String, format = "uuid")]
 pub struct RegistrationFormId(pub Uuid);
 
 impl RegistrationFormId {
@@ -21,9 +24,10 @@ impl RegistrationFormId {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct RegistrationForm {
     pub id: RegistrationFormId,
+    pub festival_id: FestivalId,
     pub created_at: DateTime,
     pub author_id: UserId,
     pub name: String,
@@ -41,6 +45,7 @@ impl RegistrationForm {
             .collect();
         RegistrationForm {
             id: RegistrationFormId::from_use_case(registration_form.id),
+            festival_id: FestivalId::from_use_case(registration_form.festival_id),
             created_at: DateTime::from_use_case(registration_form.created_at),
             author_id: UserId::from_use_case(registration_form.author_id),
             name: registration_form.name,
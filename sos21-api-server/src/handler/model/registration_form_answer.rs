@@ -7,10 +7,12 @@ use crate::handler::model::user::UserId;
 
 use serde::{Deserialize, Serialize};
 use sos21_use_case::model::registration_form_answer as use_case;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(transparent)]
+#[schema(value_type = String, format = "uuid")]
 pub struct RegistrationFormAnswerId(pub Uuid);
 
 impl RegistrationFormAnswerId {
@@ -23,7 +25,7 @@ impl RegistrationFormAnswerId {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum RegistrationFormAnswerRespondent {
     ProjectId(ProjectId),
@@ -56,7 +58,7 @@ impl RegistrationFormAnswerRespondent {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct RegistrationFormAnswer {
     pub id: RegistrationFormAnswerId,
     #[serde(flatten)]
@@ -85,3 +87,28 @@ impl RegistrationFormAnswer {
         }
     }
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RegistrationFormAnswerDraft {
+    pub pending_project_id: PendingProjectId,
+    pub registration_form_id: RegistrationFormId,
+    pub updated_at: DateTime,
+    pub author_id: UserId,
+    pub items: Vec<FormAnswerItem>,
+}
+
+impl RegistrationFormAnswerDraft {
+    pub fn from_use_case(draft: use_case::RegistrationFormAnswerDraft) -> Self {
+        RegistrationFormAnswerDraft {
+            pending_project_id: PendingProjectId::from_use_case(draft.pending_project_id),
+            registration_form_id: RegistrationFormId::from_use_case(draft.registration_form_id),
+            updated_at: DateTime::from_use_case(draft.updated_at),
+            author_id: UserId::from_use_case(draft.author_id),
+            items: draft
+                .items
+                .into_iter()
+                .map(FormAnswerItem::from_use_case)
+                .collect(),
+        }
+    }
+}
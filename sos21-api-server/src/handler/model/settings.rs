@@ -0,0 +1,101 @@
+use crate::handler::model::date_time::DateTime;
+use crate::handler::model::festival::FestivalId;
+use crate::handler::model::project::ProjectCategory;
+use crate::handler::model::user::UserId;
+
+use serde::{Deserialize, Serialize};
+use sos21_use_case::model::settings as use_case;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum ProjectCreationPeriod {
+    Always,
+    Never,
+    Range {
+        starts_at: DateTime,
+        ends_at: DateTime,
+    },
+}
+
+impl ProjectCreationPeriod {
+    pub fn from_use_case(period: use_case::ProjectCreationPeriod) -> Self {
+        match period {
+            use_case::ProjectCreationPeriod::Always => ProjectCreationPeriod::Always,
+            use_case::ProjectCreationPeriod::Never => ProjectCreationPeriod::Never,
+            use_case::ProjectCreationPeriod::Range { starts_at, ends_at } => {
+                ProjectCreationPeriod::Range {
+                    starts_at: DateTime::from_use_case(starts_at),
+                    ends_at: DateTime::from_use_case(ends_at),
+                }
+            }
+        }
+    }
+
+    pub fn into_use_case(self) -> use_case::ProjectCreationPeriod {
+        match self {
+            ProjectCreationPeriod::Always => use_case::ProjectCreationPeriod::Always,
+            ProjectCreationPeriod::Never => use_case::ProjectCreationPeriod::Never,
+            ProjectCreationPeriod::Range { starts_at, ends_at } => {
+                use_case::ProjectCreationPeriod::Range {
+                    starts_at: starts_at.into_use_case(),
+                    ends_at: ends_at.into_use_case(),
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProjectCreationPeriodEntry {
+    pub category: ProjectCategory,
+    pub period: ProjectCreationPeriod,
+}
+
+impl ProjectCreationPeriodEntry {
+    pub fn from_use_case(
+        (category, period): (
+            sos21_use_case::model::project::ProjectCategory,
+            use_case::ProjectCreationPeriod,
+        ),
+    ) -> Self {
+        ProjectCreationPeriodEntry {
+            category: ProjectCategory::from_use_case(category),
+            period: ProjectCreationPeriod::from_use_case(period),
+        }
+    }
+
+    pub fn into_use_case(
+        self,
+    ) -> (
+        sos21_use_case::model::project::ProjectCategory,
+        use_case::ProjectCreationPeriod,
+    ) {
+        (self.category.into_use_case(), self.period.into_use_case())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct Settings {
+    pub updated_at: DateTime,
+    pub author_id: Option<UserId>,
+    pub administrator_email: String,
+    pub active_festival_id: FestivalId,
+    pub project_creation_periods: Vec<ProjectCreationPeriodEntry>,
+}
+
+impl Settings {
+    pub fn from_use_case(settings: use_case::Settings) -> Self {
+        Settings {
+            updated_at: DateTime::from_use_case(settings.updated_at),
+            author_id: settings.author_id.map(UserId::from_use_case),
+            administrator_email: settings.administrator_email,
+            active_festival_id: FestivalId::from_use_case(settings.active_festival_id),
+            project_creation_periods: settings
+                .project_creation_periods
+                .into_iter()
+                .map(ProjectCreationPeriodEntry::from_use_case)
+                .collect(),
+        }
+    }
+}
@@ -1,9 +1,11 @@
+use crate::handler::model::custom_role::CustomRoleId;
 use crate::handler::model::date_time::DateTime;
 
 use serde::{Deserialize, Serialize};
 use sos21_use_case::model::user as use_case;
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(transparent)]
 pub struct UserId(pub String);
 
@@ -17,7 +19,7 @@ impl UserId {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct UserName {
     pub first: String,
     pub last: String,
@@ -39,7 +41,7 @@ impl UserName {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct UserKanaName {
     pub first: String,
     pub last: String,
@@ -61,7 +63,7 @@ impl UserKanaName {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum UserRole {
     Administrator,
@@ -90,7 +92,7 @@ impl UserRole {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum UserCategory {
     UndergraduateStudent,
@@ -116,7 +118,7 @@ impl UserCategory {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct User {
     pub id: UserId,
     pub created_at: DateTime,
@@ -126,6 +128,9 @@ pub struct User {
     pub phone_number: String,
     pub role: UserRole,
     pub category: UserCategory,
+    pub custom_role_id: Option<CustomRoleId>,
+    pub suspension: Option<UserSuspension>,
+    pub tokens_revoked_at: Option<DateTime>,
 }
 
 impl User {
@@ -139,6 +144,26 @@ impl User {
             phone_number: user.phone_number,
             role: UserRole::from_use_case(user.role),
             category: UserCategory::from_use_case(user.category),
+            custom_role_id: user.custom_role_id.map(CustomRoleId::from_use_case),
+            suspension: user.suspension.map(UserSuspension::from_use_case),
+            tokens_revoked_at: user.tokens_revoked_at.map(DateTime::from_use_case),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UserSuspension {
+    pub suspended_at: DateTime,
+    pub suspended_by: Option<UserId>,
+    pub reason: String,
+}
+
+impl UserSuspension {
+    pub fn from_use_case(suspension: use_case::UserSuspension) -> UserSuspension {
+        UserSuspension {
+            suspended_at: DateTime::from_use_case(suspension.suspended_at),
+            suspended_by: suspension.suspended_by.map(UserId::from_use_case),
+            reason: suspension.reason,
         }
     }
 }
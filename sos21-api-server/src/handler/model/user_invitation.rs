@@ -3,10 +3,12 @@ use crate::handler::model::user::UserId;
 
 use serde::{Deserialize, Serialize};
 use sos21_use_case::model::user_invitation as use_case;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(transparent)]
+#[schema(value_type = String, format = "uuid")]
 pub struct UserInvitationId(pub Uuid);
 
 impl UserInvitationId {
@@ -19,7 +21,7 @@ impl UserInvitationId {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum UserInvitationRole {
     Administrator,
@@ -49,13 +51,44 @@ impl UserInvitationRole {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum UserInvitationStatus {
+    Pending,
+    Accepted,
+    Expired,
+}
+
+impl UserInvitationStatus {
+    pub fn from_use_case(status: use_case::UserInvitationStatus) -> Self {
+        match status {
+            use_case::UserInvitationStatus::Pending => UserInvitationStatus::Pending,
+            use_case::UserInvitationStatus::Accepted => UserInvitationStatus::Accepted,
+            use_case::UserInvitationStatus::Expired => UserInvitationStatus::Expired,
+        }
+    }
+
+    pub fn into_use_case(self) -> use_case::UserInvitationStatus {
+        match self {
+            UserInvitationStatus::Pending => use_case::UserInvitationStatus::Pending,
+            UserInvitationStatus::Accepted => use_case::UserInvitationStatus::Accepted,
+            UserInvitationStatus::Expired => use_case::UserInvitationStatus::Expired,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct UserInvitation {
     pub id: UserInvitationId,
     pub created_at: DateTime,
     pub author_id: UserId,
     pub email: String,
     pub role: UserInvitationRole,
+    pub allows_unlisted_domain: bool,
+    pub expires_at: Option<DateTime>,
+    pub accepted_at: Option<DateTime>,
+    pub accepted_user_id: Option<UserId>,
+    pub status: UserInvitationStatus,
 }
 
 impl UserInvitation {
@@ -66,6 +99,11 @@ impl UserInvitation {
             author_id: UserId::from_use_case(user.author_id),
             email: user.email,
             role: UserInvitationRole::from_use_case(user.role),
+            allows_unlisted_domain: user.allows_unlisted_domain,
+            expires_at: user.expires_at.map(DateTime::from_use_case),
+            accepted_at: user.accepted_at.map(DateTime::from_use_case),
+            accepted_user_id: user.accepted_user_id.map(UserId::from_use_case),
+            status: UserInvitationStatus::from_use_case(user.status),
         }
     }
 }
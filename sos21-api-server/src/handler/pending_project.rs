@@ -2,7 +2,15 @@ pub mod registration_form;
 
 pub mod get;
 pub use get::handler as get;
+pub mod list;
+pub use list::handler as list;
+pub mod export;
+pub use export::handler as export;
 pub mod update;
 pub use update::handler as update;
 pub mod update_any;
 pub use update_any::handler as update_any;
+pub mod update_deadline;
+pub use update_deadline::handler as update_deadline;
+pub mod list_deadlines;
+pub use list_deadlines::handler as list_deadlines;
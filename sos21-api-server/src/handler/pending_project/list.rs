@@ -0,0 +1,65 @@
+use crate::app::Context;
+use crate::handler::model::festival::FestivalId;
+use crate::handler::model::pending_project::PendingProjectSummary;
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::list_all_pending_projects;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    #[serde(default)]
+    pub festival_id: Option<FestivalId>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub pending_projects: Vec<PendingProjectSummary>,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::OK
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    InsufficientPermissions,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
+impl From<list_all_pending_projects::Error> for Error {
+    fn from(err: list_all_pending_projects::Error) -> Error {
+        match err {
+            list_all_pending_projects::Error::InsufficientPermissions => {
+                Error::InsufficientPermissions
+            }
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let pending_projects =
+        list_all_pending_projects::run(&ctx, request.festival_id.map(FestivalId::into_use_case))
+            .await?;
+    let pending_projects = pending_projects
+        .into_iter()
+        .map(PendingProjectSummary::from_use_case)
+        .collect();
+    Ok(Response { pending_projects })
+}
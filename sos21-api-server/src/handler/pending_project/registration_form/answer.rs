@@ -8,32 +8,35 @@ use crate::handler::{HandlerResponse, HandlerResult};
 use serde::{Deserialize, Serialize};
 use sos21_domain::context::Login;
 use sos21_use_case::{answer_registration_form, interface};
+use utoipa::ToSchema;
 use warp::http::StatusCode;
 
+pub mod draft;
 pub mod get;
 pub use get::handler as get;
 pub mod update;
 pub use update::handler as update;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, ToSchema)]
 pub struct Request {
     pub pending_project_id: PendingProjectId,
     pub registration_form_id: RegistrationFormId,
     pub items: Vec<RequestFormAnswerItem>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct Response {
     pub answer: RegistrationFormAnswer,
 }
 
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
 impl HandlerResponse for Response {
     fn status_code(&self) -> StatusCode {
         StatusCode::CREATED
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
 pub enum Error {
     RegistrationFormNotFound,
@@ -53,6 +56,7 @@ pub enum Error {
     InsufficientPermissions,
 }
 
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
 impl HandlerResponse for Error {
     fn status_code(&self) -> StatusCode {
         match self {
@@ -133,6 +137,7 @@ pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Res
             .collect(),
     };
     let answer = answer_registration_form::run(&ctx, input).await?;
+    crate::metrics::record_registration_form_answer_submitted();
     let answer = RegistrationFormAnswer::from_use_case(answer);
     Ok(Response { answer })
 }
@@ -0,0 +1,77 @@
+use crate::app::Context;
+use crate::handler::model::{
+    pending_project::PendingProjectId, registration_form::RegistrationFormId,
+    registration_form_answer::RegistrationFormAnswerDraft,
+};
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::get_pending_project_registration_form_answer_draft;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    pub pending_project_id: PendingProjectId,
+    pub registration_form_id: RegistrationFormId,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub draft: RegistrationFormAnswerDraft,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::OK
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    PendingProjectNotFound,
+    RegistrationFormNotFound,
+    RegistrationFormAnswerDraftNotFound,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::PendingProjectNotFound => StatusCode::NOT_FOUND,
+            Error::RegistrationFormNotFound => StatusCode::NOT_FOUND,
+            Error::RegistrationFormAnswerDraftNotFound => StatusCode::NOT_FOUND,
+        }
+    }
+}
+
+impl From<get_pending_project_registration_form_answer_draft::Error> for Error {
+    fn from(err: get_pending_project_registration_form_answer_draft::Error) -> Error {
+        match err {
+            get_pending_project_registration_form_answer_draft::Error::PendingProjectNotFound => {
+                Error::PendingProjectNotFound
+            }
+            get_pending_project_registration_form_answer_draft::Error::RegistrationFormNotFound => {
+                Error::RegistrationFormNotFound
+            }
+            get_pending_project_registration_form_answer_draft::Error::RegistrationFormAnswerDraftNotFound => {
+                Error::RegistrationFormAnswerDraftNotFound
+            }
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let draft = get_pending_project_registration_form_answer_draft::run(
+        &ctx,
+        request.pending_project_id.into_use_case(),
+        request.registration_form_id.into_use_case(),
+    )
+    .await?;
+    let draft = RegistrationFormAnswerDraft::from_use_case(draft);
+    Ok(Response { draft })
+}
@@ -0,0 +1,114 @@
+use crate::app::Context;
+use crate::handler::model::{
+    form::FormItemId, form_answer::item::RequestFormAnswerItem, pending_project::PendingProjectId,
+    registration_form::RegistrationFormId, registration_form_answer::RegistrationFormAnswerDraft,
+};
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::{interface, save_pending_project_registration_form_answer_draft};
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    pub pending_project_id: PendingProjectId,
+    pub registration_form_id: RegistrationFormId,
+    pub items: Vec<RequestFormAnswerItem>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub draft: RegistrationFormAnswerDraft,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::OK
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    RegistrationFormNotFound,
+    PendingProjectNotFound,
+    AlreadyAnsweredRegistrationForm,
+    OutOfProjectCreationPeriod,
+    NoFormAnswerItems,
+    TooManyFormAnswerItems,
+    InvalidFormAnswerItem { id: FormItemId },
+    InsufficientPermissions,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::RegistrationFormNotFound => StatusCode::NOT_FOUND,
+            Error::PendingProjectNotFound => StatusCode::NOT_FOUND,
+            Error::AlreadyAnsweredRegistrationForm => StatusCode::CONFLICT,
+            Error::OutOfProjectCreationPeriod => StatusCode::CONFLICT,
+            Error::NoFormAnswerItems => StatusCode::BAD_REQUEST,
+            Error::TooManyFormAnswerItems => StatusCode::BAD_REQUEST,
+            Error::InvalidFormAnswerItem { .. } => StatusCode::BAD_REQUEST,
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
+impl From<save_pending_project_registration_form_answer_draft::Error> for Error {
+    fn from(err: save_pending_project_registration_form_answer_draft::Error) -> Error {
+        match err {
+            save_pending_project_registration_form_answer_draft::Error::RegistrationFormNotFound => {
+                Error::RegistrationFormNotFound
+            }
+            save_pending_project_registration_form_answer_draft::Error::PendingProjectNotFound => {
+                Error::PendingProjectNotFound
+            }
+            save_pending_project_registration_form_answer_draft::Error::AlreadyAnswered => {
+                Error::AlreadyAnsweredRegistrationForm
+            }
+            save_pending_project_registration_form_answer_draft::Error::OutOfProjectCreationPeriod => {
+                Error::OutOfProjectCreationPeriod
+            }
+            save_pending_project_registration_form_answer_draft::Error::InvalidItems(err) => {
+                match err {
+                    interface::form_answer::FormAnswerItemsError::NoItems => {
+                        Error::NoFormAnswerItems
+                    }
+                    interface::form_answer::FormAnswerItemsError::TooManyItems => {
+                        Error::TooManyFormAnswerItems
+                    }
+                    // TODO: break down invalid item errors
+                    interface::form_answer::FormAnswerItemsError::InvalidItem(id, _) => {
+                        Error::InvalidFormAnswerItem {
+                            id: FormItemId::from_use_case(id),
+                        }
+                    }
+                }
+            }
+            save_pending_project_registration_form_answer_draft::Error::InsufficientPermissions => {
+                Error::InsufficientPermissions
+            }
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let input = save_pending_project_registration_form_answer_draft::Input {
+        pending_project_id: request.pending_project_id.into_use_case(),
+        registration_form_id: request.registration_form_id.into_use_case(),
+        items: request
+            .items
+            .into_iter()
+            .map(RequestFormAnswerItem::into_use_case)
+            .collect(),
+    };
+    let draft = save_pending_project_registration_form_answer_draft::run(&ctx, input).await?;
+    let draft = RegistrationFormAnswerDraft::from_use_case(draft);
+    Ok(Response { draft })
+}
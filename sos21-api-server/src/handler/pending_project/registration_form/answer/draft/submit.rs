@@ -0,0 +1,122 @@
+use crate::app::Context;
+use crate::handler::model::{
+    form::FormItemId, pending_project::PendingProjectId, registration_form::RegistrationFormId,
+    registration_form_answer::RegistrationFormAnswer,
+};
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::{interface, submit_pending_project_registration_form_answer_draft};
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    pub pending_project_id: PendingProjectId,
+    pub registration_form_id: RegistrationFormId,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub answer: RegistrationFormAnswer,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::CREATED
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    RegistrationFormNotFound,
+    PendingProjectNotFound,
+    RegistrationFormAnswerDraftNotFound,
+    AlreadyAnsweredRegistrationForm,
+    OutOfProjectCreationPeriod,
+    MismatchedFormItemsLength,
+    MismatchedFormItemId {
+        expected: FormItemId,
+        got: FormItemId,
+    },
+    InvalidFormAnswerItem {
+        id: FormItemId,
+    },
+    InsufficientPermissions,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::RegistrationFormNotFound => StatusCode::NOT_FOUND,
+            Error::PendingProjectNotFound => StatusCode::NOT_FOUND,
+            Error::RegistrationFormAnswerDraftNotFound => StatusCode::NOT_FOUND,
+            Error::AlreadyAnsweredRegistrationForm => StatusCode::CONFLICT,
+            Error::OutOfProjectCreationPeriod => StatusCode::CONFLICT,
+            Error::MismatchedFormItemsLength => StatusCode::BAD_REQUEST,
+            Error::MismatchedFormItemId { .. } => StatusCode::BAD_REQUEST,
+            Error::InvalidFormAnswerItem { .. } => StatusCode::BAD_REQUEST,
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
+impl From<submit_pending_project_registration_form_answer_draft::Error> for Error {
+    fn from(err: submit_pending_project_registration_form_answer_draft::Error) -> Error {
+        match err {
+            submit_pending_project_registration_form_answer_draft::Error::RegistrationFormNotFound => {
+                Error::RegistrationFormNotFound
+            }
+            submit_pending_project_registration_form_answer_draft::Error::PendingProjectNotFound => {
+                Error::PendingProjectNotFound
+            }
+            submit_pending_project_registration_form_answer_draft::Error::RegistrationFormAnswerDraftNotFound => {
+                Error::RegistrationFormAnswerDraftNotFound
+            }
+            submit_pending_project_registration_form_answer_draft::Error::AlreadyAnswered => {
+                Error::AlreadyAnsweredRegistrationForm
+            }
+            submit_pending_project_registration_form_answer_draft::Error::OutOfProjectCreationPeriod => {
+                Error::OutOfProjectCreationPeriod
+            }
+            submit_pending_project_registration_form_answer_draft::Error::InvalidAnswer(err) => {
+                match err {
+                    interface::form::CheckAnswerError::MismatchedItemsLength => {
+                        Error::MismatchedFormItemsLength
+                    }
+                    interface::form::CheckAnswerError::MismatchedItemId { expected, got } => {
+                        Error::MismatchedFormItemId {
+                            expected: FormItemId::from_use_case(expected),
+                            got: FormItemId::from_use_case(got),
+                        }
+                    }
+                    // TODO: break down invalid answer errors
+                    interface::form::CheckAnswerError::InvalidAnswerItem { item_id, .. } => {
+                        Error::InvalidFormAnswerItem {
+                            id: FormItemId::from_use_case(item_id),
+                        }
+                    }
+                }
+            }
+            submit_pending_project_registration_form_answer_draft::Error::InsufficientPermissions => {
+                Error::InsufficientPermissions
+            }
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let input = submit_pending_project_registration_form_answer_draft::Input {
+        pending_project_id: request.pending_project_id.into_use_case(),
+        registration_form_id: request.registration_form_id.into_use_case(),
+    };
+    let answer = submit_pending_project_registration_form_answer_draft::run(&ctx, input).await?;
+    crate::metrics::record_registration_form_answer_submitted();
+    let answer = RegistrationFormAnswer::from_use_case(answer);
+    Ok(Response { answer })
+}
@@ -0,0 +1,74 @@
+use crate::app::Context;
+use crate::handler::model::date_time::DateTime;
+use crate::handler::model::pending_project::{PendingProject, PendingProjectId};
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::update_pending_project_deadline;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    pub id: PendingProjectId,
+    pub exceptional_complete_deadline: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub pending_project: PendingProject,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::OK
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    PendingProjectNotFound,
+    DeadlineNotAfterCreationPeriod,
+    InsufficientPermissions,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::PendingProjectNotFound => StatusCode::NOT_FOUND,
+            Error::DeadlineNotAfterCreationPeriod => StatusCode::CONFLICT,
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
+impl From<update_pending_project_deadline::Error> for Error {
+    fn from(err: update_pending_project_deadline::Error) -> Error {
+        match err {
+            update_pending_project_deadline::Error::NotFound => Error::PendingProjectNotFound,
+            update_pending_project_deadline::Error::NotAfterCreationPeriod => {
+                Error::DeadlineNotAfterCreationPeriod
+            }
+            update_pending_project_deadline::Error::InsufficientPermissions => {
+                Error::InsufficientPermissions
+            }
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let input = update_pending_project_deadline::Input {
+        id: request.id.into_use_case(),
+        exceptional_complete_deadline: request
+            .exceptional_complete_deadline
+            .map(DateTime::into_use_case),
+    };
+    let pending_project = update_pending_project_deadline::run(&ctx, input).await?;
+    let pending_project = PendingProject::from_use_case(pending_project);
+    Ok(Response { pending_project })
+}
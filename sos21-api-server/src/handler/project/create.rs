@@ -6,25 +6,27 @@ use crate::handler::{HandlerResponse, HandlerResult};
 use serde::{Deserialize, Serialize};
 use sos21_domain::context::Login;
 use sos21_use_case::create_project;
+use utoipa::ToSchema;
 use warp::http::StatusCode;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, ToSchema)]
 pub struct Request {
     pub pending_project_id: PendingProjectId,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct Response {
     pub project: Project,
 }
 
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
 impl HandlerResponse for Response {
     fn status_code(&self) -> StatusCode {
         StatusCode::CREATED
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
 pub enum Error {
     PendingProjectNotFound,
@@ -36,8 +38,10 @@ pub enum Error {
     AlreadyProjectSubowner,
     AlreadyPendingProjectOwner,
     OutOfProjectCreationPeriod,
+    InsufficientPermissions,
 }
 
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
 impl HandlerResponse for Error {
     fn status_code(&self) -> StatusCode {
         match self {
@@ -50,6 +54,7 @@ impl HandlerResponse for Error {
             Error::AlreadyProjectSubowner => StatusCode::CONFLICT,
             Error::AlreadyPendingProjectOwner => StatusCode::CONFLICT,
             Error::OutOfProjectCreationPeriod => StatusCode::CONFLICT,
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
         }
     }
 }
@@ -68,6 +73,7 @@ impl From<create_project::Error> for Error {
             create_project::Error::AlreadyProjectSubowner => Error::AlreadyProjectSubowner,
             create_project::Error::AlreadyPendingProjectOwner => Error::AlreadyPendingProjectOwner,
             create_project::Error::OutOfCreationPeriod => Error::OutOfProjectCreationPeriod,
+            create_project::Error::InsufficientPermissions => Error::InsufficientPermissions,
         }
     }
 }
@@ -76,6 +82,7 @@ impl From<create_project::Error> for Error {
 pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
     let pending_project_id = request.pending_project_id.into_use_case();
     let project = create_project::run(&ctx, pending_project_id).await?;
+    crate::metrics::record_project_created();
     let project = Project::from_use_case(project);
     Ok(Response { project })
 }
@@ -1,13 +1,17 @@
 use crate::app::Context;
+use crate::handler::model::festival::FestivalId;
 use crate::handler::{HandlerResponse, HandlerResult};
 
 use serde::{Deserialize, Serialize};
 use sos21_domain::context::Login;
 use sos21_use_case::export_projects;
+use utoipa::ToSchema;
 use warp::http::StatusCode;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, ToSchema)]
 pub struct Request {
+    #[serde(default)]
+    pub festival_id: Option<FestivalId>,
     #[serde(default)]
     pub field_id: Option<String>,
     #[serde(default)]
@@ -73,12 +77,13 @@ pub struct Request {
     pub category_stage: String,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
 pub enum Error {
     InsufficientPermissions,
 }
 
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
 impl HandlerResponse for Error {
     fn status_code(&self) -> StatusCode {
         match self {
@@ -102,6 +107,7 @@ pub async fn handler(
 ) -> HandlerResult<impl warp::Reply, Error> {
     let input = {
         let Request {
+            festival_id,
             field_id,
             field_code,
             field_created_at,
@@ -176,6 +182,7 @@ pub async fn handler(
             stage: category_stage,
         };
         export_projects::Input {
+            festival_id: festival_id.map(FestivalId::into_use_case),
             field_names,
             category_names,
         }
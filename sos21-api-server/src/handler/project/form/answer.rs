@@ -9,8 +9,10 @@ use crate::handler::{HandlerResponse, HandlerResult};
 use serde::{Deserialize, Serialize};
 use sos21_domain::context::Login;
 use sos21_use_case::{answer_form, interface};
+use utoipa::ToSchema;
 use warp::http::StatusCode;
 
+pub mod draft;
 pub mod file_sharing;
 
 pub mod get;
@@ -18,25 +20,26 @@ pub use get::handler as get;
 pub mod update;
 pub use update::handler as update;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, ToSchema)]
 pub struct Request {
     pub project_id: ProjectId,
     pub form_id: FormId,
     pub items: Vec<RequestFormAnswerItem>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct Response {
     pub answer: FormAnswer,
 }
 
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
 impl HandlerResponse for Response {
     fn status_code(&self) -> StatusCode {
         StatusCode::CREATED
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
 pub enum Error {
     FormNotFound,
@@ -59,6 +62,7 @@ pub enum Error {
     NotificationFailed,
 }
 
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
 impl HandlerResponse for Error {
     fn status_code(&self) -> StatusCode {
         match self {
@@ -130,6 +134,7 @@ pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Res
             .collect(),
     };
     let answer = answer_form::run(&ctx, input).await?;
+    crate::metrics::record_form_answer_submitted();
     let answer = FormAnswer::from_use_case(answer);
     Ok(Response { answer })
 }
@@ -0,0 +1,6 @@
+pub mod get;
+pub use get::handler as get;
+pub mod save;
+pub use save::handler as save;
+pub mod submit;
+pub use submit::handler as submit;
@@ -0,0 +1,70 @@
+use crate::app::Context;
+use crate::handler::model::{form::FormId, form_answer::FormAnswerDraft, project::ProjectId};
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::get_project_form_answer_draft;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    pub project_id: ProjectId,
+    pub form_id: FormId,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub draft: FormAnswerDraft,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::OK
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    ProjectNotFound,
+    FormNotFound,
+    FormAnswerDraftNotFound,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::ProjectNotFound => StatusCode::NOT_FOUND,
+            Error::FormNotFound => StatusCode::NOT_FOUND,
+            Error::FormAnswerDraftNotFound => StatusCode::NOT_FOUND,
+        }
+    }
+}
+
+impl From<get_project_form_answer_draft::Error> for Error {
+    fn from(err: get_project_form_answer_draft::Error) -> Error {
+        match err {
+            get_project_form_answer_draft::Error::ProjectNotFound => Error::ProjectNotFound,
+            get_project_form_answer_draft::Error::FormNotFound => Error::FormNotFound,
+            get_project_form_answer_draft::Error::FormAnswerDraftNotFound => {
+                Error::FormAnswerDraftNotFound
+            }
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let draft = get_project_form_answer_draft::run(
+        &ctx,
+        request.project_id.into_use_case(),
+        request.form_id.into_use_case(),
+    )
+    .await?;
+    let draft = FormAnswerDraft::from_use_case(draft);
+    Ok(Response { draft })
+}
@@ -0,0 +1,98 @@
+use crate::app::Context;
+use crate::handler::model::{
+    form::{FormId, FormItemId},
+    form_answer::{item::RequestFormAnswerItem, FormAnswerDraft},
+    project::ProjectId,
+};
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::{interface, save_project_form_answer_draft};
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    pub project_id: ProjectId,
+    pub form_id: FormId,
+    pub items: Vec<RequestFormAnswerItem>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub draft: FormAnswerDraft,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::OK
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    FormNotFound,
+    ProjectNotFound,
+    OutOfAnswerPeriod,
+    AlreadyAnsweredForm,
+    NoFormItems,
+    TooManyFormItems,
+    InvalidFormItem { id: FormItemId },
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::FormNotFound => StatusCode::NOT_FOUND,
+            Error::ProjectNotFound => StatusCode::NOT_FOUND,
+            Error::OutOfAnswerPeriod => StatusCode::CONFLICT,
+            Error::AlreadyAnsweredForm => StatusCode::CONFLICT,
+            Error::NoFormItems => StatusCode::BAD_REQUEST,
+            Error::TooManyFormItems => StatusCode::BAD_REQUEST,
+            Error::InvalidFormItem { .. } => StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
+impl From<save_project_form_answer_draft::Error> for Error {
+    fn from(err: save_project_form_answer_draft::Error) -> Error {
+        match err {
+            save_project_form_answer_draft::Error::FormNotFound => Error::FormNotFound,
+            save_project_form_answer_draft::Error::ProjectNotFound => Error::ProjectNotFound,
+            save_project_form_answer_draft::Error::OutOfAnswerPeriod => Error::OutOfAnswerPeriod,
+            save_project_form_answer_draft::Error::AlreadyAnswered => Error::AlreadyAnsweredForm,
+            save_project_form_answer_draft::Error::InvalidItems(err) => match err {
+                interface::form_answer::FormAnswerItemsError::NoItems => Error::NoFormItems,
+                interface::form_answer::FormAnswerItemsError::TooManyItems => {
+                    Error::TooManyFormItems
+                }
+                // TODO: break down invalid item errors
+                interface::form_answer::FormAnswerItemsError::InvalidItem(id, _) => {
+                    Error::InvalidFormItem {
+                        id: FormItemId::from_use_case(id),
+                    }
+                }
+            },
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let input = save_project_form_answer_draft::Input {
+        project_id: request.project_id.into_use_case(),
+        form_id: request.form_id.into_use_case(),
+        items: request
+            .items
+            .into_iter()
+            .map(RequestFormAnswerItem::into_use_case)
+            .collect(),
+    };
+    let draft = save_project_form_answer_draft::run(&ctx, input).await?;
+    let draft = FormAnswerDraft::from_use_case(draft);
+    Ok(Response { draft })
+}
@@ -0,0 +1,113 @@
+use crate::app::Context;
+use crate::handler::model::{
+    form::{FormId, FormItemId},
+    form_answer::FormAnswer,
+    project::ProjectId,
+};
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::{interface, submit_project_form_answer_draft};
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    pub project_id: ProjectId,
+    pub form_id: FormId,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub answer: FormAnswer,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::CREATED
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    FormNotFound,
+    ProjectNotFound,
+    FormAnswerDraftNotFound,
+    OutOfAnswerPeriod,
+    AlreadyAnsweredForm,
+    MismatchedFormItemsLength,
+    MismatchedFormItemId {
+        expected: FormItemId,
+        got: FormItemId,
+    },
+    InvalidFormAnswer {
+        id: FormItemId,
+    },
+    NotificationFailed,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::FormNotFound => StatusCode::NOT_FOUND,
+            Error::ProjectNotFound => StatusCode::NOT_FOUND,
+            Error::FormAnswerDraftNotFound => StatusCode::NOT_FOUND,
+            Error::OutOfAnswerPeriod => StatusCode::CONFLICT,
+            Error::AlreadyAnsweredForm => StatusCode::CONFLICT,
+            Error::MismatchedFormItemsLength => StatusCode::BAD_REQUEST,
+            Error::MismatchedFormItemId { .. } => StatusCode::BAD_REQUEST,
+            Error::InvalidFormAnswer { .. } => StatusCode::BAD_REQUEST,
+            Error::NotificationFailed => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl From<submit_project_form_answer_draft::Error> for Error {
+    fn from(err: submit_project_form_answer_draft::Error) -> Error {
+        match err {
+            submit_project_form_answer_draft::Error::FormNotFound => Error::FormNotFound,
+            submit_project_form_answer_draft::Error::ProjectNotFound => Error::ProjectNotFound,
+            submit_project_form_answer_draft::Error::FormAnswerDraftNotFound => {
+                Error::FormAnswerDraftNotFound
+            }
+            submit_project_form_answer_draft::Error::OutOfAnswerPeriod => Error::OutOfAnswerPeriod,
+            submit_project_form_answer_draft::Error::AlreadyAnswered => Error::AlreadyAnsweredForm,
+            submit_project_form_answer_draft::Error::InvalidAnswer(err) => match err {
+                interface::form::CheckAnswerError::MismatchedItemsLength => {
+                    Error::MismatchedFormItemsLength
+                }
+                interface::form::CheckAnswerError::MismatchedItemId { expected, got } => {
+                    Error::MismatchedFormItemId {
+                        expected: FormItemId::from_use_case(expected),
+                        got: FormItemId::from_use_case(got),
+                    }
+                }
+                // TODO: break down invalid answer errors
+                interface::form::CheckAnswerError::InvalidAnswerItem { item_id, .. } => {
+                    Error::InvalidFormAnswer {
+                        id: FormItemId::from_use_case(item_id),
+                    }
+                }
+            },
+            submit_project_form_answer_draft::Error::NotificationFailed => {
+                Error::NotificationFailed
+            }
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let input = submit_project_form_answer_draft::Input {
+        project_id: request.project_id.into_use_case(),
+        form_id: request.form_id.into_use_case(),
+    };
+    let answer = submit_project_form_answer_draft::run(&ctx, input).await?;
+    crate::metrics::record_form_answer_submitted();
+    let answer = FormAnswer::from_use_case(answer);
+    Ok(Response { answer })
+}
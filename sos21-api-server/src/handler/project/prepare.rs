@@ -6,9 +6,10 @@ use crate::handler::{HandlerResponse, HandlerResult};
 use serde::{Deserialize, Serialize};
 use sos21_domain::context::Login;
 use sos21_use_case::prepare_project;
+use utoipa::ToSchema;
 use warp::http::StatusCode;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, ToSchema)]
 pub struct Request {
     pub name: String,
     pub kana_name: String,
@@ -19,18 +20,19 @@ pub struct Request {
     pub attributes: Vec<ProjectAttribute>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct Response {
     pub pending_project: PendingProject,
 }
 
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
 impl HandlerResponse for Response {
     fn status_code(&self) -> StatusCode {
         StatusCode::CREATED
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
 pub enum Error {
     InvalidField { field: &'static str },
@@ -40,8 +42,10 @@ pub enum Error {
     AlreadyPendingProjectOwner,
     OutOfProjectCreationPeriod,
     ArtisticStageProject,
+    InsufficientPermissions,
 }
 
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
 impl HandlerResponse for Error {
     fn status_code(&self) -> StatusCode {
         match self {
@@ -52,6 +56,7 @@ impl HandlerResponse for Error {
             Error::AlreadyPendingProjectOwner => StatusCode::CONFLICT,
             Error::OutOfProjectCreationPeriod => StatusCode::CONFLICT,
             Error::ArtisticStageProject => StatusCode::CONFLICT,
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
         }
     }
 }
@@ -76,6 +81,7 @@ impl From<prepare_project::Error> for Error {
             prepare_project::Error::AlreadyPendingProjectOwner => Error::AlreadyPendingProjectOwner,
             prepare_project::Error::OutOfCreationPeriod => Error::OutOfProjectCreationPeriod,
             prepare_project::Error::ArtisticStageProject => Error::ArtisticStageProject,
+            prepare_project::Error::InsufficientPermissions => Error::InsufficientPermissions,
         }
     }
 }
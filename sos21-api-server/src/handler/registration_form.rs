@@ -1,7 +1,13 @@
+pub mod access_control;
 pub mod answer;
+pub mod definition;
 
 pub mod create;
 pub use create::handler as create;
+pub mod create_from_template;
+pub use create_from_template::handler as create_from_template;
+pub mod duplicate;
+pub use duplicate::handler as duplicate;
 pub mod get;
 pub use get::handler as get;
 pub mod list;
@@ -0,0 +1,93 @@
+use crate::app::Context;
+use crate::handler::model::access_control::{AccessControlEntry, AccessControlGrantee};
+use crate::handler::model::registration_form::RegistrationFormId;
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::update_registration_form_access_control;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    pub registration_form_id: RegistrationFormId,
+    pub entries: Vec<AccessControlEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub entries: Vec<AccessControlEntry>,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::OK
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    RegistrationFormNotFound,
+    GranteeNotFound { grantee: AccessControlGrantee },
+    DuplicatedGrantee { grantee: AccessControlGrantee },
+    TooManyEntries,
+    InsufficientPermissions,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::RegistrationFormNotFound => StatusCode::NOT_FOUND,
+            Error::GranteeNotFound { .. } => StatusCode::NOT_FOUND,
+            Error::DuplicatedGrantee { .. } => StatusCode::BAD_REQUEST,
+            Error::TooManyEntries => StatusCode::BAD_REQUEST,
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
+impl From<update_registration_form_access_control::Error> for Error {
+    fn from(err: update_registration_form_access_control::Error) -> Error {
+        match err {
+            update_registration_form_access_control::Error::NotFound => {
+                Error::RegistrationFormNotFound
+            }
+            update_registration_form_access_control::Error::GranteeNotFound(grantee) => {
+                Error::GranteeNotFound {
+                    grantee: AccessControlGrantee::from_use_case(grantee),
+                }
+            }
+            update_registration_form_access_control::Error::DuplicatedGrantee(grantee) => {
+                Error::DuplicatedGrantee {
+                    grantee: AccessControlGrantee::from_use_case(grantee),
+                }
+            }
+            update_registration_form_access_control::Error::TooManyEntries => Error::TooManyEntries,
+            update_registration_form_access_control::Error::InsufficientPermissions => {
+                Error::InsufficientPermissions
+            }
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let input = update_registration_form_access_control::Input {
+        registration_form_id: request.registration_form_id.into_use_case(),
+        entries: request
+            .entries
+            .into_iter()
+            .map(AccessControlEntry::into_use_case)
+            .collect(),
+    };
+    let entries = update_registration_form_access_control::run(&ctx, input).await?;
+    let entries = entries
+        .into_iter()
+        .map(AccessControlEntry::from_use_case)
+        .collect();
+    Ok(Response { entries })
+}
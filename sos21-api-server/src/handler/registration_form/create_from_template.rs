@@ -0,0 +1,85 @@
+use crate::app::Context;
+use crate::handler::model::form_template::FormTemplateId;
+use crate::handler::model::registration_form::RegistrationForm;
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::create_registration_form_from_template;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    pub form_template_id: FormTemplateId,
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub registration_form: RegistrationForm,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::CREATED
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    FormTemplateNotFound,
+    InvalidField { field: &'static str },
+    InsufficientPermissions,
+    AlreadyStartedProjectCreationPeriod,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::FormTemplateNotFound => StatusCode::NOT_FOUND,
+            Error::InvalidField { .. } => StatusCode::BAD_REQUEST,
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
+            Error::AlreadyStartedProjectCreationPeriod => StatusCode::CONFLICT,
+        }
+    }
+}
+
+impl From<create_registration_form_from_template::Error> for Error {
+    fn from(err: create_registration_form_from_template::Error) -> Error {
+        match err {
+            create_registration_form_from_template::Error::TemplateNotFound => {
+                Error::FormTemplateNotFound
+            }
+            create_registration_form_from_template::Error::InvalidName => {
+                Error::InvalidField { field: "name" }
+            }
+            create_registration_form_from_template::Error::InvalidDescription => {
+                Error::InvalidField {
+                    field: "description",
+                }
+            }
+            create_registration_form_from_template::Error::InsufficientPermissions => {
+                Error::InsufficientPermissions
+            }
+            create_registration_form_from_template::Error::AlreadyStartedProjectCreationPeriod => {
+                Error::AlreadyStartedProjectCreationPeriod
+            }
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let input = create_registration_form_from_template::Input {
+        template_id: request.form_template_id.into_use_case(),
+        name: request.name,
+    };
+    let registration_form = create_registration_form_from_template::run(&ctx, input).await?;
+    let registration_form = RegistrationForm::from_use_case(registration_form);
+    Ok(Response { registration_form })
+}
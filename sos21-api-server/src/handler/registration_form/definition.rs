@@ -0,0 +1,6 @@
+pub mod export;
+pub use export::handler as export;
+pub mod import;
+pub use import::handler as import;
+pub mod validate;
+pub use validate::handler as validate;
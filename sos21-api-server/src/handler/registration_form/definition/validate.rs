@@ -0,0 +1,72 @@
+use crate::app::Context;
+use crate::handler::model::form_definition::{FormDefinitionError, FormDefinitionFormat};
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::model::form_definition::FormDefinitionKind;
+use sos21_use_case::validate_form_definition;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    #[serde(default)]
+    pub format: FormDefinitionFormat,
+    pub definition: String,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub errors: Vec<FormDefinitionError>,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::OK
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    InsufficientPermissions,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
+impl From<validate_form_definition::Error> for Error {
+    fn from(err: validate_form_definition::Error) -> Error {
+        match err {
+            validate_form_definition::Error::InsufficientPermissions => {
+                Error::InsufficientPermissions
+            }
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let definition = match request.format.parse(&request.definition) {
+        Ok(definition) => definition,
+        Err(err) => return Ok(Response { errors: vec![err] }),
+    };
+    let input = validate_form_definition::Input {
+        kind: FormDefinitionKind::RegistrationForm,
+        definition: definition.into_use_case(),
+    };
+    let errors = validate_form_definition::run(&ctx, input).await?;
+    let errors = errors
+        .into_iter()
+        .map(FormDefinitionError::from_use_case)
+        .collect();
+    Ok(Response { errors })
+}
@@ -0,0 +1,77 @@
+use crate::app::Context;
+use crate::handler::model::registration_form::{RegistrationForm, RegistrationFormId};
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::duplicate_registration_form;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    pub registration_form_id: RegistrationFormId,
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub registration_form: RegistrationForm,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::CREATED
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    RegistrationFormNotFound,
+    InvalidField { field: &'static str },
+    InsufficientPermissions,
+    AlreadyStartedProjectCreationPeriod,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::RegistrationFormNotFound => StatusCode::NOT_FOUND,
+            Error::InvalidField { .. } => StatusCode::BAD_REQUEST,
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
+            Error::AlreadyStartedProjectCreationPeriod => StatusCode::CONFLICT,
+        }
+    }
+}
+
+impl From<duplicate_registration_form::Error> for Error {
+    fn from(err: duplicate_registration_form::Error) -> Error {
+        match err {
+            duplicate_registration_form::Error::NotFound => Error::RegistrationFormNotFound,
+            duplicate_registration_form::Error::InvalidName => {
+                Error::InvalidField { field: "name" }
+            }
+            duplicate_registration_form::Error::InsufficientPermissions => {
+                Error::InsufficientPermissions
+            }
+            duplicate_registration_form::Error::AlreadyStartedProjectCreationPeriod => {
+                Error::AlreadyStartedProjectCreationPeriod
+            }
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let input = duplicate_registration_form::Input {
+        registration_form_id: request.registration_form_id.into_use_case(),
+        name: request.name,
+    };
+    let registration_form = duplicate_registration_form::run(&ctx, input).await?;
+    let registration_form = RegistrationForm::from_use_case(registration_form);
+    Ok(Response { registration_form })
+}
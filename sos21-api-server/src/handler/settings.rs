@@ -0,0 +1,6 @@
+pub mod revision;
+
+pub mod get;
+pub use get::handler as get;
+pub mod update;
+pub use update::handler as update;
@@ -0,0 +1,2 @@
+pub mod list;
+pub use list::handler as list;
@@ -0,0 +1,86 @@
+use crate::app::Context;
+use crate::handler::model::festival::FestivalId;
+use crate::handler::model::project::ProjectCategory;
+use crate::handler::model::settings::{ProjectCreationPeriodEntry, Settings};
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::update_settings;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    #[serde(default)]
+    pub administrator_email: Option<String>,
+    #[serde(default)]
+    pub active_festival_id: Option<FestivalId>,
+    #[serde(default)]
+    pub project_creation_periods: Vec<ProjectCreationPeriodEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub settings: Settings,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::OK
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    InsufficientPermissions,
+    InvalidAdministratorEmail,
+    InvalidProjectCreationPeriod { category: ProjectCategory },
+    FestivalNotFound,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
+            Error::FestivalNotFound => StatusCode::NOT_FOUND,
+            Error::InvalidAdministratorEmail | Error::InvalidProjectCreationPeriod { .. } => {
+                StatusCode::BAD_REQUEST
+            }
+        }
+    }
+}
+
+impl From<update_settings::Error> for Error {
+    fn from(err: update_settings::Error) -> Error {
+        match err {
+            update_settings::Error::InsufficientPermissions => Error::InsufficientPermissions,
+            update_settings::Error::InvalidAdministratorEmail => Error::InvalidAdministratorEmail,
+            update_settings::Error::FestivalNotFound => Error::FestivalNotFound,
+            update_settings::Error::InvalidProjectCreationPeriod(category) => {
+                Error::InvalidProjectCreationPeriod {
+                    category: ProjectCategory::from_use_case(category),
+                }
+            }
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let input = update_settings::Input {
+        administrator_email: request.administrator_email,
+        active_festival_id: request.active_festival_id.map(FestivalId::into_use_case),
+        project_creation_periods: request
+            .project_creation_periods
+            .into_iter()
+            .map(ProjectCreationPeriodEntry::into_use_case)
+            .collect(),
+    };
+    let settings = update_settings::run(&ctx, input).await?;
+    let settings = Settings::from_use_case(settings);
+    Ok(Response { settings })
+}
@@ -6,3 +6,11 @@ pub mod update;
 pub use update::handler as update;
 pub mod export;
 pub use export::handler as export;
+pub mod set_custom_role;
+pub use set_custom_role::handler as set_custom_role;
+pub mod suspend;
+pub use suspend::handler as suspend;
+pub mod unsuspend;
+pub use unsuspend::handler as unsuspend;
+pub mod revoke_tokens;
+pub use revoke_tokens::handler as revoke_tokens;
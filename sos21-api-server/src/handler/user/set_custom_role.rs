@@ -0,0 +1,69 @@
+use crate::app::Context;
+use crate::handler::model::custom_role::CustomRoleId;
+use crate::handler::model::user::{User, UserId};
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::set_user_custom_role;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    pub id: UserId,
+    #[serde(default)]
+    pub custom_role_id: Option<CustomRoleId>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub user: User,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::OK
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    UserNotFound,
+    CustomRoleNotFound,
+    InsufficientPermissions,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::UserNotFound => StatusCode::NOT_FOUND,
+            Error::CustomRoleNotFound => StatusCode::NOT_FOUND,
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
+impl From<set_user_custom_role::Error> for Error {
+    fn from(err: set_user_custom_role::Error) -> Error {
+        match err {
+            set_user_custom_role::Error::UserNotFound => Error::UserNotFound,
+            set_user_custom_role::Error::CustomRoleNotFound => Error::CustomRoleNotFound,
+            set_user_custom_role::Error::InsufficientPermissions => Error::InsufficientPermissions,
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let input = set_user_custom_role::Input {
+        user_id: request.id.into_use_case(),
+        custom_role_id: request.custom_role_id.map(CustomRoleId::into_use_case),
+    };
+    let user = set_user_custom_role::run(&ctx, input).await?;
+    let user = User::from_use_case(user);
+    Ok(Response { user })
+}
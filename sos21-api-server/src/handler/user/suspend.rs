@@ -0,0 +1,73 @@
+use crate::app::Context;
+use crate::handler::model::user::{User, UserId};
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::suspend_user;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    pub id: UserId,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub user: User,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::OK
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    UserNotFound,
+    InvalidReason,
+    SuspendingSelf,
+    AlreadySuspended,
+    InsufficientPermissions,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::UserNotFound => StatusCode::NOT_FOUND,
+            Error::InvalidReason => StatusCode::BAD_REQUEST,
+            Error::SuspendingSelf => StatusCode::CONFLICT,
+            Error::AlreadySuspended => StatusCode::CONFLICT,
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
+impl From<suspend_user::Error> for Error {
+    fn from(err: suspend_user::Error) -> Error {
+        match err {
+            suspend_user::Error::UserNotFound => Error::UserNotFound,
+            suspend_user::Error::InvalidReason => Error::InvalidReason,
+            suspend_user::Error::SuspendingSelf => Error::SuspendingSelf,
+            suspend_user::Error::AlreadySuspended => Error::AlreadySuspended,
+            suspend_user::Error::InsufficientPermissions => Error::InsufficientPermissions,
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let input = suspend_user::Input {
+        user_id: request.id.into_use_case(),
+        reason: request.reason,
+    };
+    let user = suspend_user::run(&ctx, input).await?;
+    let user = User::from_use_case(user);
+    Ok(Response { user })
+}
@@ -0,0 +1,62 @@
+use crate::app::Context;
+use crate::handler::model::user::{User, UserId};
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::unsuspend_user;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    pub id: UserId,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub user: User,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::OK
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    UserNotFound,
+    NotSuspended,
+    InsufficientPermissions,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::UserNotFound => StatusCode::NOT_FOUND,
+            Error::NotSuspended => StatusCode::CONFLICT,
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
+impl From<unsuspend_user::Error> for Error {
+    fn from(err: unsuspend_user::Error) -> Error {
+        match err {
+            unsuspend_user::Error::UserNotFound => Error::UserNotFound,
+            unsuspend_user::Error::NotSuspended => Error::NotSuspended,
+            unsuspend_user::Error::InsufficientPermissions => Error::InsufficientPermissions,
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let user = unsuspend_user::run(&ctx, request.id.into_use_case()).await?;
+    let user = User::from_use_case(user);
+    Ok(Response { user })
+}
@@ -4,3 +4,5 @@ pub mod list;
 pub use list::handler as list;
 pub mod delete;
 pub use delete::handler as delete;
+pub mod resend;
+pub use resend::handler as resend;
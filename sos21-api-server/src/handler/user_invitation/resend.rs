@@ -0,0 +1,74 @@
+use crate::app::Context;
+use crate::handler::model::date_time::DateTime;
+use crate::handler::model::user_invitation::{UserInvitation, UserInvitationId};
+use crate::handler::{HandlerResponse, HandlerResult};
+
+use serde::{Deserialize, Serialize};
+use sos21_domain::context::Login;
+use sos21_use_case::resend_user_invitation;
+use utoipa::ToSchema;
+use warp::http::StatusCode;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct Request {
+    pub invitation_id: UserInvitationId,
+    #[serde(default)]
+    pub expires_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Response {
+    pub invitation: UserInvitation,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Response {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::OK
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "type")]
+pub enum Error {
+    UserInvitationNotFound,
+    AlreadyAcceptedUserInvitation,
+    PastExpiry,
+    InsufficientPermissions,
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler_response!)]
+impl HandlerResponse for Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::UserInvitationNotFound => StatusCode::NOT_FOUND,
+            Error::AlreadyAcceptedUserInvitation => StatusCode::CONFLICT,
+            Error::PastExpiry => StatusCode::BAD_REQUEST,
+            Error::InsufficientPermissions => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
+impl From<resend_user_invitation::Error> for Error {
+    fn from(err: resend_user_invitation::Error) -> Error {
+        match err {
+            resend_user_invitation::Error::NotFound => Error::UserInvitationNotFound,
+            resend_user_invitation::Error::AlreadyAccepted => Error::AlreadyAcceptedUserInvitation,
+            resend_user_invitation::Error::PastExpiry => Error::PastExpiry,
+            resend_user_invitation::Error::InsufficientPermissions => {
+                Error::InsufficientPermissions
+            }
+        }
+    }
+}
+
+#[macro_rules_attribute::macro_rules_attribute(handler!)]
+pub async fn handler(ctx: Login<Context>, request: Request) -> HandlerResult<Response, Error> {
+    let input = resend_user_invitation::Input {
+        id: request.invitation_id.into_use_case(),
+        expires_at: request.expires_at.map(DateTime::into_use_case),
+    };
+    let invitation = resend_user_invitation::run(&ctx, input).await?;
+    let invitation = UserInvitation::from_use_case(invitation);
+    Ok(Response { invitation })
+}
@@ -1,9 +1,15 @@
 mod app;
 mod config;
+#[cfg(feature = "dev-auth")]
+mod dev_auth;
+mod metrics;
 mod server;
 
 pub mod filter;
 pub mod handler;
+pub mod openapi;
 
-pub use config::Config;
+#[cfg(feature = "dev-auth")]
+pub use config::DevAuthConfig;
+pub use config::{Config, RateLimit, RateLimitConfig};
 pub use server::Server;
@@ -1,12 +1,32 @@
 use std::net::SocketAddr;
+#[cfg(feature = "dev-auth")]
+use std::path::PathBuf;
 
 use anyhow::{Context, Result};
-use sos21_api_server::Config;
+use sos21_api_server::{Config, RateLimit, RateLimitConfig};
+use sos21_domain::model::user::email::{AllowedEmailDomains, EmailDomainRule};
 use structopt::StructOpt;
 use tokio::runtime;
 use tracing::{event, Level};
 use url::Url;
 
+#[derive(Debug, Clone, Copy)]
+enum LogFormat {
+    Pretty,
+    Json,
+}
+
+impl std::str::FromStr for LogFormat {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "pretty" => Ok(LogFormat::Pretty),
+            "json" => Ok(LogFormat::Json),
+            _ => Err(anyhow::anyhow!("unknown log format: {}", s)),
+        }
+    }
+}
+
 #[derive(Debug, StructOpt)]
 #[structopt(name = "sos21-api-server")]
 struct Opt {
@@ -38,6 +58,15 @@ struct Opt {
     s3_object_bucket: String,
     #[structopt(long, env = "SOS21_API_SERVER_ADMINISTRATOR_EMAIL")]
     administrator_email: String,
+    /// Comma-separated domains from which users can sign up.
+    /// `example.com` matches the domain exactly, and `.example.com` matches the domain and its subdomains.
+    #[structopt(
+        long,
+        default_value = ".tsukuba.ac.jp,.sohosai.com",
+        use_delimiter = true,
+        env = "SOS21_API_SERVER_ALLOWED_EMAIL_DOMAINS"
+    )]
+    allowed_email_domains: Vec<String>,
     #[structopt(long, env = "SOS21_API_SERVER_START_PROJECT_CREATION_PERIOD")]
     #[allow(dead_code)]
     start_project_creation_period: Option<i64>,
@@ -48,12 +77,104 @@ struct Opt {
     bind: SocketAddr,
     #[structopt(short, long, env = "SOS21_API_SERVER_ADMIN_REPORT_SLACK_WEBHOOK")]
     admin_report_slack_webhook: String,
+    /// Suspends the users signed in with the email addresses out of the allowed domains
+    /// and not invited as exceptions, in addition to reporting them.
+    #[structopt(
+        long,
+        default_value = "false",
+        parse(try_from_str),
+        env = "SOS21_API_SERVER_SUSPEND_SUSPICIOUS_USERS"
+    )]
+    suspend_suspicious_users: bool,
+    /// Bearer token required to read `/meta/metrics`, which is not served without it.
+    #[structopt(long, env = "SOS21_API_SERVER_METRICS_TOKEN", hide_env_values = true)]
+    metrics_token: Option<String>,
+    /// Requests per minute to the write endpoints allowed for each user.
+    /// The limits of `0` disable the rate limiting.
+    #[structopt(
+        long,
+        default_value = "120",
+        env = "SOS21_API_SERVER_RATE_LIMIT_WRITE_PER_USER"
+    )]
+    rate_limit_write_per_user: u32,
+    #[structopt(
+        long,
+        default_value = "1200",
+        env = "SOS21_API_SERVER_RATE_LIMIT_WRITE_PER_IP"
+    )]
+    rate_limit_write_per_ip: u32,
+    #[structopt(
+        long,
+        default_value = "30",
+        env = "SOS21_API_SERVER_RATE_LIMIT_UPLOAD_PER_USER"
+    )]
+    rate_limit_upload_per_user: u32,
+    #[structopt(
+        long,
+        default_value = "300",
+        env = "SOS21_API_SERVER_RATE_LIMIT_UPLOAD_PER_IP"
+    )]
+    rate_limit_upload_per_ip: u32,
+    #[structopt(
+        long,
+        default_value = "20",
+        env = "SOS21_API_SERVER_RATE_LIMIT_INVITATION_PER_USER"
+    )]
+    rate_limit_invitation_per_user: u32,
+    #[structopt(
+        long,
+        default_value = "200",
+        env = "SOS21_API_SERVER_RATE_LIMIT_INVITATION_PER_IP"
+    )]
+    rate_limit_invitation_per_ip: u32,
+    /// Takes the client IP for the rate limiting from `X-Forwarded-For` header.
+    /// Enable this only behind a reverse proxy which sets the header.
+    #[structopt(
+        long,
+        default_value = "false",
+        parse(try_from_str),
+        env = "SOS21_API_SERVER_TRUST_X_FORWARDED_FOR"
+    )]
+    trust_x_forwarded_for: bool,
+    /// Format of the logs. `json` writes one object per line with the fields of the current span.
+    #[structopt(
+        long,
+        default_value = "pretty",
+        possible_values = &["pretty", "json"],
+        env = "SOS21_API_SERVER_LOG_FORMAT"
+    )]
+    log_format: LogFormat,
+    /// Accepts tokens issued by the server itself instead of the keys from `jwt_keys_url`.
+    #[cfg(feature = "dev-auth")]
+    #[structopt(
+        long,
+        default_value = "false",
+        parse(try_from_str),
+        env = "SOS21_API_SERVER_DEV_AUTH"
+    )]
+    dev_auth: bool,
+    /// JWKS file of the additional keys to accept in the development authentication.
+    #[cfg(feature = "dev-auth")]
+    #[structopt(long, env = "SOS21_API_SERVER_DEV_JWKS_FILE")]
+    dev_jwks_file: Option<PathBuf>,
+    /// RSA private key in PEM to issue tokens in the development authentication.
+    /// A key pair is generated at startup if not specified.
+    #[cfg(feature = "dev-auth")]
+    #[structopt(long, env = "SOS21_API_SERVER_DEV_SIGNING_KEY_FILE")]
+    dev_signing_key_file: Option<PathBuf>,
 }
 
 fn main() {
     let opt = Opt::from_args();
 
-    tracing_subscriber::fmt().pretty().init();
+    match opt.log_format {
+        LogFormat::Pretty => tracing_subscriber::fmt().pretty().init(),
+        LogFormat::Json => tracing_subscriber::fmt()
+            .json()
+            .with_current_span(true)
+            .with_span_list(false)
+            .init(),
+    }
 
     if let Err(error) = run(opt) {
         event!(Level::ERROR, ?error);
@@ -78,6 +199,16 @@ fn run(opt: Opt) -> Result<()> {
         })
         .collect();
 
+    let allowed_email_domains = opt
+        .allowed_email_domains
+        .iter()
+        .map(|rule| {
+            EmailDomainRule::from_string(rule)
+                .with_context(|| format!("invalid allowed email domain: {}", rule))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let allowed_email_domains = AllowedEmailDomains::from_rules(allowed_email_domains);
+
     runtime.block_on(async move {
         let config = Config {
             jwt_audience: opt.jwt_audience,
@@ -91,8 +222,35 @@ fn run(opt: Opt) -> Result<()> {
             s3_endpoint: opt.s3_endpoint,
             s3_object_bucket: opt.s3_object_bucket,
             administrator_email: opt.administrator_email,
+            allowed_email_domains,
             admin_report_slack_webhook: opt.admin_report_slack_webhook,
+            suspend_suspicious_users: opt.suspend_suspicious_users,
+            metrics_token: opt.metrics_token,
             project_creation_periods,
+            rate_limit: RateLimitConfig {
+                write: RateLimit {
+                    per_user: opt.rate_limit_write_per_user,
+                    per_ip: opt.rate_limit_write_per_ip,
+                },
+                upload: RateLimit {
+                    per_user: opt.rate_limit_upload_per_user,
+                    per_ip: opt.rate_limit_upload_per_ip,
+                },
+                invitation: RateLimit {
+                    per_user: opt.rate_limit_invitation_per_user,
+                    per_ip: opt.rate_limit_invitation_per_ip,
+                },
+                trust_forwarded_for: opt.trust_x_forwarded_for,
+            },
+            #[cfg(feature = "dev-auth")]
+            dev_auth: if opt.dev_auth {
+                Some(sos21_api_server::DevAuthConfig {
+                    jwks_file: opt.dev_jwks_file,
+                    signing_key_file: opt.dev_signing_key_file,
+                })
+            } else {
+                None
+            },
         };
         let server = sos21_api_server::Server::new(config).await?;
         server.run(opt.bind).await;
@@ -0,0 +1,164 @@
+//! Prometheus metrics of the API server.
+//!
+//! Metrics are recorded through the [`metrics`] facade so that the other crates
+//! (e.g. `sos21-gateway-s3`) can record their own metrics without depending on the exporter.
+
+use std::convert::Infallible;
+use std::time::Instant;
+
+use crate::filter::model::ErrorBody;
+
+use metrics_exporter_prometheus::{Matcher, PrometheusBuilder, PrometheusHandle};
+use once_cell::sync::OnceCell;
+use sqlx::postgres::PgPool;
+use warp::{
+    http::{Method, Response},
+    hyper::Body,
+    path::FullPath,
+    Filter, Reply,
+};
+
+const HTTP_REQUEST_DURATION_BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+const S3_REQUEST_DURATION_BUCKETS: &[f64] = &[0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0];
+
+static HANDLE: OnceCell<PrometheusHandle> = OnceCell::new();
+
+/// Installs the global recorder, or does nothing if it is already installed.
+pub fn install() -> anyhow::Result<()> {
+    HANDLE.get_or_try_init(|| {
+        let handle = PrometheusBuilder::new()
+            .set_buckets_for_metric(
+                Matcher::Full("sos21_http_request_duration_seconds".to_owned()),
+                HTTP_REQUEST_DURATION_BUCKETS,
+            )?
+            .set_buckets_for_metric(
+                Matcher::Full("sos21_s3_request_duration_seconds".to_owned()),
+                S3_REQUEST_DURATION_BUCKETS,
+            )?
+            .install_recorder()?;
+        describe();
+        Ok::<_, anyhow::Error>(handle)
+    })?;
+    Ok(())
+}
+
+fn describe() {
+    metrics::describe_counter!(
+        "sos21_http_requests_total",
+        "The number of HTTP requests handled by each route"
+    );
+    metrics::describe_histogram!(
+        "sos21_http_request_duration_seconds",
+        metrics::Unit::Seconds,
+        "The time taken to handle HTTP requests by each route"
+    );
+    metrics::describe_counter!(
+        "sos21_rejections_total",
+        "The number of error responses by the kind of the error"
+    );
+    metrics::describe_gauge!(
+        "sos21_db_pool_connections",
+        "The number of connections in the database pool"
+    );
+    metrics::describe_gauge!(
+        "sos21_db_pool_idle_connections",
+        "The number of idle connections in the database pool"
+    );
+    metrics::describe_gauge!(
+        "sos21_db_pool_max_connections",
+        "The maximum number of connections in the database pool"
+    );
+    metrics::describe_counter!(
+        "sos21_projects_created_total",
+        "The number of projects created"
+    );
+    metrics::describe_counter!(
+        "sos21_form_answers_submitted_total",
+        "The number of form answers submitted"
+    );
+    metrics::describe_counter!(
+        "sos21_registration_form_answers_submitted_total",
+        "The number of registration form answers submitted"
+    );
+}
+
+/// Renders the metrics in the Prometheus text format.
+///
+/// Returns `None` if the recorder is not installed.
+pub fn render(pool: &PgPool, max_connections: u32) -> Option<String> {
+    let handle = HANDLE.get()?;
+    metrics::gauge!("sos21_db_pool_connections", pool.size() as f64);
+    metrics::gauge!("sos21_db_pool_idle_connections", pool.num_idle() as f64);
+    metrics::gauge!("sos21_db_pool_max_connections", max_connections as f64);
+    Some(handle.render())
+}
+
+pub fn record_rejection(body: &ErrorBody) {
+    let kind = match body {
+        ErrorBody::Api { .. } => "API",
+        ErrorBody::Authentication { .. } => "AUTHENTICATION",
+        ErrorBody::Request { .. } => "REQUEST",
+        ErrorBody::NotSignedUp => "NOT_SIGNED_UP",
+        ErrorBody::ServiceUnavailable { .. } => "SERVICE_UNAVAILABLE",
+        ErrorBody::Internal { .. } => "INTERNAL",
+    };
+    metrics::increment_counter!("sos21_rejections_total", "kind" => kind);
+}
+
+#[derive(Debug)]
+pub struct RouteTimer {
+    path: FullPath,
+    method: Method,
+    started_at: Instant,
+}
+
+impl RouteTimer {
+    pub fn finish(self, reply: impl Reply) -> Response<Body> {
+        let response = reply.into_response();
+        let path = self.path.as_str().to_owned();
+        let method = self.method.to_string();
+        let status = response.status().as_u16().to_string();
+        metrics::increment_counter!(
+            "sos21_http_requests_total",
+            "route" => path.clone(),
+            "method" => method.clone(),
+            "status" => status
+        );
+        metrics::histogram!(
+            "sos21_http_request_duration_seconds",
+            self.started_at.elapsed().as_secs_f64(),
+            "route" => path,
+            "method" => method
+        );
+        response
+    }
+}
+
+/// Starts a timer for the matched route.
+///
+/// This is intended to be used after the path of a route is fully matched,
+/// so that the path of the request can be used as the label of the route.
+pub fn route_timer() -> impl Filter<Extract = (RouteTimer,), Error = Infallible> + Clone {
+    warp::path::full()
+        .and(warp::method())
+        .map(|path, method| RouteTimer {
+            path,
+            method,
+            started_at: Instant::now(),
+        })
+}
+
+pub fn record_project_created() {
+    metrics::increment_counter!("sos21_projects_created_total");
+}
+
+pub fn record_form_answer_submitted() {
+    metrics::increment_counter!("sos21_form_answers_submitted_total");
+}
+
+pub fn record_registration_form_answer_submitted() {
+    metrics::increment_counter!("sos21_registration_form_answers_submitted_total");
+}
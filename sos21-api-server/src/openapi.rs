@@ -0,0 +1,595 @@
+//! OpenAPI document of the API server.
+//!
+//! The document is derived from the types in handler modules (`Request`, `Response` and `Error`,
+//! which derive [`ToSchema`]), the status codes in their [`HandlerResponse`] implementations,
+//! and the route table in [`crate::filter`]. Thus it cannot drift from the actual endpoints.
+
+use std::collections::BTreeMap;
+
+use crate::filter::model::Error as ErrorModel;
+use crate::handler::HandlerResponse;
+
+use once_cell::sync::OnceCell;
+use serde_json::{json, Map, Value};
+use tracing::{event, Level};
+use utoipa::{PartialSchema, ToSchema};
+use warp::http::StatusCode;
+
+/// How the request is passed to the handler, which corresponds to the ways in `route!`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Way {
+    Get,
+    Post,
+    PostStream,
+}
+
+/// An entry of the route table, which is generated from the route tree by `route_table!`.
+#[derive(Debug, Clone, Copy)]
+pub struct Route {
+    pub path: &'static str,
+    pub way: Way,
+    pub authenticated: bool,
+    pub rate_limited: bool,
+    pub operation: fn() -> Operation,
+}
+
+/// Description of a handler, which is generated by `handler!` and `raw_response_handler!`.
+#[derive(Debug, Clone)]
+pub struct Operation {
+    request: Option<Value>,
+    response: Option<(StatusCode, Value)>,
+    error: Value,
+    error_status_codes: Vec<(Option<&'static str>, StatusCode)>,
+    schemas: Vec<(String, Value)>,
+}
+
+impl Operation {
+    pub fn new<R, E>() -> Self
+    where
+        R: HandlerResponse + ToSchema,
+        E: HandlerResponse + ToSchema,
+    {
+        let mut operation = Operation::raw::<E>();
+        let status_code = match R::status_codes().as_slice() {
+            [(_, status_code)] => *status_code,
+            _ => panic!("response of handler must have exactly one status code"),
+        };
+        operation.response = Some((status_code, to_value(R::schema())));
+        collect_schemas::<R>(&mut operation.schemas);
+        operation
+    }
+
+    pub fn raw<E>() -> Self
+    where
+        E: HandlerResponse + ToSchema,
+    {
+        let mut schemas = Vec::new();
+        collect_schemas::<E>(&mut schemas);
+        Operation {
+            request: None,
+            response: None,
+            error: to_value(E::schema()),
+            error_status_codes: E::status_codes(),
+            schemas,
+        }
+    }
+
+    pub fn with_request<T>(mut self) -> Self
+    where
+        T: ToSchema,
+    {
+        self.request = Some(to_value(T::schema()));
+        collect_schemas::<T>(&mut self.schemas);
+        self
+    }
+}
+
+fn to_value<T: serde::Serialize>(x: T) -> Value {
+    serde_json::to_value(x).expect("schema is always serializable to JSON")
+}
+
+fn collect_schemas<T: ToSchema>(schemas: &mut Vec<(String, Value)>) {
+    let mut collected = Vec::new();
+    T::schemas(&mut collected);
+    schemas.extend(
+        collected
+            .into_iter()
+            .map(|(name, schema)| (name, to_value(schema))),
+    );
+}
+
+/// Returns the OpenAPI document of the endpoints served by [`crate::filter::endpoints`].
+pub fn document() -> &'static Value {
+    static DOCUMENT: OnceCell<Value> = OnceCell::new();
+    DOCUMENT.get_or_init(|| {
+        let generated = generate(&crate::filter::route_table());
+        if !generated.conflicting_schemas.is_empty() {
+            event!(
+                Level::WARN,
+                conflicting_schemas = ?generated.conflicting_schemas,
+                "Conflicting schemas in OpenAPI document"
+            );
+        }
+        generated.document
+    })
+}
+
+struct Generated {
+    document: Value,
+    /// Names of schemas that are defined more than once with different contents.
+    conflicting_schemas: Vec<String>,
+}
+
+fn generate(routes: &[Route]) -> Generated {
+    let mut schemas = BTreeMap::new();
+    let mut conflicting_schemas = Vec::new();
+    let mut add_schemas = |new_schemas: Vec<(String, Value)>| {
+        for (name, schema) in new_schemas {
+            match schemas.get(&name) {
+                Some(existing) if existing != &schema => conflicting_schemas.push(name),
+                Some(_) => {}
+                None => {
+                    schemas.insert(name, schema);
+                }
+            }
+        }
+    };
+
+    let mut error_schemas = vec![(
+        ErrorModel::name().into_owned(),
+        to_value(ErrorModel::schema()),
+    )];
+    collect_schemas::<ErrorModel>(&mut error_schemas);
+    add_schemas(error_schemas);
+
+    let mut paths = Map::new();
+    for route in routes {
+        let operation = (route.operation)();
+        let method = match route.way {
+            Way::Get => "get",
+            Way::Post | Way::PostStream => "post",
+        };
+        let object = operation_object(route, &operation);
+        add_schemas(operation.schemas);
+        paths.insert(route.path.to_owned(), json!({ method: object }));
+    }
+
+    let document = json!({
+        "openapi": "3.1.0",
+        "info": {
+            "title": env!("CARGO_PKG_NAME"),
+            "version": env!("CARGO_PKG_VERSION"),
+            "description": "RPC-style HTTP API server for SOS21.\nWe use only `GET` and `POST` methods in our API, for immutable and mutable endpoints respectively.",
+        },
+        "paths": paths,
+        "components": {
+            "schemas": schemas,
+            "securitySchemes": {
+                "token": {
+                    "type": "http",
+                    "scheme": "bearer",
+                    "description": "Firebase Authentication が返す `idToken`、または `api-key/create` で作成した API キーのトークンを渡してください。",
+                },
+            },
+        },
+    });
+
+    Generated {
+        document,
+        conflicting_schemas,
+    }
+}
+
+fn operation_object(route: &Route, operation: &Operation) -> Value {
+    let id = route.path.trim_start_matches('/');
+    let tag = id.split('/').next().unwrap_or_default().replace('-', "_");
+    let mut object = json!({
+        "operationId": id,
+        "summary": id,
+        "tags": [tag],
+        "responses": responses(route, operation),
+    });
+    if route.authenticated {
+        object["security"] = json!([{ "token": [] }]);
+    } else {
+        object["security"] = json!([]);
+    }
+
+    match (route.way, &operation.request) {
+        (Way::Get, Some(request)) => {
+            object["parameters"] = query_parameters(request, &operation.schemas);
+        }
+        (Way::Post, Some(request)) => {
+            object["requestBody"] = json!({
+                "required": true,
+                "content": { "application/json": { "schema": request } },
+            });
+        }
+        (Way::PostStream, _) => {
+            object["requestBody"] = json!({
+                "required": true,
+                "content": {
+                    "multipart/form-data": {
+                        "schema": { "type": "string", "format": "binary" },
+                    },
+                },
+            });
+        }
+        (_, None) => {}
+    }
+
+    object
+}
+
+/// Expands the properties of the request object into query parameters.
+fn query_parameters(request: &Value, schemas: &[(String, Value)]) -> Value {
+    let mut parameters = Vec::new();
+    collect_query_parameters(request, schemas, true, &mut parameters);
+    Value::Array(parameters)
+}
+
+fn collect_query_parameters(
+    schema: &Value,
+    schemas: &[(String, Value)],
+    required: bool,
+    parameters: &mut Vec<Value>,
+) {
+    if let Some(reference) = schema["$ref"].as_str() {
+        let name = reference.trim_start_matches("#/components/schemas/");
+        if let Some((_, schema)) = schemas.iter().find(|(n, _)| n == name) {
+            collect_query_parameters(schema, schemas, required, parameters);
+        }
+        return;
+    }
+
+    // Properties from `#[serde(flatten)]` enums are optional as a whole
+    for schema in schema["allOf"].as_array().into_iter().flatten() {
+        collect_query_parameters(schema, schemas, required, parameters);
+    }
+    for schema in schema["oneOf"].as_array().into_iter().flatten() {
+        collect_query_parameters(schema, schemas, false, parameters);
+    }
+
+    let required_names = schema["required"].as_array().cloned().unwrap_or_default();
+    for (name, schema) in schema["properties"].as_object().into_iter().flatten() {
+        parameters.push(json!({
+            "name": name,
+            "in": "query",
+            "required": required && required_names.contains(&Value::from(name.as_str())),
+            "schema": schema,
+        }));
+    }
+}
+
+fn responses(route: &Route, operation: &Operation) -> Value {
+    let mut responses = Map::new();
+
+    match &operation.response {
+        Some((status_code, schema)) => {
+            responses.insert(
+                status_code.as_str().to_owned(),
+                json!({
+                    "description": description(*status_code),
+                    "content": { "application/json": { "schema": schema } },
+                }),
+            );
+        }
+        None => {
+            responses.insert(
+                StatusCode::OK.as_str().to_owned(),
+                json!({
+                    "description": description(StatusCode::OK),
+                    "content": {
+                        "application/octet-stream": {
+                            "schema": { "type": "string", "format": "binary" },
+                        },
+                    },
+                }),
+            );
+        }
+    }
+
+    let mut errors: BTreeMap<StatusCode, Vec<Value>> = BTreeMap::new();
+    let mut api_errors: BTreeMap<StatusCode, Vec<String>> = BTreeMap::new();
+    for (variant, status_code) in &operation.error_status_codes {
+        let variant = variant.expect("error of handler must be an enum");
+        api_errors
+            .entry(*status_code)
+            .or_default()
+            .push(screaming_snake_case(variant));
+    }
+    for (status_code, types) in api_errors {
+        let info = error_info(&operation.error, &types);
+        errors
+            .entry(status_code)
+            .or_default()
+            .push(error_body("API", json!({ "info": info })));
+    }
+
+    let request_error = error_body(
+        "REQUEST",
+        json!({ "id": { "$ref": "#/components/schemas/RequestErrorId" } }),
+    );
+    let authentication_error = error_body(
+        "AUTHENTICATION",
+        json!({ "id": { "$ref": "#/components/schemas/AuthenticationErrorId" } }),
+    );
+    for status_code in &[StatusCode::BAD_REQUEST, StatusCode::FORBIDDEN] {
+        errors
+            .entry(*status_code)
+            .or_default()
+            .push(request_error.clone());
+    }
+    if route.way == Way::PostStream {
+        errors
+            .entry(StatusCode::UNSUPPORTED_MEDIA_TYPE)
+            .or_default()
+            .push(request_error.clone());
+    }
+    if route.authenticated {
+        for status_code in &[
+            StatusCode::BAD_REQUEST,
+            StatusCode::UNAUTHORIZED,
+            StatusCode::FORBIDDEN,
+        ] {
+            errors
+                .entry(*status_code)
+                .or_default()
+                .push(authentication_error.clone());
+        }
+        errors
+            .entry(StatusCode::FORBIDDEN)
+            .or_default()
+            .push(error_body("NOT_SIGNED_UP", json!({})));
+    }
+    if route.rate_limited {
+        errors
+            .entry(StatusCode::TOO_MANY_REQUESTS)
+            .or_default()
+            .push(request_error);
+    }
+    errors
+        .entry(StatusCode::INTERNAL_SERVER_ERROR)
+        .or_default()
+        .push(error_body(
+            "INTERNAL",
+            json!({ "request_id": { "type": "string" } }),
+        ));
+    errors
+        .entry(StatusCode::SERVICE_UNAVAILABLE)
+        .or_default()
+        .push(error_body(
+            "SERVICE_UNAVAILABLE",
+            json!({ "request_id": { "type": "string" } }),
+        ));
+
+    for (status_code, mut bodies) in errors {
+        let error = if bodies.len() == 1 {
+            bodies.remove(0)
+        } else {
+            json!({ "oneOf": bodies })
+        };
+        responses.insert(
+            status_code.as_str().to_owned(),
+            json!({
+                "description": description(status_code),
+                "content": {
+                    "application/json": {
+                        "schema": {
+                            "allOf": [
+                                { "$ref": "#/components/schemas/Error" },
+                                {
+                                    "type": "object",
+                                    "properties": {
+                                        "status": { "type": "integer", "enum": [status_code.as_u16()] },
+                                        "error": error,
+                                    },
+                                    "required": ["status", "error"],
+                                },
+                            ],
+                        },
+                    },
+                },
+            }),
+        );
+    }
+
+    Value::Object(responses)
+}
+
+fn description(status_code: StatusCode) -> &'static str {
+    status_code.canonical_reason().unwrap_or_default()
+}
+
+fn error_body(type_: &str, properties: Value) -> Value {
+    let mut required = vec![Value::from("type")];
+    let mut all_properties = Map::new();
+    all_properties.insert(
+        "type".to_owned(),
+        json!({ "type": "string", "enum": [type_] }),
+    );
+    for (name, schema) in properties.as_object().into_iter().flatten() {
+        required.push(Value::from(name.as_str()));
+        all_properties.insert(name.clone(), schema.clone());
+    }
+    json!({
+        "type": "object",
+        "required": required,
+        "properties": all_properties,
+    })
+}
+
+/// Restricts the schema of the handler error to the variants with `types`.
+fn error_info(error: &Value, types: &[String]) -> Value {
+    let mut variants: Vec<Value> = error["oneOf"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(|variant| {
+            variant["properties"]["type"]["enum"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .any(|type_| types.iter().any(|t| type_ == t.as_str()))
+        })
+        .cloned()
+        .collect();
+    if variants.len() == 1 {
+        variants.remove(0)
+    } else {
+        json!({ "oneOf": variants })
+    }
+}
+
+/// Converts a variant name to its serialized name by `#[serde(rename_all = "SCREAMING_SNAKE_CASE")]`.
+fn screaming_snake_case(name: &str) -> String {
+    let mut converted = String::new();
+    for (i, c) in name.char_indices() {
+        if i > 0 && c.is_uppercase() {
+            converted.push('_');
+        }
+        converted.push(c.to_ascii_uppercase());
+    }
+    converted
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::{error_info, generate, screaming_snake_case, Way};
+    use crate::filter::route_table;
+    use serde_json::Value;
+    use warp::http::StatusCode;
+
+    fn method(way: Way) -> &'static str {
+        match way {
+            Way::Get => "get",
+            Way::Post | Way::PostStream => "post",
+        }
+    }
+
+    fn collect_references<'a>(value: &'a Value, references: &mut Vec<&'a str>) {
+        match value {
+            Value::Object(object) => {
+                for (key, value) in object {
+                    match (key.as_str(), value) {
+                        ("$ref", Value::String(reference)) => references.push(reference),
+                        _ => collect_references(value, references),
+                    }
+                }
+            }
+            Value::Array(array) => {
+                for value in array {
+                    collect_references(value, references);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn test_screaming_snake_case() {
+        assert_eq!(screaming_snake_case("ProjectNotFound"), "PROJECT_NOT_FOUND");
+        assert_eq!(screaming_snake_case("Api"), "API");
+    }
+
+    #[test]
+    fn test_every_route_is_documented() {
+        let routes = route_table();
+        let generated = generate(&routes);
+        let paths = generated.document["paths"].as_object().unwrap();
+        assert_eq!(paths.len(), routes.len(), "duplicated routes");
+        for route in &routes {
+            assert!(
+                paths[route.path][method(route.way)].is_object(),
+                "{} is not documented",
+                route.path
+            );
+        }
+    }
+
+    #[test]
+    fn test_schemas_are_consistent() {
+        let generated = generate(&route_table());
+        assert!(
+            generated.conflicting_schemas.is_empty(),
+            "conflicting schemas: {:?}",
+            generated.conflicting_schemas
+        );
+
+        let schemas = generated.document["components"]["schemas"]
+            .as_object()
+            .unwrap();
+        let mut references = Vec::new();
+        collect_references(&generated.document, &mut references);
+        for reference in references {
+            let name = reference.strip_prefix("#/components/schemas/").unwrap();
+            assert!(schemas.contains_key(name), "unresolved {}", reference);
+        }
+    }
+
+    #[test]
+    fn test_error_status_codes_match_variants() {
+        for route in route_table() {
+            let operation = (route.operation)();
+            let variants = operation.error["oneOf"].as_array().map_or(0, Vec::len);
+            assert_eq!(
+                operation.error_status_codes.len(),
+                variants,
+                "{}: status codes are not given to all variants",
+                route.path
+            );
+            for (variant, _) in &operation.error_status_codes {
+                let type_ = screaming_snake_case(variant.unwrap());
+                let info = error_info(&operation.error, std::slice::from_ref(&type_));
+                assert_eq!(
+                    info["properties"]["type"]["enum"][0], type_,
+                    "{}: {} is not in the schema",
+                    route.path, type_
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_api_yml_agrees_with_routes() {
+        let api: Value = serde_yaml::from_str(include_str!("../schema/api.yml")).unwrap();
+        let routes = route_table();
+
+        let documented: BTreeSet<&str> = api["paths"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .filter(|path| cfg!(feature = "dev-auth") || !path.starts_with("/dev/"))
+            .collect();
+        let routed: BTreeSet<&str> = routes.iter().map(|route| route.path).collect();
+        assert_eq!(documented, routed);
+
+        // api.yml omits some errors from filters, but must document all the responses of handlers
+        // and the server errors which any handler can return
+        for route in &routes {
+            let operation = (route.operation)();
+            let responses = &api["paths"][route.path][method(route.way)]["responses"];
+            assert!(responses.is_object(), "{} is not documented", route.path);
+            let status_codes = operation
+                .response
+                .iter()
+                .map(|(status_code, _)| *status_code)
+                .chain(operation.error_status_codes.iter().map(|(_, code)| *code))
+                .chain(vec![
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    StatusCode::SERVICE_UNAVAILABLE,
+                ]);
+            for status_code in status_codes {
+                assert!(
+                    responses[status_code.as_str()].is_object(),
+                    "{} {} is not documented in api.yml",
+                    route.path,
+                    status_code
+                );
+            }
+        }
+    }
+}
@@ -6,7 +6,8 @@ use crate::app::App;
 use crate::config::Config;
 use crate::filter::{self, KeyStore};
 
-use anyhow::Result;
+use anyhow::{Context as _, Result};
+use sos21_use_case::{delete_expired_user_invitations, UseCaseError};
 use tokio::{task::JoinHandle, time};
 use tracing::{event, Level};
 
@@ -14,7 +15,8 @@ use tracing::{event, Level};
 pub struct Server {
     app: App,
     key_store: KeyStore,
-    key_refresh_worker: JoinHandle<Infallible>,
+    key_refresh_worker: Option<JoinHandle<Infallible>>,
+    invitation_cleanup_worker: JoinHandle<Infallible>,
 }
 
 const FETCH_MINIMUM_INTERVAL: u64 = 5 * 60 * 60;
@@ -44,20 +46,99 @@ fn spawn_key_refresh_worker(key_store: KeyStore) -> JoinHandle<Infallible> {
     })
 }
 
+const INVITATION_CLEANUP_INTERVAL: u64 = 60 * 60;
+/// Expired invitations are kept for this period to be listed before they are deleted.
+const EXPIRED_INVITATION_RETENTION_DAYS: i64 = 30;
+
+async fn cleanup_invitations(app: &App) -> Result<usize> {
+    let ctx = app.start_context().await?;
+    let expired_before =
+        chrono::Utc::now() - chrono::Duration::days(EXPIRED_INVITATION_RETENTION_DAYS);
+    let count = match delete_expired_user_invitations::run(&ctx, expired_before).await {
+        Ok(count) => count,
+        Err(UseCaseError::Internal(err)) => return Err(err),
+        Err(UseCaseError::UseCase(err)) => match err {},
+    };
+    ctx.commit_changes()
+        .await
+        .context("Failed to commit the cleanup")?;
+    Ok(count)
+}
+
+fn spawn_invitation_cleanup_worker(app: App) -> JoinHandle<Infallible> {
+    tokio::spawn(async move {
+        loop {
+            match cleanup_invitations(&app).await {
+                Ok(count) => {
+                    event!(Level::INFO, count, "Deleted expired user invitations");
+                }
+                Err(error) => {
+                    event!(
+                        Level::ERROR,
+                        ?error,
+                        "Failed to delete expired user invitations",
+                    );
+                }
+            }
+            time::sleep(Duration::from_secs(INVITATION_CLEANUP_INTERVAL)).await;
+        }
+    })
+}
+
+/// Fills `key_store` with the local keys instead of the ones from `jwt_keys_url`
+/// and returns `true` if the development authentication is enabled.
+#[cfg(feature = "dev-auth")]
+async fn prepare_dev_keys(app: &App, config: &Config, key_store: &KeyStore) -> Result<bool> {
+    let dev_auth = match &config.dev_auth {
+        Some(dev_auth) => dev_auth,
+        None => return Ok(false),
+    };
+
+    event!(
+        Level::WARN,
+        "Development authentication is enabled; tokens issued by this server are accepted"
+    );
+    if let Some(issuer) = app.dev_issuer() {
+        key_store
+            .insert(
+                crate::dev_auth::DEV_KEY_ID.to_owned(),
+                issuer.decoding_key(),
+            )
+            .await;
+    }
+    if let Some(path) = &dev_auth.jwks_file {
+        key_store.load_file(path).await?;
+    }
+    Ok(true)
+}
+
+#[cfg(not(feature = "dev-auth"))]
+async fn prepare_dev_keys(_app: &App, _config: &Config, _key_store: &KeyStore) -> Result<bool> {
+    Ok(false)
+}
+
 impl Server {
     pub async fn new(config: Config) -> Result<Self> {
+        crate::metrics::install()?;
+
         let app = App::new(config.clone()).await?;
         let key_store = KeyStore::new(config.jwt_keys_url.clone());
 
-        // fill KeyStore here (i.e. not in refresh worker)
-        // to ensure it is filled at `run` and to catch early errors
-        key_store.refresh().await?;
-        let key_refresh_worker = spawn_key_refresh_worker(key_store.clone());
+        let key_refresh_worker = if prepare_dev_keys(&app, &config, &key_store).await? {
+            None
+        } else {
+            // fill KeyStore here (i.e. not in refresh worker)
+            // to ensure it is filled at `run` and to catch early errors
+            key_store.refresh().await?;
+            Some(spawn_key_refresh_worker(key_store.clone()))
+        };
+        let invitation_cleanup_worker = spawn_invitation_cleanup_worker(app.clone());
 
         Ok(Server {
             app,
             key_store,
             key_refresh_worker,
+            invitation_cleanup_worker,
         })
     }
 
@@ -81,6 +162,9 @@ impl Server {
 
 impl Drop for Server {
     fn drop(&mut self) {
-        self.key_refresh_worker.abort();
+        if let Some(worker) = &self.key_refresh_worker {
+            worker.abort();
+        }
+        self.invitation_cleanup_worker.abort();
     }
 }
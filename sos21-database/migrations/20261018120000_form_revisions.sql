@@ -0,0 +1,21 @@
+ALTER TABLE forms ADD COLUMN version integer NOT NULL DEFAULT 1 CHECK (version > 0);
+ALTER TABLE forms ALTER COLUMN version DROP DEFAULT;
+
+CREATE TABLE form_revisions (
+    form_id uuid NOT NULL REFERENCES forms ON DELETE RESTRICT,
+    version integer NOT NULL CHECK (version > 0),
+    created_at timestamptz NOT NULL,
+    name varchar(64) NOT NULL,
+    description varchar(1024) NOT NULL,
+    starts_at timestamptz NOT NULL,
+    ends_at timestamptz NOT NULL,
+    items jsonb NOT NULL,
+    PRIMARY KEY (form_id, version)
+);
+
+INSERT INTO form_revisions (form_id, version, created_at, name, description, starts_at, ends_at, items)
+    SELECT id, version, created_at, name, description, starts_at, ends_at, items FROM forms;
+
+ALTER TABLE form_answers ADD COLUMN form_version integer NOT NULL DEFAULT 1 CHECK (form_version > 0);
+ALTER TABLE form_answers ALTER COLUMN form_version DROP DEFAULT;
+ALTER TABLE form_answers ADD COLUMN needs_resubmission boolean NOT NULL DEFAULT false;
@@ -0,0 +1,17 @@
+CREATE TABLE form_answer_drafts (
+    form_id uuid NOT NULL REFERENCES forms ON DELETE CASCADE,
+    project_id uuid NOT NULL REFERENCES projects ON DELETE CASCADE,
+    author_id varchar(64) NOT NULL REFERENCES users ON DELETE RESTRICT,
+    updated_at timestamptz NOT NULL,
+    items jsonb NOT NULL,
+    PRIMARY KEY (form_id, project_id)
+);
+
+CREATE TABLE registration_form_answer_drafts (
+    registration_form_id uuid NOT NULL REFERENCES registration_forms ON DELETE CASCADE,
+    pending_project_id uuid NOT NULL REFERENCES pending_projects ON DELETE CASCADE,
+    author_id varchar(64) NOT NULL REFERENCES users ON DELETE RESTRICT,
+    updated_at timestamptz NOT NULL,
+    items jsonb NOT NULL,
+    PRIMARY KEY (registration_form_id, pending_project_id)
+);
@@ -0,0 +1,8 @@
+CREATE TYPE form_answer_review_status AS ENUM ('submitted', 'under_review', 'needs_changes', 'approved');
+
+ALTER TABLE form_answers ADD COLUMN review_status form_answer_review_status NOT NULL DEFAULT 'submitted';
+ALTER TABLE form_answers ADD COLUMN review_comments jsonb NOT NULL DEFAULT '[]';
+ALTER TABLE form_answers ADD COLUMN reviewer_id varchar(64) REFERENCES users ON DELETE RESTRICT;
+ALTER TABLE form_answers ADD COLUMN reviewed_at timestamptz;
+ALTER TABLE form_answers ALTER COLUMN review_status DROP DEFAULT;
+ALTER TABLE form_answers ALTER COLUMN review_comments DROP DEFAULT;
@@ -0,0 +1,7 @@
+CREATE TABLE settings (
+    revision serial PRIMARY KEY,
+    updated_at timestamptz NOT NULL,
+    author_id varchar(64) REFERENCES users ON DELETE RESTRICT,
+    administrator_email varchar(128) NOT NULL,
+    project_creation_periods jsonb NOT NULL
+);
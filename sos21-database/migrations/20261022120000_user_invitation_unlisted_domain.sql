@@ -0,0 +1,2 @@
+ALTER TABLE user_invitations ADD COLUMN allows_unlisted_domain boolean NOT NULL DEFAULT false;
+ALTER TABLE user_invitations ALTER COLUMN allows_unlisted_domain DROP DEFAULT;
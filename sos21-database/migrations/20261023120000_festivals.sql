@@ -0,0 +1,62 @@
+CREATE TABLE festivals (
+    id uuid PRIMARY KEY,
+    created_at timestamptz NOT NULL,
+    author_id varchar(64) REFERENCES users ON DELETE RESTRICT,
+    name varchar(64) NOT NULL
+);
+
+-- the festival which all the existing data belong to
+INSERT INTO festivals ( id, created_at, author_id, name )
+VALUES ( '00000000-0000-4000-8000-000000000000', now(), NULL, '雙峰祭' );
+
+ALTER TABLE projects ADD COLUMN festival_id uuid REFERENCES festivals ON DELETE RESTRICT;
+ALTER TABLE pending_projects ADD COLUMN festival_id uuid REFERENCES festivals ON DELETE RESTRICT;
+ALTER TABLE forms ADD COLUMN festival_id uuid REFERENCES festivals ON DELETE RESTRICT;
+ALTER TABLE registration_forms ADD COLUMN festival_id uuid REFERENCES festivals ON DELETE RESTRICT;
+ALTER TABLE file_distributions ADD COLUMN festival_id uuid REFERENCES festivals ON DELETE RESTRICT;
+ALTER TABLE settings ADD COLUMN active_festival_id uuid REFERENCES festivals ON DELETE RESTRICT;
+
+UPDATE projects SET festival_id = '00000000-0000-4000-8000-000000000000';
+UPDATE pending_projects SET festival_id = '00000000-0000-4000-8000-000000000000';
+UPDATE forms SET festival_id = '00000000-0000-4000-8000-000000000000';
+UPDATE registration_forms SET festival_id = '00000000-0000-4000-8000-000000000000';
+UPDATE file_distributions SET festival_id = '00000000-0000-4000-8000-000000000000';
+UPDATE settings SET active_festival_id = '00000000-0000-4000-8000-000000000000';
+
+ALTER TABLE projects ALTER COLUMN festival_id SET NOT NULL;
+ALTER TABLE pending_projects ALTER COLUMN festival_id SET NOT NULL;
+ALTER TABLE forms ALTER COLUMN festival_id SET NOT NULL;
+ALTER TABLE registration_forms ALTER COLUMN festival_id SET NOT NULL;
+ALTER TABLE file_distributions ALTER COLUMN festival_id SET NOT NULL;
+ALTER TABLE settings ALTER COLUMN active_festival_id SET NOT NULL;
+
+-- project indices are sequenced per festival
+ALTER TABLE projects
+    DROP CONSTRAINT projects_index_key,
+    ADD CONSTRAINT projects_festival_id_index UNIQUE ( festival_id, index );
+
+-- Users are assigned only to the projects of the active festival,
+-- so the owners of the projects are kept on the projects themselves.
+ALTER TABLE projects
+    ADD COLUMN owner_id varchar(64) REFERENCES users ON DELETE RESTRICT,
+    ADD COLUMN subowner_id varchar(64) REFERENCES users ON DELETE RESTRICT;
+ALTER TABLE pending_projects
+    ADD COLUMN owner_id varchar(64) REFERENCES users ON DELETE RESTRICT;
+
+UPDATE projects SET owner_id = users.id
+FROM users
+WHERE users.assignment = 'project_owner' AND users.assignment_owner_project_id = projects.id;
+
+UPDATE projects SET subowner_id = users.id
+FROM users
+WHERE users.assignment = 'project_subowner' AND users.assignment_subowner_project_id = projects.id;
+
+UPDATE pending_projects SET owner_id = users.id
+FROM users
+WHERE users.assignment = 'pending_project_owner' AND users.assignment_owner_pending_project_id = pending_projects.id;
+
+ALTER TABLE projects
+    ALTER COLUMN owner_id SET NOT NULL,
+    ALTER COLUMN subowner_id SET NOT NULL;
+ALTER TABLE pending_projects
+    ALTER COLUMN owner_id SET NOT NULL;
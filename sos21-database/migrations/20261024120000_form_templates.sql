@@ -0,0 +1,9 @@
+CREATE TABLE form_templates (
+    id uuid PRIMARY KEY,
+    created_at timestamptz NOT NULL,
+    author_id varchar(64) NOT NULL REFERENCES users ON DELETE RESTRICT,
+    name varchar(64) NOT NULL,
+    description varchar(1024) NOT NULL,
+    items jsonb NOT NULL,
+    query jsonb NOT NULL
+);
@@ -0,0 +1,5 @@
+ALTER TABLE user_invitations
+    ADD COLUMN expires_at timestamptz,
+    ADD COLUMN accepted_at timestamptz,
+    ADD COLUMN accepted_user_id varchar(64) REFERENCES users ON DELETE RESTRICT,
+    ADD CONSTRAINT user_invitations_accepted CHECK ((accepted_at IS NULL) = (accepted_user_id IS NULL));
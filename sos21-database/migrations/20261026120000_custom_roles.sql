@@ -0,0 +1,10 @@
+CREATE TABLE custom_roles (
+    id uuid PRIMARY KEY,
+    created_at timestamptz NOT NULL,
+    author_id varchar(64) NOT NULL REFERENCES users ON DELETE RESTRICT,
+    name varchar(64) NOT NULL,
+    permissions text[] NOT NULL
+);
+
+ALTER TABLE users
+    ADD COLUMN custom_role_id uuid REFERENCES custom_roles ON DELETE RESTRICT;
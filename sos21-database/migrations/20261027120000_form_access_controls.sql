@@ -0,0 +1,5 @@
+ALTER TABLE forms
+    ADD COLUMN access_control jsonb NOT NULL DEFAULT '[]';
+
+ALTER TABLE registration_forms
+    ADD COLUMN access_control jsonb NOT NULL DEFAULT '[]';
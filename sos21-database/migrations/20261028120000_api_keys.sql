@@ -0,0 +1,13 @@
+CREATE TABLE api_keys (
+    id uuid PRIMARY KEY,
+    created_at timestamptz NOT NULL,
+    author_id varchar(64) NOT NULL REFERENCES users ON DELETE RESTRICT,
+    name varchar(64) NOT NULL,
+    role user_role,
+    permissions text[],
+    token_digest bytea NOT NULL UNIQUE,
+    expires_at timestamptz,
+    last_used_at timestamptz,
+    revoked_at timestamptz,
+    CHECK ((role IS NULL) <> (permissions IS NULL))
+);
@@ -0,0 +1,7 @@
+ALTER TABLE users
+    ADD COLUMN suspended_at timestamptz,
+    ADD COLUMN suspended_by varchar(64) REFERENCES users ON DELETE RESTRICT,
+    ADD COLUMN suspension_reason text,
+    ADD COLUMN tokens_revoked_at timestamptz,
+    ADD CHECK ((suspended_at IS NULL) = (suspension_reason IS NULL)),
+    ADD CHECK (suspended_by IS NULL OR suspended_at IS NOT NULL);
@@ -0,0 +1 @@
+ALTER TABLE form_revisions ADD COLUMN condition jsonb;
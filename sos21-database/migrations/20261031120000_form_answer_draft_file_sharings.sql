@@ -0,0 +1,5 @@
+ALTER TABLE form_answer_drafts ADD COLUMN file_sharings jsonb NOT NULL DEFAULT '[]';
+ALTER TABLE form_answer_drafts ALTER COLUMN file_sharings DROP DEFAULT;
+
+ALTER TABLE registration_form_answer_drafts ADD COLUMN file_sharings jsonb NOT NULL DEFAULT '[]';
+ALTER TABLE registration_form_answer_drafts ALTER COLUMN file_sharings DROP DEFAULT;
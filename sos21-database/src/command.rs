@@ -1,10 +1,16 @@
+pub mod delete_custom_role;
 pub mod delete_file_distribution_files;
+pub mod delete_form_answer_draft;
 pub mod delete_form_condition_excludes;
 pub mod delete_form_condition_includes;
 pub mod delete_form_project_query_conjunctions;
 pub mod delete_pending_project;
+pub mod delete_registration_form_answer_draft;
 pub mod delete_registration_form_project_query_conjunctions;
 pub mod delete_user_invitation;
+pub mod insert_api_key;
+pub mod insert_custom_role;
+mod insert_festival;
 pub mod insert_file;
 pub mod insert_file_distribution;
 pub mod insert_file_distribution_files;
@@ -14,13 +20,18 @@ pub mod insert_form_answer;
 pub mod insert_form_condition_excludes;
 pub mod insert_form_condition_includes;
 pub mod insert_form_project_query_conjunctions;
+pub mod insert_form_revision;
+pub mod insert_form_template;
 pub mod insert_pending_project;
 pub mod insert_project;
 pub mod insert_registration_form;
 pub mod insert_registration_form_answer;
 pub mod insert_registration_form_project_query_conjunctions;
+mod insert_settings;
 pub mod insert_user;
 pub mod insert_user_invitation;
+pub mod update_api_key;
+pub mod update_custom_role;
 pub mod update_file;
 pub mod update_file_distribution;
 pub mod update_file_sharing;
@@ -32,13 +43,21 @@ pub mod update_registration_form;
 pub mod update_registration_form_answer;
 pub mod update_user;
 pub mod update_user_invitation;
+pub mod upsert_form_answer_draft;
+pub mod upsert_registration_form_answer_draft;
+pub use delete_custom_role::delete_custom_role;
 pub use delete_file_distribution_files::delete_file_distribution_files;
+pub use delete_form_answer_draft::delete_form_answer_draft;
 pub use delete_form_condition_excludes::delete_form_condition_excludes;
 pub use delete_form_condition_includes::delete_form_condition_includes;
 pub use delete_form_project_query_conjunctions::delete_form_project_query_conjunctions;
 pub use delete_pending_project::delete_pending_project;
+pub use delete_registration_form_answer_draft::delete_registration_form_answer_draft;
 pub use delete_registration_form_project_query_conjunctions::delete_registration_form_project_query_conjunctions;
 pub use delete_user_invitation::delete_user_invitation;
+pub use insert_api_key::insert_api_key;
+pub use insert_custom_role::insert_custom_role;
+pub use insert_festival::insert_festival;
 pub use insert_file::insert_file;
 pub use insert_file_distribution::insert_file_distribution;
 pub use insert_file_distribution_files::insert_file_distribution_files;
@@ -48,13 +67,18 @@ pub use insert_form_answer::insert_form_answer;
 pub use insert_form_condition_excludes::insert_form_condition_excludes;
 pub use insert_form_condition_includes::insert_form_condition_includes;
 pub use insert_form_project_query_conjunctions::insert_form_project_query_conjunctions;
+pub use insert_form_revision::insert_form_revision;
+pub use insert_form_template::insert_form_template;
 pub use insert_pending_project::insert_pending_project;
 pub use insert_project::insert_project;
 pub use insert_registration_form::insert_registration_form;
 pub use insert_registration_form_answer::insert_registration_form_answer;
 pub use insert_registration_form_project_query_conjunctions::insert_registration_form_project_query_conjunctions;
+pub use insert_settings::insert_settings;
 pub use insert_user::insert_user;
 pub use insert_user_invitation::insert_user_invitation;
+pub use update_api_key::update_api_key;
+pub use update_custom_role::update_custom_role;
 pub use update_file::update_file;
 pub use update_file_distribution::update_file_distribution;
 pub use update_file_sharing::update_file_sharing;
@@ -66,3 +90,5 @@ pub use update_registration_form::update_registration_form;
 pub use update_registration_form_answer::update_registration_form_answer;
 pub use update_user::update_user;
 pub use update_user_invitation::update_user_invitation;
+pub use upsert_form_answer_draft::upsert_form_answer_draft;
+pub use upsert_registration_form_answer_draft::upsert_registration_form_answer_draft;
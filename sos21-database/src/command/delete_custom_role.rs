@@ -0,0 +1,14 @@
+use anyhow::{Context, Result};
+use uuid::Uuid;
+
+pub async fn delete_custom_role<'a, E>(conn: E, id: Uuid) -> Result<()>
+where
+    E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    sqlx::query!("DELETE FROM custom_roles WHERE id = $1", id)
+        .execute(conn)
+        .await
+        .context("Failed to delete from custom_roles")?;
+
+    Ok(())
+}
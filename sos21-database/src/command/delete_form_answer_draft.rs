@@ -0,0 +1,21 @@
+use anyhow::{Context, Result};
+use uuid::Uuid;
+
+pub async fn delete_form_answer_draft<'a, E>(conn: E, form_id: Uuid, project_id: Uuid) -> Result<()>
+where
+    E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    sqlx::query!(
+        r#"
+DELETE FROM form_answer_drafts
+WHERE form_id = $1 AND project_id = $2
+"#,
+        form_id,
+        project_id,
+    )
+    .execute(conn)
+    .await
+    .context("Failed to delete from form answer drafts")?;
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use anyhow::{Context, Result};
+use uuid::Uuid;
+
+pub async fn delete_registration_form_answer_draft<'a, E>(
+    conn: E,
+    registration_form_id: Uuid,
+    pending_project_id: Uuid,
+) -> Result<()>
+where
+    E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    sqlx::query!(
+        r#"
+DELETE FROM registration_form_answer_drafts
+WHERE registration_form_id = $1 AND pending_project_id = $2
+"#,
+        registration_form_id,
+        pending_project_id,
+    )
+    .execute(conn)
+    .await
+    .context("Failed to delete from registration form answer drafts")?;
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use crate::model::api_key::ApiKey;
+
+use anyhow::{Context, Result};
+
+pub async fn insert_api_key<'a, E>(conn: E, key: ApiKey) -> Result<()>
+where
+    E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    let ApiKey {
+        id,
+        created_at,
+        author_id,
+        name,
+        role,
+        permissions,
+        token_digest,
+        expires_at,
+        last_used_at,
+        revoked_at,
+    } = key;
+
+    sqlx::query!(
+        r#"
+INSERT INTO api_keys (
+    id,
+    created_at,
+    author_id,
+    name,
+    role,
+    permissions,
+    token_digest,
+    expires_at,
+    last_used_at,
+    revoked_at
+) VALUES ( $1, $2, $3, $4, $5, $6, $7, $8, $9, $10 )
+"#,
+        id,
+        created_at,
+        author_id,
+        name,
+        role as _,
+        permissions.as_deref(),
+        token_digest,
+        expires_at,
+        last_used_at,
+        revoked_at,
+    )
+    .execute(conn)
+    .await
+    .context("Failed to insert to api_keys")?;
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use crate::model::custom_role::CustomRole;
+
+use anyhow::{Context, Result};
+
+pub async fn insert_custom_role<'a, E>(conn: E, role: CustomRole) -> Result<()>
+where
+    E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    let CustomRole {
+        id,
+        created_at,
+        author_id,
+        name,
+        permissions,
+    } = role;
+
+    sqlx::query!(
+        r#"
+INSERT INTO custom_roles (
+    id,
+    created_at,
+    author_id,
+    name,
+    permissions
+) VALUES ( $1, $2, $3, $4, $5 )
+"#,
+        id,
+        created_at,
+        author_id,
+        name,
+        &permissions
+    )
+    .execute(conn)
+    .await
+    .context("Failed to insert to custom_roles")?;
+
+    Ok(())
+}
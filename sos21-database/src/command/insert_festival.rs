@@ -0,0 +1,35 @@
+use crate::model::festival::Festival;
+
+use anyhow::{Context, Result};
+
+pub async fn insert_festival<'a, E>(conn: E, festival: Festival) -> Result<()>
+where
+    E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    let Festival {
+        id,
+        created_at,
+        author_id,
+        name,
+    } = festival;
+
+    sqlx::query!(
+        r#"
+INSERT INTO festivals (
+    id,
+    created_at,
+    author_id,
+    name
+) VALUES ( $1, $2, $3, $4 )
+"#,
+        id,
+        created_at,
+        author_id,
+        name
+    )
+    .execute(conn)
+    .await
+    .context("Failed to insert to festivals")?;
+
+    Ok(())
+}
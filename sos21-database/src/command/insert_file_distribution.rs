@@ -8,6 +8,7 @@ where
 {
     let FileDistribution {
         id,
+        festival_id,
         created_at,
         author_id,
         name,
@@ -21,14 +22,16 @@ INSERT INTO file_distributions (
     created_at,
     author_id,
     name,
-    description
-) VALUES ( $1, $2, $3, $4, $5 )
+    description,
+    festival_id
+) VALUES ( $1, $2, $3, $4, $5, $6 )
 "#,
         id,
         created_at,
         author_id,
         name,
-        description
+        description,
+        festival_id
     )
     .execute(conn)
     .await
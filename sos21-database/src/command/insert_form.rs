@@ -8,6 +8,8 @@ where
 {
     let Form {
         id,
+        festival_id,
+        version,
         created_at,
         author_id,
         name,
@@ -16,6 +18,7 @@ where
         ends_at,
         items,
         answer_notification_webhook,
+        access_control,
     } = form;
 
     sqlx::query!(
@@ -29,8 +32,11 @@ INSERT INTO forms (
     starts_at,
     ends_at,
     items,
-    answer_notification_webhook
-) VALUES ( $1, $2, $3, $4, $5, $6, $7, $8 ,$9)
+    answer_notification_webhook,
+    version,
+    festival_id,
+    access_control
+) VALUES ( $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12 )
 "#,
         id,
         created_at,
@@ -40,7 +46,10 @@ INSERT INTO forms (
         starts_at,
         ends_at,
         items,
-        answer_notification_webhook
+        answer_notification_webhook,
+        version,
+        festival_id,
+        access_control
     )
     .execute(conn)
     .await
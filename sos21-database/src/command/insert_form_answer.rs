@@ -11,8 +11,14 @@ where
         created_at,
         author_id,
         form_id,
+        form_version,
         project_id,
         items,
+        needs_resubmission,
+        review_status,
+        review_comments,
+        reviewer_id,
+        reviewed_at,
     } = answer;
 
     sqlx::query!(
@@ -23,8 +29,14 @@ INSERT INTO form_answers (
     author_id,
     form_id,
     project_id,
-    items
-) VALUES ( $1, $2, $3, $4, $5, $6 )
+    items,
+    form_version,
+    needs_resubmission,
+    review_status,
+    review_comments,
+    reviewer_id,
+    reviewed_at
+) VALUES ( $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12 )
 "#,
         id,
         created_at,
@@ -32,6 +44,12 @@ INSERT INTO form_answers (
         form_id,
         project_id,
         items,
+        form_version,
+        needs_resubmission,
+        review_status as _,
+        review_comments,
+        reviewer_id,
+        reviewed_at,
     )
     .execute(conn)
     .await
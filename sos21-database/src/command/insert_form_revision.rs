@@ -0,0 +1,50 @@
+use crate::model::form::FormRevision;
+
+use anyhow::{Context, Result};
+
+pub async fn insert_form_revision<'a, E>(conn: E, revision: FormRevision) -> Result<()>
+where
+    E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    let FormRevision {
+        form_id,
+        version,
+        created_at,
+        name,
+        description,
+        starts_at,
+        ends_at,
+        items,
+        condition,
+    } = revision;
+
+    sqlx::query!(
+        r#"
+INSERT INTO form_revisions (
+    form_id,
+    version,
+    created_at,
+    name,
+    description,
+    starts_at,
+    ends_at,
+    items,
+    condition
+) VALUES ( $1, $2, $3, $4, $5, $6, $7, $8, $9 )
+"#,
+        form_id,
+        version,
+        created_at,
+        name,
+        description,
+        starts_at,
+        ends_at,
+        items,
+        condition,
+    )
+    .execute(conn)
+    .await
+    .context("Failed to insert to form revisions")?;
+
+    Ok(())
+}
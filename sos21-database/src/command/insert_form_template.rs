@@ -0,0 +1,44 @@
+use crate::model::form_template::FormTemplate;
+
+use anyhow::{Context, Result};
+
+pub async fn insert_form_template<'a, E>(conn: E, template: FormTemplate) -> Result<()>
+where
+    E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    let FormTemplate {
+        id,
+        created_at,
+        author_id,
+        name,
+        description,
+        items,
+        query,
+    } = template;
+
+    sqlx::query!(
+        r#"
+INSERT INTO form_templates (
+    id,
+    created_at,
+    author_id,
+    name,
+    description,
+    items,
+    query
+) VALUES ( $1, $2, $3, $4, $5, $6, $7 )
+"#,
+        id,
+        created_at,
+        author_id,
+        name,
+        description,
+        items,
+        query
+    )
+    .execute(conn)
+    .await
+    .context("Failed to insert to form_templates")?;
+
+    Ok(())
+}
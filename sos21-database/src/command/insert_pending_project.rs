@@ -8,6 +8,8 @@ where
 {
     let PendingProject {
         id,
+        festival_id,
+        owner_id,
         created_at,
         updated_at,
         name,
@@ -33,8 +35,10 @@ INSERT INTO pending_projects (
     description,
     category,
     attributes,
-    exceptional_complete_deadline
-) VALUES ( $1, $2, $3, $4, $5, $6, $7, $8, $9, $10 , $11)
+    exceptional_complete_deadline,
+    festival_id,
+    owner_id
+) VALUES ( $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13 )
 "#,
         id,
         created_at,
@@ -46,7 +50,9 @@ INSERT INTO pending_projects (
         description,
         category as _,
         attributes as _,
-        exceptional_complete_deadline
+        exceptional_complete_deadline,
+        festival_id,
+        owner_id
     )
     .execute(conn)
     .await
@@ -8,6 +8,9 @@ where
 {
     let Project {
         id,
+        festival_id,
+        owner_id,
+        subowner_id,
         index,
         created_at,
         updated_at,
@@ -33,8 +36,11 @@ INSERT INTO projects (
     kana_group_name,
     description,
     category,
-    attributes
-) VALUES ( $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11 )
+    attributes,
+    festival_id,
+    owner_id,
+    subowner_id
+) VALUES ( $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14 )
 "#,
         id,
         index,
@@ -46,7 +52,10 @@ INSERT INTO projects (
         kana_group_name,
         description,
         category as _,
-        attributes as _
+        attributes as _,
+        festival_id,
+        owner_id,
+        subowner_id
     )
     .execute(conn)
     .await
@@ -11,11 +11,13 @@ where
 {
     let RegistrationForm {
         id,
+        festival_id,
         created_at,
         author_id,
         name,
         description,
         items,
+        access_control,
     } = registration_form;
 
     sqlx::query!(
@@ -26,8 +28,10 @@ INSERT INTO registration_forms (
     author_id,
     name,
     description,
-    items
-) VALUES ( $1, $2, $3, $4, $5, $6 )
+    items,
+    festival_id,
+    access_control
+) VALUES ( $1, $2, $3, $4, $5, $6, $7, $8 )
 "#,
         id,
         created_at,
@@ -35,6 +39,8 @@ INSERT INTO registration_forms (
         name,
         description,
         items,
+        festival_id,
+        access_control
     )
     .execute(conn)
     .await
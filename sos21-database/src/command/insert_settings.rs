@@ -0,0 +1,38 @@
+use crate::model::settings::Settings;
+
+use anyhow::{Context, Result};
+
+pub async fn insert_settings<'a, E>(conn: E, settings: Settings) -> Result<()>
+where
+    E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    let Settings {
+        updated_at,
+        author_id,
+        administrator_email,
+        active_festival_id,
+        project_creation_periods,
+    } = settings;
+
+    sqlx::query!(
+        r#"
+INSERT INTO settings (
+    updated_at,
+    author_id,
+    administrator_email,
+    active_festival_id,
+    project_creation_periods
+) VALUES ( $1, $2, $3, $4, $5 )
+"#,
+        updated_at,
+        author_id,
+        administrator_email,
+        active_festival_id,
+        project_creation_periods
+    )
+    .execute(conn)
+    .await
+    .context("Failed to insert to settings")?;
+
+    Ok(())
+}
@@ -21,6 +21,12 @@ where
         assignment_owner_project_id,
         assignment_subowner_project_id,
         assignment_owner_pending_project_id,
+        custom_role_id,
+        custom_role_permissions: _,
+        suspended_at,
+        suspended_by,
+        suspension_reason,
+        tokens_revoked_at,
     } = user;
 
     sqlx::query!(
@@ -39,8 +45,13 @@ INSERT INTO users (
     assignment,
     assignment_owner_project_id,
     assignment_subowner_project_id,
-    assignment_owner_pending_project_id
-) VALUES ( $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14 )
+    assignment_owner_pending_project_id,
+    custom_role_id,
+    suspended_at,
+    suspended_by,
+    suspension_reason,
+    tokens_revoked_at
+) VALUES ( $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19 )
 "#,
         id,
         created_at,
@@ -55,7 +66,12 @@ INSERT INTO users (
         assignment as _,
         assignment_owner_project_id,
         assignment_subowner_project_id,
-        assignment_owner_pending_project_id
+        assignment_owner_pending_project_id,
+        custom_role_id,
+        suspended_at,
+        suspended_by,
+        suspension_reason,
+        tokens_revoked_at
     )
     .execute(conn)
     .await
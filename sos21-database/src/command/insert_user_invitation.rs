@@ -12,6 +12,10 @@ where
         author_id,
         email,
         role,
+        allows_unlisted_domain,
+        expires_at,
+        accepted_at,
+        accepted_user_id,
     } = invitation;
 
     sqlx::query!(
@@ -21,14 +25,22 @@ INSERT INTO user_invitations (
     created_at,
     author_id,
     email,
-    role
-) VALUES ( $1, $2, $3, $4, $5 )
+    role,
+    allows_unlisted_domain,
+    expires_at,
+    accepted_at,
+    accepted_user_id
+) VALUES ( $1, $2, $3, $4, $5, $6, $7, $8, $9 )
 "#,
         id,
         created_at,
         author_id,
         email,
-        role as _
+        role as _,
+        allows_unlisted_domain,
+        expires_at,
+        accepted_at,
+        accepted_user_id
     )
     .execute(conn)
     .await
@@ -0,0 +1,32 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub id: Uuid,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+pub async fn update_api_key<'a, E>(conn: E, input: Input) -> Result<()>
+where
+    E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    sqlx::query!(
+        r#"
+UPDATE api_keys
+  SET
+    last_used_at = $2,
+    revoked_at = $3
+  WHERE id = $1
+"#,
+        input.id,
+        input.last_used_at,
+        input.revoked_at,
+    )
+    .execute(conn)
+    .await
+    .context("Failed to update on api_keys")?;
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anyhow::{Context, Result};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub id: Uuid,
+    pub name: String,
+    pub permissions: Vec<String>,
+}
+
+pub async fn update_custom_role<'a, E>(conn: E, input: Input) -> Result<()>
+where
+    E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    sqlx::query!(
+        r#"
+UPDATE custom_roles
+  SET
+    name = $2,
+    permissions = $3
+  WHERE id = $1
+"#,
+        input.id,
+        input.name,
+        &input.permissions,
+    )
+    .execute(conn)
+    .await
+    .context("Failed to update on custom_roles")?;
+    Ok(())
+}
@@ -1,22 +1,27 @@
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 #[derive(Debug, Clone)]
 pub struct Input {
     pub id: Uuid,
+    /// The version of the stored form to be updated,
+    /// which fails the update if the form is updated to another version concurrently.
+    pub old_version: i32,
+    pub version: i32,
     pub name: String,
     pub description: String,
     pub starts_at: DateTime<Utc>,
     pub ends_at: DateTime<Utc>,
     pub items: serde_json::Value,
+    pub access_control: serde_json::Value,
 }
 
 pub async fn update_form<'a, E>(conn: E, input: Input) -> Result<()>
 where
     E: sqlx::Executor<'a, Database = sqlx::Postgres>,
 {
-    sqlx::query!(
+    let result = sqlx::query!(
         r#"
 UPDATE forms
   SET
@@ -24,8 +29,10 @@ UPDATE forms
     description = $3,
     starts_at = $4,
     ends_at = $5,
-    items = $6
-  WHERE id = $1
+    items = $6,
+    version = $7,
+    access_control = $8
+  WHERE id = $1 AND version = $9
 "#,
         input.id,
         input.name,
@@ -33,9 +40,21 @@ UPDATE forms
         input.starts_at,
         input.ends_at,
         input.items,
+        input.version,
+        input.access_control,
+        input.old_version,
     )
     .execute(conn)
     .await
     .context("Failed to update on forms")?;
+
+    if result.rows_affected() == 0 {
+        bail!(
+            "Form {} is not in version {}, which is updated concurrently",
+            input.id,
+            input.old_version
+        );
+    }
+
     Ok(())
 }
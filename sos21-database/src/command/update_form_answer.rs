@@ -1,10 +1,19 @@
+use crate::model::form_answer::FormAnswerReviewStatus;
+
 use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 #[derive(Debug, Clone)]
 pub struct Input {
     pub id: Uuid,
     pub items: serde_json::Value,
+    pub form_version: i32,
+    pub needs_resubmission: bool,
+    pub review_status: FormAnswerReviewStatus,
+    pub review_comments: serde_json::Value,
+    pub reviewer_id: Option<String>,
+    pub reviewed_at: Option<DateTime<Utc>>,
 }
 
 pub async fn update_form_answer<'a, E>(conn: E, input: Input) -> Result<()>
@@ -15,11 +24,23 @@ where
         r#"
 UPDATE form_answers
   SET
-    items = $2
+    items = $2,
+    form_version = $3,
+    needs_resubmission = $4,
+    review_status = $5,
+    review_comments = $6,
+    reviewer_id = $7,
+    reviewed_at = $8
   WHERE id = $1
 "#,
         input.id,
-        input.items
+        input.items,
+        input.form_version,
+        input.needs_resubmission,
+        input.review_status as _,
+        input.review_comments,
+        input.reviewer_id,
+        input.reviewed_at
     )
     .execute(conn)
     .await
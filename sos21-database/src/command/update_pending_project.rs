@@ -16,6 +16,7 @@ pub struct Input {
     pub description: String,
     pub category: ProjectCategory,
     pub attributes: ProjectAttributes,
+    pub exceptional_complete_deadline: Option<DateTime<Utc>>,
 }
 
 pub async fn update_pending_project<'a, E>(conn: E, input: Input) -> Result<()>
@@ -33,6 +34,7 @@ where
         description,
         category,
         attributes,
+        exceptional_complete_deadline,
     } = input;
 
     sqlx::query!(
@@ -47,7 +49,8 @@ UPDATE pending_projects
     description = $7,
     category = $8,
     attributes = $9,
-    updated_at = $10
+    updated_at = $10,
+    exceptional_complete_deadline = $11
   WHERE id = $1
 "#,
         id,
@@ -60,6 +63,7 @@ UPDATE pending_projects
         category as _,
         attributes as _,
         updated_at,
+        exceptional_complete_deadline,
     )
     .execute(conn)
     .await
@@ -15,6 +15,7 @@ pub struct Input {
     pub description: String,
     pub category: ProjectCategory,
     pub attributes: ProjectAttributes,
+    pub subowner_id: String,
 }
 
 pub async fn update_project<'a, E>(conn: E, input: Input) -> Result<()>
@@ -32,7 +33,8 @@ UPDATE projects
     description = $6,
     category = $7,
     attributes = $8,
-    updated_at = $9
+    updated_at = $9,
+    subowner_id = $10
   WHERE id = $1
 "#,
         input.id,
@@ -43,7 +45,8 @@ UPDATE projects
         input.description,
         input.category as _,
         input.attributes as _,
-        input.updated_at
+        input.updated_at,
+        input.subowner_id
     )
     .execute(conn)
     .await
@@ -7,6 +7,7 @@ pub struct Input {
     pub name: String,
     pub description: String,
     pub items: serde_json::Value,
+    pub access_control: serde_json::Value,
 }
 
 pub async fn update_registration_form<'a, E>(conn: E, input: Input) -> Result<()>
@@ -19,13 +20,15 @@ UPDATE registration_forms
   SET
     name = $2,
     description = $3,
-    items = $4
+    items = $4,
+    access_control = $5
   WHERE id = $1
 "#,
         input.id,
         input.name,
         input.description,
         input.items,
+        input.access_control,
     )
     .execute(conn)
     .await
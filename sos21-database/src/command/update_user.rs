@@ -1,6 +1,7 @@
 use crate::model::user::{UserAssignment, UserCategory, UserRole};
 
 use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 #[derive(Debug, Clone)]
@@ -17,6 +18,11 @@ pub struct Input {
     pub assignment_owner_project_id: Option<Uuid>,
     pub assignment_subowner_project_id: Option<Uuid>,
     pub assignment_owner_pending_project_id: Option<Uuid>,
+    pub custom_role_id: Option<Uuid>,
+    pub suspended_at: Option<DateTime<Utc>>,
+    pub suspended_by: Option<String>,
+    pub suspension_reason: Option<String>,
+    pub tokens_revoked_at: Option<DateTime<Utc>>,
 }
 
 pub async fn update_user<'a, E>(conn: E, input: Input) -> Result<()>
@@ -37,7 +43,12 @@ UPDATE users
     assignment = $9,
     assignment_owner_project_id = $10,
     assignment_subowner_project_id = $11,
-    assignment_owner_pending_project_id = $12
+    assignment_owner_pending_project_id = $12,
+    custom_role_id = $13,
+    suspended_at = $14,
+    suspended_by = $15,
+    suspension_reason = $16,
+    tokens_revoked_at = $17
   WHERE id = $1
 "#,
         input.id,
@@ -52,6 +63,11 @@ UPDATE users
         input.assignment_owner_project_id,
         input.assignment_subowner_project_id,
         input.assignment_owner_pending_project_id,
+        input.custom_role_id,
+        input.suspended_at,
+        input.suspended_by,
+        input.suspension_reason,
+        input.tokens_revoked_at,
     )
     .execute(conn)
     .await
@@ -1,6 +1,7 @@
 use crate::model::user_invitation::UserInvitationRole;
 
 use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 #[derive(Debug, Clone)]
@@ -8,6 +9,10 @@ pub struct Input {
     pub id: Uuid,
     pub email: String,
     pub role: UserInvitationRole,
+    pub allows_unlisted_domain: bool,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub accepted_at: Option<DateTime<Utc>>,
+    pub accepted_user_id: Option<String>,
 }
 
 pub async fn update_user_invitation<'a, E>(conn: E, input: Input) -> Result<()>
@@ -19,12 +24,20 @@ where
 UPDATE user_invitations
   SET
     email = $2,
-    role = $3
+    role = $3,
+    allows_unlisted_domain = $4,
+    expires_at = $5,
+    accepted_at = $6,
+    accepted_user_id = $7
   WHERE id = $1
 "#,
         input.id,
         input.email,
         input.role as _,
+        input.allows_unlisted_domain,
+        input.expires_at,
+        input.accepted_at,
+        input.accepted_user_id,
     )
     .execute(conn)
     .await
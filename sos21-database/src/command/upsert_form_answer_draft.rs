@@ -0,0 +1,47 @@
+use crate::model::form_answer::FormAnswerDraft;
+
+use anyhow::{Context, Result};
+
+pub async fn upsert_form_answer_draft<'a, E>(conn: E, draft: FormAnswerDraft) -> Result<()>
+where
+    E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    let FormAnswerDraft {
+        form_id,
+        project_id,
+        author_id,
+        updated_at,
+        items,
+        file_sharings,
+    } = draft;
+
+    sqlx::query!(
+        r#"
+INSERT INTO form_answer_drafts (
+    form_id,
+    project_id,
+    author_id,
+    updated_at,
+    items,
+    file_sharings
+) VALUES ( $1, $2, $3, $4, $5, $6 )
+ON CONFLICT (form_id, project_id) DO UPDATE
+SET
+    author_id = EXCLUDED.author_id,
+    updated_at = EXCLUDED.updated_at,
+    items = EXCLUDED.items,
+    file_sharings = EXCLUDED.file_sharings
+"#,
+        form_id,
+        project_id,
+        author_id,
+        updated_at,
+        items,
+        file_sharings,
+    )
+    .execute(conn)
+    .await
+    .context("Failed to upsert to form answer drafts")?;
+
+    Ok(())
+}
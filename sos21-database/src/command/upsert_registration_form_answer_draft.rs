@@ -0,0 +1,50 @@
+use crate::model::registration_form_answer::RegistrationFormAnswerDraft;
+
+use anyhow::{Context, Result};
+
+pub async fn upsert_registration_form_answer_draft<'a, E>(
+    conn: E,
+    draft: RegistrationFormAnswerDraft,
+) -> Result<()>
+where
+    E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    let RegistrationFormAnswerDraft {
+        registration_form_id,
+        pending_project_id,
+        author_id,
+        updated_at,
+        items,
+        file_sharings,
+    } = draft;
+
+    sqlx::query!(
+        r#"
+INSERT INTO registration_form_answer_drafts (
+    registration_form_id,
+    pending_project_id,
+    author_id,
+    updated_at,
+    items,
+    file_sharings
+) VALUES ( $1, $2, $3, $4, $5, $6 )
+ON CONFLICT (registration_form_id, pending_project_id) DO UPDATE
+SET
+    author_id = EXCLUDED.author_id,
+    updated_at = EXCLUDED.updated_at,
+    items = EXCLUDED.items,
+    file_sharings = EXCLUDED.file_sharings
+"#,
+        registration_form_id,
+        pending_project_id,
+        author_id,
+        updated_at,
+        items,
+        file_sharings,
+    )
+    .execute(conn)
+    .await
+    .context("Failed to upsert to registration form answer drafts")?;
+
+    Ok(())
+}
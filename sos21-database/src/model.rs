@@ -1,11 +1,16 @@
+pub mod api_key;
+pub mod custom_role;
+pub mod festival;
 pub mod file;
 pub mod file_distribution;
 pub mod file_sharing;
 pub mod form;
 pub mod form_answer;
+pub mod form_template;
 pub mod pending_project;
 pub mod project;
 pub mod registration_form;
 pub mod registration_form_answer;
+pub mod settings;
 pub mod user;
 pub mod user_invitation;
@@ -0,0 +1,18 @@
+use crate::model::user::UserRole;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ApiKey {
+    pub id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub author_id: String,
+    pub name: String,
+    pub role: Option<UserRole>,
+    pub permissions: Option<Vec<String>>,
+    pub token_digest: Vec<u8>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
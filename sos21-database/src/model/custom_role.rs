@@ -0,0 +1,11 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct CustomRole {
+    pub id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub author_id: String,
+    pub name: String,
+    pub permissions: Vec<String>,
+}
@@ -0,0 +1,10 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct Festival {
+    pub id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub author_id: Option<String>,
+    pub name: String,
+}
@@ -4,6 +4,7 @@ use uuid::Uuid;
 #[derive(Debug, Clone, sqlx::FromRow)]
 pub struct FileDistribution {
     pub id: Uuid,
+    pub festival_id: Uuid,
     pub created_at: DateTime<Utc>,
     pub author_id: String,
     pub name: String,
@@ -6,6 +6,8 @@ use uuid::Uuid;
 #[derive(Debug, Clone, sqlx::FromRow)]
 pub struct Form {
     pub id: Uuid,
+    pub festival_id: Uuid,
+    pub version: i32,
     pub created_at: DateTime<Utc>,
     pub author_id: String,
     pub name: String,
@@ -14,6 +16,20 @@ pub struct Form {
     pub ends_at: DateTime<Utc>,
     pub items: serde_json::Value,
     pub answer_notification_webhook: Option<String>,
+    pub access_control: serde_json::Value,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct FormRevision {
+    pub form_id: Uuid,
+    pub version: i32,
+    pub created_at: DateTime<Utc>,
+    pub name: String,
+    pub description: String,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub items: serde_json::Value,
+    pub condition: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone)]
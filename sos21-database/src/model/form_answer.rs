@@ -1,12 +1,38 @@
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
+#[derive(Debug, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "form_answer_review_status")]
+#[sqlx(rename_all = "snake_case")]
+pub enum FormAnswerReviewStatus {
+    Submitted,
+    UnderReview,
+    NeedsChanges,
+    Approved,
+}
+
 #[derive(Debug, Clone, sqlx::FromRow)]
 pub struct FormAnswer {
     pub id: Uuid,
     pub created_at: DateTime<Utc>,
     pub author_id: String,
     pub form_id: Uuid,
+    pub form_version: i32,
     pub project_id: Uuid,
     pub items: serde_json::Value,
+    pub needs_resubmission: bool,
+    pub review_status: FormAnswerReviewStatus,
+    pub review_comments: serde_json::Value,
+    pub reviewer_id: Option<String>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct FormAnswerDraft {
+    pub form_id: Uuid,
+    pub project_id: Uuid,
+    pub author_id: String,
+    pub updated_at: DateTime<Utc>,
+    pub items: serde_json::Value,
+    pub file_sharings: serde_json::Value,
 }
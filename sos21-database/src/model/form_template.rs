@@ -0,0 +1,13 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct FormTemplate {
+    pub id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub author_id: String,
+    pub name: String,
+    pub description: String,
+    pub items: serde_json::Value,
+    pub query: serde_json::Value,
+}
@@ -7,6 +7,7 @@ use uuid::Uuid;
 #[derive(Debug, Clone, sqlx::FromRow)]
 pub struct PendingProject {
     pub id: Uuid,
+    pub festival_id: Uuid,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub name: String,
@@ -17,6 +18,7 @@ pub struct PendingProject {
     pub category: ProjectCategory,
     pub attributes: ProjectAttributes,
     pub exceptional_complete_deadline: Option<DateTime<Utc>>,
+    pub owner_id: String,
 }
 
 #[derive(Debug, Clone)]
@@ -24,3 +26,11 @@ pub struct PendingProjectWithOwner {
     pub pending_project: PendingProject,
     pub owner: User,
 }
+
+#[derive(Debug, Clone)]
+pub struct PendingProjectWithRegistrationProgress {
+    pub pending_project: PendingProject,
+    pub owner: User,
+    pub registration_form_count: i64,
+    pub answered_registration_form_count: i64,
+}
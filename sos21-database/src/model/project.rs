@@ -62,6 +62,7 @@ impl sqlx::Decode<'_, sqlx::Postgres> for ProjectAttributes {
 #[derive(Debug, Clone, sqlx::FromRow)]
 pub struct Project {
     pub id: Uuid,
+    pub festival_id: Uuid,
     pub index: i16,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -72,6 +73,8 @@ pub struct Project {
     pub description: String,
     pub category: ProjectCategory,
     pub attributes: ProjectAttributes,
+    pub owner_id: String,
+    pub subowner_id: String,
 }
 
 #[derive(Debug, Clone)]
@@ -6,11 +6,13 @@ use uuid::Uuid;
 #[derive(Debug, Clone, sqlx::FromRow)]
 pub struct RegistrationForm {
     pub id: Uuid,
+    pub festival_id: Uuid,
     pub created_at: DateTime<Utc>,
     pub author_id: String,
     pub name: String,
     pub description: String,
     pub items: serde_json::Value,
+    pub access_control: serde_json::Value,
 }
 
 #[derive(Debug, Clone)]
@@ -12,3 +12,13 @@ pub struct RegistrationFormAnswer {
     pub pending_project_id: Option<Uuid>,
     pub items: serde_json::Value,
 }
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct RegistrationFormAnswerDraft {
+    pub registration_form_id: Uuid,
+    pub pending_project_id: Uuid,
+    pub author_id: String,
+    pub updated_at: DateTime<Utc>,
+    pub items: serde_json::Value,
+    pub file_sharings: serde_json::Value,
+}
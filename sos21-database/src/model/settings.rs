@@ -0,0 +1,11 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct Settings {
+    pub updated_at: DateTime<Utc>,
+    pub author_id: Option<String>,
+    pub administrator_email: String,
+    pub active_festival_id: Uuid,
+    pub project_creation_periods: serde_json::Value,
+}
@@ -45,4 +45,11 @@ pub struct User {
     pub assignment_owner_project_id: Option<Uuid>,
     pub assignment_subowner_project_id: Option<Uuid>,
     pub assignment_owner_pending_project_id: Option<Uuid>,
+    pub custom_role_id: Option<Uuid>,
+    /// The permissions of the custom role, joined from `custom_roles`.
+    pub custom_role_permissions: Option<Vec<String>>,
+    pub suspended_at: Option<DateTime<Utc>>,
+    pub suspended_by: Option<String>,
+    pub suspension_reason: Option<String>,
+    pub tokens_revoked_at: Option<DateTime<Utc>>,
 }
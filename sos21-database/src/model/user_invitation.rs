@@ -17,4 +17,8 @@ pub struct UserInvitation {
     pub author_id: String,
     pub email: String,
     pub role: UserInvitationRole,
+    pub allows_unlisted_domain: bool,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub accepted_at: Option<DateTime<Utc>>,
+    pub accepted_user_id: Option<String>,
 }
@@ -1,12 +1,20 @@
 mod count_projects;
 mod count_registration_form_answers_by_pending_project;
 mod count_registration_forms_by_pending_project;
+mod find_api_key;
+mod find_api_key_by_token_digest;
+mod find_custom_role;
+mod find_festival;
 mod find_file;
 mod find_file_distribution;
 mod find_file_sharing;
 mod find_form;
 mod find_form_answer;
 mod find_form_answer_by_form_and_project;
+mod find_form_answer_draft;
+mod find_form_revision;
+mod find_form_template;
+mod find_latest_settings;
 mod find_pending_project;
 mod find_project;
 mod find_project_by_index;
@@ -14,38 +22,55 @@ mod find_registration_form;
 mod find_registration_form_answer;
 mod find_registration_form_answer_by_registration_form_and_pending_project;
 mod find_registration_form_answer_by_registration_form_and_project;
+mod find_registration_form_answer_draft;
 mod find_user;
 mod find_user_by_email;
 mod find_user_invitation;
 mod find_user_invitation_by_email;
 mod get_next_index;
 mod is_healthy;
+mod list_api_keys;
+mod list_custom_roles;
+mod list_festivals;
 mod list_file_distributions;
 mod list_file_distributions_by_project;
 mod list_file_sharings_by_pending_project;
 mod list_file_sharings_by_user;
 mod list_files_by_user;
 mod list_form_answers_by_form;
+mod list_form_revisions_by_form;
+mod list_form_templates;
 mod list_forms;
 pub mod list_forms_by_project;
+mod list_pending_projects;
+mod list_pending_projects_with_exceptional_deadline;
 mod list_projects;
 mod list_registration_form_answers_by_pending_project;
 mod list_registration_form_answers_by_registration_form;
 mod list_registration_forms;
 pub mod list_registration_forms_by_pending_project;
 mod list_registration_forms_by_project;
+mod list_settings;
 mod list_user_invitations;
 mod list_users;
 mod sum_file_size_by_user;
 pub use count_projects::count_projects;
 pub use count_registration_form_answers_by_pending_project::count_registration_form_answers_by_pending_project;
 pub use count_registration_forms_by_pending_project::count_registration_forms_by_pending_project;
+pub use find_api_key::find_api_key;
+pub use find_api_key_by_token_digest::find_api_key_by_token_digest;
+pub use find_custom_role::find_custom_role;
+pub use find_festival::find_festival;
 pub use find_file::find_file;
 pub use find_file_distribution::find_file_distribution;
 pub use find_file_sharing::find_file_sharing;
 pub use find_form::find_form;
 pub use find_form_answer::find_form_answer;
 pub use find_form_answer_by_form_and_project::find_form_answer_by_form_and_project;
+pub use find_form_answer_draft::find_form_answer_draft;
+pub use find_form_revision::find_form_revision;
+pub use find_form_template::find_form_template;
+pub use find_latest_settings::find_latest_settings;
 pub use find_pending_project::find_pending_project;
 pub use find_project::find_project;
 pub use find_project_by_index::find_project_by_index;
@@ -53,26 +78,35 @@ pub use find_registration_form::find_registration_form;
 pub use find_registration_form_answer::find_registration_form_answer;
 pub use find_registration_form_answer_by_registration_form_and_pending_project::find_registration_form_answer_by_registration_form_and_pending_project;
 pub use find_registration_form_answer_by_registration_form_and_project::find_registration_form_answer_by_registration_form_and_project;
+pub use find_registration_form_answer_draft::find_registration_form_answer_draft;
 pub use find_user::find_user;
 pub use find_user_by_email::find_user_by_email;
 pub use find_user_invitation::find_user_invitation;
 pub use find_user_invitation_by_email::find_user_invitation_by_email;
 pub use get_next_index::get_next_index;
 pub use is_healthy::is_healthy;
+pub use list_api_keys::list_api_keys;
+pub use list_custom_roles::list_custom_roles;
+pub use list_festivals::list_festivals;
 pub use list_file_distributions::list_file_distributions;
 pub use list_file_distributions_by_project::list_file_distributions_by_project;
 pub use list_file_sharings_by_pending_project::list_file_sharings_by_pending_project;
 pub use list_file_sharings_by_user::list_file_sharings_by_user;
 pub use list_files_by_user::list_files_by_user;
 pub use list_form_answers_by_form::list_form_answers_by_form;
+pub use list_form_revisions_by_form::list_form_revisions_by_form;
+pub use list_form_templates::list_form_templates;
 pub use list_forms::list_forms;
 pub use list_forms_by_project::list_forms_by_project;
+pub use list_pending_projects::list_pending_projects;
+pub use list_pending_projects_with_exceptional_deadline::list_pending_projects_with_exceptional_deadline;
 pub use list_projects::list_projects;
 pub use list_registration_form_answers_by_pending_project::list_registration_form_answers_by_pending_project;
 pub use list_registration_form_answers_by_registration_form::list_registration_form_answers_by_registration_form;
 pub use list_registration_forms::list_registration_forms;
 pub use list_registration_forms_by_pending_project::list_registration_forms_by_pending_project;
 pub use list_registration_forms_by_project::list_registration_forms_by_project;
+pub use list_settings::list_settings;
 pub use list_user_invitations::list_user_invitations;
 pub use list_users::list_users;
 pub use sum_file_size_by_user::sum_file_size_by_user;
@@ -21,7 +21,9 @@ WHERE (
             AND registration_form_project_query_conjunctions.attributes | pending_projects.attributes = pending_projects.attributes
         ))
     FROM registration_form_project_query_conjunctions, pending_projects
-    WHERE registration_form_project_query_conjunctions.registration_form_id = registration_forms.id AND pending_projects.id = $1
+    WHERE registration_form_project_query_conjunctions.registration_form_id = registration_forms.id
+        AND pending_projects.id = $1
+        AND pending_projects.festival_id = registration_forms.festival_id
 )
 "#,
         pending_project_id
@@ -0,0 +1,14 @@
+use crate::model::api_key::ApiKey;
+
+use anyhow::{Context, Result};
+use uuid::Uuid;
+
+pub async fn find_api_key<'a, E>(conn: E, id: Uuid) -> Result<Option<ApiKey>>
+where
+    E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    sqlx::query_as_unchecked!(ApiKey, "SELECT * FROM api_keys WHERE id = $1", id)
+        .fetch_optional(conn)
+        .await
+        .context("Failed to select from api_keys")
+}
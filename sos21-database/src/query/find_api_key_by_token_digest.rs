@@ -0,0 +1,20 @@
+use crate::model::api_key::ApiKey;
+
+use anyhow::{Context, Result};
+
+pub async fn find_api_key_by_token_digest<'a, E>(
+    conn: E,
+    token_digest: Vec<u8>,
+) -> Result<Option<ApiKey>>
+where
+    E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    sqlx::query_as_unchecked!(
+        ApiKey,
+        "SELECT * FROM api_keys WHERE token_digest = $1",
+        token_digest
+    )
+    .fetch_optional(conn)
+    .await
+    .context("Failed to select from api_keys")
+}
@@ -0,0 +1,14 @@
+use crate::model::custom_role::CustomRole;
+
+use anyhow::{Context, Result};
+use uuid::Uuid;
+
+pub async fn find_custom_role<'a, E>(conn: E, id: Uuid) -> Result<Option<CustomRole>>
+where
+    E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    sqlx::query_as!(CustomRole, "SELECT * FROM custom_roles WHERE id = $1", id)
+        .fetch_optional(conn)
+        .await
+        .context("Failed to select from custom_roles")
+}
@@ -0,0 +1,14 @@
+use crate::model::festival::Festival;
+
+use anyhow::{Context, Result};
+use uuid::Uuid;
+
+pub async fn find_festival<'a, E>(conn: E, id: Uuid) -> Result<Option<Festival>>
+where
+    E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    sqlx::query_as!(Festival, "SELECT * FROM festivals WHERE id = $1", id)
+        .fetch_optional(conn)
+        .await
+        .context("Failed to select from festivals")
+}
@@ -41,6 +41,7 @@ WHERE file_distributions.id = $1
 
     let distribution = FileDistribution {
         id: row.id,
+        festival_id: row.festival_id,
         created_at: row.created_at,
         author_id: row.author_id,
         name: row.name,
@@ -47,6 +47,8 @@ WHERE forms.id = $1
 
     let form = Form {
         id: row.id,
+        festival_id: row.festival_id,
+        version: row.version,
         created_at: row.created_at,
         author_id: row.author_id,
         name: row.name,
@@ -55,6 +57,7 @@ WHERE forms.id = $1
         ends_at: row.ends_at,
         items: row.items,
         answer_notification_webhook: row.answer_notification_webhook,
+        access_control: row.access_control,
     };
 
     let include_ids = row.include_ids.unwrap_or_default();
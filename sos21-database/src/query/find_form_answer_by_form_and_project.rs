@@ -11,7 +11,7 @@ pub async fn find_form_answer_by_form_and_project<'a, E>(
 where
     E: sqlx::Executor<'a, Database = sqlx::Postgres>,
 {
-    sqlx::query_as!(
+    sqlx::query_as_unchecked!(
         FormAnswer,
         "SELECT * FROM form_answers WHERE form_id = $1 AND project_id = $2",
         form_id,
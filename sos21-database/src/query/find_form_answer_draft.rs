@@ -0,0 +1,23 @@
+use crate::model::form_answer::FormAnswerDraft;
+
+use anyhow::{Context, Result};
+use uuid::Uuid;
+
+pub async fn find_form_answer_draft<'a, E>(
+    conn: E,
+    form_id: Uuid,
+    project_id: Uuid,
+) -> Result<Option<FormAnswerDraft>>
+where
+    E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    sqlx::query_as!(
+        FormAnswerDraft,
+        "SELECT * FROM form_answer_drafts WHERE form_id = $1 AND project_id = $2",
+        form_id,
+        project_id
+    )
+    .fetch_optional(conn)
+    .await
+    .context("Failed to select from form answer drafts")
+}
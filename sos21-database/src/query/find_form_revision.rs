@@ -0,0 +1,23 @@
+use crate::model::form::FormRevision;
+
+use anyhow::{Context, Result};
+use uuid::Uuid;
+
+pub async fn find_form_revision<'a, E>(
+    conn: E,
+    form_id: Uuid,
+    version: i32,
+) -> Result<Option<FormRevision>>
+where
+    E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    sqlx::query_as!(
+        FormRevision,
+        "SELECT * FROM form_revisions WHERE form_id = $1 AND version = $2",
+        form_id,
+        version
+    )
+    .fetch_optional(conn)
+    .await
+    .context("Failed to select from form revisions")
+}
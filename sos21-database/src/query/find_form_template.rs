@@ -0,0 +1,18 @@
+use crate::model::form_template::FormTemplate;
+
+use anyhow::{Context, Result};
+use uuid::Uuid;
+
+pub async fn find_form_template<'a, E>(conn: E, id: Uuid) -> Result<Option<FormTemplate>>
+where
+    E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    sqlx::query_as!(
+        FormTemplate,
+        "SELECT * FROM form_templates WHERE id = $1",
+        id
+    )
+    .fetch_optional(conn)
+    .await
+    .context("Failed to select from form_templates")
+}
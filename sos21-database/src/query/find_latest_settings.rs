@@ -0,0 +1,21 @@
+use crate::model::settings::Settings;
+
+use anyhow::{Context, Result};
+
+pub async fn find_latest_settings<'a, E>(conn: E) -> Result<Option<Settings>>
+where
+    E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    sqlx::query_as!(
+        Settings,
+        r#"
+SELECT updated_at, author_id, administrator_email, active_festival_id, project_creation_periods
+FROM settings
+ORDER BY revision DESC
+LIMIT 1
+"#
+    )
+    .fetch_optional(conn)
+    .await
+    .context("Failed to select from settings")
+}
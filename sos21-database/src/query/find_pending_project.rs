@@ -16,6 +16,7 @@ where
         r#"
 SELECT
         pending_projects.id,
+        pending_projects.festival_id,
         pending_projects.created_at,
         pending_projects.updated_at,
         pending_projects.name,
@@ -39,12 +40,16 @@ SELECT
         owners.assignment AS "owner_assignment: UserAssignment",
         owners.assignment_owner_project_id AS owner_assignment_owner_project_id,
         owners.assignment_subowner_project_id AS owner_assignment_subowner_project_id,
-        owners.assignment_owner_pending_project_id AS owner_assignment_owner_pending_project_id
+        owners.assignment_owner_pending_project_id AS owner_assignment_owner_pending_project_id,
+        owners.custom_role_id AS owner_custom_role_id,
+        owners.suspended_at AS owner_suspended_at,
+        owners.suspended_by AS owner_suspended_by,
+        owners.suspension_reason AS owner_suspension_reason,
+        owners.tokens_revoked_at AS owner_tokens_revoked_at,
+        owner_custom_roles.permissions AS "owner_custom_role_permissions?"
 FROM pending_projects
-INNER JOIN users AS owners ON (
-    owners.assignment = 'pending_project_owner'
-    AND owners.assignment_owner_pending_project_id = pending_projects.id
-)
+INNER JOIN users AS owners ON owners.id = pending_projects.owner_id
+LEFT JOIN custom_roles AS owner_custom_roles ON owner_custom_roles.id = owners.custom_role_id
 WHERE pending_projects.id = $1
 "#,
         id
@@ -59,6 +64,7 @@ WHERE pending_projects.id = $1
     };
     let pending_project = PendingProject {
         id: row.id,
+        festival_id: row.festival_id,
         created_at: row.created_at,
         updated_at: row.updated_at,
         name: row.name,
@@ -69,6 +75,7 @@ WHERE pending_projects.id = $1
         category: row.category,
         attributes: row.attributes,
         exceptional_complete_deadline: row.exceptional_complete_deadline,
+        owner_id: row.owner_id.clone(),
     };
     let owner = User {
         id: row.owner_id,
@@ -85,6 +92,12 @@ WHERE pending_projects.id = $1
         assignment_owner_project_id: row.owner_assignment_owner_project_id,
         assignment_subowner_project_id: row.owner_assignment_subowner_project_id,
         assignment_owner_pending_project_id: row.owner_assignment_owner_pending_project_id,
+        custom_role_id: row.owner_custom_role_id,
+        custom_role_permissions: row.owner_custom_role_permissions,
+        suspended_at: row.owner_suspended_at,
+        suspended_by: row.owner_suspended_by,
+        suspension_reason: row.owner_suspension_reason,
+        tokens_revoked_at: row.owner_tokens_revoked_at,
     };
 
     Ok(Some(PendingProjectWithOwner {
@@ -4,8 +4,13 @@ use crate::model::{
 };
 
 use anyhow::{Context, Result};
+use uuid::Uuid;
 
-pub async fn find_project_by_index<'a, E>(conn: E, index: i16) -> Result<Option<ProjectWithOwners>>
+pub async fn find_project_by_index<'a, E>(
+    conn: E,
+    festival_id: Uuid,
+    index: i16,
+) -> Result<Option<ProjectWithOwners>>
 where
     E: sqlx::Executor<'a, Database = sqlx::Postgres>,
 {
@@ -13,6 +18,7 @@ where
         r#"
 SELECT
         projects.id,
+        projects.festival_id,
         projects.index,
         projects.created_at,
         projects.updated_at,
@@ -37,6 +43,12 @@ SELECT
         owners.assignment_owner_project_id AS owner_assignment_owner_project_id,
         owners.assignment_subowner_project_id AS owner_assignment_subowner_project_id,
         owners.assignment_owner_pending_project_id AS owner_assignment_owner_pending_project_id,
+        owners.custom_role_id AS owner_custom_role_id,
+        owners.suspended_at AS owner_suspended_at,
+        owners.suspended_by AS owner_suspended_by,
+        owners.suspension_reason AS owner_suspension_reason,
+        owners.tokens_revoked_at AS owner_tokens_revoked_at,
+        owner_custom_roles.permissions AS "owner_custom_role_permissions?",
         subowners.id AS subowner_id,
         subowners.created_at AS subowner_created_at,
         subowners.first_name AS subowner_first_name,
@@ -50,12 +62,21 @@ SELECT
         subowners.assignment AS "subowner_assignment: UserAssignment",
         subowners.assignment_owner_project_id AS subowner_assignment_owner_project_id,
         subowners.assignment_subowner_project_id AS subowner_assignment_subowner_project_id,
-        subowners.assignment_owner_pending_project_id AS subowner_assignment_owner_pending_project_id
+        subowners.assignment_owner_pending_project_id AS subowner_assignment_owner_pending_project_id,
+        subowners.custom_role_id AS subowner_custom_role_id,
+        subowners.suspended_at AS subowner_suspended_at,
+        subowners.suspended_by AS subowner_suspended_by,
+        subowners.suspension_reason AS subowner_suspension_reason,
+        subowners.tokens_revoked_at AS subowner_tokens_revoked_at,
+        subowner_custom_roles.permissions AS "subowner_custom_role_permissions?"
 FROM projects
-INNER JOIN users AS owners ON (owners.assignment = 'project_owner' AND owners.assignment_owner_project_id = projects.id)
-INNER JOIN users AS subowners ON (subowners.assignment = 'project_subowner' AND subowners.assignment_subowner_project_id = projects.id)
-WHERE projects.index = $1
+INNER JOIN users AS owners ON owners.id = projects.owner_id
+LEFT JOIN custom_roles AS owner_custom_roles ON owner_custom_roles.id = owners.custom_role_id
+INNER JOIN users AS subowners ON subowners.id = projects.subowner_id
+LEFT JOIN custom_roles AS subowner_custom_roles ON subowner_custom_roles.id = subowners.custom_role_id
+WHERE projects.festival_id = $1 AND projects.index = $2
 "#,
+        festival_id,
         index
     )
     .fetch_optional(conn)
@@ -68,6 +89,7 @@ WHERE projects.index = $1
     };
     let project = Project {
         id: row.id,
+        festival_id: row.festival_id,
         index: row.index,
         created_at: row.created_at,
         updated_at: row.updated_at,
@@ -78,6 +100,8 @@ WHERE projects.index = $1
         description: row.description,
         category: row.category,
         attributes: row.attributes,
+        owner_id: row.owner_id.clone(),
+        subowner_id: row.subowner_id.clone(),
     };
     let owner = User {
         id: row.owner_id,
@@ -94,6 +118,12 @@ WHERE projects.index = $1
         assignment_owner_project_id: row.owner_assignment_owner_project_id,
         assignment_subowner_project_id: row.owner_assignment_subowner_project_id,
         assignment_owner_pending_project_id: row.owner_assignment_owner_pending_project_id,
+        custom_role_id: row.owner_custom_role_id,
+        custom_role_permissions: row.owner_custom_role_permissions,
+        suspended_at: row.owner_suspended_at,
+        suspended_by: row.owner_suspended_by,
+        suspension_reason: row.owner_suspension_reason,
+        tokens_revoked_at: row.owner_tokens_revoked_at,
     };
     let subowner = User {
         id: row.subowner_id,
@@ -110,6 +140,12 @@ WHERE projects.index = $1
         assignment_owner_project_id: row.subowner_assignment_owner_project_id,
         assignment_subowner_project_id: row.subowner_assignment_subowner_project_id,
         assignment_owner_pending_project_id: row.subowner_assignment_owner_pending_project_id,
+        custom_role_id: row.subowner_custom_role_id,
+        custom_role_permissions: row.subowner_custom_role_permissions,
+        suspended_at: row.subowner_suspended_at,
+        suspended_by: row.subowner_suspended_by,
+        suspension_reason: row.subowner_suspension_reason,
+        tokens_revoked_at: row.subowner_tokens_revoked_at,
     };
 
     Ok(Some(ProjectWithOwners {
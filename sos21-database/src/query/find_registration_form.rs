@@ -42,11 +42,13 @@ WHERE registration_forms.id = $1
 
     let registration_form = RegistrationForm {
         id: row.id,
+        festival_id: row.festival_id,
         created_at: row.created_at,
         author_id: row.author_id,
         name: row.name,
         description: row.description,
         items: row.items,
+        access_control: row.access_control,
     };
 
     let query = row
@@ -0,0 +1,26 @@
+use crate::model::registration_form_answer::RegistrationFormAnswerDraft;
+
+use anyhow::{Context, Result};
+use uuid::Uuid;
+
+pub async fn find_registration_form_answer_draft<'a, E>(
+    conn: E,
+    registration_form_id: Uuid,
+    pending_project_id: Uuid,
+) -> Result<Option<RegistrationFormAnswerDraft>>
+where
+    E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    sqlx::query_as!(
+        RegistrationFormAnswerDraft,
+        r#"
+SELECT * FROM registration_form_answer_drafts
+WHERE registration_form_id = $1 AND pending_project_id = $2
+"#,
+        registration_form_id,
+        pending_project_id
+    )
+    .fetch_optional(conn)
+    .await
+    .context("Failed to select from registration form answer drafts")
+}
@@ -6,8 +6,17 @@ pub async fn find_user<'a, E>(conn: E, id: String) -> Result<Option<User>>
 where
     E: sqlx::Executor<'a, Database = sqlx::Postgres>,
 {
-    sqlx::query_as_unchecked!(User, "SELECT * FROM users WHERE id = $1", id)
-        .fetch_optional(conn)
-        .await
-        .context("Failed to select from users")
+    sqlx::query_as_unchecked!(
+        User,
+        r#"
+SELECT users.*, custom_roles.permissions AS "custom_role_permissions?"
+FROM users
+LEFT JOIN custom_roles ON custom_roles.id = users.custom_role_id
+WHERE users.id = $1
+"#,
+        id
+    )
+    .fetch_optional(conn)
+    .await
+    .context("Failed to select from users")
 }
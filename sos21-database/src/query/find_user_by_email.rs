@@ -7,8 +7,17 @@ where
     E: sqlx::Executor<'a, Database = sqlx::Postgres>,
     S: AsRef<str>,
 {
-    sqlx::query_as_unchecked!(User, "SELECT * FROM users WHERE email = $1", email.as_ref())
-        .fetch_optional(conn)
-        .await
-        .context("Failed to select from users")
+    sqlx::query_as_unchecked!(
+        User,
+        r#"
+SELECT users.*, custom_roles.permissions AS "custom_role_permissions?"
+FROM users
+LEFT JOIN custom_roles ON custom_roles.id = users.custom_role_id
+WHERE users.email = $1
+"#,
+        email.as_ref()
+    )
+    .fetch_optional(conn)
+    .await
+    .context("Failed to select from users")
 }
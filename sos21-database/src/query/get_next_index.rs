@@ -1,15 +1,19 @@
 use std::convert::TryInto;
 
 use anyhow::{Context, Result};
+use uuid::Uuid;
 
-pub async fn get_next_index<'a, E>(conn: E) -> Result<u64>
+pub async fn get_next_index<'a, E>(conn: E, festival_id: Uuid) -> Result<u64>
 where
     E: sqlx::Executor<'a, Database = sqlx::Postgres>,
 {
-    let index = sqlx::query_scalar!(r#"SELECT max(index)+1 as "index" FROM projects"#)
-        .fetch_one(conn)
-        .await
-        .context("Failed to get next index")?;
+    let index = sqlx::query_scalar!(
+        r#"SELECT max(index)+1 as "index" FROM projects WHERE festival_id = $1"#,
+        festival_id
+    )
+    .fetch_one(conn)
+    .await
+    .context("Failed to get next index")?;
 
     let index = match index {
         Some(x) => x.try_into()?,
@@ -0,0 +1,14 @@
+use crate::model::api_key::ApiKey;
+
+use anyhow::{Context, Result};
+use futures::stream::{BoxStream, StreamExt};
+
+pub fn list_api_keys<'a, E>(conn: E) -> BoxStream<'a, Result<ApiKey>>
+where
+    E: sqlx::Executor<'a, Database = sqlx::Postgres> + 'a,
+{
+    sqlx::query_as_unchecked!(ApiKey, "SELECT * FROM api_keys ORDER BY created_at ASC")
+        .fetch(conn)
+        .map(|result| result.context("Failed to select from api_keys"))
+        .boxed()
+}
@@ -0,0 +1,17 @@
+use crate::model::custom_role::CustomRole;
+
+use anyhow::{Context, Result};
+use futures::stream::{BoxStream, StreamExt};
+
+pub fn list_custom_roles<'a, E>(conn: E) -> BoxStream<'a, Result<CustomRole>>
+where
+    E: sqlx::Executor<'a, Database = sqlx::Postgres> + 'a,
+{
+    sqlx::query_as!(
+        CustomRole,
+        "SELECT * FROM custom_roles ORDER BY created_at ASC"
+    )
+    .fetch(conn)
+    .map(|result| result.context("Failed to select from custom_roles"))
+    .boxed()
+}
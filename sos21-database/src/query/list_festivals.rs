@@ -0,0 +1,14 @@
+use crate::model::festival::Festival;
+
+use anyhow::{Context, Result};
+use futures::stream::{BoxStream, StreamExt};
+
+pub fn list_festivals<'a, E>(conn: E) -> BoxStream<'a, Result<Festival>>
+where
+    E: sqlx::Executor<'a, Database = sqlx::Postgres> + 'a,
+{
+    sqlx::query_as!(Festival, "SELECT * FROM festivals ORDER BY created_at DESC")
+        .fetch(conn)
+        .map(|result| result.context("Failed to select from festivals"))
+        .boxed()
+}
@@ -31,6 +31,7 @@ GROUP BY file_distributions.id
 
         let distribution = FileDistribution {
             id: row.id,
+            festival_id: row.festival_id,
             created_at: row.created_at,
             author_id: row.author_id,
             name: row.name,
@@ -8,7 +8,7 @@ pub fn list_form_answers_by_form<'a, E>(conn: E, form_id: Uuid) -> BoxStream<'a,
 where
     E: sqlx::Executor<'a, Database = sqlx::Postgres> + 'a,
 {
-    sqlx::query_as!(
+    sqlx::query_as_unchecked!(
         FormAnswer,
         "SELECT * FROM form_answers WHERE form_id = $1",
         form_id
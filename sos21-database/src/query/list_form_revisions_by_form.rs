@@ -0,0 +1,22 @@
+use crate::model::form::FormRevision;
+
+use anyhow::{Context, Result};
+use futures::stream::{BoxStream, StreamExt};
+use uuid::Uuid;
+
+pub fn list_form_revisions_by_form<'a, E>(
+    conn: E,
+    form_id: Uuid,
+) -> BoxStream<'a, Result<FormRevision>>
+where
+    E: sqlx::Executor<'a, Database = sqlx::Postgres> + 'a,
+{
+    sqlx::query_as!(
+        FormRevision,
+        "SELECT * FROM form_revisions WHERE form_id = $1 ORDER BY version",
+        form_id
+    )
+    .fetch(conn)
+    .map(|result| result.context("Failed to select from form_revisions"))
+    .boxed()
+}
@@ -0,0 +1,17 @@
+use crate::model::form_template::FormTemplate;
+
+use anyhow::{Context, Result};
+use futures::stream::{BoxStream, StreamExt};
+
+pub fn list_form_templates<'a, E>(conn: E) -> BoxStream<'a, Result<FormTemplate>>
+where
+    E: sqlx::Executor<'a, Database = sqlx::Postgres> + 'a,
+{
+    sqlx::query_as!(
+        FormTemplate,
+        "SELECT * FROM form_templates ORDER BY created_at DESC"
+    )
+    .fetch(conn)
+    .map(|result| result.context("Failed to select from form_templates"))
+    .boxed()
+}
@@ -27,7 +27,8 @@ WITH project_forms AS (
         ON form_condition_includes.form_id = forms.id
     LEFT OUTER JOIN form_condition_excludes
         ON form_condition_excludes.form_id = forms.id
-    WHERE (
+    WHERE forms.festival_id = (SELECT projects.festival_id FROM projects WHERE projects.id = $1)
+    AND (
         (
             form_condition_excludes.project_id IS NULL
             OR form_condition_excludes.project_id <> $1
@@ -82,6 +83,8 @@ GROUP BY forms.id
         let row = row.context("Failed to select from forms")?;
         let form = Form {
             id: row.id,
+            festival_id: row.festival_id,
+            version: row.version,
             created_at: row.created_at,
             author_id: row.author_id,
             name: row.name,
@@ -89,7 +92,8 @@ GROUP BY forms.id
             starts_at: row.starts_at,
             ends_at: row.ends_at,
             items: row.items,
-            answer_notification_webhook:row.answer_notification_webhook
+            answer_notification_webhook: row.answer_notification_webhook,
+            access_control: row.access_control,
         };
 
         let include_ids = row.include_ids.unwrap_or_default();
@@ -0,0 +1,126 @@
+use crate::model::pending_project::{PendingProject, PendingProjectWithRegistrationProgress};
+use crate::model::project::{ProjectAttributes, ProjectCategory};
+use crate::model::user::{User, UserAssignment, UserCategory, UserRole};
+
+use anyhow::{Context, Result};
+use futures::stream::{BoxStream, StreamExt};
+use uuid::Uuid;
+
+pub fn list_pending_projects<'a, E>(
+    conn: E,
+    festival_id: Uuid,
+) -> BoxStream<'a, Result<PendingProjectWithRegistrationProgress>>
+where
+    E: sqlx::Executor<'a, Database = sqlx::Postgres> + 'a,
+{
+    // TODO: Remove tedeous null forcings
+    sqlx::query!(
+        r#"
+SELECT
+        pending_projects.id AS "id!",
+        pending_projects.festival_id AS "festival_id!",
+        pending_projects.created_at AS "created_at!",
+        pending_projects.updated_at AS "updated_at!",
+        pending_projects.name AS "name!",
+        pending_projects.kana_name AS "kana_name!",
+        pending_projects.group_name AS "group_name!",
+        pending_projects.kana_group_name AS "kana_group_name!",
+        pending_projects.description AS "description!",
+        pending_projects.category AS "category!: ProjectCategory",
+        pending_projects.attributes AS "attributes!: ProjectAttributes",
+        pending_projects.exceptional_complete_deadline,
+        owners.id AS "owner_id!",
+        owners.created_at AS "owner_created_at!",
+        owners.first_name AS "owner_first_name!",
+        owners.kana_first_name AS "owner_kana_first_name!",
+        owners.last_name AS "owner_last_name!",
+        owners.kana_last_name AS "owner_kana_last_name!",
+        owners.phone_number AS "owner_phone_number!",
+        owners.email AS "owner_email!",
+        owners.role AS "owner_role!: UserRole",
+        owners.category AS "owner_category!: UserCategory",
+        owners.assignment AS "owner_assignment: UserAssignment",
+        owners.assignment_owner_project_id AS owner_assignment_owner_project_id,
+        owners.assignment_subowner_project_id AS owner_assignment_subowner_project_id,
+        owners.assignment_owner_pending_project_id AS owner_assignment_owner_pending_project_id,
+        owners.custom_role_id AS owner_custom_role_id,
+        owners.suspended_at AS owner_suspended_at,
+        owners.suspended_by AS owner_suspended_by,
+        owners.suspension_reason AS owner_suspension_reason,
+        owners.tokens_revoked_at AS owner_tokens_revoked_at,
+        owner_custom_roles.permissions AS "owner_custom_role_permissions?",
+        (
+            SELECT count(registration_forms.id)
+            FROM registration_forms
+            WHERE registration_forms.festival_id = pending_projects.festival_id
+                AND EXISTS (
+                    SELECT 1
+                    FROM registration_form_project_query_conjunctions
+                    WHERE registration_form_project_query_conjunctions.registration_form_id = registration_forms.id
+                        AND registration_form_project_query_conjunctions.category = pending_projects.category IS NOT FALSE
+                        AND registration_form_project_query_conjunctions.attributes | pending_projects.attributes = pending_projects.attributes
+                )
+        ) AS "registration_form_count!",
+        (
+            SELECT count(*)
+            FROM registration_form_answers
+            WHERE registration_form_answers.pending_project_id = pending_projects.id
+        ) AS "answered_registration_form_count!"
+FROM pending_projects
+INNER JOIN users AS owners ON owners.id = pending_projects.owner_id
+LEFT JOIN custom_roles AS owner_custom_roles ON owner_custom_roles.id = owners.custom_role_id
+WHERE pending_projects.festival_id = $1
+"#,
+        festival_id
+    )
+    .fetch(conn)
+    .map(|row| {
+        let row = row.context("Failed to select from pending projects")?;
+
+        let pending_project = PendingProject {
+            id: row.id,
+            festival_id: row.festival_id,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+            name: row.name,
+            kana_name: row.kana_name,
+            group_name: row.group_name,
+            kana_group_name: row.kana_group_name,
+            description: row.description,
+            category: row.category,
+            attributes: row.attributes,
+            exceptional_complete_deadline: row.exceptional_complete_deadline,
+            owner_id: row.owner_id.clone(),
+        };
+        let owner = User {
+            id: row.owner_id,
+            created_at: row.owner_created_at,
+            first_name: row.owner_first_name,
+            kana_first_name: row.owner_kana_first_name,
+            last_name: row.owner_last_name,
+            kana_last_name: row.owner_kana_last_name,
+            phone_number: row.owner_phone_number,
+            email: row.owner_email,
+            role: row.owner_role,
+            category: row.owner_category,
+            assignment: row.owner_assignment,
+            assignment_owner_project_id: row.owner_assignment_owner_project_id,
+            assignment_subowner_project_id: row.owner_assignment_subowner_project_id,
+            assignment_owner_pending_project_id: row.owner_assignment_owner_pending_project_id,
+            custom_role_id: row.owner_custom_role_id,
+            custom_role_permissions: row.owner_custom_role_permissions,
+            suspended_at: row.owner_suspended_at,
+            suspended_by: row.owner_suspended_by,
+            suspension_reason: row.owner_suspension_reason,
+            tokens_revoked_at: row.owner_tokens_revoked_at,
+        };
+
+        Ok(PendingProjectWithRegistrationProgress {
+            pending_project,
+            owner,
+            registration_form_count: row.registration_form_count,
+            answered_registration_form_count: row.answered_registration_form_count,
+        })
+    })
+    .boxed()
+}
@@ -33,11 +33,13 @@ GROUP BY registration_forms.id
         let row = row.context("Failed to select from registration forms")?;
         let registration_form = RegistrationForm {
             id: row.id,
+            festival_id: row.festival_id,
             created_at: row.created_at,
             author_id: row.author_id,
             name: row.name,
             description: row.description,
             items: row.items,
+            access_control: row.access_control,
         };
 
         let query = row
@@ -27,7 +27,9 @@ WITH project_registration_forms AS (
                 AND registration_form_project_query_conjunctions.attributes | projects.attributes = projects.attributes
             ))
         FROM registration_form_project_query_conjunctions, projects
-        WHERE registration_form_project_query_conjunctions.registration_form_id = registration_forms.id AND projects.id = $1
+        WHERE registration_form_project_query_conjunctions.registration_form_id = registration_forms.id
+            AND projects.id = $1
+            AND projects.festival_id = registration_forms.festival_id
     )
 )
 SELECT
@@ -53,11 +55,13 @@ GROUP BY registration_forms.id
         let row = row.context("Failed to select from registration forms")?;
         let registration_form = RegistrationForm {
             id: row.id,
+            festival_id: row.festival_id,
             created_at: row.created_at,
             author_id: row.author_id,
             name: row.name,
             description: row.description,
             items: row.items,
+            access_control: row.access_control,
         };
 
         let query = row
@@ -0,0 +1,21 @@
+use crate::model::settings::Settings;
+
+use anyhow::{Context, Result};
+use futures::stream::{BoxStream, StreamExt};
+
+pub fn list_settings<'a, E>(conn: E) -> BoxStream<'a, Result<Settings>>
+where
+    E: sqlx::Executor<'a, Database = sqlx::Postgres> + 'a,
+{
+    sqlx::query_as!(
+        Settings,
+        r#"
+SELECT updated_at, author_id, administrator_email, active_festival_id, project_creation_periods
+FROM settings
+ORDER BY revision DESC
+"#
+    )
+    .fetch(conn)
+    .map(|result| result.context("Failed to select from settings"))
+    .boxed()
+}
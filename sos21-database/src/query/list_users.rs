@@ -7,8 +7,15 @@ pub fn list_users<'a, E>(conn: E) -> BoxStream<'a, Result<User>>
 where
     E: sqlx::Executor<'a, Database = sqlx::Postgres> + 'a,
 {
-    sqlx::query_as_unchecked!(User, "SELECT * FROM users")
-        .fetch(conn)
-        .map(|result| result.context("Failed to select from users"))
-        .boxed()
+    sqlx::query_as_unchecked!(
+        User,
+        r#"
+SELECT users.*, custom_roles.permissions AS "custom_role_permissions?"
+FROM users
+LEFT JOIN custom_roles ON custom_roles.id = users.custom_role_id
+"#
+    )
+    .fetch(conn)
+    .map(|result| result.context("Failed to select from users"))
+    .boxed()
 }
@@ -3,29 +3,39 @@ pub mod login;
 pub use authentication::Authentication;
 pub use login::Login;
 
+pub mod api_key_repository;
 pub mod config;
+pub mod custom_role_repository;
+pub mod festival_repository;
 pub mod file_distribution_repository;
 pub mod file_repository;
 pub mod file_sharing_repository;
 pub mod form_answer_repository;
 pub mod form_repository;
+pub mod form_template_repository;
 pub mod object_repository;
 pub mod pending_project_repository;
 pub mod project_repository;
 pub mod registration_form_answer_repository;
 pub mod registration_form_repository;
+pub mod settings_repository;
 pub mod user_invitation_repository;
 pub mod user_repository;
+pub use api_key_repository::ApiKeyRepository;
 pub use config::ConfigContext;
+pub use custom_role_repository::CustomRoleRepository;
+pub use festival_repository::FestivalRepository;
 pub use file_distribution_repository::FileDistributionRepository;
 pub use file_repository::FileRepository;
 pub use file_sharing_repository::FileSharingRepository;
 pub use form_answer_repository::FormAnswerRepository;
 pub use form_repository::FormRepository;
+pub use form_template_repository::FormTemplateRepository;
 pub use object_repository::ObjectRepository;
 pub use pending_project_repository::PendingProjectRepository;
 pub use project_repository::ProjectRepository;
 pub use registration_form_answer_repository::RegistrationFormAnswerRepository;
 pub use registration_form_repository::RegistrationFormRepository;
+pub use settings_repository::SettingsRepository;
 pub use user_invitation_repository::UserInvitationRepository;
 pub use user_repository::UserRepository;
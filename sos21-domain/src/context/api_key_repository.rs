@@ -0,0 +1,70 @@
+use crate::model::api_key::{ApiKey, ApiKeyId, ApiKeyTokenDigest};
+
+use anyhow::Result;
+
+#[async_trait::async_trait]
+pub trait ApiKeyRepository {
+    async fn store_api_key(&self, key: ApiKey) -> Result<()>;
+    async fn get_api_key(&self, id: ApiKeyId) -> Result<Option<ApiKey>>;
+    async fn get_api_key_by_token_digest(
+        &self,
+        digest: &ApiKeyTokenDigest,
+    ) -> Result<Option<ApiKey>>;
+    async fn list_api_keys(&self) -> Result<Vec<ApiKey>>;
+}
+
+#[macro_export]
+macro_rules! delegate_api_key_repository {
+    (impl $(<$($vars:ident $(: $c0:ident $(+ $cs:ident)* )? ),*>)? ApiKeyRepository for $ty:ty {
+        $sel:ident $target:block
+    }) => {
+        #[::async_trait::async_trait]
+        impl $(<$($vars$(: $c0 $(+ $cs)* )?,)*>)? $crate::context::ApiKeyRepository for $ty {
+            async fn store_api_key(
+                &$sel,
+                key: $crate::model::api_key::ApiKey,
+            ) -> ::anyhow::Result<()> {
+                $target.store_api_key(key).await
+            }
+            async fn get_api_key(
+                &$sel,
+                id: $crate::model::api_key::ApiKeyId,
+            ) -> ::anyhow::Result<Option<$crate::model::api_key::ApiKey>> {
+                $target.get_api_key(id).await
+            }
+            async fn get_api_key_by_token_digest(
+                &$sel,
+                digest: &$crate::model::api_key::ApiKeyTokenDigest,
+            ) -> ::anyhow::Result<Option<$crate::model::api_key::ApiKey>> {
+                $target.get_api_key_by_token_digest(digest).await
+            }
+            async fn list_api_keys(
+                &$sel,
+            ) -> ::anyhow::Result<Vec<$crate::model::api_key::ApiKey>> {
+                $target.list_api_keys().await
+            }
+        }
+    };
+}
+
+#[async_trait::async_trait]
+impl<C: ApiKeyRepository + Sync> ApiKeyRepository for &C {
+    async fn store_api_key(&self, key: ApiKey) -> Result<()> {
+        <C as ApiKeyRepository>::store_api_key(self, key).await
+    }
+
+    async fn get_api_key(&self, id: ApiKeyId) -> Result<Option<ApiKey>> {
+        <C as ApiKeyRepository>::get_api_key(self, id).await
+    }
+
+    async fn get_api_key_by_token_digest(
+        &self,
+        digest: &ApiKeyTokenDigest,
+    ) -> Result<Option<ApiKey>> {
+        <C as ApiKeyRepository>::get_api_key_by_token_digest(self, digest).await
+    }
+
+    async fn list_api_keys(&self) -> Result<Vec<ApiKey>> {
+        <C as ApiKeyRepository>::list_api_keys(self).await
+    }
+}
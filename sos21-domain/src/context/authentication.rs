@@ -1,11 +1,14 @@
 use crate::context::{
-    ConfigContext, FileDistributionRepository, FileRepository, FileSharingRepository,
-    FormAnswerRepository, FormRepository, ObjectRepository, PendingProjectRepository,
+    ApiKeyRepository, ConfigContext, CustomRoleRepository, FestivalRepository,
+    FileDistributionRepository, FileRepository, FileSharingRepository, FormAnswerRepository,
+    FormRepository, FormTemplateRepository, ObjectRepository, PendingProjectRepository,
     ProjectRepository, RegistrationFormAnswerRepository, RegistrationFormRepository,
-    UserInvitationRepository, UserRepository,
+    SettingsRepository, UserInvitationRepository, UserRepository,
 };
+use crate::model::date_time::DateTime;
 use crate::model::user::{email, UserEmailAddress, UserId};
 
+use anyhow::Context;
 use thiserror::Error;
 
 #[derive(Debug, Clone)]
@@ -13,34 +16,86 @@ pub struct Authentication<C> {
     inner: C,
     user_id: UserId,
     email: UserEmailAddress,
+    issued_at: DateTime,
 }
 
-#[derive(Debug, Error, Clone)]
+#[derive(Debug, Error)]
 pub enum AuthenticationError {
     #[error("invalid email address")]
     InvalidEmailAddress,
     #[error("not a university email address")]
     NotUniversityEmailAddress,
+    #[error(transparent)]
+    Internal(anyhow::Error),
 }
 
 impl<C> Authentication<C> {
-    pub fn new(inner: C, user_id: String, email: String) -> Result<Self, AuthenticationError> {
+    /// Authenticate the user with `email` by the token issued at `issued_at`.
+    ///
+    /// The domain of `email` has to be one of [`ConfigContext::allowed_email_domains`],
+    /// unless the user has already signed up with `email`, or the address is invited with
+    /// [`UserInvitation::allows_unlisted_domain`] and the invitation has not expired.
+    /// The invitation is only consulted on sign-up so that deleting it afterwards or narrowing
+    /// the allowed domains does not lock out the users who have already signed up.
+    ///
+    /// [`UserInvitation::allows_unlisted_domain`]: crate::model::user_invitation::UserInvitation::allows_unlisted_domain
+    pub async fn new(
+        inner: C,
+        user_id: String,
+        email: String,
+        issued_at: DateTime,
+    ) -> Result<Self, AuthenticationError>
+    where
+        C: ConfigContext + UserRepository + UserInvitationRepository,
+    {
         let user_id = UserId(user_id);
-        match UserEmailAddress::from_string(email) {
-            Ok(email) => Ok(Authentication {
-                inner,
-                user_id,
-                email,
-            }),
+        let email = match UserEmailAddress::from_string_with_domains(
+            email.clone(),
+            inner.allowed_email_domains(),
+        ) {
+            Ok(email) => email,
             Err(err) => match err.kind() {
                 email::EmailAddressErrorKind::InvalidEmailAddress => {
-                    Err(AuthenticationError::InvalidEmailAddress)
+                    return Err(AuthenticationError::InvalidEmailAddress)
                 }
                 email::EmailAddressErrorKind::NotUniversityEmailAddress => {
-                    Err(AuthenticationError::NotUniversityEmailAddress)
+                    // the address is syntactically valid here
+                    let email = UserEmailAddress::from_string(email)
+                        .map_err(|_| AuthenticationError::InvalidEmailAddress)?;
+                    let user = inner
+                        .get_user(user_id.clone())
+                        .await
+                        .context("Failed to get user")
+                        .map_err(AuthenticationError::Internal)?;
+                    // users who have signed up with the address do not need the invitation any more
+                    if matches!(user, Some(user) if user.email() == &email) {
+                        email
+                    } else {
+                        let invitation = inner
+                            .get_user_invitation_by_email(&email)
+                            .await
+                            .context("Failed to get user invitation")
+                            .map_err(AuthenticationError::Internal)?;
+                        match invitation {
+                            Some(invitation)
+                                if invitation.allows_unlisted_domain()
+                                    && !invitation.is_expired() =>
+                            {
+                                email
+                            }
+                            _ => return Err(AuthenticationError::NotUniversityEmailAddress),
+                        }
+                    }
                 }
             },
-        }
+        };
+
+        Ok(Authentication {
+            inner,
+            user_id,
+            email,
+            issued_at,
+        })
     }
 
     pub fn authenticated_user(&self) -> UserId {
@@ -51,6 +106,10 @@ impl<C> Authentication<C> {
         self.email.clone()
     }
 
+    pub fn issued_at(&self) -> DateTime {
+        self.issued_at
+    }
+
     pub fn into_inner(self) -> C {
         self.inner
     }
@@ -123,6 +182,36 @@ crate::delegate_registration_form_answer_repository! {
     }
 }
 
+crate::delegate_form_template_repository! {
+    impl<C: FormTemplateRepository + Send + Sync> FormTemplateRepository for Authentication<C> {
+        self { &self.inner }
+    }
+}
+
+crate::delegate_api_key_repository! {
+    impl<C: ApiKeyRepository + Send + Sync> ApiKeyRepository for Authentication<C> {
+        self { &self.inner }
+    }
+}
+
+crate::delegate_custom_role_repository! {
+    impl<C: CustomRoleRepository + Send + Sync> CustomRoleRepository for Authentication<C> {
+        self { &self.inner }
+    }
+}
+
+crate::delegate_festival_repository! {
+    impl<C: FestivalRepository + Send + Sync> FestivalRepository for Authentication<C> {
+        self { &self.inner }
+    }
+}
+
+crate::delegate_settings_repository! {
+    impl<C: SettingsRepository + Send + Sync> SettingsRepository for Authentication<C> {
+        self { &self.inner }
+    }
+}
+
 crate::delegate_user_invitation_repository! {
     impl<C: UserInvitationRepository + Send + Sync> UserInvitationRepository for Authentication<C> {
         self { &self.inner }
@@ -1,10 +1,17 @@
+use crate::model::festival::FestivalId;
 use crate::model::project::ProjectCategory;
 use crate::model::project_creation_period::ProjectCreationPeriod;
-use crate::model::user::UserEmailAddress;
+use crate::model::user::{email::AllowedEmailDomains, UserEmailAddress};
 
 pub trait ConfigContext {
     fn administrator_email(&self) -> &UserEmailAddress;
     fn project_creation_period_for(&self, category: ProjectCategory) -> ProjectCreationPeriod;
+    /// The domains of email addresses from which users can sign up without an invitation.
+    fn allowed_email_domains(&self) -> &AllowedEmailDomains;
+    /// The festival which newly created projects, forms, etc. belong to.
+    ///
+    /// The data of the other festivals are kept read-only.
+    fn active_festival_id(&self) -> FestivalId;
 }
 
 #[macro_export]
@@ -22,6 +29,12 @@ macro_rules! delegate_config_context {
             ) -> $crate::model::project_creation_period::ProjectCreationPeriod {
                 $target.project_creation_period_for(category)
             }
+            fn allowed_email_domains(&$sel) -> &$crate::model::user::email::AllowedEmailDomains {
+                $target.allowed_email_domains()
+            }
+            fn active_festival_id(&$sel) -> $crate::model::festival::FestivalId {
+                $target.active_festival_id()
+            }
         }
     }
 }
@@ -34,4 +47,12 @@ impl<C: ConfigContext> ConfigContext for &C {
     fn project_creation_period_for(&self, category: ProjectCategory) -> ProjectCreationPeriod {
         <C as ConfigContext>::project_creation_period_for(self, category)
     }
+
+    fn allowed_email_domains(&self) -> &AllowedEmailDomains {
+        <C as ConfigContext>::allowed_email_domains(self)
+    }
+
+    fn active_festival_id(&self) -> FestivalId {
+        <C as ConfigContext>::active_festival_id(self)
+    }
 }
@@ -0,0 +1,64 @@
+use crate::model::custom_role::{CustomRole, CustomRoleId};
+
+use anyhow::Result;
+
+#[async_trait::async_trait]
+pub trait CustomRoleRepository {
+    async fn store_custom_role(&self, role: CustomRole) -> Result<()>;
+    async fn delete_custom_role(&self, id: CustomRoleId) -> Result<()>;
+    async fn get_custom_role(&self, id: CustomRoleId) -> Result<Option<CustomRole>>;
+    async fn list_custom_roles(&self) -> Result<Vec<CustomRole>>;
+}
+
+#[macro_export]
+macro_rules! delegate_custom_role_repository {
+    (impl $(<$($vars:ident $(: $c0:ident $(+ $cs:ident)* )? ),*>)? CustomRoleRepository for $ty:ty {
+        $sel:ident $target:block
+    }) => {
+        #[::async_trait::async_trait]
+        impl $(<$($vars$(: $c0 $(+ $cs)* )?,)*>)? $crate::context::CustomRoleRepository for $ty {
+            async fn store_custom_role(
+                &$sel,
+                role: $crate::model::custom_role::CustomRole,
+            ) -> ::anyhow::Result<()> {
+                $target.store_custom_role(role).await
+            }
+            async fn delete_custom_role(
+                &$sel,
+                id: $crate::model::custom_role::CustomRoleId,
+            ) -> ::anyhow::Result<()> {
+                $target.delete_custom_role(id).await
+            }
+            async fn get_custom_role(
+                &$sel,
+                id: $crate::model::custom_role::CustomRoleId,
+            ) -> ::anyhow::Result<Option<$crate::model::custom_role::CustomRole>> {
+                $target.get_custom_role(id).await
+            }
+            async fn list_custom_roles(
+                &$sel,
+            ) -> ::anyhow::Result<Vec<$crate::model::custom_role::CustomRole>> {
+                $target.list_custom_roles().await
+            }
+        }
+    };
+}
+
+#[async_trait::async_trait]
+impl<C: CustomRoleRepository + Sync> CustomRoleRepository for &C {
+    async fn store_custom_role(&self, role: CustomRole) -> Result<()> {
+        <C as CustomRoleRepository>::store_custom_role(self, role).await
+    }
+
+    async fn delete_custom_role(&self, id: CustomRoleId) -> Result<()> {
+        <C as CustomRoleRepository>::delete_custom_role(self, id).await
+    }
+
+    async fn get_custom_role(&self, id: CustomRoleId) -> Result<Option<CustomRole>> {
+        <C as CustomRoleRepository>::get_custom_role(self, id).await
+    }
+
+    async fn list_custom_roles(&self) -> Result<Vec<CustomRole>> {
+        <C as CustomRoleRepository>::list_custom_roles(self).await
+    }
+}
@@ -0,0 +1,54 @@
+use crate::model::festival::{Festival, FestivalId};
+
+use anyhow::Result;
+
+#[async_trait::async_trait]
+pub trait FestivalRepository {
+    async fn store_festival(&self, festival: Festival) -> Result<()>;
+    async fn get_festival(&self, id: FestivalId) -> Result<Option<Festival>>;
+    /// List the festivals in the order of newest first.
+    async fn list_festivals(&self) -> Result<Vec<Festival>>;
+}
+
+#[macro_export]
+macro_rules! delegate_festival_repository {
+    (impl $(<$($vars:ident $(: $c0:ident $(+ $cs:ident)* )? ),*>)? FestivalRepository for $ty:ty {
+        $sel:ident $target:block
+    }) => {
+        #[::async_trait::async_trait]
+        impl $(<$($vars$(: $c0 $(+ $cs)* )?,)*>)? $crate::context::FestivalRepository for $ty {
+            async fn store_festival(
+                &$sel,
+                festival: $crate::model::festival::Festival,
+            ) -> ::anyhow::Result<()> {
+                $target.store_festival(festival).await
+            }
+            async fn get_festival(
+                &$sel,
+                id: $crate::model::festival::FestivalId,
+            ) -> ::anyhow::Result<Option<$crate::model::festival::Festival>> {
+                $target.get_festival(id).await
+            }
+            async fn list_festivals(
+                &$sel,
+            ) -> ::anyhow::Result<Vec<$crate::model::festival::Festival>> {
+                $target.list_festivals().await
+            }
+        }
+    };
+}
+
+#[async_trait::async_trait]
+impl<C: FestivalRepository + Sync> FestivalRepository for &C {
+    async fn store_festival(&self, festival: Festival) -> Result<()> {
+        <C as FestivalRepository>::store_festival(self, festival).await
+    }
+
+    async fn get_festival(&self, id: FestivalId) -> Result<Option<Festival>> {
+        <C as FestivalRepository>::get_festival(self, id).await
+    }
+
+    async fn list_festivals(&self) -> Result<Vec<Festival>> {
+        <C as FestivalRepository>::list_festivals(self).await
+    }
+}
@@ -1,6 +1,6 @@
 use crate::model::{
     form::FormId,
-    form_answer::{FormAnswer, FormAnswerId},
+    form_answer::{FormAnswer, FormAnswerDraft, FormAnswerId},
     project::ProjectId,
 };
 
@@ -16,6 +16,13 @@ pub trait FormAnswerRepository {
         project_id: ProjectId,
     ) -> Result<Option<FormAnswer>>;
     async fn list_form_answers(&self, form_id: FormId) -> Result<Vec<FormAnswer>>;
+    async fn store_form_answer_draft(&self, draft: FormAnswerDraft) -> Result<()>;
+    async fn get_form_answer_draft(
+        &self,
+        form_id: FormId,
+        project_id: ProjectId,
+    ) -> Result<Option<FormAnswerDraft>>;
+    async fn delete_form_answer_draft(&self, form_id: FormId, project_id: ProjectId) -> Result<()>;
 }
 
 #[macro_export]
@@ -57,6 +64,28 @@ macro_rules! delegate_form_answer_repository {
             > {
                 $target.list_form_answers(form_id).await
             }
+            async fn store_form_answer_draft(
+                &$sel,
+                draft: $crate::model::form_answer::FormAnswerDraft
+            ) -> ::anyhow::Result<()> {
+                $target.store_form_answer_draft(draft).await
+            }
+            async fn get_form_answer_draft(
+                &$sel,
+                form_id: $crate::model::form::FormId,
+                project_id: $crate::model::project::ProjectId,
+            ) -> ::anyhow::Result<
+                Option<$crate::model::form_answer::FormAnswerDraft>
+            > {
+                $target.get_form_answer_draft(form_id, project_id).await
+            }
+            async fn delete_form_answer_draft(
+                &$sel,
+                form_id: $crate::model::form::FormId,
+                project_id: $crate::model::project::ProjectId,
+            ) -> ::anyhow::Result<()> {
+                $target.delete_form_answer_draft(form_id, project_id).await
+            }
         }
     }
 }
@@ -83,4 +112,20 @@ impl<C: FormAnswerRepository + Sync> FormAnswerRepository for &C {
     async fn list_form_answers(&self, form_id: FormId) -> Result<Vec<FormAnswer>> {
         <C as FormAnswerRepository>::list_form_answers(self, form_id).await
     }
+
+    async fn store_form_answer_draft(&self, draft: FormAnswerDraft) -> Result<()> {
+        <C as FormAnswerRepository>::store_form_answer_draft(self, draft).await
+    }
+
+    async fn get_form_answer_draft(
+        &self,
+        form_id: FormId,
+        project_id: ProjectId,
+    ) -> Result<Option<FormAnswerDraft>> {
+        <C as FormAnswerRepository>::get_form_answer_draft(self, form_id, project_id).await
+    }
+
+    async fn delete_form_answer_draft(&self, form_id: FormId, project_id: ProjectId) -> Result<()> {
+        <C as FormAnswerRepository>::delete_form_answer_draft(self, form_id, project_id).await
+    }
 }
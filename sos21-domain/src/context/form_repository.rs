@@ -1,5 +1,5 @@
 use crate::model::{
-    form::{Form, FormId},
+    form::{Form, FormId, FormRevision, FormVersion},
     project::ProjectId,
 };
 
@@ -15,6 +15,13 @@ pub struct ProjectForm {
 pub trait FormRepository {
     async fn store_form(&self, form: Form) -> Result<()>;
     async fn get_form(&self, id: FormId) -> Result<Option<Form>>;
+    async fn get_form_revision(
+        &self,
+        id: FormId,
+        version: FormVersion,
+    ) -> Result<Option<FormRevision>>;
+    /// Lists the revisions of the form in ascending order of the version.
+    async fn list_form_revisions(&self, id: FormId) -> Result<Vec<FormRevision>>;
     // TODO: Move this to query service
     async fn list_forms(&self) -> Result<Vec<Form>>;
     async fn list_forms_by_project(&self, id: ProjectId) -> Result<Vec<ProjectForm>>;
@@ -39,6 +46,19 @@ macro_rules! delegate_form_repository {
             ) -> ::anyhow::Result<Option<$crate::model::form::Form>> {
                 $target.get_form(id).await
             }
+            async fn get_form_revision(
+                &$sel,
+                id: $crate::model::form::FormId,
+                version: $crate::model::form::FormVersion
+            ) -> ::anyhow::Result<Option<$crate::model::form::FormRevision>> {
+                $target.get_form_revision(id, version).await
+            }
+            async fn list_form_revisions(
+                &$sel,
+                id: $crate::model::form::FormId
+            ) -> ::anyhow::Result<Vec<$crate::model::form::FormRevision>> {
+                $target.list_form_revisions(id).await
+            }
             async fn list_forms(
                 &$sel
             ) -> ::anyhow::Result<Vec<$crate::model::form::Form>> {
@@ -64,6 +84,18 @@ impl<C: FormRepository + Sync> FormRepository for &C {
         <C as FormRepository>::get_form(self, id).await
     }
 
+    async fn get_form_revision(
+        &self,
+        id: FormId,
+        version: FormVersion,
+    ) -> Result<Option<FormRevision>> {
+        <C as FormRepository>::get_form_revision(self, id, version).await
+    }
+
+    async fn list_form_revisions(&self, id: FormId) -> Result<Vec<FormRevision>> {
+        <C as FormRepository>::list_form_revisions(self, id).await
+    }
+
     async fn list_forms(&self) -> Result<Vec<Form>> {
         <C as FormRepository>::list_forms(self).await
     }
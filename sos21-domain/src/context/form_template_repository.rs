@@ -0,0 +1,53 @@
+use crate::model::form_template::{FormTemplate, FormTemplateId};
+
+use anyhow::Result;
+
+#[async_trait::async_trait]
+pub trait FormTemplateRepository {
+    async fn store_form_template(&self, template: FormTemplate) -> Result<()>;
+    async fn get_form_template(&self, id: FormTemplateId) -> Result<Option<FormTemplate>>;
+    async fn list_form_templates(&self) -> Result<Vec<FormTemplate>>;
+}
+
+#[macro_export]
+macro_rules! delegate_form_template_repository {
+    (impl $(<$($vars:ident $(: $c0:ident $(+ $cs:ident)* )? ),*>)? FormTemplateRepository for $ty:ty {
+        $sel:ident $target:block
+    }) => {
+        #[::async_trait::async_trait]
+        impl $(<$($vars$(: $c0 $(+ $cs)* )?,)*>)? $crate::context::FormTemplateRepository for $ty {
+            async fn store_form_template(
+                &$sel,
+                template: $crate::model::form_template::FormTemplate,
+            ) -> ::anyhow::Result<()> {
+                $target.store_form_template(template).await
+            }
+            async fn get_form_template(
+                &$sel,
+                id: $crate::model::form_template::FormTemplateId,
+            ) -> ::anyhow::Result<Option<$crate::model::form_template::FormTemplate>> {
+                $target.get_form_template(id).await
+            }
+            async fn list_form_templates(
+                &$sel,
+            ) -> ::anyhow::Result<Vec<$crate::model::form_template::FormTemplate>> {
+                $target.list_form_templates().await
+            }
+        }
+    };
+}
+
+#[async_trait::async_trait]
+impl<C: FormTemplateRepository + Sync> FormTemplateRepository for &C {
+    async fn store_form_template(&self, template: FormTemplate) -> Result<()> {
+        <C as FormTemplateRepository>::store_form_template(self, template).await
+    }
+
+    async fn get_form_template(&self, id: FormTemplateId) -> Result<Option<FormTemplate>> {
+        <C as FormTemplateRepository>::get_form_template(self, id).await
+    }
+
+    async fn list_form_templates(&self) -> Result<Vec<FormTemplate>> {
+        <C as FormTemplateRepository>::list_form_templates(self).await
+    }
+}
@@ -1,13 +1,23 @@
 use crate::context::{
-    authentication::Authentication, ConfigContext, FileDistributionRepository, FileRepository,
-    FileSharingRepository, FormAnswerRepository, FormRepository, ObjectRepository,
+    authentication::Authentication, ApiKeyRepository, ConfigContext, CustomRoleRepository,
+    FestivalRepository, FileDistributionRepository, FileRepository, FileSharingRepository,
+    FormAnswerRepository, FormRepository, FormTemplateRepository, ObjectRepository,
     PendingProjectRepository, ProjectRepository, RegistrationFormAnswerRepository,
-    RegistrationFormRepository, UserInvitationRepository, UserRepository,
+    RegistrationFormRepository, SettingsRepository, UserInvitationRepository, UserRepository,
+};
+use crate::model::{
+    api_key::ApiKeyToken,
+    date_time::DateTime,
+    phone_number::PhoneNumber,
+    user::{User, UserCategory, UserContent, UserId, UserKanaName, UserName, UserRole},
 };
-use crate::model::user::User;
 
+use anyhow::Context;
 use thiserror::Error;
 
+/// The ID of the user logged in with [`Login::system_administrator`].
+pub const SYSTEM_ADMINISTRATOR_ID: &str = "sos21-system-administrator";
+
 #[derive(Debug, Clone)]
 pub struct Login<C> {
     inner: C,
@@ -18,6 +28,12 @@ pub struct Login<C> {
 pub enum LoginError {
     #[error("the user is not signed up")]
     NotSignedUp,
+    #[error("invalid API key")]
+    InvalidApiKey,
+    #[error("the user is suspended")]
+    Suspended,
+    #[error("the token is revoked")]
+    RevokedToken,
     #[error(transparent)]
     Internal(anyhow::Error),
 }
@@ -32,12 +48,92 @@ impl<C> Login<C> {
             .await
             .map_err(LoginError::Internal)?;
 
-        if let Some(user) = user {
-            let inner = inner.into_inner();
-            Ok(Login { inner, user })
-        } else {
-            Err(LoginError::NotSignedUp)
+        let user = match user {
+            Some(user) => user,
+            None => return Err(LoginError::NotSignedUp),
+        };
+
+        if user.is_suspended() {
+            return Err(LoginError::Suspended);
+        }
+
+        if !user.accepts_token_issued_at(inner.issued_at()) {
+            return Err(LoginError::RevokedToken);
         }
+
+        let inner = inner.into_inner();
+        Ok(Login { inner, user })
+    }
+
+    /// Log in as the author of the API key, with the permissions limited to the scope of the key.
+    ///
+    /// The key acts on behalf of the author, so the operations are recorded as made by the author
+    /// and the login fails while the author is suspended. The accesses based on the ownership or
+    /// membership of the author and the ones granted by access control lists are not given,
+    /// see [`User::restrict_to_api_key`].
+    pub async fn with_api_key(inner: C, token: &ApiKeyToken) -> Result<Self, LoginError>
+    where
+        C: ApiKeyRepository + UserRepository,
+    {
+        let key = inner
+            .get_api_key_by_token_digest(&token.digest())
+            .await
+            .context("Failed to get API key")
+            .map_err(LoginError::Internal)?;
+        let mut key = match key {
+            Some(key) if key.is_active() => key,
+            _ => return Err(LoginError::InvalidApiKey),
+        };
+
+        let user = inner
+            .get_user(key.author_id().clone())
+            .await
+            .context("Failed to get the author of API key")
+            .map_err(LoginError::Internal)?;
+        let mut user = match user {
+            Some(user) => user,
+            None => return Err(LoginError::InvalidApiKey),
+        };
+        if user.is_suspended() {
+            return Err(LoginError::Suspended);
+        }
+        user.restrict_to_api_key(key.scope().permissions());
+
+        if key.record_use() {
+            inner
+                .store_api_key(key)
+                .await
+                .context("Failed to store API key")
+                .map_err(LoginError::Internal)?;
+        }
+
+        Ok(Login { inner, user })
+    }
+
+    /// Log in as a synthetic administrator who does not exist in the repository.
+    ///
+    /// This is intended for the operational tools run by the maintainers, and must not be used
+    /// to handle requests. The operations which record the login user cannot be stored,
+    /// since the user is not found in the repository.
+    pub fn system_administrator(inner: C) -> Self
+    where
+        C: ConfigContext,
+    {
+        let user = User::from_content(UserContent {
+            id: UserId(SYSTEM_ADMINISTRATOR_ID.to_owned()),
+            created_at: DateTime::now(),
+            name: UserName::from_string("sos21", "admin").unwrap(),
+            kana_name: UserKanaName::from_string("システム", "カンリシャ").unwrap(),
+            phone_number: PhoneNumber::from_string("+81000000000").unwrap(),
+            email: inner.administrator_email().clone(),
+            role: UserRole::Administrator,
+            custom_role: None,
+            category: UserCategory::AcademicStaff,
+            assignment: None,
+            suspension: None,
+            tokens_revoked_at: None,
+        });
+        Login { inner, user }
     }
 
     pub fn login_user(&self) -> &User {
@@ -116,6 +212,36 @@ crate::delegate_registration_form_answer_repository! {
     }
 }
 
+crate::delegate_form_template_repository! {
+    impl<C: FormTemplateRepository + Send + Sync> FormTemplateRepository for Login<C> {
+        self { &self.inner }
+    }
+}
+
+crate::delegate_api_key_repository! {
+    impl<C: ApiKeyRepository + Send + Sync> ApiKeyRepository for Login<C> {
+        self { &self.inner }
+    }
+}
+
+crate::delegate_custom_role_repository! {
+    impl<C: CustomRoleRepository + Send + Sync> CustomRoleRepository for Login<C> {
+        self { &self.inner }
+    }
+}
+
+crate::delegate_festival_repository! {
+    impl<C: FestivalRepository + Send + Sync> FestivalRepository for Login<C> {
+        self { &self.inner }
+    }
+}
+
+crate::delegate_settings_repository! {
+    impl<C: SettingsRepository + Send + Sync> SettingsRepository for Login<C> {
+        self { &self.inner }
+    }
+}
+
 crate::delegate_user_invitation_repository! {
     impl<C: UserInvitationRepository + Send + Sync> UserInvitationRepository for Login<C> {
         self { &self.inner }
@@ -127,3 +253,191 @@ crate::delegate_config_context! {
         self { &self.inner }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{LoginError, SYSTEM_ADMINISTRATOR_ID};
+    use crate::context::{ApiKeyRepository, Authentication, Login};
+    use crate::model::{
+        api_key::ApiKeyScope,
+        date_time::DateTime,
+        permissions::Permissions,
+        user::{UserRole, UserSuspensionReason},
+    };
+    use crate::test;
+
+    #[tokio::test]
+    async fn test_with_api_key() {
+        let admin = test::model::new_admin_user();
+        let (key, token) = test::model::new_api_key(
+            admin.id().clone(),
+            ApiKeyScope::Permissions(Permissions::READ_ALL_USERS),
+        );
+        let app = test::build_mock_app()
+            .users(vec![admin.clone()])
+            .api_keys(vec![key.clone()])
+            .build();
+
+        let login = app.clone().login_with_api_key(&token).await;
+        let user = login.login_user();
+        assert_eq!(user.id(), admin.id());
+        assert!(user
+            .require_permissions(Permissions::READ_ALL_USERS)
+            .is_ok());
+        assert!(user
+            .require_permissions(Permissions::READ_ALL_PROJECTS)
+            .is_err());
+
+        let key = app.get_api_key(key.id()).await.unwrap().unwrap();
+        assert!(key.last_used_at().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_with_api_key_not_member() {
+        let user = test::model::new_general_user();
+        let project = test::model::new_general_project(user.id().clone());
+        let (key, token) =
+            test::model::new_api_key(user.id().clone(), ApiKeyScope::Role(UserRole::General));
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .api_keys(vec![key])
+            .build();
+
+        let login = app.login_with_api_key(&token).await;
+        let login_user = login.login_user();
+        assert_eq!(login_user.id(), user.id());
+        assert!(login_user.is_with_api_key());
+        assert!(!project.is_member(login_user));
+        assert!(!project.is_visible_to(login_user));
+        assert!(project.is_member(&user));
+    }
+
+    #[tokio::test]
+    async fn test_with_api_key_role_scope() {
+        let admin = test::model::new_admin_user();
+        let (key, token) =
+            test::model::new_api_key(admin.id().clone(), ApiKeyScope::Role(UserRole::Committee));
+        let app = test::build_mock_app()
+            .users(vec![admin.clone()])
+            .api_keys(vec![key])
+            .build();
+
+        let login = app.login_with_api_key(&token).await;
+        let user = login.login_user();
+        assert!(user
+            .require_permissions(Permissions::READ_ALL_PROJECTS)
+            .is_ok());
+        assert!(user
+            .require_permissions(Permissions::READ_ALL_USERS)
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_with_api_key_revoked() {
+        let admin = test::model::new_admin_user();
+        let (mut key, token) =
+            test::model::new_api_key(admin.id().clone(), ApiKeyScope::Role(UserRole::Committee));
+        key.revoke(&admin).unwrap();
+        let app = test::build_mock_app()
+            .users(vec![admin.clone()])
+            .api_keys(vec![key])
+            .build();
+
+        assert!(matches!(
+            Login::with_api_key(app, &token).await,
+            Err(LoginError::InvalidApiKey)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_with_api_key_expired() {
+        let admin = test::model::new_admin_user();
+        let (key, token) = test::model::new_expired_api_key(admin.id().clone());
+        let app = test::build_mock_app()
+            .users(vec![admin.clone()])
+            .api_keys(vec![key])
+            .build();
+
+        assert!(matches!(
+            Login::with_api_key(app, &token).await,
+            Err(LoginError::InvalidApiKey)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_suspended() {
+        let admin = test::model::new_admin_user();
+        let mut user = test::model::new_general_user();
+        user.suspend(&admin, UserSuspensionReason::from_string("spam").unwrap())
+            .unwrap();
+        let app = test::build_mock_app()
+            .users(vec![admin, user.clone()])
+            .build();
+
+        let auth = Authentication::new(
+            app,
+            user.id().clone().0,
+            user.email().clone().into_string(),
+            DateTime::now(),
+        )
+        .await
+        .unwrap();
+        assert!(matches!(Login::new(auth).await, Err(LoginError::Suspended)));
+    }
+
+    #[tokio::test]
+    async fn test_with_api_key_suspended_author() {
+        let admin = test::model::new_admin_user();
+        let mut other = test::model::new_admin_user();
+        let (key, token) =
+            test::model::new_api_key(other.id().clone(), ApiKeyScope::Role(UserRole::Committee));
+        other
+            .suspend(&admin, UserSuspensionReason::from_string("spam").unwrap())
+            .unwrap();
+        let app = test::build_mock_app()
+            .users(vec![admin, other])
+            .api_keys(vec![key])
+            .build();
+
+        assert!(matches!(
+            Login::with_api_key(app, &token).await,
+            Err(LoginError::Suspended)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_revoked_token() {
+        let admin = test::model::new_admin_user();
+        let mut user = test::model::new_general_user();
+        let issued_at = DateTime::from_utc(chrono::Utc::now() - chrono::Duration::minutes(1));
+        user.revoke_tokens(&admin).unwrap();
+        let app = test::build_mock_app()
+            .users(vec![admin, user.clone()])
+            .build();
+
+        let authenticate = |issued_at| {
+            Authentication::new(
+                app.clone(),
+                user.id().clone().0,
+                user.email().clone().into_string(),
+                issued_at,
+            )
+        };
+        let auth = authenticate(issued_at).await.unwrap();
+        assert!(matches!(
+            Login::new(auth).await,
+            Err(LoginError::RevokedToken)
+        ));
+        let auth = authenticate(DateTime::now()).await.unwrap();
+        assert!(Login::new(auth).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_system_administrator() {
+        let app = test::build_mock_app().build();
+        let login = Login::system_administrator(app);
+        let user = login.login_user();
+        assert_eq!(user.id().0, SYSTEM_ADMINISTRATOR_ID);
+        assert!(user.require_permissions(Permissions::all()).is_ok());
+    }
+}
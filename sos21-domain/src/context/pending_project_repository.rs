@@ -1,4 +1,5 @@
 use crate::model::{
+    festival::FestivalId,
     pending_project::{PendingProject, PendingProjectId},
     user::User,
 };
@@ -11,6 +12,16 @@ pub struct PendingProjectWithOwner {
     pub owner: User,
 }
 
+/// A pending project with the number of the registration forms it has to answer
+/// and the number of the ones it has answered.
+#[derive(Debug, Clone)]
+pub struct PendingProjectWithRegistrationProgress {
+    pub pending_project: PendingProject,
+    pub owner: User,
+    pub registration_form_count: u64,
+    pub answered_registration_form_count: u64,
+}
+
 #[async_trait::async_trait]
 pub trait PendingProjectRepository {
     async fn store_pending_project(&self, pending_project: PendingProject) -> Result<()>;
@@ -19,6 +30,16 @@ pub trait PendingProjectRepository {
         &self,
         id: PendingProjectId,
     ) -> Result<Option<PendingProjectWithOwner>>;
+    /// List pending projects in the festival with their progress of registration.
+    async fn list_pending_projects(
+        &self,
+        festival_id: FestivalId,
+    ) -> Result<Vec<PendingProjectWithRegistrationProgress>>;
+    /// List pending projects in the festival which have exceptional complete deadlines.
+    async fn list_pending_projects_with_exceptional_deadline(
+        &self,
+        festival_id: FestivalId,
+    ) -> Result<Vec<PendingProjectWithOwner>>;
 }
 
 #[macro_export]
@@ -48,6 +69,24 @@ macro_rules! delegate_pending_project_repository {
             > {
                 $target.get_pending_project(id).await
             }
+            async fn list_pending_projects(
+                &$sel,
+                festival_id: $crate::model::festival::FestivalId,
+            ) -> ::anyhow::Result<
+                Vec<$crate::context::pending_project_repository::PendingProjectWithRegistrationProgress>,
+            > {
+                $target.list_pending_projects(festival_id).await
+            }
+            async fn list_pending_projects_with_exceptional_deadline(
+                &$sel,
+                festival_id: $crate::model::festival::FestivalId,
+            ) -> ::anyhow::Result<
+                Vec<$crate::context::pending_project_repository::PendingProjectWithOwner>,
+            > {
+                $target
+                    .list_pending_projects_with_exceptional_deadline(festival_id)
+                    .await
+            }
         }
     }
 }
@@ -68,4 +107,22 @@ impl<C: PendingProjectRepository + Sync> PendingProjectRepository for &C {
     ) -> Result<Option<PendingProjectWithOwner>> {
         <C as PendingProjectRepository>::get_pending_project(self, id).await
     }
+
+    async fn list_pending_projects(
+        &self,
+        festival_id: FestivalId,
+    ) -> Result<Vec<PendingProjectWithRegistrationProgress>> {
+        <C as PendingProjectRepository>::list_pending_projects(self, festival_id).await
+    }
+
+    async fn list_pending_projects_with_exceptional_deadline(
+        &self,
+        festival_id: FestivalId,
+    ) -> Result<Vec<PendingProjectWithOwner>> {
+        <C as PendingProjectRepository>::list_pending_projects_with_exceptional_deadline(
+            self,
+            festival_id,
+        )
+        .await
+    }
 }
@@ -1,4 +1,5 @@
 use crate::model::{
+    festival::FestivalId,
     project::{Project, ProjectId, ProjectIndex},
     user::User,
 };
@@ -16,9 +17,14 @@ pub struct ProjectWithOwners {
 pub trait ProjectRepository {
     async fn store_project(&self, project: Project) -> Result<()>;
     async fn get_project(&self, id: ProjectId) -> Result<Option<ProjectWithOwners>>;
-    async fn get_project_by_index(&self, index: ProjectIndex) -> Result<Option<ProjectWithOwners>>;
+    async fn get_project_by_index(
+        &self,
+        festival_id: FestivalId,
+        index: ProjectIndex,
+    ) -> Result<Option<ProjectWithOwners>>;
     async fn count_projects(&self) -> Result<u64>;
-    async fn get_next_index(&self) -> Result<u64>;
+    /// Get the next index of projects, which is sequenced per festival.
+    async fn get_next_index(&self, festival_id: FestivalId) -> Result<u64>;
     async fn list_projects(&self) -> Result<Vec<ProjectWithOwners>>;
 }
 
@@ -45,11 +51,12 @@ macro_rules! delegate_project_repository {
             }
             async fn get_project_by_index(
                 &$sel,
+                festival_id: $crate::model::festival::FestivalId,
                 index: $crate::model::project::ProjectIndex,
             ) -> ::anyhow::Result<
                 Option<$crate::context::project_repository::ProjectWithOwners>,
             > {
-                $target.get_project_by_index(index).await
+                $target.get_project_by_index(festival_id, index).await
             }
             async fn count_projects(
                 &$sel,
@@ -59,8 +66,9 @@ macro_rules! delegate_project_repository {
 
             async fn get_next_index(
                 &$sel,
+                festival_id: $crate::model::festival::FestivalId,
             ) -> ::anyhow::Result<u64> {
-                $target.get_next_index().await
+                $target.get_next_index(festival_id).await
             }
 
             async fn list_projects(
@@ -84,16 +92,20 @@ impl<C: ProjectRepository + Sync> ProjectRepository for &C {
         <C as ProjectRepository>::get_project(self, id).await
     }
 
-    async fn get_project_by_index(&self, index: ProjectIndex) -> Result<Option<ProjectWithOwners>> {
-        <C as ProjectRepository>::get_project_by_index(self, index).await
+    async fn get_project_by_index(
+        &self,
+        festival_id: FestivalId,
+        index: ProjectIndex,
+    ) -> Result<Option<ProjectWithOwners>> {
+        <C as ProjectRepository>::get_project_by_index(self, festival_id, index).await
     }
 
     async fn count_projects(&self) -> Result<u64> {
         <C as ProjectRepository>::count_projects(self).await
     }
 
-    async fn get_next_index(&self) -> Result<u64> {
-        <C as ProjectRepository>::get_next_index(self).await
+    async fn get_next_index(&self, festival_id: FestivalId) -> Result<u64> {
+        <C as ProjectRepository>::get_next_index(self, festival_id).await
     }
 
     async fn list_projects(&self) -> Result<Vec<ProjectWithOwners>> {
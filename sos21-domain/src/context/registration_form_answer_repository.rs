@@ -2,7 +2,9 @@ use crate::model::{
     pending_project::PendingProjectId,
     project::ProjectId,
     registration_form::RegistrationFormId,
-    registration_form_answer::{RegistrationFormAnswer, RegistrationFormAnswerId},
+    registration_form_answer::{
+        RegistrationFormAnswer, RegistrationFormAnswerDraft, RegistrationFormAnswerId,
+    },
 };
 
 use anyhow::Result;
@@ -37,6 +39,20 @@ pub trait RegistrationFormAnswerRepository {
         &self,
         pending_project_id: PendingProjectId,
     ) -> Result<u64>;
+    async fn store_registration_form_answer_draft(
+        &self,
+        draft: RegistrationFormAnswerDraft,
+    ) -> Result<()>;
+    async fn get_registration_form_answer_draft(
+        &self,
+        registration_form_id: RegistrationFormId,
+        pending_project_id: PendingProjectId,
+    ) -> Result<Option<RegistrationFormAnswerDraft>>;
+    async fn delete_registration_form_answer_draft(
+        &self,
+        registration_form_id: RegistrationFormId,
+        pending_project_id: PendingProjectId,
+    ) -> Result<()>;
 }
 
 #[macro_export]
@@ -108,6 +124,28 @@ macro_rules! delegate_registration_form_answer_repository {
             ) -> ::anyhow::Result<u64> {
                 $target.count_registration_form_answers_by_pending_project(pending_project_id).await
             }
+            async fn store_registration_form_answer_draft(
+                &$sel,
+                draft: $crate::model::registration_form_answer::RegistrationFormAnswerDraft,
+            ) -> ::anyhow::Result<()> {
+                $target.store_registration_form_answer_draft(draft).await
+            }
+            async fn get_registration_form_answer_draft(
+                &$sel,
+                registration_form_id: $crate::model::registration_form::RegistrationFormId,
+                pending_project_id: $crate::model::pending_project::PendingProjectId,
+            ) -> ::anyhow::Result<
+                Option<$crate::model::registration_form_answer::RegistrationFormAnswerDraft>
+            > {
+                $target.get_registration_form_answer_draft(registration_form_id, pending_project_id).await
+            }
+            async fn delete_registration_form_answer_draft(
+                &$sel,
+                registration_form_id: $crate::model::registration_form::RegistrationFormId,
+                pending_project_id: $crate::model::pending_project::PendingProjectId,
+            ) -> ::anyhow::Result<()> {
+                $target.delete_registration_form_answer_draft(registration_form_id, pending_project_id).await
+            }
         }
     }
 }
@@ -178,4 +216,38 @@ impl<C: RegistrationFormAnswerRepository + Sync> RegistrationFormAnswerRepositor
         )
         .await
     }
+
+    async fn store_registration_form_answer_draft(
+        &self,
+        draft: RegistrationFormAnswerDraft,
+    ) -> Result<()> {
+        <C as RegistrationFormAnswerRepository>::store_registration_form_answer_draft(self, draft)
+            .await
+    }
+
+    async fn get_registration_form_answer_draft(
+        &self,
+        registration_form_id: RegistrationFormId,
+        pending_project_id: PendingProjectId,
+    ) -> Result<Option<RegistrationFormAnswerDraft>> {
+        <C as RegistrationFormAnswerRepository>::get_registration_form_answer_draft(
+            self,
+            registration_form_id,
+            pending_project_id,
+        )
+        .await
+    }
+
+    async fn delete_registration_form_answer_draft(
+        &self,
+        registration_form_id: RegistrationFormId,
+        pending_project_id: PendingProjectId,
+    ) -> Result<()> {
+        <C as RegistrationFormAnswerRepository>::delete_registration_form_answer_draft(
+            self,
+            registration_form_id,
+            pending_project_id,
+        )
+        .await
+    }
 }
@@ -0,0 +1,55 @@
+use crate::model::settings::Settings;
+
+use anyhow::Result;
+
+#[async_trait::async_trait]
+pub trait SettingsRepository {
+    /// Store the settings as the latest snapshot, keeping the previous ones as the history.
+    async fn store_settings(&self, settings: Settings) -> Result<()>;
+    /// Get the latest snapshot of the settings, or `None` if no settings have been stored yet.
+    async fn get_settings(&self) -> Result<Option<Settings>>;
+    /// List the snapshots of the settings in the order of newest first.
+    async fn list_settings_history(&self) -> Result<Vec<Settings>>;
+}
+
+#[macro_export]
+macro_rules! delegate_settings_repository {
+    (impl $(<$($vars:ident $(: $c0:ident $(+ $cs:ident)* )? ),*>)? SettingsRepository for $ty:ty {
+        $sel:ident $target:block
+    }) => {
+        #[::async_trait::async_trait]
+        impl $(<$($vars$(: $c0 $(+ $cs)* )?,)*>)? $crate::context::SettingsRepository for $ty {
+            async fn store_settings(
+                &$sel,
+                settings: $crate::model::settings::Settings,
+            ) -> ::anyhow::Result<()> {
+                $target.store_settings(settings).await
+            }
+            async fn get_settings(
+                &$sel,
+            ) -> ::anyhow::Result<Option<$crate::model::settings::Settings>> {
+                $target.get_settings().await
+            }
+            async fn list_settings_history(
+                &$sel,
+            ) -> ::anyhow::Result<Vec<$crate::model::settings::Settings>> {
+                $target.list_settings_history().await
+            }
+        }
+    };
+}
+
+#[async_trait::async_trait]
+impl<C: SettingsRepository + Sync> SettingsRepository for &C {
+    async fn store_settings(&self, settings: Settings) -> Result<()> {
+        <C as SettingsRepository>::store_settings(self, settings).await
+    }
+
+    async fn get_settings(&self) -> Result<Option<Settings>> {
+        <C as SettingsRepository>::get_settings(self).await
+    }
+
+    async fn list_settings_history(&self) -> Result<Vec<Settings>> {
+        <C as SettingsRepository>::list_settings_history(self).await
+    }
+}
@@ -3,13 +3,18 @@ mod collection;
 mod integer;
 mod string;
 
+pub mod access_control;
+pub mod api_key;
+pub mod custom_role;
 pub mod date_time;
 pub mod email;
+pub mod festival;
 pub mod file;
 pub mod file_distribution;
 pub mod file_sharing;
 pub mod form;
 pub mod form_answer;
+pub mod form_template;
 pub mod object;
 pub mod pending_project;
 pub mod permissions;
@@ -19,5 +24,6 @@ pub mod project_creation_period;
 pub mod project_query;
 pub mod registration_form;
 pub mod registration_form_answer;
+pub mod settings;
 pub mod user;
 pub mod user_invitation;
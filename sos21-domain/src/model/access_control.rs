@@ -0,0 +1,214 @@
+use std::collections::HashSet;
+
+use crate::model::collection::{self, LengthBoundedVec};
+use crate::model::custom_role::CustomRoleId;
+use crate::model::permissions::Permissions;
+use crate::model::user::{User, UserId};
+
+use thiserror::Error;
+
+/// The user or the users with the custom role to whom an access is granted.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AccessControlGrantee {
+    User(UserId),
+    CustomRole(CustomRoleId),
+}
+
+impl AccessControlGrantee {
+    /// Whether the access is granted to `user`. Users logged in with API keys are never contained.
+    pub fn contains(&self, user: &User) -> bool {
+        if user.is_with_api_key() {
+            return false;
+        }
+
+        match self {
+            AccessControlGrantee::User(user_id) => user_id == user.id(),
+            AccessControlGrantee::CustomRole(custom_role_id) => user
+                .custom_role()
+                .is_some_and(|custom_role| custom_role.id == *custom_role_id),
+        }
+    }
+}
+
+/// The level of access granted on a form or a registration form.
+///
+/// Each level includes the lower ones, i.e. `Update` grants `Review` and `Read` as well.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AccessControlLevel {
+    /// Read the form and its answers.
+    Read,
+    /// Review the answers.
+    Review,
+    /// Update the answers regardless of the answer period.
+    Update,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessControlEntry {
+    pub grantee: AccessControlGrantee,
+    pub level: AccessControlLevel,
+}
+
+/// The list of accesses granted on a specific form or registration form.
+///
+/// The users in the list are given the accesses in addition to the ones given by their
+/// permissions, while the other users lose the accesses to the form given by the permissions
+/// unless they manage the lists. An empty list does not change the accesses.
+#[derive(Debug, Clone)]
+pub struct AccessControlList(LengthBoundedVec<typenum::U0, typenum::U64, AccessControlEntry>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FromEntriesErrorKind {
+    TooLong,
+    DuplicatedGrantee(AccessControlGrantee),
+}
+
+#[derive(Debug, Error, Clone)]
+#[error("invalid access control list")]
+pub struct FromEntriesError {
+    kind: FromEntriesErrorKind,
+}
+
+impl FromEntriesError {
+    pub fn kind(&self) -> FromEntriesErrorKind {
+        self.kind.clone()
+    }
+
+    fn from_length_error(_err: collection::BoundedLengthError<typenum::U0, typenum::U64>) -> Self {
+        FromEntriesError {
+            kind: FromEntriesErrorKind::TooLong,
+        }
+    }
+}
+
+impl AccessControlList {
+    pub fn empty() -> Self {
+        AccessControlList(LengthBoundedVec::new(Vec::new()).unwrap())
+    }
+
+    pub fn from_entries<I>(entries: I) -> Result<Self, FromEntriesError>
+    where
+        I: IntoIterator<Item = AccessControlEntry>,
+    {
+        let entries: Vec<_> = entries.into_iter().collect();
+
+        let mut known_grantees = HashSet::new();
+        for entry in &entries {
+            if !known_grantees.insert(&entry.grantee) {
+                return Err(FromEntriesError {
+                    kind: FromEntriesErrorKind::DuplicatedGrantee(entry.grantee.clone()),
+                });
+            }
+        }
+
+        let entries =
+            LengthBoundedVec::new(entries).map_err(FromEntriesError::from_length_error)?;
+        Ok(AccessControlList(entries))
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = &'_ AccessControlEntry> + '_ {
+        self.0.as_inner().iter()
+    }
+
+    pub fn into_entries(self) -> impl Iterator<Item = AccessControlEntry> {
+        self.0.into_inner().into_iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.len() == 0
+    }
+
+    /// The highest level of access granted to the user, if any.
+    pub fn level_for(&self, user: &User) -> Option<AccessControlLevel> {
+        self.entries()
+            .filter(|entry| entry.grantee.contains(user))
+            .map(|entry| entry.level)
+            .max()
+    }
+
+    pub fn allows(&self, user: &User, level: AccessControlLevel) -> bool {
+        self.level_for(user).is_some_and(|granted| granted >= level)
+    }
+
+    /// Whether the access of `level` is given to the user, either by the list or by `permission`.
+    ///
+    /// A non-empty list narrows the access given by `permission` to the users in the list,
+    /// except for the users with [`Permissions::UPDATE_FORM_ACCESS_CONTROLS`] who manage the lists.
+    pub fn allows_with_permission(
+        &self,
+        user: &User,
+        level: AccessControlLevel,
+        permission: Permissions,
+    ) -> bool {
+        if self.allows(user, level) {
+            return true;
+        }
+
+        let permissions = user.permissions();
+        permissions.contains(permission)
+            && (self.is_empty() || permissions.contains(Permissions::UPDATE_FORM_ACCESS_CONTROLS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AccessControlEntry, AccessControlGrantee, AccessControlLevel, AccessControlList,
+        FromEntriesErrorKind,
+    };
+    use crate::model::permissions::Permissions;
+    use crate::test::model as test_model;
+
+    #[test]
+    fn test_allows_user() {
+        let user = test_model::new_general_user();
+        let other = test_model::new_committee_user();
+        let list = AccessControlList::from_entries(vec![AccessControlEntry {
+            grantee: AccessControlGrantee::User(user.id().clone()),
+            level: AccessControlLevel::Review,
+        }])
+        .unwrap();
+
+        assert!(list.allows(&user, AccessControlLevel::Read));
+        assert!(list.allows(&user, AccessControlLevel::Review));
+        assert!(!list.allows(&user, AccessControlLevel::Update));
+        assert!(!list.allows(&other, AccessControlLevel::Read));
+    }
+
+    #[test]
+    fn test_allows_custom_role() {
+        let admin = test_model::new_admin_user();
+        let role = test_model::new_custom_role(admin.id().clone(), Permissions::READ_ALL_FORMS);
+        let mut user = test_model::new_general_user();
+        let list = AccessControlList::from_entries(vec![AccessControlEntry {
+            grantee: AccessControlGrantee::CustomRole(role.id()),
+            level: AccessControlLevel::Read,
+        }])
+        .unwrap();
+
+        assert!(!list.allows(&user, AccessControlLevel::Read));
+        user.set_custom_role(&admin, Some(&role)).unwrap();
+        assert!(list.allows(&user, AccessControlLevel::Read));
+        assert!(!list.allows(&user, AccessControlLevel::Review));
+    }
+
+    #[test]
+    fn test_duplicated_grantee() {
+        let user = test_model::new_general_user();
+        let grantee = AccessControlGrantee::User(user.id().clone());
+        let result = AccessControlList::from_entries(vec![
+            AccessControlEntry {
+                grantee: grantee.clone(),
+                level: AccessControlLevel::Read,
+            },
+            AccessControlEntry {
+                grantee: grantee.clone(),
+                level: AccessControlLevel::Update,
+            },
+        ]);
+        assert_eq!(
+            result.unwrap_err().kind(),
+            FromEntriesErrorKind::DuplicatedGrantee(grantee)
+        );
+    }
+}
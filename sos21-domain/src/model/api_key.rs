@@ -0,0 +1,342 @@
+use crate::model::date_time::DateTime;
+use crate::model::permissions::Permissions;
+use crate::model::user::{self, User, UserId};
+
+use thiserror::Error;
+use uuid::Uuid;
+
+pub mod name;
+pub mod scope;
+pub mod token;
+pub use name::ApiKeyName;
+pub use scope::ApiKeyScope;
+pub use token::{ApiKeyToken, ApiKeyTokenDigest};
+
+/// The minimum interval in minutes between the updates of `last_used_at`.
+pub const LAST_USE_RECORD_INTERVAL_MINUTES: i64 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ApiKeyId(Uuid);
+
+impl ApiKeyId {
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        ApiKeyId(uuid)
+    }
+
+    pub fn to_uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+/// A key for automated clients such as scheduled scripts, which acts on behalf of its author
+/// with the permissions limited to its scope.
+///
+/// The key is not a principal of its own: the requests made with it are recorded as made by
+/// the author, and it cannot be used while the author is suspended. Unlike the author, the key
+/// is not given the accesses based on the ownership or membership, nor the ones granted by
+/// access control lists.
+#[derive(Debug, Clone)]
+pub struct ApiKeyContent {
+    pub id: ApiKeyId,
+    pub created_at: DateTime,
+    pub author_id: UserId,
+    pub name: ApiKeyName,
+    pub scope: ApiKeyScope,
+    pub token_digest: ApiKeyTokenDigest,
+    pub expires_at: Option<DateTime>,
+    pub last_used_at: Option<DateTime>,
+    pub revoked_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ApiKey {
+    content: ApiKeyContent,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NewApiKeyErrorKind {
+    InsufficientPermissions,
+    PastExpiry,
+}
+
+#[derive(Debug, Clone, Error)]
+#[error("failed to create an API key")]
+pub struct NewApiKeyError {
+    kind: NewApiKeyErrorKind,
+}
+
+impl NewApiKeyError {
+    pub fn kind(&self) -> NewApiKeyErrorKind {
+        self.kind
+    }
+
+    fn from_permissions_error(_err: user::RequirePermissionsError) -> Self {
+        NewApiKeyError {
+            kind: NewApiKeyErrorKind::InsufficientPermissions,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RevokeApiKeyErrorKind {
+    InsufficientPermissions,
+    AlreadyRevoked,
+}
+
+#[derive(Debug, Clone, Error)]
+#[error("failed to revoke the API key")]
+pub struct RevokeApiKeyError {
+    kind: RevokeApiKeyErrorKind,
+}
+
+impl RevokeApiKeyError {
+    pub fn kind(&self) -> RevokeApiKeyErrorKind {
+        self.kind
+    }
+
+    fn from_permissions_error(_err: user::RequirePermissionsError) -> Self {
+        RevokeApiKeyError {
+            kind: RevokeApiKeyErrorKind::InsufficientPermissions,
+        }
+    }
+}
+
+impl ApiKey {
+    /// Create a key and the token for it. The token is not kept in the key
+    /// and cannot be obtained afterwards.
+    pub fn new(
+        author: &User,
+        name: ApiKeyName,
+        scope: ApiKeyScope,
+        expires_at: Option<DateTime>,
+    ) -> Result<(Self, ApiKeyToken), NewApiKeyError> {
+        author
+            .require_permissions(Permissions::UPDATE_API_KEYS)
+            .map_err(NewApiKeyError::from_permissions_error)?;
+
+        if matches!(expires_at, Some(expires_at) if expires_at <= DateTime::now()) {
+            return Err(NewApiKeyError {
+                kind: NewApiKeyErrorKind::PastExpiry,
+            });
+        }
+
+        let token = ApiKeyToken::generate();
+        let key = ApiKey::from_content(ApiKeyContent {
+            id: ApiKeyId::from_uuid(Uuid::new_v4()),
+            created_at: DateTime::now(),
+            author_id: author.id().clone(),
+            name,
+            scope,
+            token_digest: token.digest(),
+            expires_at,
+            last_used_at: None,
+            revoked_at: None,
+        });
+        Ok((key, token))
+    }
+
+    /// Restore `ApiKey` from `ApiKeyContent`.
+    ///
+    /// This is intended to be used when the data is taken out of the implementation
+    /// by [`ApiKey::into_content`] for persistence, internal serialization, etc.
+    /// Use [`ApiKey::new`] to create an API key.
+    pub fn from_content(content: ApiKeyContent) -> Self {
+        ApiKey { content }
+    }
+
+    /// Convert `ApiKey` into `ApiKeyContent`.
+    pub fn into_content(self) -> ApiKeyContent {
+        self.content
+    }
+
+    pub fn id(&self) -> ApiKeyId {
+        self.content.id
+    }
+
+    pub fn created_at(&self) -> DateTime {
+        self.content.created_at
+    }
+
+    pub fn author_id(&self) -> &UserId {
+        &self.content.author_id
+    }
+
+    pub fn name(&self) -> &ApiKeyName {
+        &self.content.name
+    }
+
+    pub fn scope(&self) -> ApiKeyScope {
+        self.content.scope
+    }
+
+    pub fn token_digest(&self) -> &ApiKeyTokenDigest {
+        &self.content.token_digest
+    }
+
+    pub fn expires_at(&self) -> Option<DateTime> {
+        self.content.expires_at
+    }
+
+    pub fn last_used_at(&self) -> Option<DateTime> {
+        self.content.last_used_at
+    }
+
+    pub fn revoked_at(&self) -> Option<DateTime> {
+        self.content.revoked_at
+    }
+
+    pub fn is_expired(&self) -> bool {
+        matches!(self.content.expires_at, Some(expires_at) if expires_at <= DateTime::now())
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.content.revoked_at.is_some()
+    }
+
+    /// Whether the key can be used to log in.
+    pub fn is_active(&self) -> bool {
+        !self.is_expired() && !self.is_revoked()
+    }
+
+    pub fn is_visible_to(&self, user: &User) -> bool {
+        user.permissions().contains(Permissions::READ_API_KEYS)
+    }
+
+    /// Record that the key is used to log in, and return whether the time of the last use is updated.
+    ///
+    /// The time is only updated once in [`LAST_USE_RECORD_INTERVAL_MINUTES`] not to store the key
+    /// on every request, so `last_used_at` may be behind the actual last use by that interval.
+    pub fn record_use(&mut self) -> bool {
+        let now = DateTime::now();
+        let interval = chrono::Duration::minutes(LAST_USE_RECORD_INTERVAL_MINUTES);
+        if matches!(self.content.last_used_at, Some(last_used_at) if now.utc() - last_used_at.utc() < interval)
+        {
+            return false;
+        }
+
+        self.content.last_used_at = Some(now);
+        true
+    }
+
+    pub fn revoke(&mut self, user: &User) -> Result<(), RevokeApiKeyError> {
+        user.require_permissions(Permissions::UPDATE_API_KEYS)
+            .map_err(RevokeApiKeyError::from_permissions_error)?;
+
+        if self.is_revoked() {
+            return Err(RevokeApiKeyError {
+                kind: RevokeApiKeyErrorKind::AlreadyRevoked,
+            });
+        }
+
+        self.content.revoked_at = Some(DateTime::now());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ApiKey, ApiKeyScope, NewApiKeyErrorKind, RevokeApiKeyErrorKind};
+    use crate::model::{date_time::DateTime, permissions::Permissions, user::UserRole};
+    use crate::test::model as test_model;
+
+    #[test]
+    fn test_new_operator() {
+        let user = test_model::new_operator_user();
+        assert_eq!(
+            ApiKey::new(
+                &user,
+                test_model::mock_api_key_name(),
+                ApiKeyScope::Role(UserRole::Committee),
+                None,
+            )
+            .unwrap_err()
+            .kind(),
+            NewApiKeyErrorKind::InsufficientPermissions
+        );
+    }
+
+    #[test]
+    fn test_new_admin() {
+        let user = test_model::new_admin_user();
+        let (key, token) = ApiKey::new(
+            &user,
+            test_model::mock_api_key_name(),
+            ApiKeyScope::Permissions(Permissions::READ_ALL_FORM_ANSWERS),
+            None,
+        )
+        .unwrap();
+        assert_eq!(key.author_id(), user.id());
+        assert_eq!(key.into_content().token_digest, token.digest());
+    }
+
+    #[test]
+    fn test_new_past_expiry() {
+        let user = test_model::new_admin_user();
+        let expires_at = DateTime::from_utc(chrono::Utc::now() - chrono::Duration::hours(1));
+        assert_eq!(
+            ApiKey::new(
+                &user,
+                test_model::mock_api_key_name(),
+                ApiKeyScope::Role(UserRole::Committee),
+                Some(expires_at),
+            )
+            .unwrap_err()
+            .kind(),
+            NewApiKeyErrorKind::PastExpiry
+        );
+    }
+
+    #[test]
+    fn test_expired() {
+        let admin = test_model::new_admin_user();
+        let (key, _) = test_model::new_expired_api_key(admin.id().clone());
+        assert!(key.is_expired());
+        assert!(!key.is_active());
+    }
+
+    #[test]
+    fn test_revoke() {
+        let admin = test_model::new_admin_user();
+        let mut key =
+            test_model::new_api_key(admin.id().clone(), ApiKeyScope::Role(UserRole::Committee)).0;
+        assert!(key.is_active());
+        key.revoke(&admin).unwrap();
+        assert!(!key.is_active());
+        assert_eq!(
+            key.revoke(&admin).unwrap_err().kind(),
+            RevokeApiKeyErrorKind::AlreadyRevoked
+        );
+    }
+
+    #[test]
+    fn test_record_use_interval() {
+        let admin = test_model::new_admin_user();
+        let mut key =
+            test_model::new_api_key(admin.id().clone(), ApiKeyScope::Role(UserRole::Committee)).0;
+        assert!(key.record_use());
+        let last_used_at = key.last_used_at().unwrap();
+        assert!(!key.record_use());
+        assert_eq!(key.last_used_at(), Some(last_used_at));
+
+        let mut content = key.into_content();
+        content.last_used_at = Some(DateTime::from_utc(
+            chrono::Utc::now() - chrono::Duration::minutes(2),
+        ));
+        let mut key = ApiKey::from_content(content);
+        assert!(key.record_use());
+        assert!(key.last_used_at().unwrap() > last_used_at);
+    }
+
+    #[test]
+    fn test_revoke_operator() {
+        let admin = test_model::new_admin_user();
+        let operator = test_model::new_operator_user();
+        let mut key =
+            test_model::new_api_key(admin.id().clone(), ApiKeyScope::Role(UserRole::Committee)).0;
+        assert_eq!(
+            key.revoke(&operator).unwrap_err().kind(),
+            RevokeApiKeyErrorKind::InsufficientPermissions
+        );
+        assert!(!key.is_visible_to(&operator));
+    }
+}
@@ -0,0 +1,18 @@
+use crate::model::permissions::Permissions;
+use crate::model::user::UserRole;
+
+/// What an API key is allowed to do, bound to either a built-in role or explicit permissions.
+#[derive(Debug, Clone, Copy)]
+pub enum ApiKeyScope {
+    Role(UserRole),
+    Permissions(Permissions),
+}
+
+impl ApiKeyScope {
+    pub fn permissions(&self) -> Permissions {
+        match self {
+            ApiKeyScope::Role(role) => role.permissions(),
+            ApiKeyScope::Permissions(permissions) => *permissions,
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use std::convert::TryInto;
+use std::fmt;
+
+use thiserror::Error;
+use uuid::Uuid;
+
+const TOKEN_PREFIX: &str = "sos21_";
+
+/// The secret presented by clients, which is only available at the creation of the key.
+#[derive(Clone)]
+pub struct ApiKeyToken(String);
+
+// prevent the token from being leaked to logs
+impl fmt::Debug for ApiKeyToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ApiKeyToken(..)")
+    }
+}
+
+impl ApiKeyToken {
+    pub fn generate() -> Self {
+        // each v4 UUID carries 122 random bits
+        let random = format!(
+            "{}{}",
+            Uuid::new_v4().to_simple(),
+            Uuid::new_v4().to_simple()
+        );
+        ApiKeyToken(format!("{}{}", TOKEN_PREFIX, random))
+    }
+
+    /// Returns `None` if `token` is not in the format of API key tokens,
+    /// which distinguishes them from other bearer tokens.
+    pub fn from_string(token: impl Into<String>) -> Option<Self> {
+        let token = token.into();
+        if token.starts_with(TOKEN_PREFIX) {
+            Some(ApiKeyToken(token))
+        } else {
+            None
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+
+    pub fn digest(&self) -> ApiKeyTokenDigest {
+        ApiKeyTokenDigest(*blake3::hash(self.0.as_bytes()).as_bytes())
+    }
+}
+
+/// The digest of [`ApiKeyToken`], which is stored instead of the token itself.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ApiKeyTokenDigest([u8; 32]);
+
+#[derive(Debug, Clone, Error)]
+#[error("invalid length of API key token digest bytes")]
+pub struct InvalidLengthError {
+    _priv: (),
+}
+
+impl ApiKeyTokenDigest {
+    pub fn from_array(bytes: [u8; 32]) -> Self {
+        ApiKeyTokenDigest(bytes)
+    }
+
+    pub fn from_vec(bytes: Vec<u8>) -> Result<Self, InvalidLengthError> {
+        bytes
+            .try_into()
+            .map(ApiKeyTokenDigest)
+            .map_err(|_| InvalidLengthError { _priv: () })
+    }
+
+    pub fn into_array(self) -> [u8; 32] {
+        self.0
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ApiKeyToken;
+
+    #[test]
+    fn test_generate() {
+        let token = ApiKeyToken::generate();
+        assert!(ApiKeyToken::from_string(token.as_str()).is_some());
+        assert_ne!(token.as_str(), ApiKeyToken::generate().as_str());
+        assert_ne!(token.digest(), ApiKeyToken::generate().digest());
+    }
+
+    #[test]
+    fn test_digest() {
+        let token = ApiKeyToken::generate();
+        let restored = ApiKeyToken::from_string(token.as_str()).unwrap();
+        assert_eq!(token.digest(), restored.digest());
+    }
+
+    #[test]
+    fn test_from_string_jwt() {
+        assert!(ApiKeyToken::from_string("eyJhbGciOiJSUzI1NiJ9.e30.c2ln").is_none());
+    }
+}
@@ -28,6 +28,12 @@ impl<N: Unsigned> Bound<u64> for Bounded<N> {
     }
 }
 
+impl<N: Unsigned> Bound<u32> for Bounded<N> {
+    fn limit() -> Option<u32> {
+        Some(N::to_u32())
+    }
+}
+
 impl<N: Unsigned> Bound<u16> for Bounded<N> {
     fn limit() -> Option<u16> {
         Some(N::to_u16())
@@ -190,9 +190,9 @@ where
         self.inner.difference(&other.inner)
     }
 
-    pub fn contains<Q: ?Sized>(&self, value: &Q) -> bool
+    pub fn contains<Q>(&self, value: &Q) -> bool
     where
-        Q: Hash + Eq,
+        Q: ?Sized + Hash + Eq,
         T: Borrow<Q>,
     {
         self.inner.contains(value)
@@ -209,17 +209,17 @@ impl<Lower, Upper, K, V> LengthBoundedMap<Lower, Upper, K, V>
 where
     K: Eq + Hash,
 {
-    pub fn contains_key<Q: ?Sized>(&self, key: &Q) -> bool
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
     where
-        Q: Hash + Eq,
+        Q: ?Sized + Hash + Eq,
         K: Borrow<Q>,
     {
         self.inner.contains_key(key)
     }
 
-    pub fn get<Q: ?Sized>(&self, key: &Q) -> Option<&V>
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
     where
-        Q: Hash + Eq,
+        Q: ?Sized + Hash + Eq,
         K: Borrow<Q>,
     {
         self.inner.get(key)
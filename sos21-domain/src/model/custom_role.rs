@@ -0,0 +1,169 @@
+use crate::model::date_time::DateTime;
+use crate::model::permissions::Permissions;
+use crate::model::user::{self, User, UserId};
+
+use thiserror::Error;
+use uuid::Uuid;
+
+pub mod name;
+pub use name::CustomRoleName;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CustomRoleId(Uuid);
+
+impl CustomRoleId {
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        CustomRoleId(uuid)
+    }
+
+    pub fn to_uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+/// A role defined by administrators, which grants the permissions
+/// in addition to the ones of the built-in [`UserRole`](crate::model::user::UserRole).
+#[derive(Debug, Clone)]
+pub struct CustomRoleContent {
+    pub id: CustomRoleId,
+    pub created_at: DateTime,
+    pub author_id: UserId,
+    pub name: CustomRoleName,
+    pub permissions: Permissions,
+}
+
+#[derive(Debug, Clone)]
+pub struct CustomRole {
+    content: CustomRoleContent,
+}
+
+#[derive(Debug, Clone, Error)]
+#[error("insufficient permissions to update custom roles")]
+pub struct NoUpdatePermissionError {
+    _priv: (),
+}
+
+impl NoUpdatePermissionError {
+    fn from_permissions_error(_err: user::RequirePermissionsError) -> Self {
+        NoUpdatePermissionError { _priv: () }
+    }
+}
+
+impl CustomRole {
+    pub fn new(
+        author: &User,
+        name: CustomRoleName,
+        permissions: Permissions,
+    ) -> Result<Self, NoUpdatePermissionError> {
+        author
+            .require_permissions(Permissions::UPDATE_CUSTOM_ROLES)
+            .map_err(NoUpdatePermissionError::from_permissions_error)?;
+
+        Ok(CustomRole::from_content(CustomRoleContent {
+            id: CustomRoleId::from_uuid(Uuid::new_v4()),
+            created_at: DateTime::now(),
+            author_id: author.id().clone(),
+            name,
+            permissions,
+        }))
+    }
+
+    /// Restore `CustomRole` from `CustomRoleContent`.
+    ///
+    /// This is intended to be used when the data is taken out of the implementation
+    /// by [`CustomRole::into_content`] for persistence, internal serialization, etc.
+    /// Use [`CustomRole::new`] to create a custom role.
+    pub fn from_content(content: CustomRoleContent) -> Self {
+        CustomRole { content }
+    }
+
+    /// Convert `CustomRole` into `CustomRoleContent`.
+    pub fn into_content(self) -> CustomRoleContent {
+        self.content
+    }
+
+    pub fn id(&self) -> CustomRoleId {
+        self.content.id
+    }
+
+    pub fn created_at(&self) -> DateTime {
+        self.content.created_at
+    }
+
+    pub fn author_id(&self) -> &UserId {
+        &self.content.author_id
+    }
+
+    pub fn name(&self) -> &CustomRoleName {
+        &self.content.name
+    }
+
+    pub fn permissions(&self) -> Permissions {
+        self.content.permissions
+    }
+
+    pub fn is_visible_to(&self, user: &User) -> bool {
+        user.permissions().contains(Permissions::READ_CUSTOM_ROLES)
+    }
+
+    pub fn set_name(
+        &mut self,
+        user: &User,
+        name: CustomRoleName,
+    ) -> Result<(), NoUpdatePermissionError> {
+        user.require_permissions(Permissions::UPDATE_CUSTOM_ROLES)
+            .map_err(NoUpdatePermissionError::from_permissions_error)?;
+        self.content.name = name;
+        Ok(())
+    }
+
+    pub fn set_permissions(
+        &mut self,
+        user: &User,
+        permissions: Permissions,
+    ) -> Result<(), NoUpdatePermissionError> {
+        user.require_permissions(Permissions::UPDATE_CUSTOM_ROLES)
+            .map_err(NoUpdatePermissionError::from_permissions_error)?;
+        self.content.permissions = permissions;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CustomRole;
+    use crate::model::permissions::Permissions;
+    use crate::test::model as test_model;
+
+    #[test]
+    fn test_new_operator() {
+        let user = test_model::new_operator_user();
+        assert!(CustomRole::new(
+            &user,
+            test_model::mock_custom_role_name(),
+            Permissions::READ_ALL_FORM_ANSWERS
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_new_admin() {
+        let user = test_model::new_admin_user();
+        let role = CustomRole::new(
+            &user,
+            test_model::mock_custom_role_name(),
+            Permissions::READ_ALL_FORM_ANSWERS,
+        )
+        .unwrap();
+        assert_eq!(role.author_id(), user.id());
+        assert!(role.is_visible_to(&user));
+    }
+
+    #[test]
+    fn test_visibility_operator() {
+        let admin = test_model::new_admin_user();
+        let operator = test_model::new_operator_user();
+        let role = test_model::new_custom_role(admin.id().clone(), Permissions::READ_ALL_USERS);
+        assert!(!role.is_visible_to(&operator));
+    }
+}
@@ -39,6 +39,12 @@ impl EmailAddress {
     pub fn ends_with(&self, suffix: impl AsRef<str>) -> bool {
         self.0.as_ref().ends_with(suffix.as_ref())
     }
+
+    /// Returns the domain part of the address.
+    pub fn domain(&self) -> &str {
+        // the local part never contains '@' in valid addresses
+        self.0.as_ref().rsplit('@').next().unwrap()
+    }
 }
 
 impl From<EmailAddress> for String {
@@ -66,12 +72,12 @@ fn is_valid_email_address(s: &str) -> bool {
     let mut sp = s.splitn(2, '@');
     let local_part = match sp.next() {
         None => return false,
-        Some(x) if x.is_empty() => return false,
+        Some("") => return false,
         Some(x) => x,
     };
     let domain = match sp.next() {
         None => return false,
-        Some(x) if x.is_empty() => return false,
+        Some("") => return false,
         Some(x) => x,
     };
     debug_assert!(sp.next().is_none());
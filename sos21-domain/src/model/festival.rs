@@ -0,0 +1,142 @@
+use crate::context::ConfigContext;
+use crate::model::date_time::DateTime;
+use crate::model::permissions::Permissions;
+use crate::model::user::{self, User, UserId};
+
+use thiserror::Error;
+use uuid::Uuid;
+
+pub mod name;
+pub use name::FestivalName;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FestivalId(Uuid);
+
+impl FestivalId {
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        FestivalId(uuid)
+    }
+
+    pub fn to_uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+/// An edition of the festival, to which projects, forms, and so on belong.
+#[derive(Debug, Clone)]
+pub struct FestivalContent {
+    pub id: FestivalId,
+    pub created_at: DateTime,
+    /// `None` for the festival which was created on the migration from the single-festival data.
+    pub author_id: Option<UserId>,
+    pub name: FestivalName,
+}
+
+#[derive(Debug, Clone)]
+pub struct Festival {
+    content: FestivalContent,
+}
+
+#[derive(Debug, Clone, Error)]
+#[error("insufficient permissions to create festivals")]
+pub struct NewFestivalError {
+    _priv: (),
+}
+
+impl NewFestivalError {
+    fn from_permissions_error(_err: user::RequirePermissionsError) -> Self {
+        NewFestivalError { _priv: () }
+    }
+}
+
+impl Festival {
+    pub fn new(author: &User, name: FestivalName) -> Result<Self, NewFestivalError> {
+        author
+            .require_permissions(Permissions::CREATE_FESTIVALS)
+            .map_err(NewFestivalError::from_permissions_error)?;
+
+        Ok(Festival::from_content(FestivalContent {
+            id: FestivalId::from_uuid(Uuid::new_v4()),
+            created_at: DateTime::now(),
+            author_id: Some(author.id().clone()),
+            name,
+        }))
+    }
+
+    /// Restore `Festival` from `FestivalContent`.
+    ///
+    /// This is intended to be used when the data is taken out of the implementation
+    /// by [`Festival::into_content`] for persistence, internal serialization, etc.
+    /// Use [`Festival::new`] to create a festival.
+    pub fn from_content(content: FestivalContent) -> Self {
+        Festival { content }
+    }
+
+    /// Convert `Festival` into `FestivalContent`.
+    pub fn into_content(self) -> FestivalContent {
+        self.content
+    }
+
+    pub fn id(&self) -> FestivalId {
+        self.content.id
+    }
+
+    pub fn created_at(&self) -> DateTime {
+        self.content.created_at
+    }
+
+    pub fn author_id(&self) -> Option<&UserId> {
+        self.content.author_id.as_ref()
+    }
+
+    pub fn name(&self) -> &FestivalName {
+        &self.content.name
+    }
+
+    pub fn is_active<C>(&self, ctx: C) -> bool
+    where
+        C: ConfigContext,
+    {
+        ctx.active_festival_id() == self.id()
+    }
+
+    pub fn is_visible_to(&self, _user: &User) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Festival;
+    use crate::test::model as test_model;
+
+    #[test]
+    fn test_new_general() {
+        let user = test_model::new_general_user();
+        assert!(Festival::new(&user, test_model::mock_festival_name()).is_err());
+    }
+
+    #[test]
+    fn test_new_operator() {
+        let user = test_model::new_operator_user();
+        assert!(Festival::new(&user, test_model::mock_festival_name()).is_err());
+    }
+
+    #[test]
+    fn test_new_admin() {
+        let user = test_model::new_admin_user();
+        let festival = Festival::new(&user, test_model::mock_festival_name()).unwrap();
+        assert_eq!(festival.author_id(), Some(user.id()));
+    }
+
+    #[test]
+    fn test_active() {
+        let app = crate::test::build_mock_app().build();
+        let festival = test_model::mock_festival();
+        assert!(festival.is_active(&app));
+
+        let user = test_model::new_admin_user();
+        let festival = Festival::new(&user, test_model::mock_festival_name()).unwrap();
+        assert!(!festival.is_active(&app));
+    }
+}
@@ -72,7 +72,7 @@ impl ShareWithExpirationError {
 
 impl File {
     pub fn is_visible_to(&self, user: &User) -> bool {
-        &self.author_id == user.id()
+        user.is_identified_by(&self.author_id)
     }
 
     pub fn is_visible_to_with_sharing(&self, witness: &FileSharingWitness) -> bool {
@@ -80,7 +80,7 @@ impl File {
     }
 
     pub fn can_be_shared_by(&self, user: &User) -> bool {
-        self.is_visible_to(user) && &self.author_id == user.id()
+        self.is_visible_to(user) && user.is_identified_by(&self.author_id)
     }
 
     pub fn share_by(
@@ -1,4 +1,5 @@
 use crate::model::date_time::DateTime;
+use crate::model::festival::FestivalId;
 use crate::model::permissions::Permissions;
 use crate::model::project::Project;
 use crate::model::user::{User, UserId};
@@ -31,6 +32,7 @@ impl FileDistributionId {
 #[derive(Debug, Clone)]
 pub struct FileDistribution {
     pub id: FileDistributionId,
+    pub festival_id: FestivalId,
     pub created_at: DateTime,
     pub author_id: UserId,
     pub name: FileDistributionName,
@@ -1,5 +1,7 @@
-use crate::context::FormAnswerRepository;
+use crate::context::{ConfigContext, FormAnswerRepository};
+use crate::model::access_control::{AccessControlLevel, AccessControlList};
 use crate::model::date_time::DateTime;
+use crate::model::festival::FestivalId;
 use crate::model::form_answer::item::FormAnswerItems;
 use crate::model::form_answer::{self, FormAnswer};
 use crate::model::permissions::Permissions;
@@ -15,12 +17,16 @@ pub mod description;
 pub mod item;
 pub mod name;
 pub mod period;
+pub mod revision;
+pub mod version;
 
 pub use condition::{FormCondition, FormConditionProjectSet};
 pub use description::FormDescription;
 pub use item::{FormItem, FormItems};
 pub use name::FormName;
 pub use period::FormPeriod;
+pub use revision::{FormRevision, FormRevisionDiff};
+pub use version::FormVersion;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct FormId(Uuid);
@@ -38,11 +44,14 @@ impl FormId {
 #[derive(Debug, Clone)]
 pub struct Form {
     content: FormContent,
+    revised_from: Option<FormVersion>,
 }
 
 #[derive(Debug, Clone)]
 pub struct FormContent {
     pub id: FormId,
+    pub festival_id: FestivalId,
+    pub version: FormVersion,
     pub created_at: DateTime,
     pub author_id: UserId,
     pub name: FormName,
@@ -51,6 +60,7 @@ pub struct FormContent {
     pub items: FormItems,
     pub condition: FormCondition,
     pub answer_notification_webhook: Option<String>,
+    pub access_control: AccessControlList,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -77,7 +87,7 @@ impl NewFormError {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AnswerErrorKind {
     OutOfAnswerPeriod,
     NotTargeted,
@@ -101,7 +111,7 @@ pub struct AnswerError {
 
 impl AnswerError {
     pub fn kind(&self) -> AnswerErrorKind {
-        self.kind
+        self.kind.clone()
     }
 
     fn from_new_form_answer_error(err: form_answer::NewFormAnswerError) -> Self {
@@ -125,7 +135,9 @@ impl AnswerError {
 }
 
 impl Form {
-    pub fn new(
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<C>(
+        ctx: C,
         author: &User,
         name: FormName,
         description: FormDescription,
@@ -133,7 +145,10 @@ impl Form {
         items: FormItems,
         condition: FormCondition,
         answer_notification_webhook: Option<String>,
-    ) -> Result<Self, NewFormError> {
+    ) -> Result<Self, NewFormError>
+    where
+        C: ConfigContext,
+    {
         author
             .require_permissions(Permissions::CREATE_FORMS)
             .map_err(NewFormError::from_permissions_error)?;
@@ -148,6 +163,8 @@ impl Form {
 
         Ok(Form::from_content(FormContent {
             id: FormId::from_uuid(Uuid::new_v4()),
+            festival_id: ctx.active_festival_id(),
+            version: FormVersion::initial(),
             created_at,
             author_id: author.id().clone(),
             name,
@@ -156,6 +173,7 @@ impl Form {
             items,
             condition,
             answer_notification_webhook,
+            access_control: AccessControlList::empty(),
         }))
     }
 
@@ -167,7 +185,7 @@ impl Form {
         items: FormAnswerItems,
     ) -> DomainResult<FormAnswer, AnswerError>
     where
-        C: FormAnswerRepository,
+        C: FormAnswerRepository + ConfigContext,
     {
         if !self.can_be_answered_by(user, project) {
             return Err(DomainError::Domain(AnswerError {
@@ -179,7 +197,8 @@ impl Form {
 
         // TODO: Move this to FormAnswer
         let created_at = DateTime::now();
-        if !self.period().contains(created_at) {
+        // forms in the past festivals are closed regardless of their periods
+        if self.festival_id() != ctx.active_festival_id() || !self.period().contains(created_at) {
             return Err(DomainError::Domain(AnswerError {
                 kind: AnswerErrorKind::OutOfAnswerPeriod,
             }));
@@ -191,7 +210,11 @@ impl Form {
     }
 
     pub fn can_be_answered_by(&self, user: &User, project: &Project) -> bool {
-        project.is_member(user) && self.condition().check(project)
+        project.is_member(user) && self.is_targeted_to(project)
+    }
+
+    fn is_targeted_to(&self, project: &Project) -> bool {
+        self.festival_id() == project.festival_id() && self.condition().check(project)
     }
 
     /// Restore `Form` from `FormContent`.
@@ -200,7 +223,10 @@ impl Form {
     /// for persistence, internal serialization, etc.
     /// Use [`Form::new`] to create a form.
     pub fn from_content(content: FormContent) -> Self {
-        Form { content }
+        Form {
+            content,
+            revised_from: None,
+        }
     }
 
     /// Convert `Form` into `FormContent`.
@@ -212,6 +238,21 @@ impl Form {
         self.content.id
     }
 
+    pub fn festival_id(&self) -> FestivalId {
+        self.content.festival_id
+    }
+
+    pub fn version(&self) -> FormVersion {
+        self.content.version
+    }
+
+    /// Returns the version of the form when it was restored if it has moved to the next version since then.
+    ///
+    /// The repository stores the revised form only if the stored form is still in this version.
+    pub fn revised_from(&self) -> Option<FormVersion> {
+        self.revised_from
+    }
+
     pub fn created_at(&self) -> DateTime {
         self.content.created_at
     }
@@ -247,6 +288,10 @@ impl Form {
     pub fn answer_notification_webhook(&self) -> &Option<String> {
         &self.content.answer_notification_webhook
     }
+
+    pub fn access_control(&self) -> &AccessControlList {
+        &self.content.access_control
+    }
 }
 
 #[derive(Debug, Clone, Error)]
@@ -288,18 +333,29 @@ impl SetPeriodError {
 impl Form {
     fn require_update_permission(&self, user: &User) -> Result<(), NoUpdatePermissionError> {
         let now = DateTime::now();
-        let permission = if self.author_id() == user.id() && now < self.period().starts_at() {
-            Permissions::UPDATE_NOT_STARTED_OWNING_FORMS
-        } else {
-            Permissions::UPDATE_ALL_FORMS
-        };
+        let permission =
+            if user.is_identified_by(self.author_id()) && now < self.period().starts_at() {
+                Permissions::UPDATE_NOT_STARTED_OWNING_FORMS
+            } else {
+                Permissions::UPDATE_ALL_FORMS
+            };
 
         user.require_permissions(permission)
             .map_err(NoUpdatePermissionError::from_permissions_error)
     }
 
+    /// Moves the form to the next version once per the restored `Form`,
+    /// so that a series of edits before storing the form results in a single new version.
+    fn revise(&mut self) {
+        if self.revised_from.is_none() {
+            self.revised_from = Some(self.content.version);
+            self.content.version = self.content.version.next();
+        }
+    }
+
     pub fn set_name(&mut self, user: &User, name: FormName) -> Result<(), NoUpdatePermissionError> {
         self.require_update_permission(user)?;
+        self.revise();
         self.content.name = name;
         Ok(())
     }
@@ -310,6 +366,7 @@ impl Form {
         description: FormDescription,
     ) -> Result<(), NoUpdatePermissionError> {
         self.require_update_permission(user)?;
+        self.revise();
         self.content.description = description;
         Ok(())
     }
@@ -324,6 +381,7 @@ impl Form {
             });
         }
 
+        self.revise();
         self.content.period = period;
         Ok(())
     }
@@ -334,6 +392,7 @@ impl Form {
         items: FormItems,
     ) -> Result<(), NoUpdatePermissionError> {
         self.require_update_permission(user)?;
+        self.revise();
         self.content.items = items;
         Ok(())
     }
@@ -344,12 +403,29 @@ impl Form {
         condition: FormCondition,
     ) -> Result<(), NoUpdatePermissionError> {
         self.require_update_permission(user)?;
+        self.revise();
         self.content.condition = condition;
         Ok(())
     }
 
+    /// Replaces the accesses granted on the form and its answers.
+    ///
+    /// This does not move the form to the next version, as the access control is not
+    /// a part of the form seen by the projects.
+    pub fn set_access_control(
+        &mut self,
+        user: &User,
+        access_control: AccessControlList,
+    ) -> Result<(), NoUpdatePermissionError> {
+        user.require_permissions(Permissions::UPDATE_FORM_ACCESS_CONTROLS)
+            .map_err(NoUpdatePermissionError::from_permissions_error)?;
+        self.content.access_control = access_control;
+        Ok(())
+    }
+
     pub fn is_visible_to(&self, user: &User) -> bool {
         user.permissions().contains(Permissions::READ_ALL_FORMS)
+            || self.access_control().allows(user, AccessControlLevel::Read)
     }
 
     pub fn is_visible_to_with_project(&self, user: &User, project: &Project) -> bool {
@@ -357,7 +433,7 @@ impl Form {
             return true;
         }
 
-        self.condition().check(project) && project.is_visible_to(user)
+        self.is_targeted_to(project) && project.is_visible_to(user)
     }
 }
 
@@ -369,6 +445,7 @@ mod tests {
     };
 
     use crate::model::{
+        access_control::AccessControlLevel,
         date_time::DateTime,
         project::{ProjectAttributes, ProjectCategory},
         project_query::{ProjectQuery, ProjectQueryConjunction},
@@ -417,10 +494,12 @@ mod tests {
     #[test]
     fn test_create_too_early_period() {
         let author = test_model::new_operator_user();
+        let app = crate::test::build_mock_app().build();
         let starts_at = DateTime::from_utc(chrono::Utc::now() - chrono::Duration::hours(1));
         let period = FormPeriod::from_datetime(starts_at, DateTime::now()).unwrap();
         assert!(matches!(
             Form::new(
+                &app,
                 &author,
                 test_model::mock_form_name(),
                 test_model::mock_form_description(),
@@ -437,8 +516,10 @@ mod tests {
     #[test]
     fn test_create_permission_general() {
         let author = test_model::new_general_user();
+        let app = crate::test::build_mock_app().build();
         assert!(matches!(
             Form::new(
+                &app,
                 &author,
                 test_model::mock_form_name(),
                 test_model::mock_form_description(),
@@ -455,8 +536,10 @@ mod tests {
     #[test]
     fn test_create_permission_committee() {
         let author = test_model::new_committee_user();
+        let app = crate::test::build_mock_app().build();
         assert!(matches!(
             Form::new(
+                &app,
                 &author,
                 test_model::mock_form_name(),
                 test_model::mock_form_description(),
@@ -473,11 +556,13 @@ mod tests {
     #[test]
     fn test_create_ok() {
         let author = test_model::new_operator_user();
+        let app = crate::test::build_mock_app().build();
         let starts_at = DateTime::from_utc(chrono::Utc::now() + chrono::Duration::hours(1));
         let ends_at = DateTime::from_utc(chrono::Utc::now() + chrono::Duration::hours(2));
         let period = FormPeriod::from_datetime(starts_at, ends_at).unwrap();
         assert!(matches!(
             Form::new(
+                &app,
                 &author,
                 test_model::mock_form_name(),
                 test_model::mock_form_description(),
@@ -543,6 +628,28 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_answer_past_festival() {
+        let user = test_model::new_general_user();
+        let project = test_model::new_stage_project(user.id().clone());
+        let operator = test_model::new_operator_user();
+        let form = test_model::new_form(operator.id().clone());
+        let festival = test_model::new_festival();
+        let app = crate::test::build_mock_app()
+            .festivals(vec![festival.clone()])
+            .active_festival(festival.id())
+            .users(vec![user.clone(), operator.clone()])
+            .projects(vec![project.clone()])
+            .forms(vec![form.clone()])
+            .build();
+        let items = test_model::mock_form_answer_items(form.items());
+        assert!(matches!(
+            form.answer_by(&app, &user, &project, items).await,
+            Err(DomainError::Domain(err))
+            if err.kind() == AnswerErrorKind::OutOfAnswerPeriod
+        ));
+    }
+
     #[tokio::test]
     async fn test_answer_ok() {
         let user = test_model::new_general_user();
@@ -567,6 +674,27 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_update_version() {
+        let author = test_model::new_operator_user();
+        let period = test_model::new_form_period_with_hours_from_now(1);
+        let mut form = test_model::new_form_with_period(author.id().clone(), period);
+        let version = form.version();
+        assert_eq!(form.revised_from(), None);
+        form.set_name(&author, test_model::mock_form_name())
+            .unwrap();
+        form.set_description(&author, test_model::mock_form_description())
+            .unwrap();
+        assert_eq!(form.version(), version.next());
+        assert_eq!(form.revised_from(), Some(version));
+
+        let mut form = Form::from_content(form.into_content());
+        form.set_name(&author, test_model::mock_form_name())
+            .unwrap();
+        assert_eq!(form.version(), version.next().next());
+        assert_eq!(form.revised_from(), Some(version.next()));
+    }
+
     #[test]
     fn test_update_name_other_operator() {
         let author = test_model::new_operator_user();
@@ -580,6 +708,34 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_update_access_control_operator() {
+        let author = test_model::new_operator_user();
+        let mut form = test_model::new_form(author.id().clone());
+        let access_control =
+            test_model::new_user_access_control(author.id().clone(), AccessControlLevel::Update);
+        assert!(matches!(
+            form.set_access_control(&author, access_control),
+            Err(NoUpdatePermissionError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_update_access_control_admin() {
+        let admin = test_model::new_admin_user();
+        let user = test_model::new_general_user();
+        let mut form = test_model::new_form(admin.id().clone());
+        assert!(!form.is_visible_to(&user));
+        let version = form.version();
+        form.set_access_control(
+            &admin,
+            test_model::new_user_access_control(user.id().clone(), AccessControlLevel::Read),
+        )
+        .unwrap();
+        assert!(form.is_visible_to(&user));
+        assert_eq!(form.version(), version);
+    }
+
     #[test]
     fn test_update_name_other_admin_after_start() {
         let author = test_model::new_operator_user();
@@ -60,6 +60,10 @@ impl FromProjectsError {
 }
 
 impl FormConditionProjectSet {
+    pub fn empty() -> Self {
+        FormConditionProjectSet(LengthLimitedSet::new(HashSet::new()).unwrap())
+    }
+
     pub fn from_projects<I>(projects: I) -> Result<Self, FromProjectsError>
     where
         I: IntoIterator<Item = ProjectId>,
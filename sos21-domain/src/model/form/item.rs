@@ -1,8 +1,9 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::model::collection::{self, LengthBoundedVec};
 use crate::model::form_answer::item::{FormAnswerItem, FormAnswerItemBody, FormAnswerItems};
 
+use anyhow::Context;
 use serde::{
     de::{self, Deserializer},
     Deserialize, Serialize,
@@ -15,15 +16,19 @@ pub mod condition;
 pub mod description;
 pub mod file;
 pub mod grid_radio;
+pub mod group;
 pub mod integer;
 pub mod name;
 pub mod radio;
 pub mod text;
 pub use checkbox::CheckboxFormItem;
-pub use condition::{FormItemCondition, FormItemConditions};
+pub use condition::{
+    FormItemCondition, FormItemConditions, IntegerConditionOperator, RespondentProject,
+};
 pub use description::FormItemDescription;
 pub use file::FileFormItem;
 pub use grid_radio::GridRadioFormItem;
+pub use group::GroupFormItem;
 pub use integer::IntegerFormItem;
 pub use name::FormItemName;
 pub use radio::RadioFormItem;
@@ -80,7 +85,7 @@ impl FromItemsError {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CheckAnswerErrorKind {
     MismatchedItemsLength,
     MismatchedItemId {
@@ -98,7 +103,7 @@ pub struct CheckAnswerError {
 
 impl CheckAnswerError {
     pub fn kind(&self) -> CheckAnswerErrorKind {
-        self.kind
+        self.kind.clone()
     }
 
     fn from_item_error(item_id: FormItemId, err: CheckAnswerItemError) -> Self {
@@ -108,6 +113,15 @@ impl CheckAnswerError {
     }
 }
 
+/// The result of checking the answer to an item, reported by [`FormItems::check_answer_items`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnswerItemCheck {
+    pub item_id: FormItemId,
+    /// `true` if the item is hidden because its conditions are not satisfied.
+    pub is_hidden: bool,
+    pub error: Option<CheckAnswerItemErrorKind>,
+}
+
 #[allow(clippy::len_without_is_empty)]
 impl FormItems {
     pub fn from_items<I>(items: I) -> Result<Self, FromItemsError>
@@ -120,6 +134,18 @@ impl FormItems {
         Ok(FormItems(items))
     }
 
+    /// Run the same checks as [`FormItems::from_items`] and report all the errors found,
+    /// instead of stopping at the first one.
+    pub fn check_items(items: &[FormItem]) -> Vec<FromItemsError> {
+        let mut errors = Vec::new();
+        CheckFormItems::default().collect_items_errors(items, &mut errors);
+        let item_refs = items.iter().collect();
+        if let Err(err) = LengthBoundedVec::<typenum::U1, typenum::U64, &FormItem>::new(item_refs) {
+            errors.push(FromItemsError::from_length_error(err));
+        }
+        errors
+    }
+
     /// it always stands that `items.len() > 0`.
     pub fn len(&self) -> usize {
         let len = self.0.len();
@@ -137,6 +163,7 @@ impl FormItems {
 
     pub fn check_answer(
         &self,
+        respondent: &RespondentProject,
         answer: &FormAnswerItems,
     ) -> Result<Result<(), CheckAnswerError>, anyhow::Error> {
         if self.len() != answer.len() {
@@ -156,7 +183,7 @@ impl FormItems {
                 }));
             }
 
-            if let Err(err) = item.check_answer(&known_answers, answer_item)? {
+            if let Err(err) = item.check_answer(respondent, &known_answers, answer_item)? {
                 return Ok(Err(CheckAnswerError::from_item_error(item.id, err)));
             }
 
@@ -165,6 +192,107 @@ impl FormItems {
 
         Ok(Ok(()))
     }
+
+    /// Checks the answer to each item like [`FormItems::check_answer`], but continues
+    /// to the following items after an invalid answer to report every item's result.
+    pub fn check_answer_items(
+        &self,
+        respondent: &RespondentProject,
+        answer: &FormAnswerItems,
+    ) -> Result<Result<Vec<AnswerItemCheck>, CheckAnswerError>, anyhow::Error> {
+        if self.len() != answer.len() {
+            return Ok(Err(CheckAnswerError {
+                kind: CheckAnswerErrorKind::MismatchedItemsLength,
+            }));
+        }
+
+        let mut known_answers = HashMap::new();
+        let mut checks = Vec::new();
+        for (item, answer_item) in self.items().zip(answer.items()) {
+            if item.id != answer_item.item_id {
+                return Ok(Err(CheckAnswerError {
+                    kind: CheckAnswerErrorKind::MismatchedItemId {
+                        expected: item.id,
+                        got: answer_item.item_id,
+                    },
+                }));
+            }
+
+            let is_hidden = match &item.conditions {
+                Some(conditions) => !conditions.is_matched_in(respondent, &known_answers)?,
+                None => false,
+            };
+            let error = item
+                .check_answer(respondent, &known_answers, answer_item)?
+                .err()
+                .map(|err| err.kind());
+            checks.push(AnswerItemCheck {
+                item_id: item.id,
+                is_hidden,
+                error,
+            });
+
+            known_answers.insert(answer_item.item_id, answer_item.clone());
+        }
+
+        Ok(Ok(checks))
+    }
+
+    /// Maps the answer to another version of the form onto these items.
+    ///
+    /// Answers are carried over by item IDs. The answer to an item is cleared when it is
+    /// no longer valid for the item, and such items are returned along with the migrated answer
+    /// when the item still requires an answer, which means the answer must be re-submitted.
+    pub fn migrate_answer(
+        &self,
+        respondent: &RespondentProject,
+        answer: &FormAnswerItems,
+    ) -> Result<(FormAnswerItems, Vec<FormItemId>), anyhow::Error> {
+        let old_answers: HashMap<_, _> = answer
+            .items()
+            .map(|answer_item| (answer_item.item_id, answer_item))
+            .collect();
+
+        let mut known_answers = HashMap::new();
+        let mut migrated_items = Vec::new();
+        let mut flagged_item_ids = Vec::new();
+        for item in self.items() {
+            let mut answer_item = FormAnswerItem {
+                item_id: item.id,
+                body: old_answers
+                    .get(&item.id)
+                    .and_then(|answer_item| answer_item.body.clone()),
+            };
+
+            if item
+                .check_answer(respondent, &known_answers, &answer_item)?
+                .is_err()
+            {
+                answer_item.body = None;
+                if item
+                    .check_answer(respondent, &known_answers, &answer_item)?
+                    .is_err()
+                {
+                    flagged_item_ids.push(item.id);
+                }
+            }
+
+            known_answers.insert(item.id, answer_item.clone());
+            migrated_items.push(answer_item);
+        }
+
+        let migrated_items = FormAnswerItems::from_items(migrated_items)?;
+        Ok((migrated_items, flagged_item_ids))
+    }
+
+    /// Duplicates the items with newly generated IDs.
+    ///
+    /// IDs of the items, checkboxes, radio buttons, and grid radio rows and columns
+    /// are all regenerated, and the references to them in the conditions are rewritten accordingly.
+    pub fn duplicate(&self) -> Result<FormItems, anyhow::Error> {
+        let items = DuplicateFormItems::default().duplicate_items(self.items())?;
+        FormItems::from_items(items).context("Duplicated form items are inconsistent")
+    }
 }
 
 impl<'de> Deserialize<'de> for FormItems {
@@ -177,7 +305,7 @@ impl<'de> Deserialize<'de> for FormItems {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CheckAnswerItemErrorKind {
     NotAnsweredWithoutCondition,
     NotAnsweredWithCondition,
@@ -214,6 +342,21 @@ pub enum CheckAnswerItemErrorKind {
     NotAllowedDuplicatedGridRadioColumn {
         id: grid_radio::GridRadioColumnId,
     },
+    TooManyGroupRows,
+    TooFewGroupRows,
+    MismatchedGroupRowLength {
+        row: usize,
+    },
+    MismatchedGroupRowItemId {
+        row: usize,
+        expected: FormItemId,
+        got: FormItemId,
+    },
+    InvalidGroupRowItem {
+        row: usize,
+        id: FormItemId,
+        kind: Box<CheckAnswerItemErrorKind>,
+    },
 }
 
 #[derive(Debug, Error, Clone)]
@@ -224,7 +367,7 @@ pub struct CheckAnswerItemError {
 
 impl CheckAnswerItemError {
     pub fn kind(&self) -> CheckAnswerItemErrorKind {
-        self.kind
+        self.kind.clone()
     }
 
     pub fn from_text_item_error(err: text::CheckAnswerError) -> Self {
@@ -312,6 +455,28 @@ impl CheckAnswerItemError {
 
         CheckAnswerItemError { kind }
     }
+
+    pub fn from_group_item_error(err: group::CheckAnswerError) -> Self {
+        let kind = match err.kind() {
+            group::CheckAnswerErrorKind::TooManyRows => CheckAnswerItemErrorKind::TooManyGroupRows,
+            group::CheckAnswerErrorKind::TooFewRows => CheckAnswerItemErrorKind::TooFewGroupRows,
+            group::CheckAnswerErrorKind::MismatchedRowLength { row } => {
+                CheckAnswerItemErrorKind::MismatchedGroupRowLength { row }
+            }
+            group::CheckAnswerErrorKind::MismatchedItemId { row, expected, got } => {
+                CheckAnswerItemErrorKind::MismatchedGroupRowItemId { row, expected, got }
+            }
+            group::CheckAnswerErrorKind::InvalidItem { row, id, kind } => {
+                CheckAnswerItemErrorKind::InvalidGroupRowItem {
+                    row,
+                    id,
+                    kind: Box::new(kind),
+                }
+            }
+        };
+
+        CheckAnswerItemError { kind }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -340,6 +505,7 @@ pub struct FormItem {
 impl FormItem {
     fn check_answer(
         &self,
+        respondent: &RespondentProject,
         known_answers: &HashMap<FormItemId, FormAnswerItem>,
         answer: &FormAnswerItem,
     ) -> Result<Result<(), CheckAnswerItemError>, anyhow::Error> {
@@ -351,7 +517,7 @@ impl FormItem {
             }
             (None, Some(body)) => body,
             (Some(conditions), body_opt) => {
-                let is_match = conditions.is_matched_in(known_answers)?;
+                let is_match = conditions.is_matched_in(respondent, known_answers)?;
                 match (is_match, body_opt) {
                     (true, Some(body)) => body,
                     (true, None) => {
@@ -369,7 +535,7 @@ impl FormItem {
             }
         };
 
-        Ok(self.body.check_answer(body))
+        self.body.check_answer(respondent, known_answers, body)
     }
 }
 
@@ -381,11 +547,17 @@ pub enum FormItemBody {
     Radio(RadioFormItem),
     GridRadio(GridRadioFormItem),
     File(FileFormItem),
+    Group(GroupFormItem),
 }
 
 impl FormItemBody {
-    pub fn check_answer(&self, answer: &FormAnswerItemBody) -> Result<(), CheckAnswerItemError> {
-        match (self, answer) {
+    pub fn check_answer(
+        &self,
+        respondent: &RespondentProject,
+        known_answers: &HashMap<FormItemId, FormAnswerItem>,
+        answer: &FormAnswerItemBody,
+    ) -> Result<Result<(), CheckAnswerItemError>, anyhow::Error> {
+        let result = match (self, answer) {
             (FormItemBody::Text(item), FormAnswerItemBody::Text(answer)) => item
                 .check_answer(answer.as_ref())
                 .map_err(CheckAnswerItemError::from_text_item_error),
@@ -404,55 +576,81 @@ impl FormItemBody {
             (FormItemBody::File(item), FormAnswerItemBody::File(answer)) => item
                 .check_answer(answer)
                 .map_err(CheckAnswerItemError::from_file_item_error),
+            (FormItemBody::Group(item), FormAnswerItemBody::Group(answer)) => item
+                .check_answer(respondent, known_answers, answer)?
+                .map_err(CheckAnswerItemError::from_group_item_error),
             (_, _) => Err(CheckAnswerItemError {
                 kind: CheckAnswerItemErrorKind::MismatchedItemType,
             }),
-        }
+        };
+
+        Ok(result)
     }
 }
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 struct CheckFormItems {
     items: HashMap<FormItemId, FormItem>,
+    group_item_ids: HashSet<FormItemId>,
 }
 
 impl CheckFormItems {
     fn check_items<'a, I>(&mut self, items: I) -> Result<(), FromItemsError>
+    where
+        I: IntoIterator<Item = &'a FormItem>,
+    {
+        let mut errors = Vec::new();
+        self.collect_items_errors(items, &mut errors);
+        match errors.into_iter().next() {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    fn collect_items_errors<'a, I>(&mut self, items: I, errors: &mut Vec<FromItemsError>)
     where
         I: IntoIterator<Item = &'a FormItem>,
     {
         for item in items {
-            self.check_item(item)?;
+            self.collect_item_errors(item, errors);
         }
-        Ok(())
     }
 
-    fn check_item(&mut self, item: &FormItem) -> Result<(), FromItemsError> {
-        if self.items.insert(item.id, item.clone()).is_some() {
-            return Err(FromItemsError {
+    fn collect_item_errors(&mut self, item: &FormItem, errors: &mut Vec<FromItemsError>) {
+        if self.group_item_ids.contains(&item.id)
+            || self.items.insert(item.id, item.clone()).is_some()
+        {
+            errors.push(FromItemsError {
                 kind: FromItemsErrorKind::DuplicatedFormItemId(item.id),
             });
         }
 
         if let Some(conditions) = &item.conditions {
-            self.check_conditions(item.id, conditions)?;
+            self.collect_conditions_errors(item.id, conditions, errors);
         }
 
-        Ok(())
+        if let FormItemBody::Group(group) = &item.body {
+            // items in a group can refer to the preceding items in the same group,
+            // while items outside of the group cannot refer to the items in the group.
+            self.clone().collect_items_errors(group.items(), errors);
+            self.group_item_ids
+                .extend(group.items().map(|group_item| group_item.id));
+        }
     }
 
-    fn check_conditions(
+    fn collect_conditions_errors(
         &self,
         item_id: FormItemId,
         conditions: &FormItemConditions,
-    ) -> Result<(), FromItemsError> {
+        errors: &mut Vec<FromItemsError>,
+    ) {
         for conj in conditions.conjunctions() {
             for condition in conj {
-                self.check_condition(item_id, condition)?;
+                if let Err(err) = self.check_condition(item_id, condition) {
+                    errors.push(err);
+                }
             }
         }
-
-        Ok(())
     }
 
     fn check_condition(
@@ -472,6 +670,47 @@ impl CheckFormItems {
             FormItemCondition::GridRadioSelected { item_id, column_id } => {
                 self.check_grid_radio_condition(provenance, *item_id, *column_id)
             }
+            FormItemCondition::Integer { item_id, .. } => {
+                match self.get_condition_target(provenance, *item_id)? {
+                    FormItemBody::Integer(_) => Ok(()),
+                    _ => Err(FromItemsError {
+                        kind: FromItemsErrorKind::MismatchedConditionType {
+                            provenance,
+                            id: *item_id,
+                        },
+                    }),
+                }
+            }
+            FormItemCondition::TextNonEmpty { item_id } => {
+                match self.get_condition_target(provenance, *item_id)? {
+                    FormItemBody::Text(_) => Ok(()),
+                    _ => Err(FromItemsError {
+                        kind: FromItemsErrorKind::MismatchedConditionType {
+                            provenance,
+                            id: *item_id,
+                        },
+                    }),
+                }
+            }
+            FormItemCondition::ProjectCategory { .. }
+            | FormItemCondition::ProjectAttribute { .. } => Ok(()),
+            FormItemCondition::Not(condition) => self.check_condition(provenance, condition),
+        }
+    }
+
+    fn get_condition_target(
+        &self,
+        provenance: FormItemId,
+        target_id: FormItemId,
+    ) -> Result<&FormItemBody, FromItemsError> {
+        match self.items.get(&target_id) {
+            Some(item) => Ok(&item.body),
+            None => Err(FromItemsError {
+                kind: FromItemsErrorKind::UnknownFormItemIdInConditions {
+                    provenance,
+                    id: target_id,
+                },
+            }),
         }
     }
 
@@ -602,12 +841,185 @@ impl CheckFormItems {
     }
 }
 
+/// Regenerates the IDs in form items, keeping the references to them in conditions consistent.
+///
+/// Items are processed in order, so that the IDs which conditions refer to are always
+/// already regenerated when the conditions are processed.
+#[derive(Default)]
+struct DuplicateFormItems {
+    item_ids: HashMap<FormItemId, FormItemId>,
+    checkbox_ids: HashMap<checkbox::CheckboxId, checkbox::CheckboxId>,
+    radio_ids: HashMap<radio::RadioId, radio::RadioId>,
+    grid_radio_column_ids: HashMap<grid_radio::GridRadioColumnId, grid_radio::GridRadioColumnId>,
+}
+
+fn get_duplicated_id<K>(ids: &HashMap<K, K>, id: K) -> Result<K, anyhow::Error>
+where
+    K: Eq + std::hash::Hash + Copy + std::fmt::Debug,
+{
+    ids.get(&id)
+        .copied()
+        .with_context(|| format!("Unknown ID {:?} in conditions", id))
+}
+
+impl DuplicateFormItems {
+    fn duplicate_items<'a, I>(&mut self, items: I) -> Result<Vec<FormItem>, anyhow::Error>
+    where
+        I: IntoIterator<Item = &'a FormItem>,
+    {
+        items
+            .into_iter()
+            .map(|item| self.duplicate_item(item))
+            .collect()
+    }
+
+    fn duplicate_item(&mut self, item: &FormItem) -> Result<FormItem, anyhow::Error> {
+        let conditions = match &item.conditions {
+            Some(conditions) => Some(self.duplicate_conditions(conditions)?),
+            None => None,
+        };
+        let body = self.duplicate_body(&item.body)?;
+
+        let id = FormItemId::from_uuid(Uuid::new_v4());
+        self.item_ids.insert(item.id, id);
+
+        Ok(FormItem {
+            id,
+            name: item.name.clone(),
+            description: item.description.clone(),
+            conditions,
+            body,
+        })
+    }
+
+    fn duplicate_body(&mut self, body: &FormItemBody) -> Result<FormItemBody, anyhow::Error> {
+        let body = match body.clone() {
+            FormItemBody::Checkbox(item) => {
+                let mut content = item.into_content();
+                let mut boxes = Vec::new();
+                for checkbox in content.boxes.into_boxes() {
+                    let id = checkbox::CheckboxId::from_uuid(Uuid::new_v4());
+                    self.checkbox_ids.insert(checkbox.id, id);
+                    boxes.push(checkbox::Checkbox { id, ..checkbox });
+                }
+                content.boxes = checkbox::CheckboxFormItemBoxes::from_boxes(boxes)?;
+                FormItemBody::Checkbox(CheckboxFormItem::from_content(content)?)
+            }
+            FormItemBody::Radio(item) => {
+                let mut buttons = Vec::new();
+                for button in item.buttons.into_buttons() {
+                    let id = radio::RadioId::from_uuid(Uuid::new_v4());
+                    self.radio_ids.insert(button.id, id);
+                    buttons.push(radio::Radio { id, ..button });
+                }
+                FormItemBody::Radio(RadioFormItem {
+                    buttons: radio::RadioFormItemButtons::from_buttons(buttons)?,
+                    is_required: item.is_required,
+                })
+            }
+            FormItemBody::GridRadio(item) => {
+                let mut content = item.into_content();
+                let rows = content
+                    .rows
+                    .into_rows()
+                    .map(|row| grid_radio::GridRadioRow {
+                        id: grid_radio::GridRadioRowId::from_uuid(Uuid::new_v4()),
+                        ..row
+                    });
+                content.rows = grid_radio::GridRadioFormItemRows::from_rows(rows)?;
+                let mut columns = Vec::new();
+                for column in content.columns.into_columns() {
+                    let id = grid_radio::GridRadioColumnId::from_uuid(Uuid::new_v4());
+                    self.grid_radio_column_ids.insert(column.id, id);
+                    columns.push(grid_radio::GridRadioColumn { id, ..column });
+                }
+                content.columns = grid_radio::GridRadioFormItemColumns::from_columns(columns)?;
+                FormItemBody::GridRadio(GridRadioFormItem::from_content(content)?)
+            }
+            FormItemBody::Group(item) => {
+                let mut content = item.into_content();
+                let items = self.duplicate_items(content.items.items())?;
+                content.items = group::GroupFormItemItems::from_items(items)?;
+                FormItemBody::Group(GroupFormItem::from_content(content)?)
+            }
+            body @ FormItemBody::Text(_)
+            | body @ FormItemBody::Integer(_)
+            | body @ FormItemBody::File(_) => body,
+        };
+
+        Ok(body)
+    }
+
+    fn duplicate_conditions(
+        &self,
+        conditions: &FormItemConditions,
+    ) -> Result<FormItemConditions, anyhow::Error> {
+        let dnf = conditions
+            .conjunctions()
+            .map(|conj| {
+                conj.iter()
+                    .map(|condition| self.duplicate_condition(condition))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(FormItemConditions::from_conjunctions(dnf)?)
+    }
+
+    fn duplicate_condition(
+        &self,
+        condition: &FormItemCondition,
+    ) -> Result<FormItemCondition, anyhow::Error> {
+        let condition = match condition {
+            FormItemCondition::Checkbox {
+                item_id,
+                checkbox_id,
+                expected,
+            } => FormItemCondition::Checkbox {
+                item_id: get_duplicated_id(&self.item_ids, *item_id)?,
+                checkbox_id: get_duplicated_id(&self.checkbox_ids, *checkbox_id)?,
+                expected: *expected,
+            },
+            FormItemCondition::RadioSelected { item_id, radio_id } => {
+                FormItemCondition::RadioSelected {
+                    item_id: get_duplicated_id(&self.item_ids, *item_id)?,
+                    radio_id: get_duplicated_id(&self.radio_ids, *radio_id)?,
+                }
+            }
+            FormItemCondition::GridRadioSelected { item_id, column_id } => {
+                FormItemCondition::GridRadioSelected {
+                    item_id: get_duplicated_id(&self.item_ids, *item_id)?,
+                    column_id: get_duplicated_id(&self.grid_radio_column_ids, *column_id)?,
+                }
+            }
+            FormItemCondition::Integer {
+                item_id,
+                operator,
+                value,
+            } => FormItemCondition::Integer {
+                item_id: get_duplicated_id(&self.item_ids, *item_id)?,
+                operator: *operator,
+                value: *value,
+            },
+            FormItemCondition::TextNonEmpty { item_id } => FormItemCondition::TextNonEmpty {
+                item_id: get_duplicated_id(&self.item_ids, *item_id)?,
+            },
+            FormItemCondition::ProjectCategory { .. }
+            | FormItemCondition::ProjectAttribute { .. } => condition.clone(),
+            FormItemCondition::Not(condition) => {
+                FormItemCondition::Not(Box::new(self.duplicate_condition(condition)?))
+            }
+        };
+
+        Ok(condition)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
         radio::{RadioFormItem, RadioFormItemButtons, RadioId},
-        CheckAnswerErrorKind, CheckAnswerItemErrorKind, CheckFormItems, FormItemBody,
-        FormItemCondition, FormItemId, FormItems, FromItemsErrorKind,
+        AnswerItemCheck, CheckAnswerErrorKind, CheckAnswerItemErrorKind, CheckFormItems,
+        FormItemBody, FormItemCondition, FormItemId, FormItems, FromItemsErrorKind,
     };
     use crate::test::model as test_model;
     use uuid::Uuid;
@@ -657,6 +1069,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_check_items_reports_all_errors() {
+        let item = test_model::new_form_item();
+        let item_id = FormItemId::from_uuid(Uuid::new_v4());
+        let dangling_item =
+            test_model::new_form_item_with_condition(FormItemCondition::TextNonEmpty { item_id });
+        let errors: Vec<_> =
+            FormItems::check_items(&[item.clone(), item.clone(), dangling_item.clone()])
+                .into_iter()
+                .map(|err| err.kind())
+                .collect();
+        assert_eq!(
+            errors,
+            vec![
+                FromItemsErrorKind::DuplicatedFormItemId(item.id),
+                FromItemsErrorKind::UnknownFormItemIdInConditions {
+                    provenance: dangling_item.id,
+                    id: item_id,
+                },
+            ]
+        );
+        assert_eq!(
+            FormItems::check_items(&[])
+                .into_iter()
+                .map(|err| err.kind())
+                .collect::<Vec<_>>(),
+            vec![FromItemsErrorKind::Empty]
+        );
+    }
+
     #[test]
     fn test_unknown_radio_id() {
         let item = test_model::new_form_item();
@@ -701,6 +1143,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_mismatched_types_in_negated_integer_condition() {
+        use super::IntegerConditionOperator;
+
+        let item = test_model::new_form_item_with_body(test_model::new_radio_form_item_body());
+        let condition = FormItemCondition::Not(Box::new(FormItemCondition::Integer {
+            item_id: item.id,
+            operator: IntegerConditionOperator::Gt,
+            value: 1500,
+        }));
+        let bad_item = test_model::new_form_item_with_condition(condition);
+        assert_eq!(
+            CheckFormItems::default()
+                .check_items(&[item.clone(), bad_item.clone()])
+                .unwrap_err()
+                .kind(),
+            FromItemsErrorKind::MismatchedConditionType {
+                provenance: bad_item.id,
+                id: item.id
+            }
+        );
+    }
+
     #[test]
     fn test_answer_mismatched_length() {
         use crate::model::form_answer::FormAnswerItems;
@@ -714,7 +1179,7 @@ mod tests {
         .unwrap();
         assert_eq!(
             items
-                .check_answer(&answer_items)
+                .check_answer(&test_model::mock_respondent_project(), &answer_items)
                 .unwrap()
                 .unwrap_err()
                 .kind(),
@@ -735,7 +1200,7 @@ mod tests {
         .unwrap();
         assert!(matches!(
             items
-                .check_answer(&answer_items)
+                .check_answer(&test_model::mock_respondent_project(), &answer_items)
                 .unwrap()
                 .unwrap_err()
                 .kind(),
@@ -755,7 +1220,7 @@ mod tests {
 
         assert_eq!(
             items
-                .check_answer(&answer_items)
+                .check_answer(&test_model::mock_respondent_project(), &answer_items)
                 .unwrap()
                 .unwrap_err()
                 .kind(),
@@ -793,7 +1258,7 @@ mod tests {
 
         assert_eq!(
             items
-                .check_answer(&answer_items)
+                .check_answer(&test_model::mock_respondent_project(), &answer_items)
                 .unwrap()
                 .unwrap_err()
                 .kind(),
@@ -836,11 +1301,268 @@ mod tests {
 
         assert_eq!(
             items
-                .check_answer(&answer_items)
+                .check_answer(&test_model::mock_respondent_project(), &answer_items)
                 .unwrap()
                 .unwrap_err()
                 .kind(),
             CheckAnswerErrorKind::Item(item2.id, CheckAnswerItemErrorKind::UnexpectedAnswer)
         );
     }
+
+    #[test]
+    fn test_check_answer_items_reports_all_items() {
+        use crate::model::form_answer::item::{
+            FormAnswerItem, FormAnswerItemBody, FormAnswerItems,
+        };
+
+        let radio1 = test_model::new_form_radio_button();
+        let radio2 = test_model::new_form_radio_button();
+        let item1 = test_model::new_form_item_with_body(FormItemBody::Radio(RadioFormItem {
+            buttons: RadioFormItemButtons::from_buttons(vec![radio1.clone(), radio2.clone()])
+                .unwrap(),
+            is_required: true,
+        }));
+        let condition = FormItemCondition::RadioSelected {
+            item_id: item1.id,
+            radio_id: radio2.id,
+        };
+        let item2 = test_model::new_form_item_with_condition(condition);
+        let item3 = test_model::new_form_item();
+        let items =
+            FormItems::from_items(vec![item1.clone(), item2.clone(), item3.clone()]).unwrap();
+
+        let answer_item1 = FormAnswerItem {
+            item_id: item1.id,
+            body: Some(FormAnswerItemBody::Radio(Some(radio1.id))),
+        };
+        let answer_item3 = FormAnswerItem {
+            item_id: item3.id,
+            body: None,
+        };
+        let answer_items = FormAnswerItems::from_items(vec![
+            answer_item1,
+            test_model::mock_form_answer_item(&item2),
+            answer_item3,
+        ])
+        .unwrap();
+
+        assert_eq!(
+            items
+                .check_answer_items(&test_model::mock_respondent_project(), &answer_items)
+                .unwrap()
+                .unwrap(),
+            vec![
+                AnswerItemCheck {
+                    item_id: item1.id,
+                    is_hidden: false,
+                    error: None,
+                },
+                AnswerItemCheck {
+                    item_id: item2.id,
+                    is_hidden: true,
+                    error: Some(CheckAnswerItemErrorKind::UnexpectedAnswer),
+                },
+                AnswerItemCheck {
+                    item_id: item3.id,
+                    is_hidden: false,
+                    error: Some(CheckAnswerItemErrorKind::NotAnsweredWithoutCondition),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_group_duplicate_item() {
+        let item = test_model::new_form_item();
+        let group_item = test_model::new_form_item_with_body(
+            test_model::new_group_form_item_body_with_items(vec![item.clone()]),
+        );
+        assert_eq!(
+            CheckFormItems::default()
+                .check_items(&[group_item, item.clone()])
+                .unwrap_err()
+                .kind(),
+            FromItemsErrorKind::DuplicatedFormItemId(item.id)
+        );
+    }
+
+    #[test]
+    fn test_group_condition_scope() {
+        let button = test_model::new_form_radio_button();
+        let outer_item = test_model::new_form_item_with_body(
+            test_model::new_radio_form_item_body_with_button(button.clone()),
+        );
+        let child_item = test_model::new_form_item_with_body(
+            test_model::new_radio_form_item_body_with_button(button.clone()),
+        );
+        let child_dependent_item =
+            test_model::new_form_item_with_condition(FormItemCondition::RadioSelected {
+                item_id: child_item.id,
+                radio_id: button.id,
+            });
+        let outer_dependent_item =
+            test_model::new_form_item_with_condition(FormItemCondition::RadioSelected {
+                item_id: outer_item.id,
+                radio_id: button.id,
+            });
+        let group_item = test_model::new_form_item_with_body(
+            test_model::new_group_form_item_body_with_items(vec![
+                child_item.clone(),
+                child_dependent_item,
+                outer_dependent_item,
+            ]),
+        );
+        CheckFormItems::default()
+            .check_items(&[outer_item, group_item])
+            .unwrap();
+
+        let group_item = test_model::new_form_item_with_body(
+            test_model::new_group_form_item_body_with_items(vec![child_item.clone()]),
+        );
+
+        let dangling_item =
+            test_model::new_form_item_with_condition(FormItemCondition::RadioSelected {
+                item_id: child_item.id,
+                radio_id: button.id,
+            });
+        assert_eq!(
+            CheckFormItems::default()
+                .check_items(&[group_item, dangling_item.clone()])
+                .unwrap_err()
+                .kind(),
+            FromItemsErrorKind::UnknownFormItemIdInConditions {
+                provenance: dangling_item.id,
+                id: child_item.id,
+            }
+        );
+    }
+
+    #[test]
+    fn test_answer_group_row_condition() {
+        use crate::model::form_answer::item::{
+            FormAnswerItem, FormAnswerItemBody, FormAnswerItemGroupRows, FormAnswerItems,
+        };
+
+        let radio1 = test_model::new_form_radio_button();
+        let radio2 = test_model::new_form_radio_button();
+        let child_item1 = test_model::new_form_item_with_body(FormItemBody::Radio(RadioFormItem {
+            buttons: RadioFormItemButtons::from_buttons(vec![radio1.clone(), radio2.clone()])
+                .unwrap(),
+            is_required: true,
+        }));
+        let child_item2 =
+            test_model::new_form_item_with_condition(FormItemCondition::RadioSelected {
+                item_id: child_item1.id,
+                radio_id: radio2.id,
+            });
+        let group_item =
+            test_model::new_form_item_with_body(test_model::new_group_form_item_body_with_items(
+                vec![child_item1.clone(), child_item2.clone()],
+            ));
+        let items = FormItems::from_items(vec![group_item.clone()]).unwrap();
+
+        let row = |radio_id, body| {
+            FormAnswerItems::from_items(vec![
+                FormAnswerItem {
+                    item_id: child_item1.id,
+                    body: Some(FormAnswerItemBody::Radio(Some(radio_id))),
+                },
+                FormAnswerItem {
+                    item_id: child_item2.id,
+                    body,
+                },
+            ])
+            .unwrap()
+        };
+        let answer = |rows| {
+            FormAnswerItems::from_items(vec![FormAnswerItem {
+                item_id: group_item.id,
+                body: Some(FormAnswerItemBody::Group(
+                    FormAnswerItemGroupRows::from_rows(rows).unwrap(),
+                )),
+            }])
+            .unwrap()
+        };
+        let child_answer = test_model::mock_form_answer_item(&child_item2).body;
+
+        items
+            .check_answer(
+                &test_model::mock_respondent_project(),
+                &answer(vec![
+                    row(radio1.id, None),
+                    row(radio2.id, child_answer.clone()),
+                ]),
+            )
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            items
+                .check_answer(
+                    &test_model::mock_respondent_project(),
+                    &answer(vec![
+                        row(radio2.id, child_answer.clone()),
+                        row(radio1.id, child_answer),
+                    ])
+                )
+                .unwrap()
+                .unwrap_err()
+                .kind(),
+            CheckAnswerErrorKind::Item(
+                group_item.id,
+                CheckAnswerItemErrorKind::InvalidGroupRowItem {
+                    row: 1,
+                    id: child_item2.id,
+                    kind: Box::new(CheckAnswerItemErrorKind::UnexpectedAnswer),
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn test_duplicate() {
+        let radio = test_model::new_form_radio_button();
+        let radio_item = test_model::new_form_item_with_body(
+            test_model::new_radio_form_item_body_with_button(radio.clone()),
+        );
+        let child_item =
+            test_model::new_form_item_with_condition(FormItemCondition::RadioSelected {
+                item_id: radio_item.id,
+                radio_id: radio.id,
+            });
+        let group_item = test_model::new_form_item_with_body(
+            test_model::new_group_form_item_body_with_items(vec![child_item.clone()]),
+        );
+        let items = FormItems::from_items(vec![radio_item.clone(), group_item.clone()]).unwrap();
+
+        let duplicated = items.duplicate().unwrap();
+        let duplicated: Vec<_> = duplicated.into_items().collect();
+        assert_eq!(duplicated.len(), 2);
+        assert_ne!(duplicated[0].id, radio_item.id);
+        assert_ne!(duplicated[1].id, group_item.id);
+
+        let new_radio_id = match &duplicated[0].body {
+            FormItemBody::Radio(item) => item.buttons().next().unwrap().id,
+            _ => panic!("expected a radio item"),
+        };
+        assert_ne!(new_radio_id, radio.id);
+
+        let new_child_item = match &duplicated[1].body {
+            FormItemBody::Group(item) => item.items().next().unwrap().clone(),
+            _ => panic!("expected a group item"),
+        };
+        assert_ne!(new_child_item.id, child_item.id);
+        let condition = new_child_item
+            .conditions
+            .unwrap()
+            .into_conjunctions()
+            .next()
+            .unwrap()
+            .remove(0);
+        assert!(matches!(
+            condition,
+            FormItemCondition::RadioSelected { item_id, radio_id }
+            if item_id == duplicated[0].id && radio_id == new_radio_id
+        ));
+    }
 }
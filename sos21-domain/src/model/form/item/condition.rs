@@ -4,6 +4,8 @@ use super::{checkbox::CheckboxId, grid_radio::GridRadioColumnId, radio::RadioId,
 use crate::model::bound::{Bounded, Unbounded};
 use crate::model::collection::{self, LengthLimitedVec};
 use crate::model::form_answer::item::{FormAnswerItem, FormAnswerItemBody};
+use crate::model::pending_project::PendingProject;
+use crate::model::project::{Project, ProjectAttribute, ProjectAttributes, ProjectCategory};
 
 use anyhow::bail;
 use serde::{
@@ -26,6 +28,8 @@ pub struct FormItemConditions(
 pub enum SizeErrorKind {
     TooLongConjunction(usize),
     TooLongDisjunction,
+    /// `Not(Not(..))` is rejected to bound the recursion in evaluating conditions.
+    NestedNegation(usize),
 }
 
 #[derive(Debug, Error, Clone)]
@@ -70,6 +74,11 @@ impl FormItemConditions {
             .into_iter()
             .enumerate()
             .map(|(idx, conj)| {
+                if conj.iter().any(FormItemCondition::is_nested_negation) {
+                    return Err(SizeError {
+                        kind: SizeErrorKind::NestedNegation(idx),
+                    });
+                }
                 LengthLimitedVec::new(conj).map_err(|e| SizeError::from_conj_error(e, idx))
             })
             .collect::<Result<_, _>>()?;
@@ -79,11 +88,12 @@ impl FormItemConditions {
 
     pub fn is_matched_in(
         &self,
+        respondent: &RespondentProject,
         known_answers: &HashMap<FormItemId, FormAnswerItem>,
     ) -> Result<bool, anyhow::Error> {
         let is_matched_in_conj = |conj: &Vec<FormItemCondition>| -> Result<bool, anyhow::Error> {
             for condition in conj.iter() {
-                if !condition.is_matched_in(known_answers)? {
+                if !condition.is_matched_in(respondent, known_answers)? {
                     return Ok(false);
                 }
             }
@@ -120,6 +130,52 @@ impl<'de> Deserialize<'de> for FormItemConditions {
     }
 }
 
+/// The project answering the form, against which the project conditions are evaluated.
+#[derive(Debug, Clone)]
+pub struct RespondentProject {
+    pub category: ProjectCategory,
+    pub attributes: ProjectAttributes,
+}
+
+impl RespondentProject {
+    pub fn from_project(project: &Project) -> Self {
+        RespondentProject {
+            category: project.category(),
+            attributes: project.attributes().clone(),
+        }
+    }
+
+    pub fn from_pending_project(pending_project: &PendingProject) -> Self {
+        RespondentProject {
+            category: pending_project.category(),
+            attributes: pending_project.attributes().clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IntegerConditionOperator {
+    Eq,
+    Ne,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+impl IntegerConditionOperator {
+    pub fn compare(&self, lhs: u64, rhs: u64) -> bool {
+        match self {
+            IntegerConditionOperator::Eq => lhs == rhs,
+            IntegerConditionOperator::Ne => lhs != rhs,
+            IntegerConditionOperator::Gt => lhs > rhs,
+            IntegerConditionOperator::Ge => lhs >= rhs,
+            IntegerConditionOperator::Lt => lhs < rhs,
+            IntegerConditionOperator::Le => lhs <= rhs,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum FormItemCondition {
     Checkbox {
@@ -135,9 +191,30 @@ pub enum FormItemCondition {
         item_id: FormItemId,
         column_id: GridRadioColumnId,
     },
+    /// Matches when the integer answer compares to `value` with `operator`.
+    /// Unanswered items never match.
+    Integer {
+        item_id: FormItemId,
+        operator: IntegerConditionOperator,
+        value: u64,
+    },
+    TextNonEmpty {
+        item_id: FormItemId,
+    },
+    ProjectCategory {
+        category: ProjectCategory,
+    },
+    ProjectAttribute {
+        attribute: ProjectAttribute,
+    },
+    Not(Box<FormItemCondition>),
 }
 
 impl FormItemCondition {
+    fn is_nested_negation(&self) -> bool {
+        matches!(self, FormItemCondition::Not(condition) if matches!(**condition, FormItemCondition::Not(_)))
+    }
+
     /// Checks that the condition matches in the environment.
     ///
     /// Note that the answer environment is expected to have all preceding items of the condition
@@ -147,6 +224,7 @@ impl FormItemCondition {
     /// an unexpected error.
     pub fn is_matched_in(
         &self,
+        respondent: &RespondentProject,
         known_answers: &HashMap<FormItemId, FormAnswerItem>,
     ) -> Result<bool, anyhow::Error> {
         match self {
@@ -193,6 +271,40 @@ impl FormItemCondition {
                     .any(|row_answer| row_answer.value == Some(*column_id));
                 Ok(is_match)
             }
+            FormItemCondition::Integer {
+                item_id,
+                operator,
+                value,
+            } => {
+                let answer_item = match known_answers.get(item_id) {
+                    Some(item) => item,
+                    None => bail!("item_id must be known on the valid form"),
+                };
+                let answer = match answer_item.body.as_ref() {
+                    Some(FormAnswerItemBody::Integer(Some(answer))) => *answer,
+                    Some(FormAnswerItemBody::Integer(None)) | None => return Ok(false),
+                    _ => bail!("answer_item.body must be Integer on the valid form"),
+                };
+                Ok(operator.compare(answer, *value))
+            }
+            FormItemCondition::TextNonEmpty { item_id } => {
+                let answer_item = match known_answers.get(item_id) {
+                    Some(item) => item,
+                    None => bail!("item_id must be known on the valid form"),
+                };
+                match answer_item.body.as_ref() {
+                    Some(FormAnswerItemBody::Text(answer)) => Ok(answer.is_some()),
+                    None => Ok(false),
+                    _ => bail!("answer_item.body must be Text on the valid form"),
+                }
+            }
+            FormItemCondition::ProjectCategory { category } => Ok(respondent.category == *category),
+            FormItemCondition::ProjectAttribute { attribute } => {
+                Ok(respondent.attributes.contains(*attribute))
+            }
+            FormItemCondition::Not(condition) => {
+                Ok(!condition.is_matched_in(respondent, known_answers)?)
+            }
         }
     }
 }
@@ -201,11 +313,15 @@ impl FormItemCondition {
 mod tests {
     use std::collections::HashMap;
 
-    use super::FormItemCondition;
+    use super::{
+        FormItemCondition, FormItemConditions, IntegerConditionOperator, RespondentProject,
+        SizeErrorKind,
+    };
     use crate::model::form_answer::item::{
         FormAnswerItem, FormAnswerItemBody, FormAnswerItemChecks, FormAnswerItemGridRows,
-        GridRadioRowAnswer,
+        FormAnswerItemText, GridRadioRowAnswer,
     };
+    use crate::model::project::{ProjectAttribute, ProjectAttributes, ProjectCategory};
     use crate::test::model as test_model;
 
     #[test]
@@ -237,7 +353,9 @@ mod tests {
                 checkbox_id,
                 expected: true,
             };
-            assert!(condition.is_matched_in(&known_answers).unwrap());
+            assert!(condition
+                .is_matched_in(&test_model::mock_respondent_project(), &known_answers)
+                .unwrap());
         }
 
         {
@@ -246,7 +364,9 @@ mod tests {
                 checkbox_id,
                 expected: true,
             };
-            assert!(!condition.is_matched_in(&known_answers).unwrap());
+            assert!(!condition
+                .is_matched_in(&test_model::mock_respondent_project(), &known_answers)
+                .unwrap());
         }
 
         {
@@ -255,7 +375,9 @@ mod tests {
                 checkbox_id,
                 expected: false,
             };
-            assert!(condition.is_matched_in(&known_answers).unwrap());
+            assert!(condition
+                .is_matched_in(&test_model::mock_respondent_project(), &known_answers)
+                .unwrap());
         }
 
         {
@@ -264,7 +386,9 @@ mod tests {
                 checkbox_id,
                 expected: false,
             };
-            assert!(!condition.is_matched_in(&known_answers).unwrap());
+            assert!(!condition
+                .is_matched_in(&test_model::mock_respondent_project(), &known_answers)
+                .unwrap());
         }
     }
 
@@ -295,7 +419,9 @@ mod tests {
                 item_id: item1.item_id,
                 radio_id: radio_id1,
             };
-            assert!(condition.is_matched_in(&known_answers).unwrap());
+            assert!(condition
+                .is_matched_in(&test_model::mock_respondent_project(), &known_answers)
+                .unwrap());
         }
 
         {
@@ -303,7 +429,9 @@ mod tests {
                 item_id: item2.item_id,
                 radio_id: radio_id1,
             };
-            assert!(!condition.is_matched_in(&known_answers).unwrap());
+            assert!(!condition
+                .is_matched_in(&test_model::mock_respondent_project(), &known_answers)
+                .unwrap());
         }
 
         {
@@ -311,7 +439,9 @@ mod tests {
                 item_id: item3.item_id,
                 radio_id: radio_id1,
             };
-            assert!(!condition.is_matched_in(&known_answers).unwrap());
+            assert!(!condition
+                .is_matched_in(&test_model::mock_respondent_project(), &known_answers)
+                .unwrap());
         }
     }
 
@@ -352,14 +482,145 @@ mod tests {
                 item_id: item1.item_id,
                 column_id,
             };
-            assert!(condition.is_matched_in(&known_answers).unwrap());
+            assert!(condition
+                .is_matched_in(&test_model::mock_respondent_project(), &known_answers)
+                .unwrap());
         }
         {
             let condition = FormItemCondition::GridRadioSelected {
                 item_id: item2.item_id,
                 column_id,
             };
-            assert!(!condition.is_matched_in(&known_answers).unwrap());
+            assert!(!condition
+                .is_matched_in(&test_model::mock_respondent_project(), &known_answers)
+                .unwrap());
+        }
+    }
+
+    #[test]
+    fn test_integer() {
+        let item1 = FormAnswerItem {
+            item_id: test_model::new_form_item_id(),
+            body: Some(FormAnswerItemBody::Integer(Some(2000))),
+        };
+        let item2 = FormAnswerItem {
+            item_id: test_model::new_form_item_id(),
+            body: Some(FormAnswerItemBody::Integer(None)),
+        };
+
+        let mut known_answers = HashMap::new();
+        known_answers.insert(item1.item_id, item1.clone());
+        known_answers.insert(item2.item_id, item2.clone());
+
+        let respondent = test_model::mock_respondent_project();
+        let condition = |item_id, operator| FormItemCondition::Integer {
+            item_id,
+            operator,
+            value: 1500,
+        };
+
+        for (operator, expected) in [
+            (IntegerConditionOperator::Eq, false),
+            (IntegerConditionOperator::Ne, true),
+            (IntegerConditionOperator::Gt, true),
+            (IntegerConditionOperator::Ge, true),
+            (IntegerConditionOperator::Lt, false),
+            (IntegerConditionOperator::Le, false),
+        ] {
+            assert_eq!(
+                condition(item1.item_id, operator)
+                    .is_matched_in(&respondent, &known_answers)
+                    .unwrap(),
+                expected
+            );
+            assert!(!condition(item2.item_id, operator)
+                .is_matched_in(&respondent, &known_answers)
+                .unwrap());
         }
     }
+
+    #[test]
+    fn test_text_non_empty() {
+        let item1 = FormAnswerItem {
+            item_id: test_model::new_form_item_id(),
+            body: Some(FormAnswerItemBody::Text(Some(
+                FormAnswerItemText::from_string("テスト").unwrap(),
+            ))),
+        };
+        let item2 = FormAnswerItem {
+            item_id: test_model::new_form_item_id(),
+            body: Some(FormAnswerItemBody::Text(None)),
+        };
+
+        let mut known_answers = HashMap::new();
+        known_answers.insert(item1.item_id, item1.clone());
+        known_answers.insert(item2.item_id, item2.clone());
+
+        let respondent = test_model::mock_respondent_project();
+        assert!(FormItemCondition::TextNonEmpty {
+            item_id: item1.item_id
+        }
+        .is_matched_in(&respondent, &known_answers)
+        .unwrap());
+        assert!(!FormItemCondition::TextNonEmpty {
+            item_id: item2.item_id
+        }
+        .is_matched_in(&respondent, &known_answers)
+        .unwrap());
+    }
+
+    #[test]
+    fn test_project() {
+        let respondent = RespondentProject {
+            category: ProjectCategory::Stage,
+            attributes: ProjectAttributes::from_attributes(vec![ProjectAttribute::Outdoor])
+                .unwrap(),
+        };
+        let known_answers = HashMap::new();
+
+        let is_matched = |condition: FormItemCondition| {
+            condition
+                .is_matched_in(&respondent, &known_answers)
+                .unwrap()
+        };
+        assert!(is_matched(FormItemCondition::ProjectCategory {
+            category: ProjectCategory::Stage
+        }));
+        assert!(!is_matched(FormItemCondition::ProjectCategory {
+            category: ProjectCategory::Food
+        }));
+        assert!(is_matched(FormItemCondition::ProjectAttribute {
+            attribute: ProjectAttribute::Outdoor
+        }));
+        assert!(!is_matched(FormItemCondition::ProjectAttribute {
+            attribute: ProjectAttribute::Indoor
+        }));
+        assert!(is_matched(FormItemCondition::Not(Box::new(
+            FormItemCondition::ProjectAttribute {
+                attribute: ProjectAttribute::Indoor
+            }
+        ))));
+    }
+
+    #[test]
+    fn test_nested_negation() {
+        let condition = FormItemCondition::ProjectAttribute {
+            attribute: ProjectAttribute::Indoor,
+        };
+        let negated = FormItemCondition::Not(Box::new(condition));
+        assert!(FormItemConditions::from_conjunctions(vec![vec![negated.clone()]]).is_ok());
+
+        let nested = FormItemCondition::Not(Box::new(negated.clone()));
+        let err =
+            FormItemConditions::from_conjunctions(vec![vec![negated], vec![nested]]).unwrap_err();
+        assert_eq!(err.kind(), SizeErrorKind::NestedNegation(1));
+    }
+
+    #[test]
+    fn test_deserialize_nested_negation() {
+        let negated = r#"[[{"Not":{"ProjectCategory":{"category":"stage"}}}]]"#;
+        assert!(serde_json::from_str::<FormItemConditions>(negated).is_ok());
+        let nested = r#"[[{"Not":{"Not":{"ProjectCategory":{"category":"stage"}}}}]]"#;
+        assert!(serde_json::from_str::<FormItemConditions>(nested).is_err());
+    }
 }
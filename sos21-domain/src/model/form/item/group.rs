@@ -0,0 +1,360 @@
+use std::collections::HashMap;
+
+use crate::model::collection::{self, LengthBoundedVec};
+use crate::model::form_answer::item::{FormAnswerItem, FormAnswerItemGroupRows};
+
+use serde::{
+    de::{self, Deserializer},
+    Deserialize, Serialize,
+};
+use thiserror::Error;
+
+pub mod limit;
+
+pub use limit::GroupFormItemLimit;
+
+use super::{CheckAnswerItemErrorKind, FormItem, FormItemBody, FormItemId, RespondentProject};
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+pub struct GroupFormItemItems(LengthBoundedVec<typenum::U1, typenum::U16, FormItem>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FromItemsErrorKind {
+    Empty,
+    TooLong,
+    NestedGroup { id: FormItemId },
+}
+
+#[derive(Debug, Error, Clone)]
+#[error("invalid group form item item list")]
+pub struct FromItemsError {
+    kind: FromItemsErrorKind,
+}
+
+impl FromItemsError {
+    pub fn kind(&self) -> FromItemsErrorKind {
+        self.kind
+    }
+
+    fn from_length_error(e: collection::BoundedLengthError<typenum::U1, typenum::U16>) -> Self {
+        let kind = match e.kind() {
+            collection::LengthErrorKind::TooLong => FromItemsErrorKind::TooLong,
+            collection::LengthErrorKind::TooShort => FromItemsErrorKind::Empty,
+        };
+        FromItemsError { kind }
+    }
+}
+
+#[allow(clippy::len_without_is_empty)]
+impl GroupFormItemItems {
+    pub fn from_items<I>(items: I) -> Result<Self, FromItemsError>
+    where
+        I: IntoIterator<Item = FormItem>,
+    {
+        let items: Vec<_> = items.into_iter().collect();
+        for item in &items {
+            if matches!(item.body, FormItemBody::Group(_)) {
+                return Err(FromItemsError {
+                    kind: FromItemsErrorKind::NestedGroup { id: item.id },
+                });
+            }
+        }
+
+        let items = LengthBoundedVec::new(items).map_err(FromItemsError::from_length_error)?;
+        Ok(GroupFormItemItems(items))
+    }
+
+    pub fn items(&self) -> impl Iterator<Item = &'_ FormItem> {
+        self.0.iter()
+    }
+
+    pub fn into_items(self) -> impl Iterator<Item = FormItem> {
+        self.0.into_inner().into_iter()
+    }
+
+    /// it always stands that `items.len() > 0`.
+    pub fn len(&self) -> usize {
+        let len = self.0.len();
+        debug_assert!(len > 0);
+        len
+    }
+}
+
+impl<'de> Deserialize<'de> for GroupFormItemItems {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        GroupFormItemItems::from_items(Vec::<FormItem>::deserialize(deserializer)?)
+            .map_err(de::Error::custom)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupFormItemContent {
+    pub items: GroupFormItemItems,
+    pub min_rows: Option<GroupFormItemLimit>,
+    pub max_rows: Option<GroupFormItemLimit>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+pub struct GroupFormItem(GroupFormItemContent);
+
+#[derive(Debug, Error, Clone)]
+#[error("invalid group form item")]
+pub struct InconsistentRowLimitsError {
+    _priv: (),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckAnswerErrorKind {
+    TooManyRows,
+    TooFewRows,
+    MismatchedRowLength {
+        row: usize,
+    },
+    MismatchedItemId {
+        row: usize,
+        expected: FormItemId,
+        got: FormItemId,
+    },
+    InvalidItem {
+        row: usize,
+        id: FormItemId,
+        kind: CheckAnswerItemErrorKind,
+    },
+}
+
+#[derive(Debug, Error, Clone)]
+#[error("invalid form answer group item")]
+pub struct CheckAnswerError {
+    kind: CheckAnswerErrorKind,
+}
+
+impl CheckAnswerError {
+    pub fn kind(&self) -> CheckAnswerErrorKind {
+        self.kind.clone()
+    }
+}
+
+impl GroupFormItem {
+    pub fn from_content(content: GroupFormItemContent) -> Result<Self, InconsistentRowLimitsError> {
+        match (&content.min_rows, &content.max_rows) {
+            (Some(min_rows), Some(max_rows)) if min_rows > max_rows => {
+                Err(InconsistentRowLimitsError { _priv: () })
+            }
+            _ => Ok(GroupFormItem(content)),
+        }
+    }
+
+    pub fn min_rows(&self) -> Option<u64> {
+        self.0.min_rows.map(GroupFormItemLimit::to_u64)
+    }
+
+    pub fn max_rows(&self) -> Option<u64> {
+        self.0.max_rows.map(GroupFormItemLimit::to_u64)
+    }
+
+    pub fn into_content(self) -> GroupFormItemContent {
+        self.0
+    }
+
+    pub fn items(&self) -> impl Iterator<Item = &'_ FormItem> {
+        self.0.items.items()
+    }
+
+    /// Checks each row of the answer against the items in the group.
+    ///
+    /// Conditions of the items in a row are evaluated with `known_answers`
+    /// and the answers to the preceding items in the same row.
+    pub fn check_answer(
+        &self,
+        respondent: &RespondentProject,
+        known_answers: &HashMap<FormItemId, FormAnswerItem>,
+        answer: &FormAnswerItemGroupRows,
+    ) -> Result<Result<(), CheckAnswerError>, anyhow::Error> {
+        if let Some(min_rows) = self.0.min_rows {
+            if min_rows.to_u64() > answer.len() as u64 {
+                return Ok(Err(CheckAnswerError {
+                    kind: CheckAnswerErrorKind::TooFewRows,
+                }));
+            }
+        }
+
+        if let Some(max_rows) = self.0.max_rows {
+            if max_rows.to_u64() < answer.len() as u64 {
+                return Ok(Err(CheckAnswerError {
+                    kind: CheckAnswerErrorKind::TooManyRows,
+                }));
+            }
+        }
+
+        for (row, row_answer) in answer.rows().enumerate() {
+            if self.0.items.len() != row_answer.len() {
+                return Ok(Err(CheckAnswerError {
+                    kind: CheckAnswerErrorKind::MismatchedRowLength { row },
+                }));
+            }
+
+            let mut row_known_answers = known_answers.clone();
+            for (item, answer_item) in self.items().zip(row_answer.items()) {
+                if item.id != answer_item.item_id {
+                    return Ok(Err(CheckAnswerError {
+                        kind: CheckAnswerErrorKind::MismatchedItemId {
+                            row,
+                            expected: item.id,
+                            got: answer_item.item_id,
+                        },
+                    }));
+                }
+
+                if let Err(err) = item.check_answer(respondent, &row_known_answers, answer_item)? {
+                    return Ok(Err(CheckAnswerError {
+                        kind: CheckAnswerErrorKind::InvalidItem {
+                            row,
+                            id: item.id,
+                            kind: err.kind(),
+                        },
+                    }));
+                }
+
+                row_known_answers.insert(answer_item.item_id, answer_item.clone());
+            }
+        }
+
+        Ok(Ok(()))
+    }
+}
+
+impl<'de> Deserialize<'de> for GroupFormItem {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        GroupFormItem::from_content(GroupFormItemContent::deserialize(deserializer)?)
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CheckAnswerErrorKind, FromItemsErrorKind, GroupFormItem, GroupFormItemContent,
+        GroupFormItemItems, GroupFormItemLimit,
+    };
+    use crate::model::form_answer::item::{FormAnswerItemGroupRows, FormAnswerItems};
+    use crate::test::model as test_model;
+    use std::collections::HashMap;
+
+    fn new_group_form_item(min_rows: Option<u64>, max_rows: Option<u64>) -> GroupFormItem {
+        GroupFormItem::from_content(GroupFormItemContent {
+            items: GroupFormItemItems::from_items(vec![test_model::new_form_item()]).unwrap(),
+            min_rows: min_rows.map(|limit| GroupFormItemLimit::from_u64(limit).unwrap()),
+            max_rows: max_rows.map(|limit| GroupFormItemLimit::from_u64(limit).unwrap()),
+        })
+        .unwrap()
+    }
+
+    fn mock_rows(item: &GroupFormItem, len: usize) -> FormAnswerItemGroupRows {
+        FormAnswerItemGroupRows::from_rows((0..len).map(|_| {
+            FormAnswerItems::from_items(item.items().map(test_model::mock_form_answer_item))
+                .unwrap()
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_inconsistent_limits() {
+        let items = GroupFormItemItems::from_items(vec![test_model::new_form_item()]).unwrap();
+        assert!(GroupFormItem::from_content(GroupFormItemContent {
+            items,
+            min_rows: Some(GroupFormItemLimit::from_u64(3).unwrap()),
+            max_rows: Some(GroupFormItemLimit::from_u64(2).unwrap()),
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_nested_group() {
+        let group_item = test_model::new_form_item_with_body(
+            test_model::new_group_form_item_body_with_items(vec![test_model::new_form_item()]),
+        );
+        assert_eq!(
+            GroupFormItemItems::from_items(vec![group_item.clone()])
+                .unwrap_err()
+                .kind(),
+            FromItemsErrorKind::NestedGroup { id: group_item.id }
+        );
+    }
+
+    #[test]
+    fn test_answer_pass() {
+        let item = new_group_form_item(Some(1), Some(3));
+        for len in 1..=3 {
+            item.check_answer(
+                &test_model::mock_respondent_project(),
+                &HashMap::new(),
+                &mock_rows(&item, len),
+            )
+            .unwrap()
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn test_answer_too_few_rows() {
+        let item = new_group_form_item(Some(2), None);
+        assert_eq!(
+            item.check_answer(
+                &test_model::mock_respondent_project(),
+                &HashMap::new(),
+                &mock_rows(&item, 1)
+            )
+            .unwrap()
+            .unwrap_err()
+            .kind(),
+            CheckAnswerErrorKind::TooFewRows
+        );
+    }
+
+    #[test]
+    fn test_answer_too_many_rows() {
+        let item = new_group_form_item(None, Some(2));
+        assert_eq!(
+            item.check_answer(
+                &test_model::mock_respondent_project(),
+                &HashMap::new(),
+                &mock_rows(&item, 3)
+            )
+            .unwrap()
+            .unwrap_err()
+            .kind(),
+            CheckAnswerErrorKind::TooManyRows
+        );
+    }
+
+    #[test]
+    fn test_answer_mismatched_row_length() {
+        let item = new_group_form_item(None, None);
+        let child_item = item.items().next().unwrap();
+        let rows = FormAnswerItemGroupRows::from_rows(vec![FormAnswerItems::from_items(vec![
+            test_model::mock_form_answer_item(child_item),
+            test_model::mock_form_answer_item(child_item),
+        ])
+        .unwrap()])
+        .unwrap();
+        assert_eq!(
+            item.check_answer(
+                &test_model::mock_respondent_project(),
+                &HashMap::new(),
+                &rows
+            )
+            .unwrap()
+            .unwrap_err()
+            .kind(),
+            CheckAnswerErrorKind::MismatchedRowLength { row: 0 }
+        );
+    }
+}
@@ -0,0 +1,25 @@
+use crate::model::integer::BoundedInteger;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct GroupFormItemLimit(BoundedInteger<typenum::U0, typenum::U64, u64>);
+
+#[derive(Debug, Error, Clone)]
+#[error("invalid group form item limit")]
+pub struct LimitError {
+    _priv: (),
+}
+
+impl GroupFormItemLimit {
+    pub fn from_u64(limit: u64) -> Result<Self, LimitError> {
+        let inner = BoundedInteger::new(limit).map_err(|_| LimitError { _priv: () })?;
+        Ok(GroupFormItemLimit(inner))
+    }
+
+    pub fn to_u64(self) -> u64 {
+        self.0.into_inner()
+    }
+}
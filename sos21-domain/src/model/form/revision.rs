@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use super::{
+    item::FormItemId, Form, FormCondition, FormDescription, FormId, FormItems, FormName, FormPeriod,
+};
+use crate::model::date_time::DateTime;
+
+use super::FormVersion;
+
+/// An immutable snapshot of a form, which is recorded for every version of the form.
+#[derive(Debug, Clone)]
+pub struct FormRevision {
+    pub form_id: FormId,
+    pub version: FormVersion,
+    pub created_at: DateTime,
+    pub name: FormName,
+    pub description: FormDescription,
+    pub period: FormPeriod,
+    pub items: FormItems,
+    /// The condition of the projects targeted by the version,
+    /// which is `None` for the revisions recorded before the conditions were recorded.
+    pub condition: Option<FormCondition>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FormRevisionDiff {
+    pub name_changed: bool,
+    pub description_changed: bool,
+    pub period_changed: bool,
+    pub added_item_ids: Vec<FormItemId>,
+    pub removed_item_ids: Vec<FormItemId>,
+    pub modified_item_ids: Vec<FormItemId>,
+}
+
+impl FormRevision {
+    pub fn of_form(form: &Form) -> Self {
+        FormRevision {
+            form_id: form.id(),
+            version: form.version(),
+            created_at: DateTime::now(),
+            name: form.name().clone(),
+            description: form.description().clone(),
+            period: form.period(),
+            items: form.items().clone(),
+            condition: Some(form.condition().clone()),
+        }
+    }
+
+    /// Computes the difference from this revision to `to`.
+    ///
+    /// Items are compared by their serialized representation,
+    /// which is the same as the one used to persist them.
+    pub fn diff(&self, to: &FormRevision) -> Result<FormRevisionDiff, anyhow::Error> {
+        let old_items = self
+            .items
+            .items()
+            .map(|item| Ok((item.id, serde_json::to_value(item)?)))
+            .collect::<Result<HashMap<_, _>, anyhow::Error>>()?;
+
+        let mut diff = FormRevisionDiff {
+            name_changed: self.name != to.name,
+            description_changed: self.description != to.description,
+            period_changed: self.period.starts_at() != to.period.starts_at()
+                || self.period.ends_at() != to.period.ends_at(),
+            ..FormRevisionDiff::default()
+        };
+
+        for item in to.items.items() {
+            match old_items.get(&item.id) {
+                None => diff.added_item_ids.push(item.id),
+                Some(old_item) if old_item != &serde_json::to_value(item)? => {
+                    diff.modified_item_ids.push(item.id)
+                }
+                Some(_) => {}
+            }
+        }
+
+        diff.removed_item_ids = self
+            .items
+            .items()
+            .map(|item| item.id)
+            .filter(|id| to.items.items().all(|item| item.id != *id))
+            .collect();
+
+        Ok(diff)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FormRevision, FormRevisionDiff};
+    use crate::model::form::{FormItems, FormName};
+    use crate::test::model as test_model;
+
+    #[test]
+    fn test_diff() {
+        let operator = test_model::new_operator_user();
+        let kept_item = test_model::new_form_item();
+        let removed_item = test_model::new_form_item();
+        let modified_item = test_model::new_form_item();
+        let form = test_model::new_form_with_items(
+            operator.id().clone(),
+            FormItems::from_items(vec![
+                kept_item.clone(),
+                removed_item.clone(),
+                modified_item.clone(),
+            ])
+            .unwrap(),
+        );
+        let from = FormRevision::of_form(&form);
+
+        let added_item = test_model::new_form_item();
+        let mut new_modified_item = modified_item.clone();
+        new_modified_item.body = test_model::new_radio_form_item_body();
+        let to = FormRevision {
+            name: FormName::from_string("新しい申請").unwrap(),
+            items: FormItems::from_items(vec![kept_item, new_modified_item, added_item.clone()])
+                .unwrap(),
+            ..from.clone()
+        };
+
+        assert_eq!(
+            from.diff(&to).unwrap(),
+            FormRevisionDiff {
+                name_changed: true,
+                description_changed: false,
+                period_changed: false,
+                added_item_ids: vec![added_item.id],
+                removed_item_ids: vec![removed_item.id],
+                modified_item_ids: vec![modified_item.id],
+            }
+        );
+        assert_eq!(from.diff(&from).unwrap(), FormRevisionDiff::default());
+    }
+}
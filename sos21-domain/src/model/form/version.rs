@@ -0,0 +1,71 @@
+use std::convert::TryInto;
+
+use crate::model::bound::{Bounded, Unbounded};
+use crate::model::integer::{self, LimitedInteger};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// A version number of the form, which starts from 1 and is incremented on every edit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct FormVersion(LimitedInteger<Bounded<typenum::U1>, Unbounded, u32>);
+
+#[derive(Debug, Error, Clone)]
+#[error("invalid form version")]
+pub struct VersionError {
+    _priv: (),
+}
+
+impl VersionError {
+    fn from_integer_error(_err: integer::BoundError) -> Self {
+        VersionError { _priv: () }
+    }
+}
+
+impl FormVersion {
+    pub fn initial() -> Self {
+        FormVersion::from_u32(1).unwrap()
+    }
+
+    pub fn from_u32(version: u32) -> Result<Self, VersionError> {
+        let version = LimitedInteger::new(version).map_err(VersionError::from_integer_error)?;
+        Ok(FormVersion(version))
+    }
+
+    pub fn from_i32(version: i32) -> Result<Self, VersionError> {
+        let version = version.try_into().map_err(|_| VersionError { _priv: () })?;
+        FormVersion::from_u32(version)
+    }
+
+    pub fn to_u32(&self) -> u32 {
+        self.0.into_inner()
+    }
+
+    pub fn to_i32(&self) -> i32 {
+        // versions are incremented one by one from 1, so it never exceeds i32::MAX in practice
+        self.0.into_inner().try_into().unwrap()
+    }
+
+    pub fn next(&self) -> Self {
+        FormVersion::from_u32(self.to_u32() + 1).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FormVersion;
+
+    #[test]
+    fn test_initial() {
+        assert_eq!(FormVersion::initial().to_u32(), 1);
+        assert!(FormVersion::from_u32(0).is_err());
+    }
+
+    #[test]
+    fn test_next() {
+        let version = FormVersion::initial();
+        assert!(version < version.next());
+        assert_eq!(version.next().to_u32(), 2);
+    }
+}
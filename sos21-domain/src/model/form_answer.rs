@@ -1,6 +1,7 @@
 use crate::context::FormAnswerRepository;
+use crate::model::access_control::AccessControlLevel;
 use crate::model::date_time::DateTime;
-use crate::model::form::{self, Form, FormId};
+use crate::model::form::{self, item::RespondentProject, Form, FormId, FormVersion};
 use crate::model::permissions::Permissions;
 use crate::model::project::{Project, ProjectId};
 use crate::model::user::{self, User, UserId};
@@ -10,8 +11,12 @@ use anyhow::Context;
 use thiserror::Error;
 use uuid::Uuid;
 
+pub mod draft;
 pub mod item;
+pub mod review;
+pub use draft::FormAnswerDraft;
 pub use item::{FormAnswerItem, FormAnswerItems};
+pub use review::{FormAnswerReview, FormAnswerReviewComments, FormAnswerReviewStatus};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct FormAnswerId(Uuid);
@@ -31,9 +36,12 @@ pub struct FormAnswerContent {
     pub id: FormAnswerId,
     pub project_id: ProjectId,
     pub form_id: FormId,
+    pub form_version: FormVersion,
     pub created_at: DateTime,
     pub author_id: UserId,
     pub items: FormAnswerItems,
+    pub needs_resubmission: bool,
+    pub review: FormAnswerReview,
 }
 
 #[derive(Debug, Clone)]
@@ -41,7 +49,7 @@ pub struct FormAnswer {
     content: FormAnswerContent,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum NewFormAnswerErrorKind {
     AlreadyAnswered,
     MismatchedItemsLength,
@@ -63,7 +71,7 @@ pub struct NewFormAnswerError {
 
 impl NewFormAnswerError {
     pub fn kind(&self) -> NewFormAnswerErrorKind {
-        self.kind
+        self.kind.clone()
     }
 
     fn from_check_error(err: form::item::CheckAnswerError) -> Self {
@@ -105,7 +113,7 @@ impl FormAnswer {
         }
 
         form.items()
-            .check_answer(&items)
+            .check_answer(&RespondentProject::from_project(project), &items)
             .context("Failed to check form answers unexpectedly")?
             .map_err(|err| DomainError::Domain(NewFormAnswerError::from_check_error(err)))?;
 
@@ -115,7 +123,10 @@ impl FormAnswer {
             author_id: author.id().clone(),
             project_id: project.id(),
             form_id: form.id(),
+            form_version: form.version(),
             items,
+            needs_resubmission: false,
+            review: FormAnswerReview::submitted(),
         }))
     }
 
@@ -145,6 +156,11 @@ impl FormAnswer {
         self.content.form_id
     }
 
+    /// The version of the form which this answer is made for.
+    pub fn form_version(&self) -> FormVersion {
+        self.content.form_version
+    }
+
     pub fn created_at(&self) -> DateTime {
         self.content.created_at
     }
@@ -161,21 +177,50 @@ impl FormAnswer {
         self.content.items
     }
 
+    /// Whether this answer has been migrated to a newer version of the form
+    /// and some of the answers need to be submitted again.
+    pub fn needs_resubmission(&self) -> bool {
+        self.content.needs_resubmission
+    }
+
+    pub fn review(&self) -> &FormAnswerReview {
+        &self.content.review
+    }
+
+    pub fn review_status(&self) -> FormAnswerReviewStatus {
+        self.content.review.status
+    }
+
+    /// Whether the answer is reopened by the committee and can be edited by the project
+    /// even out of the answer period.
+    pub fn is_reopened(&self) -> bool {
+        self.review_status() == FormAnswerReviewStatus::NeedsChanges
+    }
+
+    /// Whether the permissions of the user allow to read the answer, regardless of the access
+    /// control list of the form. Use [`FormAnswer::is_visible_to_with_form`] to check the access.
     pub fn is_visible_to(&self, user: &User) -> bool {
         user.permissions()
             .contains(Permissions::READ_ALL_FORM_ANSWERS)
     }
 
+    /// Checks the visibility to the members of the project.
     pub fn is_visible_to_with_project(&self, user: &User, project: &Project) -> bool {
-        if self.is_visible_to(user) {
-            return true;
-        }
+        self.project_id() == project.id() && project.is_member(user)
+    }
 
-        self.project_id() == project.id() && project.is_visible_to(user)
+    /// Checks the visibility including the accesses granted or narrowed by the form.
+    pub fn is_visible_to_with_form(&self, user: &User, form: &Form) -> bool {
+        self.form_id() == form.id()
+            && form.access_control().allows_with_permission(
+                user,
+                AccessControlLevel::Read,
+                Permissions::READ_ALL_FORM_ANSWERS,
+            )
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SetItemsErrorKind {
     InsufficientPermissions,
     MismatchedItemsLength,
@@ -197,7 +242,7 @@ pub struct SetItemsError {
 
 impl SetItemsError {
     pub fn kind(&self) -> SetItemsErrorKind {
-        self.kind
+        self.kind.clone()
     }
 
     fn from_check_error(err: form::item::CheckAnswerError) -> Self {
@@ -225,6 +270,8 @@ impl SetItemsError {
 
 impl FormAnswer {
     // TODO: Fetch form and project in set_items
+    /// Updating the items always brings the answer back to [`FormAnswerReviewStatus::Submitted`],
+    /// so that the answer is reviewed again.
     pub fn set_items(
         &mut self,
         user: &User,
@@ -236,28 +283,166 @@ impl FormAnswer {
         domain_ensure!(project.id() == self.project_id());
 
         let now = DateTime::now();
-        let permission = if form.period().contains(now) && project.is_member(user) {
-            Permissions::UPDATE_FORM_ANSWERS_IN_PERIOD
-        } else {
-            Permissions::UPDATE_ALL_FORM_ANSWERS
-        };
+        let permission =
+            if (form.period().contains(now) || self.is_reopened()) && project.is_member(user) {
+                Permissions::UPDATE_FORM_ANSWERS_IN_PERIOD
+            } else {
+                Permissions::UPDATE_ALL_FORM_ANSWERS
+            };
 
-        user.require_permissions(permission)
-            .map_err(|err| DomainError::Domain(SetItemsError::from_permissions_error(err)))?;
+        if !form
+            .access_control()
+            .allows(user, AccessControlLevel::Update)
+        {
+            user.require_permissions(permission)
+                .map_err(|err| DomainError::Domain(SetItemsError::from_permissions_error(err)))?;
+        }
 
         form.items()
-            .check_answer(&items)
+            .check_answer(&RespondentProject::from_project(project), &items)
             .context("Failed to check form answers unexpectedly")?
             .map_err(|err| DomainError::Domain(SetItemsError::from_check_error(err)))?;
 
         self.content.items = items;
+        self.content.form_version = form.version();
+        self.content.needs_resubmission = false;
+        self.content.review.status = FormAnswerReviewStatus::Submitted;
         Ok(())
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewErrorKind {
+    InsufficientPermissions,
+    InvalidStatus,
+    UnknownCommentItemId(form::item::FormItemId),
+}
+
+#[derive(Debug, Clone, Error)]
+#[error("failed to review form answer")]
+pub struct ReviewError {
+    kind: ReviewErrorKind,
+}
+
+impl ReviewError {
+    pub fn kind(&self) -> ReviewErrorKind {
+        self.kind
+    }
+}
+
+impl FormAnswer {
+    /// Records the review by the committee, replacing the previous status and comments.
+    ///
+    /// [`FormAnswerReviewStatus::Submitted`] cannot be set here because it is only reached by
+    /// (re)submission from the project.
+    pub fn review_by(
+        &mut self,
+        user: &User,
+        form: &Form,
+        status: FormAnswerReviewStatus,
+        comments: FormAnswerReviewComments,
+    ) -> DomainResult<(), ReviewError> {
+        domain_ensure!(form.id() == self.form_id());
+
+        if !form.access_control().allows_with_permission(
+            user,
+            AccessControlLevel::Review,
+            Permissions::REVIEW_FORM_ANSWERS,
+        ) {
+            return Err(DomainError::Domain(ReviewError {
+                kind: ReviewErrorKind::InsufficientPermissions,
+            }));
+        }
+
+        if status == FormAnswerReviewStatus::Submitted {
+            return Err(DomainError::Domain(ReviewError {
+                kind: ReviewErrorKind::InvalidStatus,
+            }));
+        }
+
+        for comment in comments.comments() {
+            if !form.items().items().any(|item| item.id == comment.item_id) {
+                return Err(DomainError::Domain(ReviewError {
+                    kind: ReviewErrorKind::UnknownCommentItemId(comment.item_id),
+                }));
+            }
+        }
+
+        self.content.review = FormAnswerReview {
+            status,
+            comments,
+            reviewer_id: Some(user.id().clone()),
+            reviewed_at: Some(DateTime::now()),
+        };
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrateErrorKind {
+    InsufficientPermissions,
+}
+
+#[derive(Debug, Clone, Error)]
+#[error("failed to migrate form answer")]
+pub struct MigrateError {
+    kind: MigrateErrorKind,
+}
+
+impl MigrateError {
+    pub fn kind(&self) -> MigrateErrorKind {
+        self.kind
+    }
+
+    fn from_permissions_error(_err: user::RequirePermissionsError) -> Self {
+        MigrateError {
+            kind: MigrateErrorKind::InsufficientPermissions,
+        }
+    }
+}
+
+impl FormAnswer {
+    /// Migrates the answer to the current version of the form.
+    ///
+    /// This returns IDs of the items whose answers must be re-submitted.
+    /// The answer is flagged by [`FormAnswer::needs_resubmission`] when there are such items.
+    pub fn migrate(
+        &mut self,
+        user: &User,
+        form: &Form,
+        project: &Project,
+    ) -> DomainResult<Vec<form::item::FormItemId>, MigrateError> {
+        domain_ensure!(form.id() == self.form_id());
+        domain_ensure!(project.id() == self.project_id());
+
+        user.require_permissions(Permissions::UPDATE_ALL_FORM_ANSWERS)
+            .map_err(|err| DomainError::Domain(MigrateError::from_permissions_error(err)))?;
+
+        if self.form_version() == form.version() {
+            return Ok(Vec::new());
+        }
+
+        let (items, flagged_item_ids) = form
+            .items()
+            .migrate_answer(&RespondentProject::from_project(project), self.items())
+            .context("Failed to migrate form answers unexpectedly")?;
+
+        self.content.items = items;
+        self.content.form_version = form.version();
+        self.content.needs_resubmission = !flagged_item_ids.is_empty();
+        Ok(flagged_item_ids)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::SetItemsErrorKind;
+    use super::{
+        FormAnswerReviewComments, FormAnswerReviewStatus, MigrateErrorKind, ReviewErrorKind,
+        SetItemsErrorKind,
+    };
+    use crate::model::access_control::AccessControlLevel;
+    use crate::model::form::FormItems;
+    use crate::model::form_answer::review::{FormAnswerReviewComment, FormAnswerReviewCommentBody};
 
     use crate::test::model as test_model;
     use crate::DomainError;
@@ -386,4 +571,263 @@ mod tests {
             if err.kind() == SetItemsErrorKind::InsufficientPermissions
         ));
     }
+
+    #[test]
+    fn test_migrate() {
+        let user = test_model::new_general_user();
+        let user_project = test_model::new_general_project(user.id().clone());
+        let admin = test_model::new_admin_user();
+        let kept_item = test_model::new_form_item();
+        let retyped_item = test_model::new_form_item();
+        let form = test_model::new_form_with_items(
+            admin.id().clone(),
+            FormItems::from_items(vec![kept_item.clone(), retyped_item.clone()]).unwrap(),
+        );
+        let mut form_answer = test_model::new_form_answer(user.id().clone(), &user_project, &form);
+
+        let mut new_retyped_item = retyped_item.clone();
+        new_retyped_item.body = test_model::new_radio_form_item_body();
+        let added_item = test_model::new_form_item();
+        let mut new_form = form.clone();
+        new_form
+            .set_items(
+                &admin,
+                FormItems::from_items(vec![
+                    kept_item.clone(),
+                    new_retyped_item,
+                    added_item.clone(),
+                ])
+                .unwrap(),
+            )
+            .unwrap();
+
+        let flagged_item_ids = form_answer
+            .migrate(&admin, &new_form, &user_project)
+            .unwrap();
+        assert_eq!(flagged_item_ids, vec![retyped_item.id, added_item.id]);
+        assert!(form_answer.needs_resubmission());
+        assert_eq!(form_answer.form_version(), new_form.version());
+        let answer_items: Vec<_> = form_answer.items().items().collect();
+        assert_eq!(answer_items.len(), 3);
+        assert!(answer_items[0].body.is_some());
+        assert!(answer_items[1].body.is_none());
+        assert!(answer_items[2].body.is_none());
+    }
+
+    #[test]
+    fn test_migrate_general() {
+        let user = test_model::new_general_user();
+        let user_project = test_model::new_general_project(user.id().clone());
+        let admin = test_model::new_admin_user();
+        let form = test_model::new_form(admin.id().clone());
+        let mut form_answer = test_model::new_form_answer(user.id().clone(), &user_project, &form);
+        assert!(matches!(
+            form_answer.migrate(&user, &form, &user_project),
+            Err(DomainError::Domain(err))
+            if err.kind() == MigrateErrorKind::InsufficientPermissions
+        ));
+    }
+
+    fn new_review_comments(
+        item_id: crate::model::form::item::FormItemId,
+    ) -> FormAnswerReviewComments {
+        FormAnswerReviewComments::from_comments(vec![FormAnswerReviewComment {
+            item_id,
+            body: FormAnswerReviewCommentBody::from_string("please fix this").unwrap(),
+        }])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_review_committee() {
+        let user = test_model::new_general_user();
+        let user_project = test_model::new_general_project(user.id().clone());
+        let committee = test_model::new_committee_user();
+        let form = test_model::new_form(committee.id().clone());
+        let mut form_answer = test_model::new_form_answer(user.id().clone(), &user_project, &form);
+        assert_eq!(
+            form_answer.review_status(),
+            FormAnswerReviewStatus::Submitted
+        );
+
+        let item_id = form.items().items().next().unwrap().id;
+        form_answer
+            .review_by(
+                &committee,
+                &form,
+                FormAnswerReviewStatus::Approved,
+                new_review_comments(item_id),
+            )
+            .unwrap();
+        assert_eq!(
+            form_answer.review_status(),
+            FormAnswerReviewStatus::Approved
+        );
+        assert_eq!(
+            form_answer.review().reviewer_id.as_ref(),
+            Some(committee.id())
+        );
+        assert_eq!(form_answer.review().comments.len(), 1);
+    }
+
+    #[test]
+    fn test_review_general() {
+        let user = test_model::new_general_user();
+        let user_project = test_model::new_general_project(user.id().clone());
+        let operator = test_model::new_operator_user();
+        let form = test_model::new_form(operator.id().clone());
+        let mut form_answer = test_model::new_form_answer(user.id().clone(), &user_project, &form);
+        assert!(matches!(
+            form_answer.review_by(
+                &user,
+                &form,
+                FormAnswerReviewStatus::Approved,
+                FormAnswerReviewComments::empty(),
+            ),
+            Err(DomainError::Domain(err))
+            if err.kind() == ReviewErrorKind::InsufficientPermissions
+        ));
+    }
+
+    #[test]
+    fn test_visibility_general_via_access_control() {
+        let admin = test_model::new_admin_user();
+        let user = test_model::new_general_user();
+        let other_form = test_model::new_form(admin.id().clone());
+        let mut form = test_model::new_form(admin.id().clone());
+        form.set_access_control(
+            &admin,
+            test_model::new_user_access_control(user.id().clone(), AccessControlLevel::Read),
+        )
+        .unwrap();
+
+        let other = test_model::new_general_user();
+        let other_project = test_model::new_general_project(other.id().clone());
+        let form_answer = test_model::new_form_answer(other.id().clone(), &other_project, &form);
+        assert!(!form_answer.is_visible_to(&user));
+        assert!(form_answer.is_visible_to_with_form(&user, &form));
+        assert!(!form_answer.is_visible_to_with_form(&user, &other_form));
+    }
+
+    #[test]
+    fn test_visibility_committee_narrowed_by_access_control() {
+        let admin = test_model::new_admin_user();
+        let user = test_model::new_general_user();
+        let committee = test_model::new_committee_user();
+        let mut form = test_model::new_form(admin.id().clone());
+        let other = test_model::new_general_user();
+        let other_project = test_model::new_general_project(other.id().clone());
+        let form_answer = test_model::new_form_answer(other.id().clone(), &other_project, &form);
+        assert!(form_answer.is_visible_to_with_form(&committee, &form));
+
+        form.set_access_control(
+            &admin,
+            test_model::new_user_access_control(user.id().clone(), AccessControlLevel::Read),
+        )
+        .unwrap();
+        assert!(form_answer.is_visible_to(&committee));
+        assert!(!form_answer.is_visible_to_with_form(&committee, &form));
+        assert!(!form_answer.is_visible_to_with_project(&committee, &other_project));
+        assert!(form_answer.is_visible_to_with_form(&user, &form));
+        assert!(form_answer.is_visible_to_with_form(&admin, &form));
+    }
+
+    #[test]
+    fn test_review_general_via_access_control() {
+        let admin = test_model::new_admin_user();
+        let user = test_model::new_general_user();
+        let mut form = test_model::new_form(admin.id().clone());
+        form.set_access_control(
+            &admin,
+            test_model::new_user_access_control(user.id().clone(), AccessControlLevel::Read),
+        )
+        .unwrap();
+
+        let other = test_model::new_general_user();
+        let other_project = test_model::new_general_project(other.id().clone());
+        let mut form_answer =
+            test_model::new_form_answer(other.id().clone(), &other_project, &form);
+        assert!(matches!(
+            form_answer.review_by(
+                &user,
+                &form,
+                FormAnswerReviewStatus::Approved,
+                FormAnswerReviewComments::empty(),
+            ),
+            Err(DomainError::Domain(err))
+            if err.kind() == ReviewErrorKind::InsufficientPermissions
+        ));
+
+        form.set_access_control(
+            &admin,
+            test_model::new_user_access_control(user.id().clone(), AccessControlLevel::Review),
+        )
+        .unwrap();
+        form_answer
+            .review_by(
+                &user,
+                &form,
+                FormAnswerReviewStatus::Approved,
+                FormAnswerReviewComments::empty(),
+            )
+            .unwrap();
+        assert_eq!(
+            form_answer.review_status(),
+            FormAnswerReviewStatus::Approved
+        );
+    }
+
+    #[test]
+    fn test_review_unknown_item() {
+        let user = test_model::new_general_user();
+        let user_project = test_model::new_general_project(user.id().clone());
+        let committee = test_model::new_committee_user();
+        let form = test_model::new_form(committee.id().clone());
+        let mut form_answer = test_model::new_form_answer(user.id().clone(), &user_project, &form);
+
+        let item_id = test_model::new_form_item().id;
+        assert!(matches!(
+            form_answer.review_by(
+                &committee,
+                &form,
+                FormAnswerReviewStatus::NeedsChanges,
+                new_review_comments(item_id),
+            ),
+            Err(DomainError::Domain(err))
+            if err.kind() == ReviewErrorKind::UnknownCommentItemId(item_id)
+        ));
+    }
+
+    #[test]
+    fn test_set_items_general_reopened_out_of_period() {
+        let user = test_model::new_general_user();
+        let user_project = test_model::new_general_project(user.id().clone());
+        let committee = test_model::new_committee_user();
+        let period = test_model::new_form_period_to_now();
+        let form = test_model::new_form_with_period(committee.id().clone(), period);
+        let mut form_answer = test_model::new_form_answer(user.id().clone(), &user_project, &form);
+
+        form_answer
+            .review_by(
+                &committee,
+                &form,
+                FormAnswerReviewStatus::NeedsChanges,
+                FormAnswerReviewComments::empty(),
+            )
+            .unwrap();
+        assert!(form_answer.is_reopened());
+
+        form_answer
+            .set_items(
+                &user,
+                &form,
+                &user_project,
+                test_model::mock_form_answer_items(form.items()),
+            )
+            .unwrap();
+        assert_eq!(
+            form_answer.review_status(),
+            FormAnswerReviewStatus::Submitted
+        );
+    }
 }
@@ -0,0 +1,340 @@
+use crate::context::{ConfigContext, FormAnswerRepository};
+use crate::model::date_time::DateTime;
+use crate::model::file::FileId;
+use crate::model::file_sharing::{
+    FileSharing, FileSharingContent, FileSharingId, FileSharingScope,
+};
+use crate::model::form::{self, Form, FormId};
+use crate::model::form_answer::{FormAnswer, FormAnswerItems};
+use crate::model::project::{Project, ProjectId};
+use crate::model::user::{User, UserId};
+use crate::{DomainError, DomainResult};
+
+use thiserror::Error;
+
+/// A file attached to a draft, which is shared only when the draft is submitted
+/// so that saving a draft repeatedly does not leave file sharings behind.
+///
+/// The draft items refer to the file with `sharing_id`, which becomes the ID of the sharing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DraftFileSharing {
+    pub sharing_id: FileSharingId,
+    pub file_id: FileId,
+}
+
+impl DraftFileSharing {
+    /// Defers storing `sharing`, which is created by [`crate::model::file::File::share_by`]
+    /// to check that the file can be shared.
+    pub fn from_sharing(sharing: &FileSharing) -> Self {
+        DraftFileSharing {
+            sharing_id: sharing.id(),
+            file_id: sharing.file_id(),
+        }
+    }
+
+    pub(crate) fn into_sharing(self, scope: FileSharingScope) -> FileSharing {
+        FileSharing::from_content(FileSharingContent {
+            id: self.sharing_id,
+            created_at: DateTime::now(),
+            file_id: self.file_id,
+            is_revoked: false,
+            expires_at: None,
+            scope,
+        })
+    }
+}
+
+/// An incomplete answer to a form, which is saved without being checked against the form items.
+///
+/// There is at most one draft for each pair of a form and a project.
+#[derive(Debug, Clone)]
+pub struct FormAnswerDraftContent {
+    pub form_id: FormId,
+    pub project_id: ProjectId,
+    pub author_id: UserId,
+    pub updated_at: DateTime,
+    pub items: FormAnswerItems,
+    pub file_sharings: Vec<DraftFileSharing>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FormAnswerDraft {
+    content: FormAnswerDraftContent,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveErrorKind {
+    OutOfAnswerPeriod,
+    NotTargeted,
+    AlreadyAnswered,
+}
+
+#[derive(Debug, Error, Clone)]
+#[error("failed to save form answer draft")]
+pub struct SaveError {
+    kind: SaveErrorKind,
+}
+
+impl SaveError {
+    pub fn kind(&self) -> SaveErrorKind {
+        self.kind
+    }
+}
+
+impl FormAnswerDraft {
+    /// Save `items` as a draft answer to `form` from `project`.
+    ///
+    /// Unlike [`Form::answer_by`], `items` is not checked against the form items here.
+    /// The check is deferred until the draft is submitted with [`FormAnswerDraft::submit`].
+    pub async fn save<C>(
+        ctx: C,
+        author: &User,
+        project: &Project,
+        form: &Form,
+        items: FormAnswerItems,
+        file_sharings: Vec<DraftFileSharing>,
+    ) -> DomainResult<Self, SaveError>
+    where
+        C: FormAnswerRepository,
+    {
+        if !form.can_be_answered_by(author, project) {
+            return Err(DomainError::Domain(SaveError {
+                kind: SaveErrorKind::NotTargeted,
+            }));
+        }
+
+        let updated_at = DateTime::now();
+        if !form.period().contains(updated_at) {
+            return Err(DomainError::Domain(SaveError {
+                kind: SaveErrorKind::OutOfAnswerPeriod,
+            }));
+        }
+
+        if ctx
+            .get_form_answer_by_form_and_project(form.id(), project.id())
+            .await?
+            .is_some()
+        {
+            return Err(DomainError::Domain(SaveError {
+                kind: SaveErrorKind::AlreadyAnswered,
+            }));
+        }
+
+        Ok(FormAnswerDraft::from_content(FormAnswerDraftContent {
+            form_id: form.id(),
+            project_id: project.id(),
+            author_id: author.id().clone(),
+            updated_at,
+            items,
+            file_sharings,
+        }))
+    }
+
+    /// Restore `FormAnswerDraft` from `FormAnswerDraftContent`.
+    ///
+    /// This is intended to be used when the data is taken out of the implementation by [`FormAnswerDraft::into_content`]
+    /// for persistence, internal serialization, etc.
+    /// Use [`FormAnswerDraft::save`] to create a form answer draft.
+    pub fn from_content(content: FormAnswerDraftContent) -> Self {
+        FormAnswerDraft { content }
+    }
+
+    /// Convert `FormAnswerDraft` into `FormAnswerDraftContent`.
+    pub fn into_content(self) -> FormAnswerDraftContent {
+        self.content
+    }
+
+    pub fn form_id(&self) -> FormId {
+        self.content.form_id
+    }
+
+    pub fn project_id(&self) -> ProjectId {
+        self.content.project_id
+    }
+
+    pub fn author_id(&self) -> &UserId {
+        &self.content.author_id
+    }
+
+    pub fn updated_at(&self) -> DateTime {
+        self.content.updated_at
+    }
+
+    pub fn items(&self) -> &FormAnswerItems {
+        &self.content.items
+    }
+
+    pub fn into_items(self) -> FormAnswerItems {
+        self.content.items
+    }
+
+    pub fn file_sharings(&self) -> &[DraftFileSharing] {
+        &self.content.file_sharings
+    }
+
+    /// Drafts are only visible to the members of the project, even to the committee members.
+    pub fn is_visible_to_with_project(&self, user: &User, project: &Project) -> bool {
+        self.project_id() == project.id() && project.is_member(user)
+    }
+
+    /// Check the drafted items against the form items and promote them into a `FormAnswer`,
+    /// along with the sharings of the files attached to the draft.
+    pub async fn submit<C>(
+        self,
+        ctx: C,
+        user: &User,
+        project: &Project,
+        form: &Form,
+    ) -> DomainResult<(FormAnswer, Vec<FileSharing>), form::AnswerError>
+    where
+        C: FormAnswerRepository + ConfigContext,
+    {
+        domain_ensure!(form.id() == self.form_id());
+        domain_ensure!(project.id() == self.project_id());
+
+        let FormAnswerDraftContent {
+            items,
+            file_sharings,
+            ..
+        } = self.content;
+        let answer = form.answer_by(ctx, user, project, items).await?;
+        let file_sharings = file_sharings
+            .into_iter()
+            .map(|sharing| {
+                sharing.into_sharing(FileSharingScope::FormAnswer(project.id(), form.id()))
+            })
+            .collect();
+        Ok((answer, file_sharings))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DraftFileSharing, FormAnswerDraft, SaveErrorKind};
+    use crate::model::file_sharing::FileSharingScope;
+    use crate::model::form;
+    use crate::model::form_answer::{item::FormAnswerItem, FormAnswerItems};
+    use crate::test::model as test_model;
+    use crate::DomainError;
+
+    fn partial_items(form: &form::Form) -> FormAnswerItems {
+        let item = form.items().items().next().unwrap();
+        FormAnswerItems::from_items(vec![FormAnswerItem {
+            item_id: item.id,
+            body: None,
+        }])
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_save_and_submit() {
+        let operator = test_model::new_operator_user();
+        let user = test_model::new_general_user();
+        let project = test_model::new_general_project(user.id().clone());
+        let form = test_model::new_form(operator.id().clone());
+
+        let app = crate::test::build_mock_app()
+            .users(vec![user.clone(), operator.clone()])
+            .projects(vec![project.clone()])
+            .forms(vec![form.clone()])
+            .build();
+
+        let draft =
+            FormAnswerDraft::save(&app, &user, &project, &form, partial_items(&form), vec![])
+                .await
+                .unwrap();
+        assert!(draft.is_visible_to_with_project(&user, &project));
+        assert!(!draft.is_visible_to_with_project(&operator, &project));
+
+        let items = test_model::mock_form_answer_items(form.items());
+        let draft = FormAnswerDraft::save(&app, &user, &project, &form, items, vec![])
+            .await
+            .unwrap();
+        let (answer, _) = draft.submit(&app, &user, &project, &form).await.unwrap();
+        assert_eq!(answer.form_id(), form.id());
+        assert_eq!(answer.project_id(), project.id());
+    }
+
+    #[tokio::test]
+    async fn test_save_out_of_period() {
+        let operator = test_model::new_operator_user();
+        let user = test_model::new_general_user();
+        let project = test_model::new_general_project(user.id().clone());
+        let form = test_model::new_form_with_period(
+            operator.id().clone(),
+            test_model::new_form_period_with_hours_from_now(1),
+        );
+
+        let app = crate::test::build_mock_app()
+            .users(vec![user.clone(), operator.clone()])
+            .projects(vec![project.clone()])
+            .forms(vec![form.clone()])
+            .build();
+
+        assert!(matches!(
+            FormAnswerDraft::save(&app, &user, &project, &form, partial_items(&form), vec![]).await,
+            Err(DomainError::Domain(err)) if err.kind() == SaveErrorKind::OutOfAnswerPeriod
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_submit_incomplete() {
+        let operator = test_model::new_operator_user();
+        let user = test_model::new_general_user();
+        let project = test_model::new_general_project(user.id().clone());
+        let form = test_model::new_form(operator.id().clone());
+
+        let app = crate::test::build_mock_app()
+            .users(vec![user.clone(), operator.clone()])
+            .projects(vec![project.clone()])
+            .forms(vec![form.clone()])
+            .build();
+
+        let draft =
+            FormAnswerDraft::save(&app, &user, &project, &form, partial_items(&form), vec![])
+                .await
+                .unwrap();
+        assert!(matches!(
+            draft.submit(&app, &user, &project, &form).await,
+            Err(DomainError::Domain(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_submit_file_sharings() {
+        let operator = test_model::new_operator_user();
+        let user = test_model::new_general_user();
+        let project = test_model::new_general_project(user.id().clone());
+        let form = test_model::new_form(operator.id().clone());
+        let (file, _) = test_model::new_file(user.id().clone());
+
+        let app = crate::test::build_mock_app()
+            .users(vec![user.clone(), operator.clone()])
+            .projects(vec![project.clone()])
+            .forms(vec![form.clone()])
+            .build();
+
+        let sharing = file
+            .share_by(&user, FileSharingScope::FormAnswer(project.id(), form.id()))
+            .unwrap();
+        let items = test_model::mock_form_answer_items(form.items());
+        let draft = FormAnswerDraft::save(
+            &app,
+            &user,
+            &project,
+            &form,
+            items,
+            vec![DraftFileSharing::from_sharing(&sharing)],
+        )
+        .await
+        .unwrap();
+
+        let (_, sharings) = draft.submit(&app, &user, &project, &form).await.unwrap();
+        assert_eq!(sharings.len(), 1);
+        assert_eq!(sharings[0].id(), sharing.id());
+        assert_eq!(sharings[0].file_id(), file.id);
+        assert!(sharings[0]
+            .scope()
+            .contains_project_form_answer(&project, &form));
+    }
+}
@@ -8,10 +8,12 @@ use thiserror::Error;
 pub mod checks;
 pub mod file_sharings;
 pub mod grid_rows;
+pub mod group_rows;
 pub mod text;
 pub use checks::FormAnswerItemChecks;
 pub use file_sharings::{FileSharingAnswer, FormAnswerItemFileSharings};
 pub use grid_rows::{FormAnswerItemGridRows, GridRadioRowAnswer};
+pub use group_rows::FormAnswerItemGroupRows;
 pub use text::FormAnswerItemText;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -85,4 +87,5 @@ pub enum FormAnswerItemBody {
     Radio(Option<RadioId>),
     GridRadio(FormAnswerItemGridRows),
     File(FormAnswerItemFileSharings),
+    Group(FormAnswerItemGroupRows),
 }
@@ -0,0 +1,78 @@
+use crate::model::bound::{Bounded, Unbounded};
+use crate::model::collection::{self, LengthLimitedVec};
+use crate::model::form_answer::item::FormAnswerItems;
+
+use serde::{
+    de::{self, Deserializer},
+    Deserialize, Serialize,
+};
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(transparent)]
+pub struct FormAnswerItemGroupRows(
+    LengthLimitedVec<Unbounded, Bounded<typenum::U64>, FormAnswerItems>,
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FromRowsErrorKind {
+    TooLong,
+}
+
+#[derive(Debug, Error, Clone)]
+#[error("invalid form answer item group rows")]
+pub struct FromRowsError {
+    kind: FromRowsErrorKind,
+}
+
+impl FromRowsError {
+    pub fn kind(&self) -> FromRowsErrorKind {
+        self.kind
+    }
+
+    fn from_length_error(e: collection::LengthError<Unbounded, Bounded<typenum::U64>>) -> Self {
+        let kind = match e.kind() {
+            collection::LengthErrorKind::TooLong => FromRowsErrorKind::TooLong,
+            // TODO: statically assert unreachability
+            collection::LengthErrorKind::TooShort => unreachable!(),
+        };
+        FromRowsError { kind }
+    }
+}
+
+impl FormAnswerItemGroupRows {
+    pub fn from_rows<I>(rows: I) -> Result<Self, FromRowsError>
+    where
+        I: IntoIterator<Item = FormAnswerItems>,
+    {
+        let rows = LengthLimitedVec::new(rows.into_iter().collect())
+            .map_err(FromRowsError::from_length_error)?;
+        Ok(FormAnswerItemGroupRows(rows))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'_ FormAnswerItems> {
+        self.0.iter()
+    }
+
+    pub fn into_rows(self) -> impl Iterator<Item = FormAnswerItems> {
+        self.0.into_inner().into_iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.len() == 0
+    }
+}
+
+impl<'de> Deserialize<'de> for FormAnswerItemGroupRows {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        FormAnswerItemGroupRows::from_rows(Vec::<FormAnswerItems>::deserialize(deserializer)?)
+            .map_err(de::Error::custom)
+    }
+}
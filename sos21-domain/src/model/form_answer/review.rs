@@ -0,0 +1,194 @@
+use std::collections::HashSet;
+
+use crate::model::bound::{Bounded, Unbounded};
+use crate::model::collection::{self, LengthLimitedVec};
+use crate::model::date_time::DateTime;
+use crate::model::form::item::FormItemId;
+use crate::model::string::LengthBoundedString;
+use crate::model::user::UserId;
+
+use serde::{
+    de::{self, Deserializer},
+    Deserialize, Serialize,
+};
+use thiserror::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormAnswerReviewStatus {
+    /// The answer has been submitted (or resubmitted) and has not been reviewed yet.
+    Submitted,
+    UnderReview,
+    /// The answer is reopened to the project for editing, regardless of the answer period.
+    NeedsChanges,
+    Approved,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct FormAnswerReviewCommentBody(LengthBoundedString<typenum::U1, typenum::U1024, String>);
+
+#[derive(Debug, Error, Clone)]
+#[error("invalid form answer review comment")]
+pub struct CommentBodyError {
+    _priv: (),
+}
+
+impl FormAnswerReviewCommentBody {
+    pub fn from_string(body: impl Into<String>) -> Result<Self, CommentBodyError> {
+        let inner =
+            LengthBoundedString::new(body.into()).map_err(|_| CommentBodyError { _priv: () })?;
+        Ok(FormAnswerReviewCommentBody(inner))
+    }
+
+    pub fn into_string(self) -> String {
+        self.0.into_inner()
+    }
+}
+
+/// A comment from the committee on the answer to a specific item.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FormAnswerReviewComment {
+    pub item_id: FormItemId,
+    pub body: FormAnswerReviewCommentBody,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(transparent)]
+pub struct FormAnswerReviewComments(
+    LengthLimitedVec<Unbounded, Bounded<typenum::U64>, FormAnswerReviewComment>,
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FromCommentsErrorKind {
+    TooLong,
+    DuplicatedItemId(FormItemId),
+}
+
+#[derive(Debug, Error, Clone)]
+#[error("invalid form answer review comment list")]
+pub struct FromCommentsError {
+    kind: FromCommentsErrorKind,
+}
+
+impl FromCommentsError {
+    pub fn kind(&self) -> FromCommentsErrorKind {
+        self.kind
+    }
+
+    fn from_length_error(e: collection::LengthError<Unbounded, Bounded<typenum::U64>>) -> Self {
+        let kind = match e.kind() {
+            collection::LengthErrorKind::TooLong => FromCommentsErrorKind::TooLong,
+            // TODO: statically assert unreachability
+            collection::LengthErrorKind::TooShort => unreachable!(),
+        };
+        FromCommentsError { kind }
+    }
+}
+
+impl FormAnswerReviewComments {
+    /// At most one comment can be made on each item.
+    pub fn from_comments<I>(comments: I) -> Result<Self, FromCommentsError>
+    where
+        I: IntoIterator<Item = FormAnswerReviewComment>,
+    {
+        let comments: Vec<_> = comments.into_iter().collect();
+
+        let mut known_item_ids = HashSet::new();
+        for comment in &comments {
+            if !known_item_ids.insert(comment.item_id) {
+                return Err(FromCommentsError {
+                    kind: FromCommentsErrorKind::DuplicatedItemId(comment.item_id),
+                });
+            }
+        }
+
+        let comments =
+            LengthLimitedVec::new(comments).map_err(FromCommentsError::from_length_error)?;
+        Ok(FormAnswerReviewComments(comments))
+    }
+
+    pub fn empty() -> Self {
+        FormAnswerReviewComments::from_comments(std::iter::empty()).unwrap()
+    }
+
+    pub fn comments(&self) -> impl Iterator<Item = &'_ FormAnswerReviewComment> {
+        self.0.iter()
+    }
+
+    pub fn into_comments(self) -> impl Iterator<Item = FormAnswerReviewComment> {
+        self.0.into_inner().into_iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.len() == 0
+    }
+}
+
+impl<'de> Deserialize<'de> for FormAnswerReviewComments {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        FormAnswerReviewComments::from_comments(Vec::<FormAnswerReviewComment>::deserialize(
+            deserializer,
+        )?)
+        .map_err(de::Error::custom)
+    }
+}
+
+/// The result of the review on a form answer by the committee.
+#[derive(Debug, Clone)]
+pub struct FormAnswerReview {
+    pub status: FormAnswerReviewStatus,
+    pub comments: FormAnswerReviewComments,
+    /// `None` when the answer has never been reviewed.
+    pub reviewer_id: Option<UserId>,
+    pub reviewed_at: Option<DateTime>,
+}
+
+impl FormAnswerReview {
+    /// The review state of a newly submitted answer.
+    pub fn submitted() -> Self {
+        FormAnswerReview {
+            status: FormAnswerReviewStatus::Submitted,
+            comments: FormAnswerReviewComments::empty(),
+            reviewer_id: None,
+            reviewed_at: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        FormAnswerReviewComment, FormAnswerReviewCommentBody, FormAnswerReviewComments,
+        FromCommentsErrorKind,
+    };
+    use crate::model::form::item::FormItemId;
+
+    fn new_comment(item_id: FormItemId) -> FormAnswerReviewComment {
+        FormAnswerReviewComment {
+            item_id,
+            body: FormAnswerReviewCommentBody::from_string("please fix this").unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_comment_body() {
+        assert!(FormAnswerReviewCommentBody::from_string("").is_err());
+        assert!(FormAnswerReviewCommentBody::from_string("a".repeat(1025)).is_err());
+    }
+
+    #[test]
+    fn test_duplicated_comments() {
+        let item_id = FormItemId::from_uuid(uuid::Uuid::new_v4());
+        assert!(matches!(
+            FormAnswerReviewComments::from_comments(vec![new_comment(item_id), new_comment(item_id)]),
+            Err(err) if err.kind() == FromCommentsErrorKind::DuplicatedItemId(item_id)
+        ));
+    }
+}
@@ -0,0 +1,157 @@
+use crate::model::date_time::DateTime;
+use crate::model::form::{FormDescription, FormItems, FormName};
+use crate::model::permissions::Permissions;
+use crate::model::project_query::ProjectQuery;
+use crate::model::user::{self, User, UserId};
+
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FormTemplateId(Uuid);
+
+impl FormTemplateId {
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        FormTemplateId(uuid)
+    }
+
+    pub fn to_uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+/// A saved set of form contents, from which forms and registration forms are created.
+///
+/// Templates do not belong to any festival, so that they can be used over the festivals.
+#[derive(Debug, Clone)]
+pub struct FormTemplateContent {
+    pub id: FormTemplateId,
+    pub created_at: DateTime,
+    pub author_id: UserId,
+    pub name: FormName,
+    pub description: FormDescription,
+    pub items: FormItems,
+    pub query: ProjectQuery,
+}
+
+#[derive(Debug, Clone)]
+pub struct FormTemplate {
+    content: FormTemplateContent,
+}
+
+#[derive(Debug, Clone, Error)]
+#[error("insufficient permissions to create form templates")]
+pub struct NewFormTemplateError {
+    _priv: (),
+}
+
+impl NewFormTemplateError {
+    fn from_permissions_error(_err: user::RequirePermissionsError) -> Self {
+        NewFormTemplateError { _priv: () }
+    }
+}
+
+impl FormTemplate {
+    pub fn new(
+        author: &User,
+        name: FormName,
+        description: FormDescription,
+        items: FormItems,
+        query: ProjectQuery,
+    ) -> Result<Self, NewFormTemplateError> {
+        author
+            .require_permissions(Permissions::CREATE_FORMS)
+            .map_err(NewFormTemplateError::from_permissions_error)?;
+
+        Ok(FormTemplate::from_content(FormTemplateContent {
+            id: FormTemplateId::from_uuid(Uuid::new_v4()),
+            created_at: DateTime::now(),
+            author_id: author.id().clone(),
+            name,
+            description,
+            items,
+            query,
+        }))
+    }
+
+    /// Restore `FormTemplate` from `FormTemplateContent`.
+    ///
+    /// This is intended to be used when the data is taken out of the implementation
+    /// by [`FormTemplate::into_content`] for persistence, internal serialization, etc.
+    /// Use [`FormTemplate::new`] to create a form template.
+    pub fn from_content(content: FormTemplateContent) -> Self {
+        FormTemplate { content }
+    }
+
+    /// Convert `FormTemplate` into `FormTemplateContent`.
+    pub fn into_content(self) -> FormTemplateContent {
+        self.content
+    }
+
+    pub fn id(&self) -> FormTemplateId {
+        self.content.id
+    }
+
+    pub fn created_at(&self) -> DateTime {
+        self.content.created_at
+    }
+
+    pub fn author_id(&self) -> &UserId {
+        &self.content.author_id
+    }
+
+    pub fn name(&self) -> &FormName {
+        &self.content.name
+    }
+
+    pub fn description(&self) -> &FormDescription {
+        &self.content.description
+    }
+
+    pub fn items(&self) -> &FormItems {
+        &self.content.items
+    }
+
+    pub fn query(&self) -> &ProjectQuery {
+        &self.content.query
+    }
+
+    pub fn is_visible_to(&self, user: &User) -> bool {
+        user.permissions().contains(Permissions::READ_ALL_FORMS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FormTemplate;
+    use crate::test::model as test_model;
+
+    #[test]
+    fn test_new_committee() {
+        let user = test_model::new_committee_user();
+        assert!(FormTemplate::new(
+            &user,
+            test_model::mock_form_name(),
+            test_model::mock_form_description(),
+            test_model::new_form_items(),
+            test_model::mock_project_query(),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_new_operator() {
+        let user = test_model::new_operator_user();
+        let template = FormTemplate::new(
+            &user,
+            test_model::mock_form_name(),
+            test_model::mock_form_description(),
+            test_model::new_form_items(),
+            test_model::mock_project_query(),
+        )
+        .unwrap();
+        assert_eq!(template.author_id(), user.id());
+        assert!(template.is_visible_to(&test_model::new_committee_user()));
+        assert!(!template.is_visible_to(&test_model::new_general_user()));
+    }
+}
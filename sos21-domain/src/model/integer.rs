@@ -13,7 +13,7 @@ use crate::model::bound::{Bound, Bounded};
 ///
 /// This provides a wrapper to validate that the value of integer is
 /// between `Lower` and `Upper` bounds.
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct LimitedInteger<Lower, Upper, T> {
     _lower: PhantomData<Lower>,
     _upper: PhantomData<Upper>,
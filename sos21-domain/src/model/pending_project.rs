@@ -1,5 +1,6 @@
 use crate::context::ConfigContext;
 use crate::model::date_time::DateTime;
+use crate::model::festival::FestivalId;
 use crate::model::permissions::Permissions;
 use crate::model::project::{
     ProjectAttribute, ProjectAttributes, ProjectCategory, ProjectDescription, ProjectGroupName,
@@ -26,6 +27,7 @@ impl PendingProjectId {
 #[derive(Debug, Clone)]
 pub struct PendingProjectContent {
     pub id: PendingProjectId,
+    pub festival_id: FestivalId,
     pub created_at: DateTime,
     pub updated_at: DateTime,
     pub name: ProjectName,
@@ -117,6 +119,7 @@ impl PendingProject {
         Ok(PendingProject::from_content(
             PendingProjectContent {
                 id: PendingProjectId::from_uuid(Uuid::new_v4()),
+                festival_id: ctx.active_festival_id(),
                 created_at,
                 updated_at: created_at,
                 name,
@@ -136,6 +139,10 @@ impl PendingProject {
         self.content.id
     }
 
+    pub fn festival_id(&self) -> FestivalId {
+        self.content.festival_id
+    }
+
     pub fn created_at(&self) -> DateTime {
         self.content.created_at
     }
@@ -221,7 +228,12 @@ impl PendingProject {
     where
         C: ConfigContext,
     {
-        let permission = if &self.owner_id == user.id()
+        // pending projects in the past festivals are read-only
+        if self.festival_id() != ctx.active_festival_id() {
+            return Err(NoUpdatePermissionError { _priv: () });
+        }
+
+        let permission = if user.is_identified_by(&self.owner_id)
             && ctx
                 .project_creation_period_for(self.category())
                 .contains(now)
@@ -343,23 +355,69 @@ impl PendingProject {
         Ok(())
     }
 
-    pub fn set_exceptional_complete_deadline(
+    pub fn set_exceptional_complete_deadline<C>(
         &mut self,
+        ctx: C,
         user: &User,
         exceptional_complete_deadline: Option<DateTime>,
-    ) -> Result<(), NoUpdatePermissionError> {
-        user.require_permissions(Permissions::UPDATE_ALL_PENDING_PROJECTS)
-            .map_err(NoUpdatePermissionError::from_permissions_error)?;
+    ) -> Result<(), SetExceptionalCompleteDeadlineError>
+    where
+        C: ConfigContext,
+    {
+        user.require_permissions(Permissions::UPDATE_PENDING_PROJECT_DEADLINES)
+            .map_err(SetExceptionalCompleteDeadlineError::from_permissions_error)?;
+
+        // an exceptional deadline within the creation period has no effect
+        if let (Some(deadline), Some((_, ends_at))) = (
+            exceptional_complete_deadline,
+            ctx.project_creation_period_for(self.category()).range(),
+        ) {
+            if deadline <= ends_at {
+                return Err(SetExceptionalCompleteDeadlineError {
+                    kind: SetExceptionalCompleteDeadlineErrorKind::NotAfterCreationPeriod,
+                });
+            }
+        }
+
         self.content.exceptional_complete_deadline = exceptional_complete_deadline;
+        self.content.updated_at = DateTime::now();
         Ok(())
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetExceptionalCompleteDeadlineErrorKind {
+    InsufficientPermissions,
+    NotAfterCreationPeriod,
+}
+
+#[derive(Debug, Clone, Error)]
+#[error("failed to set an exceptional complete deadline to the pending project")]
+pub struct SetExceptionalCompleteDeadlineError {
+    kind: SetExceptionalCompleteDeadlineErrorKind,
+}
+
+impl SetExceptionalCompleteDeadlineError {
+    pub fn kind(&self) -> SetExceptionalCompleteDeadlineErrorKind {
+        self.kind
+    }
+
+    fn from_permissions_error(_err: user::RequirePermissionsError) -> Self {
+        SetExceptionalCompleteDeadlineError {
+            kind: SetExceptionalCompleteDeadlineErrorKind::InsufficientPermissions,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{NewPendingProjectErrorKind, PendingProject};
+    use super::{
+        NewPendingProjectErrorKind, PendingProject, SetExceptionalCompleteDeadlineErrorKind,
+    };
 
+    use crate::model::date_time::DateTime;
     use crate::model::project::{ProjectAttributes, ProjectCategory};
+    use crate::model::project_creation_period::ProjectCreationPeriod;
     use crate::test::model as test_model;
 
     #[tokio::test]
@@ -441,6 +499,63 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_set_exceptional_complete_deadline_general() {
+        let owner = test_model::new_general_user();
+        let mut pending_project = test_model::new_general_pending_project(owner.id().clone());
+        let app = crate::test::build_mock_app().build();
+
+        assert_eq!(
+            pending_project
+                .set_exceptional_complete_deadline(&app, &owner, Some(DateTime::now()))
+                .unwrap_err()
+                .kind(),
+            SetExceptionalCompleteDeadlineErrorKind::InsufficientPermissions
+        );
+    }
+
+    #[test]
+    fn test_set_exceptional_complete_deadline_operator() {
+        let owner = test_model::new_general_user();
+        let mut pending_project = test_model::new_general_pending_project(owner.id().clone());
+        let ends_at = chrono::Utc::now() + chrono::Duration::days(1);
+        let period = ProjectCreationPeriod::from_datetime(
+            DateTime::from_utc(ends_at - chrono::Duration::days(7)),
+            DateTime::from_utc(ends_at),
+        )
+        .unwrap();
+        let app = crate::test::build_mock_app()
+            .project_creation_period_for(ProjectCategory::General, period)
+            .build();
+
+        let operator = test_model::new_operator_user();
+        assert_eq!(
+            pending_project
+                .set_exceptional_complete_deadline(
+                    &app,
+                    &operator,
+                    Some(DateTime::from_utc(ends_at - chrono::Duration::hours(1)))
+                )
+                .unwrap_err()
+                .kind(),
+            SetExceptionalCompleteDeadlineErrorKind::NotAfterCreationPeriod
+        );
+
+        let deadline = DateTime::from_utc(ends_at + chrono::Duration::days(1));
+        pending_project
+            .set_exceptional_complete_deadline(&app, &operator, Some(deadline))
+            .unwrap();
+        assert_eq!(
+            *pending_project.exceptional_complete_deadline(),
+            Some(deadline)
+        );
+
+        pending_project
+            .set_exceptional_complete_deadline(&app, &operator, None)
+            .unwrap();
+        assert_eq!(*pending_project.exceptional_complete_deadline(), None);
+    }
+
     // TODO: test new out of period
     // TODO: test set_* permissions and period
 }
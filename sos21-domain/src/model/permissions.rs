@@ -23,6 +23,10 @@ macro_rules! define_permissions {
                     $flag_vis const $flag_name: $ty_name =
                         $ty_name(make_bitflags!([<$ty_name Flags>]::{[<$flag_name:lower:camel>]}));
                 )*
+
+                const NAMED_FLAGS: &'static [(&'static str, $ty_name)] = &[
+                    $((stringify!($flag_name), $ty_name::$flag_name)),*
+                ];
             }
         }
     }
@@ -30,7 +34,7 @@ macro_rules! define_permissions {
 
 define_permissions! {
     #[derive(Copy, Clone, Debug)]
-    pub enum Permissions: u32 {
+    pub enum Permissions: u64 {
         pub READ_ALL_USERS,
         pub READ_ALL_PROJECTS,
         pub UPDATE_ALL_USERS,
@@ -59,6 +63,17 @@ define_permissions! {
         pub UPDATE_REGISTRATION_FORM_ANSWERS_IN_PERIOD,
         pub UPDATE_PROJECT_CATEGORY,
         pub UPDATE_PENDING_PROJECT_CATEGORY,
+        pub UPDATE_PENDING_PROJECT_DEADLINES,
+        pub REVIEW_FORM_ANSWERS,
+        pub READ_SETTINGS,
+        pub UPDATE_SETTINGS,
+        pub CREATE_FESTIVALS,
+        pub READ_CUSTOM_ROLES,
+        pub UPDATE_CUSTOM_ROLES,
+        pub UPDATE_FORM_ACCESS_CONTROLS,
+        pub READ_API_KEYS,
+        pub UPDATE_API_KEYS,
+        pub SUSPEND_USERS,
     }
 }
 
@@ -74,6 +89,30 @@ impl Permissions {
     pub fn union(self, other: Permissions) -> Self {
         Permissions(self.0 | other.0)
     }
+
+    pub fn intersection(self, other: Permissions) -> Self {
+        Permissions(self.0 & other.0)
+    }
+
+    pub fn empty() -> Self {
+        Permissions(BitFlags::empty())
+    }
+
+    /// Returns the permission named after the constant, such as `"READ_ALL_USERS"`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Permissions::NAMED_FLAGS
+            .iter()
+            .find(|(flag_name, _)| *flag_name == name)
+            .map(|(_, flag)| *flag)
+    }
+
+    /// Returns the names of the permissions contained in `self`.
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        Permissions::NAMED_FLAGS
+            .iter()
+            .filter(move |(_, flag)| self.contains(*flag))
+            .map(|(name, _)| *name)
+    }
 }
 
 impl BitOr for Permissions {
@@ -82,3 +121,30 @@ impl BitOr for Permissions {
         self.union(other)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Permissions;
+
+    #[test]
+    fn test_names() {
+        let permissions = Permissions::READ_ALL_USERS | Permissions::CREATE_FORMS;
+        let names: Vec<_> = permissions.names().collect();
+        assert_eq!(names, vec!["READ_ALL_USERS", "CREATE_FORMS"]);
+
+        let restored = names
+            .into_iter()
+            .map(|name| Permissions::from_name(name).unwrap())
+            .fold(Permissions::empty(), Permissions::union);
+        assert!(restored.contains(permissions) && permissions.contains(restored));
+        assert!(Permissions::from_name("UNKNOWN").is_none());
+    }
+
+    #[test]
+    fn test_names_all() {
+        assert_eq!(
+            Permissions::all().names().count(),
+            Permissions::NAMED_FLAGS.len()
+        );
+    }
+}
@@ -4,6 +4,7 @@ use crate::context::{
     ConfigContext, ProjectRepository, RegistrationFormAnswerRepository, RegistrationFormRepository,
 };
 use crate::model::date_time::DateTime;
+use crate::model::festival::FestivalId;
 use crate::model::pending_project::PendingProject;
 use crate::model::permissions::Permissions;
 use crate::model::user::{self, User, UserAssignment, UserId};
@@ -44,6 +45,7 @@ impl ProjectId {
 #[derive(Debug, Clone)]
 pub struct ProjectContent {
     pub id: ProjectId,
+    pub festival_id: FestivalId,
     pub index: ProjectIndex,
     pub created_at: DateTime,
     pub updated_at: DateTime,
@@ -133,6 +135,33 @@ impl NewProjectError {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetSubownerErrorKind {
+    InsufficientPermissions,
+    SameOwnerSubowner,
+    AlreadyProjectOwnerSubowner,
+    AlreadyProjectSubownerSubowner,
+    AlreadyPendingProjectOwnerSubowner,
+}
+
+#[derive(Debug, Clone, Error)]
+#[error("failed to set the subowner of the project")]
+pub struct SetSubownerError {
+    kind: SetSubownerErrorKind,
+}
+
+impl SetSubownerError {
+    pub fn kind(&self) -> SetSubownerErrorKind {
+        self.kind
+    }
+
+    fn from_permissions_error(_err: user::RequirePermissionsError) -> Self {
+        SetSubownerError {
+            kind: SetSubownerErrorKind::InsufficientPermissions,
+        }
+    }
+}
+
 impl Project {
     pub async fn new<C>(
         ctx: C,
@@ -146,13 +175,15 @@ impl Project {
             + ConfigContext,
     {
         let created_at = DateTime::now();
-        if !(ctx
-            .project_creation_period_for(pending_project.category())
-            .contains(created_at)
-            || created_at
-                < pending_project
-                    .exceptional_complete_deadline()
-                    .unwrap_or(created_at))
+        // pending projects left in the past festivals are out of their creation periods
+        if pending_project.festival_id() != ctx.active_festival_id()
+            || !(ctx
+                .project_creation_period_for(pending_project.category())
+                .contains(created_at)
+                || created_at
+                    < pending_project
+                        .exceptional_complete_deadline()
+                        .unwrap_or(created_at))
         {
             return Err(DomainError::Domain(NewProjectError {
                 kind: NewProjectErrorKind::OutOfCreationPeriod,
@@ -173,7 +204,10 @@ impl Project {
             }));
         }
 
-        let index = ctx.get_next_index().await.context("Failed to get index")?;
+        let index = ctx
+            .get_next_index(pending_project.festival_id())
+            .await
+            .context("Failed to get index")?;
         let index = match index.try_into() {
             Ok(count) => count,
             Err(err) => {
@@ -203,6 +237,7 @@ impl Project {
         Project::from_content(
             ProjectContent {
                 id: ProjectId::from_uuid(Uuid::new_v4()),
+                festival_id: pending_project.festival_id(),
                 created_at,
                 updated_at: created_at,
                 index,
@@ -260,6 +295,10 @@ impl Project {
         self.content.id
     }
 
+    pub fn festival_id(&self) -> FestivalId {
+        self.content.festival_id
+    }
+
     pub fn index(&self) -> ProjectIndex {
         self.content.index
     }
@@ -317,7 +356,7 @@ impl Project {
     }
 
     pub fn is_member(&self, user: &User) -> bool {
-        &self.owner_id == user.id() || &self.subowner_id == user.id()
+        user.is_identified_by(&self.owner_id) || user.is_identified_by(&self.subowner_id)
     }
     pub fn kind(&self) -> ProjectKind {
         self.category().into()
@@ -352,6 +391,11 @@ impl Project {
     where
         C: ConfigContext,
     {
+        // projects in the past festivals are read-only
+        if self.festival_id() != ctx.active_festival_id() {
+            return Err(NoUpdatePermissionError { _priv: () });
+        }
+
         let permission = if self.is_member(user)
             && ctx
                 .project_creation_period_for(self.category())
@@ -458,6 +502,39 @@ impl Project {
         Ok(())
     }
 
+    /// Replace the subowner with `subowner`, who must not be assigned to any project.
+    ///
+    /// The assignments of the previous and the new subowner are left to the caller.
+    pub fn set_subowner(&mut self, user: &User, subowner: &User) -> Result<(), SetSubownerError> {
+        user.require_permissions(Permissions::UPDATE_ALL_PROJECTS)
+            .map_err(SetSubownerError::from_permissions_error)?;
+
+        if &self.owner_id == subowner.id() {
+            return Err(SetSubownerError {
+                kind: SetSubownerErrorKind::SameOwnerSubowner,
+            });
+        }
+
+        if let Some(assignment) = subowner.assignment() {
+            let kind = match assignment {
+                UserAssignment::ProjectOwner(_) => {
+                    SetSubownerErrorKind::AlreadyProjectOwnerSubowner
+                }
+                UserAssignment::ProjectSubowner(_) => {
+                    SetSubownerErrorKind::AlreadyProjectSubownerSubowner
+                }
+                UserAssignment::PendingProjectOwner(_) => {
+                    SetSubownerErrorKind::AlreadyPendingProjectOwnerSubowner
+                }
+            };
+            return Err(SetSubownerError { kind });
+        }
+
+        self.subowner_id = subowner.id().clone();
+        self.content.updated_at = DateTime::now();
+        Ok(())
+    }
+
     pub fn set_attributes<C>(
         &mut self,
         ctx: C,
@@ -477,7 +554,7 @@ impl Project {
 
 #[cfg(test)]
 mod tests {
-    use super::{NewProjectErrorKind, Project};
+    use super::{NewProjectErrorKind, Project, SetSubownerErrorKind};
 
     use crate::test::model as test_model;
     use crate::DomainError;
@@ -541,6 +618,44 @@ mod tests {
         assert_eq!(project.subowner_id(), subowner.id());
     }
 
+    #[tokio::test]
+    async fn test_new_past_festival() {
+        let owner = test_model::new_general_user();
+        let subowner = test_model::new_general_user();
+        let pending_project = test_model::new_general_pending_project(owner.id().clone());
+
+        let festival = test_model::new_festival();
+        let app = crate::test::build_mock_app()
+            .festivals(vec![festival.clone()])
+            .active_festival(festival.id())
+            .users(vec![owner.clone(), subowner.clone()])
+            .pending_projects(vec![pending_project.clone()])
+            .build();
+
+        assert!(matches!(
+            Project::new(&app, pending_project, &subowner).await,
+            Err(DomainError::Domain(err))
+            if err.kind() == NewProjectErrorKind::OutOfCreationPeriod
+        ));
+    }
+
+    #[test]
+    fn test_update_past_festival_admin() {
+        let owner = test_model::new_general_user();
+        let mut project = test_model::new_general_project(owner.id().clone());
+
+        let festival = test_model::new_festival();
+        let app = crate::test::build_mock_app()
+            .festivals(vec![festival.clone()])
+            .active_festival(festival.id())
+            .build();
+
+        let admin = test_model::new_admin_user();
+        assert!(project
+            .set_name(&app, &admin, test_model::mock_project_name())
+            .is_err());
+    }
+
     #[tokio::test]
     async fn test_new_not_answered() {
         let owner = test_model::new_general_user();
@@ -651,4 +766,52 @@ mod tests {
 
     // TODO: test new out of period
     // TODO: test set_* permissions and period
+
+    #[test]
+    fn test_set_subowner() {
+        let admin = test_model::new_admin_user();
+        let owner = test_model::new_general_user();
+        let subowner = test_model::new_general_user();
+        let mut project = test_model::new_general_project_with_subowner(
+            owner.id().clone(),
+            subowner.id().clone(),
+        );
+
+        let new_subowner = test_model::new_general_user();
+        project.set_subowner(&admin, &new_subowner).unwrap();
+        assert_eq!(project.subowner_id(), new_subowner.id());
+    }
+
+    #[test]
+    fn test_set_subowner_invalid() {
+        let admin = test_model::new_admin_user();
+        let operator = test_model::new_operator_user();
+        let mut owner = test_model::new_general_user();
+        let mut project = test_model::new_general_project(owner.id().clone());
+        owner.assign_project_owner(&project).unwrap();
+
+        let new_subowner = test_model::new_general_user();
+        assert_eq!(
+            project
+                .set_subowner(&operator, &new_subowner)
+                .unwrap_err()
+                .kind(),
+            SetSubownerErrorKind::InsufficientPermissions
+        );
+        assert_eq!(
+            project.set_subowner(&admin, &owner).unwrap_err().kind(),
+            SetSubownerErrorKind::SameOwnerSubowner
+        );
+
+        let mut other_owner = test_model::new_general_user();
+        let other_project = test_model::new_general_project(other_owner.id().clone());
+        other_owner.assign_project_owner(&other_project).unwrap();
+        assert_eq!(
+            project
+                .set_subowner(&admin, &other_owner)
+                .unwrap_err()
+                .kind(),
+            SetSubownerErrorKind::AlreadyProjectOwnerSubowner
+        );
+    }
 }
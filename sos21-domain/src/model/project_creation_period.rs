@@ -46,6 +46,23 @@ impl ProjectCreationPeriod {
         }
     }
 
+    pub fn is_always(&self) -> bool {
+        matches!(self.inner, ProjectCreationPeriodInner::Always)
+    }
+
+    pub fn is_never(&self) -> bool {
+        matches!(self.inner, ProjectCreationPeriodInner::Never)
+    }
+
+    /// Returns the start and the end of the period, or `None` if the period is
+    /// [`ProjectCreationPeriod::always`] or [`ProjectCreationPeriod::never`].
+    pub fn range(&self) -> Option<(DateTime, DateTime)> {
+        match self.inner {
+            ProjectCreationPeriodInner::Range { starts_at, ends_at } => Some((starts_at, ends_at)),
+            ProjectCreationPeriodInner::Always | ProjectCreationPeriodInner::Never => None,
+        }
+    }
+
     pub fn is_after(&self, time: DateTime) -> bool {
         match self.inner {
             ProjectCreationPeriodInner::Always => false,
@@ -1,5 +1,7 @@
 use crate::context::{ConfigContext, RegistrationFormAnswerRepository};
+use crate::model::access_control::{AccessControlLevel, AccessControlList};
 use crate::model::date_time::DateTime;
+use crate::model::festival::FestivalId;
 use crate::model::form::item::{self, FormItemId, FormItems};
 use crate::model::form_answer::item::FormAnswerItems;
 use crate::model::pending_project::PendingProject;
@@ -7,7 +9,7 @@ use crate::model::permissions::Permissions;
 use crate::model::project::Project;
 use crate::model::project_query::ProjectQuery;
 use crate::model::registration_form_answer::{self, RegistrationFormAnswer};
-use crate::model::user::{User, UserId};
+use crate::model::user::{self, User, UserId};
 use crate::{DomainError, DomainResult};
 
 use thiserror::Error;
@@ -35,15 +37,17 @@ impl RegistrationFormId {
 #[derive(Debug, Clone)]
 pub struct RegistrationForm {
     pub id: RegistrationFormId,
+    pub festival_id: FestivalId,
     pub created_at: DateTime,
     pub author_id: UserId,
     pub name: RegistrationFormName,
     pub description: RegistrationFormDescription,
     pub items: FormItems,
     pub query: ProjectQuery,
+    pub access_control: AccessControlList,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub enum AnswerErrorKind {
     NotTargeted,
     AlreadyAnswered,
@@ -67,7 +71,7 @@ pub struct AnswerError {
 
 impl AnswerError {
     pub fn kind(&self) -> AnswerErrorKind {
-        self.kind
+        self.kind.clone()
     }
 
     fn from_new_registration_form_answer_error(
@@ -97,6 +101,18 @@ impl AnswerError {
     }
 }
 
+#[derive(Debug, Clone, Error)]
+#[error("insufficient permissions to update registration forms")]
+pub struct NoUpdatePermissionError {
+    _priv: (),
+}
+
+impl NoUpdatePermissionError {
+    fn from_permissions_error(_err: user::RequirePermissionsError) -> Self {
+        NoUpdatePermissionError { _priv: () }
+    }
+}
+
 impl RegistrationForm {
     pub async fn answer_by<C>(
         &self,
@@ -108,14 +124,22 @@ impl RegistrationForm {
     where
         C: RegistrationFormAnswerRepository + ConfigContext,
     {
-        domain_ensure!(user.id() == pending_project.owner_id());
+        domain_ensure!(user.is_identified_by(pending_project.owner_id()));
 
-        if !self.query.check_pending_project(pending_project) {
+        if !self.is_targeted_to_pending_project(pending_project) {
             return Err(DomainError::Domain(AnswerError {
                 kind: AnswerErrorKind::NotTargeted,
             }));
         }
 
+        // registration forms in the past festivals are closed
+        // along with the project creation periods of the festivals
+        if self.festival_id != ctx.active_festival_id() {
+            return Err(DomainError::Domain(AnswerError {
+                kind: AnswerErrorKind::OutOfProjectCreationPeriod,
+            }));
+        }
+
         RegistrationFormAnswer::new(ctx, user, pending_project, self, items)
             .await
             .map_err(|err| err.map_domain(AnswerError::from_new_registration_form_answer_error))
@@ -129,9 +153,22 @@ impl RegistrationForm {
         &self.items
     }
 
+    /// Replaces the accesses granted on the registration form and its answers.
+    pub fn set_access_control(
+        &mut self,
+        user: &User,
+        access_control: AccessControlList,
+    ) -> Result<(), NoUpdatePermissionError> {
+        user.require_permissions(Permissions::UPDATE_FORM_ACCESS_CONTROLS)
+            .map_err(NoUpdatePermissionError::from_permissions_error)?;
+        self.access_control = access_control;
+        Ok(())
+    }
+
     pub fn is_visible_to(&self, user: &User) -> bool {
         user.permissions()
             .contains(Permissions::READ_ALL_REGISTRATION_FORMS)
+            || self.access_control.allows(user, AccessControlLevel::Read)
     }
 
     pub fn is_visible_to_with_project(&self, user: &User, project: &Project) -> bool {
@@ -139,7 +176,7 @@ impl RegistrationForm {
             return true;
         }
 
-        self.query.check_project(project) && project.is_visible_to(user)
+        self.is_targeted_to_project(project) && project.is_visible_to(user)
     }
 
     pub fn is_visible_to_with_pending_project(
@@ -151,7 +188,17 @@ impl RegistrationForm {
             return true;
         }
 
-        self.query.check_pending_project(pending_project) && pending_project.owner_id() == user.id()
+        self.is_targeted_to_pending_project(pending_project)
+            && user.is_identified_by(pending_project.owner_id())
+    }
+
+    fn is_targeted_to_project(&self, project: &Project) -> bool {
+        self.festival_id == project.festival_id() && self.query.check_project(project)
+    }
+
+    fn is_targeted_to_pending_project(&self, pending_project: &PendingProject) -> bool {
+        self.festival_id == pending_project.festival_id()
+            && self.query.check_pending_project(pending_project)
     }
 }
 
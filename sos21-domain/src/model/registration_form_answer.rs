@@ -1,6 +1,7 @@
 use crate::context::{ConfigContext, RegistrationFormAnswerRepository};
+use crate::model::access_control::AccessControlLevel;
 use crate::model::date_time::DateTime;
-use crate::model::form;
+use crate::model::form::{self, item::RespondentProject};
 use crate::model::form_answer::FormAnswerItems;
 use crate::model::pending_project::PendingProject;
 use crate::model::permissions::Permissions;
@@ -13,7 +14,9 @@ use anyhow::Context;
 use thiserror::Error;
 use uuid::Uuid;
 
+pub mod draft;
 pub mod respondent;
+pub use draft::RegistrationFormAnswerDraft;
 pub use respondent::RegistrationFormAnswerRespondent;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -45,7 +48,7 @@ pub struct RegistrationFormAnswer {
     content: RegistrationFormAnswerContent,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum NewRegistrationFormAnswerErrorKind {
     AlreadyAnswered,
     OutOfProjectCreationPeriod,
@@ -68,7 +71,7 @@ pub struct NewRegistrationFormAnswerError {
 
 impl NewRegistrationFormAnswerError {
     pub fn kind(&self) -> NewRegistrationFormAnswerErrorKind {
-        self.kind
+        self.kind.clone()
     }
 
     fn from_check_error(err: form::item::CheckAnswerError) -> Self {
@@ -128,7 +131,10 @@ impl RegistrationFormAnswer {
 
         registration_form
             .items()
-            .check_answer(&items)
+            .check_answer(
+                &RespondentProject::from_pending_project(pending_project),
+                &items,
+            )
             .context("Failed to check registration form answers unexpectedly")?
             .map_err(|err| {
                 DomainError::Domain(NewRegistrationFormAnswerError::from_check_error(err))
@@ -193,30 +199,41 @@ impl RegistrationFormAnswer {
         self.content.items
     }
 
+    /// Whether the permissions of the user allow to read the answer, regardless of the access
+    /// control list of the registration form.
+    /// Use [`RegistrationFormAnswer::is_visible_to_with_registration_form`] to check the access.
     pub fn is_visible_to(&self, user: &User) -> bool {
         user.permissions()
             .contains(Permissions::READ_ALL_REGISTRATION_FORM_ANSWERS)
     }
 
+    /// Checks the visibility to the members of the project.
     pub fn is_visible_to_with_project(&self, user: &User, project: &Project) -> bool {
-        if self.is_visible_to(user) {
-            return true;
-        }
-
-        self.respondent().is_project(project) && project.is_visible_to(user)
+        self.respondent().is_project(project) && project.is_member(user)
     }
 
+    /// Checks the visibility to the owner of the pending project.
     pub fn is_visible_to_with_pending_project(
         &self,
         user: &User,
         pending_project: &PendingProject,
     ) -> bool {
-        if self.is_visible_to(user) {
-            return true;
-        }
-
         self.respondent().is_pending_project(pending_project)
-            && pending_project.owner_id() == user.id()
+            && user.is_identified_by(pending_project.owner_id())
+    }
+
+    /// Checks the visibility including the accesses granted or narrowed by the registration form.
+    pub fn is_visible_to_with_registration_form(
+        &self,
+        user: &User,
+        registration_form: &RegistrationForm,
+    ) -> bool {
+        self.registration_form_id() == registration_form.id()
+            && registration_form.access_control.allows_with_permission(
+                user,
+                AccessControlLevel::Read,
+                Permissions::READ_ALL_REGISTRATION_FORM_ANSWERS,
+            )
     }
 
     // TODO: restrict user
@@ -225,7 +242,7 @@ impl RegistrationFormAnswer {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SetItemsErrorKind {
     InsufficientPermissions,
     MismatchedItemsLength,
@@ -247,7 +264,7 @@ pub struct SetItemsError {
 
 impl SetItemsError {
     pub fn kind(&self) -> SetItemsErrorKind {
-        self.kind
+        self.kind.clone()
     }
 
     fn from_check_error(err: form::item::CheckAnswerError) -> Self {
@@ -294,19 +311,27 @@ impl RegistrationFormAnswer {
         let permission = if ctx
             .project_creation_period_for(pending_project.category())
             .contains(now)
-            && pending_project.owner_id() == user.id()
+            && user.is_identified_by(pending_project.owner_id())
         {
             Permissions::UPDATE_REGISTRATION_FORM_ANSWERS_IN_PERIOD
         } else {
             Permissions::UPDATE_ALL_FORM_ANSWERS
         };
 
-        user.require_permissions(permission)
-            .map_err(|err| DomainError::Domain(SetItemsError::from_permissions_error(err)))?;
+        if !registration_form
+            .access_control
+            .allows(user, AccessControlLevel::Update)
+        {
+            user.require_permissions(permission)
+                .map_err(|err| DomainError::Domain(SetItemsError::from_permissions_error(err)))?;
+        }
 
         registration_form
             .items()
-            .check_answer(&items)
+            .check_answer(
+                &RespondentProject::from_pending_project(pending_project),
+                &items,
+            )
             .context("Failed to check registration form answers unexpectedly")?
             .map_err(|err| DomainError::Domain(SetItemsError::from_check_error(err)))?;
 
@@ -340,12 +365,17 @@ impl RegistrationFormAnswer {
             Permissions::UPDATE_ALL_FORM_ANSWERS
         };
 
-        user.require_permissions(permission)
-            .map_err(|err| DomainError::Domain(SetItemsError::from_permissions_error(err)))?;
+        if !registration_form
+            .access_control
+            .allows(user, AccessControlLevel::Update)
+        {
+            user.require_permissions(permission)
+                .map_err(|err| DomainError::Domain(SetItemsError::from_permissions_error(err)))?;
+        }
 
         registration_form
             .items()
-            .check_answer(&items)
+            .check_answer(&RespondentProject::from_project(project), &items)
             .context("Failed to check registration form answers unexpectedly")?
             .map_err(|err| DomainError::Domain(SetItemsError::from_check_error(err)))?;
 
@@ -0,0 +1,281 @@
+use crate::context::{ConfigContext, RegistrationFormAnswerRepository};
+use crate::model::date_time::DateTime;
+use crate::model::file_sharing::{FileSharing, FileSharingScope};
+use crate::model::form_answer::{draft::DraftFileSharing, FormAnswerItems};
+use crate::model::pending_project::{PendingProject, PendingProjectId};
+use crate::model::registration_form::{self, RegistrationForm, RegistrationFormId};
+use crate::model::registration_form_answer::{
+    RegistrationFormAnswer, RegistrationFormAnswerRespondent,
+};
+use crate::model::user::{User, UserId};
+use crate::{DomainError, DomainResult};
+
+use thiserror::Error;
+
+/// An incomplete answer to a registration form, which is saved without being checked against the form items.
+///
+/// There is at most one draft for each pair of a registration form and a pending project.
+#[derive(Debug, Clone)]
+pub struct RegistrationFormAnswerDraftContent {
+    pub registration_form_id: RegistrationFormId,
+    pub pending_project_id: PendingProjectId,
+    pub author_id: UserId,
+    pub updated_at: DateTime,
+    pub items: FormAnswerItems,
+    pub file_sharings: Vec<DraftFileSharing>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RegistrationFormAnswerDraft {
+    content: RegistrationFormAnswerDraftContent,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveErrorKind {
+    NotTargeted,
+    AlreadyAnswered,
+    OutOfProjectCreationPeriod,
+}
+
+#[derive(Debug, Error, Clone)]
+#[error("failed to save registration form answer draft")]
+pub struct SaveError {
+    kind: SaveErrorKind,
+}
+
+impl SaveError {
+    pub fn kind(&self) -> SaveErrorKind {
+        self.kind
+    }
+}
+
+impl RegistrationFormAnswerDraft {
+    /// Save `items` as a draft answer to `registration_form` from `pending_project`.
+    ///
+    /// Unlike [`RegistrationForm::answer_by`], `items` is not checked against the form items here.
+    /// The check is deferred until the draft is submitted with [`RegistrationFormAnswerDraft::submit`].
+    pub async fn save<C>(
+        ctx: C,
+        author: &User,
+        pending_project: &PendingProject,
+        registration_form: &RegistrationForm,
+        items: FormAnswerItems,
+        file_sharings: Vec<DraftFileSharing>,
+    ) -> DomainResult<Self, SaveError>
+    where
+        C: RegistrationFormAnswerRepository + ConfigContext,
+    {
+        domain_ensure!(author.id() == pending_project.owner_id());
+
+        if !registration_form
+            .query
+            .check_pending_project(pending_project)
+        {
+            return Err(DomainError::Domain(SaveError {
+                kind: SaveErrorKind::NotTargeted,
+            }));
+        }
+
+        if ctx
+            .get_registration_form_answer_by_registration_form_and_pending_project(
+                registration_form.id(),
+                pending_project.id(),
+            )
+            .await?
+            .is_some()
+        {
+            return Err(DomainError::Domain(SaveError {
+                kind: SaveErrorKind::AlreadyAnswered,
+            }));
+        }
+
+        let updated_at = DateTime::now();
+        if !(ctx
+            .project_creation_period_for(pending_project.category())
+            .contains(updated_at)
+            || updated_at
+                < pending_project
+                    .exceptional_complete_deadline()
+                    .unwrap_or(updated_at))
+        {
+            return Err(DomainError::Domain(SaveError {
+                kind: SaveErrorKind::OutOfProjectCreationPeriod,
+            }));
+        }
+
+        Ok(RegistrationFormAnswerDraft::from_content(
+            RegistrationFormAnswerDraftContent {
+                registration_form_id: registration_form.id(),
+                pending_project_id: pending_project.id(),
+                author_id: author.id().clone(),
+                updated_at,
+                items,
+                file_sharings,
+            },
+        ))
+    }
+
+    /// Restore `RegistrationFormAnswerDraft` from `RegistrationFormAnswerDraftContent`.
+    ///
+    /// This is intended to be used when the data is taken out of the implementation
+    /// by [`RegistrationFormAnswerDraft::into_content`] for persistence, internal serialization, etc.
+    /// Use [`RegistrationFormAnswerDraft::save`] to create a registration form answer draft.
+    pub fn from_content(content: RegistrationFormAnswerDraftContent) -> Self {
+        RegistrationFormAnswerDraft { content }
+    }
+
+    /// Convert `RegistrationFormAnswerDraft` into `RegistrationFormAnswerDraftContent`.
+    pub fn into_content(self) -> RegistrationFormAnswerDraftContent {
+        self.content
+    }
+
+    pub fn registration_form_id(&self) -> RegistrationFormId {
+        self.content.registration_form_id
+    }
+
+    pub fn pending_project_id(&self) -> PendingProjectId {
+        self.content.pending_project_id
+    }
+
+    pub fn author_id(&self) -> &UserId {
+        &self.content.author_id
+    }
+
+    pub fn updated_at(&self) -> DateTime {
+        self.content.updated_at
+    }
+
+    pub fn items(&self) -> &FormAnswerItems {
+        &self.content.items
+    }
+
+    pub fn into_items(self) -> FormAnswerItems {
+        self.content.items
+    }
+
+    pub fn file_sharings(&self) -> &[DraftFileSharing] {
+        &self.content.file_sharings
+    }
+
+    /// Drafts are only visible to the owner of the pending project, even to the committee members.
+    pub fn is_visible_to_with_pending_project(
+        &self,
+        user: &User,
+        pending_project: &PendingProject,
+    ) -> bool {
+        self.pending_project_id() == pending_project.id()
+            && user.is_identified_by(pending_project.owner_id())
+    }
+
+    /// Check the drafted items against the form items and promote them into a `RegistrationFormAnswer`,
+    /// along with the sharings of the files attached to the draft.
+    pub async fn submit<C>(
+        self,
+        ctx: C,
+        user: &User,
+        pending_project: &PendingProject,
+        registration_form: &RegistrationForm,
+    ) -> DomainResult<(RegistrationFormAnswer, Vec<FileSharing>), registration_form::AnswerError>
+    where
+        C: RegistrationFormAnswerRepository + ConfigContext,
+    {
+        domain_ensure!(registration_form.id() == self.registration_form_id());
+        domain_ensure!(pending_project.id() == self.pending_project_id());
+
+        let RegistrationFormAnswerDraftContent {
+            items,
+            file_sharings,
+            ..
+        } = self.content;
+        let answer = registration_form
+            .answer_by(ctx, user, pending_project, items)
+            .await?;
+        let file_sharings = file_sharings
+            .into_iter()
+            .map(|sharing| {
+                sharing.into_sharing(FileSharingScope::RegistrationFormAnswer(
+                    RegistrationFormAnswerRespondent::PendingProject(pending_project.id()),
+                    registration_form.id(),
+                ))
+            })
+            .collect();
+        Ok((answer, file_sharings))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RegistrationFormAnswerDraft, SaveErrorKind};
+    use crate::model::project::ProjectCategory;
+    use crate::test::model as test_model;
+    use crate::DomainError;
+
+    #[tokio::test]
+    async fn test_save_and_submit() {
+        let operator = test_model::new_operator_user();
+        let user = test_model::new_general_user();
+        let pending_project =
+            test_model::new_pending_project(user.id().clone(), ProjectCategory::General);
+        let registration_form = test_model::new_registration_form(operator.id().clone());
+
+        let app = crate::test::build_mock_app()
+            .users(vec![user.clone(), operator.clone()])
+            .pending_projects(vec![pending_project.clone()])
+            .registration_forms(vec![registration_form.clone()])
+            .build();
+
+        let items = test_model::mock_form_answer_items(&registration_form.items);
+        let draft = RegistrationFormAnswerDraft::save(
+            &app,
+            &user,
+            &pending_project,
+            &registration_form,
+            items,
+            vec![],
+        )
+        .await
+        .unwrap();
+        assert!(draft.is_visible_to_with_pending_project(&user, &pending_project));
+        assert!(!draft.is_visible_to_with_pending_project(&operator, &pending_project));
+
+        let (answer, _) = draft
+            .submit(&app, &user, &pending_project, &registration_form)
+            .await
+            .unwrap();
+        assert_eq!(answer.registration_form_id(), registration_form.id());
+        assert!(answer.respondent().is_pending_project(&pending_project));
+    }
+
+    #[tokio::test]
+    async fn test_save_out_of_period() {
+        let operator = test_model::new_operator_user();
+        let user = test_model::new_general_user();
+        let pending_project =
+            test_model::new_pending_project(user.id().clone(), ProjectCategory::General);
+        let registration_form = test_model::new_registration_form(operator.id().clone());
+
+        let app = crate::test::build_mock_app()
+            .users(vec![user.clone(), operator.clone()])
+            .pending_projects(vec![pending_project.clone()])
+            .registration_forms(vec![registration_form.clone()])
+            .project_creation_period_for(
+                ProjectCategory::General,
+                test_model::new_project_creation_period_to_now(),
+            )
+            .build();
+
+        let items = test_model::mock_form_answer_items(&registration_form.items);
+        assert!(matches!(
+            RegistrationFormAnswerDraft::save(
+                &app,
+                &user,
+                &pending_project,
+                &registration_form,
+                items,
+                vec![],
+            )
+            .await,
+            Err(DomainError::Domain(err)) if err.kind() == SaveErrorKind::OutOfProjectCreationPeriod
+        ));
+    }
+}
@@ -0,0 +1,223 @@
+use std::collections::HashMap;
+
+use crate::context::ConfigContext;
+use crate::model::date_time::DateTime;
+use crate::model::festival::{Festival, FestivalId};
+use crate::model::permissions::Permissions;
+use crate::model::project::ProjectCategory;
+use crate::model::project_creation_period::ProjectCreationPeriod;
+use crate::model::user::{self, User, UserEmailAddress, UserId};
+
+use thiserror::Error;
+
+/// Festival-wide settings which can be edited at runtime.
+///
+/// The settings are stored as a new snapshot on every update,
+/// and the snapshots are kept as the history of changes.
+#[derive(Debug, Clone)]
+pub struct SettingsContent {
+    pub updated_at: DateTime,
+    /// `None` when the settings have never been edited since they were taken from the configuration.
+    pub author_id: Option<UserId>,
+    pub administrator_email: UserEmailAddress,
+    pub active_festival_id: FestivalId,
+    /// The periods in which projects can be created in the active festival.
+    pub project_creation_periods: HashMap<ProjectCategory, ProjectCreationPeriod>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Settings {
+    content: SettingsContent,
+}
+
+#[derive(Debug, Error, Clone)]
+#[error("insufficient permissions to update settings")]
+pub struct NoUpdatePermissionError {
+    _priv: (),
+}
+
+impl NoUpdatePermissionError {
+    fn from_permissions_error(_err: user::RequirePermissionsError) -> Self {
+        NoUpdatePermissionError { _priv: () }
+    }
+}
+
+impl Settings {
+    /// Take the current settings from the configuration.
+    ///
+    /// This is intended to be used as the initial settings when no settings have been stored yet.
+    pub fn from_config<C>(ctx: C) -> Self
+    where
+        C: ConfigContext,
+    {
+        let project_creation_periods = ProjectCategory::enumerate()
+            .map(|category| (category, ctx.project_creation_period_for(category)))
+            .collect();
+
+        Settings::from_content(SettingsContent {
+            updated_at: DateTime::now(),
+            author_id: None,
+            administrator_email: ctx.administrator_email().clone(),
+            active_festival_id: ctx.active_festival_id(),
+            project_creation_periods,
+        })
+    }
+
+    /// Restore `Settings` from `SettingsContent`.
+    ///
+    /// This is intended to be used when the data is taken out of the implementation by [`Settings::into_content`]
+    /// for persistence, internal serialization, etc.
+    pub fn from_content(content: SettingsContent) -> Self {
+        Settings { content }
+    }
+
+    /// Convert `Settings` into `SettingsContent`.
+    pub fn into_content(self) -> SettingsContent {
+        self.content
+    }
+
+    pub fn updated_at(&self) -> DateTime {
+        self.content.updated_at
+    }
+
+    pub fn author_id(&self) -> Option<&UserId> {
+        self.content.author_id.as_ref()
+    }
+
+    pub fn administrator_email(&self) -> &UserEmailAddress {
+        &self.content.administrator_email
+    }
+
+    pub fn active_festival_id(&self) -> FestivalId {
+        self.content.active_festival_id
+    }
+
+    pub fn project_creation_period_for(&self, category: ProjectCategory) -> ProjectCreationPeriod {
+        self.content
+            .project_creation_periods
+            .get(&category)
+            .copied()
+            .unwrap_or_else(ProjectCreationPeriod::never)
+    }
+
+    pub fn is_visible_to(&self, user: &User) -> bool {
+        user.permissions().contains(Permissions::READ_SETTINGS)
+    }
+
+    fn require_update_permission(&mut self, user: &User) -> Result<(), NoUpdatePermissionError> {
+        user.require_permissions(Permissions::UPDATE_SETTINGS)
+            .map_err(NoUpdatePermissionError::from_permissions_error)?;
+
+        self.content.updated_at = DateTime::now();
+        self.content.author_id = Some(user.id().clone());
+        Ok(())
+    }
+
+    pub fn set_administrator_email(
+        &mut self,
+        user: &User,
+        administrator_email: UserEmailAddress,
+    ) -> Result<(), NoUpdatePermissionError> {
+        self.require_update_permission(user)?;
+        self.content.administrator_email = administrator_email;
+        Ok(())
+    }
+
+    pub fn set_active_festival(
+        &mut self,
+        user: &User,
+        festival: &Festival,
+    ) -> Result<(), NoUpdatePermissionError> {
+        self.require_update_permission(user)?;
+        self.content.active_festival_id = festival.id();
+        Ok(())
+    }
+
+    pub fn set_project_creation_period(
+        &mut self,
+        user: &User,
+        category: ProjectCategory,
+        period: ProjectCreationPeriod,
+    ) -> Result<(), NoUpdatePermissionError> {
+        self.require_update_permission(user)?;
+        self.content
+            .project_creation_periods
+            .insert(category, period);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Settings;
+    use crate::model::project::ProjectCategory;
+    use crate::model::project_creation_period::ProjectCreationPeriod;
+    use crate::test::model as test_model;
+
+    #[test]
+    fn test_from_config() {
+        let app = crate::test::build_mock_app()
+            .project_creation_period_for(ProjectCategory::Stage, ProjectCreationPeriod::always())
+            .project_creation_period_for(ProjectCategory::General, ProjectCreationPeriod::never())
+            .build();
+        let settings = Settings::from_config(&app);
+        assert!(settings.author_id().is_none());
+        assert!(settings
+            .project_creation_period_for(ProjectCategory::Stage)
+            .is_always());
+        assert!(settings
+            .project_creation_period_for(ProjectCategory::General)
+            .is_never());
+    }
+
+    #[test]
+    fn test_update_admin() {
+        let admin = test_model::new_admin_user();
+        let app = crate::test::build_mock_app()
+            .project_creation_period_for(ProjectCategory::Food, ProjectCreationPeriod::never())
+            .build();
+        let mut settings = Settings::from_config(&app);
+        settings
+            .set_project_creation_period(
+                &admin,
+                ProjectCategory::Food,
+                ProjectCreationPeriod::always(),
+            )
+            .unwrap();
+        assert_eq!(settings.author_id(), Some(admin.id()));
+        assert!(settings
+            .project_creation_period_for(ProjectCategory::Food)
+            .is_always());
+    }
+
+    #[test]
+    fn test_update_operator() {
+        let operator = test_model::new_operator_user();
+        let app = crate::test::build_mock_app().build();
+        let mut settings = Settings::from_config(&app);
+        assert!(!settings.is_visible_to(&operator));
+        assert!(settings
+            .set_project_creation_period(
+                &operator,
+                ProjectCategory::Food,
+                ProjectCreationPeriod::always(),
+            )
+            .is_err());
+        assert!(settings.author_id().is_none());
+    }
+
+    #[test]
+    fn test_set_active_festival() {
+        let admin = test_model::new_admin_user();
+        let app = crate::test::build_mock_app().build();
+        let mut settings = Settings::from_config(&app);
+        assert_eq!(
+            settings.active_festival_id(),
+            *test_model::KNOWN_MOCK_FESTIVAL_ID
+        );
+
+        let festival = test_model::new_festival();
+        settings.set_active_festival(&admin, &festival).unwrap();
+        assert_eq!(settings.active_festival_id(), festival.id());
+    }
+}
@@ -1,4 +1,5 @@
 use crate::context::{ConfigContext, FileRepository, UserInvitationRepository, UserRepository};
+use crate::model::custom_role::CustomRole;
 use crate::model::date_time::DateTime;
 use crate::model::pending_project::PendingProject;
 use crate::model::permissions::Permissions;
@@ -11,18 +12,22 @@ use thiserror::Error;
 
 pub mod assignment;
 pub mod category;
+pub mod custom_role;
 pub mod email;
 pub mod file_usage;
 pub mod file_usage_quota;
 pub mod name;
 pub mod role;
+pub mod suspension;
 pub use assignment::UserAssignment;
 pub use category::UserCategory;
+pub use custom_role::UserCustomRole;
 pub use email::UserEmailAddress;
 pub use file_usage::UserFileUsage;
 pub use file_usage_quota::UserFileUsageQuota;
 pub use name::{UserKanaName, UserName};
 pub use role::UserRole;
+pub use suspension::{UserSuspension, UserSuspensionReason};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct UserId(pub String);
@@ -36,13 +41,22 @@ pub struct UserContent {
     pub phone_number: PhoneNumber,
     pub email: UserEmailAddress,
     pub role: UserRole,
+    /// Assigned alongside the built-in `role`, granting the additional permissions.
+    pub custom_role: Option<UserCustomRole>,
     pub category: UserCategory,
     pub assignment: Option<UserAssignment>,
+    pub suspension: Option<UserSuspension>,
+    /// The tokens issued before this time are rejected on login.
+    pub tokens_revoked_at: Option<DateTime>,
 }
 
 #[derive(Debug, Clone)]
 pub struct User {
     content: UserContent,
+    /// The upper bound of the permissions, which is not persisted.
+    permissions_limit: Option<Permissions>,
+    /// Whether the user is logged in with an API key, which is not persisted.
+    with_api_key: bool,
 }
 
 #[derive(Debug, Error, Clone)]
@@ -85,6 +99,7 @@ impl User {
             ctx.get_user_invitation_by_email(&email)
                 .await
                 .context("Failed to get user invitation")?
+                .filter(|invitation| !invitation.is_expired())
                 .map(|invitation| invitation.role().to_user_role())
                 .unwrap_or(UserRole::General)
         };
@@ -97,8 +112,11 @@ impl User {
             email,
             phone_number,
             role,
+            custom_role: None,
             category,
             assignment: None,
+            suspension: None,
+            tokens_revoked_at: None,
         }))
     }
 
@@ -108,7 +126,11 @@ impl User {
     /// by [`User::into_content`] for persistence, internal serialization, etc.
     /// Use [`User::new`] to create a project.
     pub fn from_content(content: UserContent) -> Self {
-        User { content }
+        User {
+            content,
+            permissions_limit: None,
+            with_api_key: false,
+        }
     }
 
     /// Convert `User` into `UserContent`.
@@ -144,6 +166,10 @@ impl User {
         self.content.role
     }
 
+    pub fn custom_role(&self) -> Option<&UserCustomRole> {
+        self.content.custom_role.as_ref()
+    }
+
     pub fn category(&self) -> &UserCategory {
         &self.content.category
     }
@@ -152,6 +178,32 @@ impl User {
         self.content.assignment
     }
 
+    pub fn suspension(&self) -> Option<&UserSuspension> {
+        self.content.suspension.as_ref()
+    }
+
+    pub fn is_suspended(&self) -> bool {
+        self.content.suspension.is_some()
+    }
+
+    pub fn tokens_revoked_at(&self) -> Option<DateTime> {
+        self.content.tokens_revoked_at
+    }
+
+    /// Whether the token issued at `issued_at` can be used to log in as the user.
+    ///
+    /// `issued_at` comes from the `iat` claim, which only has second precision, so the revocation
+    /// time is truncated to seconds before the comparison not to reject the tokens issued
+    /// right after the revocation.
+    pub fn accepts_token_issued_at(&self, issued_at: DateTime) -> bool {
+        use chrono::SubsecRound;
+
+        match self.content.tokens_revoked_at {
+            Some(revoked_at) => issued_at.utc() >= revoked_at.utc().trunc_subsecs(0),
+            None => true,
+        }
+    }
+
     pub fn assign_project_owner(&mut self, project: &Project) -> anyhow::Result<()> {
         anyhow::ensure!(project.owner_id() == self.id());
         self.content
@@ -179,8 +231,49 @@ impl User {
         Ok(())
     }
 
+    /// Release the user from the project or pending project assigned,
+    /// so that the user can take part in a project of another festival.
+    pub fn release_assignment(&mut self) {
+        self.content.assignment.take();
+    }
+
     pub fn permissions(&self) -> Permissions {
-        self.role().permissions()
+        let permissions = match &self.content.custom_role {
+            Some(custom_role) => self.role().permissions() | custom_role.permissions,
+            None => self.role().permissions(),
+        };
+        match self.permissions_limit {
+            Some(limit) => permissions.intersection(limit),
+            None => permissions,
+        }
+    }
+
+    /// Limit the permissions to the ones contained in `limit`, such as the scope of the API key
+    /// with which the user is logged in. Limits applied multiple times are intersected.
+    pub fn limit_permissions(&mut self, limit: Permissions) {
+        let limit = match self.permissions_limit {
+            Some(current) => current.intersection(limit),
+            None => limit,
+        };
+        self.permissions_limit = Some(limit);
+    }
+
+    /// Restrict the user to act with the API key of `scope`, which is only given the accesses
+    /// allowed by the permissions in `scope`. The accesses based on the ownership or membership,
+    /// and the ones granted by access control lists are not given.
+    pub fn restrict_to_api_key(&mut self, scope: Permissions) {
+        self.limit_permissions(scope);
+        self.with_api_key = true;
+    }
+
+    pub fn is_with_api_key(&self) -> bool {
+        self.with_api_key
+    }
+
+    /// Whether the user is the one identified by `id` in checking the accesses based on
+    /// the ownership or membership. This never holds for the user logged in with an API key.
+    pub fn is_identified_by(&self, id: &UserId) -> bool {
+        !self.with_api_key && self.id() == id
     }
 
     pub fn require_permissions(
@@ -195,7 +288,7 @@ impl User {
     }
 
     pub fn is_visible_to(&self, user: &User) -> bool {
-        if self.id() == user.id() {
+        if user.is_identified_by(self.id()) {
             return true;
         }
 
@@ -274,6 +367,19 @@ impl User {
         Ok(())
     }
 
+    /// Assign the custom role, or remove the assigned one with `None`.
+    pub fn set_custom_role(
+        &mut self,
+        user: &User,
+        custom_role: Option<&CustomRole>,
+    ) -> Result<(), NoUpdatePermissionError> {
+        self.require_update_permission(user)?;
+        user.require_permissions(Permissions::UPDATE_CUSTOM_ROLES)
+            .map_err(NoUpdatePermissionError::from_permissions_error)?;
+        self.content.custom_role = custom_role.map(UserCustomRole::from_custom_role);
+        Ok(())
+    }
+
     pub fn set_category(
         &mut self,
         user: &User,
@@ -285,10 +391,135 @@ impl User {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuspendErrorKind {
+    InsufficientPermissions,
+    SuspendingSelf,
+    AlreadySuspended,
+}
+
+#[derive(Debug, Clone, Error)]
+#[error("failed to suspend the user")]
+pub struct SuspendError {
+    kind: SuspendErrorKind,
+}
+
+impl SuspendError {
+    pub fn kind(&self) -> SuspendErrorKind {
+        self.kind
+    }
+
+    fn from_permissions_error(_err: RequirePermissionsError) -> Self {
+        SuspendError {
+            kind: SuspendErrorKind::InsufficientPermissions,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnsuspendErrorKind {
+    InsufficientPermissions,
+    NotSuspended,
+}
+
+#[derive(Debug, Clone, Error)]
+#[error("failed to unsuspend the user")]
+pub struct UnsuspendError {
+    kind: UnsuspendErrorKind,
+}
+
+impl UnsuspendError {
+    pub fn kind(&self) -> UnsuspendErrorKind {
+        self.kind
+    }
+
+    fn from_permissions_error(_err: RequirePermissionsError) -> Self {
+        UnsuspendError {
+            kind: UnsuspendErrorKind::InsufficientPermissions,
+        }
+    }
+}
+
+impl User {
+    /// Suspend the user and revoke the tokens issued so far.
+    pub fn suspend(
+        &mut self,
+        user: &User,
+        reason: UserSuspensionReason,
+    ) -> Result<(), SuspendError> {
+        user.require_permissions(Permissions::SUSPEND_USERS)
+            .map_err(SuspendError::from_permissions_error)?;
+
+        if self.id() == user.id() {
+            return Err(SuspendError {
+                kind: SuspendErrorKind::SuspendingSelf,
+            });
+        }
+
+        self.set_suspension(Some(user.id().clone()), reason)
+    }
+
+    /// Suspend the user on behalf of the server, such as on the detection of a suspicious account.
+    pub fn suspend_automatically(
+        &mut self,
+        reason: UserSuspensionReason,
+    ) -> Result<(), SuspendError> {
+        self.set_suspension(None, reason)
+    }
+
+    fn set_suspension(
+        &mut self,
+        suspended_by: Option<UserId>,
+        reason: UserSuspensionReason,
+    ) -> Result<(), SuspendError> {
+        if self.is_suspended() {
+            return Err(SuspendError {
+                kind: SuspendErrorKind::AlreadySuspended,
+            });
+        }
+
+        let now = DateTime::now();
+        self.content.suspension = Some(UserSuspension {
+            suspended_at: now,
+            suspended_by,
+            reason,
+        });
+        self.content.tokens_revoked_at = Some(now);
+        Ok(())
+    }
+
+    /// Lift the suspension. The tokens revoked on the suspension remain rejected.
+    pub fn unsuspend(&mut self, user: &User) -> Result<(), UnsuspendError> {
+        user.require_permissions(Permissions::SUSPEND_USERS)
+            .map_err(UnsuspendError::from_permissions_error)?;
+
+        if self.content.suspension.take().is_none() {
+            return Err(UnsuspendError {
+                kind: UnsuspendErrorKind::NotSuspended,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Reject the tokens issued so far, so that the user has to sign in again.
+    pub fn revoke_tokens(&mut self, user: &User) -> Result<(), NoUpdatePermissionError> {
+        user.require_permissions(Permissions::SUSPEND_USERS)
+            .map_err(NoUpdatePermissionError::from_permissions_error)?;
+        self.content.tokens_revoked_at = Some(DateTime::now());
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{AlreadySignedUpError, NoUpdatePermissionError, User, UserEmailAddress, UserRole};
+    use super::{
+        AlreadySignedUpError, NoUpdatePermissionError, SuspendErrorKind, UnsuspendErrorKind, User,
+        UserEmailAddress, UserRole, UserSuspensionReason,
+    };
+    use crate::model::date_time::DateTime;
 
+    use crate::model::permissions::Permissions;
     use crate::test::model as test_model;
     use crate::DomainError;
 
@@ -346,6 +577,40 @@ mod tests {
         assert_eq!(user.role(), UserRole::Administrator);
     }
 
+    #[test]
+    fn test_custom_role_permissions() {
+        let admin = test_model::new_admin_user();
+        let mut user = test_model::new_committee_user();
+        assert!(user
+            .require_permissions(Permissions::UPDATE_ALL_FORM_ANSWERS)
+            .is_err());
+
+        let role =
+            test_model::new_custom_role(admin.id().clone(), Permissions::UPDATE_ALL_FORM_ANSWERS);
+        user.set_custom_role(&admin, Some(&role)).unwrap();
+        assert!(user
+            .require_permissions(Permissions::UPDATE_ALL_FORM_ANSWERS)
+            .is_ok());
+        // the permissions of the built-in role are kept
+        assert!(user
+            .require_permissions(Permissions::READ_ALL_FORM_ANSWERS)
+            .is_ok());
+
+        user.set_custom_role(&admin, None).unwrap();
+        assert!(user
+            .require_permissions(Permissions::UPDATE_ALL_FORM_ANSWERS)
+            .is_err());
+    }
+
+    #[test]
+    fn test_set_custom_role_operator() {
+        let admin = test_model::new_admin_user();
+        let operator = test_model::new_operator_user();
+        let mut user = test_model::new_general_user();
+        let role = test_model::new_custom_role(admin.id().clone(), Permissions::READ_ALL_USERS);
+        assert!(user.set_custom_role(&operator, Some(&role)).is_err());
+    }
+
     #[tokio::test]
     async fn test_new_already_signed_up() {
         let user = test_model::new_general_user();
@@ -439,4 +704,113 @@ mod tests {
             && user.email() == &email
         ));
     }
+
+    #[test]
+    fn test_limit_permissions() {
+        let mut user = test_model::new_admin_user();
+        user.limit_permissions(Permissions::READ_ALL_USERS | Permissions::READ_ALL_PROJECTS);
+        assert!(user
+            .require_permissions(Permissions::READ_ALL_USERS)
+            .is_ok());
+        assert!(user
+            .require_permissions(Permissions::UPDATE_ALL_USERS)
+            .is_err());
+
+        user.limit_permissions(Permissions::READ_ALL_PROJECTS);
+        assert!(user
+            .require_permissions(Permissions::READ_ALL_USERS)
+            .is_err());
+        assert!(user
+            .require_permissions(Permissions::READ_ALL_PROJECTS)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_limit_permissions_beyond_role() {
+        let mut user = test_model::new_general_user();
+        user.limit_permissions(Permissions::all());
+        assert!(user
+            .require_permissions(Permissions::READ_ALL_USERS)
+            .is_err());
+    }
+
+    #[test]
+    fn test_suspend() {
+        let admin = test_model::new_admin_user();
+        let mut user = test_model::new_general_user();
+        let reason = UserSuspensionReason::from_string("spam").unwrap();
+        user.suspend(&admin, reason.clone()).unwrap();
+        assert!(user.is_suspended());
+        assert_eq!(
+            user.suspension().unwrap().suspended_by.as_ref(),
+            Some(admin.id())
+        );
+        assert!(!user.accepts_token_issued_at(DateTime::from_utc(
+            chrono::Utc::now() - chrono::Duration::minutes(1)
+        )));
+        assert_eq!(
+            user.suspend(&admin, reason).unwrap_err().kind(),
+            SuspendErrorKind::AlreadySuspended
+        );
+
+        user.unsuspend(&admin).unwrap();
+        assert!(!user.is_suspended());
+        assert_eq!(
+            user.unsuspend(&admin).unwrap_err().kind(),
+            UnsuspendErrorKind::NotSuspended
+        );
+    }
+
+    #[test]
+    fn test_suspend_operator() {
+        let operator = test_model::new_operator_user();
+        let mut user = test_model::new_general_user();
+        let reason = UserSuspensionReason::from_string("spam").unwrap();
+        assert_eq!(
+            user.suspend(&operator, reason).unwrap_err().kind(),
+            SuspendErrorKind::InsufficientPermissions
+        );
+    }
+
+    #[test]
+    fn test_suspend_self() {
+        let mut admin = test_model::new_admin_user();
+        let reason = UserSuspensionReason::from_string("spam").unwrap();
+        assert_eq!(
+            admin.suspend(&admin.clone(), reason).unwrap_err().kind(),
+            SuspendErrorKind::SuspendingSelf
+        );
+    }
+
+    #[test]
+    fn test_revoke_tokens() {
+        let admin = test_model::new_admin_user();
+        let mut user = test_model::new_general_user();
+        let issued_at = DateTime::from_utc(chrono::Utc::now() - chrono::Duration::minutes(1));
+        assert!(user.accepts_token_issued_at(issued_at));
+        user.revoke_tokens(&admin).unwrap();
+        assert!(!user.accepts_token_issued_at(issued_at));
+        assert!(user.accepts_token_issued_at(DateTime::now()));
+    }
+
+    #[test]
+    fn test_revoke_tokens_second_precision() {
+        use chrono::TimeZone;
+
+        let mut user = test_model::new_general_user().into_content();
+        user.tokens_revoked_at = Some(DateTime::from_utc(
+            chrono::Utc
+                .timestamp_opt(1_600_000_000, 500_000_000)
+                .unwrap(),
+        ));
+        let user = User::from_content(user);
+
+        // `iat` of the token issued in the same second as the revocation
+        assert!(user.accepts_token_issued_at(DateTime::from_utc(
+            chrono::Utc.timestamp_opt(1_600_000_000, 0).unwrap()
+        )));
+        assert!(!user.accepts_token_issued_at(DateTime::from_utc(
+            chrono::Utc.timestamp_opt(1_599_999_999, 0).unwrap()
+        )));
+    }
 }
@@ -0,0 +1,19 @@
+use crate::model::custom_role::{CustomRole, CustomRoleId};
+use crate::model::permissions::Permissions;
+
+/// The custom role assigned to a user, holding the permissions of the role
+/// at the time the user is loaded.
+#[derive(Debug, Clone)]
+pub struct UserCustomRole {
+    pub id: CustomRoleId,
+    pub permissions: Permissions,
+}
+
+impl UserCustomRole {
+    pub fn from_custom_role(role: &CustomRole) -> Self {
+        UserCustomRole {
+            id: role.id(),
+            permissions: role.permissions(),
+        }
+    }
+}
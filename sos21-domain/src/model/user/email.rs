@@ -1,8 +1,10 @@
+use std::fmt::{self, Display};
+
 use crate::model::email::{self, EmailAddress};
 
 use thiserror::Error;
 
-/// A valid university email address.
+/// An email address of a user, whose domain is allowed to sign up.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct UserEmailAddress(EmailAddress);
 
@@ -46,12 +48,14 @@ impl EmailAddressError {
 }
 
 impl UserEmailAddress {
-    pub fn from_string(s: impl Into<String>) -> Result<UserEmailAddress, EmailAddressError> {
-        let email = EmailAddress::from_string(s.into()).map_err(|err| EmailAddressError {
-            inner: EmailAddressErrorInner::InvalidEmailAddress(err),
-        })?;
-        if email.ends_with("tsukuba.ac.jp") || email.ends_with("sohosai.com") {
-            Ok(UserEmailAddress(email))
+    /// Create `UserEmailAddress` from `s`, checking that its domain is one of `domains`.
+    pub fn from_string_with_domains(
+        s: impl Into<String>,
+        domains: &AllowedEmailDomains,
+    ) -> Result<UserEmailAddress, EmailAddressError> {
+        let email = UserEmailAddress::from_string(s)?;
+        if domains.allows(&email.0) {
+            Ok(email)
         } else {
             Err(EmailAddressError {
                 inner: EmailAddressErrorInner::NotUniversityEmailAddress,
@@ -59,6 +63,18 @@ impl UserEmailAddress {
         }
     }
 
+    /// Create `UserEmailAddress` from `s` without checking its domain.
+    ///
+    /// This is intended to be used to restore the addresses which are already checked,
+    /// or the addresses which are exempted from the domain restriction by an invitation.
+    /// Use [`UserEmailAddress::from_string_with_domains`] to check the addresses from users.
+    pub fn from_string(s: impl Into<String>) -> Result<UserEmailAddress, EmailAddressError> {
+        let email = EmailAddress::from_string(s.into()).map_err(|err| EmailAddressError {
+            inner: EmailAddressErrorInner::InvalidEmailAddress(err),
+        })?;
+        Ok(UserEmailAddress(email))
+    }
+
     pub fn as_str(&self) -> &str {
         self.0.as_str()
     }
@@ -68,27 +84,171 @@ impl UserEmailAddress {
     }
 }
 
+/// A rule on the domain part of email addresses.
+///
+/// The rule is written as `example.com` to match `example.com` exactly,
+/// or as `.example.com` to match `example.com` and all of its subdomains.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailDomainRule {
+    domain: String,
+    include_subdomains: bool,
+}
+
+#[derive(Debug, Error, Clone)]
+#[error("invalid email domain rule")]
+pub struct EmailDomainRuleError {
+    _priv: (),
+}
+
+impl EmailDomainRule {
+    pub fn from_string(s: impl AsRef<str>) -> Result<Self, EmailDomainRuleError> {
+        let s = s.as_ref().trim();
+        let (domain, include_subdomains) = match s.strip_prefix('.') {
+            Some(domain) => (domain, true),
+            None => (s, false),
+        };
+
+        if !is_valid_domain(domain) {
+            return Err(EmailDomainRuleError { _priv: () });
+        }
+
+        Ok(EmailDomainRule {
+            domain: domain.to_ascii_lowercase(),
+            include_subdomains,
+        })
+    }
+
+    pub fn domain(&self) -> &str {
+        &self.domain
+    }
+
+    pub fn includes_subdomains(&self) -> bool {
+        self.include_subdomains
+    }
+
+    pub fn matches(&self, domain: &str) -> bool {
+        let domain = domain.to_ascii_lowercase();
+        if domain == self.domain {
+            return true;
+        }
+
+        self.include_subdomains
+            && domain
+                .strip_suffix(&self.domain)
+                .is_some_and(|sub| sub.ends_with('.'))
+    }
+}
+
+impl Display for EmailDomainRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.include_subdomains {
+            write!(f, ".{}", self.domain)
+        } else {
+            write!(f, "{}", self.domain)
+        }
+    }
+}
+
+fn is_valid_domain(domain: &str) -> bool {
+    !domain.is_empty()
+        && domain.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label
+                    .bytes()
+                    .all(|c| c == b'-' || c.is_ascii_alphanumeric())
+        })
+}
+
+/// The list of domains from which users can sign up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AllowedEmailDomains(Vec<EmailDomainRule>);
+
+impl AllowedEmailDomains {
+    pub fn from_rules<I>(rules: I) -> Self
+    where
+        I: IntoIterator<Item = EmailDomainRule>,
+    {
+        AllowedEmailDomains(rules.into_iter().collect())
+    }
+
+    pub fn rules(&self) -> impl Iterator<Item = &'_ EmailDomainRule> {
+        self.0.iter()
+    }
+
+    pub fn allows(&self, email: &EmailAddress) -> bool {
+        self.0.iter().any(|rule| rule.matches(email.domain()))
+    }
+}
+
+impl Default for AllowedEmailDomains {
+    /// The university domains, which had been the only allowed domains before they became configurable.
+    fn default() -> Self {
+        AllowedEmailDomains(vec![
+            EmailDomainRule {
+                domain: "tsukuba.ac.jp".to_owned(),
+                include_subdomains: true,
+            },
+            EmailDomainRule {
+                domain: "sohosai.com".to_owned(),
+                include_subdomains: true,
+            },
+        ])
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::UserEmailAddress;
+    use super::{AllowedEmailDomains, EmailDomainRule, UserEmailAddress};
+
+    fn from_string_default(s: &str) -> bool {
+        UserEmailAddress::from_string_with_domains(s, &AllowedEmailDomains::default()).is_ok()
+    }
 
     #[test]
     fn test_address_invalid() {
-        assert!(UserEmailAddress::from_string("a@b.c").is_err());
-        assert!(UserEmailAddress::from_string("a.b.c@de.fg").is_err());
-        assert!(UserEmailAddress::from_string("ab.c@d-e.fg").is_err());
-        assert!(UserEmailAddress::from_string("a@s.tsukuba.ac.jp.com").is_err());
-        assert!(UserEmailAddress::from_string("b@sohosai.com.jp").is_err());
+        assert!(!from_string_default("a@b.c"));
+        assert!(!from_string_default("a.b.c@de.fg"));
+        assert!(!from_string_default("ab.c@d-e.fg"));
+        assert!(!from_string_default("a@s.tsukuba.ac.jp.com"));
+        assert!(!from_string_default("b@sohosai.com.jp"));
+        assert!(!from_string_default("c@notsohosai.com"));
     }
 
     #[test]
     fn test_address_valid() {
-        assert!(UserEmailAddress::from_string("a@s.tsukuba.ac.jp").is_ok());
-        assert!(UserEmailAddress::from_string("a.b.c@u.tsukuba.ac.jp").is_ok());
-        assert!(UserEmailAddress::from_string("a-c@s.tsukuba.ac.jp").is_ok());
-        assert!(UserEmailAddress::from_string("abcde@su.tsukuba.ac.jp").is_ok());
-        assert!(UserEmailAddress::from_string("ab.c@coins.tsukuba.ac.jp").is_ok());
-        assert!(UserEmailAddress::from_string("a@external.sohosai.com").is_ok());
-        assert!(UserEmailAddress::from_string("a-b@sohosai.com").is_ok());
+        assert!(from_string_default("a@s.tsukuba.ac.jp"));
+        assert!(from_string_default("a.b.c@u.tsukuba.ac.jp"));
+        assert!(from_string_default("a-c@s.tsukuba.ac.jp"));
+        assert!(from_string_default("abcde@su.tsukuba.ac.jp"));
+        assert!(from_string_default("ab.c@coins.tsukuba.ac.jp"));
+        assert!(from_string_default("a@external.sohosai.com"));
+        assert!(from_string_default("a-b@sohosai.com"));
+    }
+
+    #[test]
+    fn test_address_unchecked_domain() {
+        assert!(UserEmailAddress::from_string("a@example.com").is_ok());
+        assert!(UserEmailAddress::from_string("a@@example.com").is_err());
+    }
+
+    #[test]
+    fn test_domain_rule() {
+        let exact = EmailDomainRule::from_string("example.com").unwrap();
+        assert!(exact.matches("example.com"));
+        assert!(exact.matches("Example.COM"));
+        assert!(!exact.matches("sub.example.com"));
+
+        let subdomains = EmailDomainRule::from_string(".example.com").unwrap();
+        assert!(subdomains.matches("example.com"));
+        assert!(subdomains.matches("sub.example.com"));
+        assert!(!subdomains.matches("badexample.com"));
+        assert_eq!(subdomains.to_string(), ".example.com");
+
+        assert!(EmailDomainRule::from_string("").is_err());
+        assert!(EmailDomainRule::from_string("..example.com").is_err());
+        assert!(EmailDomainRule::from_string("-a.com").is_err());
     }
 }
@@ -28,12 +28,14 @@ impl UserRole {
                     | Permissions::UPDATE_NOT_STARTED_OWNING_FORMS
                     | Permissions::DISTRIBUTE_FILES
                     | Permissions::CREATE_REGISTRATION_FORMS
+                    | Permissions::UPDATE_PENDING_PROJECT_DEADLINES
             }
             UserRole::Committee => {
                 UserRole::General.permissions()
                     | Permissions::READ_ALL_PROJECTS
                     | Permissions::READ_ALL_FORMS
                     | Permissions::READ_ALL_FORM_ANSWERS
+                    | Permissions::REVIEW_FORM_ANSWERS
                     | Permissions::READ_ALL_FILE_DISTRIBUTIONS
                     | Permissions::READ_ALL_REGISTRATION_FORMS
                     | Permissions::READ_ALL_REGISTRATION_FORM_ANSWERS
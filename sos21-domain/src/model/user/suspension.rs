@@ -0,0 +1,52 @@
+use crate::model::date_time::DateTime;
+use crate::model::string::{self, LengthBoundedString, StrippedString};
+use crate::model::user::UserId;
+
+use thiserror::Error;
+
+/// The state of a user who is not allowed to log in.
+#[derive(Debug, Clone)]
+pub struct UserSuspension {
+    pub suspended_at: DateTime,
+    /// `None` if the user is suspended automatically by the server.
+    pub suspended_by: Option<UserId>,
+    pub reason: UserSuspensionReason,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserSuspensionReason(
+    StrippedString<LengthBoundedString<typenum::U1, typenum::U1024, String>>,
+);
+
+#[derive(Debug, Error, Clone)]
+#[error("invalid suspension reason")]
+pub struct ReasonError {
+    _priv: (),
+}
+
+impl ReasonError {
+    fn from_length_error(_err: string::BoundedLengthError<typenum::U1, typenum::U1024>) -> Self {
+        ReasonError { _priv: () }
+    }
+
+    fn from_not_stripped_error(_err: string::NotStrippedError) -> Self {
+        ReasonError { _priv: () }
+    }
+}
+
+impl UserSuspensionReason {
+    pub fn from_string(reason: impl Into<String>) -> Result<Self, ReasonError> {
+        let inner =
+            LengthBoundedString::new(reason.into()).map_err(ReasonError::from_length_error)?;
+        let inner = StrippedString::new(inner).map_err(ReasonError::from_not_stripped_error)?;
+        Ok(UserSuspensionReason(inner))
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    pub fn into_string(self) -> String {
+        self.0.into_inner().into_inner()
+    }
+}
@@ -31,6 +31,25 @@ pub struct UserInvitationContent {
     pub author_id: UserId,
     pub email: UserEmailAddress,
     pub role: UserInvitationRole,
+    /// Whether the invited address can sign up even if its domain is not in the allowed domains.
+    pub allows_unlisted_domain: bool,
+    /// The invitation cannot be accepted after this time if set.
+    pub expires_at: Option<DateTime>,
+    pub acceptance: Option<UserInvitationAcceptance>,
+}
+
+/// The record of the sign up with the invited address.
+#[derive(Debug, Clone)]
+pub struct UserInvitationAcceptance {
+    pub accepted_at: DateTime,
+    pub user_id: UserId,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserInvitationStatus {
+    Pending,
+    Accepted,
+    Expired,
 }
 
 #[derive(Debug, Clone)]
@@ -63,12 +82,56 @@ impl NewUserInvitationError {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetExpiresAtErrorKind {
+    InsufficientPermissions,
+    AlreadyAccepted,
+    PastExpiry,
+}
+
+#[derive(Debug, Clone, Error)]
+#[error("failed to set the expiry of the user invitation")]
+pub struct SetExpiresAtError {
+    kind: SetExpiresAtErrorKind,
+}
+
+impl SetExpiresAtError {
+    pub fn kind(&self) -> SetExpiresAtErrorKind {
+        self.kind
+    }
+
+    fn from_permissions_error(_err: user::RequirePermissionsError) -> Self {
+        SetExpiresAtError {
+            kind: SetExpiresAtErrorKind::InsufficientPermissions,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AcceptUserInvitationErrorKind {
+    NotPending,
+    MismatchedEmailAddress,
+}
+
+#[derive(Debug, Clone, Error)]
+#[error("failed to accept the user invitation")]
+pub struct AcceptUserInvitationError {
+    kind: AcceptUserInvitationErrorKind,
+}
+
+impl AcceptUserInvitationError {
+    pub fn kind(&self) -> AcceptUserInvitationErrorKind {
+        self.kind
+    }
+}
+
 impl UserInvitation {
     pub async fn new<C>(
         ctx: &C,
         author: &User,
         email: UserEmailAddress,
         role: UserInvitationRole,
+        allows_unlisted_domain: bool,
     ) -> DomainResult<Self, NewUserInvitationError>
     where
         C: UserInvitationRepository + UserRepository,
@@ -106,6 +169,9 @@ impl UserInvitation {
             author_id: author.id().clone(),
             email,
             role,
+            allows_unlisted_domain,
+            expires_at: None,
+            acceptance: None,
         }))
     }
 
@@ -143,6 +209,86 @@ impl UserInvitation {
         self.content.role
     }
 
+    pub fn allows_unlisted_domain(&self) -> bool {
+        self.content.allows_unlisted_domain
+    }
+
+    pub fn expires_at(&self) -> Option<DateTime> {
+        self.content.expires_at
+    }
+
+    pub fn acceptance(&self) -> Option<&UserInvitationAcceptance> {
+        self.content.acceptance.as_ref()
+    }
+
+    pub fn status_at(&self, time: DateTime) -> UserInvitationStatus {
+        if self.content.acceptance.is_some() {
+            UserInvitationStatus::Accepted
+        } else if matches!(self.content.expires_at, Some(expires_at) if expires_at <= time) {
+            UserInvitationStatus::Expired
+        } else {
+            UserInvitationStatus::Pending
+        }
+    }
+
+    pub fn status(&self) -> UserInvitationStatus {
+        self.status_at(DateTime::now())
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.status() == UserInvitationStatus::Expired
+    }
+
+    /// Whether the invitation had expired and not been accepted before the given time.
+    pub fn is_expired_before(&self, time: DateTime) -> bool {
+        self.status_at(time) == UserInvitationStatus::Expired
+    }
+
+    pub fn set_expires_at(
+        &mut self,
+        user: &User,
+        expires_at: Option<DateTime>,
+    ) -> Result<(), SetExpiresAtError> {
+        user.require_permissions(Permissions::CREATE_USER_INVITATIONS)
+            .map_err(SetExpiresAtError::from_permissions_error)?;
+
+        if self.content.acceptance.is_some() {
+            return Err(SetExpiresAtError {
+                kind: SetExpiresAtErrorKind::AlreadyAccepted,
+            });
+        }
+
+        if matches!(expires_at, Some(expires_at) if expires_at <= DateTime::now()) {
+            return Err(SetExpiresAtError {
+                kind: SetExpiresAtErrorKind::PastExpiry,
+            });
+        }
+
+        self.content.expires_at = expires_at;
+        Ok(())
+    }
+
+    /// Record that the user has signed up with the invited address.
+    pub fn accept(&mut self, user: &User) -> Result<(), AcceptUserInvitationError> {
+        if self.status() != UserInvitationStatus::Pending {
+            return Err(AcceptUserInvitationError {
+                kind: AcceptUserInvitationErrorKind::NotPending,
+            });
+        }
+
+        if user.email() != &self.content.email {
+            return Err(AcceptUserInvitationError {
+                kind: AcceptUserInvitationErrorKind::MismatchedEmailAddress,
+            });
+        }
+
+        self.content.acceptance = Some(UserInvitationAcceptance {
+            accepted_at: DateTime::now(),
+            user_id: user.id().clone(),
+        });
+        Ok(())
+    }
+
     pub fn is_visible_to(&self, user: &User) -> bool {
         user.permissions()
             .contains(Permissions::READ_ALL_USER_INVITATIONS)
@@ -151,9 +297,12 @@ impl UserInvitation {
 
 #[cfg(test)]
 mod tests {
-    use super::{NewUserInvitationErrorKind, UserInvitation, UserInvitationRole};
+    use super::{
+        AcceptUserInvitationErrorKind, NewUserInvitationErrorKind, SetExpiresAtErrorKind,
+        UserInvitation, UserInvitationRole, UserInvitationStatus,
+    };
 
-    use crate::model::user::UserEmailAddress;
+    use crate::model::{date_time::DateTime, user::UserEmailAddress};
     use crate::test::model as test_model;
     use crate::DomainError;
 
@@ -196,7 +345,7 @@ mod tests {
             .users(vec![general.clone()])
             .build();
         assert!(matches!(
-            UserInvitation::new(&app, &general, email, UserInvitationRole::Committee).await,
+            UserInvitation::new(&app, &general, email, UserInvitationRole::Committee, false).await,
             Err(DomainError::Domain(err))
             if err.kind() == NewUserInvitationErrorKind::InsufficientPermissions
         ));
@@ -211,7 +360,7 @@ mod tests {
             .users(vec![operator.clone()])
             .build();
         assert!(matches!(
-            UserInvitation::new(&app, &operator, email, UserInvitationRole::Committee).await,
+            UserInvitation::new(&app, &operator, email, UserInvitationRole::Committee, false).await,
             Err(DomainError::Domain(err))
             if err.kind() == NewUserInvitationErrorKind::InsufficientPermissions
         ));
@@ -226,7 +375,7 @@ mod tests {
             .users(vec![admin.clone()])
             .build();
         assert!(matches!(
-            UserInvitation::new(&app, &admin, email, UserInvitationRole::Committee).await,
+            UserInvitation::new(&app, &admin, email, UserInvitationRole::Committee, false).await,
             Err(DomainError::Domain(err))
             if err.kind() == NewUserInvitationErrorKind::AlreadySignedUpEmailAddress
         ));
@@ -246,7 +395,7 @@ mod tests {
             .user_invitations(vec![invitation])
             .build();
         assert!(matches!(
-            UserInvitation::new(&app, &admin, email, UserInvitationRole::Committee).await,
+            UserInvitation::new(&app, &admin, email, UserInvitationRole::Committee, false).await,
             Err(DomainError::Domain(err))
             if err.kind() == NewUserInvitationErrorKind::AlreadyInvitedEmailAddress
         ));
@@ -261,10 +410,85 @@ mod tests {
             .users(vec![admin.clone()])
             .build();
         assert!(matches!(
-            UserInvitation::new(&app, &admin, email.clone(), UserInvitationRole::Committee).await,
+            UserInvitation::new(&app, &admin, email.clone(), UserInvitationRole::Committee, false).await,
             Ok(invitation)
             if invitation.role() == UserInvitationRole::Committee
             && invitation.email() == &email
         ));
     }
+
+    #[test]
+    fn test_status_expired() {
+        let admin = test_model::new_admin_user();
+        let email = test_model::mock_user_email_address().into_string();
+
+        let invitation = test_model::new_expired_user_invitation(admin.id().clone(), email);
+        assert_eq!(invitation.status(), UserInvitationStatus::Expired);
+    }
+
+    #[test]
+    fn test_set_expires_at_past() {
+        let admin = test_model::new_admin_user();
+        let email = test_model::mock_user_email_address().into_string();
+
+        let mut invitation = test_model::new_operator_user_invitation(admin.id().clone(), email);
+        let expires_at = DateTime::from_utc(chrono::Utc::now() - chrono::Duration::hours(1));
+        assert!(matches!(
+            invitation.set_expires_at(&admin, Some(expires_at)),
+            Err(err) if err.kind() == SetExpiresAtErrorKind::PastExpiry
+        ));
+    }
+
+    #[test]
+    fn test_set_expires_at_operator() {
+        let admin = test_model::new_admin_user();
+        let operator = test_model::new_operator_user();
+        let email = test_model::mock_user_email_address().into_string();
+
+        let mut invitation = test_model::new_operator_user_invitation(admin.id().clone(), email);
+        let expires_at = DateTime::from_utc(chrono::Utc::now() + chrono::Duration::days(1));
+        assert!(matches!(
+            invitation.set_expires_at(&operator, Some(expires_at)),
+            Err(err) if err.kind() == SetExpiresAtErrorKind::InsufficientPermissions
+        ));
+    }
+
+    #[test]
+    fn test_accept() {
+        let admin = test_model::new_admin_user();
+        let user = test_model::new_general_user();
+
+        let mut invitation = test_model::new_operator_user_invitation(
+            admin.id().clone(),
+            user.email().clone().into_string(),
+        );
+        invitation.accept(&user).unwrap();
+        assert_eq!(invitation.status(), UserInvitationStatus::Accepted);
+        assert_eq!(&invitation.acceptance().unwrap().user_id, user.id());
+
+        // accepted invitations cannot be accepted twice nor be extended
+        assert!(matches!(
+            invitation.accept(&user),
+            Err(err) if err.kind() == AcceptUserInvitationErrorKind::NotPending
+        ));
+        assert!(matches!(
+            invitation.set_expires_at(&admin, None),
+            Err(err) if err.kind() == SetExpiresAtErrorKind::AlreadyAccepted
+        ));
+    }
+
+    #[test]
+    fn test_accept_expired() {
+        let admin = test_model::new_admin_user();
+        let user = test_model::new_general_user();
+
+        let mut invitation = test_model::new_expired_user_invitation(
+            admin.id().clone(),
+            user.email().clone().into_string(),
+        );
+        assert!(matches!(
+            invitation.accept(&user),
+            Err(err) if err.kind() == AcceptUserInvitationErrorKind::NotPending
+        ));
+    }
 }
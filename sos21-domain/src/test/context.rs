@@ -3,33 +3,44 @@ use std::convert::TryInto;
 use std::sync::Arc;
 
 use crate::context::form_repository::ProjectForm;
-use crate::context::pending_project_repository::PendingProjectWithOwner;
+use crate::context::pending_project_repository::{
+    PendingProjectWithOwner, PendingProjectWithRegistrationProgress,
+};
 use crate::context::project_repository::ProjectWithOwners;
 use crate::context::registration_form_repository::PendingProjectRegistrationForm;
 use crate::context::{
-    Authentication, ConfigContext, FileDistributionRepository, FileRepository,
-    FileSharingRepository, FormAnswerRepository, FormRepository, Login, ObjectRepository,
-    PendingProjectRepository, ProjectRepository, RegistrationFormAnswerRepository,
-    RegistrationFormRepository, UserInvitationRepository, UserRepository,
+    ApiKeyRepository, Authentication, ConfigContext, CustomRoleRepository, FestivalRepository,
+    FileDistributionRepository, FileRepository, FileSharingRepository, FormAnswerRepository,
+    FormRepository, FormTemplateRepository, Login, ObjectRepository, PendingProjectRepository,
+    ProjectRepository, RegistrationFormAnswerRepository, RegistrationFormRepository,
+    SettingsRepository, UserInvitationRepository, UserRepository,
 };
 use crate::model::{
+    api_key::{ApiKey, ApiKeyId, ApiKeyToken, ApiKeyTokenDigest},
+    custom_role::{CustomRole, CustomRoleId},
+    date_time::DateTime,
+    festival::{Festival, FestivalId},
     file::{File, FileId},
     file_distribution::{FileDistribution, FileDistributionId},
     file_sharing::{FileSharing, FileSharingId, FileSharingScope},
-    form::{Form, FormId},
-    form_answer::{FormAnswer, FormAnswerId},
+    form::{Form, FormId, FormRevision, FormVersion},
+    form_answer::{FormAnswer, FormAnswerDraft, FormAnswerId},
+    form_template::{FormTemplate, FormTemplateId},
     object::{Object, ObjectData, ObjectId},
     pending_project::{PendingProject, PendingProjectId},
     project::{Project, ProjectCategory, ProjectId, ProjectIndex},
     project_creation_period::ProjectCreationPeriod,
     registration_form::{RegistrationForm, RegistrationFormId},
-    registration_form_answer::{RegistrationFormAnswer, RegistrationFormAnswerId},
-    user::{User, UserEmailAddress, UserFileUsage, UserId, UserRole},
+    registration_form_answer::{
+        RegistrationFormAnswer, RegistrationFormAnswerDraft, RegistrationFormAnswerId,
+    },
+    settings::Settings,
+    user::{email::AllowedEmailDomains, User, UserEmailAddress, UserFileUsage, UserId, UserRole},
     user_invitation::{UserInvitation, UserInvitationId},
 };
 use crate::test::model as test_model;
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use bytes::{BufMut, Bytes, BytesMut};
 use futures::lock::Mutex;
 use futures::{
@@ -40,9 +51,12 @@ use thiserror::Error;
 
 #[derive(Default, Debug, Clone)]
 pub struct MockAppBuilder {
+    festivals: Vec<Festival>,
+    active_festival_id: Option<FestivalId>,
     users: Vec<User>,
     projects: Vec<Project>,
     forms: Vec<Form>,
+    form_templates: HashMap<FormTemplateId, FormTemplate>,
     answers: HashMap<FormAnswerId, FormAnswer>,
     files: HashMap<FileId, File>,
     objects: HashMap<ObjectId, Bytes>,
@@ -52,7 +66,10 @@ pub struct MockAppBuilder {
     registration_forms: HashMap<RegistrationFormId, RegistrationForm>,
     registration_form_answers: HashMap<RegistrationFormAnswerId, RegistrationFormAnswer>,
     user_invitations: HashMap<UserInvitationId, UserInvitation>,
+    custom_roles: HashMap<CustomRoleId, CustomRole>,
+    api_keys: HashMap<ApiKeyId, ApiKey>,
     project_creation_periods: HashMap<ProjectCategory, ProjectCreationPeriod>,
+    allowed_email_domains: AllowedEmailDomains,
 }
 
 impl MockAppBuilder {
@@ -60,6 +77,20 @@ impl MockAppBuilder {
         MockAppBuilder::default()
     }
 
+    pub fn festivals<I>(&mut self, festivals: I) -> &mut Self
+    where
+        I: IntoIterator<Item = Festival>,
+    {
+        self.festivals.extend(festivals);
+        self
+    }
+
+    /// Set the active festival, which defaults to [`test_model::KNOWN_MOCK_FESTIVAL_ID`].
+    pub fn active_festival(&mut self, festival_id: FestivalId) -> &mut Self {
+        self.active_festival_id = Some(festival_id);
+        self
+    }
+
     pub fn users<I>(&mut self, users: I) -> &mut Self
     where
         I: IntoIterator<Item = User>,
@@ -84,6 +115,18 @@ impl MockAppBuilder {
         self
     }
 
+    pub fn form_templates<I>(&mut self, templates: I) -> &mut Self
+    where
+        I: IntoIterator<Item = FormTemplate>,
+    {
+        self.form_templates.extend(
+            templates
+                .into_iter()
+                .map(|template| (template.id(), template)),
+        );
+        self
+    }
+
     pub fn answers<I>(&mut self, answers: I) -> &mut Self
     where
         I: IntoIterator<Item = FormAnswer>,
@@ -196,6 +239,24 @@ impl MockAppBuilder {
         self
     }
 
+    pub fn custom_roles<I>(&mut self, custom_roles: I) -> &mut Self
+    where
+        I: IntoIterator<Item = CustomRole>,
+    {
+        self.custom_roles
+            .extend(custom_roles.into_iter().map(|role| (role.id(), role)));
+        self
+    }
+
+    pub fn api_keys<I>(&mut self, api_keys: I) -> &mut Self
+    where
+        I: IntoIterator<Item = ApiKey>,
+    {
+        self.api_keys
+            .extend(api_keys.into_iter().map(|key| (key.id(), key)));
+        self
+    }
+
     pub fn project_creation_period_for(
         &mut self,
         category: ProjectCategory,
@@ -206,7 +267,19 @@ impl MockAppBuilder {
         self
     }
 
+    pub fn allowed_email_domains(
+        &mut self,
+        allowed_email_domains: AllowedEmailDomains,
+    ) -> &mut Self {
+        self.allowed_email_domains = allowed_email_domains;
+        self
+    }
+
     pub fn build(&self) -> MockApp {
+        let festivals = std::iter::once(test_model::mock_festival())
+            .chain(self.festivals.clone())
+            .map(|festival| (festival.id(), festival))
+            .collect();
         let users = self
             .users
             .clone()
@@ -225,12 +298,22 @@ impl MockAppBuilder {
             .into_iter()
             .map(|form| (form.id(), form))
             .collect();
+        // forms with the same ID are considered as the revisions of the form
+        let form_revisions = self
+            .forms
+            .iter()
+            .map(|form| ((form.id(), form.version()), FormRevision::of_form(form)))
+            .collect();
 
         MockApp {
+            festivals: Arc::new(Mutex::new(festivals)),
             users: Arc::new(Mutex::new(users)),
             projects: Arc::new(Mutex::new(projects)),
             forms: Arc::new(Mutex::new(forms)),
+            form_revisions: Arc::new(Mutex::new(form_revisions)),
+            form_templates: Arc::new(Mutex::new(self.form_templates.clone())),
             answers: Arc::new(Mutex::new(self.answers.clone())),
+            answer_drafts: Arc::new(Mutex::new(HashMap::new())),
             files: Arc::new(Mutex::new(self.files.clone())),
             objects: Arc::new(Mutex::new(self.objects.clone())),
             sharings: Arc::new(Mutex::new(self.sharings.clone())),
@@ -238,18 +321,30 @@ impl MockAppBuilder {
             pending_projects: Arc::new(Mutex::new(self.pending_projects.clone())),
             registration_forms: Arc::new(Mutex::new(self.registration_forms.clone())),
             registration_form_answers: Arc::new(Mutex::new(self.registration_form_answers.clone())),
+            registration_form_answer_drafts: Arc::new(Mutex::new(HashMap::new())),
             user_invitations: Arc::new(Mutex::new(self.user_invitations.clone())),
+            custom_roles: Arc::new(Mutex::new(self.custom_roles.clone())),
+            api_keys: Arc::new(Mutex::new(self.api_keys.clone())),
+            settings_history: Arc::new(Mutex::new(Vec::new())),
             project_creation_periods: self.project_creation_periods.clone(),
+            allowed_email_domains: self.allowed_email_domains.clone(),
+            active_festival_id: self
+                .active_festival_id
+                .unwrap_or(*test_model::KNOWN_MOCK_FESTIVAL_ID),
         }
     }
 }
 
 #[derive(Debug, Clone)]
 pub struct MockApp {
+    festivals: Arc<Mutex<HashMap<FestivalId, Festival>>>,
     users: Arc<Mutex<HashMap<UserId, User>>>,
     projects: Arc<Mutex<HashMap<ProjectId, Project>>>,
     forms: Arc<Mutex<HashMap<FormId, Form>>>,
+    form_revisions: Arc<Mutex<HashMap<(FormId, FormVersion), FormRevision>>>,
+    form_templates: Arc<Mutex<HashMap<FormTemplateId, FormTemplate>>>,
     answers: Arc<Mutex<HashMap<FormAnswerId, FormAnswer>>>,
+    answer_drafts: Arc<Mutex<HashMap<(FormId, ProjectId), FormAnswerDraft>>>,
     files: Arc<Mutex<HashMap<FileId, File>>>,
     objects: Arc<Mutex<HashMap<ObjectId, Bytes>>>,
     sharings: Arc<Mutex<HashMap<FileSharingId, FileSharing>>>,
@@ -258,16 +353,25 @@ pub struct MockApp {
     registration_forms: Arc<Mutex<HashMap<RegistrationFormId, RegistrationForm>>>,
     registration_form_answers:
         Arc<Mutex<HashMap<RegistrationFormAnswerId, RegistrationFormAnswer>>>,
+    registration_form_answer_drafts:
+        Arc<Mutex<HashMap<(RegistrationFormId, PendingProjectId), RegistrationFormAnswerDraft>>>,
     user_invitations: Arc<Mutex<HashMap<UserInvitationId, UserInvitation>>>,
+    custom_roles: Arc<Mutex<HashMap<CustomRoleId, CustomRole>>>,
+    api_keys: Arc<Mutex<HashMap<ApiKeyId, ApiKey>>>,
+    settings_history: Arc<Mutex<Vec<Settings>>>,
     project_creation_periods: HashMap<ProjectCategory, ProjectCreationPeriod>,
+    allowed_email_domains: AllowedEmailDomains,
+    active_festival_id: FestivalId,
 }
 
 impl MockApp {
     /// # Panics
     ///
     /// This function panics when the given email is not valid.
-    pub fn authenticate_as(self, user_id: String, email: String) -> Authentication<MockApp> {
-        Authentication::new(self, user_id, email).unwrap()
+    pub async fn authenticate_as(self, user_id: String, email: String) -> Authentication<MockApp> {
+        Authentication::new(self, user_id, email, DateTime::now())
+            .await
+            .unwrap()
     }
 
     /// # Panics
@@ -279,12 +383,108 @@ impl MockApp {
                 self,
                 user.id().clone().0,
                 user.email().clone().into_string(),
+                DateTime::now(),
             )
+            .await
             .unwrap(),
         )
         .await
         .unwrap()
     }
+
+    /// # Panics
+    ///
+    /// This function panics when the login is not successful.
+    pub async fn login_with_api_key(self, token: &ApiKeyToken) -> Login<MockApp> {
+        Login::with_api_key(self, token).await.unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+impl FormTemplateRepository for MockApp {
+    async fn store_form_template(&self, template: FormTemplate) -> Result<()> {
+        self.form_templates
+            .lock()
+            .await
+            .insert(template.id(), template);
+        Ok(())
+    }
+
+    async fn get_form_template(&self, id: FormTemplateId) -> Result<Option<FormTemplate>> {
+        Ok(self.form_templates.lock().await.get(&id).cloned())
+    }
+
+    async fn list_form_templates(&self) -> Result<Vec<FormTemplate>> {
+        Ok(self.form_templates.lock().await.values().cloned().collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl ApiKeyRepository for MockApp {
+    async fn store_api_key(&self, key: ApiKey) -> Result<()> {
+        self.api_keys.lock().await.insert(key.id(), key);
+        Ok(())
+    }
+
+    async fn get_api_key(&self, id: ApiKeyId) -> Result<Option<ApiKey>> {
+        Ok(self.api_keys.lock().await.get(&id).cloned())
+    }
+
+    async fn get_api_key_by_token_digest(
+        &self,
+        digest: &ApiKeyTokenDigest,
+    ) -> Result<Option<ApiKey>> {
+        Ok(self
+            .api_keys
+            .lock()
+            .await
+            .values()
+            .find(|key| key.token_digest() == digest)
+            .cloned())
+    }
+
+    async fn list_api_keys(&self) -> Result<Vec<ApiKey>> {
+        Ok(self.api_keys.lock().await.values().cloned().collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl CustomRoleRepository for MockApp {
+    async fn store_custom_role(&self, role: CustomRole) -> Result<()> {
+        self.custom_roles.lock().await.insert(role.id(), role);
+        Ok(())
+    }
+
+    async fn delete_custom_role(&self, id: CustomRoleId) -> Result<()> {
+        self.custom_roles.lock().await.remove(&id);
+        Ok(())
+    }
+
+    async fn get_custom_role(&self, id: CustomRoleId) -> Result<Option<CustomRole>> {
+        Ok(self.custom_roles.lock().await.get(&id).cloned())
+    }
+
+    async fn list_custom_roles(&self) -> Result<Vec<CustomRole>> {
+        Ok(self.custom_roles.lock().await.values().cloned().collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl FestivalRepository for MockApp {
+    async fn store_festival(&self, festival: Festival) -> Result<()> {
+        self.festivals.lock().await.insert(festival.id(), festival);
+        Ok(())
+    }
+
+    async fn get_festival(&self, id: FestivalId) -> Result<Option<Festival>> {
+        Ok(self.festivals.lock().await.get(&id).cloned())
+    }
+
+    async fn list_festivals(&self) -> Result<Vec<Festival>> {
+        let mut festivals: Vec<_> = self.festivals.lock().await.values().cloned().collect();
+        festivals.sort_by_key(|festival| std::cmp::Reverse(festival.created_at()));
+        Ok(festivals)
+    }
 }
 
 #[async_trait::async_trait]
@@ -342,13 +542,17 @@ impl ProjectRepository for MockApp {
         }
     }
 
-    async fn get_project_by_index(&self, index: ProjectIndex) -> Result<Option<ProjectWithOwners>> {
+    async fn get_project_by_index(
+        &self,
+        festival_id: FestivalId,
+        index: ProjectIndex,
+    ) -> Result<Option<ProjectWithOwners>> {
         let project = self
             .projects
             .lock()
             .await
             .values()
-            .find(|project| project.index() == index)
+            .find(|project| project.festival_id() == festival_id && project.index() == index)
             .cloned();
         if let Some(project) = project {
             let owner = self.get_user(project.owner_id().clone()).await?.unwrap();
@@ -368,13 +572,14 @@ impl ProjectRepository for MockApp {
         Ok(len)
     }
 
-    async fn get_next_index(&self) -> Result<u64> {
+    async fn get_next_index(&self, festival_id: FestivalId) -> Result<u64> {
         let latest_index = self
             .projects
             .lock()
             .await
-            .iter()
-            .map(|(_, x)| x.clone().index().to_u16())
+            .values()
+            .filter(|x| x.festival_id() == festival_id)
+            .map(|x| x.clone().index().to_u16())
             .max();
 
         let index = match latest_index {
@@ -404,10 +609,54 @@ impl ProjectRepository for MockApp {
 #[async_trait::async_trait]
 impl FormRepository for MockApp {
     async fn store_form(&self, form: Form) -> Result<()> {
-        self.forms.lock().await.insert(form.id(), form);
+        let mut forms = self.forms.lock().await;
+        let old_version = form.revised_from();
+        if let Some(old_form) = forms.get(&form.id()) {
+            let expected_version = old_version.unwrap_or_else(|| form.version());
+            if old_form.version() != expected_version {
+                bail!("form is updated concurrently");
+            }
+        }
+
+        if !forms.contains_key(&form.id()) || old_version.is_some() {
+            let mut revisions = self.form_revisions.lock().await;
+            let key = (form.id(), form.version());
+            if revisions.contains_key(&key) {
+                bail!("duplicated form revision");
+            }
+            revisions.insert(key, FormRevision::of_form(&form));
+        }
+
+        forms.insert(form.id(), form);
         Ok(())
     }
 
+    async fn get_form_revision(
+        &self,
+        id: FormId,
+        version: FormVersion,
+    ) -> Result<Option<FormRevision>> {
+        Ok(self
+            .form_revisions
+            .lock()
+            .await
+            .get(&(id, version))
+            .cloned())
+    }
+
+    async fn list_form_revisions(&self, id: FormId) -> Result<Vec<FormRevision>> {
+        let mut revisions: Vec<_> = self
+            .form_revisions
+            .lock()
+            .await
+            .values()
+            .filter(|revision| revision.form_id == id)
+            .cloned()
+            .collect();
+        revisions.sort_by_key(|revision| revision.version);
+        Ok(revisions)
+    }
+
     async fn get_form(&self, id: FormId) -> Result<Option<Form>> {
         Ok(self.forms.lock().await.get(&id).cloned())
     }
@@ -473,6 +722,35 @@ impl FormAnswerRepository for MockApp {
             .cloned()
             .collect())
     }
+
+    async fn store_form_answer_draft(&self, draft: FormAnswerDraft) -> Result<()> {
+        self.answer_drafts
+            .lock()
+            .await
+            .insert((draft.form_id(), draft.project_id()), draft);
+        Ok(())
+    }
+
+    async fn get_form_answer_draft(
+        &self,
+        form_id: FormId,
+        project_id: ProjectId,
+    ) -> Result<Option<FormAnswerDraft>> {
+        Ok(self
+            .answer_drafts
+            .lock()
+            .await
+            .get(&(form_id, project_id))
+            .cloned())
+    }
+
+    async fn delete_form_answer_draft(&self, form_id: FormId, project_id: ProjectId) -> Result<()> {
+        self.answer_drafts
+            .lock()
+            .await
+            .remove(&(form_id, project_id));
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -697,6 +975,71 @@ impl PendingProjectRepository for MockApp {
             None => Ok(None),
         }
     }
+
+    async fn list_pending_projects(
+        &self,
+        festival_id: FestivalId,
+    ) -> Result<Vec<PendingProjectWithRegistrationProgress>> {
+        let pending_projects: Vec<_> = self
+            .pending_projects
+            .lock()
+            .await
+            .values()
+            .filter(|pending_project| pending_project.festival_id() == festival_id)
+            .cloned()
+            .collect();
+
+        let mut result = Vec::new();
+        for pending_project in pending_projects {
+            let owner = self
+                .get_user(pending_project.owner_id().clone())
+                .await?
+                .unwrap();
+            let registration_form_count = self
+                .count_registration_forms_by_pending_project(pending_project.id())
+                .await?;
+            let answered_registration_form_count = self
+                .count_registration_form_answers_by_pending_project(pending_project.id())
+                .await?;
+            result.push(PendingProjectWithRegistrationProgress {
+                pending_project,
+                owner,
+                registration_form_count,
+                answered_registration_form_count,
+            });
+        }
+        Ok(result)
+    }
+
+    async fn list_pending_projects_with_exceptional_deadline(
+        &self,
+        festival_id: FestivalId,
+    ) -> Result<Vec<PendingProjectWithOwner>> {
+        let pending_projects: Vec<_> = self
+            .pending_projects
+            .lock()
+            .await
+            .values()
+            .filter(|pending_project| {
+                pending_project.festival_id() == festival_id
+                    && pending_project.exceptional_complete_deadline().is_some()
+            })
+            .cloned()
+            .collect();
+
+        let mut result = Vec::new();
+        for pending_project in pending_projects {
+            let owner = self
+                .get_user(pending_project.owner_id().clone())
+                .await?
+                .unwrap();
+            result.push(PendingProjectWithOwner {
+                pending_project,
+                owner,
+            });
+        }
+        Ok(result)
+    }
 }
 
 #[async_trait::async_trait]
@@ -930,6 +1273,42 @@ impl RegistrationFormAnswerRepository for MockApp {
         let len = len.try_into()?;
         Ok(len)
     }
+
+    async fn store_registration_form_answer_draft(
+        &self,
+        draft: RegistrationFormAnswerDraft,
+    ) -> Result<()> {
+        self.registration_form_answer_drafts.lock().await.insert(
+            (draft.registration_form_id(), draft.pending_project_id()),
+            draft,
+        );
+        Ok(())
+    }
+
+    async fn get_registration_form_answer_draft(
+        &self,
+        registration_form_id: RegistrationFormId,
+        pending_project_id: PendingProjectId,
+    ) -> Result<Option<RegistrationFormAnswerDraft>> {
+        Ok(self
+            .registration_form_answer_drafts
+            .lock()
+            .await
+            .get(&(registration_form_id, pending_project_id))
+            .cloned())
+    }
+
+    async fn delete_registration_form_answer_draft(
+        &self,
+        registration_form_id: RegistrationFormId,
+        pending_project_id: PendingProjectId,
+    ) -> Result<()> {
+        self.registration_form_answer_drafts
+            .lock()
+            .await
+            .remove(&(registration_form_id, pending_project_id));
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -975,6 +1354,29 @@ impl UserInvitationRepository for MockApp {
     }
 }
 
+#[async_trait::async_trait]
+impl SettingsRepository for MockApp {
+    async fn store_settings(&self, settings: Settings) -> Result<()> {
+        self.settings_history.lock().await.push(settings);
+        Ok(())
+    }
+
+    async fn get_settings(&self) -> Result<Option<Settings>> {
+        Ok(self.settings_history.lock().await.last().cloned())
+    }
+
+    async fn list_settings_history(&self) -> Result<Vec<Settings>> {
+        Ok(self
+            .settings_history
+            .lock()
+            .await
+            .iter()
+            .rev()
+            .cloned()
+            .collect())
+    }
+}
+
 impl ConfigContext for MockApp {
     fn administrator_email(&self) -> &UserEmailAddress {
         &test_model::ADMINISTRATOR_EMAIL
@@ -986,4 +1388,12 @@ impl ConfigContext for MockApp {
             .copied()
             .unwrap_or_else(ProjectCreationPeriod::always)
     }
+
+    fn allowed_email_domains(&self) -> &AllowedEmailDomains {
+        &self.allowed_email_domains
+    }
+
+    fn active_festival_id(&self) -> FestivalId {
+        self.active_festival_id
+    }
 }
@@ -26,3 +26,13 @@ mod user_invitation;
 pub use user_invitation::*;
 mod project_creation_period;
 pub use project_creation_period::*;
+mod festival;
+pub use festival::*;
+mod form_template;
+pub use form_template::*;
+mod custom_role;
+pub use custom_role::*;
+mod access_control;
+pub use access_control::*;
+mod api_key;
+pub use api_key::*;
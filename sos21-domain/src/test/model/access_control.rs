@@ -0,0 +1,14 @@
+use crate::model::{
+    access_control::{
+        AccessControlEntry, AccessControlGrantee, AccessControlLevel, AccessControlList,
+    },
+    user::UserId,
+};
+
+pub fn new_user_access_control(user_id: UserId, level: AccessControlLevel) -> AccessControlList {
+    AccessControlList::from_entries(vec![AccessControlEntry {
+        grantee: AccessControlGrantee::User(user_id),
+        level,
+    }])
+    .unwrap()
+}
@@ -0,0 +1,43 @@
+use crate::model::{
+    api_key::{ApiKey, ApiKeyContent, ApiKeyId, ApiKeyName, ApiKeyScope, ApiKeyToken},
+    date_time::DateTime,
+    user::UserId,
+};
+
+use uuid::Uuid;
+
+pub fn new_api_key_id() -> ApiKeyId {
+    ApiKeyId::from_uuid(Uuid::new_v4())
+}
+
+pub fn mock_api_key_name() -> ApiKeyName {
+    ApiKeyName::from_string("mock nightly export").unwrap()
+}
+
+pub fn new_api_key(author_id: UserId, scope: ApiKeyScope) -> (ApiKey, ApiKeyToken) {
+    let token = ApiKeyToken::generate();
+    let key = ApiKey::from_content(ApiKeyContent {
+        id: new_api_key_id(),
+        created_at: DateTime::now(),
+        author_id,
+        name: mock_api_key_name(),
+        scope,
+        token_digest: token.digest(),
+        expires_at: None,
+        last_used_at: None,
+        revoked_at: None,
+    });
+    (key, token)
+}
+
+pub fn new_expired_api_key(author_id: UserId) -> (ApiKey, ApiKeyToken) {
+    let (key, token) = new_api_key(
+        author_id,
+        ApiKeyScope::Role(crate::model::user::UserRole::Committee),
+    );
+    let mut content = key.into_content();
+    content.expires_at = Some(DateTime::from_utc(
+        chrono::Utc::now() - chrono::Duration::hours(1),
+    ));
+    (ApiKey::from_content(content), token)
+}
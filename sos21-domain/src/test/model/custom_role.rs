@@ -0,0 +1,26 @@
+use crate::model::{
+    custom_role::{CustomRole, CustomRoleContent, CustomRoleId, CustomRoleName},
+    date_time::DateTime,
+    permissions::Permissions,
+    user::UserId,
+};
+
+use uuid::Uuid;
+
+pub fn new_custom_role_id() -> CustomRoleId {
+    CustomRoleId::from_uuid(Uuid::new_v4())
+}
+
+pub fn mock_custom_role_name() -> CustomRoleName {
+    CustomRoleName::from_string("mock 会計").unwrap()
+}
+
+pub fn new_custom_role(author_id: UserId, permissions: Permissions) -> CustomRole {
+    CustomRole::from_content(CustomRoleContent {
+        id: new_custom_role_id(),
+        created_at: DateTime::now(),
+        author_id,
+        name: mock_custom_role_name(),
+        permissions,
+    })
+}
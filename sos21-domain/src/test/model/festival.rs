@@ -0,0 +1,39 @@
+use crate::model::{
+    date_time::DateTime,
+    festival::{Festival, FestivalContent, FestivalId, FestivalName},
+};
+
+use once_cell::sync::Lazy;
+use uuid::Uuid;
+
+/// `FestivalId` that is known to be the active festival in the testing context.
+///
+/// The test data are created in this festival unless otherwise specified.
+pub static KNOWN_MOCK_FESTIVAL_ID: Lazy<FestivalId> =
+    Lazy::new(|| FestivalId::from_uuid(Uuid::from_u128(0x5050_2100_0000_0000_0000_0000_0000_0001)));
+
+pub fn new_festival_id() -> FestivalId {
+    FestivalId::from_uuid(Uuid::new_v4())
+}
+
+pub fn mock_festival_name() -> FestivalName {
+    FestivalName::from_string("mock 雙峰祭").unwrap()
+}
+
+pub fn mock_festival() -> Festival {
+    Festival::from_content(FestivalContent {
+        id: *KNOWN_MOCK_FESTIVAL_ID,
+        created_at: DateTime::now(),
+        author_id: None,
+        name: mock_festival_name(),
+    })
+}
+
+pub fn new_festival() -> Festival {
+    Festival::from_content(FestivalContent {
+        id: new_festival_id(),
+        created_at: DateTime::now(),
+        author_id: None,
+        name: mock_festival_name(),
+    })
+}
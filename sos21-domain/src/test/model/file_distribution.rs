@@ -48,6 +48,7 @@ pub fn new_file_distribution_with_files(
 ) -> FileDistribution {
     FileDistribution {
         id: new_file_distribution_id(),
+        festival_id: *test_model::KNOWN_MOCK_FESTIVAL_ID,
         created_at: DateTime::now(),
         author_id,
         name: mock_file_distribution_name(),
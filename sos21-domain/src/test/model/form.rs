@@ -1,8 +1,9 @@
 use crate::model::{
+    access_control::AccessControlList,
     date_time::DateTime,
     form::{
         Form, FormCondition, FormConditionProjectSet, FormContent, FormDescription, FormId,
-        FormItems, FormName, FormPeriod,
+        FormItems, FormName, FormPeriod, FormVersion,
     },
     project_query::ProjectQuery,
     user::UserId,
@@ -65,6 +66,8 @@ pub fn mock_form_answer_notification_webhook() -> Option<String> {
 pub fn new_form_with_period(author_id: UserId, period: FormPeriod) -> Form {
     Form::from_content(FormContent {
         id: new_form_id(),
+        festival_id: *test_model::KNOWN_MOCK_FESTIVAL_ID,
+        version: FormVersion::initial(),
         created_at: DateTime::now(),
         author_id,
         name: mock_form_name(),
@@ -73,12 +76,15 @@ pub fn new_form_with_period(author_id: UserId, period: FormPeriod) -> Form {
         items: new_form_items(),
         condition: mock_form_condition(),
         answer_notification_webhook: mock_form_answer_notification_webhook(),
+        access_control: AccessControlList::empty(),
     })
 }
 
 pub fn new_form_with_items(author_id: UserId, items: FormItems) -> Form {
     Form::from_content(FormContent {
         id: new_form_id(),
+        festival_id: *test_model::KNOWN_MOCK_FESTIVAL_ID,
+        version: FormVersion::initial(),
         created_at: DateTime::now(),
         author_id,
         name: mock_form_name(),
@@ -87,12 +93,15 @@ pub fn new_form_with_items(author_id: UserId, items: FormItems) -> Form {
         items,
         condition: mock_form_condition(),
         answer_notification_webhook: mock_form_answer_notification_webhook(),
+        access_control: AccessControlList::empty(),
     })
 }
 
 pub fn new_form_with_condition(author_id: UserId, condition: FormCondition) -> Form {
     Form::from_content(FormContent {
         id: new_form_id(),
+        festival_id: *test_model::KNOWN_MOCK_FESTIVAL_ID,
+        version: FormVersion::initial(),
         created_at: DateTime::now(),
         author_id,
         name: mock_form_name(),
@@ -101,6 +110,7 @@ pub fn new_form_with_condition(author_id: UserId, condition: FormCondition) -> F
         items: new_form_items(),
         condition,
         answer_notification_webhook: mock_form_answer_notification_webhook(),
+        access_control: AccessControlList::empty(),
     })
 }
 
@@ -4,10 +4,12 @@ use crate::model::form::item::{
         GridRadioColumn, GridRadioColumnId, GridRadioColumnLabel, GridRadioRow, GridRadioRowId,
         GridRadioRowLabel,
     },
+    group::{GroupFormItem, GroupFormItemContent, GroupFormItemItems},
     radio::{Radio, RadioFormItemButtons, RadioId, RadioLabel},
     FormItem, FormItemBody, FormItemCondition, FormItemConditions, FormItemDescription, FormItemId,
-    FormItemName, FormItems, RadioFormItem,
+    FormItemName, FormItems, RadioFormItem, RespondentProject,
 };
+use crate::model::project::{ProjectAttributes, ProjectCategory};
 use uuid::Uuid;
 
 pub fn new_form_item_id() -> FormItemId {
@@ -92,6 +94,20 @@ pub fn new_radio_form_item_body() -> FormItemBody {
     new_radio_form_item_body_with_button(new_form_radio_button())
 }
 
+pub fn new_group_form_item_body_with_items<I>(items: I) -> FormItemBody
+where
+    I: IntoIterator<Item = FormItem>,
+{
+    FormItemBody::Group(
+        GroupFormItem::from_content(GroupFormItemContent {
+            items: GroupFormItemItems::from_items(items).unwrap(),
+            min_rows: None,
+            max_rows: None,
+        })
+        .unwrap(),
+    )
+}
+
 pub fn new_form_item_body() -> FormItemBody {
     new_radio_form_item_body()
 }
@@ -123,3 +139,10 @@ pub fn new_form_item() -> FormItem {
 pub fn new_form_items() -> FormItems {
     FormItems::from_items(vec![new_form_item()]).unwrap()
 }
+
+pub fn mock_respondent_project() -> RespondentProject {
+    RespondentProject {
+        category: ProjectCategory::General,
+        attributes: ProjectAttributes::from_attributes(vec![]).unwrap(),
+    }
+}
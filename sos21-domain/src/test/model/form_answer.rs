@@ -4,17 +4,18 @@ use crate::model::{
     form::{
         item::{
             radio::RadioId, CheckboxFormItem, FileFormItem, FormItem, FormItemBody, FormItems,
-            GridRadioFormItem, IntegerFormItem, RadioFormItem, TextFormItem,
+            GridRadioFormItem, GroupFormItem, IntegerFormItem, RadioFormItem, RespondentProject,
+            TextFormItem,
         },
         Form,
     },
     form_answer::{
         item::{
             FileSharingAnswer, FormAnswerItem, FormAnswerItemBody, FormAnswerItemChecks,
-            FormAnswerItemFileSharings, FormAnswerItemGridRows, FormAnswerItemText,
-            FormAnswerItems, GridRadioRowAnswer,
+            FormAnswerItemFileSharings, FormAnswerItemGridRows, FormAnswerItemGroupRows,
+            FormAnswerItemText, FormAnswerItems, GridRadioRowAnswer,
         },
-        FormAnswer, FormAnswerContent, FormAnswerId,
+        FormAnswer, FormAnswerContent, FormAnswerId, FormAnswerReview,
     },
     project::Project,
     user::UserId,
@@ -97,6 +98,19 @@ pub fn mock_form_answer_item_file(item: &FileFormItem) -> FormAnswerItemFileShar
     FormAnswerItemFileSharings::from_sharing_answers(answers).unwrap()
 }
 
+pub fn mock_form_answer_item_group(item: &GroupFormItem) -> FormAnswerItemGroupRows {
+    let rows = match (item.min_rows(), item.max_rows()) {
+        (Some(min_rows), _) => min_rows,
+        (None, Some(max_rows)) => std::cmp::min(max_rows, 1),
+        (None, None) => 1,
+    };
+    FormAnswerItemGroupRows::from_rows(
+        (0..rows)
+            .map(|_| FormAnswerItems::from_items(item.items().map(mock_form_answer_item)).unwrap()),
+    )
+    .unwrap()
+}
+
 pub fn mock_form_answer_item_body(body: &FormItemBody) -> FormAnswerItemBody {
     match body {
         FormItemBody::Text(item) => FormAnswerItemBody::Text(mock_form_answer_item_text(item)),
@@ -111,6 +125,7 @@ pub fn mock_form_answer_item_body(body: &FormItemBody) -> FormAnswerItemBody {
             FormAnswerItemBody::GridRadio(mock_form_answer_item_grid_radio(item))
         }
         FormItemBody::File(item) => FormAnswerItemBody::File(mock_form_answer_item_file(item)),
+        FormItemBody::Group(item) => FormAnswerItemBody::Group(mock_form_answer_item_group(item)),
     }
 }
 
@@ -131,14 +146,20 @@ pub fn new_form_answer_with_items(
     form: &Form,
     items: FormAnswerItems,
 ) -> FormAnswer {
-    form.items().check_answer(&items).unwrap().unwrap();
+    form.items()
+        .check_answer(&RespondentProject::from_project(project), &items)
+        .unwrap()
+        .unwrap();
     FormAnswer::from_content(FormAnswerContent {
         id: new_form_answer_id(),
         project_id: project.id(),
         form_id: form.id(),
+        form_version: form.version(),
         created_at: DateTime::now(),
         author_id,
         items,
+        needs_resubmission: false,
+        review: FormAnswerReview::submitted(),
     })
 }
 
@@ -0,0 +1,24 @@
+use crate::model::{
+    date_time::DateTime,
+    form_template::{FormTemplate, FormTemplateContent, FormTemplateId},
+    user::UserId,
+};
+use crate::test::model as test_model;
+
+use uuid::Uuid;
+
+pub fn new_form_template_id() -> FormTemplateId {
+    FormTemplateId::from_uuid(Uuid::new_v4())
+}
+
+pub fn new_form_template(author_id: UserId) -> FormTemplate {
+    FormTemplate::from_content(FormTemplateContent {
+        id: new_form_template_id(),
+        created_at: DateTime::now(),
+        author_id,
+        name: test_model::mock_form_name(),
+        description: test_model::mock_form_description(),
+        items: test_model::new_form_items(),
+        query: test_model::mock_project_query(),
+    })
+}
@@ -23,6 +23,7 @@ pub fn new_pending_project_with_attributes(
     PendingProject::from_content(
         PendingProjectContent {
             id: new_pending_project_id(),
+            festival_id: *test_model::KNOWN_MOCK_FESTIVAL_ID,
             created_at: DateTime::now(),
             updated_at: DateTime::now(),
             name: test_model::mock_project_name(),
@@ -57,6 +57,7 @@ pub fn new_project_with_attributes(
     Project::from_content(
         ProjectContent {
             id: new_project_id(),
+            festival_id: *test_model::KNOWN_MOCK_FESTIVAL_ID,
             index: new_project_index(),
             created_at: DateTime::now(),
             updated_at: DateTime::now(),
@@ -82,6 +83,7 @@ pub fn new_project_with_subowner(
     Project::from_content(
         ProjectContent {
             id: new_project_id(),
+            festival_id: *test_model::KNOWN_MOCK_FESTIVAL_ID,
             index: new_project_index(),
             created_at: DateTime::now(),
             updated_at: DateTime::now(),
@@ -1,4 +1,5 @@
 use crate::model::{
+    access_control::AccessControlList,
     date_time::DateTime,
     form::FormItems,
     project_query::ProjectQuery,
@@ -26,12 +27,14 @@ pub fn mock_registration_form_description() -> RegistrationFormDescription {
 pub fn new_registration_form_with_items(author_id: UserId, items: FormItems) -> RegistrationForm {
     RegistrationForm {
         id: new_registration_form_id(),
+        festival_id: *test_model::KNOWN_MOCK_FESTIVAL_ID,
         created_at: DateTime::now(),
         author_id,
         name: mock_registration_form_name(),
         description: mock_registration_form_description(),
         items,
         query: test_model::mock_project_query(),
+        access_control: AccessControlList::empty(),
     }
 }
 
@@ -41,12 +44,14 @@ pub fn new_registration_form_with_query(
 ) -> RegistrationForm {
     RegistrationForm {
         id: new_registration_form_id(),
+        festival_id: *test_model::KNOWN_MOCK_FESTIVAL_ID,
         created_at: DateTime::now(),
         author_id,
         name: mock_registration_form_name(),
         description: mock_registration_form_description(),
         items: test_model::new_form_items(),
         query,
+        access_control: AccessControlList::empty(),
     }
 }
 
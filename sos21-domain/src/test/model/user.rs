@@ -42,8 +42,11 @@ pub fn mock_user(id: UserId, role: UserRole) -> User {
         email: mock_user_email_address(),
         phone_number: mock_phone_number(),
         role,
+        custom_role: None,
         category: mock_user_category(),
         assignment: None,
+        suspension: None,
+        tokens_revoked_at: None,
     })
 }
 
@@ -26,6 +26,9 @@ where
         author_id,
         email: UserEmailAddress::from_string(email).unwrap(),
         role,
+        allows_unlisted_domain: false,
+        expires_at: None,
+        acceptance: None,
     })
 }
 
@@ -49,3 +52,14 @@ where
 {
     new_user_invitation(author_id, email, UserInvitationRole::Administrator)
 }
+
+pub fn new_expired_user_invitation<S>(author_id: UserId, email: S) -> UserInvitation
+where
+    S: Into<String>,
+{
+    let mut content = new_committee_user_invitation(author_id, email).into_content();
+    content.expires_at = Some(DateTime::from_utc(
+        chrono::Utc::now() - chrono::Duration::days(1),
+    ));
+    UserInvitation::from_content(content)
+}
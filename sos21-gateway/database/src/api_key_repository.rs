@@ -0,0 +1,149 @@
+use crate::user_repository::to_permissions;
+
+use anyhow::{Context, Result};
+use futures::lock::Mutex;
+use futures::{future, stream::TryStreamExt};
+use ref_cast::RefCast;
+use sos21_database::{command, model as data, query};
+use sos21_domain::context::ApiKeyRepository;
+use sos21_domain::model::{
+    api_key::{ApiKey, ApiKeyContent, ApiKeyId, ApiKeyName, ApiKeyScope, ApiKeyTokenDigest},
+    date_time::DateTime,
+    user::{UserId, UserRole},
+};
+use sqlx::{Postgres, Transaction};
+
+#[derive(Debug, RefCast)]
+#[repr(transparent)]
+pub struct ApiKeyDatabase(Mutex<Transaction<'static, Postgres>>);
+
+#[async_trait::async_trait]
+impl ApiKeyRepository for ApiKeyDatabase {
+    async fn store_api_key(&self, key: ApiKey) -> Result<()> {
+        let mut lock = self.0.lock().await;
+
+        let key = from_api_key(key);
+        if query::find_api_key(&mut *lock, key.id).await?.is_some() {
+            let input = command::update_api_key::Input {
+                id: key.id,
+                last_used_at: key.last_used_at,
+                revoked_at: key.revoked_at,
+            };
+            command::update_api_key(&mut *lock, input).await
+        } else {
+            command::insert_api_key(&mut *lock, key).await
+        }
+    }
+
+    async fn get_api_key(&self, id: ApiKeyId) -> Result<Option<ApiKey>> {
+        let mut lock = self.0.lock().await;
+        query::find_api_key(&mut *lock, id.to_uuid())
+            .await
+            .and_then(|opt| opt.map(to_api_key).transpose())
+    }
+
+    async fn get_api_key_by_token_digest(
+        &self,
+        digest: &ApiKeyTokenDigest,
+    ) -> Result<Option<ApiKey>> {
+        let mut lock = self.0.lock().await;
+        query::find_api_key_by_token_digest(&mut *lock, digest.as_slice().to_vec())
+            .await
+            .and_then(|opt| opt.map(to_api_key).transpose())
+    }
+
+    async fn list_api_keys(&self) -> Result<Vec<ApiKey>> {
+        let mut lock = self.0.lock().await;
+        query::list_api_keys(&mut *lock)
+            .and_then(|key| future::ready(to_api_key(key)))
+            .try_collect()
+            .await
+    }
+}
+
+fn from_api_key(key: ApiKey) -> data::api_key::ApiKey {
+    let ApiKeyContent {
+        id,
+        created_at,
+        author_id,
+        name,
+        scope,
+        token_digest,
+        expires_at,
+        last_used_at,
+        revoked_at,
+    } = key.into_content();
+
+    let (role, permissions) = match scope {
+        ApiKeyScope::Role(role) => (Some(from_user_role(role)), None),
+        ApiKeyScope::Permissions(permissions) => (
+            None,
+            Some(permissions.names().map(ToOwned::to_owned).collect()),
+        ),
+    };
+
+    data::api_key::ApiKey {
+        id: id.to_uuid(),
+        created_at: created_at.utc(),
+        author_id: author_id.0,
+        name: name.into_string(),
+        role,
+        permissions,
+        token_digest: token_digest.into_array().to_vec(),
+        expires_at: expires_at.map(|expires_at| expires_at.utc()),
+        last_used_at: last_used_at.map(|last_used_at| last_used_at.utc()),
+        revoked_at: revoked_at.map(|revoked_at| revoked_at.utc()),
+    }
+}
+
+fn from_user_role(role: UserRole) -> data::user::UserRole {
+    match role {
+        UserRole::Administrator => data::user::UserRole::Administrator,
+        UserRole::CommitteeOperator => data::user::UserRole::CommitteeOperator,
+        UserRole::Committee => data::user::UserRole::Committee,
+        UserRole::General => data::user::UserRole::General,
+    }
+}
+
+fn to_api_key(key: data::api_key::ApiKey) -> Result<ApiKey> {
+    let data::api_key::ApiKey {
+        id,
+        created_at,
+        author_id,
+        name,
+        role,
+        permissions,
+        token_digest,
+        expires_at,
+        last_used_at,
+        revoked_at,
+    } = key;
+
+    let scope = match (role, permissions) {
+        (Some(role), None) => ApiKeyScope::Role(to_user_role(role)),
+        (None, Some(permissions)) => ApiKeyScope::Permissions(to_permissions(permissions)?),
+        _ => anyhow::bail!("API key {} must have either a role or permissions", id),
+    };
+
+    Ok(ApiKey::from_content(ApiKeyContent {
+        id: ApiKeyId::from_uuid(id),
+        created_at: DateTime::from_utc(created_at),
+        author_id: UserId(author_id),
+        name: ApiKeyName::from_string(name)?,
+        scope,
+        token_digest: ApiKeyTokenDigest::from_vec(token_digest)
+            .context("invalid API key token digest")?,
+        expires_at: expires_at.map(DateTime::from_utc),
+        last_used_at: last_used_at.map(DateTime::from_utc),
+        revoked_at: revoked_at.map(DateTime::from_utc),
+    }))
+}
+
+fn to_user_role(role: data::user::UserRole) -> UserRole {
+    match role {
+        data::user::UserRole::Administrator => UserRole::Administrator,
+        data::user::UserRole::CommitteeOperator => UserRole::CommitteeOperator,
+        data::user::UserRole::Committee => UserRole::Committee,
+        data::user::UserRole::General => UserRole::General,
+    }
+}
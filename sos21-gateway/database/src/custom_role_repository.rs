@@ -0,0 +1,96 @@
+use crate::user_repository::to_permissions;
+
+use anyhow::Result;
+use futures::lock::Mutex;
+use futures::{future, stream::TryStreamExt};
+use ref_cast::RefCast;
+use sos21_database::{command, model as data, query};
+use sos21_domain::context::CustomRoleRepository;
+use sos21_domain::model::{
+    custom_role::{CustomRole, CustomRoleContent, CustomRoleId, CustomRoleName},
+    date_time::DateTime,
+    user::UserId,
+};
+use sqlx::{Postgres, Transaction};
+
+#[derive(Debug, RefCast)]
+#[repr(transparent)]
+pub struct CustomRoleDatabase(Mutex<Transaction<'static, Postgres>>);
+
+#[async_trait::async_trait]
+impl CustomRoleRepository for CustomRoleDatabase {
+    async fn store_custom_role(&self, role: CustomRole) -> Result<()> {
+        let mut lock = self.0.lock().await;
+
+        let role = from_custom_role(role);
+        if query::find_custom_role(&mut *lock, role.id)
+            .await?
+            .is_some()
+        {
+            let input = command::update_custom_role::Input {
+                id: role.id,
+                name: role.name,
+                permissions: role.permissions,
+            };
+            command::update_custom_role(&mut *lock, input).await
+        } else {
+            command::insert_custom_role(&mut *lock, role).await
+        }
+    }
+
+    async fn delete_custom_role(&self, id: CustomRoleId) -> Result<()> {
+        let mut lock = self.0.lock().await;
+        command::delete_custom_role(&mut *lock, id.to_uuid()).await
+    }
+
+    async fn get_custom_role(&self, id: CustomRoleId) -> Result<Option<CustomRole>> {
+        let mut lock = self.0.lock().await;
+        query::find_custom_role(&mut *lock, id.to_uuid())
+            .await
+            .and_then(|opt| opt.map(to_custom_role).transpose())
+    }
+
+    async fn list_custom_roles(&self) -> Result<Vec<CustomRole>> {
+        let mut lock = self.0.lock().await;
+        query::list_custom_roles(&mut *lock)
+            .and_then(|role| future::ready(to_custom_role(role)))
+            .try_collect()
+            .await
+    }
+}
+
+fn from_custom_role(role: CustomRole) -> data::custom_role::CustomRole {
+    let CustomRoleContent {
+        id,
+        created_at,
+        author_id,
+        name,
+        permissions,
+    } = role.into_content();
+
+    data::custom_role::CustomRole {
+        id: id.to_uuid(),
+        created_at: created_at.utc(),
+        author_id: author_id.0,
+        name: name.into_string(),
+        permissions: permissions.names().map(ToOwned::to_owned).collect(),
+    }
+}
+
+fn to_custom_role(role: data::custom_role::CustomRole) -> Result<CustomRole> {
+    let data::custom_role::CustomRole {
+        id,
+        created_at,
+        author_id,
+        name,
+        permissions,
+    } = role;
+
+    Ok(CustomRole::from_content(CustomRoleContent {
+        id: CustomRoleId::from_uuid(id),
+        created_at: DateTime::from_utc(created_at),
+        author_id: UserId(author_id),
+        name: CustomRoleName::from_string(name)?,
+        permissions: to_permissions(permissions)?,
+    }))
+}
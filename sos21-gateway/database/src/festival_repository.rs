@@ -0,0 +1,79 @@
+use anyhow::Result;
+use futures::lock::Mutex;
+use futures::{future, stream::TryStreamExt};
+use ref_cast::RefCast;
+use sos21_database::{command, model as data, query};
+use sos21_domain::context::FestivalRepository;
+use sos21_domain::model::{
+    date_time::DateTime,
+    festival::{Festival, FestivalContent, FestivalId, FestivalName},
+    user::UserId,
+};
+use sqlx::{Postgres, Transaction};
+
+#[derive(Debug, RefCast)]
+#[repr(transparent)]
+pub struct FestivalDatabase(Mutex<Transaction<'static, Postgres>>);
+
+#[async_trait::async_trait]
+impl FestivalRepository for FestivalDatabase {
+    async fn store_festival(&self, festival: Festival) -> Result<()> {
+        let mut lock = self.0.lock().await;
+
+        let festival = from_festival(festival);
+        anyhow::ensure!(
+            query::find_festival(&mut *lock, festival.id)
+                .await?
+                .is_none(),
+            "festivals are immutable once created"
+        );
+        command::insert_festival(&mut *lock, festival).await
+    }
+
+    async fn get_festival(&self, id: FestivalId) -> Result<Option<Festival>> {
+        let mut lock = self.0.lock().await;
+        query::find_festival(&mut *lock, id.to_uuid())
+            .await
+            .and_then(|opt| opt.map(to_festival).transpose())
+    }
+
+    async fn list_festivals(&self) -> Result<Vec<Festival>> {
+        let mut lock = self.0.lock().await;
+        query::list_festivals(&mut *lock)
+            .and_then(|festival| future::ready(to_festival(festival)))
+            .try_collect()
+            .await
+    }
+}
+
+fn from_festival(festival: Festival) -> data::festival::Festival {
+    let FestivalContent {
+        id,
+        created_at,
+        author_id,
+        name,
+    } = festival.into_content();
+
+    data::festival::Festival {
+        id: id.to_uuid(),
+        created_at: created_at.utc(),
+        author_id: author_id.map(|id| id.0),
+        name: name.into_string(),
+    }
+}
+
+fn to_festival(festival: data::festival::Festival) -> Result<Festival> {
+    let data::festival::Festival {
+        id,
+        created_at,
+        author_id,
+        name,
+    } = festival;
+
+    Ok(Festival::from_content(FestivalContent {
+        id: FestivalId::from_uuid(id),
+        created_at: DateTime::from_utc(created_at),
+        author_id: author_id.map(UserId),
+        name: FestivalName::from_string(name)?,
+    }))
+}
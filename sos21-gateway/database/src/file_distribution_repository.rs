@@ -9,6 +9,7 @@ use sos21_database::{command, model as data, query};
 use sos21_domain::context::FileDistributionRepository;
 use sos21_domain::model::{
     date_time::DateTime,
+    festival::FestivalId,
     file_distribution::{
         FileDistribution, FileDistributionDescription, FileDistributionFiles, FileDistributionId,
         FileDistributionName,
@@ -104,6 +105,7 @@ fn from_file_distribution(
 ) -> data::file_distribution::FileDistribution {
     data::file_distribution::FileDistribution {
         id: distribution.id.to_uuid(),
+        festival_id: distribution.festival_id.to_uuid(),
         created_at: distribution.created_at.utc(),
         author_id: distribution.author_id.0,
         name: distribution.name.into_string(),
@@ -124,6 +126,7 @@ fn to_file_distribution(
 
     Ok(FileDistribution {
         id: FileDistributionId::from_uuid(distribution.id),
+        festival_id: FestivalId::from_uuid(distribution.festival_id),
         created_at: DateTime::from_utc(distribution.created_at),
         author_id: UserId(distribution.author_id),
         name: FileDistributionName::from_string(distribution.name)?,
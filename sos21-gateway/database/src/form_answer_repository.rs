@@ -1,16 +1,23 @@
 use anyhow::Result;
 use futures::{future, lock::Mutex, stream::TryStreamExt};
 use ref_cast::RefCast;
+use serde::{Deserialize, Serialize};
 use sos21_database::{command, model as data, query};
 use sos21_domain::context::FormAnswerRepository;
 use sos21_domain::model::{
     date_time::DateTime,
-    form::FormId,
-    form_answer::{FormAnswer, FormAnswerContent, FormAnswerId},
+    file::FileId,
+    file_sharing::FileSharingId,
+    form::{FormId, FormVersion},
+    form_answer::{
+        draft::{DraftFileSharing, FormAnswerDraft, FormAnswerDraftContent},
+        FormAnswer, FormAnswerContent, FormAnswerId, FormAnswerReview, FormAnswerReviewStatus,
+    },
     project::ProjectId,
     user::UserId,
 };
 use sqlx::{Postgres, Transaction};
+use uuid::Uuid;
 
 #[derive(Debug, RefCast)]
 #[repr(transparent)]
@@ -29,6 +36,12 @@ impl FormAnswerRepository for FormAnswerDatabase {
             let input = command::update_form_answer::Input {
                 id: answer.id,
                 items: serde_json::to_value(&answer.items)?,
+                form_version: answer.form_version,
+                needs_resubmission: answer.needs_resubmission,
+                review_status: answer.review_status,
+                review_comments: answer.review_comments,
+                reviewer_id: answer.reviewer_id,
+                reviewed_at: answer.reviewed_at,
             };
             command::update_form_answer(&mut *lock, input).await
         } else {
@@ -65,6 +78,28 @@ impl FormAnswerRepository for FormAnswerDatabase {
             .try_collect()
             .await
     }
+
+    async fn store_form_answer_draft(&self, draft: FormAnswerDraft) -> Result<()> {
+        let mut lock = self.0.lock().await;
+        let draft = from_form_answer_draft(draft)?;
+        command::upsert_form_answer_draft(&mut *lock, draft).await
+    }
+
+    async fn get_form_answer_draft(
+        &self,
+        form_id: FormId,
+        project_id: ProjectId,
+    ) -> Result<Option<FormAnswerDraft>> {
+        let mut lock = self.0.lock().await;
+        query::find_form_answer_draft(&mut *lock, form_id.to_uuid(), project_id.to_uuid())
+            .await
+            .and_then(|opt| opt.map(to_form_answer_draft).transpose())
+    }
+
+    async fn delete_form_answer_draft(&self, form_id: FormId, project_id: ProjectId) -> Result<()> {
+        let mut lock = self.0.lock().await;
+        command::delete_form_answer_draft(&mut *lock, form_id.to_uuid(), project_id.to_uuid()).await
+    }
 }
 
 fn to_form_answer(answer: data::form_answer::FormAnswer) -> Result<FormAnswer> {
@@ -73,28 +108,75 @@ fn to_form_answer(answer: data::form_answer::FormAnswer) -> Result<FormAnswer> {
         created_at,
         author_id,
         form_id,
+        form_version,
         project_id,
         items,
+        needs_resubmission,
+        review_status,
+        review_comments,
+        reviewer_id,
+        reviewed_at,
     } = answer;
 
     Ok(FormAnswer::from_content(FormAnswerContent {
         id: FormAnswerId::from_uuid(id),
         project_id: ProjectId::from_uuid(project_id),
         form_id: FormId::from_uuid(form_id),
+        form_version: FormVersion::from_i32(form_version)?,
         created_at: DateTime::from_utc(created_at),
         author_id: UserId(author_id),
         items: serde_json::from_value(items)?,
+        needs_resubmission,
+        review: FormAnswerReview {
+            status: to_form_answer_review_status(review_status),
+            comments: serde_json::from_value(review_comments)?,
+            reviewer_id: reviewer_id.map(UserId),
+            reviewed_at: reviewed_at.map(DateTime::from_utc),
+        },
     }))
 }
 
+fn to_form_answer_review_status(
+    status: data::form_answer::FormAnswerReviewStatus,
+) -> FormAnswerReviewStatus {
+    match status {
+        data::form_answer::FormAnswerReviewStatus::Submitted => FormAnswerReviewStatus::Submitted,
+        data::form_answer::FormAnswerReviewStatus::UnderReview => {
+            FormAnswerReviewStatus::UnderReview
+        }
+        data::form_answer::FormAnswerReviewStatus::NeedsChanges => {
+            FormAnswerReviewStatus::NeedsChanges
+        }
+        data::form_answer::FormAnswerReviewStatus::Approved => FormAnswerReviewStatus::Approved,
+    }
+}
+
+fn from_form_answer_review_status(
+    status: FormAnswerReviewStatus,
+) -> data::form_answer::FormAnswerReviewStatus {
+    match status {
+        FormAnswerReviewStatus::Submitted => data::form_answer::FormAnswerReviewStatus::Submitted,
+        FormAnswerReviewStatus::UnderReview => {
+            data::form_answer::FormAnswerReviewStatus::UnderReview
+        }
+        FormAnswerReviewStatus::NeedsChanges => {
+            data::form_answer::FormAnswerReviewStatus::NeedsChanges
+        }
+        FormAnswerReviewStatus::Approved => data::form_answer::FormAnswerReviewStatus::Approved,
+    }
+}
+
 fn from_form_answer(answer: FormAnswer) -> Result<data::form_answer::FormAnswer> {
     let FormAnswerContent {
         id,
         project_id,
         form_id,
+        form_version,
         created_at,
         author_id,
         items,
+        needs_resubmission,
+        review,
     } = answer.into_content();
 
     Ok(data::form_answer::FormAnswer {
@@ -102,7 +184,83 @@ fn from_form_answer(answer: FormAnswer) -> Result<data::form_answer::FormAnswer>
         created_at: created_at.utc(),
         author_id: author_id.0,
         form_id: form_id.to_uuid(),
+        form_version: form_version.to_i32(),
         project_id: project_id.to_uuid(),
         items: serde_json::to_value(&items)?,
+        needs_resubmission,
+        review_status: from_form_answer_review_status(review.status),
+        review_comments: serde_json::to_value(&review.comments)?,
+        reviewer_id: review.reviewer_id.map(|id| id.0),
+        reviewed_at: review.reviewed_at.map(|t| t.utc()),
     })
 }
+
+fn to_form_answer_draft(draft: data::form_answer::FormAnswerDraft) -> Result<FormAnswerDraft> {
+    let data::form_answer::FormAnswerDraft {
+        form_id,
+        project_id,
+        author_id,
+        updated_at,
+        items,
+        file_sharings,
+    } = draft;
+
+    Ok(FormAnswerDraft::from_content(FormAnswerDraftContent {
+        form_id: FormId::from_uuid(form_id),
+        project_id: ProjectId::from_uuid(project_id),
+        author_id: UserId(author_id),
+        updated_at: DateTime::from_utc(updated_at),
+        items: serde_json::from_value(items)?,
+        file_sharings: to_draft_file_sharings(file_sharings)?,
+    }))
+}
+
+fn from_form_answer_draft(draft: FormAnswerDraft) -> Result<data::form_answer::FormAnswerDraft> {
+    let FormAnswerDraftContent {
+        form_id,
+        project_id,
+        author_id,
+        updated_at,
+        items,
+        file_sharings,
+    } = draft.into_content();
+
+    Ok(data::form_answer::FormAnswerDraft {
+        form_id: form_id.to_uuid(),
+        project_id: project_id.to_uuid(),
+        author_id: author_id.0,
+        updated_at: updated_at.utc(),
+        items: serde_json::to_value(&items)?,
+        file_sharings: from_draft_file_sharings(file_sharings)?,
+    })
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DraftFileSharingData {
+    sharing_id: Uuid,
+    file_id: Uuid,
+}
+
+pub(crate) fn to_draft_file_sharings(data: serde_json::Value) -> Result<Vec<DraftFileSharing>> {
+    let sharings: Vec<DraftFileSharingData> = serde_json::from_value(data)?;
+    Ok(sharings
+        .into_iter()
+        .map(|sharing| DraftFileSharing {
+            sharing_id: FileSharingId::from_uuid(sharing.sharing_id),
+            file_id: FileId::from_uuid(sharing.file_id),
+        })
+        .collect())
+}
+
+pub(crate) fn from_draft_file_sharings(
+    sharings: Vec<DraftFileSharing>,
+) -> Result<serde_json::Value> {
+    let sharings: Vec<_> = sharings
+        .into_iter()
+        .map(|sharing| DraftFileSharingData {
+            sharing_id: sharing.sharing_id.to_uuid(),
+            file_id: sharing.file_id.to_uuid(),
+        })
+        .collect();
+    Ok(serde_json::to_value(sharings)?)
+}
@@ -9,13 +9,19 @@ use futures::{
     stream::TryStreamExt,
 };
 use ref_cast::RefCast;
+use serde::{Deserialize, Serialize};
 use sos21_database::{command, model as data, query};
 use sos21_domain::context::form_repository::{FormRepository, ProjectForm};
 use sos21_domain::model::{
+    access_control::{
+        AccessControlEntry, AccessControlGrantee, AccessControlLevel, AccessControlList,
+    },
+    custom_role::CustomRoleId,
     date_time::DateTime,
+    festival::FestivalId,
     form::{
         Form, FormCondition, FormConditionProjectSet, FormContent, FormDescription, FormId,
-        FormName, FormPeriod,
+        FormName, FormPeriod, FormRevision, FormVersion,
     },
     project::ProjectId,
     project_query::{ProjectQuery, ProjectQueryConjunction},
@@ -33,7 +39,9 @@ impl FormRepository for FormDatabase {
         let mut lock = self.0.lock().await;
 
         let form_id = form.id().to_uuid();
-        if let Some(old_form) = query::find_form(&mut *lock, form_id).await? {
+        let revision = from_form_revision(FormRevision::of_form(&form))?;
+        let old_version = form.revised_from();
+        let is_new = if let Some(old_form) = query::find_form(&mut *lock, form_id).await? {
             let old_includes = FormConditionProjectSet::from_projects(
                 old_form.include_ids.into_iter().map(ProjectId::from_uuid),
             )?;
@@ -87,13 +95,17 @@ impl FormRepository for FormDatabase {
             let form = from_form(form)?;
             let input = command::update_form::Input {
                 id: form.id,
+                old_version: old_version.map_or(form.version, |version| version.to_i32()),
+                version: form.version,
                 name: form.name,
                 description: form.description,
                 starts_at: form.starts_at,
                 ends_at: form.ends_at,
                 items: form.items,
+                access_control: form.access_control,
             };
             command::update_form(&mut *lock, input).await?;
+            false
         } else {
             let include_ids = form
                 .condition()
@@ -114,6 +126,12 @@ impl FormRepository for FormDatabase {
             command::insert_form_project_query_conjunctions(&mut *lock, form_id, query).await?;
             command::insert_form_condition_includes(&mut *lock, form_id, include_ids).await?;
             command::insert_form_condition_excludes(&mut *lock, form_id, exclude_ids).await?;
+            true
+        };
+
+        // unrevised updates such as the access control do not make a new revision
+        if is_new || old_version.is_some() {
+            command::insert_form_revision(&mut *lock, revision).await?;
         }
 
         Ok(())
@@ -127,6 +145,27 @@ impl FormRepository for FormDatabase {
             .await
     }
 
+    async fn get_form_revision(
+        &self,
+        id: FormId,
+        version: FormVersion,
+    ) -> Result<Option<FormRevision>> {
+        let mut lock = self.0.lock().await;
+
+        query::find_form_revision(&mut *lock, id.to_uuid(), version.to_i32())
+            .await?
+            .map(to_form_revision)
+            .transpose()
+    }
+
+    async fn list_form_revisions(&self, id: FormId) -> Result<Vec<FormRevision>> {
+        let mut lock = self.0.lock().await;
+        query::list_form_revisions_by_form(&mut *lock, id.to_uuid())
+            .and_then(|data| future::ready(to_form_revision(data)))
+            .try_collect()
+            .await
+    }
+
     async fn list_forms(&self) -> Result<Vec<Form>> {
         let mut lock = self.0.lock().await;
         query::list_forms(&mut *lock)
@@ -150,6 +189,8 @@ impl FormRepository for FormDatabase {
 fn to_form(data: data::form::FormData) -> Result<Form> {
     let data::form::Form {
         id,
+        festival_id,
+        version,
         created_at,
         author_id,
         name,
@@ -158,6 +199,7 @@ fn to_form(data: data::form::FormData) -> Result<Form> {
         ends_at,
         items,
         answer_notification_webhook,
+        access_control,
     } = data.form;
 
     let starts_at = DateTime::from_utc(starts_at);
@@ -188,6 +230,8 @@ fn to_form(data: data::form::FormData) -> Result<Form> {
 
     Ok(Form::from_content(FormContent {
         id: FormId::from_uuid(id),
+        festival_id: FestivalId::from_uuid(festival_id),
+        version: FormVersion::from_i32(version)?,
         created_at: DateTime::from_utc(created_at),
         author_id: UserId(author_id),
         name: FormName::from_string(name)?,
@@ -196,12 +240,15 @@ fn to_form(data: data::form::FormData) -> Result<Form> {
         items: serde_json::from_value(items)?,
         condition,
         answer_notification_webhook,
+        access_control: to_access_control(access_control)?,
     }))
 }
 
 fn from_form(form: Form) -> Result<data::form::Form> {
     let FormContent {
         id,
+        festival_id,
+        version,
         created_at,
         author_id,
         name,
@@ -210,10 +257,13 @@ fn from_form(form: Form) -> Result<data::form::Form> {
         items,
         condition: _,
         answer_notification_webhook,
+        access_control,
     } = form.into_content();
 
     Ok(data::form::Form {
         id: id.to_uuid(),
+        festival_id: festival_id.to_uuid(),
+        version: version.to_i32(),
         created_at: created_at.utc(),
         author_id: author_id.0,
         name: name.into_string(),
@@ -222,6 +272,60 @@ fn from_form(form: Form) -> Result<data::form::Form> {
         ends_at: period.ends_at().utc(),
         items: serde_json::to_value(&items)?,
         answer_notification_webhook,
+        access_control: from_access_control(access_control)?,
+    })
+}
+
+fn to_form_revision(data: data::form::FormRevision) -> Result<FormRevision> {
+    let data::form::FormRevision {
+        form_id,
+        version,
+        created_at,
+        name,
+        description,
+        starts_at,
+        ends_at,
+        items,
+        condition,
+    } = data;
+
+    let starts_at = DateTime::from_utc(starts_at);
+    let ends_at = DateTime::from_utc(ends_at);
+
+    Ok(FormRevision {
+        form_id: FormId::from_uuid(form_id),
+        version: FormVersion::from_i32(version)?,
+        created_at: DateTime::from_utc(created_at),
+        name: FormName::from_string(name)?,
+        description: FormDescription::from_string(description)?,
+        period: FormPeriod::from_datetime(starts_at, ends_at)?,
+        items: serde_json::from_value(items)?,
+        condition: condition.map(serde_json::from_value).transpose()?,
+    })
+}
+
+fn from_form_revision(revision: FormRevision) -> Result<data::form::FormRevision> {
+    let FormRevision {
+        form_id,
+        version,
+        created_at,
+        name,
+        description,
+        period,
+        items,
+        condition,
+    } = revision;
+
+    Ok(data::form::FormRevision {
+        form_id: form_id.to_uuid(),
+        version: version.to_i32(),
+        created_at: created_at.utc(),
+        name: name.into_string(),
+        description: description.into_string(),
+        starts_at: period.starts_at().utc(),
+        ends_at: period.ends_at().utc(),
+        items: serde_json::to_value(&items)?,
+        condition: condition.map(|c| serde_json::to_value(&c)).transpose()?,
     })
 }
 
@@ -238,3 +342,62 @@ fn from_project_query(
         )
         .collect()
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "id", rename_all = "snake_case")]
+enum AccessControlGranteeData {
+    User(String),
+    CustomRole(uuid::Uuid),
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum AccessControlLevelData {
+    Read,
+    Review,
+    Update,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct AccessControlEntryData {
+    grantee: AccessControlGranteeData,
+    level: AccessControlLevelData,
+}
+
+pub fn to_access_control(data: serde_json::Value) -> Result<AccessControlList> {
+    let entries: Vec<AccessControlEntryData> = serde_json::from_value(data)?;
+    let entries = entries.into_iter().map(|entry| AccessControlEntry {
+        grantee: match entry.grantee {
+            AccessControlGranteeData::User(id) => AccessControlGrantee::User(UserId(id)),
+            AccessControlGranteeData::CustomRole(id) => {
+                AccessControlGrantee::CustomRole(CustomRoleId::from_uuid(id))
+            }
+        },
+        level: match entry.level {
+            AccessControlLevelData::Read => AccessControlLevel::Read,
+            AccessControlLevelData::Review => AccessControlLevel::Review,
+            AccessControlLevelData::Update => AccessControlLevel::Update,
+        },
+    });
+    Ok(AccessControlList::from_entries(entries)?)
+}
+
+pub fn from_access_control(access_control: AccessControlList) -> Result<serde_json::Value> {
+    let entries: Vec<_> = access_control
+        .into_entries()
+        .map(|entry| AccessControlEntryData {
+            grantee: match entry.grantee {
+                AccessControlGrantee::User(id) => AccessControlGranteeData::User(id.0),
+                AccessControlGrantee::CustomRole(id) => {
+                    AccessControlGranteeData::CustomRole(id.to_uuid())
+                }
+            },
+            level: match entry.level {
+                AccessControlLevel::Read => AccessControlLevelData::Read,
+                AccessControlLevel::Review => AccessControlLevelData::Review,
+                AccessControlLevel::Update => AccessControlLevelData::Update,
+            },
+        })
+        .collect();
+    Ok(serde_json::to_value(entries)?)
+}
@@ -0,0 +1,92 @@
+use anyhow::Result;
+use futures::lock::Mutex;
+use futures::{future, stream::TryStreamExt};
+use ref_cast::RefCast;
+use sos21_database::{command, model as data, query};
+use sos21_domain::context::FormTemplateRepository;
+use sos21_domain::model::{
+    date_time::DateTime,
+    form::{FormDescription, FormName},
+    form_template::{FormTemplate, FormTemplateContent, FormTemplateId},
+    user::UserId,
+};
+use sqlx::{Postgres, Transaction};
+
+#[derive(Debug, RefCast)]
+#[repr(transparent)]
+pub struct FormTemplateDatabase(Mutex<Transaction<'static, Postgres>>);
+
+#[async_trait::async_trait]
+impl FormTemplateRepository for FormTemplateDatabase {
+    async fn store_form_template(&self, template: FormTemplate) -> Result<()> {
+        let mut lock = self.0.lock().await;
+
+        let template = from_form_template(template)?;
+        anyhow::ensure!(
+            query::find_form_template(&mut *lock, template.id)
+                .await?
+                .is_none(),
+            "form templates are immutable once created"
+        );
+        command::insert_form_template(&mut *lock, template).await
+    }
+
+    async fn get_form_template(&self, id: FormTemplateId) -> Result<Option<FormTemplate>> {
+        let mut lock = self.0.lock().await;
+        query::find_form_template(&mut *lock, id.to_uuid())
+            .await
+            .and_then(|opt| opt.map(to_form_template).transpose())
+    }
+
+    async fn list_form_templates(&self) -> Result<Vec<FormTemplate>> {
+        let mut lock = self.0.lock().await;
+        query::list_form_templates(&mut *lock)
+            .and_then(|template| future::ready(to_form_template(template)))
+            .try_collect()
+            .await
+    }
+}
+
+fn from_form_template(template: FormTemplate) -> Result<data::form_template::FormTemplate> {
+    let FormTemplateContent {
+        id,
+        created_at,
+        author_id,
+        name,
+        description,
+        items,
+        query,
+    } = template.into_content();
+
+    Ok(data::form_template::FormTemplate {
+        id: id.to_uuid(),
+        created_at: created_at.utc(),
+        author_id: author_id.0,
+        name: name.into_string(),
+        description: description.into_string(),
+        items: serde_json::to_value(&items)?,
+        query: serde_json::to_value(&query)?,
+    })
+}
+
+fn to_form_template(template: data::form_template::FormTemplate) -> Result<FormTemplate> {
+    let data::form_template::FormTemplate {
+        id,
+        created_at,
+        author_id,
+        name,
+        description,
+        items,
+        query,
+    } = template;
+
+    Ok(FormTemplate::from_content(FormTemplateContent {
+        id: FormTemplateId::from_uuid(id),
+        created_at: DateTime::from_utc(created_at),
+        author_id: UserId(author_id),
+        name: FormName::from_string(name)?,
+        description: FormDescription::from_string(description)?,
+        items: serde_json::from_value(items)?,
+        query: serde_json::from_value(query)?,
+    }))
+}
@@ -2,12 +2,20 @@ use futures::lock::Mutex;
 use ref_cast::RefCast;
 use sqlx::{Postgres, Transaction};
 
+mod api_key_repository;
+use api_key_repository::ApiKeyDatabase;
+mod custom_role_repository;
+use custom_role_repository::CustomRoleDatabase;
+mod festival_repository;
+use festival_repository::FestivalDatabase;
 mod project_repository;
 use project_repository::ProjectDatabase;
 mod pending_project_repository;
 use pending_project_repository::PendingProjectDatabase;
 mod form_repository;
 use form_repository::FormDatabase;
+mod form_template_repository;
+use form_template_repository::FormTemplateDatabase;
 mod registration_form_repository;
 use registration_form_repository::RegistrationFormDatabase;
 mod file_repository;
@@ -20,6 +28,8 @@ mod form_answer_repository;
 use form_answer_repository::FormAnswerDatabase;
 mod registration_form_answer_repository;
 use registration_form_answer_repository::RegistrationFormAnswerDatabase;
+mod settings_repository;
+use settings_repository::SettingsDatabase;
 mod user_repository;
 use user_repository::UserDatabase;
 mod user_invitation_repository;
@@ -42,6 +52,24 @@ impl Database {
     }
 }
 
+sos21_domain::delegate_api_key_repository! {
+    impl ApiKeyRepository for Database {
+        self { ApiKeyDatabase::ref_cast(&self.connection) }
+    }
+}
+
+sos21_domain::delegate_custom_role_repository! {
+    impl CustomRoleRepository for Database {
+        self { CustomRoleDatabase::ref_cast(&self.connection) }
+    }
+}
+
+sos21_domain::delegate_festival_repository! {
+    impl FestivalRepository for Database {
+        self { FestivalDatabase::ref_cast(&self.connection) }
+    }
+}
+
 sos21_domain::delegate_project_repository! {
     impl ProjectRepository for Database {
         self { ProjectDatabase::ref_cast(&self.connection) }
@@ -90,6 +118,12 @@ sos21_domain::delegate_pending_project_repository! {
     }
 }
 
+sos21_domain::delegate_form_template_repository! {
+    impl FormTemplateRepository for Database {
+        self { FormTemplateDatabase::ref_cast(&self.connection) }
+    }
+}
+
 sos21_domain::delegate_registration_form_repository! {
     impl RegistrationFormRepository for Database {
         self { RegistrationFormDatabase::ref_cast(&self.connection) }
@@ -107,3 +141,9 @@ sos21_domain::delegate_user_invitation_repository! {
         self { UserInvitationDatabase::ref_cast(&self.connection) }
     }
 }
+
+sos21_domain::delegate_settings_repository! {
+    impl SettingsRepository for Database {
+        self { SettingsDatabase::ref_cast(&self.connection) }
+    }
+}
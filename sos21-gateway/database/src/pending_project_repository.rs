@@ -3,15 +3,19 @@ use crate::project_repository::{
 };
 use crate::user_repository::to_user;
 
+use std::convert::TryInto;
+
 use anyhow::Result;
 use futures::lock::Mutex;
+use futures::{future, stream::TryStreamExt};
 use ref_cast::RefCast;
 use sos21_database::{command, model as data, query};
 use sos21_domain::context::pending_project_repository::{
-    PendingProjectRepository, PendingProjectWithOwner,
+    PendingProjectRepository, PendingProjectWithOwner, PendingProjectWithRegistrationProgress,
 };
 use sos21_domain::model::{
     date_time::DateTime,
+    festival::FestivalId,
     pending_project::{PendingProject, PendingProjectContent, PendingProjectId},
     project::{
         ProjectDescription, ProjectGroupName, ProjectKanaGroupName, ProjectKanaName, ProjectName,
@@ -45,6 +49,7 @@ impl PendingProjectRepository for PendingProjectDatabase {
                 description: pending_project.description,
                 category: pending_project.category,
                 attributes: pending_project.attributes,
+                exceptional_complete_deadline: pending_project.exceptional_complete_deadline,
             };
             command::update_pending_project(&mut *lock, input).await
         } else {
@@ -66,11 +71,35 @@ impl PendingProjectRepository for PendingProjectDatabase {
             .await
             .and_then(|opt| opt.map(to_pending_project_with_owner).transpose())
     }
+
+    async fn list_pending_projects(
+        &self,
+        festival_id: FestivalId,
+    ) -> Result<Vec<PendingProjectWithRegistrationProgress>> {
+        let mut lock = self.0.lock().await;
+        query::list_pending_projects(&mut *lock, festival_id.to_uuid())
+            .and_then(|result| future::ready(to_pending_project_with_registration_progress(result)))
+            .try_collect()
+            .await
+    }
+
+    async fn list_pending_projects_with_exceptional_deadline(
+        &self,
+        festival_id: FestivalId,
+    ) -> Result<Vec<PendingProjectWithOwner>> {
+        let mut lock = self.0.lock().await;
+        query::list_pending_projects_with_exceptional_deadline(&mut *lock, festival_id.to_uuid())
+            .and_then(|result| future::ready(to_pending_project_with_owner(result)))
+            .try_collect()
+            .await
+    }
 }
 
 fn from_pending_project(pending_project: PendingProject) -> data::pending_project::PendingProject {
+    let owner_id = pending_project.owner_id().clone();
     let PendingProjectContent {
         id,
+        festival_id,
         created_at,
         updated_at,
         name,
@@ -85,6 +114,7 @@ fn from_pending_project(pending_project: PendingProject) -> data::pending_projec
 
     data::pending_project::PendingProject {
         id: id.to_uuid(),
+        festival_id: festival_id.to_uuid(),
         created_at: created_at.utc(),
         updated_at: updated_at.utc(),
         name: name.into_string(),
@@ -95,9 +125,35 @@ fn from_pending_project(pending_project: PendingProject) -> data::pending_projec
         category: from_project_category(category),
         attributes: from_project_attributes(&attributes),
         exceptional_complete_deadline: exceptional_complete_deadline.map(|x| x.utc()),
+        owner_id: owner_id.0,
     }
 }
 
+fn to_pending_project_with_registration_progress(
+    result: data::pending_project::PendingProjectWithRegistrationProgress,
+) -> Result<PendingProjectWithRegistrationProgress> {
+    let data::pending_project::PendingProjectWithRegistrationProgress {
+        pending_project,
+        owner,
+        registration_form_count,
+        answered_registration_form_count,
+    } = result;
+
+    let PendingProjectWithOwner {
+        pending_project,
+        owner,
+    } = to_pending_project_with_owner(data::pending_project::PendingProjectWithOwner {
+        pending_project,
+        owner,
+    })?;
+    Ok(PendingProjectWithRegistrationProgress {
+        pending_project,
+        owner,
+        registration_form_count: registration_form_count.try_into()?,
+        answered_registration_form_count: answered_registration_form_count.try_into()?,
+    })
+}
+
 fn to_pending_project_with_owner(
     pending_project_with_owner: data::pending_project::PendingProjectWithOwner,
 ) -> Result<PendingProjectWithOwner> {
@@ -108,6 +164,7 @@ fn to_pending_project_with_owner(
 
     let data::pending_project::PendingProject {
         id,
+        festival_id,
         created_at,
         updated_at,
         name,
@@ -118,11 +175,13 @@ fn to_pending_project_with_owner(
         category,
         attributes,
         exceptional_complete_deadline,
+        owner_id,
     } = pending_project;
 
     let pending_project = PendingProject::from_content(
         PendingProjectContent {
             id: PendingProjectId::from_uuid(id),
+            festival_id: FestivalId::from_uuid(festival_id),
             created_at: DateTime::from_utc(created_at),
             updated_at: DateTime::from_utc(updated_at),
             name: ProjectName::from_string(name)?,
@@ -134,7 +193,7 @@ fn to_pending_project_with_owner(
             attributes: to_project_attributes(attributes)?,
             exceptional_complete_deadline: exceptional_complete_deadline.map(DateTime::from_utc),
         },
-        UserId(owner.id.clone()),
+        UserId(owner_id),
     );
 
     Ok(PendingProjectWithOwner {
@@ -11,6 +11,7 @@ use sos21_database::{command, model as data, query};
 use sos21_domain::context::project_repository::{ProjectRepository, ProjectWithOwners};
 use sos21_domain::model::{
     date_time::DateTime,
+    festival::FestivalId,
     project::{
         Project, ProjectAttribute, ProjectAttributes, ProjectCategory, ProjectContent,
         ProjectDescription, ProjectGroupName, ProjectId, ProjectIndex, ProjectKanaGroupName,
@@ -41,6 +42,7 @@ impl ProjectRepository for ProjectDatabase {
                 description: project.description,
                 category: project.category,
                 attributes: project.attributes,
+                subowner_id: project.subowner_id,
             };
             command::update_project(&mut *lock, input).await
         } else {
@@ -48,9 +50,13 @@ impl ProjectRepository for ProjectDatabase {
         }
     }
 
-    async fn get_project_by_index(&self, index: ProjectIndex) -> Result<Option<ProjectWithOwners>> {
+    async fn get_project_by_index(
+        &self,
+        festival_id: FestivalId,
+        index: ProjectIndex,
+    ) -> Result<Option<ProjectWithOwners>> {
         let mut lock = self.0.lock().await;
-        query::find_project_by_index(&mut *lock, index.to_i16())
+        query::find_project_by_index(&mut *lock, festival_id.to_uuid(), index.to_i16())
             .await?
             .map(to_project_with_owner)
             .transpose()
@@ -69,9 +75,9 @@ impl ProjectRepository for ProjectDatabase {
         query::count_projects(&mut *lock).await
     }
 
-    async fn get_next_index(&self) -> Result<u64> {
+    async fn get_next_index(&self, festival_id: FestivalId) -> Result<u64> {
         let mut lock = self.0.lock().await;
-        query::get_next_index(&mut *lock).await
+        query::get_next_index(&mut *lock, festival_id.to_uuid()).await
     }
 
     async fn list_projects(&self) -> Result<Vec<ProjectWithOwners>> {
@@ -94,6 +100,7 @@ fn to_project_with_owner(
 
     let data::project::Project {
         id,
+        festival_id,
         index,
         created_at,
         updated_at,
@@ -104,11 +111,14 @@ fn to_project_with_owner(
         description,
         category,
         attributes,
+        owner_id,
+        subowner_id,
     } = project;
 
     let project = Project::from_content(
         ProjectContent {
             id: ProjectId::from_uuid(id),
+            festival_id: FestivalId::from_uuid(festival_id),
             index: ProjectIndex::from_u16(index.try_into()?)?,
             created_at: DateTime::from_utc(created_at),
             updated_at: DateTime::from_utc(updated_at),
@@ -120,8 +130,8 @@ fn to_project_with_owner(
             category: to_project_category(category),
             attributes: to_project_attributes(attributes)?,
         },
-        UserId(owner.id.clone()),
-        UserId(subowner.id.clone()),
+        UserId(owner_id),
+        UserId(subowner_id),
     )?;
 
     Ok(ProjectWithOwners {
@@ -132,8 +142,11 @@ fn to_project_with_owner(
 }
 
 fn from_project(project: Project) -> data::project::Project {
+    let owner_id = project.owner_id().clone();
+    let subowner_id = project.subowner_id().clone();
     let ProjectContent {
         id,
+        festival_id,
         index,
         created_at,
         updated_at,
@@ -148,6 +161,7 @@ fn from_project(project: Project) -> data::project::Project {
 
     data::project::Project {
         id: id.to_uuid(),
+        festival_id: festival_id.to_uuid(),
         index: index.to_i16(),
         created_at: created_at.utc(),
         updated_at: updated_at.utc(),
@@ -158,6 +172,8 @@ fn from_project(project: Project) -> data::project::Project {
         description: description.into_string(),
         category: from_project_category(category),
         attributes: from_project_attributes(&attributes),
+        owner_id: owner_id.0,
+        subowner_id: subowner_id.0,
     }
 }
 
@@ -1,3 +1,5 @@
+use crate::form_answer_repository::{from_draft_file_sharings, to_draft_file_sharings};
+
 use anyhow::Result;
 use futures::{future, lock::Mutex, stream::TryStreamExt};
 use ref_cast::RefCast;
@@ -9,6 +11,7 @@ use sos21_domain::model::{
     project::ProjectId,
     registration_form::RegistrationFormId,
     registration_form_answer::{
+        draft::{RegistrationFormAnswerDraft, RegistrationFormAnswerDraftContent},
         RegistrationFormAnswer, RegistrationFormAnswerContent, RegistrationFormAnswerId,
         RegistrationFormAnswerRespondent,
     },
@@ -128,6 +131,44 @@ impl RegistrationFormAnswerRepository for RegistrationFormAnswerDatabase {
         )
         .await
     }
+
+    async fn store_registration_form_answer_draft(
+        &self,
+        draft: RegistrationFormAnswerDraft,
+    ) -> Result<()> {
+        let mut lock = self.0.lock().await;
+        let draft = from_registration_form_answer_draft(draft)?;
+        command::upsert_registration_form_answer_draft(&mut *lock, draft).await
+    }
+
+    async fn get_registration_form_answer_draft(
+        &self,
+        registration_form_id: RegistrationFormId,
+        pending_project_id: PendingProjectId,
+    ) -> Result<Option<RegistrationFormAnswerDraft>> {
+        let mut lock = self.0.lock().await;
+        query::find_registration_form_answer_draft(
+            &mut *lock,
+            registration_form_id.to_uuid(),
+            pending_project_id.to_uuid(),
+        )
+        .await
+        .and_then(|opt| opt.map(to_registration_form_answer_draft).transpose())
+    }
+
+    async fn delete_registration_form_answer_draft(
+        &self,
+        registration_form_id: RegistrationFormId,
+        pending_project_id: PendingProjectId,
+    ) -> Result<()> {
+        let mut lock = self.0.lock().await;
+        command::delete_registration_form_answer_draft(
+            &mut *lock,
+            registration_form_id.to_uuid(),
+            pending_project_id.to_uuid(),
+        )
+        .await
+    }
 }
 
 fn to_registration_form_answer(
@@ -198,3 +239,51 @@ fn from_registration_form_answer(
         items: serde_json::to_value(&items)?,
     })
 }
+
+fn to_registration_form_answer_draft(
+    draft: data::registration_form_answer::RegistrationFormAnswerDraft,
+) -> Result<RegistrationFormAnswerDraft> {
+    let data::registration_form_answer::RegistrationFormAnswerDraft {
+        registration_form_id,
+        pending_project_id,
+        author_id,
+        updated_at,
+        items,
+        file_sharings,
+    } = draft;
+
+    Ok(RegistrationFormAnswerDraft::from_content(
+        RegistrationFormAnswerDraftContent {
+            registration_form_id: RegistrationFormId::from_uuid(registration_form_id),
+            pending_project_id: PendingProjectId::from_uuid(pending_project_id),
+            author_id: UserId(author_id),
+            updated_at: DateTime::from_utc(updated_at),
+            items: serde_json::from_value(items)?,
+            file_sharings: to_draft_file_sharings(file_sharings)?,
+        },
+    ))
+}
+
+fn from_registration_form_answer_draft(
+    draft: RegistrationFormAnswerDraft,
+) -> Result<data::registration_form_answer::RegistrationFormAnswerDraft> {
+    let RegistrationFormAnswerDraftContent {
+        registration_form_id,
+        pending_project_id,
+        author_id,
+        updated_at,
+        items,
+        file_sharings,
+    } = draft.into_content();
+
+    Ok(
+        data::registration_form_answer::RegistrationFormAnswerDraft {
+            registration_form_id: registration_form_id.to_uuid(),
+            pending_project_id: pending_project_id.to_uuid(),
+            author_id: author_id.0,
+            updated_at: updated_at.utc(),
+            items: serde_json::to_value(&items)?,
+            file_sharings: from_draft_file_sharings(file_sharings)?,
+        },
+    )
+}
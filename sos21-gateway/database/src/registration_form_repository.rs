@@ -1,3 +1,4 @@
+use crate::form_repository::{from_access_control, to_access_control};
 use crate::project_repository::{
     from_project_attributes, from_project_category, to_project_attributes, to_project_category,
 };
@@ -15,6 +16,7 @@ use sos21_domain::context::registration_form_repository::{
 };
 use sos21_domain::model::{
     date_time::DateTime,
+    festival::FestivalId,
     pending_project::PendingProjectId,
     project::ProjectId,
     project_query::{ProjectQuery, ProjectQueryConjunction},
@@ -58,6 +60,7 @@ impl RegistrationFormRepository for RegistrationFormDatabase {
                 name: registration_form.name,
                 description: registration_form.description,
                 items: registration_form.items,
+                access_control: registration_form.access_control,
             };
             command::update_registration_form(&mut *lock, input).await?;
         } else {
@@ -144,11 +147,13 @@ fn to_registration_form(
 ) -> Result<RegistrationForm> {
     let data::registration_form::RegistrationForm {
         id,
+        festival_id,
         created_at,
         author_id,
         name,
         description,
         items,
+        access_control,
     } = data.registration_form;
 
     let query = data
@@ -165,12 +170,14 @@ fn to_registration_form(
 
     Ok(RegistrationForm {
         id: RegistrationFormId::from_uuid(id),
+        festival_id: FestivalId::from_uuid(festival_id),
         created_at: DateTime::from_utc(created_at),
         author_id: UserId(author_id),
         name: RegistrationFormName::from_string(name)?,
         description: RegistrationFormDescription::from_string(description)?,
         items: serde_json::from_value(items)?,
         query,
+        access_control: to_access_control(access_control)?,
     })
 }
 
@@ -179,21 +186,25 @@ fn from_registration_form(
 ) -> Result<data::registration_form::RegistrationForm> {
     let RegistrationForm {
         id,
+        festival_id,
         created_at,
         author_id,
         name,
         description,
         items,
         query: _,
+        access_control,
     } = registration_form;
 
     Ok(data::registration_form::RegistrationForm {
         id: id.to_uuid(),
+        festival_id: festival_id.to_uuid(),
         created_at: created_at.utc(),
         author_id: author_id.0,
         name: name.into_string(),
         description: description.into_string(),
         items: serde_json::to_value(&items)?,
+        access_control: from_access_control(access_control)?,
     })
 }
 
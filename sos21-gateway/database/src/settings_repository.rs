@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use futures::lock::Mutex;
+use futures::{future, stream::TryStreamExt};
+use ref_cast::RefCast;
+use serde::{Deserialize, Serialize};
+use sos21_database::{command, model as data, query};
+use sos21_domain::context::SettingsRepository;
+use sos21_domain::model::{
+    date_time::DateTime,
+    festival::FestivalId,
+    project::ProjectCategory,
+    project_creation_period::ProjectCreationPeriod,
+    settings::{Settings, SettingsContent},
+    user::{UserEmailAddress, UserId},
+};
+use sqlx::{Postgres, Transaction};
+
+#[derive(Debug, RefCast)]
+#[repr(transparent)]
+pub struct SettingsDatabase(Mutex<Transaction<'static, Postgres>>);
+
+#[async_trait::async_trait]
+impl SettingsRepository for SettingsDatabase {
+    async fn store_settings(&self, settings: Settings) -> Result<()> {
+        let mut lock = self.0.lock().await;
+        let settings = from_settings(settings)?;
+        command::insert_settings(&mut *lock, settings).await
+    }
+
+    async fn get_settings(&self) -> Result<Option<Settings>> {
+        let mut lock = self.0.lock().await;
+        query::find_latest_settings(&mut *lock)
+            .await
+            .and_then(|opt| opt.map(to_settings).transpose())
+    }
+
+    async fn list_settings_history(&self) -> Result<Vec<Settings>> {
+        let mut lock = self.0.lock().await;
+        query::list_settings(&mut *lock)
+            .and_then(|settings| future::ready(to_settings(settings)))
+            .try_collect()
+            .await
+    }
+}
+
+/// The representation of `ProjectCreationPeriod` in the `project_creation_periods` column.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum StoredProjectCreationPeriod {
+    Always,
+    Never,
+    Range {
+        starts_at: chrono::DateTime<chrono::Utc>,
+        ends_at: chrono::DateTime<chrono::Utc>,
+    },
+}
+
+fn from_project_creation_period(period: ProjectCreationPeriod) -> StoredProjectCreationPeriod {
+    if let Some((starts_at, ends_at)) = period.range() {
+        StoredProjectCreationPeriod::Range {
+            starts_at: starts_at.utc(),
+            ends_at: ends_at.utc(),
+        }
+    } else if period.is_always() {
+        StoredProjectCreationPeriod::Always
+    } else {
+        StoredProjectCreationPeriod::Never
+    }
+}
+
+fn to_project_creation_period(
+    period: StoredProjectCreationPeriod,
+) -> Result<ProjectCreationPeriod> {
+    match period {
+        StoredProjectCreationPeriod::Always => Ok(ProjectCreationPeriod::always()),
+        StoredProjectCreationPeriod::Never => Ok(ProjectCreationPeriod::never()),
+        StoredProjectCreationPeriod::Range { starts_at, ends_at } => {
+            let period = ProjectCreationPeriod::from_datetime(
+                DateTime::from_utc(starts_at),
+                DateTime::from_utc(ends_at),
+            )?;
+            Ok(period)
+        }
+    }
+}
+
+fn from_settings(settings: Settings) -> Result<data::settings::Settings> {
+    let SettingsContent {
+        updated_at,
+        author_id,
+        administrator_email,
+        active_festival_id,
+        project_creation_periods,
+    } = settings.into_content();
+
+    let project_creation_periods: HashMap<_, _> = project_creation_periods
+        .into_iter()
+        .map(|(category, period)| (category, from_project_creation_period(period)))
+        .collect();
+
+    Ok(data::settings::Settings {
+        updated_at: updated_at.utc(),
+        author_id: author_id.map(|id| id.0),
+        administrator_email: administrator_email.into_string(),
+        active_festival_id: active_festival_id.to_uuid(),
+        project_creation_periods: serde_json::to_value(project_creation_periods)?,
+    })
+}
+
+fn to_settings(settings: data::settings::Settings) -> Result<Settings> {
+    let data::settings::Settings {
+        updated_at,
+        author_id,
+        administrator_email,
+        active_festival_id,
+        project_creation_periods,
+    } = settings;
+
+    let project_creation_periods: HashMap<ProjectCategory, StoredProjectCreationPeriod> =
+        serde_json::from_value(project_creation_periods)?;
+    let project_creation_periods = project_creation_periods
+        .into_iter()
+        .map(|(category, period)| Ok((category, to_project_creation_period(period)?)))
+        .collect::<Result<_>>()?;
+
+    Ok(Settings::from_content(SettingsContent {
+        updated_at: DateTime::from_utc(updated_at),
+        author_id: author_id.map(UserId),
+        administrator_email: UserEmailAddress::from_string(administrator_email)?,
+        active_festival_id: FestivalId::from_uuid(active_festival_id),
+        project_creation_periods,
+    }))
+}
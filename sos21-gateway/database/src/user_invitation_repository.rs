@@ -8,7 +8,8 @@ use sos21_domain::model::{
     date_time::DateTime,
     user::{UserEmailAddress, UserId},
     user_invitation::{
-        UserInvitation, UserInvitationContent, UserInvitationId, UserInvitationRole,
+        UserInvitation, UserInvitationAcceptance, UserInvitationContent, UserInvitationId,
+        UserInvitationRole,
     },
 };
 use sqlx::{Postgres, Transaction};
@@ -31,6 +32,10 @@ impl UserInvitationRepository for UserInvitationDatabase {
                 id: invitation.id,
                 email: invitation.email,
                 role: invitation.role,
+                allows_unlisted_domain: invitation.allows_unlisted_domain,
+                expires_at: invitation.expires_at,
+                accepted_at: invitation.accepted_at,
+                accepted_user_id: invitation.accepted_user_id,
             };
             command::update_user_invitation(&mut *lock, input).await
         } else {
@@ -76,14 +81,29 @@ fn from_user_invitation(invitation: UserInvitation) -> data::user_invitation::Us
         author_id,
         email,
         role,
+        allows_unlisted_domain,
+        expires_at,
+        acceptance,
     } = invitation.into_content();
 
+    let (accepted_at, accepted_user_id) = match acceptance {
+        Some(acceptance) => (
+            Some(acceptance.accepted_at.utc()),
+            Some(acceptance.user_id.0),
+        ),
+        None => (None, None),
+    };
+
     data::user_invitation::UserInvitation {
         id: id.to_uuid(),
         created_at: created_at.utc(),
         author_id: author_id.0,
         email: email.into_string(),
         role: from_user_invitation_role(role),
+        allows_unlisted_domain,
+        expires_at: expires_at.map(|expires_at| expires_at.utc()),
+        accepted_at,
+        accepted_user_id,
     }
 }
 
@@ -108,14 +128,30 @@ fn to_user_invitation(invitation: data::user_invitation::UserInvitation) -> Resu
         author_id,
         email,
         role,
+        allows_unlisted_domain,
+        expires_at,
+        accepted_at,
+        accepted_user_id,
     } = invitation;
 
+    let acceptance = match (accepted_at, accepted_user_id) {
+        (Some(accepted_at), Some(user_id)) => Some(UserInvitationAcceptance {
+            accepted_at: DateTime::from_utc(accepted_at),
+            user_id: UserId(user_id),
+        }),
+        (None, None) => None,
+        _ => anyhow::bail!("accepted_at and accepted_user_id must be set together"),
+    };
+
     Ok(UserInvitation::from_content(UserInvitationContent {
         id: UserInvitationId::from_uuid(id),
         created_at: DateTime::from_utc(created_at),
         author_id: UserId(author_id),
         email: UserEmailAddress::from_string(email)?,
         role: to_user_invitation_role(role),
+        allows_unlisted_domain,
+        expires_at: expires_at.map(DateTime::from_utc),
+        acceptance,
     }))
 }
 
@@ -5,13 +5,15 @@ use ref_cast::RefCast;
 use sos21_database::{command, model as data, query};
 use sos21_domain::context::UserRepository;
 use sos21_domain::model::{
+    custom_role::CustomRoleId,
     date_time::DateTime,
     pending_project::PendingProjectId,
+    permissions::Permissions,
     phone_number::PhoneNumber,
     project::ProjectId,
     user::{
-        User, UserAssignment, UserCategory, UserContent, UserEmailAddress, UserId, UserKanaName,
-        UserName, UserRole,
+        User, UserAssignment, UserCategory, UserContent, UserCustomRole, UserEmailAddress, UserId,
+        UserKanaName, UserName, UserRole, UserSuspension, UserSuspensionReason,
     },
 };
 use sqlx::{Postgres, Transaction};
@@ -43,6 +45,11 @@ impl UserRepository for UserDatabase {
                 assignment_owner_project_id: user.assignment_owner_project_id,
                 assignment_subowner_project_id: user.assignment_subowner_project_id,
                 assignment_owner_pending_project_id: user.assignment_owner_pending_project_id,
+                custom_role_id: user.custom_role_id,
+                suspended_at: user.suspended_at,
+                suspended_by: user.suspended_by,
+                suspension_reason: user.suspension_reason,
+                tokens_revoked_at: user.tokens_revoked_at,
             };
             command::update_user(&mut *lock, input).await
         } else {
@@ -84,6 +91,9 @@ fn from_user(user: User) -> data::user::User {
         role,
         category,
         assignment,
+        custom_role,
+        suspension,
+        tokens_revoked_at,
     } = user.into_content();
 
     let (first_name, last_name) = name.into_string();
@@ -97,6 +107,19 @@ fn from_user(user: User) -> data::user::User {
         None => (None, None, None),
     };
 
+    let (suspended_at, suspended_by, suspension_reason) = match suspension {
+        Some(UserSuspension {
+            suspended_at,
+            suspended_by,
+            reason,
+        }) => (
+            Some(suspended_at.utc()),
+            suspended_by.map(|id| id.0),
+            Some(reason.into_string()),
+        ),
+        None => (None, None, None),
+    };
+
     let category = match category {
         UserCategory::UndergraduateStudent => data::user::UserCategory::UndergraduateStudent,
         UserCategory::GraduateStudent => data::user::UserCategory::GraduateStudent,
@@ -123,6 +146,12 @@ fn from_user(user: User) -> data::user::User {
         assignment_owner_project_id: owner_project_id.map(|id| id.to_uuid()),
         assignment_subowner_project_id: subowner_project_id.map(|id| id.to_uuid()),
         assignment_owner_pending_project_id: owner_pending_project_id.map(|id| id.to_uuid()),
+        custom_role_id: custom_role.map(|custom_role| custom_role.id.to_uuid()),
+        custom_role_permissions: None,
+        suspended_at,
+        suspended_by,
+        suspension_reason,
+        tokens_revoked_at: tokens_revoked_at.map(|revoked_at| revoked_at.utc()),
     }
 }
 
@@ -150,6 +179,12 @@ pub fn to_user(user: data::user::User) -> Result<User> {
         assignment_owner_project_id,
         assignment_subowner_project_id,
         assignment_owner_pending_project_id,
+        custom_role_id,
+        custom_role_permissions,
+        suspended_at,
+        suspended_by,
+        suspension_reason,
+        tokens_revoked_at,
     } = user;
 
     let assignment = if let Some(assignment) = assignment {
@@ -177,6 +212,27 @@ pub fn to_user(user: data::user::User) -> Result<User> {
         None
     };
 
+    let custom_role = if let Some(custom_role_id) = custom_role_id {
+        let names = custom_role_permissions
+            .context("custom_role_id is set but custom_role_permissions is null")?;
+        Some(UserCustomRole {
+            id: CustomRoleId::from_uuid(custom_role_id),
+            permissions: to_permissions(names)?,
+        })
+    } else {
+        None
+    };
+
+    let suspension = match (suspended_at, suspension_reason) {
+        (Some(suspended_at), Some(reason)) => Some(UserSuspension {
+            suspended_at: DateTime::from_utc(suspended_at),
+            suspended_by: suspended_by.map(UserId),
+            reason: UserSuspensionReason::from_string(reason)?,
+        }),
+        (None, None) => None,
+        _ => anyhow::bail!("suspended_at and suspension_reason must be set together"),
+    };
+
     let category = match category {
         data::user::UserCategory::UndergraduateStudent => UserCategory::UndergraduateStudent,
         data::user::UserCategory::GraduateStudent => UserCategory::GraduateStudent,
@@ -198,5 +254,18 @@ pub fn to_user(user: data::user::User) -> Result<User> {
         },
         category,
         assignment,
+        custom_role,
+        suspension,
+        tokens_revoked_at: tokens_revoked_at.map(DateTime::from_utc),
     }))
 }
+
+pub fn to_permissions(names: Vec<String>) -> Result<Permissions> {
+    names
+        .into_iter()
+        .try_fold(Permissions::empty(), |acc, name| {
+            let permission = Permissions::from_name(&name)
+                .with_context(|| format!("unknown permission name {:?}", name))?;
+            Ok(acc | permission)
+        })
+}
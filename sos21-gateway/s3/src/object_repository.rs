@@ -1,5 +1,7 @@
 use std::convert::TryInto;
 use std::fmt::{self, Debug};
+use std::future::Future;
+use std::time::Instant;
 
 use anyhow::Context;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
@@ -75,7 +77,7 @@ impl ObjectRepository for ObjectS3 {
             key: object_key,
             ..Default::default()
         };
-        let result = self.client.get_object(request).await;
+        let result = timed("get_object", self.client.get_object(request)).await;
         if let Err(RusotoError::Service(rusoto_s3::GetObjectError::NoSuchKey(_))) = result {
             return Ok(None);
         }
@@ -110,10 +112,12 @@ impl ObjectS3 {
             key: object_key.clone(),
             ..Default::default()
         };
-        let create_multipart_output = self
-            .client
-            .create_multipart_upload(create_multipart_request)
-            .await?;
+        let create_multipart_output = timed(
+            "create_multipart_upload",
+            self.client
+                .create_multipart_upload(create_multipart_request),
+        )
+        .await?;
 
         let upload_id = create_multipart_output
             .upload_id
@@ -137,14 +141,16 @@ impl ObjectS3 {
                     upload_id,
                     ..Default::default()
                 };
-                self.client
-                    .abort_multipart_upload(abort_multipart_request)
-                    .await
-                    .context(if let Err(err) = &upload_result {
-                        format!("Failed to abort multipart upload (abort cause: {})", err)
-                    } else {
-                        "Failed to abort multipart upload on exceeding the limit".to_owned()
-                    })?;
+                timed(
+                    "abort_multipart_upload",
+                    self.client.abort_multipart_upload(abort_multipart_request),
+                )
+                .await
+                .context(if let Err(err) = &upload_result {
+                    format!("Failed to abort multipart upload (abort cause: {})", err)
+                } else {
+                    "Failed to abort multipart upload on exceeding the limit".to_owned()
+                })?;
             }
             _ => {}
         }
@@ -233,7 +239,11 @@ where
         }),
         ..Default::default()
     };
-    client.complete_multipart_upload(complete_request).await?;
+    timed(
+        "complete_multipart_upload",
+        client.complete_multipart_upload(complete_request),
+    )
+    .await?;
 
     Ok(StoreObjectResult::Stored)
 }
@@ -268,7 +278,7 @@ async fn upload_part(input: UploadPartInput) -> anyhow::Result<rusoto_s3::Comple
         upload_id,
         ..Default::default()
     };
-    let output = client.upload_part(upload_request).await?;
+    let output = timed("upload_part", client.upload_part(upload_request)).await?;
     let e_tag = output
         .e_tag
         .context("No e_tag in the response of UploadPart")?;
@@ -279,6 +289,21 @@ async fn upload_part(input: UploadPartInput) -> anyhow::Result<rusoto_s3::Comple
     })
 }
 
+/// Records the time taken by the S3 request to the `sos21_s3_request_duration_seconds` metric.
+async fn timed<F>(operation: &'static str, request: F) -> F::Output
+where
+    F: Future,
+{
+    let started_at = Instant::now();
+    let output = request.await;
+    metrics::histogram!(
+        "sos21_s3_request_duration_seconds",
+        started_at.elapsed().as_secs_f64(),
+        "operation" => operation
+    );
+    output
+}
+
 fn to_object_key(id: ObjectId) -> String {
     id.to_uuid().to_hyphenated().to_string()
 }
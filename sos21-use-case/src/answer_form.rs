@@ -6,8 +6,8 @@ use crate::model::project::ProjectId;
 
 use anyhow::Context;
 use sos21_domain::context::{
-    FileRepository, FileSharingRepository, FormAnswerRepository, FormRepository, Login,
-    ProjectRepository,
+    ConfigContext, FileRepository, FileSharingRepository, FormAnswerRepository, FormRepository,
+    Login, ProjectRepository,
 };
 use sos21_domain::model::{date_time::DateTime, form};
 
@@ -68,6 +68,7 @@ where
         + FormAnswerRepository
         + FileRepository
         + FileSharingRepository
+        + ConfigContext
         + Send
         + Sync,
 {
@@ -107,7 +108,10 @@ where
     ctx.store_form_answer(answer.clone())
         .await
         .context("Failed to store a form answer")?;
-    use_case_ensure!(answer.is_visible_to_with_project(login_user, &project));
+    use_case_ensure!(
+        answer.is_visible_to_with_project(login_user, &project)
+            || answer.is_visible_to_with_form(login_user, &form)
+    );
 
     // Notify Slack
 
@@ -120,7 +120,10 @@ where
     ctx.store_registration_form_answer(answer.clone())
         .await
         .context("Failed to store a registration form answer")?;
-    use_case_ensure!(answer.is_visible_to_with_pending_project(login_user, &pending_project));
+    use_case_ensure!(
+        answer.is_visible_to_with_pending_project(login_user, &pending_project)
+            || answer.is_visible_to_with_registration_form(login_user, &registration_form)
+    );
     Ok(RegistrationFormAnswer::from_entity(answer))
 }
 
@@ -3,7 +3,7 @@ use crate::model::user::User;
 use crate::model::user_invitation::{UserInvitation, UserInvitationRole};
 
 use anyhow::Context;
-use sos21_domain::context::{Login, UserInvitationRepository, UserRepository};
+use sos21_domain::context::{ConfigContext, Login, UserInvitationRepository, UserRepository};
 use sos21_domain::model::{user, user_invitation};
 
 #[derive(Debug, Clone)]
@@ -52,6 +52,9 @@ impl Error {
 pub struct Input {
     pub email: String,
     pub role: UserInvitationRole,
+    /// Let the invited address sign up even if its domain is not in the allowed domains.
+    /// This is ignored when the user with the address has already signed up.
+    pub allows_unlisted_domain: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -63,12 +66,16 @@ pub enum Output {
 #[tracing::instrument(skip(ctx))]
 pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<Output, Error>
 where
-    C: UserInvitationRepository + UserRepository + Send + Sync,
+    C: UserInvitationRepository + UserRepository + ConfigContext + Send + Sync,
 {
     let login_user = ctx.login_user();
 
-    let email = user::UserEmailAddress::from_string(input.email)
-        .map_err(|err| UseCaseError::UseCase(Error::from_email_error(err)))?;
+    let email = if input.allows_unlisted_domain {
+        user::UserEmailAddress::from_string(input.email)
+    } else {
+        user::UserEmailAddress::from_string_with_domains(input.email, ctx.allowed_email_domains())
+    }
+    .map_err(|err| UseCaseError::UseCase(Error::from_email_error(err)))?;
     let role = input.role.into_entity();
 
     if let Some(mut user) = ctx
@@ -90,7 +97,10 @@ where
             .await
             .context("Failed to get user invitation")?
         {
-            if invitation.role() == role {
+            if invitation.role() == role
+                && invitation.allows_unlisted_domain() == input.allows_unlisted_domain
+                && !invitation.is_expired()
+            {
                 use_case_ensure!(invitation.is_visible_to(login_user));
                 return Ok(Output::Invitation(UserInvitation::from_entity(invitation)));
             } else {
@@ -98,11 +108,17 @@ where
             }
         }
 
-        let invitation = user_invitation::UserInvitation::new(ctx, login_user, email, role)
-            .await
-            .map_err(|err| {
-                UseCaseError::from_domain(err, Error::from_new_invitation_error).flatten()
-            })?;
+        let invitation = user_invitation::UserInvitation::new(
+            ctx,
+            login_user,
+            email,
+            role,
+            input.allows_unlisted_domain,
+        )
+        .await
+        .map_err(|err| {
+            UseCaseError::from_domain(err, Error::from_new_invitation_error).flatten()
+        })?;
 
         ctx.store_user_invitation(invitation.clone())
             .await
@@ -0,0 +1,191 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::interface;
+use crate::model::api_key::{ApiKey, ApiKeyScope};
+
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use sos21_domain::context::{ApiKeyRepository, Login};
+use sos21_domain::model::{api_key, date_time};
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub name: String,
+    pub scope: ApiKeyScope,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    InvalidName,
+    UnknownPermission(String),
+    PastExpiry,
+    InsufficientPermissions,
+}
+
+impl Error {
+    fn from_name_error(_err: api_key::name::NameError) -> Self {
+        Error::InvalidName
+    }
+
+    fn from_permissions_error(err: interface::permissions::UnknownPermissionError) -> Self {
+        Error::UnknownPermission(err.name)
+    }
+
+    fn from_new_api_key_error(err: api_key::NewApiKeyError) -> Self {
+        match err.kind() {
+            api_key::NewApiKeyErrorKind::InsufficientPermissions => Error::InsufficientPermissions,
+            api_key::NewApiKeyErrorKind::PastExpiry => Error::PastExpiry,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Output {
+    pub key: ApiKey,
+    /// The token to authenticate with the key, which cannot be obtained afterwards.
+    pub token: String,
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<Output, Error>
+where
+    C: ApiKeyRepository + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    let name = api_key::ApiKeyName::from_string(input.name)
+        .map_err(|err| UseCaseError::UseCase(Error::from_name_error(err)))?;
+    let scope = match input.scope {
+        ApiKeyScope::Role(role) => api_key::ApiKeyScope::Role(role.into_entity()),
+        ApiKeyScope::Permissions(permissions) => {
+            let permissions = interface::permissions::to_permissions(permissions)
+                .map_err(|err| UseCaseError::UseCase(Error::from_permissions_error(err)))?;
+            api_key::ApiKeyScope::Permissions(permissions)
+        }
+    };
+    let expires_at = input.expires_at.map(date_time::DateTime::from_utc);
+
+    let (key, token) = api_key::ApiKey::new(login_user, name, scope, expires_at)
+        .map_err(|err| UseCaseError::UseCase(Error::from_new_api_key_error(err)))?;
+    ctx.store_api_key(key.clone())
+        .await
+        .context("Failed to store an API key")?;
+    use_case_ensure!(key.is_visible_to(login_user));
+    Ok(Output {
+        key: ApiKey::from_entity(key),
+        token: token.into_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{api_key::ApiKeyScope, user::UserRole};
+    use crate::{create_api_key, UseCaseError};
+    use sos21_domain::context::Login;
+    use sos21_domain::model::api_key::ApiKeyToken;
+    use sos21_domain::test;
+
+    fn mock_input() -> create_api_key::Input {
+        create_api_key::Input {
+            name: test::model::mock_api_key_name().into_string(),
+            scope: ApiKeyScope::Permissions(vec!["READ_ALL_PROJECTS".to_owned()]),
+            expires_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_operator() {
+        let user = test::model::new_operator_user();
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        assert!(matches!(
+            create_api_key::run(&app, mock_input()).await,
+            Err(UseCaseError::UseCase(
+                create_api_key::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_admin() {
+        let user = test::model::new_admin_user();
+
+        let app = test::build_mock_app().users(vec![user.clone()]).build();
+        let login = app.clone().login_as(user.clone()).await;
+
+        let output = create_api_key::run(&login, mock_input()).await.unwrap();
+        assert_eq!(
+            output.key.scope,
+            ApiKeyScope::Permissions(vec!["READ_ALL_PROJECTS".to_owned()])
+        );
+
+        let token = ApiKeyToken::from_string(output.token).unwrap();
+        let login = Login::with_api_key(app, &token).await.unwrap();
+        assert_eq!(login.login_user().id(), user.id());
+    }
+
+    #[tokio::test]
+    async fn test_role() {
+        let user = test::model::new_admin_user();
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let input = create_api_key::Input {
+            scope: ApiKeyScope::Role(UserRole::Committee),
+            ..mock_input()
+        };
+        let output = create_api_key::run(&app, input).await.unwrap();
+        assert_eq!(output.key.scope, ApiKeyScope::Role(UserRole::Committee));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_permission() {
+        let user = test::model::new_admin_user();
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let input = create_api_key::Input {
+            scope: ApiKeyScope::Permissions(vec!["FLY_TO_THE_MOON".to_owned()]),
+            ..mock_input()
+        };
+        assert!(matches!(
+            create_api_key::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                create_api_key::Error::UnknownPermission(name)
+            )) if name == "FLY_TO_THE_MOON"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_past_expiry() {
+        let user = test::model::new_admin_user();
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let input = create_api_key::Input {
+            expires_at: Some(chrono::Utc::now() - chrono::Duration::hours(1)),
+            ..mock_input()
+        };
+        assert!(matches!(
+            create_api_key::run(&app, input).await,
+            Err(UseCaseError::UseCase(create_api_key::Error::PastExpiry))
+        ));
+    }
+}
@@ -0,0 +1,128 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::interface;
+use crate::model::custom_role::CustomRole;
+
+use anyhow::Context;
+use sos21_domain::context::{CustomRoleRepository, Login};
+use sos21_domain::model::custom_role;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub name: String,
+    pub permissions: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    InvalidName,
+    UnknownPermission(String),
+    InsufficientPermissions,
+}
+
+impl Error {
+    fn from_name_error(_err: custom_role::name::NameError) -> Self {
+        Error::InvalidName
+    }
+
+    fn from_permissions_error(err: interface::permissions::UnknownPermissionError) -> Self {
+        Error::UnknownPermission(err.name)
+    }
+
+    fn from_new_custom_role_error(_err: custom_role::NoUpdatePermissionError) -> Self {
+        Error::InsufficientPermissions
+    }
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<CustomRole, Error>
+where
+    C: CustomRoleRepository + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    let name = custom_role::CustomRoleName::from_string(input.name)
+        .map_err(|err| UseCaseError::UseCase(Error::from_name_error(err)))?;
+    let permissions = interface::permissions::to_permissions(input.permissions)
+        .map_err(|err| UseCaseError::UseCase(Error::from_permissions_error(err)))?;
+
+    let role = custom_role::CustomRole::new(login_user, name, permissions)
+        .map_err(|err| UseCaseError::UseCase(Error::from_new_custom_role_error(err)))?;
+    ctx.store_custom_role(role.clone())
+        .await
+        .context("Failed to store a custom role")?;
+    use_case_ensure!(role.is_visible_to(login_user));
+    Ok(CustomRole::from_entity(role))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{create_custom_role, UseCaseError};
+    use sos21_domain::context::CustomRoleRepository;
+    use sos21_domain::test;
+
+    fn mock_input() -> create_custom_role::Input {
+        create_custom_role::Input {
+            name: test::model::mock_custom_role_name().into_string(),
+            permissions: vec!["READ_ALL_USERS".to_owned(), "DISTRIBUTE_FILES".to_owned()],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_operator() {
+        let user = test::model::new_operator_user();
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        assert!(matches!(
+            create_custom_role::run(&app, mock_input()).await,
+            Err(UseCaseError::UseCase(
+                create_custom_role::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_admin() {
+        let user = test::model::new_admin_user();
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let role = create_custom_role::run(&app, mock_input()).await.unwrap();
+        assert_eq!(role.permissions, vec!["READ_ALL_USERS", "DISTRIBUTE_FILES"]);
+        assert!(app
+            .get_custom_role(role.id.into_entity())
+            .await
+            .unwrap()
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn test_unknown_permission() {
+        let user = test::model::new_admin_user();
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let input = create_custom_role::Input {
+            permissions: vec!["FLY_TO_THE_MOON".to_owned()],
+            ..mock_input()
+        };
+        assert!(matches!(
+            create_custom_role::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                create_custom_role::Error::UnknownPermission(name)
+            )) if name == "FLY_TO_THE_MOON"
+        ));
+    }
+}
@@ -0,0 +1,111 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::festival::Festival;
+
+use anyhow::Context;
+use sos21_domain::context::{ConfigContext, FestivalRepository, Login};
+use sos21_domain::model::festival;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub name: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    InvalidName,
+    InsufficientPermissions,
+}
+
+impl Error {
+    fn from_name_error(_err: festival::name::NameError) -> Self {
+        Error::InvalidName
+    }
+
+    fn from_new_festival_error(_err: festival::NewFestivalError) -> Self {
+        Error::InsufficientPermissions
+    }
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<Festival, Error>
+where
+    C: FestivalRepository + ConfigContext + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    let name = festival::FestivalName::from_string(input.name)
+        .map_err(|err| UseCaseError::UseCase(Error::from_name_error(err)))?;
+    let festival = festival::Festival::new(login_user, name)
+        .map_err(|err| UseCaseError::UseCase(Error::from_new_festival_error(err)))?;
+
+    ctx.store_festival(festival.clone())
+        .await
+        .context("Failed to store a festival")?;
+    use_case_ensure!(festival.is_visible_to(login_user));
+
+    let is_active = festival.is_active(ctx);
+    Ok(Festival::from_entity(festival, is_active))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{create_festival, get_festival, UseCaseError};
+    use sos21_domain::test;
+
+    #[tokio::test]
+    async fn test_operator() {
+        let user = test::model::new_operator_user();
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user)
+            .await;
+
+        let input = create_festival::Input {
+            name: "第48回雙峰祭".to_owned(),
+        };
+        assert!(matches!(
+            create_festival::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                create_festival::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_admin() {
+        let user = test::model::new_admin_user();
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user)
+            .await;
+
+        let input = create_festival::Input {
+            name: "第48回雙峰祭".to_owned(),
+        };
+        let festival = create_festival::run(&app, input).await.unwrap();
+        assert!(!festival.is_active);
+
+        let got = get_festival::run(&app, festival.id).await.unwrap();
+        assert_eq!(got.name, "第48回雙峰祭");
+    }
+
+    #[tokio::test]
+    async fn test_admin_invalid_name() {
+        let user = test::model::new_admin_user();
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user)
+            .await;
+
+        let input = create_festival::Input {
+            name: "".to_owned(),
+        };
+        assert!(matches!(
+            create_festival::run(&app, input).await,
+            Err(UseCaseError::UseCase(create_festival::Error::InvalidName))
+        ));
+    }
+}
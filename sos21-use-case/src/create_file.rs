@@ -52,6 +52,11 @@ where
         .require_permissions(Permissions::CREATE_FILES)
         .map_err(|err| UseCaseError::UseCase(Error::from_permissions_error(err)))?;
 
+    // the files are owned by the author, which is not accessible with API keys
+    if login_user.is_with_api_key() {
+        return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
+    }
+
     let name = input
         .name
         .map(file::FileName::from_string)
@@ -3,7 +3,7 @@ use crate::interface;
 use crate::model::form::{Form, FormCondition, FormItem};
 
 use anyhow::Context;
-use sos21_domain::context::{FormRepository, Login};
+use sos21_domain::context::{ConfigContext, FormRepository, Login};
 use sos21_domain::model::{date_time::DateTime, form};
 
 #[derive(Debug, Clone)]
@@ -60,7 +60,7 @@ impl Error {
 #[tracing::instrument(skip(ctx))]
 pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<Form, Error>
 where
-    C: FormRepository + Send + Sync,
+    C: FormRepository + ConfigContext + Send + Sync,
 {
     let login_user = ctx.login_user();
 
@@ -79,6 +79,7 @@ where
     let answer_notification_webhook = input.answer_notification_webhook;
 
     let form = form::Form::new(
+        ctx,
         login_user,
         name,
         description,
@@ -209,7 +210,7 @@ mod tests {
         assert!(got.name == name);
         assert!(got.author_id == UserId::from_entity(user.id().clone()));
 
-        assert!(matches!(get_form::run(&app, got.id).await, Ok(_)));
+        assert!(get_form::run(&app, got.id).await.is_ok());
     }
 
     #[tokio::test]
@@ -0,0 +1,190 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::form::Form;
+use crate::model::form_template::FormTemplateId;
+
+use anyhow::Context;
+use sos21_domain::context::{ConfigContext, FormRepository, FormTemplateRepository, Login};
+use sos21_domain::model::permissions::Permissions;
+use sos21_domain::model::{date_time::DateTime, form};
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub template_id: FormTemplateId,
+    /// `None` to use the name of the template.
+    pub name: Option<String>,
+    pub starts_at: chrono::DateTime<chrono::Utc>,
+    pub ends_at: chrono::DateTime<chrono::Utc>,
+    pub answer_notification_webhook: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    TemplateNotFound,
+    InvalidName,
+    InvalidPeriod,
+    TooEarlyPeriodStart,
+    InsufficientPermissions,
+}
+
+impl Error {
+    fn from_name_error(_err: form::name::NameError) -> Self {
+        Error::InvalidName
+    }
+
+    fn from_period_error(_err: form::period::PeriodError) -> Self {
+        Error::InvalidPeriod
+    }
+
+    fn from_new_form_error(err: form::NewFormError) -> Self {
+        match err.kind() {
+            form::NewFormErrorKind::TooEarlyPeriodStart => Error::TooEarlyPeriodStart,
+            form::NewFormErrorKind::InsufficientPermissions => Error::InsufficientPermissions,
+        }
+    }
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<Form, Error>
+where
+    C: FormRepository + FormTemplateRepository + ConfigContext + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    if login_user
+        .require_permissions(Permissions::READ_ALL_FORMS)
+        .is_err()
+    {
+        return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
+    }
+
+    let template = ctx
+        .get_form_template(input.template_id.into_entity())
+        .await
+        .context("Failed to get a form template")?;
+    let template = match template {
+        Some(template) => template,
+        None => return Err(UseCaseError::UseCase(Error::TemplateNotFound)),
+    };
+    use_case_ensure!(template.is_visible_to(login_user));
+
+    let name = match input.name {
+        Some(name) => form::FormName::from_string(name)
+            .map_err(|err| UseCaseError::UseCase(Error::from_name_error(err)))?,
+        None => template.name().clone(),
+    };
+    let starts_at = DateTime::from_utc(input.starts_at);
+    let ends_at = DateTime::from_utc(input.ends_at);
+    let period = form::FormPeriod::from_datetime(starts_at, ends_at)
+        .map_err(|err| UseCaseError::UseCase(Error::from_period_error(err)))?;
+    let items = template
+        .items()
+        .duplicate()
+        .context("Failed to duplicate form items")?;
+    let condition = form::FormCondition {
+        query: template.query().clone(),
+        includes: form::FormConditionProjectSet::empty(),
+        excludes: form::FormConditionProjectSet::empty(),
+    };
+
+    let form = form::Form::new(
+        ctx,
+        login_user,
+        name,
+        template.description().clone(),
+        period,
+        items,
+        condition,
+        input.answer_notification_webhook,
+    )
+    .map_err(|err| UseCaseError::UseCase(Error::from_new_form_error(err)))?;
+    ctx.store_form(form.clone())
+        .await
+        .context("Failed to store a form")?;
+    use_case_ensure!(form.is_visible_to(login_user));
+    Ok(Form::from_entity(form))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::form_template::FormTemplateId;
+    use crate::{create_form_from_template, get_form, UseCaseError};
+    use sos21_domain::{model::date_time, test};
+
+    fn mock_input(template_id: FormTemplateId) -> create_form_from_template::Input {
+        let period = test::model::mock_form_period_with_start(date_time::DateTime::from_utc(
+            chrono::Utc::now() + chrono::Duration::hours(1),
+        ));
+        create_form_from_template::Input {
+            template_id,
+            name: None,
+            starts_at: period.starts_at().utc(),
+            ends_at: period.ends_at().utc(),
+            answer_notification_webhook: None,
+        }
+    }
+
+    // Checks that the (unprivileged) committee user cannot create forms from templates.
+    #[tokio::test]
+    async fn test_committee() {
+        let user = test::model::new_committee_user();
+        let operator = test::model::new_operator_user();
+        let template = test::model::new_form_template(operator.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), operator.clone()])
+            .form_templates(vec![template.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let input = mock_input(FormTemplateId::from_entity(template.id()));
+        assert!(matches!(
+            create_form_from_template::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                create_form_from_template::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    // Checks that the privileged committee user can create forms from templates.
+    #[tokio::test]
+    async fn test_operator() {
+        let user = test::model::new_operator_user();
+        let template = test::model::new_form_template(user.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .form_templates(vec![template.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let input = mock_input(FormTemplateId::from_entity(template.id()));
+        let got = create_form_from_template::run(&app, input).await.unwrap();
+        assert_eq!(got.name, template.name().clone().into_string());
+        assert_eq!(got.items.len(), template.items().items().count());
+
+        assert!(get_form::run(&app, got.id).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_not_found() {
+        let user = test::model::new_operator_user();
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let input = mock_input(FormTemplateId::from_entity(
+            test::model::new_form_template_id(),
+        ));
+        assert!(matches!(
+            create_form_from_template::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                create_form_from_template::Error::TemplateNotFound
+            ))
+        ));
+    }
+}
@@ -0,0 +1,128 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::interface;
+use crate::model::form::FormItem;
+use crate::model::form_template::FormTemplate;
+use crate::model::project_query::ProjectQuery;
+
+use anyhow::Context;
+use sos21_domain::context::{FormTemplateRepository, Login};
+use sos21_domain::model::{form, form_template};
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub name: String,
+    pub description: String,
+    pub items: Vec<FormItem>,
+    pub query: ProjectQuery,
+}
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    InvalidName,
+    InvalidDescription,
+    InvalidItems(interface::form::FormItemsError),
+    InvalidQuery(interface::project_query::ProjectQueryError),
+    InsufficientPermissions,
+}
+
+impl Error {
+    fn from_name_error(_err: form::name::NameError) -> Self {
+        Error::InvalidName
+    }
+
+    fn from_description_error(_err: form::description::DescriptionError) -> Self {
+        Error::InvalidDescription
+    }
+
+    fn from_items_error(err: interface::form::FormItemsError) -> Self {
+        Error::InvalidItems(err)
+    }
+
+    fn from_query_error(err: interface::project_query::ProjectQueryError) -> Self {
+        Error::InvalidQuery(err)
+    }
+
+    fn from_new_form_template_error(_err: form_template::NewFormTemplateError) -> Self {
+        Error::InsufficientPermissions
+    }
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<FormTemplate, Error>
+where
+    C: FormTemplateRepository + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    let name = form::FormName::from_string(input.name)
+        .map_err(|err| UseCaseError::UseCase(Error::from_name_error(err)))?;
+    let description = form::FormDescription::from_string(input.description)
+        .map_err(|err| UseCaseError::UseCase(Error::from_description_error(err)))?;
+    let items = interface::form::to_form_items(input.items)
+        .map_err(|err| UseCaseError::UseCase(Error::from_items_error(err)))?;
+    let query = interface::project_query::to_project_query(input.query)
+        .map_err(|err| UseCaseError::UseCase(Error::from_query_error(err)))?;
+
+    let template = form_template::FormTemplate::new(login_user, name, description, items, query)
+        .map_err(|err| UseCaseError::UseCase(Error::from_new_form_template_error(err)))?;
+    ctx.store_form_template(template.clone())
+        .await
+        .context("Failed to store a form template")?;
+    use_case_ensure!(template.is_visible_to(login_user));
+    Ok(FormTemplate::from_entity(template))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{form::FormItem, project_query::ProjectQuery, user::UserId};
+    use crate::{create_form_template, get_form_template, UseCaseError};
+    use sos21_domain::test;
+
+    fn mock_input() -> create_form_template::Input {
+        create_form_template::Input {
+            name: test::model::mock_form_name().into_string(),
+            description: test::model::mock_form_description().into_string(),
+            items: test::model::new_form_items()
+                .into_items()
+                .map(FormItem::from_entity)
+                .collect(),
+            query: ProjectQuery::from_entity(test::model::mock_project_query()),
+        }
+    }
+
+    // Checks that the (unprivileged) committee user cannot create form templates.
+    #[tokio::test]
+    async fn test_committee() {
+        let user = test::model::new_committee_user();
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        assert!(matches!(
+            create_form_template::run(&app, mock_input()).await,
+            Err(UseCaseError::UseCase(
+                create_form_template::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    // Checks that the privileged committee user can create form templates.
+    #[tokio::test]
+    async fn test_operator() {
+        let user = test::model::new_operator_user();
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let got = create_form_template::run(&app, mock_input()).await.unwrap();
+        assert_eq!(got.author_id, UserId::from_entity(user.id().clone()));
+
+        assert!(get_form_template::run(&app, got.id).await.is_ok());
+    }
+}
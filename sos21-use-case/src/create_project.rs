@@ -20,6 +20,7 @@ pub enum Error {
     AlreadyProjectSubowner,
     AlreadyPendingProjectOwner,
     OutOfCreationPeriod,
+    InsufficientPermissions,
 }
 
 impl Error {
@@ -61,6 +62,11 @@ where
 {
     let mut login_user = ctx.login_user().clone();
 
+    // API keys cannot be subowners of projects, which they are not given the accesses to
+    if login_user.is_with_api_key() {
+        return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
+    }
+
     let result = ctx
         .get_pending_project(pending_project_id.into_entity())
         .await
@@ -344,7 +350,7 @@ mod tests {
         assert!(matches!(
             create_project::run(&app, PendingProjectId::from_entity(pending_project.id())).await,
             Err(UseCaseError::UseCase(
-                create_project::Error::NotAnsweredRegistrationForm { .. }
+                create_project::Error::NotAnsweredRegistrationForm
             ))
         ));
     }
@@ -7,7 +7,9 @@ use crate::model::registration_form::RegistrationForm;
 use anyhow::Context;
 use sos21_domain::context::{ConfigContext, Login, RegistrationFormRepository};
 use sos21_domain::model::permissions::Permissions;
-use sos21_domain::model::{date_time::DateTime, registration_form, user};
+use sos21_domain::model::{
+    access_control::AccessControlList, date_time::DateTime, registration_form, user,
+};
 use uuid::Uuid;
 
 #[derive(Debug, Clone)]
@@ -86,12 +88,14 @@ where
 
     let registration_form = registration_form::RegistrationForm {
         id: registration_form::RegistrationFormId::from_uuid(Uuid::new_v4()),
+        festival_id: ctx.active_festival_id(),
         created_at: DateTime::now(),
         author_id: login_user.id().clone(),
         name,
         description,
         items,
         query,
+        access_control: AccessControlList::empty(),
     };
     ctx.store_registration_form(registration_form.clone())
         .await
@@ -204,10 +208,7 @@ mod tests {
         assert!(got.name == name);
         assert!(got.author_id == UserId::from_entity(user.id().clone()));
 
-        assert!(matches!(
-            get_registration_form::run(&app, got.id).await,
-            Ok(_)
-        ));
+        assert!(get_registration_form::run(&app, got.id).await.is_ok());
     }
 
     // TODO: test in period
@@ -0,0 +1,184 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::form_template::FormTemplateId;
+use crate::model::registration_form::RegistrationForm;
+
+use anyhow::Context;
+use sos21_domain::context::{
+    ConfigContext, FormTemplateRepository, Login, RegistrationFormRepository,
+};
+use sos21_domain::model::permissions::Permissions;
+use sos21_domain::model::{
+    access_control::AccessControlList, date_time::DateTime, registration_form, user,
+};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub template_id: FormTemplateId,
+    /// `None` to use the name of the template.
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    TemplateNotFound,
+    InvalidName,
+    InvalidDescription,
+    InsufficientPermissions,
+    AlreadyStartedProjectCreationPeriod,
+}
+
+impl Error {
+    fn from_permissions_error(_err: user::RequirePermissionsError) -> Self {
+        Error::InsufficientPermissions
+    }
+
+    fn from_name_error(_err: registration_form::name::NameError) -> Self {
+        Error::InvalidName
+    }
+
+    fn from_description_error(_err: registration_form::description::DescriptionError) -> Self {
+        Error::InvalidDescription
+    }
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<RegistrationForm, Error>
+where
+    C: RegistrationFormRepository + FormTemplateRepository + ConfigContext + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    login_user
+        .require_permissions(Permissions::READ_ALL_FORMS | Permissions::CREATE_REGISTRATION_FORMS)
+        .map_err(|err| UseCaseError::UseCase(Error::from_permissions_error(err)))?;
+
+    let template = ctx
+        .get_form_template(input.template_id.into_entity())
+        .await
+        .context("Failed to get a form template")?;
+    let template = match template {
+        Some(template) => template,
+        None => return Err(UseCaseError::UseCase(Error::TemplateNotFound)),
+    };
+    use_case_ensure!(template.is_visible_to(login_user));
+
+    // TODO: Move this constraint to domain
+    for category in template.query().possible_categories() {
+        if ctx
+            .project_creation_period_for(category)
+            .contains(DateTime::now())
+        {
+            return Err(UseCaseError::UseCase(
+                Error::AlreadyStartedProjectCreationPeriod,
+            ));
+        }
+    }
+
+    let name = input
+        .name
+        .unwrap_or_else(|| template.name().clone().into_string());
+    let name = registration_form::RegistrationFormName::from_string(name)
+        .map_err(|err| UseCaseError::UseCase(Error::from_name_error(err)))?;
+    let description = registration_form::RegistrationFormDescription::from_string(
+        template.description().clone().into_string(),
+    )
+    .map_err(|err| UseCaseError::UseCase(Error::from_description_error(err)))?;
+    let items = template
+        .items()
+        .duplicate()
+        .context("Failed to duplicate form items")?;
+
+    let registration_form = registration_form::RegistrationForm {
+        id: registration_form::RegistrationFormId::from_uuid(Uuid::new_v4()),
+        festival_id: ctx.active_festival_id(),
+        created_at: DateTime::now(),
+        author_id: login_user.id().clone(),
+        name,
+        description,
+        items,
+        query: template.query().clone(),
+        access_control: AccessControlList::empty(),
+    };
+    ctx.store_registration_form(registration_form.clone())
+        .await
+        .context("Failed to store a registration form")?;
+    use_case_ensure!(registration_form.is_visible_to(login_user));
+    Ok(RegistrationForm::from_entity(registration_form))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::form_template::FormTemplateId;
+    use crate::{create_registration_form_from_template, get_registration_form, UseCaseError};
+    use sos21_domain::model::{form_template, project, project_query, user::UserId};
+    use sos21_domain::test;
+
+    fn new_general_form_template(author_id: UserId) -> form_template::FormTemplate {
+        let mut template = test::model::new_form_template(author_id).into_content();
+        template.query = project_query::ProjectQuery::from_conjunctions(vec![
+            project_query::ProjectQueryConjunction {
+                category: Some(project::ProjectCategory::General),
+                attributes: project::ProjectAttributes::from_attributes(Vec::new()).unwrap(),
+            },
+        ])
+        .unwrap();
+        form_template::FormTemplate::from_content(template)
+    }
+
+    // Checks that the (unprivileged) committee user cannot create registration forms from templates.
+    #[tokio::test]
+    async fn test_committee() {
+        let user = test::model::new_committee_user();
+        let operator = test::model::new_operator_user();
+        let template = new_general_form_template(operator.id().clone());
+        let period = test::model::new_project_creation_period_with_hours_from_now(1);
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), operator.clone()])
+            .form_templates(vec![template.clone()])
+            .project_creation_period_for(project::ProjectCategory::General, period)
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let input = create_registration_form_from_template::Input {
+            template_id: FormTemplateId::from_entity(template.id()),
+            name: None,
+        };
+        assert!(matches!(
+            create_registration_form_from_template::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                create_registration_form_from_template::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    // Checks that the privileged committee user can create registration forms from templates.
+    #[tokio::test]
+    async fn test_operator() {
+        let user = test::model::new_operator_user();
+        let template = new_general_form_template(user.id().clone());
+        let period = test::model::new_project_creation_period_with_hours_from_now(1);
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .form_templates(vec![template.clone()])
+            .project_creation_period_for(project::ProjectCategory::General, period)
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let input = create_registration_form_from_template::Input {
+            template_id: FormTemplateId::from_entity(template.id()),
+            name: Some("テンプレートから".to_string()),
+        };
+        let got = create_registration_form_from_template::run(&app, input)
+            .await
+            .unwrap();
+        assert_eq!(got.name, "テンプレートから");
+        assert_eq!(got.items.len(), template.items().items().count());
+
+        assert!(get_registration_form::run(&app, got.id).await.is_ok());
+    }
+}
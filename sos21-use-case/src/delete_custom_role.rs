@@ -0,0 +1,115 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::custom_role::CustomRoleId;
+
+use anyhow::Context;
+use sos21_domain::context::{CustomRoleRepository, Login, UserRepository};
+use sos21_domain::model::{permissions::Permissions, user};
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    NotFound,
+    InsufficientPermissions,
+}
+
+impl Error {
+    fn from_permissions_error(_err: user::RequirePermissionsError) -> Self {
+        Error::InsufficientPermissions
+    }
+
+    fn from_update_user_error(_err: user::NoUpdatePermissionError) -> Self {
+        Error::InsufficientPermissions
+    }
+}
+
+/// Deletes the custom role, unassigning it from the users beforehand.
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, id: CustomRoleId) -> UseCaseResult<(), Error>
+where
+    C: CustomRoleRepository + UserRepository + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    let result = ctx
+        .get_custom_role(id.into_entity())
+        .await
+        .context("Failed to get a custom role")?;
+    let role = match result {
+        Some(role) if role.is_visible_to(login_user) => role,
+        _ => return Err(UseCaseError::UseCase(Error::NotFound)),
+    };
+
+    login_user
+        .require_permissions(Permissions::UPDATE_CUSTOM_ROLES)
+        .map_err(|err| UseCaseError::UseCase(Error::from_permissions_error(err)))?;
+
+    let users = ctx.list_users().await.context("Failed to list users")?;
+    for mut user in users {
+        if user.custom_role().map(|custom_role| custom_role.id) != Some(role.id()) {
+            continue;
+        }
+
+        user.set_custom_role(login_user, None)
+            .map_err(|err| UseCaseError::UseCase(Error::from_update_user_error(err)))?;
+        ctx.store_user(user)
+            .await
+            .context("Failed to store a user")?;
+    }
+
+    ctx.delete_custom_role(role.id())
+        .await
+        .context("Failed to delete a custom role")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::custom_role::CustomRoleId;
+    use crate::{delete_custom_role, UseCaseError};
+    use sos21_domain::context::{CustomRoleRepository, UserRepository};
+    use sos21_domain::model::permissions::Permissions;
+    use sos21_domain::test;
+
+    #[tokio::test]
+    async fn test_committee() {
+        let admin = test::model::new_admin_user();
+        let committee = test::model::new_committee_user();
+        let role = test::model::new_custom_role(admin.id().clone(), Permissions::READ_ALL_USERS);
+
+        let app = test::build_mock_app()
+            .users(vec![admin, committee.clone()])
+            .custom_roles(vec![role.clone()])
+            .build()
+            .login_as(committee)
+            .await;
+
+        assert!(matches!(
+            delete_custom_role::run(&app, CustomRoleId::from_entity(role.id())).await,
+            Err(UseCaseError::UseCase(delete_custom_role::Error::NotFound))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_admin_unassigns_users() {
+        let admin = test::model::new_admin_user();
+        let role = test::model::new_custom_role(admin.id().clone(), Permissions::READ_ALL_USERS);
+        let mut user = test::model::new_general_user();
+        user.set_custom_role(&admin, Some(&role)).unwrap();
+
+        let app = test::build_mock_app()
+            .users(vec![admin.clone(), user.clone()])
+            .custom_roles(vec![role.clone()])
+            .build()
+            .login_as(admin)
+            .await;
+
+        assert!(matches!(
+            delete_custom_role::run(&app, CustomRoleId::from_entity(role.id())).await,
+            Ok(())
+        ));
+        assert!(app.get_custom_role(role.id()).await.unwrap().is_none());
+        let user = app.get_user(user.id().clone()).await.unwrap().unwrap();
+        assert!(user.custom_role().is_none());
+        assert!(!user.permissions().contains(Permissions::READ_ALL_USERS));
+    }
+}
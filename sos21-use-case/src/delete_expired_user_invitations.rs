@@ -0,0 +1,84 @@
+use std::convert::Infallible;
+
+use crate::error::UseCaseResult;
+
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use sos21_domain::context::UserInvitationRepository;
+use sos21_domain::model::date_time;
+
+/// Deletes the invitations which had expired before `expired_before`,
+/// and returns the number of the deleted invitations.
+///
+/// This is not performed on behalf of any user, but run periodically by the server.
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &C, expired_before: DateTime<Utc>) -> UseCaseResult<usize, Infallible>
+where
+    C: UserInvitationRepository + Send + Sync,
+{
+    let expired_before = date_time::DateTime::from_utc(expired_before);
+
+    let mut count = 0;
+    for invitation in ctx
+        .list_user_invitations()
+        .await
+        .context("Failed to list user invitations")?
+    {
+        if !invitation.is_expired_before(expired_before) {
+            continue;
+        }
+
+        ctx.delete_user_invitation(invitation.id())
+            .await
+            .context("Failed to delete user invitation")?;
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::delete_expired_user_invitations;
+
+    use sos21_domain::context::UserInvitationRepository;
+    use sos21_domain::test;
+
+    #[tokio::test]
+    async fn test_delete_expired() {
+        let admin = test::model::new_admin_user();
+        let pending = test::model::new_operator_user_invitation(
+            admin.id().clone(),
+            "example1@s.tsukuba.ac.jp",
+        );
+        let expired = test::model::new_expired_user_invitation(
+            admin.id().clone(),
+            "example2@s.tsukuba.ac.jp",
+        );
+
+        let app = test::build_mock_app()
+            .users(vec![admin])
+            .user_invitations(vec![pending.clone(), expired.clone()])
+            .build();
+
+        // the invitation expired a day ago is kept in the retention
+        let before = chrono::Utc::now() - chrono::Duration::days(7);
+        assert_eq!(
+            delete_expired_user_invitations::run(&app, before)
+                .await
+                .unwrap(),
+            0
+        );
+
+        let before = chrono::Utc::now();
+        assert_eq!(
+            delete_expired_user_invitations::run(&app, before)
+                .await
+                .unwrap(),
+            1
+        );
+        let remaining = app.list_user_invitations().await.unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].id(), pending.id());
+    }
+}
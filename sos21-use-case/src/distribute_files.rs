@@ -6,7 +6,8 @@ use crate::model::project::ProjectId;
 
 use anyhow::Context;
 use sos21_domain::context::{
-    FileDistributionRepository, FileRepository, FileSharingRepository, Login, ProjectRepository,
+    ConfigContext, FileDistributionRepository, FileRepository, FileSharingRepository, Login,
+    ProjectRepository,
 };
 use sos21_domain::model::{
     date_time::DateTime, file, file_distribution, file_sharing, permissions::Permissions, project,
@@ -94,6 +95,7 @@ where
         + FileRepository
         + FileSharingRepository
         + ProjectRepository
+        + ConfigContext
         + Send
         + Sync,
 {
@@ -123,6 +125,7 @@ where
 
     let distribution = file_distribution::FileDistribution {
         id: file_distribution::FileDistributionId::from_uuid(Uuid::new_v4()),
+        festival_id: ctx.active_festival_id(),
         created_at: DateTime::now(),
         author_id: login_user.id().clone(),
         name,
@@ -142,7 +145,7 @@ async fn to_project<C>(
     project: &InputProject,
 ) -> UseCaseResult<project::Project, Error>
 where
-    C: ProjectRepository + Send + Sync,
+    C: ProjectRepository + ConfigContext + Send + Sync,
 {
     let login_user = ctx.login_user();
 
@@ -155,7 +158,7 @@ where
             let code = project::ProjectCode::parse(project_code)
                 .map_err(|err| UseCaseError::UseCase(Error::from_code_error(err)))?;
 
-            ctx.get_project_by_index(code.index)
+            ctx.get_project_by_index(ctx.active_festival_id(), code.index)
                 .await
                 .context("Failed to get a project")?
                 .filter(|result| result.project.kind() == code.kind)
@@ -0,0 +1,239 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::form::{Form, FormId};
+
+use anyhow::Context;
+use sos21_domain::context::{ConfigContext, FormRepository, Login};
+use sos21_domain::model::permissions::Permissions;
+use sos21_domain::model::{date_time::DateTime, form};
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub form_id: FormId,
+    /// `None` to copy the name of the original form.
+    pub name: Option<String>,
+    pub starts_at: chrono::DateTime<chrono::Utc>,
+    pub ends_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    NotFound,
+    InvalidName,
+    InvalidPeriod,
+    TooEarlyPeriodStart,
+    InsufficientPermissions,
+}
+
+impl Error {
+    fn from_name_error(_err: form::name::NameError) -> Self {
+        Error::InvalidName
+    }
+
+    fn from_period_error(_err: form::period::PeriodError) -> Self {
+        Error::InvalidPeriod
+    }
+
+    fn from_new_form_error(err: form::NewFormError) -> Self {
+        match err.kind() {
+            form::NewFormErrorKind::TooEarlyPeriodStart => Error::TooEarlyPeriodStart,
+            form::NewFormErrorKind::InsufficientPermissions => Error::InsufficientPermissions,
+        }
+    }
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<Form, Error>
+where
+    C: FormRepository + ConfigContext + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    if login_user
+        .require_permissions(Permissions::READ_ALL_FORMS)
+        .is_err()
+    {
+        return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
+    }
+
+    let original = ctx
+        .get_form(input.form_id.into_entity())
+        .await
+        .context("Failed to get a form")?;
+    let original = match original {
+        Some(original) => original,
+        None => return Err(UseCaseError::UseCase(Error::NotFound)),
+    };
+    use_case_ensure!(original.is_visible_to(login_user));
+
+    let name = match input.name {
+        Some(name) => form::FormName::from_string(name)
+            .map_err(|err| UseCaseError::UseCase(Error::from_name_error(err)))?,
+        None => original.name().clone(),
+    };
+    let starts_at = DateTime::from_utc(input.starts_at);
+    let ends_at = DateTime::from_utc(input.ends_at);
+    let period = form::FormPeriod::from_datetime(starts_at, ends_at)
+        .map_err(|err| UseCaseError::UseCase(Error::from_period_error(err)))?;
+
+    let items = original
+        .items()
+        .duplicate()
+        .context("Failed to duplicate form items")?;
+    let mut condition = original.condition().clone();
+    // projects in the past festivals cannot be the target of the new form
+    if original.festival_id() != ctx.active_festival_id() {
+        condition.includes = form::FormConditionProjectSet::empty();
+        condition.excludes = form::FormConditionProjectSet::empty();
+    }
+
+    let form = form::Form::new(
+        ctx,
+        login_user,
+        name,
+        original.description().clone(),
+        period,
+        items,
+        condition,
+        original.answer_notification_webhook().clone(),
+    )
+    .map_err(|err| UseCaseError::UseCase(Error::from_new_form_error(err)))?;
+    ctx.store_form(form.clone())
+        .await
+        .context("Failed to store a form")?;
+    use_case_ensure!(form.is_visible_to(login_user));
+    Ok(Form::from_entity(form))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::form::FormId;
+    use crate::{duplicate_form, UseCaseError};
+    use sos21_domain::model::{date_time, form};
+    use sos21_domain::test;
+
+    fn mock_input(form_id: FormId) -> duplicate_form::Input {
+        let period = test::model::mock_form_period_with_start(date_time::DateTime::from_utc(
+            chrono::Utc::now() + chrono::Duration::hours(1),
+        ));
+        duplicate_form::Input {
+            form_id,
+            name: None,
+            starts_at: period.starts_at().utc(),
+            ends_at: period.ends_at().utc(),
+        }
+    }
+
+    // Checks that the (unprivileged) committee user cannot duplicate forms.
+    #[tokio::test]
+    async fn test_committee() {
+        let user = test::model::new_committee_user();
+        let operator = test::model::new_operator_user();
+        let form = test::model::new_form(operator.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), operator.clone()])
+            .forms(vec![form.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let input = mock_input(FormId::from_entity(form.id()));
+        assert!(matches!(
+            duplicate_form::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                duplicate_form::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    // Checks that the privileged committee user can duplicate forms with fresh item IDs.
+    #[tokio::test]
+    async fn test_operator() {
+        let user = test::model::new_operator_user();
+        let form = test::model::new_form(user.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .forms(vec![form.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let input = mock_input(FormId::from_entity(form.id()));
+        let got = duplicate_form::run(&app, input).await.unwrap();
+        assert_ne!(got.id, FormId::from_entity(form.id()));
+        assert_eq!(got.name, form.name().clone().into_string());
+        assert_eq!(got.items.len(), form.items().items().count());
+        assert!(got
+            .items
+            .iter()
+            .zip(form.items().items())
+            .all(|(got, original)| got.id.into_entity() != original.id));
+    }
+
+    // Checks that the includes and excludes are dropped on duplication from the past festivals.
+    #[tokio::test]
+    async fn test_operator_past_festival() {
+        let user = test::model::new_operator_user();
+        let project = test::model::new_general_project(user.id().clone());
+        let festival = test::model::new_festival();
+        let mut form = test::model::new_form(user.id().clone()).into_content();
+        form.festival_id = festival.id();
+        form.condition.includes =
+            form::FormConditionProjectSet::from_projects(vec![project.id()]).unwrap();
+        let form = form::Form::from_content(form);
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .projects(vec![project.clone()])
+            .festivals(vec![festival.clone()])
+            .forms(vec![form.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let input = mock_input(FormId::from_entity(form.id()));
+        let got = duplicate_form::run(&app, input).await.unwrap();
+        assert!(got.condition.includes.is_empty());
+        assert_eq!(
+            got.festival_id.into_entity(),
+            test::model::mock_festival().id()
+        );
+    }
+
+    // Checks that the duplicated form can be renamed.
+    #[tokio::test]
+    async fn test_operator_rename() {
+        let user = test::model::new_operator_user();
+        let form = test::model::new_form(user.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .forms(vec![form.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let mut input = mock_input(FormId::from_entity(form.id()));
+        input.name = Some("Copied".to_string());
+        let got = duplicate_form::run(&app, input).await.unwrap();
+        assert_eq!(got.name, "Copied");
+    }
+
+    #[tokio::test]
+    async fn test_not_found() {
+        let user = test::model::new_admin_user();
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let input = mock_input(FormId::from_entity(test::model::new_form_id()));
+        assert!(matches!(
+            duplicate_form::run(&app, input).await,
+            Err(UseCaseError::UseCase(duplicate_form::Error::NotFound))
+        ));
+    }
+}
@@ -0,0 +1,203 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::registration_form::{RegistrationForm, RegistrationFormId};
+
+use anyhow::Context;
+use sos21_domain::context::{ConfigContext, Login, RegistrationFormRepository};
+use sos21_domain::model::permissions::Permissions;
+use sos21_domain::model::{
+    access_control::AccessControlList, date_time::DateTime, registration_form, user,
+};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub registration_form_id: RegistrationFormId,
+    /// `None` to copy the name of the original registration form.
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    NotFound,
+    InvalidName,
+    InsufficientPermissions,
+    AlreadyStartedProjectCreationPeriod,
+}
+
+impl Error {
+    fn from_permissions_error(_err: user::RequirePermissionsError) -> Self {
+        Error::InsufficientPermissions
+    }
+
+    fn from_name_error(_err: registration_form::name::NameError) -> Self {
+        Error::InvalidName
+    }
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<RegistrationForm, Error>
+where
+    C: RegistrationFormRepository + ConfigContext + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    login_user
+        .require_permissions(
+            Permissions::READ_ALL_REGISTRATION_FORMS | Permissions::CREATE_REGISTRATION_FORMS,
+        )
+        .map_err(|err| UseCaseError::UseCase(Error::from_permissions_error(err)))?;
+
+    let original = ctx
+        .get_registration_form(input.registration_form_id.into_entity())
+        .await
+        .context("Failed to get a registration form")?;
+    let original = match original {
+        Some(original) => original,
+        None => return Err(UseCaseError::UseCase(Error::NotFound)),
+    };
+    use_case_ensure!(original.is_visible_to(login_user));
+
+    // TODO: Move this constraint to domain
+    for category in original.query.possible_categories() {
+        if ctx
+            .project_creation_period_for(category)
+            .contains(DateTime::now())
+        {
+            return Err(UseCaseError::UseCase(
+                Error::AlreadyStartedProjectCreationPeriod,
+            ));
+        }
+    }
+
+    let name = match input.name {
+        Some(name) => registration_form::RegistrationFormName::from_string(name)
+            .map_err(|err| UseCaseError::UseCase(Error::from_name_error(err)))?,
+        None => original.name.clone(),
+    };
+    let items = original
+        .items
+        .duplicate()
+        .context("Failed to duplicate form items")?;
+
+    let registration_form = registration_form::RegistrationForm {
+        id: registration_form::RegistrationFormId::from_uuid(Uuid::new_v4()),
+        festival_id: ctx.active_festival_id(),
+        created_at: DateTime::now(),
+        author_id: login_user.id().clone(),
+        name,
+        description: original.description,
+        items,
+        query: original.query,
+        access_control: AccessControlList::empty(),
+    };
+    ctx.store_registration_form(registration_form.clone())
+        .await
+        .context("Failed to store a registration form")?;
+    use_case_ensure!(registration_form.is_visible_to(login_user));
+    Ok(RegistrationForm::from_entity(registration_form))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::registration_form::RegistrationFormId;
+    use crate::model::user::UserId;
+    use crate::{duplicate_registration_form, UseCaseError};
+    use sos21_domain::model::{project, project_query};
+    use sos21_domain::test;
+
+    fn general_query() -> project_query::ProjectQuery {
+        project_query::ProjectQuery::from_conjunctions(vec![
+            project_query::ProjectQueryConjunction {
+                category: Some(project::ProjectCategory::General),
+                attributes: project::ProjectAttributes::from_attributes(Vec::new()).unwrap(),
+            },
+        ])
+        .unwrap()
+    }
+
+    // Checks that the (unprivileged) committee user cannot duplicate registration forms.
+    #[tokio::test]
+    async fn test_committee() {
+        let user = test::model::new_committee_user();
+        let operator = test::model::new_operator_user();
+        let registration_form =
+            test::model::new_registration_form_with_query(operator.id().clone(), general_query());
+        let period = test::model::new_project_creation_period_with_hours_from_now(1);
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), operator.clone()])
+            .registration_forms(vec![registration_form.clone()])
+            .project_creation_period_for(project::ProjectCategory::General, period)
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let input = duplicate_registration_form::Input {
+            registration_form_id: RegistrationFormId::from_entity(registration_form.id),
+            name: None,
+        };
+        assert!(matches!(
+            duplicate_registration_form::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                duplicate_registration_form::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    // Checks that the privileged committee user can duplicate registration forms.
+    #[tokio::test]
+    async fn test_operator() {
+        let user = test::model::new_operator_user();
+        let other = test::model::new_admin_user();
+        let registration_form =
+            test::model::new_registration_form_with_query(other.id().clone(), general_query());
+        let period = test::model::new_project_creation_period_with_hours_from_now(1);
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), other.clone()])
+            .registration_forms(vec![registration_form.clone()])
+            .project_creation_period_for(project::ProjectCategory::General, period)
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let input = duplicate_registration_form::Input {
+            registration_form_id: RegistrationFormId::from_entity(registration_form.id),
+            name: None,
+        };
+        let got = duplicate_registration_form::run(&app, input).await.unwrap();
+        assert_ne!(
+            got.id,
+            RegistrationFormId::from_entity(registration_form.id)
+        );
+        assert_eq!(got.author_id, UserId::from_entity(user.id().clone()));
+        assert_eq!(got.name, registration_form.name.into_string());
+        assert_eq!(got.items.len(), registration_form.items.items().count());
+    }
+
+    // Checks that registration forms cannot be duplicated after the project creation period starts.
+    #[tokio::test]
+    async fn test_operator_started() {
+        let user = test::model::new_operator_user();
+        let registration_form =
+            test::model::new_registration_form_with_query(user.id().clone(), general_query());
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .registration_forms(vec![registration_form.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let input = duplicate_registration_form::Input {
+            registration_form_id: RegistrationFormId::from_entity(registration_form.id),
+            name: None,
+        };
+        assert!(matches!(
+            duplicate_registration_form::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                duplicate_registration_form::Error::AlreadyStartedProjectCreationPeriod
+            ))
+        ));
+    }
+}
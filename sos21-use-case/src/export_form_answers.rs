@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::fmt::{self, Debug};
 
 use crate::error::{UseCaseError, UseCaseResult};
@@ -5,7 +6,9 @@ use crate::model::form::FormId;
 
 use anyhow::{bail, Context};
 use sos21_domain::context::{FormAnswerRepository, FormRepository, Login};
-use sos21_domain::model::{form, form_answer, permissions::Permissions};
+use sos21_domain::model::{
+    access_control::AccessControlLevel, form, form_answer, permissions::Permissions,
+};
 
 #[derive(Debug, Clone)]
 pub enum Error {
@@ -22,6 +25,7 @@ pub struct RenderFileAnswerInput {
 pub struct Input<F> {
     pub form_id: FormId,
     pub field_names: InputFieldNames,
+    pub group_layout: InputGroupLayout,
     pub render_file_answer: F,
 }
 
@@ -30,6 +34,7 @@ impl<F> Debug for Input<F> {
         f.debug_struct("Input")
             .field("form_id", &self.form_id)
             .field("field_names", &self.field_names)
+            .field("group_layout", &self.group_layout)
             .finish()
     }
 }
@@ -42,6 +47,15 @@ pub struct InputFieldNames {
     pub author_id: Option<String>,
 }
 
+/// How the rows of the answers to group items are laid out in the CSV.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputGroupLayout {
+    /// Each row is written to its own set of columns in the same record.
+    Columns,
+    /// Each row is written to its own record, repeating the other fields.
+    Rows,
+}
+
 #[tracing::instrument(skip(ctx))]
 pub async fn run<C, F>(ctx: &Login<C>, input: Input<F>) -> UseCaseResult<Vec<u8>, Error>
 where
@@ -50,17 +64,25 @@ where
 {
     let login_user = ctx.login_user();
 
-    if login_user
-        .require_permissions(Permissions::READ_ALL_FORM_ANSWERS)
-        .is_err()
-    {
-        return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
-    }
-
     let result = ctx
         .get_form(input.form_id.into_entity())
         .await
         .context("Failed to get a form")?;
+
+    let is_granted = match &result {
+        Some(form) => form.access_control().allows_with_permission(
+            login_user,
+            AccessControlLevel::Read,
+            Permissions::READ_ALL_FORM_ANSWERS,
+        ),
+        None => login_user
+            .permissions()
+            .contains(Permissions::READ_ALL_FORM_ANSWERS),
+    };
+    if !is_granted {
+        return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
+    }
+
     let form = match result {
         Some(x) => x,
         None => return Err(UseCaseError::UseCase(Error::FormNotFound)),
@@ -72,17 +94,18 @@ where
         .context("Failed to list form answers")?;
     use_case_ensure!(answers
         .iter()
-        .all(|answer| answer.is_visible_to(login_user)));
+        .all(|answer| answer.is_visible_to_with_form(login_user, &form)));
 
     // TODO: Tune buffer size and initial vector capacity
     let mut writer = csv::WriterBuilder::new()
         .terminator(csv::Terminator::CRLF)
         .from_writer(Vec::new());
 
-    write_header(&mut writer, &input.field_names, &form)?;
+    let group_widths = group_widths(form.items(), answers.iter().map(|answer| answer.items()));
+    write_header(&mut writer, &input, &form, &group_widths)?;
 
     for answer in answers {
-        write_record(&mut writer, &input, &form, answer)?;
+        write_record(&mut writer, &input, &form, &group_widths, answer)?;
     }
 
     let csv = writer.into_inner().context("Failed to write CSV data")?;
@@ -90,10 +113,11 @@ where
 }
 
 // TODO: Ensure that the field orders are consistent between `write_header` and `write_record`
-fn write_header<W>(
+fn write_header<W, F>(
     writer: &mut csv::Writer<W>,
-    field_names: &InputFieldNames,
+    input: &Input<F>,
     form: &form::Form,
+    group_widths: &HashMap<form::item::FormItemId, usize>,
 ) -> anyhow::Result<()>
 where
     W: std::io::Write,
@@ -103,7 +127,7 @@ where
         created_at,
         project_id,
         author_id,
-    } = field_names;
+    } = &input.field_names;
 
     macro_rules! write_field {
         ($writer:ident, $name:ident) => {
@@ -121,24 +145,31 @@ where
     for item in form.items().items() {
         use form::item::FormItemBody;
         match &item.body {
-            FormItemBody::Checkbox(checkbox_item) => {
-                for checkbox in checkbox_item.boxes() {
-                    let mut field_name = item.name.as_str().to_string();
-                    field_name.push(' ');
-                    field_name.push_str(checkbox.label.as_str());
-                    writer.write_field(field_name)?;
-                }
-            }
-            FormItemBody::GridRadio(grid_item) => {
-                for row in grid_item.rows() {
-                    let mut field_name = item.name.as_str().to_string();
-                    field_name.push(' ');
-                    field_name.push_str(row.label.as_str());
-                    writer.write_field(field_name)?;
+            FormItemBody::Group(group_item) => {
+                let rows = match input.group_layout {
+                    InputGroupLayout::Columns => group_widths.get(&item.id).copied().unwrap_or(1),
+                    InputGroupLayout::Rows => 1,
+                };
+                for row in 1..=rows {
+                    for group_item in group_item.items() {
+                        for name in item_field_names(group_item) {
+                            let field_name = match input.group_layout {
+                                InputGroupLayout::Columns => {
+                                    format!("{} {} {}", item.name.as_str(), row, name)
+                                }
+                                InputGroupLayout::Rows => {
+                                    format!("{} {}", item.name.as_str(), name)
+                                }
+                            };
+                            writer.write_field(field_name)?;
+                        }
+                    }
                 }
             }
             _ => {
-                writer.write_field(item.name.as_str())?;
+                for name in item_field_names(item) {
+                    writer.write_field(name)?;
+                }
             }
         }
     }
@@ -149,12 +180,92 @@ where
     Ok(())
 }
 
+fn item_field_names(item: &form::item::FormItem) -> Vec<String> {
+    use form::item::FormItemBody;
+
+    let with_suffix = |suffix: &str| format!("{} {}", item.name.as_str(), suffix);
+    match &item.body {
+        FormItemBody::Checkbox(checkbox_item) => checkbox_item
+            .boxes()
+            .map(|checkbox| with_suffix(checkbox.label.as_str()))
+            .collect(),
+        FormItemBody::GridRadio(grid_item) => grid_item
+            .rows()
+            .map(|row| with_suffix(row.label.as_str()))
+            .collect(),
+        _ => vec![item.name.as_str().to_string()],
+    }
+}
+
+/// Computes the number of rows to be written for each group item in the columns layout,
+/// which is the maximum number of the answered rows.
+fn group_widths<'a, I>(
+    items: &form::item::FormItems,
+    answers: I,
+) -> HashMap<form::item::FormItemId, usize>
+where
+    I: IntoIterator<Item = &'a form_answer::item::FormAnswerItems>,
+{
+    use form::item::FormItemBody;
+    use form_answer::item::FormAnswerItemBody;
+
+    let mut widths: HashMap<_, _> = items
+        .items()
+        .filter(|item| matches!(item.body, FormItemBody::Group(_)))
+        .map(|item| (item.id, 1))
+        .collect();
+    for answer_items in answers {
+        for answer_item in answer_items.items() {
+            if let Some(FormAnswerItemBody::Group(rows)) = &answer_item.body {
+                if let Some(width) = widths.get_mut(&answer_item.item_id) {
+                    *width = std::cmp::max(*width, rows.len());
+                }
+            }
+        }
+    }
+    widths
+}
+
 fn write_record<W, F>(
     writer: &mut csv::Writer<W>,
     input: &Input<F>,
     form: &form::Form,
+    group_widths: &HashMap<form::item::FormItemId, usize>,
     answer: form_answer::FormAnswer,
 ) -> anyhow::Result<()>
+where
+    W: std::io::Write,
+    F: Fn(RenderFileAnswerInput) -> anyhow::Result<String>,
+{
+    let records = match input.group_layout {
+        InputGroupLayout::Columns => 1,
+        InputGroupLayout::Rows => answer
+            .items()
+            .items()
+            .filter_map(|answer_item| match &answer_item.body {
+                Some(form_answer::item::FormAnswerItemBody::Group(rows)) => Some(rows.len()),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(1)
+            .max(1),
+    };
+
+    for record in 0..records {
+        write_record_fields(writer, input, form, group_widths, &answer, record)?;
+    }
+
+    Ok(())
+}
+
+fn write_record_fields<W, F>(
+    writer: &mut csv::Writer<W>,
+    input: &Input<F>,
+    form: &form::Form,
+    group_widths: &HashMap<form::item::FormItemId, usize>,
+    answer: &form_answer::FormAnswer,
+    record: usize,
+) -> anyhow::Result<()>
 where
     W: std::io::Write,
     F: Fn(RenderFileAnswerInput) -> anyhow::Result<String>,
@@ -190,8 +301,44 @@ where
             sharing_ids,
         })
     };
-    for (item, answer_item) in form.items().items().zip(answer.into_items().into_items()) {
-        write_item_fields(writer, render, item, answer_item)?;
+    for (item, answer_item) in form.items().items().zip(answer.items().items()) {
+        use form::item::FormItemBody;
+        use form_answer::item::FormAnswerItemBody;
+
+        let group_item = match &item.body {
+            FormItemBody::Group(group_item) => group_item,
+            _ => {
+                write_item_fields(writer, &render, item, answer_item.clone())?;
+                continue;
+            }
+        };
+
+        let rows: Vec<_> = match &answer_item.body {
+            Some(FormAnswerItemBody::Group(rows)) => rows.rows().collect(),
+            Some(_) => bail!("unexpectedly mismatched form item and form answer item"),
+            None => Vec::new(),
+        };
+        let rows = match input.group_layout {
+            InputGroupLayout::Columns => {
+                let width = group_widths.get(&item.id).copied().unwrap_or(1);
+                (0..width).map(|row| rows.get(row)).collect()
+            }
+            InputGroupLayout::Rows => vec![rows.get(record)],
+        };
+        for row in rows {
+            match row {
+                Some(row) => {
+                    for (group_item, answer_item) in group_item.items().zip(row.items()) {
+                        write_item_fields(writer, &render, group_item, answer_item.clone())?;
+                    }
+                }
+                None => {
+                    for group_item in group_item.items() {
+                        write_empty_item_fields(writer, group_item)?;
+                    }
+                }
+            }
+        }
     }
 
     // this terminates the record (see docs on `csv::Writer::write_record`)
@@ -202,20 +349,17 @@ where
 
 fn write_item_fields<W, F>(
     writer: &mut csv::Writer<W>,
-    render_file_answer: F,
+    render_file_answer: &F,
     item: &form::item::FormItem,
     answer_item: form_answer::item::FormAnswerItem,
 ) -> anyhow::Result<()>
 where
     W: std::io::Write,
-    F: FnOnce(Vec<String>) -> anyhow::Result<String>,
+    F: Fn(Vec<String>) -> anyhow::Result<String>,
 {
     let body = match answer_item.body {
         Some(body) => body,
-        None => {
-            writer.write_field("")?;
-            return Ok(());
-        }
+        None => return write_empty_item_fields(writer, item),
     };
 
     use form::item::FormItemBody;
@@ -284,6 +428,23 @@ where
             let field = (render_file_answer)(sharings).context("Failed to render file answer")?;
             writer.write_field(field)?;
         }
+        FormAnswerItemBody::Group(_) => {
+            bail!("unexpectedly mismatched form item and form answer item")
+        }
+    }
+
+    Ok(())
+}
+
+fn write_empty_item_fields<W>(
+    writer: &mut csv::Writer<W>,
+    item: &form::item::FormItem,
+) -> anyhow::Result<()>
+where
+    W: std::io::Write,
+{
+    for _ in item_field_names(item) {
+        writer.write_field("")?;
     }
 
     Ok(())
@@ -344,6 +505,7 @@ mod tests {
         export_form_answers::Input {
             form_id,
             field_names,
+            group_layout: export_form_answers::InputGroupLayout::Columns,
             render_file_answer,
         }
     }
@@ -369,10 +531,9 @@ mod tests {
         let user = test::model::new_committee_user();
         let (app, form_id) = prepare_app(user).await;
 
-        assert!(matches!(
-            export_form_answers::run(&app, mock_input(form_id)).await,
-            Ok(_)
-        ));
+        assert!(export_form_answers::run(&app, mock_input(form_id))
+            .await
+            .is_ok());
     }
 
     // Checks that the privileged committee user can export form answers.
@@ -381,9 +542,68 @@ mod tests {
         let user = test::model::new_operator_user();
         let (app, form_id) = prepare_app(user).await;
 
-        assert!(matches!(
-            export_form_answers::run(&app, mock_input(form_id)).await,
-            Ok(_)
-        ));
+        assert!(export_form_answers::run(&app, mock_input(form_id))
+            .await
+            .is_ok());
+    }
+
+    // Checks that the rows of group items are flattened according to the layout.
+    #[tokio::test]
+    async fn test_group_layout() {
+        use domain::form::item::FormItems;
+        use domain::form_answer::item::{
+            FormAnswerItem, FormAnswerItemBody, FormAnswerItemGroupRows, FormAnswerItems,
+        };
+
+        let user = test::model::new_operator_user();
+        let project = test::model::new_general_project(user.id().clone());
+
+        let item = test::model::new_form_item();
+        let group_item = test::model::new_form_item_with_body(
+            test::model::new_group_form_item_body_with_items(vec![item.clone()]),
+        );
+        let row =
+            FormAnswerItems::from_items(vec![test::model::mock_form_answer_item(&item)]).unwrap();
+        let form = test::model::new_form_with_items(
+            user.id().clone(),
+            FormItems::from_items(vec![group_item.clone()]).unwrap(),
+        );
+        let form_id = FormId::from_entity(form.id());
+        let answer_items = FormAnswerItems::from_items(vec![FormAnswerItem {
+            item_id: group_item.id,
+            body: Some(FormAnswerItemBody::Group(
+                FormAnswerItemGroupRows::from_rows(vec![row.clone(), row]).unwrap(),
+            )),
+        }])
+        .unwrap();
+        let answer = test::model::new_form_answer_with_items(
+            user.id().clone(),
+            &project,
+            &form,
+            answer_items,
+        );
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .forms(vec![form])
+            .projects(vec![project])
+            .answers(vec![answer])
+            .build()
+            .login_as(user)
+            .await;
+
+        let csv = export_form_answers::run(&app, mock_input(form_id))
+            .await
+            .unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        assert_eq!(csv.lines().count(), 2);
+        assert_eq!(csv.lines().next().unwrap().split(',').count(), 5);
+
+        let mut input = mock_input(form_id);
+        input.group_layout = export_form_answers::InputGroupLayout::Rows;
+        let csv = export_form_answers::run(&app, input).await.unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        assert_eq!(csv.lines().count(), 3);
+        assert_eq!(csv.lines().next().unwrap().split(',').count(), 4);
     }
 }
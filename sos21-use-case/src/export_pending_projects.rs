@@ -0,0 +1,490 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::festival::FestivalId;
+
+use anyhow::Context;
+use sos21_domain::context::{
+    pending_project_repository::{self, PendingProjectRepository},
+    ConfigContext, Login,
+};
+use sos21_domain::model::{pending_project, permissions::Permissions, project, user};
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    InsufficientPermissions,
+}
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub festival_id: Option<FestivalId>,
+    pub field_names: InputFieldNames,
+    pub category_names: InputCategoryNames,
+}
+
+#[derive(Debug, Clone)]
+pub struct InputFieldNames {
+    pub id: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+    pub owner_id: Option<String>,
+    pub owner_first_name: Option<String>,
+    pub owner_last_name: Option<String>,
+    pub owner_full_name: Option<String>,
+    pub owner_kana_first_name: Option<String>,
+    pub owner_kana_last_name: Option<String>,
+    pub owner_kana_full_name: Option<String>,
+    pub name: Option<String>,
+    pub kana_name: Option<String>,
+    pub group_name: Option<String>,
+    pub kana_group_name: Option<String>,
+    pub description: Option<String>,
+    pub category: Option<String>,
+    pub attribute_academic: Option<String>,
+    pub attribute_artistic: Option<String>,
+    pub attribute_committee: Option<String>,
+    pub attribute_outdoor: Option<String>,
+    pub attribute_indoor: Option<String>,
+    pub exceptional_complete_deadline: Option<String>,
+    pub registration_form_count: Option<String>,
+    pub answered_registration_form_count: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct InputCategoryNames {
+    pub general: String,
+    pub cooking_requiring_preparation_area: String,
+    pub cooking: String,
+    pub food: String,
+    pub stage: String,
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<Vec<u8>, Error>
+where
+    Login<C>: PendingProjectRepository + ConfigContext,
+{
+    let login_user = ctx.login_user();
+
+    if login_user
+        .require_permissions(Permissions::READ_ALL_PROJECTS)
+        .is_err()
+    {
+        return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
+    }
+
+    let festival_id = input
+        .festival_id
+        .map(FestivalId::into_entity)
+        .unwrap_or_else(|| ctx.active_festival_id());
+    let pending_projects = ctx
+        .list_pending_projects(festival_id)
+        .await
+        .context("Failed to list pending projects")?;
+
+    let mut writer = csv::WriterBuilder::new()
+        .terminator(csv::Terminator::CRLF)
+        .from_writer(Vec::new());
+
+    write_header(&mut writer, &input)?;
+
+    for pending_project_with_progress in pending_projects {
+        let pending_project_repository::PendingProjectWithRegistrationProgress {
+            pending_project,
+            owner,
+            registration_form_count,
+            answered_registration_form_count,
+        } = pending_project_with_progress;
+
+        use_case_ensure!(
+            pending_project.is_visible_to(login_user)
+                && owner.name().is_visible_to(login_user)
+                && owner.kana_name().is_visible_to(login_user)
+        );
+
+        write_record(
+            &mut writer,
+            &input,
+            WriteRecordInput {
+                pending_project,
+                owner_name: owner.name(),
+                owner_kana_name: owner.kana_name(),
+                registration_form_count,
+                answered_registration_form_count,
+            },
+        )?;
+    }
+
+    let csv = writer.into_inner().context("Failed to write CSV data")?;
+    Ok(csv)
+}
+
+fn write_header<W>(writer: &mut csv::Writer<W>, input: &Input) -> anyhow::Result<()>
+where
+    W: std::io::Write,
+{
+    let InputFieldNames {
+        id,
+        created_at,
+        updated_at,
+        owner_id,
+        owner_first_name,
+        owner_last_name,
+        owner_full_name,
+        owner_kana_first_name,
+        owner_kana_last_name,
+        owner_kana_full_name,
+        name,
+        kana_name,
+        group_name,
+        kana_group_name,
+        description,
+        category,
+        attribute_academic,
+        attribute_artistic,
+        attribute_committee,
+        attribute_outdoor,
+        attribute_indoor,
+        exceptional_complete_deadline,
+        registration_form_count,
+        answered_registration_form_count,
+    } = &input.field_names;
+
+    macro_rules! write_field {
+        ($writer:ident, $name:ident) => {
+            if let Some(x) = $name {
+                $writer.write_field(x)?;
+            }
+        };
+    }
+
+    write_field!(writer, id);
+    write_field!(writer, created_at);
+    write_field!(writer, updated_at);
+    write_field!(writer, owner_id);
+    write_field!(writer, owner_first_name);
+    write_field!(writer, owner_last_name);
+    write_field!(writer, owner_full_name);
+    write_field!(writer, owner_kana_first_name);
+    write_field!(writer, owner_kana_last_name);
+    write_field!(writer, owner_kana_full_name);
+    write_field!(writer, name);
+    write_field!(writer, kana_name);
+    write_field!(writer, group_name);
+    write_field!(writer, kana_group_name);
+    write_field!(writer, description);
+    write_field!(writer, category);
+    write_field!(writer, attribute_academic);
+    write_field!(writer, attribute_artistic);
+    write_field!(writer, attribute_committee);
+    write_field!(writer, attribute_outdoor);
+    write_field!(writer, attribute_indoor);
+    write_field!(writer, exceptional_complete_deadline);
+    write_field!(writer, registration_form_count);
+    write_field!(writer, answered_registration_form_count);
+
+    // this terminates the record (see docs on `csv::Writer::write_record`)
+    writer.write_record(std::iter::empty::<&[u8]>())?;
+
+    Ok(())
+}
+
+struct WriteRecordInput<'a> {
+    pending_project: pending_project::PendingProject,
+    owner_name: &'a user::UserName,
+    owner_kana_name: &'a user::UserKanaName,
+    registration_form_count: u64,
+    answered_registration_form_count: u64,
+}
+
+fn write_record<W>(
+    writer: &mut csv::Writer<W>,
+    input: &Input,
+    data: WriteRecordInput<'_>,
+) -> anyhow::Result<()>
+where
+    W: std::io::Write,
+{
+    let InputFieldNames {
+        id,
+        created_at,
+        updated_at,
+        owner_id,
+        owner_first_name,
+        owner_last_name,
+        owner_full_name,
+        owner_kana_first_name,
+        owner_kana_last_name,
+        owner_kana_full_name,
+        name,
+        kana_name,
+        group_name,
+        kana_group_name,
+        description,
+        category,
+        attribute_academic,
+        attribute_artistic,
+        attribute_committee,
+        attribute_outdoor,
+        attribute_indoor,
+        exceptional_complete_deadline,
+        registration_form_count,
+        answered_registration_form_count,
+    } = &input.field_names;
+
+    let pending_project = &data.pending_project;
+
+    if id.is_some() {
+        writer.write_field(pending_project.id().to_uuid().to_hyphenated().to_string())?;
+    }
+
+    if created_at.is_some() {
+        let created_at = pending_project
+            .created_at()
+            .jst()
+            .format("%F %T")
+            .to_string();
+        writer.write_field(created_at)?;
+    }
+
+    if updated_at.is_some() {
+        let updated_at = pending_project
+            .updated_at()
+            .jst()
+            .format("%F %T")
+            .to_string();
+        writer.write_field(updated_at)?;
+    }
+
+    if owner_id.is_some() {
+        writer.write_field(&pending_project.owner_id().0)?;
+    }
+
+    write_user_name_fields(
+        writer,
+        WriteUserNameFieldsInput {
+            first_name: owner_first_name.as_ref(),
+            last_name: owner_last_name.as_ref(),
+            full_name: owner_full_name.as_ref(),
+            kana_first_name: owner_kana_first_name.as_ref(),
+            kana_last_name: owner_kana_last_name.as_ref(),
+            kana_full_name: owner_kana_full_name.as_ref(),
+        },
+        data.owner_name,
+        data.owner_kana_name,
+    )?;
+
+    if name.is_some() {
+        writer.write_field(pending_project.name().as_str())?;
+    }
+
+    if kana_name.is_some() {
+        writer.write_field(pending_project.kana_name().as_str())?;
+    }
+
+    if group_name.is_some() {
+        writer.write_field(pending_project.group_name().as_str())?;
+    }
+
+    if kana_group_name.is_some() {
+        writer.write_field(pending_project.kana_group_name().as_str())?;
+    }
+
+    if description.is_some() {
+        writer.write_field(pending_project.description().as_str())?;
+    }
+
+    if category.is_some() {
+        let category_name = match pending_project.category() {
+            project::ProjectCategory::General => &input.category_names.general,
+            project::ProjectCategory::CookingRequiringPreparationArea => {
+                &input.category_names.cooking_requiring_preparation_area
+            }
+            project::ProjectCategory::Cooking => &input.category_names.cooking,
+            project::ProjectCategory::Food => &input.category_names.food,
+            project::ProjectCategory::Stage => &input.category_names.stage,
+        };
+        writer.write_field(category_name)?;
+    }
+
+    let attribute_fields = [
+        (attribute_academic, project::ProjectAttribute::Academic),
+        (attribute_artistic, project::ProjectAttribute::Artistic),
+        (attribute_committee, project::ProjectAttribute::Committee),
+        (attribute_outdoor, project::ProjectAttribute::Outdoor),
+        (attribute_indoor, project::ProjectAttribute::Indoor),
+    ];
+    for (field, attribute) in attribute_fields {
+        if field.is_some() {
+            if pending_project.attributes().contains(attribute) {
+                writer.write_field(b"TRUE")?;
+            } else {
+                writer.write_field(b"FALSE")?;
+            }
+        }
+    }
+
+    if exceptional_complete_deadline.is_some() {
+        match pending_project.exceptional_complete_deadline() {
+            Some(deadline) => writer.write_field(deadline.jst().format("%F %T").to_string())?,
+            None => writer.write_field(b"")?,
+        }
+    }
+
+    if registration_form_count.is_some() {
+        writer.write_field(data.registration_form_count.to_string())?;
+    }
+
+    if answered_registration_form_count.is_some() {
+        writer.write_field(data.answered_registration_form_count.to_string())?;
+    }
+
+    // this terminates the record (see docs on `csv::Writer::write_record`)
+    writer.write_record(std::iter::empty::<&[u8]>())?;
+
+    Ok(())
+}
+
+struct WriteUserNameFieldsInput<'a> {
+    first_name: Option<&'a String>,
+    last_name: Option<&'a String>,
+    full_name: Option<&'a String>,
+    kana_first_name: Option<&'a String>,
+    kana_last_name: Option<&'a String>,
+    kana_full_name: Option<&'a String>,
+}
+
+fn write_user_name_fields<'a, W>(
+    writer: &mut csv::Writer<W>,
+    input: WriteUserNameFieldsInput<'_>,
+    name: &'a user::UserName,
+    kana_name: &'a user::UserKanaName,
+) -> anyhow::Result<()>
+where
+    W: std::io::Write,
+{
+    if input.first_name.is_some() {
+        writer.write_field(name.first())?;
+    }
+
+    if input.last_name.is_some() {
+        writer.write_field(name.last())?;
+    }
+
+    if input.full_name.is_some() {
+        writer.write_field(format!("{} {}", name.last(), name.first()))?;
+    }
+
+    if input.kana_first_name.is_some() {
+        writer.write_field(kana_name.first())?;
+    }
+
+    if input.kana_last_name.is_some() {
+        writer.write_field(kana_name.last())?;
+    }
+
+    if input.kana_full_name.is_some() {
+        writer.write_field(format!("{} {}", kana_name.last(), kana_name.first()))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{export_pending_projects, UseCaseError};
+    use sos21_domain::context::Login;
+    use sos21_domain::model as domain;
+    use sos21_domain::test;
+
+    async fn prepare_app(
+        login_user: domain::user::User,
+    ) -> (
+        Login<test::context::MockApp>,
+        domain::pending_project::PendingProject,
+    ) {
+        let owner = test::model::new_general_user();
+        let pending_project = test::model::new_general_pending_project(owner.id().clone());
+        let registration_form = test::model::new_registration_form(login_user.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![login_user.clone(), owner])
+            .pending_projects(vec![pending_project.clone()])
+            .registration_forms(vec![registration_form])
+            .build()
+            .login_as(login_user)
+            .await;
+        (app, pending_project)
+    }
+
+    fn mock_input() -> export_pending_projects::Input {
+        let field_names = export_pending_projects::InputFieldNames {
+            id: Some("内部ID".to_string()),
+            created_at: Some("作成日時".to_string()),
+            updated_at: None,
+            owner_id: None,
+            owner_first_name: None,
+            owner_last_name: None,
+            owner_full_name: Some("責任者名".to_string()),
+            owner_kana_first_name: None,
+            owner_kana_last_name: None,
+            owner_kana_full_name: None,
+            name: Some("企画名".to_string()),
+            kana_name: None,
+            group_name: None,
+            kana_group_name: None,
+            description: None,
+            category: Some("企画形態".to_string()),
+            attribute_academic: Some("学術企画".to_string()),
+            attribute_artistic: None,
+            attribute_committee: None,
+            attribute_outdoor: None,
+            attribute_indoor: None,
+            exceptional_complete_deadline: Some("例外的な登録期限".to_string()),
+            registration_form_count: Some("登録申請数".to_string()),
+            answered_registration_form_count: Some("回答済み登録申請数".to_string()),
+        };
+        let category_names = export_pending_projects::InputCategoryNames {
+            general: "一般企画（食品取扱い企画を除く）".to_string(),
+            cooking_requiring_preparation_area: "調理を行う企画（仕込場が必要）".to_string(),
+            cooking: "調理を行う企画（仕込場が不要）".to_string(),
+            food: "飲食物取扱い企画".to_string(),
+            stage: "ステージ企画".to_string(),
+        };
+        export_pending_projects::Input {
+            festival_id: None,
+            field_names,
+            category_names,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_general() {
+        let user = test::model::new_general_user();
+        let (app, _) = prepare_app(user).await;
+
+        assert!(matches!(
+            export_pending_projects::run(&app, mock_input()).await,
+            Err(UseCaseError::UseCase(
+                export_pending_projects::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_committee() {
+        let user = test::model::new_committee_user();
+        let (app, pending_project) = prepare_app(user).await;
+
+        let csv = export_pending_projects::run(&app, mock_input())
+            .await
+            .unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let mut lines = csv.split("\r\n");
+        assert_eq!(
+            lines.next().unwrap(),
+            "内部ID,作成日時,責任者名,企画名,企画形態,学術企画,例外的な登録期限,登録申請数,回答済み登録申請数"
+        );
+        let record = lines.next().unwrap();
+        assert!(record.starts_with(&pending_project.id().to_uuid().to_hyphenated().to_string()));
+        assert!(record.ends_with(",1,0"));
+    }
+}
@@ -1,9 +1,10 @@
 use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::festival::FestivalId;
 
 use anyhow::Context;
 use sos21_domain::context::{
     project_repository::{self, ProjectRepository},
-    Login,
+    ConfigContext, Login,
 };
 use sos21_domain::model::{permissions::Permissions, project, user};
 
@@ -14,6 +15,7 @@ pub enum Error {
 
 #[derive(Debug, Clone)]
 pub struct Input {
+    pub festival_id: Option<FestivalId>,
     pub field_names: InputFieldNames,
     pub category_names: InputCategoryNames,
 }
@@ -63,7 +65,7 @@ pub struct InputCategoryNames {
 #[tracing::instrument(skip(ctx))]
 pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<Vec<u8>, Error>
 where
-    Login<C>: ProjectRepository,
+    Login<C>: ProjectRepository + ConfigContext,
 {
     let login_user = ctx.login_user();
 
@@ -74,6 +76,10 @@ where
         return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
     }
 
+    let festival_id = input
+        .festival_id
+        .map(FestivalId::into_entity)
+        .unwrap_or_else(|| ctx.active_festival_id());
     let projects = ctx
         .list_projects()
         .await
@@ -93,6 +99,10 @@ where
             subowner,
         } = project_with_owners;
 
+        if project.festival_id() != festival_id {
+            continue;
+        }
+
         use_case_ensure!(
             project.is_visible_to(login_user)
                 && owner.name().is_visible_to(login_user)
@@ -518,6 +528,7 @@ mod tests {
             stage: "ステージ企画".to_string(),
         };
         export_projects::Input {
+            festival_id: None,
             field_names,
             category_names,
         }
@@ -543,10 +554,7 @@ mod tests {
         let user = test::model::new_committee_user();
         let app = prepare_app(user).await;
 
-        assert!(matches!(
-            export_projects::run(&app, mock_input()).await,
-            Ok(_)
-        ));
+        assert!(export_projects::run(&app, mock_input()).await.is_ok());
     }
 
     // Checks that the privileged committee user can list projects.
@@ -555,9 +563,6 @@ mod tests {
         let user = test::model::new_operator_user();
         let app = prepare_app(user).await;
 
-        assert!(matches!(
-            export_projects::run(&app, mock_input()).await,
-            Ok(_)
-        ));
+        assert!(export_projects::run(&app, mock_input()).await.is_ok());
     }
 }
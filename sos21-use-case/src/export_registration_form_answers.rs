@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::fmt::{self, Debug};
 
 use crate::error::{UseCaseError, UseCaseResult};
@@ -6,7 +7,7 @@ use crate::model::registration_form::RegistrationFormId;
 use anyhow::{bail, Context};
 use sos21_domain::context::{Login, RegistrationFormAnswerRepository, RegistrationFormRepository};
 use sos21_domain::model::{
-    form, form_answer, permissions, registration_form, registration_form_answer, user,
+    access_control, form, form_answer, permissions, registration_form, registration_form_answer,
 };
 
 #[derive(Debug, Clone)]
@@ -15,12 +16,6 @@ pub enum Error {
     InsufficientPermissions,
 }
 
-impl Error {
-    fn from_permissions_error(_err: user::RequirePermissionsError) -> Self {
-        Error::InsufficientPermissions
-    }
-}
-
 #[derive(Debug, Clone)]
 pub struct RenderFileAnswerInput {
     pub answer_id: String,
@@ -30,6 +25,7 @@ pub struct RenderFileAnswerInput {
 pub struct Input<F> {
     pub registration_form_id: RegistrationFormId,
     pub field_names: InputFieldNames,
+    pub group_layout: InputGroupLayout,
     pub render_file_answer: F,
 }
 
@@ -38,6 +34,7 @@ impl<F> Debug for Input<F> {
         f.debug_struct("Input")
             .field("registration_form_id", &self.registration_form_id)
             .field("field_names", &self.field_names)
+            .field("group_layout", &self.group_layout)
             .finish()
     }
 }
@@ -52,6 +49,15 @@ pub struct InputFieldNames {
     pub author_id: Option<String>,
 }
 
+/// How the rows of the answers to group items are laid out in the CSV.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputGroupLayout {
+    /// Each row is written to its own set of columns in the same record.
+    Columns,
+    /// Each row is written to its own record, repeating the other fields.
+    Rows,
+}
+
 #[tracing::instrument(skip(ctx))]
 pub async fn run<C, F>(ctx: &Login<C>, input: Input<F>) -> UseCaseResult<Vec<u8>, Error>
 where
@@ -60,14 +66,24 @@ where
 {
     let login_user = ctx.login_user();
 
-    login_user
-        .require_permissions(permissions::Permissions::READ_ALL_REGISTRATION_FORM_ANSWERS)
-        .map_err(|err| UseCaseError::UseCase(Error::from_permissions_error(err)))?;
-
     let result = ctx
         .get_registration_form(input.registration_form_id.into_entity())
         .await
         .context("Failed to get a registration form")?;
+
+    let is_granted = match &result {
+        Some(registration_form) => registration_form.access_control.allows_with_permission(
+            login_user,
+            access_control::AccessControlLevel::Read,
+            permissions::Permissions::READ_ALL_REGISTRATION_FORM_ANSWERS,
+        ),
+        None => login_user
+            .permissions()
+            .contains(permissions::Permissions::READ_ALL_REGISTRATION_FORM_ANSWERS),
+    };
+    if !is_granted {
+        return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
+    }
     let registration_form = match result {
         Some(x) => x,
         None => return Err(UseCaseError::UseCase(Error::RegistrationFormNotFound)),
@@ -83,11 +99,23 @@ where
         .terminator(csv::Terminator::CRLF)
         .from_writer(Vec::new());
 
-    write_header(&mut writer, &input.field_names, &registration_form)?;
+    let group_widths = group_widths(
+        &registration_form.items,
+        answers.iter().map(|answer| answer.items()),
+    );
+    write_header(&mut writer, &input, &registration_form, &group_widths)?;
 
     for answer in answers {
-        use_case_ensure!(answer.is_visible_to(login_user));
-        write_record(&mut writer, &input, &registration_form, answer)?;
+        use_case_ensure!(
+            answer.is_visible_to_with_registration_form(login_user, &registration_form)
+        );
+        write_record(
+            &mut writer,
+            &input,
+            &registration_form,
+            &group_widths,
+            answer,
+        )?;
     }
 
     let csv = writer.into_inner().context("Failed to write CSV data")?;
@@ -95,10 +123,11 @@ where
 }
 
 // TODO: Ensure that the field orders are consistent between `write_header` and `write_record`
-fn write_header<W>(
+fn write_header<W, F>(
     writer: &mut csv::Writer<W>,
-    field_names: &InputFieldNames,
+    input: &Input<F>,
     registration_form: &registration_form::RegistrationForm,
+    group_widths: &HashMap<form::item::FormItemId, usize>,
 ) -> anyhow::Result<()>
 where
     W: std::io::Write,
@@ -110,7 +139,7 @@ where
         project_id,
         pending_project_id,
         author_id,
-    } = field_names;
+    } = &input.field_names;
 
     macro_rules! write_field {
         ($writer:ident, $name:ident) => {
@@ -128,7 +157,35 @@ where
     write_field!(writer, author_id);
 
     for item in registration_form.items.items() {
-        write_item_header_fields(writer, item)?;
+        use form::item::FormItemBody;
+        match &item.body {
+            FormItemBody::Group(group_item) => {
+                let rows = match input.group_layout {
+                    InputGroupLayout::Columns => group_widths.get(&item.id).copied().unwrap_or(1),
+                    InputGroupLayout::Rows => 1,
+                };
+                for row in 1..=rows {
+                    for group_item in group_item.items() {
+                        for name in item_field_names(group_item) {
+                            let field_name = match input.group_layout {
+                                InputGroupLayout::Columns => {
+                                    format!("{} {} {}", item.name.as_str(), row, name)
+                                }
+                                InputGroupLayout::Rows => {
+                                    format!("{} {}", item.name.as_str(), name)
+                                }
+                            };
+                            writer.write_field(field_name)?;
+                        }
+                    }
+                }
+            }
+            _ => {
+                for name in item_field_names(item) {
+                    writer.write_field(name)?;
+                }
+            }
+        }
     }
 
     // this terminates the record (see docs on `csv::Writer::write_record`)
@@ -137,46 +194,99 @@ where
     Ok(())
 }
 
-fn write_item_header_fields<W>(
-    writer: &mut csv::Writer<W>,
-    item: &form::item::FormItem,
-) -> anyhow::Result<()>
+fn item_field_names(item: &form::item::FormItem) -> Vec<String> {
+    use form::item::FormItemBody;
+
+    let with_suffix = |suffix: &str| format!("{} {}", item.name.as_str(), suffix);
+    match &item.body {
+        FormItemBody::Checkbox(checkbox_item) => checkbox_item
+            .boxes()
+            .map(|checkbox| with_suffix(checkbox.label.as_str()))
+            .collect(),
+        FormItemBody::GridRadio(grid_item) => grid_item
+            .rows()
+            .map(|row| with_suffix(row.label.as_str()))
+            .collect(),
+        _ => vec![item.name.as_str().to_string()],
+    }
+}
+
+/// Computes the number of rows to be written for each group item in the columns layout,
+/// which is the maximum number of the answered rows.
+fn group_widths<'a, I>(
+    items: &form::item::FormItems,
+    answers: I,
+) -> HashMap<form::item::FormItemId, usize>
 where
-    W: std::io::Write,
+    I: IntoIterator<Item = &'a form_answer::item::FormAnswerItems>,
 {
     use form::item::FormItemBody;
+    use form_answer::item::FormAnswerItemBody;
 
-    match &item.body {
-        FormItemBody::Checkbox(checkbox_item) => {
-            for checkbox in checkbox_item.boxes() {
-                let mut field_name = item.name.as_str().to_string();
-                field_name.push(' ');
-                field_name.push_str(checkbox.label.as_str());
-                writer.write_field(field_name)?;
-            }
-        }
-        FormItemBody::GridRadio(grid_item) => {
-            for row in grid_item.rows() {
-                let mut field_name = item.name.as_str().to_string();
-                field_name.push(' ');
-                field_name.push_str(row.label.as_str());
-                writer.write_field(field_name)?;
+    let mut widths: HashMap<_, _> = items
+        .items()
+        .filter(|item| matches!(item.body, FormItemBody::Group(_)))
+        .map(|item| (item.id, 1))
+        .collect();
+    for answer_items in answers {
+        for answer_item in answer_items.items() {
+            if let Some(FormAnswerItemBody::Group(rows)) = &answer_item.body {
+                if let Some(width) = widths.get_mut(&answer_item.item_id) {
+                    *width = std::cmp::max(*width, rows.len());
+                }
             }
         }
-        _ => {
-            writer.write_field(item.name.as_str())?;
-        }
     }
-
-    Ok(())
+    widths
 }
 
 fn write_record<W, F>(
     writer: &mut csv::Writer<W>,
     input: &Input<F>,
     registration_form: &registration_form::RegistrationForm,
+    group_widths: &HashMap<form::item::FormItemId, usize>,
     answer: registration_form_answer::RegistrationFormAnswer,
 ) -> anyhow::Result<()>
+where
+    W: std::io::Write,
+    F: Fn(RenderFileAnswerInput) -> anyhow::Result<String>,
+{
+    let records = match input.group_layout {
+        InputGroupLayout::Columns => 1,
+        InputGroupLayout::Rows => answer
+            .items()
+            .items()
+            .filter_map(|answer_item| match &answer_item.body {
+                Some(form_answer::item::FormAnswerItemBody::Group(rows)) => Some(rows.len()),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(1)
+            .max(1),
+    };
+
+    for record in 0..records {
+        write_record_fields(
+            writer,
+            input,
+            registration_form,
+            group_widths,
+            &answer,
+            record,
+        )?;
+    }
+
+    Ok(())
+}
+
+fn write_record_fields<W, F>(
+    writer: &mut csv::Writer<W>,
+    input: &Input<F>,
+    registration_form: &registration_form::RegistrationForm,
+    group_widths: &HashMap<form::item::FormItemId, usize>,
+    answer: &registration_form_answer::RegistrationFormAnswer,
+    record: usize,
+) -> anyhow::Result<()>
 where
     W: std::io::Write,
     F: Fn(RenderFileAnswerInput) -> anyhow::Result<String>,
@@ -236,12 +346,44 @@ where
             sharing_ids,
         })
     };
-    for (item, answer_item) in registration_form
-        .items
-        .items()
-        .zip(answer.into_items().into_items())
-    {
-        write_item_fields(writer, render, item, answer_item)?;
+    for (item, answer_item) in registration_form.items.items().zip(answer.items().items()) {
+        use form::item::FormItemBody;
+        use form_answer::item::FormAnswerItemBody;
+
+        let group_item = match &item.body {
+            FormItemBody::Group(group_item) => group_item,
+            _ => {
+                write_item_fields(writer, &render, item, answer_item.clone())?;
+                continue;
+            }
+        };
+
+        let rows: Vec<_> = match &answer_item.body {
+            Some(FormAnswerItemBody::Group(rows)) => rows.rows().collect(),
+            Some(_) => bail!("unexpectedly mismatched form item and form answer item"),
+            None => Vec::new(),
+        };
+        let rows = match input.group_layout {
+            InputGroupLayout::Columns => {
+                let width = group_widths.get(&item.id).copied().unwrap_or(1);
+                (0..width).map(|row| rows.get(row)).collect()
+            }
+            InputGroupLayout::Rows => vec![rows.get(record)],
+        };
+        for row in rows {
+            match row {
+                Some(row) => {
+                    for (group_item, answer_item) in group_item.items().zip(row.items()) {
+                        write_item_fields(writer, &render, group_item, answer_item.clone())?;
+                    }
+                }
+                None => {
+                    for group_item in group_item.items() {
+                        write_empty_item_fields(writer, group_item)?;
+                    }
+                }
+            }
+        }
     }
 
     // this terminates the record (see docs on `csv::Writer::write_record`)
@@ -252,20 +394,17 @@ where
 
 fn write_item_fields<W, F>(
     writer: &mut csv::Writer<W>,
-    render_file_answer: F,
+    render_file_answer: &F,
     item: &form::item::FormItem,
     answer_item: form_answer::item::FormAnswerItem,
 ) -> anyhow::Result<()>
 where
     W: std::io::Write,
-    F: FnOnce(Vec<String>) -> anyhow::Result<String>,
+    F: Fn(Vec<String>) -> anyhow::Result<String>,
 {
     let body = match answer_item.body {
         Some(body) => body,
-        None => {
-            writer.write_field("")?;
-            return Ok(());
-        }
+        None => return write_empty_item_fields(writer, item),
     };
 
     use form::item::FormItemBody;
@@ -334,6 +473,23 @@ where
             let field = (render_file_answer)(sharings).context("Failed to render file answer")?;
             writer.write_field(field)?;
         }
+        FormAnswerItemBody::Group(_) => {
+            bail!("unexpectedly mismatched form item and form answer item")
+        }
+    }
+
+    Ok(())
+}
+
+fn write_empty_item_fields<W>(
+    writer: &mut csv::Writer<W>,
+    item: &form::item::FormItem,
+) -> anyhow::Result<()>
+where
+    W: std::io::Write,
+{
+    for _ in item_field_names(item) {
+        writer.write_field("")?;
     }
 
     Ok(())
@@ -405,6 +561,7 @@ mod tests {
         export_registration_form_answers::Input {
             registration_form_id,
             field_names,
+            group_layout: export_registration_form_answers::InputGroupLayout::Columns,
             render_file_answer,
         }
     }
@@ -430,10 +587,11 @@ mod tests {
         let user = test::model::new_committee_user();
         let (app, registration_form_id) = prepare_app(user).await;
 
-        assert!(matches!(
-            export_registration_form_answers::run(&app, mock_input(registration_form_id)).await,
-            Ok(_)
-        ));
+        assert!(
+            export_registration_form_answers::run(&app, mock_input(registration_form_id))
+                .await
+                .is_ok()
+        );
     }
 
     // Checks that the privileged committee user can export registration_form answers.
@@ -442,9 +600,10 @@ mod tests {
         let user = test::model::new_operator_user();
         let (app, registration_form_id) = prepare_app(user).await;
 
-        assert!(matches!(
-            export_registration_form_answers::run(&app, mock_input(registration_form_id)).await,
-            Ok(_)
-        ));
+        assert!(
+            export_registration_form_answers::run(&app, mock_input(registration_form_id))
+                .await
+                .is_ok()
+        );
     }
 }
@@ -0,0 +1,68 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::festival::{Festival, FestivalId};
+
+use anyhow::Context;
+use sos21_domain::context::{ConfigContext, FestivalRepository, Login};
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    NotFound,
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, festival_id: FestivalId) -> UseCaseResult<Festival, Error>
+where
+    C: FestivalRepository + ConfigContext + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    let result = ctx
+        .get_festival(festival_id.into_entity())
+        .await
+        .context("Failed to get a festival")?;
+    let festival = match result {
+        Some(festival) if festival.is_visible_to(login_user) => festival,
+        _ => return Err(UseCaseError::UseCase(Error::NotFound)),
+    };
+
+    let is_active = festival.is_active(ctx);
+    Ok(Festival::from_entity(festival, is_active))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::festival::FestivalId;
+    use crate::{get_festival, UseCaseError};
+    use sos21_domain::test;
+
+    #[tokio::test]
+    async fn test_general() {
+        let user = test::model::new_general_user();
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user)
+            .await;
+
+        let festival_id = FestivalId::from_entity(*test::model::KNOWN_MOCK_FESTIVAL_ID);
+        let got = get_festival::run(&app, festival_id).await.unwrap();
+        assert_eq!(got.id, festival_id);
+        assert!(got.is_active);
+    }
+
+    #[tokio::test]
+    async fn test_not_found() {
+        let user = test::model::new_general_user();
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user)
+            .await;
+
+        let festival_id = FestivalId::from_entity(test::model::new_festival_id());
+        assert!(matches!(
+            get_festival::run(&app, festival_id).await,
+            Err(UseCaseError::UseCase(get_festival::Error::NotFound))
+        ));
+    }
+}
@@ -3,7 +3,6 @@ use crate::model::form::{Form, FormId};
 
 use anyhow::Context;
 use sos21_domain::context::{FormRepository, Login};
-use sos21_domain::model::permissions::Permissions;
 
 #[derive(Debug, Clone)]
 pub enum Error {
@@ -18,13 +17,6 @@ where
 {
     let login_user = ctx.login_user();
 
-    if login_user
-        .require_permissions(Permissions::READ_ALL_FORMS)
-        .is_err()
-    {
-        return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
-    }
-
     let result = ctx
         .get_form(form_id.into_entity())
         .await
@@ -34,7 +26,11 @@ where
         None => return Err(UseCaseError::UseCase(Error::NotFound)),
     };
 
-    use_case_ensure!(form.is_visible_to(login_user));
+    // `Form::is_visible_to` takes the accesses granted on the form into account
+    if !form.is_visible_to(login_user) {
+        return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
+    }
+
     Ok(Form::from_entity(form))
 }
 
@@ -2,8 +2,7 @@ use crate::error::{UseCaseError, UseCaseResult};
 use crate::model::form_answer::{FormAnswer, FormAnswerId};
 
 use anyhow::Context;
-use sos21_domain::context::{FormAnswerRepository, Login};
-use sos21_domain::model::permissions::Permissions;
+use sos21_domain::context::{FormAnswerRepository, FormRepository, Login};
 
 #[derive(Debug, Clone)]
 pub enum Error {
@@ -14,17 +13,10 @@ pub enum Error {
 #[tracing::instrument(skip(ctx))]
 pub async fn run<C>(ctx: &Login<C>, answer_id: FormAnswerId) -> UseCaseResult<FormAnswer, Error>
 where
-    C: FormAnswerRepository + Send + Sync,
+    C: FormRepository + FormAnswerRepository + Send + Sync,
 {
     let login_user = ctx.login_user();
 
-    if login_user
-        .require_permissions(Permissions::READ_ALL_FORM_ANSWERS)
-        .is_err()
-    {
-        return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
-    }
-
     let result = ctx
         .get_form_answer(answer_id.into_entity())
         .await
@@ -34,7 +26,19 @@ where
         _ => return Err(UseCaseError::UseCase(Error::NotFound)),
     };
 
-    use_case_ensure!(answer.is_visible_to(login_user));
+    let form = match ctx
+        .get_form(answer.form_id())
+        .await
+        .context("Failed to get a form")?
+    {
+        Some(form) => form,
+        None => return Err(use_case_internal!("Form answer refers to a missing form")),
+    };
+
+    if !answer.is_visible_to_with_form(login_user, &form) {
+        return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
+    }
+
     Ok(FormAnswer::from_entity(answer))
 }
 
@@ -4,7 +4,9 @@ use crate::model::file_sharing::FileSharingId;
 use crate::model::form_answer::FormAnswerId;
 
 use anyhow::Context;
-use sos21_domain::context::{FileSharingRepository, FormAnswerRepository, Login, ObjectRepository};
+use sos21_domain::context::{
+    FileSharingRepository, FormAnswerRepository, FormRepository, Login, ObjectRepository,
+};
 use sos21_domain::model::file_sharing;
 
 #[derive(Debug, Clone)]
@@ -33,7 +35,12 @@ impl Error {
 #[tracing::instrument(skip(ctx))]
 pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<FileObject, Error>
 where
-    C: FormAnswerRepository + FileSharingRepository + ObjectRepository + Send + Sync,
+    C: FormRepository
+        + FormAnswerRepository
+        + FileSharingRepository
+        + ObjectRepository
+        + Send
+        + Sync,
 {
     let login_user = ctx.login_user();
 
@@ -42,10 +49,23 @@ where
         .await
         .context("Failed to get a form answer")?;
     let answer = match result {
-        Some(answer) if answer.is_visible_to(login_user) => answer,
-        _ => return Err(UseCaseError::UseCase(Error::FormAnswerNotFound)),
+        Some(answer) => answer,
+        None => return Err(UseCaseError::UseCase(Error::FormAnswerNotFound)),
     };
 
+    let form = match ctx
+        .get_form(answer.form_id())
+        .await
+        .context("Failed to get a form")?
+    {
+        Some(form) => form,
+        None => return Err(use_case_internal!("Form answer refers to a missing form")),
+    };
+
+    if !answer.is_visible_to_with_form(login_user, &form) {
+        return Err(UseCaseError::UseCase(Error::FormAnswerNotFound));
+    }
+
     let result = ctx
         .get_file_sharing(input.sharing_id.into_entity())
         .await
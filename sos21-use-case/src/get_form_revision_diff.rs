@@ -0,0 +1,144 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::form::{FormId, FormRevisionDiff};
+
+use anyhow::Context;
+use sos21_domain::context::{FormRepository, Login};
+use sos21_domain::model::form;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub form_id: FormId,
+    pub from_version: u32,
+    pub to_version: u32,
+}
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    NotFound,
+    RevisionNotFound(u32),
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<FormRevisionDiff, Error>
+where
+    C: FormRepository + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    let form_id = input.form_id.into_entity();
+    match ctx
+        .get_form(form_id)
+        .await
+        .context("Failed to get a form")?
+    {
+        Some(form) if form.is_visible_to(login_user) => {}
+        _ => return Err(UseCaseError::UseCase(Error::NotFound)),
+    }
+
+    let from = get_revision(ctx, form_id, input.from_version).await?;
+    let to = get_revision(ctx, form_id, input.to_version).await?;
+
+    let diff = from
+        .diff(&to)
+        .context("Failed to compute difference of form revisions")?;
+    Ok(FormRevisionDiff::from_entity(diff))
+}
+
+async fn get_revision<C>(
+    ctx: &Login<C>,
+    form_id: form::FormId,
+    version: u32,
+) -> UseCaseResult<form::FormRevision, Error>
+where
+    C: FormRepository + Send + Sync,
+{
+    let revision_version = match form::FormVersion::from_u32(version) {
+        Ok(revision_version) => revision_version,
+        Err(_) => return Err(UseCaseError::UseCase(Error::RevisionNotFound(version))),
+    };
+
+    match ctx
+        .get_form_revision(form_id, revision_version)
+        .await
+        .context("Failed to get a form revision")?
+    {
+        Some(revision) => Ok(revision),
+        None => Err(UseCaseError::UseCase(Error::RevisionNotFound(version))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::form::FormId;
+    use crate::{get_form_revision_diff, UseCaseError};
+    use sos21_domain::model::form::FormItems;
+    use sos21_domain::test;
+
+    #[tokio::test]
+    async fn test_diff() {
+        let user = test::model::new_committee_user();
+        let operator = test::model::new_operator_user();
+        let form = test::model::new_form(operator.id().clone());
+        let added_item = test::model::new_form_item();
+        let mut new_form = form.clone();
+        new_form
+            .set_items(
+                &test::model::new_admin_user(),
+                FormItems::from_items(
+                    form.items()
+                        .items()
+                        .cloned()
+                        .chain(std::iter::once(added_item.clone())),
+                )
+                .unwrap(),
+            )
+            .unwrap();
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), operator.clone()])
+            .forms(vec![form.clone(), new_form.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let input = get_form_revision_diff::Input {
+            form_id: FormId::from_entity(form.id()),
+            from_version: form.version().to_u32(),
+            to_version: new_form.version().to_u32(),
+        };
+        let diff = get_form_revision_diff::run(&app, input).await.unwrap();
+        assert!(!diff.name_changed);
+        assert_eq!(
+            diff.added_item_ids,
+            vec![crate::model::form::FormItemId::from_entity(added_item.id)]
+        );
+        assert!(diff.removed_item_ids.is_empty());
+        assert!(diff.modified_item_ids.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_revision_not_found() {
+        let user = test::model::new_committee_user();
+        let operator = test::model::new_operator_user();
+        let form = test::model::new_form(operator.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), operator.clone()])
+            .forms(vec![form.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let input = get_form_revision_diff::Input {
+            form_id: FormId::from_entity(form.id()),
+            from_version: form.version().to_u32(),
+            to_version: form.version().next().to_u32(),
+        };
+        assert!(matches!(
+            get_form_revision_diff::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                get_form_revision_diff::Error::RevisionNotFound(version)
+            )) if version == form.version().next().to_u32()
+        ));
+    }
+}
@@ -0,0 +1,107 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::form_template::{FormTemplate, FormTemplateId};
+
+use anyhow::Context;
+use sos21_domain::context::{FormTemplateRepository, Login};
+use sos21_domain::model::permissions::Permissions;
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    NotFound,
+    InsufficientPermissions,
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(
+    ctx: &Login<C>,
+    template_id: FormTemplateId,
+) -> UseCaseResult<FormTemplate, Error>
+where
+    C: FormTemplateRepository + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    if login_user
+        .require_permissions(Permissions::READ_ALL_FORMS)
+        .is_err()
+    {
+        return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
+    }
+
+    let result = ctx
+        .get_form_template(template_id.into_entity())
+        .await
+        .context("Failed to get a form template")?;
+    let template = match result {
+        Some(x) => x,
+        None => return Err(UseCaseError::UseCase(Error::NotFound)),
+    };
+
+    use_case_ensure!(template.is_visible_to(login_user));
+    Ok(FormTemplate::from_entity(template))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::form_template::FormTemplateId;
+    use crate::{get_form_template, UseCaseError};
+    use sos21_domain::test;
+
+    // Checks that the normal user cannot read form templates.
+    #[tokio::test]
+    async fn test_general() {
+        let user = test::model::new_general_user();
+        let operator = test::model::new_operator_user();
+        let template = test::model::new_form_template(operator.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), operator.clone()])
+            .form_templates(vec![template.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        assert!(matches!(
+            get_form_template::run(&app, FormTemplateId::from_entity(template.id())).await,
+            Err(UseCaseError::UseCase(
+                get_form_template::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    // Checks that the committee user can read form templates.
+    #[tokio::test]
+    async fn test_committee() {
+        let user = test::model::new_committee_user();
+        let operator = test::model::new_operator_user();
+        let template = test::model::new_form_template(operator.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), operator.clone()])
+            .form_templates(vec![template.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let template_id = FormTemplateId::from_entity(template.id());
+        let got = get_form_template::run(&app, template_id).await.unwrap();
+        assert_eq!(got.id, template_id);
+    }
+
+    #[tokio::test]
+    async fn test_not_found() {
+        let user = test::model::new_committee_user();
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let template_id = FormTemplateId::from_entity(test::model::new_form_template_id());
+        assert!(matches!(
+            get_form_template::run(&app, template_id).await,
+            Err(UseCaseError::UseCase(get_form_template::Error::NotFound))
+        ));
+    }
+}
@@ -47,27 +47,29 @@ where
         )
         .await
         .context("Failed to get a registration form answer")?;
+    let registration_form = match ctx
+        .get_registration_form(registration_form_id.into_entity())
+        .await?
+    {
+        Some(registration_form) => registration_form,
+        None => return Err(UseCaseError::UseCase(Error::RegistrationFormNotFound)),
+    };
     let answer = match result {
-        Some(answer) => answer,
-        None => {
-            if let Some(registration_form) = ctx
-                .get_registration_form(registration_form_id.into_entity())
-                .await?
-            {
-                if registration_form
-                    .is_visible_to_with_pending_project(login_user, &pending_project)
-                {
-                    return Err(UseCaseError::UseCase(Error::RegistrationFormAnswerNotFound));
-                }
-            }
-            return Err(UseCaseError::UseCase(Error::RegistrationFormNotFound));
+        Some(answer)
+            if answer.is_visible_to_with_pending_project(login_user, &pending_project)
+                || answer.is_visible_to_with_registration_form(login_user, &registration_form) =>
+        {
+            answer
+        }
+        Some(_) => return Err(UseCaseError::UseCase(Error::RegistrationFormAnswerNotFound)),
+        None if registration_form
+            .is_visible_to_with_pending_project(login_user, &pending_project) =>
+        {
+            return Err(UseCaseError::UseCase(Error::RegistrationFormAnswerNotFound))
         }
+        None => return Err(UseCaseError::UseCase(Error::RegistrationFormNotFound)),
     };
 
-    if !answer.is_visible_to_with_pending_project(login_user, &pending_project) {
-        return Err(UseCaseError::UseCase(Error::RegistrationFormAnswerNotFound));
-    }
-
     Ok(RegistrationFormAnswer::from_entity(answer))
 }
 
@@ -0,0 +1,68 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::{
+    pending_project::PendingProjectId, registration_form::RegistrationFormId,
+    registration_form_answer::RegistrationFormAnswerDraft,
+};
+
+use anyhow::Context;
+use sos21_domain::context::{
+    Login, PendingProjectRepository, RegistrationFormAnswerRepository, RegistrationFormRepository,
+};
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    PendingProjectNotFound,
+    RegistrationFormNotFound,
+    RegistrationFormAnswerDraftNotFound,
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(
+    ctx: &Login<C>,
+    pending_project_id: PendingProjectId,
+    registration_form_id: RegistrationFormId,
+) -> UseCaseResult<RegistrationFormAnswerDraft, Error>
+where
+    C: PendingProjectRepository
+        + RegistrationFormRepository
+        + RegistrationFormAnswerRepository
+        + Send
+        + Sync,
+{
+    let login_user = ctx.login_user();
+
+    let result = ctx
+        .get_pending_project(pending_project_id.into_entity())
+        .await
+        .context("Failed to get a pending project")?;
+    let pending_project = match result {
+        Some(result) if result.pending_project.is_visible_to(login_user) => result.pending_project,
+        _ => return Err(UseCaseError::UseCase(Error::PendingProjectNotFound)),
+    };
+
+    let result = ctx
+        .get_registration_form(registration_form_id.into_entity())
+        .await
+        .context("Failed to get a registration form")?;
+    let registration_form = match result {
+        Some(form) if form.is_visible_to_with_pending_project(login_user, &pending_project) => form,
+        _ => return Err(UseCaseError::UseCase(Error::RegistrationFormNotFound)),
+    };
+
+    let result = ctx
+        .get_registration_form_answer_draft(registration_form.id(), pending_project.id())
+        .await
+        .context("Failed to get a registration form answer draft")?;
+    let draft = match result {
+        Some(draft) if draft.is_visible_to_with_pending_project(login_user, &pending_project) => {
+            draft
+        }
+        _ => {
+            return Err(UseCaseError::UseCase(
+                Error::RegistrationFormAnswerDraftNotFound,
+            ))
+        }
+    };
+
+    Ok(RegistrationFormAnswerDraft::from_entity(draft))
+}
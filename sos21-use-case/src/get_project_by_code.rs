@@ -3,7 +3,7 @@ use crate::model::project::{Project, ProjectFromEntityInput};
 
 use anyhow::Context;
 use sos21_domain::context::project_repository::{self, ProjectRepository};
-use sos21_domain::context::Login;
+use sos21_domain::context::{ConfigContext, Login};
 use sos21_domain::model::project;
 
 #[derive(Debug, Clone)]
@@ -21,13 +21,13 @@ impl Error {
 #[tracing::instrument(skip(ctx))]
 pub async fn run<C>(ctx: &Login<C>, code: String) -> UseCaseResult<Project, Error>
 where
-    Login<C>: ProjectRepository,
+    Login<C>: ProjectRepository + ConfigContext,
 {
     let code = project::ProjectCode::parse(&code)
         .map_err(|err| UseCaseError::UseCase(Error::from_code_error(err)))?;
 
     let result = ctx
-        .get_project_by_index(code.index)
+        .get_project_by_index(ctx.active_festival_id(), code.index)
         .await
         .context("Failed to get a project")?;
     let result = match result {
@@ -35,22 +35,24 @@ where
         .get_form_answer_by_form_and_project(form_id.into_entity(), project_id.into_entity())
         .await
         .context("Failed to get a form answer")?;
+    let form = match ctx.get_form(form_id.into_entity()).await? {
+        Some(form) => form,
+        None => return Err(UseCaseError::UseCase(Error::FormNotFound)),
+    };
     let answer = match result {
-        Some(answer) => answer,
-        None => {
-            if let Some(form) = ctx.get_form(form_id.into_entity()).await? {
-                if form.is_visible_to(login_user) {
-                    return Err(UseCaseError::UseCase(Error::FormAnswerNotFound));
-                }
-            }
-            return Err(UseCaseError::UseCase(Error::FormNotFound));
+        Some(answer)
+            if answer.is_visible_to_with_project(login_user, &project)
+                || answer.is_visible_to_with_form(login_user, &form) =>
+        {
+            answer
+        }
+        Some(_) => return Err(UseCaseError::UseCase(Error::FormAnswerNotFound)),
+        None if form.is_visible_to(login_user) => {
+            return Err(UseCaseError::UseCase(Error::FormAnswerNotFound))
         }
+        None => return Err(UseCaseError::UseCase(Error::FormNotFound)),
     };
 
-    if !answer.is_visible_to_with_project(login_user, &project) {
-        return Err(UseCaseError::UseCase(Error::FormAnswerNotFound));
-    }
-
     Ok(FormAnswer::from_entity(answer))
 }
 
@@ -0,0 +1,53 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::{form::FormId, form_answer::FormAnswerDraft, project::ProjectId};
+
+use anyhow::Context;
+use sos21_domain::context::{FormAnswerRepository, FormRepository, Login, ProjectRepository};
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    ProjectNotFound,
+    FormNotFound,
+    FormAnswerDraftNotFound,
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(
+    ctx: &Login<C>,
+    project_id: ProjectId,
+    form_id: FormId,
+) -> UseCaseResult<FormAnswerDraft, Error>
+where
+    C: ProjectRepository + FormRepository + FormAnswerRepository + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    let result = ctx
+        .get_project(project_id.into_entity())
+        .await
+        .context("Failed to get a project")?;
+    let project = match result {
+        Some(result) if result.project.is_visible_to(login_user) => result.project,
+        _ => return Err(UseCaseError::UseCase(Error::ProjectNotFound)),
+    };
+
+    let result = ctx
+        .get_form(form_id.into_entity())
+        .await
+        .context("Failed to get a form")?;
+    let form = match result {
+        Some(form) if form.is_visible_to_with_project(login_user, &project) => form,
+        _ => return Err(UseCaseError::UseCase(Error::FormNotFound)),
+    };
+
+    let result = ctx
+        .get_form_answer_draft(form.id(), project.id())
+        .await
+        .context("Failed to get a form answer draft")?;
+    let draft = match result {
+        Some(draft) if draft.is_visible_to_with_project(login_user, &project) => draft,
+        _ => return Err(UseCaseError::UseCase(Error::FormAnswerDraftNotFound)),
+    };
+
+    Ok(FormAnswerDraft::from_entity(draft))
+}
@@ -62,16 +62,21 @@ where
         .get_form_answer_by_form_and_project(form_id, project.id())
         .await
         .context("Failed to get a form answer")?;
+    let form = match ctx.get_form(form_id).await? {
+        Some(form) => form,
+        None => return Err(UseCaseError::UseCase(Error::FormNotFound)),
+    };
     let answer = match result {
-        Some(answer) if answer.is_visible_to_with_project(login_user, &project) => answer,
-        _ => {
-            if let Some(form) = ctx.get_form(form_id).await? {
-                if form.is_visible_to(login_user) {
-                    return Err(UseCaseError::UseCase(Error::FormAnswerNotFound));
-                }
-            }
-            return Err(UseCaseError::UseCase(Error::FormNotFound));
+        Some(answer)
+            if answer.is_visible_to_with_project(login_user, &project)
+                || answer.is_visible_to_with_form(login_user, &form) =>
+        {
+            answer
+        }
+        _ if form.is_visible_to(login_user) => {
+            return Err(UseCaseError::UseCase(Error::FormAnswerNotFound))
         }
+        _ => return Err(UseCaseError::UseCase(Error::FormNotFound)),
     };
 
     let result = ctx
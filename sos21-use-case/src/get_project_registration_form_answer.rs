@@ -47,25 +47,27 @@ where
         )
         .await
         .context("Failed to get a registration form answer")?;
+    let registration_form = match ctx
+        .get_registration_form(registration_form_id.into_entity())
+        .await?
+    {
+        Some(registration_form) => registration_form,
+        None => return Err(UseCaseError::UseCase(Error::RegistrationFormNotFound)),
+    };
     let answer = match result {
-        Some(answer) => answer,
-        None => {
-            if let Some(registration_form) = ctx
-                .get_registration_form(registration_form_id.into_entity())
-                .await?
-            {
-                if registration_form.is_visible_to_with_project(login_user, &project) {
-                    return Err(UseCaseError::UseCase(Error::RegistrationFormAnswerNotFound));
-                }
-            }
-            return Err(UseCaseError::UseCase(Error::RegistrationFormNotFound));
+        Some(answer)
+            if answer.is_visible_to_with_project(login_user, &project)
+                || answer.is_visible_to_with_registration_form(login_user, &registration_form) =>
+        {
+            answer
+        }
+        Some(_) => return Err(UseCaseError::UseCase(Error::RegistrationFormAnswerNotFound)),
+        None if registration_form.is_visible_to_with_project(login_user, &project) => {
+            return Err(UseCaseError::UseCase(Error::RegistrationFormAnswerNotFound))
         }
+        None => return Err(UseCaseError::UseCase(Error::RegistrationFormNotFound)),
     };
 
-    if !answer.is_visible_to_with_project(login_user, &project) {
-        return Err(UseCaseError::UseCase(Error::RegistrationFormAnswerNotFound));
-    }
-
     Ok(RegistrationFormAnswer::from_entity(answer))
 }
 
@@ -76,6 +78,7 @@ mod tests {
         registration_form_answer::RegistrationFormAnswerId,
     };
     use crate::{get_project_registration_form_answer, UseCaseError};
+    use sos21_domain::model::access_control::AccessControlLevel;
     use sos21_domain::test;
 
     // Checks that the normal user cannot read registration form answers of others' projects.
@@ -254,4 +257,46 @@ mod tests {
             if got.id == RegistrationFormAnswerId::from_entity(answer_other.id())
         ));
     }
+
+    // Checks that the access control list of the registration form narrows the committee's access.
+    #[tokio::test]
+    async fn test_committee_narrowed_by_access_control() {
+        let user = test::model::new_committee_user();
+        let admin = test::model::new_admin_user();
+        let other = test::model::new_general_user();
+        let project_other = test::model::new_general_project(other.id().clone());
+        let mut registration_form = test::model::new_registration_form(other.id().clone());
+        registration_form
+            .set_access_control(
+                &admin,
+                test::model::new_user_access_control(admin.id().clone(), AccessControlLevel::Read),
+            )
+            .unwrap();
+        let answer_other = test::model::new_registration_form_answer_with_project(
+            other.id().clone(),
+            project_other.id(),
+            &registration_form,
+        );
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), admin, other.clone()])
+            .projects(vec![project_other.clone()])
+            .registration_forms(vec![registration_form.clone()])
+            .registration_form_answers(vec![answer_other])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        assert!(matches!(
+            get_project_registration_form_answer::run(
+                &app,
+                ProjectId::from_entity(project_other.id()),
+                RegistrationFormId::from_entity(registration_form.id)
+            )
+            .await,
+            Err(UseCaseError::UseCase(
+                get_project_registration_form_answer::Error::RegistrationFormAnswerNotFound
+            ))
+        ));
+    }
 }
@@ -66,17 +66,21 @@ where
         )
         .await
         .context("Failed to get a registration form answer")?;
+    let registration_form = match ctx.get_registration_form(registration_form_id).await? {
+        Some(registration_form) => registration_form,
+        None => return Err(UseCaseError::UseCase(Error::RegistrationFormNotFound)),
+    };
     let answer = match result {
-        Some(answer) if answer.is_visible_to_with_project(login_user, &project) => answer,
-        _ => {
-            if let Some(registration_form) = ctx.get_registration_form(registration_form_id).await?
-            {
-                if registration_form.is_visible_to_with_project(login_user, &project) {
-                    return Err(UseCaseError::UseCase(Error::RegistrationFormAnswerNotFound));
-                }
-            }
-            return Err(UseCaseError::UseCase(Error::RegistrationFormNotFound));
+        Some(answer)
+            if answer.is_visible_to_with_project(login_user, &project)
+                || answer.is_visible_to_with_registration_form(login_user, &registration_form) =>
+        {
+            answer
+        }
+        _ if registration_form.is_visible_to_with_project(login_user, &project) => {
+            return Err(UseCaseError::UseCase(Error::RegistrationFormAnswerNotFound))
         }
+        _ => return Err(UseCaseError::UseCase(Error::RegistrationFormNotFound)),
     };
 
     let result = ctx
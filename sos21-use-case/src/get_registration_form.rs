@@ -3,7 +3,7 @@ use crate::model::registration_form::{RegistrationForm, RegistrationFormId};
 
 use anyhow::Context;
 use sos21_domain::context::{Login, RegistrationFormRepository};
-use sos21_domain::model::{permissions::Permissions, user};
+use sos21_domain::model::{access_control::AccessControlLevel, permissions::Permissions, user};
 
 #[derive(Debug, Clone)]
 pub enum Error {
@@ -27,10 +27,6 @@ where
 {
     let login_user = ctx.login_user();
 
-    login_user
-        .require_permissions(Permissions::READ_ALL_REGISTRATION_FORMS)
-        .map_err(|err| UseCaseError::UseCase(Error::from_permissions_error(err)))?;
-
     let result = ctx
         .get_registration_form(registration_form_id.into_entity())
         .await
@@ -40,6 +36,15 @@ where
         None => return Err(UseCaseError::UseCase(Error::NotFound)),
     };
 
+    if !registration_form
+        .access_control
+        .allows(login_user, AccessControlLevel::Read)
+    {
+        login_user
+            .require_permissions(Permissions::READ_ALL_REGISTRATION_FORMS)
+            .map_err(|err| UseCaseError::UseCase(Error::from_permissions_error(err)))?;
+    }
+
     use_case_ensure!(registration_form.is_visible_to(login_user));
     Ok(RegistrationForm::from_entity(registration_form))
 }
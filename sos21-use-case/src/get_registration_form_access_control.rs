@@ -0,0 +1,123 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::{access_control::AccessControlEntry, registration_form::RegistrationFormId};
+
+use anyhow::Context;
+use sos21_domain::context::{Login, RegistrationFormRepository};
+use sos21_domain::model::{permissions::Permissions, user};
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    NotFound,
+    InsufficientPermissions,
+}
+
+impl Error {
+    fn from_permissions_error(_err: user::RequirePermissionsError) -> Self {
+        Error::InsufficientPermissions
+    }
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(
+    ctx: &Login<C>,
+    registration_form_id: RegistrationFormId,
+) -> UseCaseResult<Vec<AccessControlEntry>, Error>
+where
+    C: RegistrationFormRepository + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    login_user
+        .require_permissions(Permissions::READ_ALL_REGISTRATION_FORMS)
+        .map_err(|err| UseCaseError::UseCase(Error::from_permissions_error(err)))?;
+
+    let result = ctx
+        .get_registration_form(registration_form_id.into_entity())
+        .await
+        .context("Failed to get a registration form")?;
+    let registration_form = match result {
+        Some(registration_form) if registration_form.is_visible_to(login_user) => registration_form,
+        _ => return Err(UseCaseError::UseCase(Error::NotFound)),
+    };
+
+    Ok(registration_form
+        .access_control
+        .entries()
+        .cloned()
+        .map(AccessControlEntry::from_entity)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{
+        access_control::{AccessControlGrantee, AccessControlLevel},
+        registration_form::RegistrationFormId,
+        user::UserId,
+    };
+    use crate::{get_registration_form_access_control, UseCaseError};
+    use sos21_domain::model::access_control as entity;
+    use sos21_domain::test;
+
+    #[tokio::test]
+    async fn test_general() {
+        let user = test::model::new_general_user();
+        let operator = test::model::new_operator_user();
+        let registration_form = test::model::new_registration_form(operator.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), operator.clone()])
+            .registration_forms(vec![registration_form.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        assert!(matches!(
+            get_registration_form_access_control::run(
+                &app,
+                RegistrationFormId::from_entity(registration_form.id)
+            )
+            .await,
+            Err(UseCaseError::UseCase(
+                get_registration_form_access_control::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_committee() {
+        let admin = test::model::new_admin_user();
+        let user = test::model::new_committee_user();
+        let grantee = test::model::new_general_user();
+        let mut registration_form = test::model::new_registration_form(admin.id().clone());
+        registration_form
+            .set_access_control(
+                &admin,
+                test::model::new_user_access_control(
+                    grantee.id().clone(),
+                    entity::AccessControlLevel::Review,
+                ),
+            )
+            .unwrap();
+
+        let app = test::build_mock_app()
+            .users(vec![admin.clone(), user.clone(), grantee.clone()])
+            .registration_forms(vec![registration_form.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let got = get_registration_form_access_control::run(
+            &app,
+            RegistrationFormId::from_entity(registration_form.id),
+        )
+        .await
+        .unwrap();
+        assert_eq!(got.len(), 1);
+        assert_eq!(
+            got[0].grantee,
+            AccessControlGrantee::User(UserId::from_entity(grantee.id().clone()))
+        );
+        assert_eq!(got[0].level, AccessControlLevel::Review);
+    }
+}
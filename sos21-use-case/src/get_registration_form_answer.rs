@@ -2,8 +2,8 @@ use crate::error::{UseCaseError, UseCaseResult};
 use crate::model::registration_form_answer::{RegistrationFormAnswer, RegistrationFormAnswerId};
 
 use anyhow::Context;
-use sos21_domain::context::{Login, RegistrationFormAnswerRepository};
-use sos21_domain::model::{permissions::Permissions, user};
+use sos21_domain::context::{Login, RegistrationFormAnswerRepository, RegistrationFormRepository};
+use sos21_domain::model::{access_control::AccessControlLevel, permissions::Permissions};
 
 #[derive(Debug, Clone)]
 pub enum Error {
@@ -11,26 +11,16 @@ pub enum Error {
     InsufficientPermissions,
 }
 
-impl Error {
-    fn from_permissions_error(_err: user::RequirePermissionsError) -> Self {
-        Error::InsufficientPermissions
-    }
-}
-
 #[tracing::instrument(skip(ctx))]
 pub async fn run<C>(
     ctx: &Login<C>,
     answer_id: RegistrationFormAnswerId,
 ) -> UseCaseResult<RegistrationFormAnswer, Error>
 where
-    C: RegistrationFormAnswerRepository + Send + Sync,
+    C: RegistrationFormRepository + RegistrationFormAnswerRepository + Send + Sync,
 {
     let login_user = ctx.login_user();
 
-    login_user
-        .require_permissions(Permissions::READ_ALL_REGISTRATION_FORM_ANSWERS)
-        .map_err(|err| UseCaseError::UseCase(Error::from_permissions_error(err)))?;
-
     let result = ctx
         .get_registration_form_answer(answer_id.into_entity())
         .await
@@ -40,7 +30,28 @@ where
         _ => return Err(UseCaseError::UseCase(Error::NotFound)),
     };
 
-    use_case_ensure!(answer.is_visible_to(login_user));
+    let registration_form = match ctx
+        .get_registration_form(answer.registration_form_id())
+        .await
+        .context("Failed to get a registration form")?
+    {
+        Some(registration_form) => registration_form,
+        None => {
+            return Err(use_case_internal!(
+                "Registration form answer refers to a missing registration form"
+            ))
+        }
+    };
+
+    if !registration_form.access_control.allows_with_permission(
+        login_user,
+        AccessControlLevel::Read,
+        Permissions::READ_ALL_REGISTRATION_FORM_ANSWERS,
+    ) {
+        return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
+    }
+
+    use_case_ensure!(answer.is_visible_to_with_registration_form(login_user, &registration_form));
     Ok(RegistrationFormAnswer::from_entity(answer))
 }
 
@@ -48,6 +59,12 @@ where
 mod tests {
     use crate::model::registration_form_answer::RegistrationFormAnswerId;
     use crate::{get_registration_form_answer, UseCaseError};
+    use sos21_domain::model::{
+        access_control::{
+            AccessControlEntry, AccessControlGrantee, AccessControlLevel, AccessControlList,
+        },
+        permissions::Permissions,
+    };
     use sos21_domain::test;
 
     // Checks that the normal user cannot read the form answers directly.
@@ -143,4 +160,46 @@ mod tests {
             if got.id == answer_id
         ));
     }
+
+    // Checks that the users with the custom role granted on the registration form can read its answers.
+    #[tokio::test]
+    async fn test_general_access_control_custom_role() {
+        let admin = test::model::new_admin_user();
+        let role = test::model::new_custom_role(admin.id().clone(), Permissions::empty());
+        let mut user = test::model::new_general_user();
+        user.set_custom_role(&admin, Some(&role)).unwrap();
+        let other = test::model::new_general_user();
+        let pending_project = test::model::new_general_pending_project(other.id().clone());
+        let mut registration_form = test::model::new_registration_form(admin.id().clone());
+        let access_control = AccessControlList::from_entries(vec![AccessControlEntry {
+            grantee: AccessControlGrantee::CustomRole(role.id()),
+            level: AccessControlLevel::Read,
+        }])
+        .unwrap();
+        registration_form
+            .set_access_control(&admin, access_control)
+            .unwrap();
+        let answer = test::model::new_registration_form_answer_with_pending_project(
+            other.id().clone(),
+            pending_project.id(),
+            &registration_form,
+        );
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), admin, other])
+            .custom_roles(vec![role])
+            .pending_projects(vec![pending_project])
+            .registration_forms(vec![registration_form])
+            .registration_form_answers(vec![answer.clone()])
+            .build()
+            .login_as(user)
+            .await;
+
+        let answer_id = RegistrationFormAnswerId::from_entity(answer.id());
+        assert!(matches!(
+            get_registration_form_answer::run(&app, answer_id).await,
+            Ok(got)
+            if got.id == answer_id
+        ));
+    }
 }
@@ -4,8 +4,10 @@ use crate::model::file_sharing::FileSharingId;
 use crate::model::registration_form_answer::RegistrationFormAnswerId;
 
 use anyhow::Context;
-use sos21_domain::context::{FileSharingRepository, Login, RegistrationFormAnswerRepository};
-use sos21_domain::model::{file_sharing, permissions, user};
+use sos21_domain::context::{
+    FileSharingRepository, Login, RegistrationFormAnswerRepository, RegistrationFormRepository,
+};
+use sos21_domain::model::{access_control, file_sharing, permissions};
 
 #[derive(Debug, Clone)]
 pub struct Input {
@@ -29,32 +31,53 @@ impl Error {
             file_sharing::ToWitnessErrorKind::RevokedSharing => Error::InvalidSharing,
         }
     }
-
-    fn from_permissions_error(_err: user::RequirePermissionsError) -> Self {
-        Error::InsufficientPermissions
-    }
 }
 
 #[tracing::instrument(skip(ctx))]
 pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<File, Error>
 where
-    C: RegistrationFormAnswerRepository + FileSharingRepository + Send + Sync,
+    C: RegistrationFormRepository
+        + RegistrationFormAnswerRepository
+        + FileSharingRepository
+        + Send
+        + Sync,
 {
     let login_user = ctx.login_user();
 
-    login_user
-        .require_permissions(permissions::Permissions::READ_ALL_REGISTRATION_FORM_ANSWERS)
-        .map_err(|err| UseCaseError::UseCase(Error::from_permissions_error(err)))?;
-
     let result = ctx
         .get_registration_form_answer(input.answer_id.into_entity())
         .await
         .context("Failed to get a registration form answer")?;
     let answer = match result {
-        Some(answer) if answer.is_visible_to(login_user) => answer,
-        _ => return Err(UseCaseError::UseCase(Error::RegistrationFormAnswerNotFound)),
+        Some(answer) => answer,
+        None => return Err(UseCaseError::UseCase(Error::RegistrationFormAnswerNotFound)),
+    };
+
+    let registration_form = match ctx
+        .get_registration_form(answer.registration_form_id())
+        .await
+        .context("Failed to get a registration form")?
+    {
+        Some(registration_form) => registration_form,
+        None => {
+            return Err(use_case_internal!(
+                "Registration form answer refers to a missing registration form"
+            ))
+        }
     };
 
+    if !registration_form.access_control.allows_with_permission(
+        login_user,
+        access_control::AccessControlLevel::Read,
+        permissions::Permissions::READ_ALL_REGISTRATION_FORM_ANSWERS,
+    ) {
+        return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
+    }
+
+    if !answer.is_visible_to_with_registration_form(login_user, &registration_form) {
+        return Err(UseCaseError::UseCase(Error::RegistrationFormAnswerNotFound));
+    }
+
     let result = ctx
         .get_file_sharing(input.sharing_id.into_entity())
         .await
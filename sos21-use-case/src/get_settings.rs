@@ -0,0 +1,86 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::settings::Settings;
+
+use anyhow::Context;
+use sos21_domain::context::{ConfigContext, Login, SettingsRepository};
+use sos21_domain::model::{permissions::Permissions, settings, user};
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    InsufficientPermissions,
+}
+
+impl Error {
+    fn from_permissions_error(_err: user::RequirePermissionsError) -> Self {
+        Error::InsufficientPermissions
+    }
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>) -> UseCaseResult<Settings, Error>
+where
+    C: SettingsRepository + ConfigContext + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    login_user
+        .require_permissions(Permissions::READ_SETTINGS)
+        .map_err(|err| UseCaseError::UseCase(Error::from_permissions_error(err)))?;
+
+    let settings = match ctx.get_settings().await.context("Failed to get settings")? {
+        Some(settings) => settings,
+        None => settings::Settings::from_config(ctx),
+    };
+
+    use_case_ensure!(settings.is_visible_to(login_user));
+    Ok(Settings::from_entity(settings))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::project::ProjectCategory;
+    use crate::model::settings::ProjectCreationPeriod;
+    use crate::{get_settings, UseCaseError};
+
+    use sos21_domain::model::{project, project_creation_period};
+    use sos21_domain::test;
+
+    #[tokio::test]
+    async fn test_operator() {
+        let operator = test::model::new_operator_user();
+
+        let app = test::build_mock_app()
+            .users(vec![operator.clone()])
+            .build()
+            .login_as(operator)
+            .await;
+
+        assert!(matches!(
+            get_settings::run(&app).await,
+            Err(UseCaseError::UseCase(
+                get_settings::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_admin_from_config() {
+        let admin = test::model::new_admin_user();
+
+        let app = test::build_mock_app()
+            .users(vec![admin.clone()])
+            .project_creation_period_for(
+                project::ProjectCategory::Stage,
+                project_creation_period::ProjectCreationPeriod::never(),
+            )
+            .build()
+            .login_as(admin)
+            .await;
+
+        let got = get_settings::run(&app).await.unwrap();
+        assert!(got.author_id.is_none());
+        assert!(got
+            .project_creation_periods
+            .contains(&(ProjectCategory::Stage, ProjectCreationPeriod::Never)));
+    }
+}
@@ -17,6 +17,11 @@ where
 {
     let login_user = ctx.login_user();
 
+    // API keys are not given the accesses based on the ownership of the author
+    if login_user.is_with_api_key() {
+        return Err(UseCaseError::UseCase(Error::NotFound));
+    }
+
     let pending_project_id = match login_user.assignment() {
         Some(user::UserAssignment::PendingProjectOwner(pending_project_id)) => pending_project_id,
         _ => return Err(UseCaseError::UseCase(Error::NotFound)),
@@ -20,6 +20,11 @@ where
 {
     let login_user = ctx.login_user();
 
+    // API keys are not given the accesses based on the membership of the author
+    if login_user.is_with_api_key() {
+        return Err(UseCaseError::UseCase(Error::NotFound));
+    }
+
     let project_id = match login_user.assignment() {
         Some(user::UserAssignment::ProjectOwner(project_id)) => project_id,
         Some(user::UserAssignment::ProjectSubowner(project_id)) => project_id,
@@ -59,6 +64,7 @@ where
 mod tests {
     use crate::model::project::ProjectId;
     use crate::{get_user_project, UseCaseError};
+    use sos21_domain::model::{api_key::ApiKeyScope, user};
     use sos21_domain::test;
 
     #[tokio::test]
@@ -123,4 +129,28 @@ mod tests {
             Err(UseCaseError::UseCase(get_user_project::Error::NotFound))
         ));
     }
+
+    #[tokio::test]
+    async fn test_api_key_not_found() {
+        let mut user = test::model::new_general_user();
+        let project = test::model::new_general_project(user.id().clone());
+        user.assign_project_owner(&project).unwrap();
+        let (key, token) = test::model::new_api_key(
+            user.id().clone(),
+            ApiKeyScope::Role(user::UserRole::General),
+        );
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .projects(vec![project])
+            .api_keys(vec![key])
+            .build()
+            .login_with_api_key(&token)
+            .await;
+
+        assert!(matches!(
+            get_user_project::run(&app).await,
+            Err(UseCaseError::UseCase(get_user_project::Error::NotFound))
+        ));
+    }
 }
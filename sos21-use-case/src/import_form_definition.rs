@@ -0,0 +1,162 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::interface;
+use crate::model::form::Form;
+use crate::model::form_definition::{FormDefinition, FormDefinitionKind};
+
+use anyhow::Context;
+use sos21_domain::context::{ConfigContext, FormRepository, Login};
+use sos21_domain::model::form;
+use sos21_domain::model::permissions::Permissions;
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    InvalidDefinition(Vec<interface::form_definition::FormDefinitionError>),
+    TooEarlyPeriodStart,
+    InsufficientPermissions,
+}
+
+impl Error {
+    fn from_new_form_error(err: form::NewFormError) -> Self {
+        match err.kind() {
+            form::NewFormErrorKind::TooEarlyPeriodStart => Error::TooEarlyPeriodStart,
+            form::NewFormErrorKind::InsufficientPermissions => Error::InsufficientPermissions,
+        }
+    }
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, definition: FormDefinition) -> UseCaseResult<Form, Error>
+where
+    C: FormRepository + ConfigContext + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    if login_user
+        .require_permissions(Permissions::CREATE_FORMS)
+        .is_err()
+    {
+        return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
+    }
+
+    let content = interface::form_definition::to_form_definition_content(
+        FormDefinitionKind::Form,
+        definition,
+    )
+    .map_err(|errors| UseCaseError::UseCase(Error::InvalidDefinition(errors)))?;
+    let period = content
+        .period
+        .context("Unexpected missing period in a valid form definition")?;
+    let condition = form::FormCondition {
+        query: content.query,
+        includes: form::FormConditionProjectSet::empty(),
+        excludes: form::FormConditionProjectSet::empty(),
+    };
+
+    let form = form::Form::new(
+        ctx,
+        login_user,
+        content.name,
+        content.description,
+        period,
+        content.items,
+        condition,
+        None,
+    )
+    .map_err(|err| UseCaseError::UseCase(Error::from_new_form_error(err)))?;
+    ctx.store_form(form.clone())
+        .await
+        .context("Failed to store a form")?;
+    use_case_ensure!(form.is_visible_to(login_user));
+    Ok(Form::from_entity(form))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{
+        form::FormItem,
+        form_definition::{FormDefinition, FormDefinitionPeriod},
+        project_query::ProjectQuery,
+    };
+    use crate::{get_form, import_form_definition, UseCaseError};
+    use sos21_domain::{model::date_time, test};
+
+    fn mock_definition() -> FormDefinition {
+        let period = test::model::mock_form_period_with_start(date_time::DateTime::from_utc(
+            chrono::Utc::now() + chrono::Duration::hours(1),
+        ));
+        FormDefinition {
+            name: test::model::mock_form_name().into_string(),
+            description: test::model::mock_form_description().into_string(),
+            period: Some(FormDefinitionPeriod {
+                starts_at: period.starts_at().utc(),
+                ends_at: period.ends_at().utc(),
+            }),
+            items: test::model::new_form_items()
+                .into_items()
+                .map(FormItem::from_entity)
+                .collect(),
+            query: ProjectQuery::from_entity(test::model::mock_project_query()),
+        }
+    }
+
+    // Checks that the (unprivileged) committee user cannot import forms.
+    #[tokio::test]
+    async fn test_committee() {
+        let user = test::model::new_committee_user();
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        assert!(matches!(
+            import_form_definition::run(&app, mock_definition()).await,
+            Err(UseCaseError::UseCase(
+                import_form_definition::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    // Checks that the privileged committee user can import forms.
+    #[tokio::test]
+    async fn test_operator() {
+        let user = test::model::new_operator_user();
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let definition = mock_definition();
+        let got = import_form_definition::run(&app, definition.clone())
+            .await
+            .unwrap();
+        assert_eq!(got.name, definition.name);
+        assert_eq!(got.items.len(), definition.items.len());
+
+        assert!(get_form::run(&app, got.id).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_operator_invalid() {
+        let user = test::model::new_operator_user();
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let mut definition = mock_definition();
+        definition.name = String::new();
+        definition.items = Vec::new();
+        assert!(matches!(
+            import_form_definition::run(&app, definition).await,
+            Err(UseCaseError::UseCase(
+                import_form_definition::Error::InvalidDefinition(errors)
+            )) if errors.len() == 2
+        ));
+    }
+}
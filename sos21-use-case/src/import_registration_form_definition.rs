@@ -0,0 +1,172 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::interface;
+use crate::model::form_definition::{FormDefinition, FormDefinitionKind};
+use crate::model::registration_form::RegistrationForm;
+
+use anyhow::Context;
+use sos21_domain::context::{ConfigContext, Login, RegistrationFormRepository};
+use sos21_domain::model::permissions::Permissions;
+use sos21_domain::model::{
+    access_control::AccessControlList, date_time::DateTime, registration_form, user,
+};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    InvalidDefinition(Vec<interface::form_definition::FormDefinitionError>),
+    InsufficientPermissions,
+    AlreadyStartedProjectCreationPeriod,
+}
+
+impl Error {
+    fn from_permissions_error(_err: user::RequirePermissionsError) -> Self {
+        Error::InsufficientPermissions
+    }
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(
+    ctx: &Login<C>,
+    definition: FormDefinition,
+) -> UseCaseResult<RegistrationForm, Error>
+where
+    C: RegistrationFormRepository + ConfigContext + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    login_user
+        .require_permissions(Permissions::CREATE_REGISTRATION_FORMS)
+        .map_err(|err| UseCaseError::UseCase(Error::from_permissions_error(err)))?;
+
+    let content = interface::form_definition::to_form_definition_content(
+        FormDefinitionKind::RegistrationForm,
+        definition,
+    )
+    .map_err(|errors| UseCaseError::UseCase(Error::InvalidDefinition(errors)))?;
+
+    // TODO: Move this constraint to domain
+    for category in content.query.possible_categories() {
+        if ctx
+            .project_creation_period_for(category)
+            .contains(DateTime::now())
+        {
+            return Err(UseCaseError::UseCase(
+                Error::AlreadyStartedProjectCreationPeriod,
+            ));
+        }
+    }
+
+    // form names and descriptions share the constraints with registration form ones
+    let name = registration_form::RegistrationFormName::from_string(content.name.into_string())
+        .context("Failed to convert a form name into a registration form name")?;
+    let description = registration_form::RegistrationFormDescription::from_string(
+        content.description.into_string(),
+    )
+    .context("Failed to convert a form description into a registration form description")?;
+
+    let registration_form = registration_form::RegistrationForm {
+        id: registration_form::RegistrationFormId::from_uuid(Uuid::new_v4()),
+        festival_id: ctx.active_festival_id(),
+        created_at: DateTime::now(),
+        author_id: login_user.id().clone(),
+        name,
+        description,
+        items: content.items,
+        query: content.query,
+        access_control: AccessControlList::empty(),
+    };
+    ctx.store_registration_form(registration_form.clone())
+        .await
+        .context("Failed to store a registration form")?;
+    use_case_ensure!(registration_form.is_visible_to(login_user));
+    Ok(RegistrationForm::from_entity(registration_form))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{
+        form::FormItem,
+        form_definition::FormDefinition,
+        project::ProjectCategory,
+        project_query::{ProjectQuery, ProjectQueryConjunction},
+    };
+    use crate::{get_registration_form, import_registration_form_definition, UseCaseError};
+    use sos21_domain::{model::project, test};
+
+    fn mock_definition() -> FormDefinition {
+        FormDefinition {
+            name: test::model::mock_registration_form_name().into_string(),
+            description: test::model::mock_registration_form_description().into_string(),
+            period: None,
+            items: test::model::new_form_items()
+                .into_items()
+                .map(FormItem::from_entity)
+                .collect(),
+            query: ProjectQuery(vec![ProjectQueryConjunction {
+                category: Some(ProjectCategory::General),
+                attributes: vec![],
+            }]),
+        }
+    }
+
+    // Checks that the (unprivileged) committee user cannot import registration forms.
+    #[tokio::test]
+    async fn test_committee() {
+        let user = test::model::new_committee_user();
+        let period = test::model::new_project_creation_period_with_hours_from_now(1);
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .project_creation_period_for(project::ProjectCategory::General, period)
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        assert!(matches!(
+            import_registration_form_definition::run(&app, mock_definition()).await,
+            Err(UseCaseError::UseCase(
+                import_registration_form_definition::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    // Checks that the privileged committee user can import registration forms.
+    #[tokio::test]
+    async fn test_operator() {
+        let user = test::model::new_operator_user();
+        let period = test::model::new_project_creation_period_with_hours_from_now(1);
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .project_creation_period_for(project::ProjectCategory::General, period)
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let definition = mock_definition();
+        let got = import_registration_form_definition::run(&app, definition.clone())
+            .await
+            .unwrap();
+        assert_eq!(got.name, definition.name);
+
+        assert!(get_registration_form::run(&app, got.id).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_operator_started() {
+        let user = test::model::new_operator_user();
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        assert!(matches!(
+            import_registration_form_definition::run(&app, mock_definition()).await,
+            Err(UseCaseError::UseCase(
+                import_registration_form_definition::Error::AlreadyStartedProjectCreationPeriod
+            ))
+        ));
+    }
+}
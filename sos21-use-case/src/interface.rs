@@ -1,3 +1,5 @@
 pub mod form;
 pub mod form_answer;
+pub mod form_definition;
+pub mod permissions;
 pub mod project_query;
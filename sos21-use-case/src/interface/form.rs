@@ -2,7 +2,10 @@ mod condition;
 pub use condition::{to_form_condition, FormConditionError};
 
 mod item;
-pub use item::{to_form_item, to_form_items, FormItemError, FormItemsError};
+pub use item::{
+    check_form_items, to_form_item, to_form_items, FormItemError, FormItemPath, FormItemsError,
+    LocatedFormItemsError,
+};
 
 mod check_answer_error;
 pub use check_answer_error::{
@@ -73,6 +73,21 @@ pub enum CheckAnswerItemError {
     NotAllowedDuplicatedGridRadioColumn {
         id: GridRadioColumnId,
     },
+    TooManyGroupRows,
+    TooFewGroupRows,
+    MismatchedGroupRowLength {
+        row: usize,
+    },
+    MismatchedGroupRowItemId {
+        row: usize,
+        expected: FormItemId,
+        got: FormItemId,
+    },
+    InvalidGroupRowItem {
+        row: usize,
+        item_id: FormItemId,
+        item_error: Box<CheckAnswerItemError>,
+    },
 }
 
 pub fn to_check_answer_item_error(
@@ -152,5 +167,28 @@ pub fn to_check_answer_item_error(
                 id: GridRadioColumnId::from_entity(id),
             }
         }
+        form::item::CheckAnswerItemErrorKind::TooManyGroupRows => {
+            CheckAnswerItemError::TooManyGroupRows
+        }
+        form::item::CheckAnswerItemErrorKind::TooFewGroupRows => {
+            CheckAnswerItemError::TooFewGroupRows
+        }
+        form::item::CheckAnswerItemErrorKind::MismatchedGroupRowLength { row } => {
+            CheckAnswerItemError::MismatchedGroupRowLength { row }
+        }
+        form::item::CheckAnswerItemErrorKind::MismatchedGroupRowItemId { row, expected, got } => {
+            CheckAnswerItemError::MismatchedGroupRowItemId {
+                row,
+                expected: FormItemId::from_entity(expected),
+                got: FormItemId::from_entity(got),
+            }
+        }
+        form::item::CheckAnswerItemErrorKind::InvalidGroupRowItem { row, id, kind } => {
+            CheckAnswerItemError::InvalidGroupRowItem {
+                row,
+                item_id: FormItemId::from_entity(id),
+                item_error: Box::new(to_check_answer_item_error(*kind)),
+            }
+        }
     }
 }
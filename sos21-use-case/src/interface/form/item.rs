@@ -1,3 +1,5 @@
+use std::collections::{HashMap, HashSet};
+
 use crate::model::form::{
     item::{
         Checkbox, CheckboxId, FormItemBody, FormItemCondition, GridRadioColumn, GridRadioColumnId,
@@ -44,6 +46,13 @@ pub enum FormItemError {
     TooManyFileTypes,
     NoFileTypes,
     DuplicatedFileType,
+    InvalidGroupMinRows,
+    InvalidGroupMaxRows,
+    InconsistentGroupRowLimits,
+    NoGroupItems,
+    TooManyGroupItems,
+    NestedGroup(FormItemId),
+    InvalidGroupItem(FormItemId, Box<FormItemError>),
     DuplicatedCheckboxId(CheckboxId),
     DuplicatedRadioId(RadioId),
     DuplicatedGridRadioRowId(GridRadioRowId),
@@ -135,6 +144,20 @@ impl FormItemError {
             item::file::types::FromTypesErrorKind::Duplicated => FormItemError::DuplicatedFileType,
         }
     }
+
+    fn from_group_items_error(err: item::group::FromItemsError) -> Self {
+        match err.kind() {
+            item::group::FromItemsErrorKind::Empty => FormItemError::NoGroupItems,
+            item::group::FromItemsErrorKind::TooLong => FormItemError::TooManyGroupItems,
+            item::group::FromItemsErrorKind::NestedGroup { id } => {
+                FormItemError::NestedGroup(FormItemId::from_entity(id))
+            }
+        }
+    }
+
+    fn from_group_content_error(_err: item::group::InconsistentRowLimitsError) -> Self {
+        FormItemError::InconsistentGroupRowLimits
+    }
 }
 
 pub fn to_form_item(item: FormItem) -> Result<form::FormItem, FormItemError> {
@@ -305,6 +328,37 @@ pub fn to_form_item(item: FormItem) -> Result<form::FormItem, FormItemError> {
             };
             item::FormItemBody::File(file_item)
         }
+        FormItemBody::Group {
+            items,
+            min_rows,
+            max_rows,
+        } => {
+            let items = items
+                .into_iter()
+                .map(|item| {
+                    let item_id = item.id;
+                    to_form_item(item)
+                        .map_err(|err| FormItemError::InvalidGroupItem(item_id, Box::new(err)))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let items = item::group::GroupFormItemItems::from_items(items)
+                .map_err(FormItemError::from_group_items_error)?;
+            let min_rows = min_rows
+                .map(item::group::GroupFormItemLimit::from_u64)
+                .transpose()
+                .map_err(|_| FormItemError::InvalidGroupMinRows)?;
+            let max_rows = max_rows
+                .map(item::group::GroupFormItemLimit::from_u64)
+                .transpose()
+                .map_err(|_| FormItemError::InvalidGroupMaxRows)?;
+            let group_item = item::GroupFormItem::from_content(item::group::GroupFormItemContent {
+                items,
+                min_rows,
+                max_rows,
+            })
+            .map_err(FormItemError::from_group_content_error)?;
+            item::FormItemBody::Group(group_item)
+        }
     };
 
     Ok(form::FormItem {
@@ -423,3 +477,145 @@ where
         .collect::<Result<Vec<_>, _>>()?;
     form::FormItems::from_items(items).map_err(FormItemsError::from_items_error)
 }
+
+/// The position of a form item, as the indices from the top-level items through the group items.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormItemPath(pub Vec<usize>);
+
+#[derive(Debug, Clone)]
+pub struct LocatedFormItemsError {
+    /// `None` for the errors on the whole items.
+    pub path: Option<FormItemPath>,
+    pub error: FormItemsError,
+}
+
+#[derive(Default)]
+struct FormItemPaths {
+    paths: HashMap<FormItemId, Vec<FormItemPath>>,
+    duplicated_count: HashMap<FormItemId, usize>,
+}
+
+impl FormItemPaths {
+    fn collect(&mut self, items: &[FormItem], prefix: &[usize]) {
+        for (index, item) in items.iter().enumerate() {
+            let mut path = prefix.to_vec();
+            path.push(index);
+            if let FormItemBody::Group { items, .. } = &item.body {
+                self.collect(items, &path);
+            }
+            self.paths
+                .entry(item.id)
+                .or_default()
+                .push(FormItemPath(path));
+        }
+    }
+
+    fn first(&self, id: FormItemId) -> Option<FormItemPath> {
+        self.paths.get(&id).and_then(|paths| paths.first()).cloned()
+    }
+
+    /// Returns the path of the next duplicated occurrence of the item.
+    fn next_duplicated(&mut self, id: FormItemId) -> Option<FormItemPath> {
+        let count = self.duplicated_count.entry(id).or_insert(0);
+        *count += 1;
+        self.paths
+            .get(&id)
+            .and_then(|paths| paths.get(*count))
+            .cloned()
+    }
+}
+
+fn collect_group_item_ids(item: &FormItem, ids: &mut HashSet<FormItemId>) {
+    ids.insert(item.id);
+    if let FormItemBody::Group { items, .. } = &item.body {
+        for item in items {
+            collect_group_item_ids(item, ids);
+        }
+    }
+}
+
+/// Same as [`to_form_items`], but reports all the errors found with the paths to the items.
+pub fn check_form_items<I>(items: I) -> Result<item::FormItems, Vec<LocatedFormItemsError>>
+where
+    I: IntoIterator<Item = FormItem>,
+{
+    let items: Vec<_> = items.into_iter().collect();
+    let mut paths = FormItemPaths::default();
+    paths.collect(&items, &[]);
+
+    let mut errors = Vec::new();
+    if items.is_empty() {
+        errors.push(LocatedFormItemsError {
+            path: None,
+            error: FormItemsError::NoItems,
+        });
+    }
+
+    let mut failed_ids = HashSet::new();
+    let mut entities = Vec::new();
+    for (index, item) in items.into_iter().enumerate() {
+        let item_id = item.id;
+        let mut group_item_ids = HashSet::new();
+        collect_group_item_ids(&item, &mut group_item_ids);
+        match to_form_item(item) {
+            Ok(entity) => entities.push(entity),
+            Err(FormItemError::InvalidGroupItem(group_item_id, err)) => {
+                failed_ids.extend(group_item_ids);
+                let path = paths
+                    .first(group_item_id)
+                    .unwrap_or_else(|| FormItemPath(vec![index]));
+                errors.push(LocatedFormItemsError {
+                    path: Some(path),
+                    error: FormItemsError::InvalidItem(group_item_id, *err),
+                });
+            }
+            Err(err) => {
+                failed_ids.extend(group_item_ids);
+                errors.push(LocatedFormItemsError {
+                    path: Some(FormItemPath(vec![index])),
+                    error: FormItemsError::from_item_error(item_id, err),
+                });
+            }
+        }
+    }
+
+    for err in form::FormItems::check_items(&entities) {
+        let error = match err.kind() {
+            // these are checked against the input items above
+            item::FromItemsErrorKind::Empty => continue,
+            item::FromItemsErrorKind::TooLong => FormItemsError::TooManyItems,
+            item::FromItemsErrorKind::DuplicatedFormItemId(id) => {
+                let id = FormItemId::from_entity(id);
+                errors.push(LocatedFormItemsError {
+                    path: paths.next_duplicated(id),
+                    error: FormItemsError::DuplicatedItemId(id),
+                });
+                continue;
+            }
+            item::FromItemsErrorKind::MismatchedConditionType { id, .. }
+            | item::FromItemsErrorKind::UnknownFormItemIdInConditions { id, .. }
+                if failed_ids.contains(&FormItemId::from_entity(id)) =>
+            {
+                // the item referred to is already reported to be invalid
+                continue;
+            }
+            _ => FormItemsError::from_items_error(err),
+        };
+        let path = match &error {
+            FormItemsError::InvalidItem(id, _) => paths.first(*id),
+            _ => None,
+        };
+        errors.push(LocatedFormItemsError { path, error });
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    form::FormItems::from_items(entities).map_err(|err| {
+        vec![LocatedFormItemsError {
+            path: None,
+            error: FormItemsError::from_items_error(err),
+        }]
+    })
+}
@@ -1,6 +1,7 @@
 mod item;
 pub use item::{
-    to_form_answer_items, to_registration_form_answer_items,
+    to_form_answer_draft_items, to_form_answer_items, to_preview_form_answer_items,
+    to_registration_form_answer_draft_items, to_registration_form_answer_items,
     to_registration_form_answer_items_with_project, FormAnswerItemError, FormAnswerItemsError,
     InputFormAnswerItem, InputFormAnswerItemBody, InputFormAnswerItemFile,
 };
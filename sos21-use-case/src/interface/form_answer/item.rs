@@ -7,12 +7,15 @@ use crate::model::form::item::{
 use crate::model::form_answer::item::GridRadioRowAnswer;
 
 use anyhow::Context;
+use futures::future::{BoxFuture, FutureExt};
 use sos21_domain::context::{FileRepository, FileSharingRepository, Login};
 use sos21_domain::model::{
     file, file_sharing, form,
-    form_answer::{self, item},
+    form_answer::{self, draft::DraftFileSharing, item},
     pending_project, project, registration_form, registration_form_answer,
 };
+use std::sync::Mutex;
+use uuid::Uuid;
 
 #[derive(Debug, Clone)]
 pub struct InputFormAnswerItem {
@@ -28,6 +31,7 @@ pub enum InputFormAnswerItemBody {
     Radio(Option<RadioId>),
     GridRadio(Vec<GridRadioRowAnswer>),
     File(Vec<InputFormAnswerItemFile>),
+    Group(Vec<Vec<InputFormAnswerItem>>),
 }
 
 #[derive(Debug, Clone)]
@@ -43,13 +47,24 @@ pub enum FormAnswerItemError {
     OutOfScopeFileSharing,
     NonSharableFile,
     TooManyFiles,
-    DuplicatedFileSharingId { id: FileSharingId },
+    DuplicatedFileSharingId {
+        id: FileSharingId,
+    },
     InvalidText,
     TooManyChecks,
     NoRowAnswers,
     TooManyRowAnswers,
-    DuplicatedCheckboxId { id: CheckboxId },
-    DuplicatedGridRadioRowId { id: GridRadioRowId },
+    DuplicatedCheckboxId {
+        id: CheckboxId,
+    },
+    DuplicatedGridRadioRowId {
+        id: GridRadioRowId,
+    },
+    TooManyGroupRows,
+    InvalidGroupRow {
+        row: usize,
+        error: Box<FormAnswerItemsError>,
+    },
 }
 
 impl FormAnswerItemError {
@@ -98,6 +113,12 @@ impl FormAnswerItemError {
             }
         }
     }
+
+    fn from_group_rows_error(err: item::group_rows::FromRowsError) -> Self {
+        match err.kind() {
+            item::group_rows::FromRowsErrorKind::TooLong => FormAnswerItemError::TooManyGroupRows,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -171,6 +192,77 @@ where
     .await
 }
 
+/// Converts the items of a draft answer, deferring the file sharings until the draft is submitted.
+///
+/// `drafted` is the list of the files attached to the previously saved draft, which the items may
+/// refer to with their sharing IDs.
+pub async fn to_form_answer_draft_items<C, I>(
+    ctx: &Login<C>,
+    project: &project::Project,
+    form: &form::Form,
+    drafted: &[DraftFileSharing],
+    items: I,
+) -> UseCaseResult<(form_answer::FormAnswerItems, Vec<DraftFileSharing>), FormAnswerItemsError>
+where
+    C: FileRepository + FileSharingRepository + Send + Sync,
+    I: IntoIterator<Item = InputFormAnswerItem>,
+{
+    let target = ShareTarget::FormAnswer { project, form };
+    to_draft_items_with_target(ctx, &target, drafted, items).await
+}
+
+/// Converts the items of a draft answer, deferring the file sharings until the draft is submitted.
+pub async fn to_registration_form_answer_draft_items<C, I>(
+    ctx: &Login<C>,
+    pending_project: &pending_project::PendingProject,
+    registration_form: &registration_form::RegistrationForm,
+    drafted: &[DraftFileSharing],
+    items: I,
+) -> UseCaseResult<(form_answer::FormAnswerItems, Vec<DraftFileSharing>), FormAnswerItemsError>
+where
+    C: FileRepository + FileSharingRepository + Send + Sync,
+    I: IntoIterator<Item = InputFormAnswerItem>,
+{
+    let target = ShareTarget::RegistrationFormAnswer {
+        pending_project,
+        registration_form,
+    };
+    to_draft_items_with_target(ctx, &target, drafted, items).await
+}
+
+async fn to_draft_items_with_target<C, I>(
+    ctx: &Login<C>,
+    target: &ShareTarget<'_>,
+    drafted: &[DraftFileSharing],
+    items: I,
+) -> UseCaseResult<(form_answer::FormAnswerItems, Vec<DraftFileSharing>), FormAnswerItemsError>
+where
+    C: FileRepository + FileSharingRepository + Send + Sync,
+    I: IntoIterator<Item = InputFormAnswerItem>,
+{
+    let sharings = Mutex::new(Vec::new());
+    let target = ShareTarget::Draft {
+        target,
+        drafted,
+        sharings: &sharings,
+    };
+    let items = to_form_answer_items_with_target(ctx, target, items).await?;
+    let sharings = sharings.into_inner().unwrap();
+    Ok((items, sharings))
+}
+
+/// Converts the answer items without sharing any files, for answers which are never stored.
+pub async fn to_preview_form_answer_items<C, I>(
+    ctx: &Login<C>,
+    items: I,
+) -> UseCaseResult<form_answer::FormAnswerItems, FormAnswerItemsError>
+where
+    C: FileRepository + FileSharingRepository + Send + Sync,
+    I: IntoIterator<Item = InputFormAnswerItem>,
+{
+    to_form_answer_items_with_target(ctx, ShareTarget::Preview, items).await
+}
+
 /// Internal data type that carries sharing target to each functions
 #[derive(Debug, Clone)]
 enum ShareTarget<'a> {
@@ -186,11 +278,18 @@ enum ShareTarget<'a> {
         project: &'a project::Project,
         registration_form: &'a registration_form::RegistrationForm,
     },
+    /// Collects the files to be shared into `sharings` instead of storing the sharings.
+    Draft {
+        target: &'a ShareTarget<'a>,
+        drafted: &'a [DraftFileSharing],
+        sharings: &'a Mutex<Vec<DraftFileSharing>>,
+    },
+    Preview,
 }
 
 impl<'a> ShareTarget<'a> {
-    fn to_scope(&self) -> file_sharing::FileSharingScope {
-        match self {
+    fn to_scope(&self) -> Option<file_sharing::FileSharingScope> {
+        let scope = match self {
             ShareTarget::FormAnswer { project, form } => {
                 file_sharing::FileSharingScope::FormAnswer(project.id(), form.id())
             }
@@ -210,7 +309,10 @@ impl<'a> ShareTarget<'a> {
                 registration_form_answer::RegistrationFormAnswerRespondent::Project(project.id()),
                 registration_form.id,
             ),
-        }
+            ShareTarget::Draft { target, .. } => return target.to_scope(),
+            ShareTarget::Preview => return None,
+        };
+        Some(scope)
     }
 
     fn is_contained_by(&self, scope: &file_sharing::FileSharingScope) -> bool {
@@ -229,6 +331,8 @@ impl<'a> ShareTarget<'a> {
                 project,
                 registration_form,
             } => scope.contains_project_registration_form_answer(project, registration_form),
+            ShareTarget::Draft { target, .. } => target.is_contained_by(scope),
+            ShareTarget::Preview => true,
         }
     }
 }
@@ -317,6 +421,10 @@ where
                 })?;
             item::FormAnswerItemBody::File(sharings)
         }
+        InputFormAnswerItemBody::Group(rows) => {
+            let rows = to_group_rows(ctx, target, rows).await?;
+            item::FormAnswerItemBody::Group(rows)
+        }
     };
 
     Ok(form_answer::FormAnswerItem {
@@ -325,6 +433,35 @@ where
     })
 }
 
+// boxed to break the recursion through `to_form_answer_items_with_target`
+fn to_group_rows<'a, C>(
+    ctx: &'a Login<C>,
+    target: ShareTarget<'a>,
+    rows: Vec<Vec<InputFormAnswerItem>>,
+) -> BoxFuture<'a, UseCaseResult<item::FormAnswerItemGroupRows, FormAnswerItemError>>
+where
+    C: FileRepository + FileSharingRepository + Send + Sync,
+{
+    async move {
+        let mut result = Vec::new();
+        for (row, items) in rows.into_iter().enumerate() {
+            let items = to_form_answer_items_with_target(ctx, target.clone(), items)
+                .await
+                .map_err(|err| {
+                    err.map_use_case(|err| FormAnswerItemError::InvalidGroupRow {
+                        row,
+                        error: Box::new(err),
+                    })
+                })?;
+            result.push(items);
+        }
+
+        item::FormAnswerItemGroupRows::from_rows(result)
+            .map_err(|err| UseCaseError::UseCase(FormAnswerItemError::from_group_rows_error(err)))
+    }
+    .boxed()
+}
+
 async fn to_file_sharing_answer<C>(
     ctx: &Login<C>,
     target: ShareTarget<'_>,
@@ -346,7 +483,18 @@ where
                 _ => return Err(UseCaseError::UseCase(FormAnswerItemError::FileNotFound)),
             };
 
-            let sharing = match file.share_by(login_user, target.to_scope()) {
+            let scope = match target.to_scope() {
+                Some(scope) => scope,
+                // the answer is never stored, so the file is not actually shared
+                None => {
+                    return Ok(item::FileSharingAnswer {
+                        sharing_id: file_sharing::FileSharingId::from_uuid(Uuid::new_v4()),
+                        type_: file.type_,
+                    })
+                }
+            };
+
+            let sharing = match file.share_by(login_user, scope) {
                 Ok(sharing) => sharing,
                 Err(err) => {
                     return Err(UseCaseError::UseCase(
@@ -355,9 +503,14 @@ where
                 }
             };
 
-            ctx.store_file_sharing(sharing.clone())
-                .await
-                .context("Failed to store a file sharing")?;
+            if let ShareTarget::Draft { sharings, .. } = &target {
+                let sharing = DraftFileSharing::from_sharing(&sharing);
+                sharings.lock().unwrap().push(sharing);
+            } else {
+                ctx.store_file_sharing(sharing.clone())
+                    .await
+                    .context("Failed to store a file sharing")?;
+            }
 
             use_case_ensure!(target.is_contained_by(sharing.scope()));
             Ok(item::FileSharingAnswer {
@@ -366,8 +519,31 @@ where
             })
         }
         InputFormAnswerItemFile::Sharing(sharing_id) => {
+            let sharing_id = sharing_id.into_entity();
+            if let ShareTarget::Draft {
+                drafted, sharings, ..
+            } = &target
+            {
+                // files attached to the previous draft, which are not shared yet
+                if let Some(sharing) = drafted.iter().find(|s| s.sharing_id == sharing_id) {
+                    let result = ctx
+                        .get_file(sharing.file_id)
+                        .await
+                        .context("Failed to get a file")?;
+                    let file = match result {
+                        Some(file) if file.is_visible_to(login_user) => file,
+                        _ => return Err(UseCaseError::UseCase(FormAnswerItemError::FileNotFound)),
+                    };
+                    sharings.lock().unwrap().push(sharing.clone());
+                    return Ok(item::FileSharingAnswer {
+                        sharing_id,
+                        type_: file.type_,
+                    });
+                }
+            }
+
             let result = ctx
-                .get_file_sharing(sharing_id.into_entity())
+                .get_file_sharing(sharing_id)
                 .await
                 .context("Failed to get a file sharing")?;
             let (sharing, file) = match result {
@@ -0,0 +1,77 @@
+use crate::interface;
+use crate::model::form_definition::{FormDefinition, FormDefinitionKind};
+
+use sos21_domain::model::{date_time::DateTime, form, project_query};
+
+#[derive(Debug, Clone)]
+pub enum FormDefinitionError {
+    InvalidName,
+    InvalidDescription,
+    MissingPeriod,
+    InvalidPeriod,
+    InvalidQuery(interface::project_query::ProjectQueryError),
+    InvalidItems(interface::form::LocatedFormItemsError),
+}
+
+#[derive(Debug, Clone)]
+pub struct FormDefinitionContent {
+    pub name: form::FormName,
+    pub description: form::FormDescription,
+    /// Always `Some` for [`FormDefinitionKind::Form`].
+    pub period: Option<form::FormPeriod>,
+    pub items: form::FormItems,
+    pub query: project_query::ProjectQuery,
+}
+
+/// Converts the definition, reporting all the errors found instead of the first one.
+pub fn to_form_definition_content(
+    kind: FormDefinitionKind,
+    definition: FormDefinition,
+) -> Result<FormDefinitionContent, Vec<FormDefinitionError>> {
+    let mut errors = Vec::new();
+
+    let name = form::FormName::from_string(definition.name)
+        .map_err(|_| errors.push(FormDefinitionError::InvalidName))
+        .ok();
+    let description = form::FormDescription::from_string(definition.description)
+        .map_err(|_| errors.push(FormDefinitionError::InvalidDescription))
+        .ok();
+    let period = match (kind, definition.period) {
+        (FormDefinitionKind::Form, None) => {
+            errors.push(FormDefinitionError::MissingPeriod);
+            None
+        }
+        (FormDefinitionKind::Form, Some(period)) => form::FormPeriod::from_datetime(
+            DateTime::from_utc(period.starts_at),
+            DateTime::from_utc(period.ends_at),
+        )
+        .map_err(|_| errors.push(FormDefinitionError::InvalidPeriod))
+        .ok(),
+        (FormDefinitionKind::RegistrationForm, _) => None,
+    };
+    let query = interface::project_query::to_project_query(definition.query)
+        .map_err(|err| errors.push(FormDefinitionError::InvalidQuery(err)))
+        .ok();
+    let items = interface::form::check_form_items(definition.items)
+        .map_err(|item_errors| {
+            errors.extend(
+                item_errors
+                    .into_iter()
+                    .map(FormDefinitionError::InvalidItems),
+            )
+        })
+        .ok();
+
+    match (name, description, query, items) {
+        (Some(name), Some(description), Some(query), Some(items)) if errors.is_empty() => {
+            Ok(FormDefinitionContent {
+                name,
+                description,
+                period,
+                items,
+                query,
+            })
+        }
+        _ => Err(errors),
+    }
+}
@@ -0,0 +1,19 @@
+use sos21_domain::model::permissions::Permissions;
+
+#[derive(Debug, Clone)]
+pub struct UnknownPermissionError {
+    pub name: String,
+}
+
+pub fn to_permissions<I>(names: I) -> Result<Permissions, UnknownPermissionError>
+where
+    I: IntoIterator<Item = String>,
+{
+    names.into_iter().try_fold(
+        Permissions::empty(),
+        |acc, name| match Permissions::from_name(&name) {
+            Some(permission) => Ok(acc | permission),
+            None => Err(UnknownPermissionError { name }),
+        },
+    )
+}
@@ -2,8 +2,9 @@ use crate::error::{UseCaseError, UseCaseResult};
 use crate::model::user_invitation::{UserInvitation, UserInvitationRole};
 
 use anyhow::Context;
-use sos21_domain::context::{Login, UserInvitationRepository, UserRepository};
-use sos21_domain::model::{user, user_invitation};
+use chrono::{DateTime, Utc};
+use sos21_domain::context::{ConfigContext, Login, UserInvitationRepository, UserRepository};
+use sos21_domain::model::{date_time, user, user_invitation};
 
 #[derive(Debug, Clone)]
 pub enum Error {
@@ -11,6 +12,7 @@ pub enum Error {
     NotUniversityEmailAddress,
     AlreadyInvitedEmailAddress,
     AlreadySignedUpEmailAddress,
+    PastExpiry,
     InsufficientPermissions,
 }
 
@@ -37,29 +39,74 @@ impl Error {
             }
         }
     }
+
+    fn from_set_expires_at_error(err: user_invitation::SetExpiresAtError) -> UseCaseError<Self> {
+        match err.kind() {
+            user_invitation::SetExpiresAtErrorKind::InsufficientPermissions => {
+                UseCaseError::UseCase(Error::InsufficientPermissions)
+            }
+            user_invitation::SetExpiresAtErrorKind::AlreadyAccepted => {
+                use_case_internal!("Unexpected SetExpiresAtErrorKind::AlreadyAccepted")
+            }
+            user_invitation::SetExpiresAtErrorKind::PastExpiry => {
+                UseCaseError::UseCase(Error::PastExpiry)
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct Input {
     pub email: String,
     pub role: UserInvitationRole,
+    /// Let the invited address sign up even if its domain is not in the allowed domains.
+    pub allows_unlisted_domain: bool,
+    pub expires_at: Option<DateTime<Utc>>,
 }
 
 // TODO: Actually send an invitation email
 #[tracing::instrument(skip(ctx))]
 pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<UserInvitation, Error>
 where
-    C: UserInvitationRepository + UserRepository + Send + Sync,
+    C: UserInvitationRepository + UserRepository + ConfigContext + Send + Sync,
 {
     let login_user = ctx.login_user();
 
-    let email = user::UserEmailAddress::from_string(input.email)
-        .map_err(|err| UseCaseError::UseCase(Error::from_email_error(err)))?;
+    let email = if input.allows_unlisted_domain {
+        user::UserEmailAddress::from_string(input.email)
+    } else {
+        user::UserEmailAddress::from_string_with_domains(input.email, ctx.allowed_email_domains())
+    }
+    .map_err(|err| UseCaseError::UseCase(Error::from_email_error(err)))?;
+
+    // expired invitations are replaced with the new one
+    if let Some(invitation) = ctx
+        .get_user_invitation_by_email(&email)
+        .await
+        .context("Failed to get user invitation")?
+    {
+        if invitation.is_expired() {
+            ctx.delete_user_invitation(invitation.id())
+                .await
+                .context("Failed to delete user invitation")?;
+        }
+    }
 
-    let invitation =
-        user_invitation::UserInvitation::new(ctx, login_user, email, input.role.into_entity())
-            .await
-            .map_err(|err| UseCaseError::from_domain(err, Error::from_new_invitation_error))?;
+    let mut invitation = user_invitation::UserInvitation::new(
+        ctx,
+        login_user,
+        email,
+        input.role.into_entity(),
+        input.allows_unlisted_domain,
+    )
+    .await
+    .map_err(|err| UseCaseError::from_domain(err, Error::from_new_invitation_error))?;
+    invitation
+        .set_expires_at(
+            login_user,
+            input.expires_at.map(date_time::DateTime::from_utc),
+        )
+        .map_err(Error::from_set_expires_at_error)?;
 
     ctx.store_user_invitation(invitation.clone())
         .await
@@ -71,7 +118,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::model::user_invitation::UserInvitationRole;
+    use crate::model::user_invitation::{UserInvitationRole, UserInvitationStatus};
     use crate::{invite_user, UseCaseError};
 
     use sos21_domain::test;
@@ -89,6 +136,8 @@ mod tests {
         let input = invite_user::Input {
             email: "example-invite-user@s.tsukuba.ac.jp".to_string(),
             role: UserInvitationRole::CommitteeOperator,
+            allows_unlisted_domain: false,
+            expires_at: None,
         };
         assert!(matches!(
             invite_user::run(&app, input).await,
@@ -116,6 +165,8 @@ mod tests {
         let input = invite_user::Input {
             email: invitation.email().clone().into_string(),
             role: UserInvitationRole::CommitteeOperator,
+            allows_unlisted_domain: false,
+            expires_at: None,
         };
         assert!(matches!(
             invite_user::run(&app, input).await,
@@ -138,6 +189,8 @@ mod tests {
         let input = invite_user::Input {
             email: user.email().clone().into_string(),
             role: UserInvitationRole::CommitteeOperator,
+            allows_unlisted_domain: false,
+            expires_at: None,
         };
         assert!(matches!(
             invite_user::run(&app, input).await,
@@ -161,6 +214,8 @@ mod tests {
         let input = invite_user::Input {
             email: email.clone(),
             role: UserInvitationRole::CommitteeOperator,
+            allows_unlisted_domain: false,
+            expires_at: None,
         };
         assert!(matches!(
             invite_user::run(&app, input).await,
@@ -168,4 +223,95 @@ mod tests {
             if invitation.email == email
         ));
     }
+
+    #[tokio::test]
+    async fn test_admin_unlisted_domain() {
+        let user = test::model::new_admin_user();
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user)
+            .await;
+
+        let email = "example-invite-user@example.com".to_string();
+        let input = invite_user::Input {
+            email: email.clone(),
+            role: UserInvitationRole::Committee,
+            allows_unlisted_domain: false,
+            expires_at: None,
+        };
+        assert!(matches!(
+            invite_user::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                invite_user::Error::NotUniversityEmailAddress
+            ))
+        ));
+
+        let input = invite_user::Input {
+            email: email.clone(),
+            role: UserInvitationRole::Committee,
+            allows_unlisted_domain: true,
+            expires_at: None,
+        };
+        assert!(matches!(
+            invite_user::run(&app, input).await,
+            Ok(invitation)
+            if invitation.email == email && invitation.allows_unlisted_domain
+        ));
+    }
+
+    // Checks that the expired invitation is replaced with the new one.
+    #[tokio::test]
+    async fn test_admin_expired() {
+        let user = test::model::new_admin_user();
+        let invitation = test::model::new_expired_user_invitation(
+            user.id().clone(),
+            "example-expired@s.tsukuba.ac.jp",
+        );
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .user_invitations(vec![invitation.clone()])
+            .build()
+            .login_as(user)
+            .await;
+
+        let expires_at = chrono::Utc::now() + chrono::Duration::days(7);
+        let input = invite_user::Input {
+            email: invitation.email().clone().into_string(),
+            role: UserInvitationRole::CommitteeOperator,
+            allows_unlisted_domain: false,
+            expires_at: Some(expires_at),
+        };
+        assert!(matches!(
+            invite_user::run(&app, input).await,
+            Ok(got)
+            if got.id.into_entity() != invitation.id()
+            && got.status == UserInvitationStatus::Pending
+            && got.expires_at == Some(expires_at)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_admin_past_expiry() {
+        let user = test::model::new_admin_user();
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user)
+            .await;
+
+        let input = invite_user::Input {
+            email: "example-invite-user@s.tsukuba.ac.jp".to_string(),
+            role: UserInvitationRole::Committee,
+            allows_unlisted_domain: false,
+            expires_at: Some(chrono::Utc::now() - chrono::Duration::days(1)),
+        };
+        assert!(matches!(
+            invite_user::run(&app, input).await,
+            Err(UseCaseError::UseCase(invite_user::Error::PastExpiry))
+        ));
+    }
 }
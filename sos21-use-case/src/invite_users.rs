@@ -0,0 +1,340 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::user::User;
+use crate::model::user_invitation::{UserInvitation, UserInvitationRole};
+
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use sos21_domain::context::{ConfigContext, Login, UserInvitationRepository, UserRepository};
+use sos21_domain::model::{date_time, permissions::Permissions, user, user_invitation};
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    PastExpiry,
+    InsufficientPermissions,
+}
+
+impl Error {
+    fn from_permissions_error(_err: user::RequirePermissionsError) -> Self {
+        Error::InsufficientPermissions
+    }
+
+    fn from_set_role_error(_err: user::NoUpdatePermissionError) -> Self {
+        Error::InsufficientPermissions
+    }
+
+    fn from_new_invitation_error(
+        err: user_invitation::NewUserInvitationError,
+    ) -> UseCaseError<Self> {
+        match err.kind() {
+            user_invitation::NewUserInvitationErrorKind::InsufficientPermissions => {
+                UseCaseError::UseCase(Error::InsufficientPermissions)
+            }
+            user_invitation::NewUserInvitationErrorKind::AlreadyInvitedEmailAddress => {
+                use_case_internal!(
+                    "Unexpected NewUserInvitationErrorKind::AlreadyInvitedEmailAddress"
+                )
+            }
+            user_invitation::NewUserInvitationErrorKind::AlreadySignedUpEmailAddress => {
+                use_case_internal!(
+                    "Unexpected NewUserInvitationErrorKind::AlreadySignedUpEmailAddress"
+                )
+            }
+        }
+    }
+
+    fn from_set_expires_at_error(err: user_invitation::SetExpiresAtError) -> UseCaseError<Self> {
+        match err.kind() {
+            user_invitation::SetExpiresAtErrorKind::InsufficientPermissions => {
+                UseCaseError::UseCase(Error::InsufficientPermissions)
+            }
+            user_invitation::SetExpiresAtErrorKind::AlreadyAccepted => {
+                use_case_internal!("Unexpected SetExpiresAtErrorKind::AlreadyAccepted")
+            }
+            user_invitation::SetExpiresAtErrorKind::PastExpiry => {
+                UseCaseError::UseCase(Error::PastExpiry)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct InputRow {
+    pub email: String,
+    pub role: UserInvitationRole,
+}
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub rows: Vec<InputRow>,
+    /// Let the invited addresses sign up even if their domains are not in the allowed domains.
+    pub allows_unlisted_domain: bool,
+    /// Update the roles of the users who have already signed up with the addresses,
+    /// instead of reporting them as already signed up.
+    pub updates_signed_up_users: bool,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone)]
+pub enum RowOutcome {
+    Invited(UserInvitation),
+    RoleUpdated(User),
+    AlreadyInvited,
+    AlreadySignedUp,
+    InvalidEmailAddress,
+    NotUniversityEmailAddress,
+}
+
+impl RowOutcome {
+    fn from_email_error(err: user::email::EmailAddressError) -> Self {
+        match err.kind() {
+            user::email::EmailAddressErrorKind::NotUniversityEmailAddress => {
+                RowOutcome::NotUniversityEmailAddress
+            }
+            user::email::EmailAddressErrorKind::InvalidEmailAddress => {
+                RowOutcome::InvalidEmailAddress
+            }
+        }
+    }
+}
+
+/// Invites the addresses in the rows and reports the outcome of each row in the same order.
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<Vec<RowOutcome>, Error>
+where
+    C: UserInvitationRepository + UserRepository + ConfigContext + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    login_user
+        .require_permissions(Permissions::CREATE_USER_INVITATIONS)
+        .map_err(|err| UseCaseError::UseCase(Error::from_permissions_error(err)))?;
+
+    let mut outcomes = Vec::with_capacity(input.rows.len());
+    for row in &input.rows {
+        let outcome = invite_row(ctx, row.clone(), &input).await?;
+        outcomes.push(outcome);
+    }
+
+    Ok(outcomes)
+}
+
+async fn invite_row<C>(
+    ctx: &Login<C>,
+    row: InputRow,
+    input: &Input,
+) -> UseCaseResult<RowOutcome, Error>
+where
+    C: UserInvitationRepository + UserRepository + ConfigContext + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    let email = if input.allows_unlisted_domain {
+        user::UserEmailAddress::from_string(row.email)
+    } else {
+        user::UserEmailAddress::from_string_with_domains(row.email, ctx.allowed_email_domains())
+    };
+    let email = match email {
+        Ok(email) => email,
+        Err(err) => return Ok(RowOutcome::from_email_error(err)),
+    };
+    let role = row.role.into_entity();
+
+    if let Some(mut user) = ctx
+        .get_user_by_email(&email)
+        .await
+        .context("Failed to get user")?
+    {
+        if !input.updates_signed_up_users {
+            return Ok(RowOutcome::AlreadySignedUp);
+        }
+
+        user.set_role(login_user, role.to_user_role())
+            .map_err(|err| UseCaseError::UseCase(Error::from_set_role_error(err)))?;
+        ctx.store_user(user.clone())
+            .await
+            .context("Failed to store user")?;
+
+        use_case_ensure!(user.is_visible_to(login_user));
+        return Ok(RowOutcome::RoleUpdated(User::from_entity(user)));
+    }
+
+    if let Some(invitation) = ctx
+        .get_user_invitation_by_email(&email)
+        .await
+        .context("Failed to get user invitation")?
+    {
+        if !invitation.is_expired() {
+            return Ok(RowOutcome::AlreadyInvited);
+        }
+
+        // expired invitations are replaced with the new one
+        ctx.delete_user_invitation(invitation.id())
+            .await
+            .context("Failed to delete user invitation")?;
+    }
+
+    let mut invitation = user_invitation::UserInvitation::new(
+        ctx,
+        login_user,
+        email,
+        role,
+        input.allows_unlisted_domain,
+    )
+    .await
+    .map_err(|err| UseCaseError::from_domain(err, Error::from_new_invitation_error).flatten())?;
+    invitation
+        .set_expires_at(
+            login_user,
+            input.expires_at.map(date_time::DateTime::from_utc),
+        )
+        .map_err(Error::from_set_expires_at_error)?;
+
+    ctx.store_user_invitation(invitation.clone())
+        .await
+        .context("Failed to store user invitation")?;
+
+    use_case_ensure!(invitation.is_visible_to(login_user));
+    Ok(RowOutcome::Invited(UserInvitation::from_entity(invitation)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{user::UserRole, user_invitation::UserInvitationRole};
+    use crate::{get_user, invite_users, UseCaseError};
+
+    use sos21_domain::model::user;
+    use sos21_domain::test;
+
+    fn row(email: &str, role: UserInvitationRole) -> invite_users::InputRow {
+        invite_users::InputRow {
+            email: email.to_string(),
+            role,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_operator() {
+        let user = test::model::new_operator_user();
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user)
+            .await;
+
+        let input = invite_users::Input {
+            rows: vec![row(
+                "example-invite-users@s.tsukuba.ac.jp",
+                UserInvitationRole::Committee,
+            )],
+            allows_unlisted_domain: false,
+            updates_signed_up_users: false,
+            expires_at: None,
+        };
+        assert!(matches!(
+            invite_users::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                invite_users::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_admin() {
+        let user = test::model::new_admin_user();
+        let other = test::model::new_general_user();
+        let invitation = test::model::new_operator_user_invitation(
+            user.id().clone(),
+            "example-invited@s.tsukuba.ac.jp",
+        );
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), other.clone()])
+            .user_invitations(vec![invitation.clone()])
+            .build()
+            .login_as(user)
+            .await;
+
+        let email = "example-invite-users@s.tsukuba.ac.jp";
+        let input = invite_users::Input {
+            rows: vec![
+                row(email, UserInvitationRole::Committee),
+                row("invalid", UserInvitationRole::Committee),
+                row(
+                    "example-invite-users@example.com",
+                    UserInvitationRole::Committee,
+                ),
+                row(
+                    &invitation.email().clone().into_string(),
+                    UserInvitationRole::Committee,
+                ),
+                row(
+                    &other.email().clone().into_string(),
+                    UserInvitationRole::Committee,
+                ),
+                row(email, UserInvitationRole::Committee),
+            ],
+            allows_unlisted_domain: false,
+            updates_signed_up_users: false,
+            expires_at: None,
+        };
+        let got = invite_users::run(&app, input).await.unwrap();
+        assert_eq!(got.len(), 6);
+        assert!(matches!(
+            &got[0],
+            invite_users::RowOutcome::Invited(invitation)
+            if invitation.email == email
+        ));
+        assert!(matches!(
+            got[1],
+            invite_users::RowOutcome::InvalidEmailAddress
+        ));
+        assert!(matches!(
+            got[2],
+            invite_users::RowOutcome::NotUniversityEmailAddress
+        ));
+        assert!(matches!(got[3], invite_users::RowOutcome::AlreadyInvited));
+        assert!(matches!(got[4], invite_users::RowOutcome::AlreadySignedUp));
+        assert!(matches!(got[5], invite_users::RowOutcome::AlreadyInvited));
+    }
+
+    #[tokio::test]
+    async fn test_admin_update_signed_up_users() {
+        let user = test::model::new_admin_user();
+        let other = {
+            let mut content = test::model::new_general_user().into_content();
+            content.email =
+                user::UserEmailAddress::from_string("example-signed-up@s.tsukuba.ac.jp").unwrap();
+            user::User::from_content(content)
+        };
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), other.clone()])
+            .build()
+            .login_as(user)
+            .await;
+
+        let input = invite_users::Input {
+            rows: vec![row(
+                &other.email().clone().into_string(),
+                UserInvitationRole::CommitteeOperator,
+            )],
+            allows_unlisted_domain: false,
+            updates_signed_up_users: true,
+            expires_at: None,
+        };
+        let got = invite_users::run(&app, input).await.unwrap();
+        assert!(matches!(
+            &got[0],
+            invite_users::RowOutcome::RoleUpdated(got_user)
+            if got_user.role == UserRole::CommitteeOperator
+        ));
+
+        let other_id = crate::model::user::UserId::from_entity(other.id().clone());
+        assert!(matches!(
+            get_user::run(&app, other_id).await,
+            Ok(got_user)
+            if got_user.role == UserRole::CommitteeOperator
+        ));
+    }
+}
@@ -25,34 +25,51 @@ macro_rules! use_case_internal {
 pub mod answer_form;
 pub mod answer_registration_form;
 pub mod assign_user_role_to_email;
+pub mod create_api_key;
+pub mod create_custom_role;
+pub mod create_festival;
 pub mod create_file;
 pub mod create_form;
+pub mod create_form_from_template;
+pub mod create_form_template;
 pub mod create_project;
 pub mod create_registration_form;
+pub mod create_registration_form_from_template;
+pub mod delete_custom_role;
+pub mod delete_expired_user_invitations;
 pub mod delete_user_invitation;
 pub mod distribute_files;
+pub mod duplicate_form;
+pub mod duplicate_registration_form;
 pub mod export_form_answers;
+pub mod export_pending_projects;
 pub mod export_projects;
 pub mod export_registration_form_answers;
 pub mod export_users;
 pub mod get_distributed_file;
+pub mod get_festival;
 pub mod get_file;
 pub mod get_file_distribution;
 pub mod get_file_object;
 pub mod get_file_sharing;
 pub mod get_form;
+pub mod get_form_access_control;
 pub mod get_form_answer;
 pub mod get_form_answer_shared_file;
 pub mod get_form_answer_shared_file_object;
+pub mod get_form_revision_diff;
+pub mod get_form_template;
 pub mod get_login_user;
 pub mod get_pending_project;
 pub mod get_pending_project_registration_form;
 pub mod get_pending_project_registration_form_answer;
+pub mod get_pending_project_registration_form_answer_draft;
 pub mod get_project;
 pub mod get_project_by_code;
 pub mod get_project_creation_availability;
 pub mod get_project_form;
 pub mod get_project_form_answer;
+pub mod get_project_form_answer_draft;
 pub mod get_project_form_answer_shared_file;
 pub mod get_project_form_answer_shared_file_object;
 pub mod get_project_registration_form;
@@ -64,9 +81,11 @@ pub mod get_project_shared_file_object;
 pub mod get_publicly_shared_file;
 pub mod get_publicly_shared_file_object;
 pub mod get_registration_form;
+pub mod get_registration_form_access_control;
 pub mod get_registration_form_answer;
 pub mod get_registration_form_answer_shared_file;
 pub mod get_registration_form_answer_shared_file_object;
+pub mod get_settings;
 pub mod get_shared_file;
 pub mod get_shared_file_object;
 pub mod get_user;
@@ -74,34 +93,66 @@ pub mod get_user_file_usage;
 pub mod get_user_invitation;
 pub mod get_user_pending_project;
 pub mod get_user_project;
+pub mod import_form_definition;
+pub mod import_registration_form_definition;
 pub mod invite_user;
+pub mod invite_users;
 pub mod list_all_file_distributions;
 pub mod list_all_forms;
+pub mod list_all_pending_projects;
 pub mod list_all_projects;
 pub mod list_all_registration_forms;
 pub mod list_all_user_invitations;
+pub mod list_api_keys;
+pub mod list_custom_roles;
 pub mod list_distributed_files;
+pub mod list_festivals;
 pub mod list_form_answers;
+pub mod list_form_revisions;
+pub mod list_form_templates;
 pub mod list_pending_project_registration_forms;
+pub mod list_pending_projects_with_exceptional_deadline;
 pub mod list_project_forms;
 pub mod list_project_registration_forms;
 pub mod list_registration_form_answers;
+pub mod list_settings_history;
 pub mod list_user_file_sharings;
 pub mod list_user_files;
 pub mod list_users;
+pub mod migrate_form_answers;
 pub mod prepare_project;
+pub mod reassign_project_subowner;
+pub mod resend_user_invitation;
+pub mod review_form_answer;
+pub mod revoke_api_key;
 pub mod revoke_file_sharing;
+pub mod revoke_user_tokens;
+pub mod save_pending_project_registration_form_answer_draft;
+pub mod save_project_form_answer_draft;
+pub mod set_user_custom_role;
 pub mod share_file;
 pub mod signup;
+pub mod submit_pending_project_registration_form_answer_draft;
+pub mod submit_project_form_answer_draft;
+pub mod suspend_suspicious_user;
+pub mod suspend_user;
+pub mod unsuspend_user;
 pub mod update_any_pending_project;
 pub mod update_any_project;
 pub mod update_any_user;
+pub mod update_custom_role;
 pub mod update_form;
+pub mod update_form_access_control;
 pub mod update_pending_project;
+pub mod update_pending_project_deadline;
 pub mod update_pending_project_registration_form_answer;
 pub mod update_project;
 pub mod update_project_form_answer;
 pub mod update_project_registration_form_answer;
+pub mod update_registration_form_access_control;
+pub mod update_settings;
+pub mod validate_form_answer;
+pub mod validate_form_definition;
 
 mod error;
 pub use error::{UseCaseError, UseCaseResult};
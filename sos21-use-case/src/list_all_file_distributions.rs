@@ -1,8 +1,9 @@
 use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::festival::FestivalId;
 use crate::model::file_distribution::FileDistribution;
 
 use anyhow::Context;
-use sos21_domain::context::{FileDistributionRepository, Login};
+use sos21_domain::context::{ConfigContext, FileDistributionRepository, Login};
 use sos21_domain::model::{permissions::Permissions, user};
 
 #[derive(Debug, Clone)]
@@ -17,9 +18,12 @@ impl Error {
 }
 
 #[tracing::instrument(skip(ctx))]
-pub async fn run<C>(ctx: &Login<C>) -> UseCaseResult<Vec<FileDistribution>, Error>
+pub async fn run<C>(
+    ctx: &Login<C>,
+    festival_id: Option<FestivalId>,
+) -> UseCaseResult<Vec<FileDistribution>, Error>
 where
-    C: FileDistributionRepository + Send + Sync,
+    C: FileDistributionRepository + ConfigContext + Send + Sync,
 {
     let login_user = ctx.login_user();
 
@@ -27,6 +31,9 @@ where
         .require_permissions(Permissions::READ_ALL_FILE_DISTRIBUTIONS)
         .map_err(|err| UseCaseError::UseCase(Error::from_permissions_error(err)))?;
 
+    let festival_id = festival_id
+        .map(FestivalId::into_entity)
+        .unwrap_or_else(|| ctx.active_festival_id());
     let distributions = ctx
         .list_file_distributions()
         .await
@@ -35,6 +42,9 @@ where
     let mut result = Vec::new();
     for distribution in distributions {
         use_case_ensure!(distribution.is_visible_to(login_user));
+        if distribution.festival_id != festival_id {
+            continue;
+        }
         result.push(FileDistribution::from_entity(distribution));
     }
 
@@ -60,7 +70,7 @@ mod tests {
             .await;
 
         assert!(matches!(
-            list_all_file_distributions::run(&app).await,
+            list_all_file_distributions::run(&app, None).await,
             Err(UseCaseError::UseCase(
                 list_all_file_distributions::Error::InsufficientPermissions
             ))
@@ -95,7 +105,7 @@ mod tests {
             .await;
 
         assert!(matches!(
-            list_all_file_distributions::run(&app).await,
+            list_all_file_distributions::run(&app, None).await,
             Ok(distributions)
             if distributions.len() == 1 && distributions[0].id == FileDistributionId::from_entity(distribution.id)
         ));
@@ -1,8 +1,9 @@
 use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::festival::FestivalId;
 use crate::model::form::Form;
 
 use anyhow::Context;
-use sos21_domain::context::{FormRepository, Login};
+use sos21_domain::context::{ConfigContext, FormRepository, Login};
 use sos21_domain::model::permissions::Permissions;
 
 #[derive(Debug, Clone)]
@@ -11,9 +12,12 @@ pub enum Error {
 }
 
 #[tracing::instrument(skip(ctx))]
-pub async fn run<C>(ctx: &Login<C>) -> UseCaseResult<Vec<Form>, Error>
+pub async fn run<C>(
+    ctx: &Login<C>,
+    festival_id: Option<FestivalId>,
+) -> UseCaseResult<Vec<Form>, Error>
 where
-    C: FormRepository + Send + Sync,
+    C: FormRepository + ConfigContext + Send + Sync,
 {
     let login_user = ctx.login_user();
 
@@ -24,15 +28,23 @@ where
         return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
     }
 
+    let festival_id = festival_id
+        .map(FestivalId::into_entity)
+        .unwrap_or_else(|| ctx.active_festival_id());
     let forms = ctx.list_forms().await.context("Failed to list forms")?;
     use_case_ensure!(forms.iter().all(|form| form.is_visible_to(login_user)));
-    Ok(forms.into_iter().map(Form::from_entity).collect())
+    Ok(forms
+        .into_iter()
+        .filter(|form| form.festival_id() == festival_id)
+        .map(Form::from_entity)
+        .collect())
 }
 
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
 
+    use crate::model::festival::FestivalId;
     use crate::model::form::FormId;
     use crate::{list_all_forms, UseCaseError};
     use sos21_domain::context::Login;
@@ -66,7 +78,7 @@ mod tests {
         let (app, _) = prepare_app(user).await;
 
         assert!(matches!(
-            list_all_forms::run(&app).await,
+            list_all_forms::run(&app, None).await,
             Err(UseCaseError::UseCase(
                 list_all_forms::Error::InsufficientPermissions
             ))
@@ -79,7 +91,7 @@ mod tests {
         let user = test::model::new_committee_user();
         let (app, expected) = prepare_app(user).await;
 
-        let result = list_all_forms::run(&app).await;
+        let result = list_all_forms::run(&app, None).await;
         assert!(result.is_ok());
 
         let got: HashSet<_> = result.unwrap().into_iter().map(|form| form.id).collect();
@@ -92,10 +104,37 @@ mod tests {
         let user = test::model::new_operator_user();
         let (app, expected) = prepare_app(user).await;
 
-        let result = list_all_forms::run(&app).await;
+        let result = list_all_forms::run(&app, None).await;
         assert!(result.is_ok());
 
         let got: HashSet<_> = result.unwrap().into_iter().map(|form| form.id).collect();
         assert_eq!(got, expected);
     }
+
+    // Checks that the forms in the past festivals are listed only when specified.
+    #[tokio::test]
+    async fn test_committee_past_festival() {
+        let user = test::model::new_committee_user();
+        let form = test::model::new_form(user.id().clone());
+        let festival = test::model::new_festival();
+
+        let app = test::build_mock_app()
+            .festivals(vec![festival.clone()])
+            .active_festival(festival.id())
+            .users(vec![user.clone()])
+            .forms(vec![form.clone()])
+            .build()
+            .login_as(user)
+            .await;
+
+        let got = list_all_forms::run(&app, None).await.unwrap();
+        assert!(got.is_empty());
+
+        let festival_id = FestivalId::from_entity(*test::model::KNOWN_MOCK_FESTIVAL_ID);
+        let got = list_all_forms::run(&app, Some(festival_id)).await.unwrap();
+        assert_eq!(
+            got.into_iter().map(|form| form.id).collect::<Vec<_>>(),
+            vec![FormId::from_entity(form.id())]
+        );
+    }
 }
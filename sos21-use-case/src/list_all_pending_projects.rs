@@ -0,0 +1,145 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::festival::FestivalId;
+use crate::model::pending_project::{PendingProject, PendingProjectSummary};
+use crate::model::user::{UserKanaName, UserName};
+
+use anyhow::Context;
+use sos21_domain::context::pending_project_repository::{
+    PendingProjectRepository, PendingProjectWithRegistrationProgress,
+};
+use sos21_domain::context::{ConfigContext, Login};
+use sos21_domain::model::permissions::Permissions;
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    InsufficientPermissions,
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(
+    ctx: &Login<C>,
+    festival_id: Option<FestivalId>,
+) -> UseCaseResult<Vec<PendingProjectSummary>, Error>
+where
+    Login<C>: PendingProjectRepository + ConfigContext,
+{
+    let login_user = ctx.login_user();
+
+    if login_user
+        .require_permissions(Permissions::READ_ALL_PROJECTS)
+        .is_err()
+    {
+        return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
+    }
+
+    let festival_id = festival_id
+        .map(FestivalId::into_entity)
+        .unwrap_or_else(|| ctx.active_festival_id());
+    let pending_projects = ctx
+        .list_pending_projects(festival_id)
+        .await
+        .context("Failed to list pending projects")?;
+
+    let mut result = Vec::new();
+    for PendingProjectWithRegistrationProgress {
+        pending_project,
+        owner,
+        registration_form_count,
+        answered_registration_form_count,
+    } in pending_projects
+    {
+        use_case_ensure!(
+            pending_project.is_visible_to(login_user)
+                && owner.name().is_visible_to(login_user)
+                && owner.kana_name().is_visible_to(login_user)
+        );
+
+        result.push(PendingProjectSummary {
+            pending_project: PendingProject::from_entity(pending_project),
+            owner_name: UserName::from_entity(owner.name().clone()),
+            owner_kana_name: UserKanaName::from_entity(owner.kana_name().clone()),
+            registration_form_count,
+            answered_registration_form_count,
+        });
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::pending_project::PendingProjectId;
+    use crate::{list_all_pending_projects, UseCaseError};
+    use sos21_domain::test;
+
+    #[tokio::test]
+    async fn test_general() {
+        let user = test::model::new_general_user();
+        let pending_project = test::model::new_general_pending_project(user.id().clone());
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .pending_projects(vec![pending_project])
+            .build()
+            .login_as(user)
+            .await;
+
+        assert!(matches!(
+            list_all_pending_projects::run(&app, None).await,
+            Err(UseCaseError::UseCase(
+                list_all_pending_projects::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_committee() {
+        let user = test::model::new_committee_user();
+        let operator = test::model::new_operator_user();
+        let owner1 = test::model::new_general_user();
+        let owner2 = test::model::new_general_user();
+        let pending_project1 = test::model::new_general_pending_project(owner1.id().clone());
+        let pending_project2 = test::model::new_general_pending_project(owner2.id().clone());
+        let registration_form1 = test::model::new_registration_form(operator.id().clone());
+        let registration_form2 = test::model::new_registration_form(operator.id().clone());
+        let answer = test::model::new_registration_form_answer_with_pending_project(
+            owner1.id().clone(),
+            pending_project1.id(),
+            &registration_form1,
+        );
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), operator, owner1.clone(), owner2])
+            .pending_projects(vec![pending_project1.clone(), pending_project2.clone()])
+            .registration_forms(vec![registration_form1, registration_form2])
+            .registration_form_answers(vec![answer])
+            .build()
+            .login_as(user)
+            .await;
+
+        let mut result = list_all_pending_projects::run(&app, None).await.unwrap();
+        result.sort_by_key(|summary| summary.pending_project.id);
+
+        let mut expected = vec![
+            (PendingProjectId::from_entity(pending_project1.id()), 2, 1),
+            (PendingProjectId::from_entity(pending_project2.id()), 2, 0),
+        ];
+        expected.sort();
+        let got: Vec<_> = result
+            .iter()
+            .map(|summary| {
+                (
+                    summary.pending_project.id,
+                    summary.registration_form_count,
+                    summary.answered_registration_form_count,
+                )
+            })
+            .collect();
+        assert_eq!(got, expected);
+
+        let summary = result
+            .iter()
+            .find(|summary| summary.pending_project.owner_id.0 == owner1.id().0)
+            .unwrap();
+        assert_eq!(summary.owner_name.first, owner1.name().first());
+    }
+}
@@ -1,9 +1,10 @@
 use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::festival::FestivalId;
 use crate::model::project::{Project, ProjectFromEntityInput};
 
 use anyhow::Context;
 use sos21_domain::context::project_repository::{self, ProjectRepository};
-use sos21_domain::context::Login;
+use sos21_domain::context::{ConfigContext, Login};
 use sos21_domain::model::permissions::Permissions;
 
 #[derive(Debug, Clone)]
@@ -12,9 +13,12 @@ pub enum Error {
 }
 
 #[tracing::instrument(skip(ctx))]
-pub async fn run<C>(ctx: &Login<C>) -> UseCaseResult<Vec<Project>, Error>
+pub async fn run<C>(
+    ctx: &Login<C>,
+    festival_id: Option<FestivalId>,
+) -> UseCaseResult<Vec<Project>, Error>
 where
-    Login<C>: ProjectRepository,
+    Login<C>: ProjectRepository + ConfigContext,
 {
     let login_user = ctx.login_user();
 
@@ -25,6 +29,9 @@ where
         return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
     }
 
+    let festival_id = festival_id
+        .map(FestivalId::into_entity)
+        .unwrap_or_else(|| ctx.active_festival_id());
     let projects = ctx
         .list_projects()
         .await
@@ -39,6 +46,10 @@ where
             subowner,
         } = project_with_owner;
 
+        if project.festival_id() != festival_id {
+            continue;
+        }
+
         use_case_ensure!(
             project.is_visible_to(login_user)
                 && owner.name().is_visible_to(login_user)
@@ -90,7 +101,7 @@ mod tests {
         let (app, _) = prepare_app(user).await;
 
         assert!(matches!(
-            list_all_projects::run(&app).await,
+            list_all_projects::run(&app, None).await,
             Err(UseCaseError::UseCase(
                 list_all_projects::Error::InsufficientPermissions
             ))
@@ -105,7 +116,7 @@ mod tests {
         let user = test::model::new_committee_user();
         let (app, projects) = prepare_app(user).await;
 
-        let result = list_all_projects::run(&app).await;
+        let result = list_all_projects::run(&app, None).await;
         assert!(result.is_ok());
 
         let got: HashSet<_> = result
@@ -128,7 +139,7 @@ mod tests {
         let user = test::model::new_operator_user();
         let (app, projects) = prepare_app(user).await;
 
-        let result = list_all_projects::run(&app).await;
+        let result = list_all_projects::run(&app, None).await;
         assert!(result.is_ok());
 
         let got: HashSet<_> = result
@@ -1,8 +1,9 @@
 use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::festival::FestivalId;
 use crate::model::registration_form::RegistrationForm;
 
 use anyhow::Context;
-use sos21_domain::context::{Login, RegistrationFormRepository};
+use sos21_domain::context::{ConfigContext, Login, RegistrationFormRepository};
 use sos21_domain::model::{permissions::Permissions, user};
 
 #[derive(Debug, Clone)]
@@ -17,9 +18,12 @@ impl Error {
 }
 
 #[tracing::instrument(skip(ctx))]
-pub async fn run<C>(ctx: &Login<C>) -> UseCaseResult<Vec<RegistrationForm>, Error>
+pub async fn run<C>(
+    ctx: &Login<C>,
+    festival_id: Option<FestivalId>,
+) -> UseCaseResult<Vec<RegistrationForm>, Error>
 where
-    C: RegistrationFormRepository + Send + Sync,
+    C: RegistrationFormRepository + ConfigContext + Send + Sync,
 {
     let login_user = ctx.login_user();
 
@@ -27,10 +31,14 @@ where
         .require_permissions(Permissions::READ_ALL_FORMS)
         .map_err(|err| UseCaseError::UseCase(Error::from_permissions_error(err)))?;
 
+    let festival_id = festival_id
+        .map(FestivalId::into_entity)
+        .unwrap_or_else(|| ctx.active_festival_id());
     ctx.list_registration_forms()
         .await
         .context("Failed to list registration forms")?
         .into_iter()
+        .filter(|registration_form| registration_form.festival_id == festival_id)
         .map(|registration_form| {
             use_case_ensure!(registration_form.is_visible_to(login_user));
             Ok(RegistrationForm::from_entity(registration_form))
@@ -75,7 +83,7 @@ mod tests {
         let (app, _) = prepare_app(user).await;
 
         assert!(matches!(
-            list_all_registration_forms::run(&app).await,
+            list_all_registration_forms::run(&app, None).await,
             Err(UseCaseError::UseCase(
                 list_all_registration_forms::Error::InsufficientPermissions
             ))
@@ -88,7 +96,7 @@ mod tests {
         let user = test::model::new_committee_user();
         let (app, expected) = prepare_app(user).await;
 
-        let result = list_all_registration_forms::run(&app).await;
+        let result = list_all_registration_forms::run(&app, None).await;
         assert!(result.is_ok());
 
         let got: HashSet<_> = result
@@ -105,7 +113,7 @@ mod tests {
         let user = test::model::new_operator_user();
         let (app, expected) = prepare_app(user).await;
 
-        let result = list_all_registration_forms::run(&app).await;
+        let result = list_all_registration_forms::run(&app, None).await;
         assert!(result.is_ok());
 
         let got: HashSet<_> = result
@@ -1,9 +1,9 @@
 use crate::error::{UseCaseError, UseCaseResult};
-use crate::model::user_invitation::UserInvitation;
+use crate::model::user_invitation::{UserInvitation, UserInvitationStatus};
 
 use anyhow::Context;
 use sos21_domain::context::{Login, UserInvitationRepository};
-use sos21_domain::model::{permissions::Permissions, user};
+use sos21_domain::model::{date_time::DateTime, permissions::Permissions, user};
 
 #[derive(Debug, Clone)]
 pub enum Error {
@@ -17,7 +17,10 @@ impl Error {
 }
 
 #[tracing::instrument(skip(ctx))]
-pub async fn run<C>(ctx: &Login<C>) -> UseCaseResult<Vec<UserInvitation>, Error>
+pub async fn run<C>(
+    ctx: &Login<C>,
+    status: Option<UserInvitationStatus>,
+) -> UseCaseResult<Vec<UserInvitation>, Error>
 where
     C: UserInvitationRepository + Send + Sync,
 {
@@ -27,10 +30,16 @@ where
         .require_permissions(Permissions::READ_ALL_USER_INVITATIONS)
         .map_err(|err| UseCaseError::UseCase(Error::from_permissions_error(err)))?;
 
+    let status = status.map(UserInvitationStatus::into_entity);
+    let now = DateTime::now();
     ctx.list_user_invitations()
         .await
         .context("Failed to list user invitations")?
         .into_iter()
+        .filter(|invitation| match status {
+            Some(status) => invitation.status_at(now) == status,
+            None => true,
+        })
         .map(|invitation| {
             use_case_ensure!(invitation.is_visible_to(login_user));
             Ok(UserInvitation::from_entity(invitation))
@@ -40,7 +49,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::model::user_invitation::UserInvitationId;
+    use crate::model::user_invitation::{UserInvitationId, UserInvitationStatus};
     use crate::{list_all_user_invitations, UseCaseError};
 
     use sos21_domain::test;
@@ -56,7 +65,7 @@ mod tests {
             .await;
 
         assert!(matches!(
-            list_all_user_invitations::run(&app).await,
+            list_all_user_invitations::run(&app, None).await,
             Err(UseCaseError::UseCase(
                 list_all_user_invitations::Error::InsufficientPermissions
             ))
@@ -84,7 +93,7 @@ mod tests {
             .login_as(admin)
             .await;
 
-        let invitations = list_all_user_invitations::run(&app).await.unwrap();
+        let invitations = list_all_user_invitations::run(&app, None).await.unwrap();
         let got: HashSet<_> = invitations
             .into_iter()
             .map(|invitation| invitation.id)
@@ -95,4 +104,37 @@ mod tests {
             .collect();
         assert_eq!(got, expected);
     }
+
+    #[tokio::test]
+    async fn test_admin_list_status() {
+        let admin = test::model::new_admin_user();
+        let pending = test::model::new_operator_user_invitation(
+            admin.id().clone(),
+            "example1@s.tsukuba.ac.jp",
+        );
+        let expired = test::model::new_expired_user_invitation(
+            admin.id().clone(),
+            "example2@s.tsukuba.ac.jp",
+        );
+
+        let app = test::build_mock_app()
+            .users(vec![admin.clone()])
+            .user_invitations(vec![pending.clone(), expired.clone()])
+            .build()
+            .login_as(admin)
+            .await;
+
+        let got = list_all_user_invitations::run(&app, Some(UserInvitationStatus::Expired))
+            .await
+            .unwrap();
+        assert_eq!(got.len(), 1);
+        assert_eq!(got[0].id, UserInvitationId::from_entity(expired.id()));
+        assert_eq!(got[0].status, UserInvitationStatus::Expired);
+
+        let got = list_all_user_invitations::run(&app, Some(UserInvitationStatus::Pending))
+            .await
+            .unwrap();
+        assert_eq!(got.len(), 1);
+        assert_eq!(got[0].id, UserInvitationId::from_entity(pending.id()));
+    }
 }
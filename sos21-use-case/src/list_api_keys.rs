@@ -0,0 +1,86 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::api_key::ApiKey;
+
+use anyhow::Context;
+use sos21_domain::context::{ApiKeyRepository, Login};
+use sos21_domain::model::permissions::Permissions;
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    InsufficientPermissions,
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>) -> UseCaseResult<Vec<ApiKey>, Error>
+where
+    C: ApiKeyRepository + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    if login_user
+        .require_permissions(Permissions::READ_API_KEYS)
+        .is_err()
+    {
+        return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
+    }
+
+    let keys = ctx
+        .list_api_keys()
+        .await
+        .context("Failed to list API keys")?;
+    use_case_ensure!(keys.iter().all(|key| key.is_visible_to(login_user)));
+
+    Ok(keys.into_iter().map(ApiKey::from_entity).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::api_key::ApiKeyId;
+    use crate::{list_api_keys, UseCaseError};
+    use sos21_domain::model::{api_key::ApiKeyScope, user::UserRole};
+    use sos21_domain::test;
+
+    #[tokio::test]
+    async fn test_operator() {
+        let admin = test::model::new_admin_user();
+        let operator = test::model::new_operator_user();
+        let (key, _) =
+            test::model::new_api_key(admin.id().clone(), ApiKeyScope::Role(UserRole::Committee));
+
+        let app = test::build_mock_app()
+            .users(vec![admin, operator.clone()])
+            .api_keys(vec![key])
+            .build()
+            .login_as(operator)
+            .await;
+
+        assert!(matches!(
+            list_api_keys::run(&app).await,
+            Err(UseCaseError::UseCase(
+                list_api_keys::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_admin() {
+        let admin = test::model::new_admin_user();
+        let (key, _) =
+            test::model::new_api_key(admin.id().clone(), ApiKeyScope::Role(UserRole::Committee));
+        let (expired, _) = test::model::new_expired_api_key(admin.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![admin.clone()])
+            .api_keys(vec![key.clone(), expired.clone()])
+            .build()
+            .login_as(admin)
+            .await;
+
+        let keys = list_api_keys::run(&app).await.unwrap();
+        assert_eq!(keys.len(), 2);
+        assert!(keys.iter().any(|k| k.id == ApiKeyId::from_entity(key.id())));
+        assert!(keys
+            .iter()
+            .any(|k| k.id == ApiKeyId::from_entity(expired.id())));
+    }
+}
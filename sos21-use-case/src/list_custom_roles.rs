@@ -0,0 +1,112 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::custom_role::{BuiltinRole, CustomRole};
+
+use anyhow::Context;
+use sos21_domain::context::{CustomRoleRepository, Login};
+use sos21_domain::model::{permissions::Permissions, user::UserRole};
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    InsufficientPermissions,
+}
+
+#[derive(Debug, Clone)]
+pub struct Output {
+    /// The built-in roles, which are always available as the defaults.
+    pub builtin_roles: Vec<BuiltinRole>,
+    pub custom_roles: Vec<CustomRole>,
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>) -> UseCaseResult<Output, Error>
+where
+    C: CustomRoleRepository + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    if login_user
+        .require_permissions(Permissions::READ_CUSTOM_ROLES)
+        .is_err()
+    {
+        return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
+    }
+
+    let custom_roles = ctx
+        .list_custom_roles()
+        .await
+        .context("Failed to list custom roles")?;
+    use_case_ensure!(custom_roles
+        .iter()
+        .all(|role| role.is_visible_to(login_user)));
+
+    let builtin_roles = vec![
+        UserRole::Administrator,
+        UserRole::CommitteeOperator,
+        UserRole::Committee,
+        UserRole::General,
+    ];
+    Ok(Output {
+        builtin_roles: builtin_roles
+            .into_iter()
+            .map(BuiltinRole::from_entity)
+            .collect(),
+        custom_roles: custom_roles
+            .into_iter()
+            .map(CustomRole::from_entity)
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{custom_role::CustomRoleId, user::UserRole};
+    use crate::{list_custom_roles, UseCaseError};
+    use sos21_domain::model::permissions::Permissions;
+    use sos21_domain::test;
+
+    #[tokio::test]
+    async fn test_operator() {
+        let admin = test::model::new_admin_user();
+        let operator = test::model::new_operator_user();
+
+        let app = test::build_mock_app()
+            .users(vec![admin, operator.clone()])
+            .build()
+            .login_as(operator)
+            .await;
+
+        assert!(matches!(
+            list_custom_roles::run(&app).await,
+            Err(UseCaseError::UseCase(
+                list_custom_roles::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_admin() {
+        let admin = test::model::new_admin_user();
+        let role = test::model::new_custom_role(admin.id().clone(), Permissions::READ_ALL_USERS);
+
+        let app = test::build_mock_app()
+            .users(vec![admin.clone()])
+            .custom_roles(vec![role.clone()])
+            .build()
+            .login_as(admin)
+            .await;
+
+        let output = list_custom_roles::run(&app).await.unwrap();
+        assert_eq!(output.builtin_roles.len(), 4);
+        assert!(output
+            .builtin_roles
+            .iter()
+            .any(|builtin| builtin.role == UserRole::General
+                && builtin.permissions.contains(&"CREATE_FILES".to_owned())));
+        assert_eq!(output.custom_roles.len(), 1);
+        assert_eq!(
+            output.custom_roles[0].id,
+            CustomRoleId::from_entity(role.id())
+        );
+        assert_eq!(output.custom_roles[0].permissions, vec!["READ_ALL_USERS"]);
+    }
+}
@@ -0,0 +1,59 @@
+use std::convert::Infallible;
+
+use crate::error::UseCaseResult;
+use crate::model::festival::Festival;
+
+use anyhow::Context;
+use sos21_domain::context::{ConfigContext, FestivalRepository, Login};
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>) -> UseCaseResult<Vec<Festival>, Infallible>
+where
+    C: FestivalRepository + ConfigContext + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    let festivals = ctx
+        .list_festivals()
+        .await
+        .context("Failed to list festivals")?;
+    use_case_ensure!(festivals
+        .iter()
+        .all(|festival| festival.is_visible_to(login_user)));
+
+    Ok(festivals
+        .into_iter()
+        .map(|festival| {
+            let is_active = festival.is_active(ctx);
+            Festival::from_entity(festival, is_active)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::list_festivals;
+    use crate::model::festival::FestivalId;
+    use sos21_domain::test;
+
+    #[tokio::test]
+    async fn test_general() {
+        let user = test::model::new_general_user();
+        let festival = test::model::new_festival();
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .festivals(vec![festival.clone()])
+            .build()
+            .login_as(user)
+            .await;
+
+        let got = list_festivals::run(&app).await.unwrap();
+        assert_eq!(got.len(), 2);
+        assert!(got
+            .iter()
+            .any(|got| got.id == FestivalId::from_entity(festival.id()) && !got.is_active));
+        assert!(got.iter().any(|got| got.id
+            == FestivalId::from_entity(*test::model::KNOWN_MOCK_FESTIVAL_ID)
+            && got.is_active));
+    }
+}
@@ -3,7 +3,7 @@ use crate::model::{form::FormId, form_answer::FormAnswer};
 
 use anyhow::Context;
 use sos21_domain::context::{FormAnswerRepository, FormRepository, Login};
-use sos21_domain::model::permissions::Permissions;
+use sos21_domain::model::{access_control::AccessControlLevel, permissions::Permissions};
 
 #[derive(Debug, Clone)]
 pub enum Error {
@@ -18,28 +18,37 @@ where
 {
     let login_user = ctx.login_user();
 
-    if login_user
-        .require_permissions(Permissions::READ_ALL_FORM_ANSWERS)
-        .is_err()
-    {
+    let form = ctx
+        .get_form(form_id.into_entity())
+        .await
+        .context("Failed to get a form")?;
+
+    let is_granted = match &form {
+        Some(form) => form.access_control().allows_with_permission(
+            login_user,
+            AccessControlLevel::Read,
+            Permissions::READ_ALL_FORM_ANSWERS,
+        ),
+        None => login_user
+            .permissions()
+            .contains(Permissions::READ_ALL_FORM_ANSWERS),
+    };
+    if !is_granted {
         return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
     }
 
-    let form_id = form_id.into_entity();
+    let form = match form {
+        Some(form) if form.is_visible_to(login_user) => form,
+        _ => return Err(UseCaseError::UseCase(Error::FormNotFound)),
+    };
+
     let answers = ctx
-        .list_form_answers(form_id)
+        .list_form_answers(form.id())
         .await
         .context("Failed to list form answers")?;
-    if answers.is_empty() {
-        match ctx.get_form(form_id).await? {
-            Some(form) if form.is_visible_to(login_user) => {}
-            _ => return Err(UseCaseError::UseCase(Error::FormNotFound)),
-        }
-    }
-
     use_case_ensure!(answers
         .iter()
-        .all(|answer| answer.is_visible_to(login_user)));
+        .all(|answer| answer.is_visible_to_with_form(login_user, &form)));
     Ok(answers.into_iter().map(FormAnswer::from_entity).collect())
 }
 
@@ -141,4 +150,92 @@ mod tests {
             .collect();
         assert_eq!(got, expected);
     }
+
+    // Checks that the normal user granted on the form can list its answers.
+    #[tokio::test]
+    async fn test_general_access_control() {
+        let user = test::model::new_general_user();
+        let admin = test::model::new_admin_user();
+        let other = test::model::new_general_user();
+        let project = test::model::new_general_project(other.id().clone());
+        let mut form = test::model::new_form(admin.id().clone());
+        form.set_access_control(
+            &admin,
+            test::model::new_user_access_control(
+                user.id().clone(),
+                domain::access_control::AccessControlLevel::Read,
+            ),
+        )
+        .unwrap();
+        let answer = test::model::new_form_answer(other.id().clone(), &project, &form);
+        let other_form = test::model::new_form(admin.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), admin, other])
+            .forms(vec![form.clone(), other_form.clone()])
+            .projects(vec![project])
+            .answers(vec![answer.clone()])
+            .build()
+            .login_as(user)
+            .await;
+
+        let got = list_form_answers::run(&app, FormId::from_entity(form.id()))
+            .await
+            .unwrap();
+        assert_eq!(got.len(), 1);
+        assert_eq!(got[0].id, FormAnswerId::from_entity(answer.id()));
+
+        assert!(matches!(
+            list_form_answers::run(&app, FormId::from_entity(other_form.id())).await,
+            Err(UseCaseError::UseCase(
+                list_form_answers::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_committee_narrowed_by_access_control() {
+        let user = test::model::new_general_user();
+        let committee = test::model::new_committee_user();
+        let admin = test::model::new_admin_user();
+        let other = test::model::new_general_user();
+        let project = test::model::new_general_project(other.id().clone());
+        let mut form = test::model::new_form(admin.id().clone());
+        form.set_access_control(
+            &admin,
+            test::model::new_user_access_control(
+                user.id().clone(),
+                domain::access_control::AccessControlLevel::Read,
+            ),
+        )
+        .unwrap();
+        let answer = test::model::new_form_answer(other.id().clone(), &project, &form);
+        let other_form = test::model::new_form(admin.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![user, committee.clone(), admin.clone(), other])
+            .forms(vec![form.clone(), other_form.clone()])
+            .projects(vec![project])
+            .answers(vec![answer])
+            .build();
+
+        let committee_app = app.clone().login_as(committee).await;
+        assert!(matches!(
+            list_form_answers::run(&committee_app, FormId::from_entity(form.id())).await,
+            Err(UseCaseError::UseCase(
+                list_form_answers::Error::InsufficientPermissions
+            ))
+        ));
+        assert!(
+            list_form_answers::run(&committee_app, FormId::from_entity(other_form.id()))
+                .await
+                .is_ok()
+        );
+
+        let admin_app = app.login_as(admin).await;
+        let got = list_form_answers::run(&admin_app, FormId::from_entity(form.id()))
+            .await
+            .unwrap();
+        assert_eq!(got.len(), 1);
+    }
 }
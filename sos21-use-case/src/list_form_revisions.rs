@@ -0,0 +1,97 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::form::{FormId, FormRevision};
+
+use anyhow::Context;
+use sos21_domain::context::{FormRepository, Login};
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    NotFound,
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, form_id: FormId) -> UseCaseResult<Vec<FormRevision>, Error>
+where
+    C: FormRepository + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    let form_id = form_id.into_entity();
+    match ctx
+        .get_form(form_id)
+        .await
+        .context("Failed to get a form")?
+    {
+        Some(form) if form.is_visible_to(login_user) => {}
+        _ => return Err(UseCaseError::UseCase(Error::NotFound)),
+    }
+
+    let revisions = ctx
+        .list_form_revisions(form_id)
+        .await
+        .context("Failed to list form revisions")?;
+    Ok(revisions
+        .into_iter()
+        .map(FormRevision::from_entity)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::form::FormId;
+    use crate::{list_form_revisions, UseCaseError};
+    use sos21_domain::test;
+
+    #[tokio::test]
+    async fn test_general() {
+        let user = test::model::new_general_user();
+        let operator = test::model::new_operator_user();
+        let form = test::model::new_form(operator.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), operator.clone()])
+            .forms(vec![form.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let form_id = FormId::from_entity(form.id());
+        assert!(matches!(
+            list_form_revisions::run(&app, form_id).await,
+            Err(UseCaseError::UseCase(list_form_revisions::Error::NotFound))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_committee() {
+        let user = test::model::new_committee_user();
+        let operator = test::model::new_operator_user();
+        let form = test::model::new_form(operator.id().clone());
+        let mut new_form = form.clone();
+        new_form
+            .set_name(
+                &test::model::new_admin_user(),
+                test::model::mock_form_name(),
+            )
+            .unwrap();
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), operator.clone()])
+            .forms(vec![form.clone(), new_form.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let form_id = FormId::from_entity(form.id());
+        let got: Vec<_> = list_form_revisions::run(&app, form_id)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|revision| revision.version)
+            .collect();
+        assert_eq!(
+            got,
+            vec![form.version().to_u32(), new_form.version().to_u32()]
+        );
+    }
+}
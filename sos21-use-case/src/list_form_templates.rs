@@ -0,0 +1,94 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::form_template::FormTemplate;
+
+use anyhow::Context;
+use sos21_domain::context::{FormTemplateRepository, Login};
+use sos21_domain::model::permissions::Permissions;
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    InsufficientPermissions,
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>) -> UseCaseResult<Vec<FormTemplate>, Error>
+where
+    C: FormTemplateRepository + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    if login_user
+        .require_permissions(Permissions::READ_ALL_FORMS)
+        .is_err()
+    {
+        return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
+    }
+
+    let templates = ctx
+        .list_form_templates()
+        .await
+        .context("Failed to list form templates")?;
+    use_case_ensure!(templates
+        .iter()
+        .all(|template| template.is_visible_to(login_user)));
+    Ok(templates
+        .into_iter()
+        .map(FormTemplate::from_entity)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::form_template::FormTemplateId;
+    use crate::{list_form_templates, UseCaseError};
+    use sos21_domain::test;
+
+    // Checks that the normal user cannot list form templates.
+    #[tokio::test]
+    async fn test_general() {
+        let user = test::model::new_general_user();
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        assert!(matches!(
+            list_form_templates::run(&app).await,
+            Err(UseCaseError::UseCase(
+                list_form_templates::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    // Checks that the committee user can list form templates.
+    #[tokio::test]
+    async fn test_committee() {
+        let user = test::model::new_committee_user();
+        let operator = test::model::new_operator_user();
+        let template1 = test::model::new_form_template(operator.id().clone());
+        let template2 = test::model::new_form_template(operator.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), operator.clone()])
+            .form_templates(vec![template1.clone(), template2.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let mut got: Vec<_> = list_form_templates::run(&app)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|template| template.id)
+            .collect();
+        got.sort();
+        let mut expected = vec![
+            FormTemplateId::from_entity(template1.id()),
+            FormTemplateId::from_entity(template2.id()),
+        ];
+        expected.sort();
+        assert_eq!(got, expected);
+    }
+}
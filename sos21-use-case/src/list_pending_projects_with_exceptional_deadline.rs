@@ -0,0 +1,110 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::festival::FestivalId;
+use crate::model::pending_project::PendingProject;
+
+use anyhow::Context;
+use sos21_domain::context::pending_project_repository::{
+    PendingProjectRepository, PendingProjectWithOwner,
+};
+use sos21_domain::context::{ConfigContext, Login};
+use sos21_domain::model::permissions::Permissions;
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    InsufficientPermissions,
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(
+    ctx: &Login<C>,
+    festival_id: Option<FestivalId>,
+) -> UseCaseResult<Vec<PendingProject>, Error>
+where
+    Login<C>: PendingProjectRepository + ConfigContext,
+{
+    let login_user = ctx.login_user();
+
+    if login_user
+        .require_permissions(Permissions::READ_ALL_PROJECTS)
+        .is_err()
+    {
+        return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
+    }
+
+    let festival_id = festival_id
+        .map(FestivalId::into_entity)
+        .unwrap_or_else(|| ctx.active_festival_id());
+    let pending_projects = ctx
+        .list_pending_projects_with_exceptional_deadline(festival_id)
+        .await
+        .context("Failed to list pending projects")?;
+
+    let mut result = Vec::new();
+    for PendingProjectWithOwner {
+        pending_project, ..
+    } in pending_projects
+    {
+        use_case_ensure!(pending_project.is_visible_to(login_user));
+        result.push(PendingProject::from_entity(pending_project));
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::pending_project::PendingProjectId;
+    use crate::{list_pending_projects_with_exceptional_deadline, UseCaseError};
+    use sos21_domain::model::{date_time::DateTime, pending_project::PendingProject};
+    use sos21_domain::test;
+
+    #[tokio::test]
+    async fn test_general() {
+        let user = test::model::new_general_user();
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user)
+            .await;
+
+        assert!(matches!(
+            list_pending_projects_with_exceptional_deadline::run(&app, None).await,
+            Err(UseCaseError::UseCase(
+                list_pending_projects_with_exceptional_deadline::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_committee() {
+        let user = test::model::new_committee_user();
+        let owner1 = test::model::new_general_user();
+        let owner2 = test::model::new_general_user();
+        let pending_project1 = test::model::new_general_pending_project(owner1.id().clone());
+        let pending_project2 = {
+            let mut content =
+                test::model::new_general_pending_project(owner2.id().clone()).into_content();
+            content.exceptional_complete_deadline = Some(DateTime::now());
+            PendingProject::from_content(content, owner2.id().clone())
+        };
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), owner1, owner2])
+            .pending_projects(vec![pending_project1, pending_project2.clone()])
+            .build()
+            .login_as(user)
+            .await;
+
+        let result = list_pending_projects_with_exceptional_deadline::run(&app, None)
+            .await
+            .unwrap();
+        let got: Vec<_> = result
+            .into_iter()
+            .map(|pending_project| pending_project.id)
+            .collect();
+        assert_eq!(
+            got,
+            vec![PendingProjectId::from_entity(pending_project2.id())]
+        );
+    }
+}
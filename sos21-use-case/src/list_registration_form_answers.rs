@@ -5,7 +5,7 @@ use crate::model::{
 
 use anyhow::Context;
 use sos21_domain::context::{Login, RegistrationFormAnswerRepository, RegistrationFormRepository};
-use sos21_domain::model::{permissions::Permissions, user};
+use sos21_domain::model::{access_control::AccessControlLevel, permissions::Permissions};
 
 #[derive(Debug, Clone)]
 pub enum Error {
@@ -13,12 +13,6 @@ pub enum Error {
     InsufficientPermissions,
 }
 
-impl Error {
-    fn from_permissions_error(_err: user::RequirePermissionsError) -> Self {
-        Error::InsufficientPermissions
-    }
-}
-
 #[tracing::instrument(skip(ctx))]
 pub async fn run<C>(
     ctx: &Login<C>,
@@ -29,26 +23,41 @@ where
 {
     let login_user = ctx.login_user();
 
-    login_user
-        .require_permissions(Permissions::READ_ALL_REGISTRATION_FORM_ANSWERS)
-        .map_err(|err| UseCaseError::UseCase(Error::from_permissions_error(err)))?;
+    let registration_form = ctx
+        .get_registration_form(registration_form_id.into_entity())
+        .await
+        .context("Failed to get a registration form")?;
+
+    let is_granted = match &registration_form {
+        Some(registration_form) => registration_form.access_control.allows_with_permission(
+            login_user,
+            AccessControlLevel::Read,
+            Permissions::READ_ALL_REGISTRATION_FORM_ANSWERS,
+        ),
+        None => login_user
+            .permissions()
+            .contains(Permissions::READ_ALL_REGISTRATION_FORM_ANSWERS),
+    };
+    if !is_granted {
+        return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
+    }
+
+    let registration_form = match registration_form {
+        Some(registration_form) if registration_form.is_visible_to(login_user) => registration_form,
+        _ => return Err(UseCaseError::UseCase(Error::RegistrationFormNotFound)),
+    };
 
-    let registration_form_id = registration_form_id.into_entity();
     let answers = ctx
-        .list_registration_form_answers(registration_form_id)
+        .list_registration_form_answers(registration_form.id)
         .await
         .context("Failed to list registration form answers")?;
-    if answers.is_empty() {
-        match ctx.get_registration_form(registration_form_id).await? {
-            Some(registration_form) if registration_form.is_visible_to(login_user) => {}
-            _ => return Err(UseCaseError::UseCase(Error::RegistrationFormNotFound)),
-        }
-    }
 
     answers
         .into_iter()
         .map(|answer| {
-            use_case_ensure!(answer.is_visible_to(login_user));
+            use_case_ensure!(
+                answer.is_visible_to_with_registration_form(login_user, &registration_form)
+            );
             Ok(RegistrationFormAnswer::from_entity(answer))
         })
         .collect()
@@ -0,0 +1,96 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::settings::Settings;
+
+use anyhow::Context;
+use sos21_domain::context::{Login, SettingsRepository};
+use sos21_domain::model::{permissions::Permissions, user};
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    InsufficientPermissions,
+}
+
+impl Error {
+    fn from_permissions_error(_err: user::RequirePermissionsError) -> Self {
+        Error::InsufficientPermissions
+    }
+}
+
+/// List the stored settings from the newest to the oldest.
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>) -> UseCaseResult<Vec<Settings>, Error>
+where
+    C: SettingsRepository + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    login_user
+        .require_permissions(Permissions::READ_SETTINGS)
+        .map_err(|err| UseCaseError::UseCase(Error::from_permissions_error(err)))?;
+
+    ctx.list_settings_history()
+        .await
+        .context("Failed to list settings history")?
+        .into_iter()
+        .map(|settings| {
+            use_case_ensure!(settings.is_visible_to(login_user));
+            Ok(Settings::from_entity(settings))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::project::ProjectCategory;
+    use crate::model::settings::ProjectCreationPeriod;
+    use crate::{list_settings_history, update_settings, UseCaseError};
+
+    use sos21_domain::test;
+
+    #[tokio::test]
+    async fn test_committee() {
+        let committee = test::model::new_committee_user();
+
+        let app = test::build_mock_app()
+            .users(vec![committee.clone()])
+            .build()
+            .login_as(committee)
+            .await;
+
+        assert!(matches!(
+            list_settings_history::run(&app).await,
+            Err(UseCaseError::UseCase(
+                list_settings_history::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_admin_history() {
+        let admin = test::model::new_admin_user();
+
+        let app = test::build_mock_app()
+            .users(vec![admin.clone()])
+            .build()
+            .login_as(admin.clone())
+            .await;
+
+        for period in [ProjectCreationPeriod::Never, ProjectCreationPeriod::Always] {
+            let input = update_settings::Input {
+                administrator_email: None,
+                active_festival_id: None,
+                project_creation_periods: vec![(ProjectCategory::Food, period)],
+            };
+            update_settings::run(&app, input).await.unwrap();
+        }
+
+        let got = list_settings_history::run(&app).await.unwrap();
+        assert_eq!(got.len(), 2);
+        assert!(got[0]
+            .project_creation_periods
+            .contains(&(ProjectCategory::Food, ProjectCreationPeriod::Always)));
+        assert!(got[1]
+            .project_creation_periods
+            .contains(&(ProjectCategory::Food, ProjectCreationPeriod::Never)));
+    }
+}
@@ -13,6 +13,11 @@ where
 {
     let login_user = ctx.login_user();
 
+    // API keys are not given the accesses to the files of the author
+    if login_user.is_with_api_key() {
+        return Ok(Vec::new());
+    }
+
     let files = ctx
         .list_files_by_user(login_user.id().clone())
         .await
@@ -51,11 +56,10 @@ mod tests {
         assert!(result.is_ok());
 
         let got: HashSet<_> = result.unwrap().into_iter().map(|file| file.id).collect();
-        let expected: HashSet<_> =
-            (&[FileId::from_entity(file1.id), FileId::from_entity(file2.id)])
-                .iter()
-                .cloned()
-                .collect();
+        let expected: HashSet<_> = [FileId::from_entity(file1.id), FileId::from_entity(file2.id)]
+            .iter()
+            .cloned()
+            .collect();
         assert_eq!(got, expected);
     }
 }
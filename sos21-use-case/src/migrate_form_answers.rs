@@ -0,0 +1,192 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::form::{FormId, FormItemId};
+use crate::model::form_answer::FormAnswer;
+
+use anyhow::Context;
+use sos21_domain::context::{FormAnswerRepository, FormRepository, Login, ProjectRepository};
+use sos21_domain::model::{
+    access_control::AccessControlLevel, form_answer, permissions::Permissions,
+};
+
+#[derive(Debug, Clone)]
+pub struct FormAnswerMigration {
+    pub answer: FormAnswer,
+    /// Items whose answers must be re-submitted.
+    pub flagged_item_ids: Vec<FormItemId>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    FormNotFound,
+    InsufficientPermissions,
+}
+
+impl Error {
+    fn from_migrate_error(err: form_answer::MigrateError) -> Self {
+        match err.kind() {
+            form_answer::MigrateErrorKind::InsufficientPermissions => {
+                Error::InsufficientPermissions
+            }
+        }
+    }
+}
+
+/// Migrates the answers made for the older versions of the form to the current version.
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(
+    ctx: &Login<C>,
+    form_id: FormId,
+) -> UseCaseResult<Vec<FormAnswerMigration>, Error>
+where
+    C: FormRepository + FormAnswerRepository + ProjectRepository + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    if login_user
+        .require_permissions(Permissions::UPDATE_ALL_FORM_ANSWERS)
+        .is_err()
+    {
+        return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
+    }
+
+    let result = ctx
+        .get_form(form_id.into_entity())
+        .await
+        .context("Failed to get a form")?;
+    let form = match result {
+        Some(form) if form.is_visible_to(login_user) => form,
+        _ => return Err(UseCaseError::UseCase(Error::FormNotFound)),
+    };
+
+    if !form.access_control().allows_with_permission(
+        login_user,
+        AccessControlLevel::Update,
+        Permissions::UPDATE_ALL_FORM_ANSWERS,
+    ) {
+        return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
+    }
+
+    let answers = ctx
+        .list_form_answers(form.id())
+        .await
+        .context("Failed to list form answers")?;
+
+    let mut migrations = Vec::new();
+    for mut answer in answers {
+        if answer.form_version() == form.version() {
+            continue;
+        }
+
+        let result = ctx
+            .get_project(answer.project_id())
+            .await
+            .context("Failed to get a project")?;
+        let project = match result {
+            Some(result) => result.project,
+            None => {
+                return Err(use_case_internal!(
+                    "Form answer refers to a missing project"
+                ))
+            }
+        };
+
+        let flagged_item_ids = answer
+            .migrate(login_user, &form, &project)
+            .map_err(|err| UseCaseError::from_domain(err, Error::from_migrate_error))?;
+
+        ctx.store_form_answer(answer.clone())
+            .await
+            .context("Failed to store a form answer")?;
+
+        use_case_ensure!(answer.is_visible_to_with_form(login_user, &form));
+        migrations.push(FormAnswerMigration {
+            answer: FormAnswer::from_entity(answer),
+            flagged_item_ids: flagged_item_ids
+                .into_iter()
+                .map(FormItemId::from_entity)
+                .collect(),
+        });
+    }
+
+    Ok(migrations)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::form::{FormId, FormItemId};
+    use crate::{migrate_form_answers, UseCaseError};
+    use sos21_domain::model::form::FormItems;
+    use sos21_domain::test;
+
+    #[tokio::test]
+    async fn test_operator() {
+        let user = test::model::new_general_user();
+        let project = test::model::new_general_project(user.id().clone());
+        let operator = test::model::new_operator_user();
+        let form = test::model::new_form(operator.id().clone());
+        let answer = test::model::new_form_answer(user.id().clone(), &project, &form);
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), operator.clone()])
+            .projects(vec![project.clone()])
+            .forms(vec![form.clone()])
+            .answers(vec![answer.clone()])
+            .build()
+            .login_as(operator.clone())
+            .await;
+
+        let form_id = FormId::from_entity(form.id());
+        assert!(matches!(
+            migrate_form_answers::run(&app, form_id).await,
+            Err(UseCaseError::UseCase(
+                migrate_form_answers::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_admin() {
+        let user = test::model::new_general_user();
+        let project = test::model::new_general_project(user.id().clone());
+        let admin = test::model::new_admin_user();
+        let form = test::model::new_form(admin.id().clone());
+        let answer = test::model::new_form_answer(user.id().clone(), &project, &form);
+        let added_item = test::model::new_form_item();
+        let mut new_form = form.clone();
+        new_form
+            .set_items(
+                &admin,
+                FormItems::from_items(
+                    form.items()
+                        .items()
+                        .cloned()
+                        .chain(std::iter::once(added_item.clone())),
+                )
+                .unwrap(),
+            )
+            .unwrap();
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), admin.clone()])
+            .projects(vec![project.clone()])
+            .forms(vec![form.clone(), new_form.clone()])
+            .answers(vec![answer.clone()])
+            .build()
+            .login_as(admin.clone())
+            .await;
+
+        let form_id = FormId::from_entity(form.id());
+        let got = migrate_form_answers::run(&app, form_id).await.unwrap();
+        assert_eq!(got.len(), 1);
+        assert_eq!(
+            got[0].flagged_item_ids,
+            vec![FormItemId::from_entity(added_item.id)]
+        );
+        assert!(got[0].answer.needs_resubmission);
+        assert_eq!(got[0].answer.form_version, new_form.version().to_u32());
+
+        // migrated answers are skipped afterwards
+        let got = migrate_form_answers::run(&app, form_id).await.unwrap();
+        assert!(got.is_empty());
+    }
+}
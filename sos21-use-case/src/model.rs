@@ -1,16 +1,23 @@
 //! Data transfer object in the use case layer.
 
+pub mod access_control;
+pub mod api_key;
+pub mod custom_role;
+pub mod festival;
 pub mod file;
 pub mod file_distribution;
 pub mod file_sharing;
 pub mod form;
 pub mod form_answer;
+pub mod form_definition;
+pub mod form_template;
 pub mod pending_project;
 pub mod project;
 pub mod project_creation_availability;
 pub mod project_query;
 pub mod registration_form;
 pub mod registration_form_answer;
+pub mod settings;
 pub mod stream;
 pub mod user;
 pub mod user_invitation;
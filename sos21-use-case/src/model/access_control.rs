@@ -0,0 +1,79 @@
+use crate::model::custom_role::CustomRoleId;
+use crate::model::user::UserId;
+
+use sos21_domain::model::access_control as entity;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccessControlGrantee {
+    User(UserId),
+    CustomRole(CustomRoleId),
+}
+
+impl AccessControlGrantee {
+    pub fn from_entity(grantee: entity::AccessControlGrantee) -> Self {
+        match grantee {
+            entity::AccessControlGrantee::User(id) => {
+                AccessControlGrantee::User(UserId::from_entity(id))
+            }
+            entity::AccessControlGrantee::CustomRole(id) => {
+                AccessControlGrantee::CustomRole(CustomRoleId::from_entity(id))
+            }
+        }
+    }
+
+    pub fn into_entity(self) -> entity::AccessControlGrantee {
+        match self {
+            AccessControlGrantee::User(id) => entity::AccessControlGrantee::User(id.into_entity()),
+            AccessControlGrantee::CustomRole(id) => {
+                entity::AccessControlGrantee::CustomRole(id.into_entity())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessControlLevel {
+    Read,
+    Review,
+    Update,
+}
+
+impl AccessControlLevel {
+    pub fn from_entity(level: entity::AccessControlLevel) -> Self {
+        match level {
+            entity::AccessControlLevel::Read => AccessControlLevel::Read,
+            entity::AccessControlLevel::Review => AccessControlLevel::Review,
+            entity::AccessControlLevel::Update => AccessControlLevel::Update,
+        }
+    }
+
+    pub fn into_entity(self) -> entity::AccessControlLevel {
+        match self {
+            AccessControlLevel::Read => entity::AccessControlLevel::Read,
+            AccessControlLevel::Review => entity::AccessControlLevel::Review,
+            AccessControlLevel::Update => entity::AccessControlLevel::Update,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AccessControlEntry {
+    pub grantee: AccessControlGrantee,
+    pub level: AccessControlLevel,
+}
+
+impl AccessControlEntry {
+    pub fn from_entity(entry: entity::AccessControlEntry) -> Self {
+        AccessControlEntry {
+            grantee: AccessControlGrantee::from_entity(entry.grantee),
+            level: AccessControlLevel::from_entity(entry.level),
+        }
+    }
+
+    pub fn into_entity(self) -> entity::AccessControlEntry {
+        entity::AccessControlEntry {
+            grantee: self.grantee.into_entity(),
+            level: self.level.into_entity(),
+        }
+    }
+}
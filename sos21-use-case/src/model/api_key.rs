@@ -0,0 +1,73 @@
+use crate::model::user::{UserId, UserRole};
+
+use chrono::{DateTime, Utc};
+use sos21_domain::model::api_key as entity;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ApiKeyId(pub Uuid);
+
+impl ApiKeyId {
+    pub fn from_entity(id: entity::ApiKeyId) -> ApiKeyId {
+        ApiKeyId(id.to_uuid())
+    }
+
+    pub fn into_entity(self) -> entity::ApiKeyId {
+        entity::ApiKeyId::from_uuid(self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApiKeyScope {
+    Role(UserRole),
+    Permissions(Vec<String>),
+}
+
+impl ApiKeyScope {
+    pub fn from_entity(scope: entity::ApiKeyScope) -> ApiKeyScope {
+        match scope {
+            entity::ApiKeyScope::Role(role) => ApiKeyScope::Role(UserRole::from_entity(role)),
+            entity::ApiKeyScope::Permissions(permissions) => {
+                ApiKeyScope::Permissions(permissions.names().map(ToOwned::to_owned).collect())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ApiKey {
+    pub id: ApiKeyId,
+    pub created_at: DateTime<Utc>,
+    pub author_id: UserId,
+    pub name: String,
+    pub scope: ApiKeyScope,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl ApiKey {
+    pub fn from_entity(key: entity::ApiKey) -> ApiKey {
+        let entity::ApiKeyContent {
+            id,
+            created_at,
+            author_id,
+            name,
+            scope,
+            token_digest: _,
+            expires_at,
+            last_used_at,
+            revoked_at,
+        } = key.into_content();
+        ApiKey {
+            id: ApiKeyId::from_entity(id),
+            created_at: created_at.utc(),
+            author_id: UserId::from_entity(author_id),
+            name: name.into_string(),
+            scope: ApiKeyScope::from_entity(scope),
+            expires_at: expires_at.map(|expires_at| expires_at.utc()),
+            last_used_at: last_used_at.map(|last_used_at| last_used_at.utc()),
+            revoked_at: revoked_at.map(|revoked_at| revoked_at.utc()),
+        }
+    }
+}
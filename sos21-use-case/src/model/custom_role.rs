@@ -0,0 +1,66 @@
+use crate::model::user::{UserId, UserRole};
+
+use chrono::{DateTime, Utc};
+use sos21_domain::model::{custom_role as entity, permissions::Permissions, user};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CustomRoleId(pub Uuid);
+
+impl CustomRoleId {
+    pub fn from_entity(id: entity::CustomRoleId) -> CustomRoleId {
+        CustomRoleId(id.to_uuid())
+    }
+
+    pub fn into_entity(self) -> entity::CustomRoleId {
+        entity::CustomRoleId::from_uuid(self.0)
+    }
+}
+
+fn permission_names(permissions: Permissions) -> Vec<String> {
+    permissions.names().map(ToOwned::to_owned).collect()
+}
+
+#[derive(Debug, Clone)]
+pub struct CustomRole {
+    pub id: CustomRoleId,
+    pub created_at: DateTime<Utc>,
+    pub author_id: UserId,
+    pub name: String,
+    pub permissions: Vec<String>,
+}
+
+impl CustomRole {
+    pub fn from_entity(role: entity::CustomRole) -> CustomRole {
+        let entity::CustomRoleContent {
+            id,
+            created_at,
+            author_id,
+            name,
+            permissions,
+        } = role.into_content();
+        CustomRole {
+            id: CustomRoleId::from_entity(id),
+            created_at: created_at.utc(),
+            author_id: UserId::from_entity(author_id),
+            name: name.into_string(),
+            permissions: permission_names(permissions),
+        }
+    }
+}
+
+/// A built-in role with the permissions it grants by default.
+#[derive(Debug, Clone)]
+pub struct BuiltinRole {
+    pub role: UserRole,
+    pub permissions: Vec<String>,
+}
+
+impl BuiltinRole {
+    pub fn from_entity(role: user::UserRole) -> BuiltinRole {
+        BuiltinRole {
+            role: UserRole::from_entity(role),
+            permissions: permission_names(role.permissions()),
+        }
+    }
+}
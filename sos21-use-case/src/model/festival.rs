@@ -0,0 +1,39 @@
+use crate::model::user::UserId;
+
+use chrono::{DateTime, Utc};
+use sos21_domain::model::festival as entity;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FestivalId(pub Uuid);
+
+impl FestivalId {
+    pub fn from_entity(id: entity::FestivalId) -> FestivalId {
+        FestivalId(id.to_uuid())
+    }
+
+    pub fn into_entity(self) -> entity::FestivalId {
+        entity::FestivalId::from_uuid(self.0)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Festival {
+    pub id: FestivalId,
+    pub created_at: DateTime<Utc>,
+    pub author_id: Option<UserId>,
+    pub name: String,
+    pub is_active: bool,
+}
+
+impl Festival {
+    pub fn from_entity(festival: entity::Festival, is_active: bool) -> Self {
+        Festival {
+            id: FestivalId::from_entity(festival.id()),
+            created_at: festival.created_at().utc(),
+            author_id: festival.author_id().cloned().map(UserId::from_entity),
+            name: festival.name().clone().into_string(),
+            is_active,
+        }
+    }
+}
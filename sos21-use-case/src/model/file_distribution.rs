@@ -1,3 +1,4 @@
+use crate::model::festival::FestivalId;
 use crate::model::file_sharing::FileSharingId;
 use crate::model::project::ProjectId;
 use crate::model::user::UserId;
@@ -45,6 +46,7 @@ impl FileDistributionDistributedFile {
 #[derive(Debug, Clone)]
 pub struct FileDistribution {
     pub id: FileDistributionId,
+    pub festival_id: FestivalId,
     pub created_at: DateTime<Utc>,
     pub author_id: UserId,
     pub name: String,
@@ -62,6 +64,7 @@ impl FileDistribution {
     pub fn from_entity(distribution: entity::FileDistribution) -> Self {
         FileDistribution {
             id: FileDistributionId::from_entity(distribution.id),
+            festival_id: FestivalId::from_entity(distribution.festival_id),
             created_at: distribution.created_at.utc(),
             author_id: UserId::from_entity(distribution.author_id),
             name: distribution.name.into_string(),
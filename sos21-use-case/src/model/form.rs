@@ -1,3 +1,4 @@
+use crate::model::festival::FestivalId;
 use crate::model::project::ProjectId;
 use crate::model::project_query::ProjectQuery;
 use crate::model::user::UserId;
@@ -50,6 +51,8 @@ impl FormCondition {
 #[derive(Debug, Clone)]
 pub struct Form {
     pub id: FormId,
+    pub festival_id: FestivalId,
+    pub version: u32,
     pub created_at: DateTime<Utc>,
     pub author_id: UserId,
     pub name: String,
@@ -67,6 +70,8 @@ impl Form {
         let condition = FormCondition::from_entity(form.condition().clone());
         Form {
             id: FormId::from_entity(form.id()),
+            festival_id: FestivalId::from_entity(form.festival_id()),
+            version: form.version().to_u32(),
             created_at: form.created_at().utc(),
             author_id: UserId::from_entity(form.author_id().clone()),
             name: form.name().clone().into_string(),
@@ -83,3 +88,71 @@ impl Form {
         }
     }
 }
+
+#[derive(Debug, Clone)]
+pub struct FormRevision {
+    pub form_id: FormId,
+    pub version: u32,
+    pub created_at: DateTime<Utc>,
+    pub name: String,
+    pub description: String,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub items: Vec<FormItem>,
+    pub condition: Option<FormCondition>,
+}
+
+impl FormRevision {
+    pub fn from_entity(revision: entity::FormRevision) -> FormRevision {
+        FormRevision {
+            form_id: FormId::from_entity(revision.form_id),
+            version: revision.version.to_u32(),
+            created_at: revision.created_at.utc(),
+            name: revision.name.into_string(),
+            description: revision.description.into_string(),
+            starts_at: revision.period.starts_at().utc(),
+            ends_at: revision.period.ends_at().utc(),
+            items: revision
+                .items
+                .into_items()
+                .map(FormItem::from_entity)
+                .collect(),
+            condition: revision.condition.map(FormCondition::from_entity),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FormRevisionDiff {
+    pub name_changed: bool,
+    pub description_changed: bool,
+    pub period_changed: bool,
+    pub added_item_ids: Vec<FormItemId>,
+    pub removed_item_ids: Vec<FormItemId>,
+    pub modified_item_ids: Vec<FormItemId>,
+}
+
+impl FormRevisionDiff {
+    pub fn from_entity(diff: entity::FormRevisionDiff) -> FormRevisionDiff {
+        FormRevisionDiff {
+            name_changed: diff.name_changed,
+            description_changed: diff.description_changed,
+            period_changed: diff.period_changed,
+            added_item_ids: diff
+                .added_item_ids
+                .into_iter()
+                .map(FormItemId::from_entity)
+                .collect(),
+            removed_item_ids: diff
+                .removed_item_ids
+                .into_iter()
+                .map(FormItemId::from_entity)
+                .collect(),
+            modified_item_ids: diff
+                .modified_item_ids
+                .into_iter()
+                .map(FormItemId::from_entity)
+                .collect(),
+        }
+    }
+}
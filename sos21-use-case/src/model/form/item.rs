@@ -5,7 +5,7 @@ use uuid::Uuid;
 mod checkbox;
 pub use checkbox::{Checkbox, CheckboxId};
 mod condition;
-pub use condition::FormItemCondition;
+pub use condition::{FormItemCondition, IntegerConditionOperator};
 mod radio;
 pub use radio::{Radio, RadioId};
 mod grid_radio;
@@ -62,6 +62,11 @@ pub enum FormItemBody {
         accept_multiple_files: bool,
         is_required: bool,
     },
+    Group {
+        items: Vec<FormItem>,
+        min_rows: Option<u64>,
+        max_rows: Option<u64>,
+    },
 }
 
 impl FormItemBody {
@@ -133,6 +138,15 @@ impl FormItemBody {
                 accept_multiple_files: item.accept_multiple_files,
                 is_required: item.is_required,
             },
+            entity::FormItemBody::Group(item) => {
+                let item = item.into_content();
+                let items = item.items.into_items().map(FormItem::from_entity).collect();
+                FormItemBody::Group {
+                    items,
+                    min_rows: item.min_rows.map(|l| l.to_u64()),
+                    max_rows: item.max_rows.map(|l| l.to_u64()),
+                }
+            }
         }
     }
 }
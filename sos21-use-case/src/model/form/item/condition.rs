@@ -1,6 +1,7 @@
 use sos21_domain::model::form::item as entity;
 
 use super::{CheckboxId, FormItemId, GridRadioColumnId, RadioId};
+use crate::model::project::{ProjectAttribute, ProjectCategory};
 
 #[derive(Debug, Clone)]
 pub enum FormItemCondition {
@@ -17,6 +18,57 @@ pub enum FormItemCondition {
         item_id: FormItemId,
         column_id: GridRadioColumnId,
     },
+    Integer {
+        item_id: FormItemId,
+        operator: IntegerConditionOperator,
+        value: u64,
+    },
+    TextNonEmpty {
+        item_id: FormItemId,
+    },
+    ProjectCategory {
+        category: ProjectCategory,
+    },
+    ProjectAttribute {
+        attribute: ProjectAttribute,
+    },
+    Not {
+        condition: Box<FormItemCondition>,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum IntegerConditionOperator {
+    Eq,
+    Ne,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+impl IntegerConditionOperator {
+    pub fn from_entity(operator: entity::IntegerConditionOperator) -> Self {
+        match operator {
+            entity::IntegerConditionOperator::Eq => IntegerConditionOperator::Eq,
+            entity::IntegerConditionOperator::Ne => IntegerConditionOperator::Ne,
+            entity::IntegerConditionOperator::Gt => IntegerConditionOperator::Gt,
+            entity::IntegerConditionOperator::Ge => IntegerConditionOperator::Ge,
+            entity::IntegerConditionOperator::Lt => IntegerConditionOperator::Lt,
+            entity::IntegerConditionOperator::Le => IntegerConditionOperator::Le,
+        }
+    }
+
+    pub fn into_entity(self) -> entity::IntegerConditionOperator {
+        match self {
+            IntegerConditionOperator::Eq => entity::IntegerConditionOperator::Eq,
+            IntegerConditionOperator::Ne => entity::IntegerConditionOperator::Ne,
+            IntegerConditionOperator::Gt => entity::IntegerConditionOperator::Gt,
+            IntegerConditionOperator::Ge => entity::IntegerConditionOperator::Ge,
+            IntegerConditionOperator::Lt => entity::IntegerConditionOperator::Lt,
+            IntegerConditionOperator::Le => entity::IntegerConditionOperator::Le,
+        }
+    }
 }
 
 impl FormItemCondition {
@@ -43,6 +95,33 @@ impl FormItemCondition {
                     column_id: GridRadioColumnId::from_entity(column_id),
                 }
             }
+            entity::FormItemCondition::Integer {
+                item_id,
+                operator,
+                value,
+            } => FormItemCondition::Integer {
+                item_id: FormItemId::from_entity(item_id),
+                operator: IntegerConditionOperator::from_entity(operator),
+                value,
+            },
+            entity::FormItemCondition::TextNonEmpty { item_id } => {
+                FormItemCondition::TextNonEmpty {
+                    item_id: FormItemId::from_entity(item_id),
+                }
+            }
+            entity::FormItemCondition::ProjectCategory { category } => {
+                FormItemCondition::ProjectCategory {
+                    category: ProjectCategory::from_entity(category),
+                }
+            }
+            entity::FormItemCondition::ProjectAttribute { attribute } => {
+                FormItemCondition::ProjectAttribute {
+                    attribute: ProjectAttribute::from_entity(attribute),
+                }
+            }
+            entity::FormItemCondition::Not(condition) => FormItemCondition::Not {
+                condition: Box::new(FormItemCondition::from_entity(*condition)),
+            },
         }
     }
 
@@ -69,6 +148,33 @@ impl FormItemCondition {
                     column_id: column_id.into_entity(),
                 }
             }
+            FormItemCondition::Integer {
+                item_id,
+                operator,
+                value,
+            } => entity::FormItemCondition::Integer {
+                item_id: item_id.into_entity(),
+                operator: operator.into_entity(),
+                value,
+            },
+            FormItemCondition::TextNonEmpty { item_id } => {
+                entity::FormItemCondition::TextNonEmpty {
+                    item_id: item_id.into_entity(),
+                }
+            }
+            FormItemCondition::ProjectCategory { category } => {
+                entity::FormItemCondition::ProjectCategory {
+                    category: category.into_entity(),
+                }
+            }
+            FormItemCondition::ProjectAttribute { attribute } => {
+                entity::FormItemCondition::ProjectAttribute {
+                    attribute: attribute.into_entity(),
+                }
+            }
+            FormItemCondition::Not { condition } => {
+                entity::FormItemCondition::Not(Box::new(condition.into_entity()))
+            }
         }
     }
 }
@@ -1,4 +1,4 @@
-use crate::model::form::FormId;
+use crate::model::form::{FormId, FormItemId};
 use crate::model::project::ProjectId;
 use crate::model::user::UserId;
 
@@ -22,24 +22,84 @@ impl FormAnswerId {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormAnswerReviewStatus {
+    Submitted,
+    UnderReview,
+    NeedsChanges,
+    Approved,
+}
+
+impl FormAnswerReviewStatus {
+    pub fn from_entity(status: entity::FormAnswerReviewStatus) -> Self {
+        match status {
+            entity::FormAnswerReviewStatus::Submitted => FormAnswerReviewStatus::Submitted,
+            entity::FormAnswerReviewStatus::UnderReview => FormAnswerReviewStatus::UnderReview,
+            entity::FormAnswerReviewStatus::NeedsChanges => FormAnswerReviewStatus::NeedsChanges,
+            entity::FormAnswerReviewStatus::Approved => FormAnswerReviewStatus::Approved,
+        }
+    }
+
+    pub fn into_entity(self) -> entity::FormAnswerReviewStatus {
+        match self {
+            FormAnswerReviewStatus::Submitted => entity::FormAnswerReviewStatus::Submitted,
+            FormAnswerReviewStatus::UnderReview => entity::FormAnswerReviewStatus::UnderReview,
+            FormAnswerReviewStatus::NeedsChanges => entity::FormAnswerReviewStatus::NeedsChanges,
+            FormAnswerReviewStatus::Approved => entity::FormAnswerReviewStatus::Approved,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FormAnswerReviewComment {
+    pub item_id: FormItemId,
+    pub body: String,
+}
+
+impl FormAnswerReviewComment {
+    pub fn from_entity(comment: entity::review::FormAnswerReviewComment) -> Self {
+        FormAnswerReviewComment {
+            item_id: FormItemId::from_entity(comment.item_id),
+            body: comment.body.into_string(),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct FormAnswer {
     pub id: FormAnswerId,
     pub project_id: ProjectId,
     pub form_id: FormId,
+    pub form_version: u32,
     pub created_at: DateTime<Utc>,
     pub author_id: UserId,
     pub items: Vec<FormAnswerItem>,
+    pub needs_resubmission: bool,
+    pub review_status: FormAnswerReviewStatus,
+    pub review_comments: Vec<FormAnswerReviewComment>,
+    pub reviewer_id: Option<UserId>,
+    pub reviewed_at: Option<DateTime<Utc>>,
 }
 
 impl FormAnswer {
     pub fn from_entity(answer: entity::FormAnswer) -> Self {
+        let review = answer.review().clone();
         FormAnswer {
             id: FormAnswerId::from_entity(answer.id()),
             project_id: ProjectId::from_entity(answer.project_id()),
             form_id: FormId::from_entity(answer.form_id()),
+            form_version: answer.form_version().to_u32(),
             created_at: answer.created_at().utc(),
             author_id: UserId::from_entity(answer.author_id().clone()),
+            needs_resubmission: answer.needs_resubmission(),
+            review_status: FormAnswerReviewStatus::from_entity(review.status),
+            review_comments: review
+                .comments
+                .into_comments()
+                .map(FormAnswerReviewComment::from_entity)
+                .collect(),
+            reviewer_id: review.reviewer_id.map(UserId::from_entity),
+            reviewed_at: review.reviewed_at.map(|reviewed_at| reviewed_at.utc()),
             items: answer
                 .into_items()
                 .into_items()
@@ -48,3 +108,28 @@ impl FormAnswer {
         }
     }
 }
+
+#[derive(Debug, Clone)]
+pub struct FormAnswerDraft {
+    pub project_id: ProjectId,
+    pub form_id: FormId,
+    pub updated_at: DateTime<Utc>,
+    pub author_id: UserId,
+    pub items: Vec<FormAnswerItem>,
+}
+
+impl FormAnswerDraft {
+    pub fn from_entity(draft: entity::FormAnswerDraft) -> Self {
+        FormAnswerDraft {
+            project_id: ProjectId::from_entity(draft.project_id()),
+            form_id: FormId::from_entity(draft.form_id()),
+            updated_at: draft.updated_at().utc(),
+            author_id: UserId::from_entity(draft.author_id().clone()),
+            items: draft
+                .into_items()
+                .into_items()
+                .map(FormAnswerItem::from_entity)
+                .collect(),
+        }
+    }
+}
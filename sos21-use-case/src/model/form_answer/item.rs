@@ -28,6 +28,7 @@ pub enum FormAnswerItemBody {
     Radio(Option<RadioId>),
     GridRadio(Vec<GridRadioRowAnswer>),
     File(Vec<FileSharingId>),
+    Group(Vec<Vec<FormAnswerItem>>),
 }
 
 impl FormAnswerItemBody {
@@ -58,6 +59,13 @@ impl FormAnswerItemBody {
                     .collect();
                 FormAnswerItemBody::File(answer)
             }
+            entity::FormAnswerItemBody::Group(answer) => {
+                let rows = answer
+                    .into_rows()
+                    .map(|row| row.into_items().map(FormAnswerItem::from_entity).collect())
+                    .collect();
+                FormAnswerItemBody::Group(rows)
+            }
         }
     }
 }
@@ -0,0 +1,55 @@
+use crate::model::form::{Form, FormItem};
+use crate::model::project_query::ProjectQuery;
+use crate::model::registration_form::RegistrationForm;
+
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormDefinitionKind {
+    Form,
+    RegistrationForm,
+}
+
+#[derive(Debug, Clone)]
+pub struct FormDefinitionPeriod {
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
+
+/// The portable definition of a form or a registration form.
+///
+/// The answer period is only used in forms, and project IDs in form conditions are not
+/// included since they are meaningless outside of the festival.
+#[derive(Debug, Clone)]
+pub struct FormDefinition {
+    pub name: String,
+    pub description: String,
+    pub period: Option<FormDefinitionPeriod>,
+    pub items: Vec<FormItem>,
+    pub query: ProjectQuery,
+}
+
+impl FormDefinition {
+    pub fn from_form(form: Form) -> Self {
+        FormDefinition {
+            name: form.name,
+            description: form.description,
+            period: Some(FormDefinitionPeriod {
+                starts_at: form.starts_at,
+                ends_at: form.ends_at,
+            }),
+            items: form.items,
+            query: form.condition.query,
+        }
+    }
+
+    pub fn from_registration_form(registration_form: RegistrationForm) -> Self {
+        FormDefinition {
+            name: registration_form.name,
+            description: registration_form.description,
+            period: None,
+            items: registration_form.items,
+            query: registration_form.query,
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::model::form::FormItem;
+use crate::model::project_query::ProjectQuery;
+use crate::model::user::UserId;
+
+use chrono::{DateTime, Utc};
+use sos21_domain::model::form_template as entity;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FormTemplateId(pub Uuid);
+
+impl FormTemplateId {
+    pub fn from_entity(id: entity::FormTemplateId) -> FormTemplateId {
+        FormTemplateId(id.to_uuid())
+    }
+
+    pub fn into_entity(self) -> entity::FormTemplateId {
+        entity::FormTemplateId::from_uuid(self.0)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FormTemplate {
+    pub id: FormTemplateId,
+    pub created_at: DateTime<Utc>,
+    pub author_id: UserId,
+    pub name: String,
+    pub description: String,
+    pub items: Vec<FormItem>,
+    pub query: ProjectQuery,
+}
+
+impl FormTemplate {
+    pub fn from_entity(template: entity::FormTemplate) -> FormTemplate {
+        let entity::FormTemplateContent {
+            id,
+            created_at,
+            author_id,
+            name,
+            description,
+            items,
+            query,
+        } = template.into_content();
+        FormTemplate {
+            id: FormTemplateId::from_entity(id),
+            created_at: created_at.utc(),
+            author_id: UserId::from_entity(author_id),
+            name: name.into_string(),
+            description: description.into_string(),
+            items: items.into_items().map(FormItem::from_entity).collect(),
+            query: ProjectQuery::from_entity(query),
+        }
+    }
+}
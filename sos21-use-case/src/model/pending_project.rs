@@ -1,5 +1,6 @@
+use crate::model::festival::FestivalId;
 use crate::model::project::{ProjectAttribute, ProjectCategory};
-use crate::model::user::UserId;
+use crate::model::user::{UserId, UserKanaName, UserName};
 
 use chrono::{DateTime, Utc};
 use sos21_domain::model::pending_project as entity;
@@ -21,6 +22,7 @@ impl PendingProjectId {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PendingProject {
     pub id: PendingProjectId,
+    pub festival_id: FestivalId,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub owner_id: UserId,
@@ -38,6 +40,7 @@ impl PendingProject {
     pub fn from_entity(pending_project: entity::PendingProject) -> Self {
         PendingProject {
             id: PendingProjectId::from_entity(pending_project.id()),
+            festival_id: FestivalId::from_entity(pending_project.festival_id()),
             created_at: pending_project.created_at().utc(),
             updated_at: pending_project.updated_at().utc(),
             owner_id: UserId::from_entity(pending_project.owner_id().clone()),
@@ -58,3 +61,13 @@ impl PendingProject {
         }
     }
 }
+
+/// A pending project with its owner's name and the progress of its registration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingProjectSummary {
+    pub pending_project: PendingProject,
+    pub owner_name: UserName,
+    pub owner_kana_name: UserKanaName,
+    pub registration_form_count: u64,
+    pub answered_registration_form_count: u64,
+}
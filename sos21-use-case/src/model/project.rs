@@ -1,3 +1,4 @@
+use crate::model::festival::FestivalId;
 use crate::model::user::{UserId, UserKanaName, UserName};
 
 use chrono::{DateTime, Utc};
@@ -86,6 +87,7 @@ impl ProjectAttribute {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Project {
     pub id: ProjectId,
+    pub festival_id: FestivalId,
     pub code: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -125,6 +127,7 @@ impl Project {
 
         Project {
             id: ProjectId::from_entity(project.id()),
+            festival_id: FestivalId::from_entity(project.festival_id()),
             code: project.code().to_string(),
             created_at: project.created_at().utc(),
             updated_at: project.updated_at().utc(),
@@ -1,3 +1,4 @@
+use crate::model::festival::FestivalId;
 use crate::model::form::FormItem;
 use crate::model::project_query::ProjectQuery;
 use crate::model::user::UserId;
@@ -22,6 +23,7 @@ impl RegistrationFormId {
 #[derive(Debug, Clone)]
 pub struct RegistrationForm {
     pub id: RegistrationFormId,
+    pub festival_id: FestivalId,
     pub created_at: DateTime<Utc>,
     pub author_id: UserId,
     pub name: String,
@@ -34,6 +36,7 @@ impl RegistrationForm {
     pub fn from_entity(registratiion_form: entity::RegistrationForm) -> RegistrationForm {
         RegistrationForm {
             id: RegistrationFormId::from_entity(registratiion_form.id),
+            festival_id: FestivalId::from_entity(registratiion_form.festival_id),
             created_at: registratiion_form.created_at.utc(),
             author_id: UserId::from_entity(registratiion_form.author_id),
             name: registratiion_form.name.into_string(),
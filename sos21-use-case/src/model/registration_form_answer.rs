@@ -83,3 +83,28 @@ impl RegistrationFormAnswer {
         }
     }
 }
+
+#[derive(Debug, Clone)]
+pub struct RegistrationFormAnswerDraft {
+    pub registration_form_id: RegistrationFormId,
+    pub pending_project_id: PendingProjectId,
+    pub updated_at: DateTime<Utc>,
+    pub author_id: UserId,
+    pub items: Vec<FormAnswerItem>,
+}
+
+impl RegistrationFormAnswerDraft {
+    pub fn from_entity(draft: entity::RegistrationFormAnswerDraft) -> Self {
+        RegistrationFormAnswerDraft {
+            registration_form_id: RegistrationFormId::from_entity(draft.registration_form_id()),
+            pending_project_id: PendingProjectId::from_entity(draft.pending_project_id()),
+            updated_at: draft.updated_at().utc(),
+            author_id: UserId::from_entity(draft.author_id().clone()),
+            items: draft
+                .into_items()
+                .into_items()
+                .map(FormAnswerItem::from_entity)
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use crate::model::festival::FestivalId;
+use crate::model::project::ProjectCategory;
+use crate::model::user::UserId;
+
+use chrono::{DateTime, Utc};
+use sos21_domain::model::{
+    date_time, project as project_entity, project_creation_period as period_entity,
+    settings as entity,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectCreationPeriod {
+    Always,
+    Never,
+    Range {
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+    },
+}
+
+impl ProjectCreationPeriod {
+    pub fn from_entity(period: period_entity::ProjectCreationPeriod) -> Self {
+        if let Some((starts_at, ends_at)) = period.range() {
+            ProjectCreationPeriod::Range {
+                starts_at: starts_at.utc(),
+                ends_at: ends_at.utc(),
+            }
+        } else if period.is_always() {
+            ProjectCreationPeriod::Always
+        } else {
+            ProjectCreationPeriod::Never
+        }
+    }
+
+    pub fn into_entity(
+        self,
+    ) -> Result<period_entity::ProjectCreationPeriod, period_entity::PeriodError> {
+        match self {
+            ProjectCreationPeriod::Always => Ok(period_entity::ProjectCreationPeriod::always()),
+            ProjectCreationPeriod::Never => Ok(period_entity::ProjectCreationPeriod::never()),
+            ProjectCreationPeriod::Range { starts_at, ends_at } => {
+                period_entity::ProjectCreationPeriod::from_datetime(
+                    date_time::DateTime::from_utc(starts_at),
+                    date_time::DateTime::from_utc(ends_at),
+                )
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Settings {
+    pub updated_at: DateTime<Utc>,
+    pub author_id: Option<UserId>,
+    pub administrator_email: String,
+    pub active_festival_id: FestivalId,
+    pub project_creation_periods: Vec<(ProjectCategory, ProjectCreationPeriod)>,
+}
+
+impl Settings {
+    pub fn from_entity(settings: entity::Settings) -> Self {
+        let project_creation_periods = project_entity::ProjectCategory::enumerate()
+            .map(|category| {
+                let period = settings.project_creation_period_for(category);
+                (
+                    ProjectCategory::from_entity(category),
+                    ProjectCreationPeriod::from_entity(period),
+                )
+            })
+            .collect();
+
+        Settings {
+            updated_at: settings.updated_at().utc(),
+            author_id: settings.author_id().cloned().map(UserId::from_entity),
+            administrator_email: settings.administrator_email().clone().into_string(),
+            active_festival_id: FestivalId::from_entity(settings.active_festival_id()),
+            project_creation_periods,
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use crate::model::custom_role::CustomRoleId;
+
 use chrono::{DateTime, Utc};
 use sos21_domain::model::user as entity;
 
@@ -95,6 +97,9 @@ pub struct User {
     pub phone_number: String,
     pub role: UserRole,
     pub category: UserCategory,
+    pub custom_role_id: Option<CustomRoleId>,
+    pub suspension: Option<UserSuspension>,
+    pub tokens_revoked_at: Option<DateTime<Utc>>,
 }
 
 impl User {
@@ -108,6 +113,29 @@ impl User {
             phone_number: user.phone_number().clone().into_string(),
             role: UserRole::from_entity(user.role()),
             category: UserCategory::from_entity(user.category().clone()),
+            custom_role_id: user
+                .custom_role()
+                .map(|custom_role| CustomRoleId::from_entity(custom_role.id)),
+            suspension: user.suspension().cloned().map(UserSuspension::from_entity),
+            tokens_revoked_at: user.tokens_revoked_at().map(|revoked_at| revoked_at.utc()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserSuspension {
+    pub suspended_at: DateTime<Utc>,
+    /// `None` if the user is suspended automatically by the server.
+    pub suspended_by: Option<UserId>,
+    pub reason: String,
+}
+
+impl UserSuspension {
+    pub fn from_entity(suspension: entity::UserSuspension) -> UserSuspension {
+        UserSuspension {
+            suspended_at: suspension.suspended_at.utc(),
+            suspended_by: suspension.suspended_by.map(UserId::from_entity),
+            reason: suspension.reason.into_string(),
         }
     }
 }
@@ -42,6 +42,31 @@ impl UserInvitationRole {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserInvitationStatus {
+    Pending,
+    Accepted,
+    Expired,
+}
+
+impl UserInvitationStatus {
+    pub fn from_entity(status: entity::UserInvitationStatus) -> Self {
+        match status {
+            entity::UserInvitationStatus::Pending => UserInvitationStatus::Pending,
+            entity::UserInvitationStatus::Accepted => UserInvitationStatus::Accepted,
+            entity::UserInvitationStatus::Expired => UserInvitationStatus::Expired,
+        }
+    }
+
+    pub fn into_entity(self) -> entity::UserInvitationStatus {
+        match self {
+            UserInvitationStatus::Pending => entity::UserInvitationStatus::Pending,
+            UserInvitationStatus::Accepted => entity::UserInvitationStatus::Accepted,
+            UserInvitationStatus::Expired => entity::UserInvitationStatus::Expired,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct UserInvitation {
     pub id: UserInvitationId,
@@ -49,16 +74,34 @@ pub struct UserInvitation {
     pub author_id: UserId,
     pub email: String,
     pub role: UserInvitationRole,
+    pub allows_unlisted_domain: bool,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub accepted_at: Option<DateTime<Utc>>,
+    pub accepted_user_id: Option<UserId>,
+    pub status: UserInvitationStatus,
 }
 
 impl UserInvitation {
     pub fn from_entity(invitation: entity::UserInvitation) -> Self {
+        let status = UserInvitationStatus::from_entity(invitation.status());
+        let (accepted_at, accepted_user_id) = match invitation.acceptance() {
+            Some(acceptance) => (
+                Some(acceptance.accepted_at.utc()),
+                Some(UserId::from_entity(acceptance.user_id.clone())),
+            ),
+            None => (None, None),
+        };
         UserInvitation {
             id: UserInvitationId::from_entity(invitation.id()),
             created_at: invitation.created_at().utc(),
             author_id: UserId::from_entity(invitation.author_id().clone()),
             email: invitation.email().clone().into_string(),
             role: UserInvitationRole::from_entity(invitation.role()),
+            allows_unlisted_domain: invitation.allows_unlisted_domain(),
+            expires_at: invitation.expires_at().map(|expires_at| expires_at.utc()),
+            accepted_at,
+            accepted_user_id,
+            status,
         }
     }
 }
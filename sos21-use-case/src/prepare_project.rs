@@ -30,6 +30,7 @@ pub enum Error {
     AlreadyPendingProjectOwner,
     OutOfCreationPeriod,
     ArtisticStageProject,
+    InsufficientPermissions,
 }
 
 impl Error {
@@ -85,6 +86,11 @@ where
 {
     let mut login_user = ctx.login_user().clone();
 
+    // API keys cannot own pending projects, which they are not given the accesses to
+    if login_user.is_with_api_key() {
+        return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
+    }
+
     let name = project::ProjectName::from_string(input.name)
         .map_err(|err| UseCaseError::UseCase(Error::from_name_error(err)))?;
     let kana_name = project::ProjectKanaName::from_string(input.kana_name)
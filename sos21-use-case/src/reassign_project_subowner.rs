@@ -0,0 +1,235 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::project::{Project, ProjectFromEntityInput, ProjectId};
+use crate::model::user::UserId;
+
+use anyhow::Context;
+use sos21_domain::context::project_repository::{self, ProjectRepository};
+use sos21_domain::context::{Login, UserRepository};
+use sos21_domain::model::project;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub id: ProjectId,
+    pub subowner_id: UserId,
+}
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    NotFound,
+    SubownerNotFound,
+    SameOwnerSubowner,
+    AlreadyProjectOwnerSubowner,
+    AlreadyProjectSubownerSubowner,
+    AlreadyPendingProjectOwnerSubowner,
+    InsufficientPermissions,
+}
+
+impl Error {
+    fn from_set_subowner_error(err: project::SetSubownerError) -> Self {
+        match err.kind() {
+            project::SetSubownerErrorKind::InsufficientPermissions => {
+                Error::InsufficientPermissions
+            }
+            project::SetSubownerErrorKind::SameOwnerSubowner => Error::SameOwnerSubowner,
+            project::SetSubownerErrorKind::AlreadyProjectOwnerSubowner => {
+                Error::AlreadyProjectOwnerSubowner
+            }
+            project::SetSubownerErrorKind::AlreadyProjectSubownerSubowner => {
+                Error::AlreadyProjectSubownerSubowner
+            }
+            project::SetSubownerErrorKind::AlreadyPendingProjectOwnerSubowner => {
+                Error::AlreadyPendingProjectOwnerSubowner
+            }
+        }
+    }
+}
+
+/// Replace the subowner of the project, releasing the previous subowner from the project.
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<Project, Error>
+where
+    C: ProjectRepository + UserRepository + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    let result = ctx
+        .get_project(input.id.into_entity())
+        .await
+        .context("Failed to get a project")?;
+    let project_repository::ProjectWithOwners {
+        mut project,
+        owner,
+        subowner: mut previous_subowner,
+    } = match result {
+        Some(result) if result.project.is_visible_to(login_user) => result,
+        _ => return Err(UseCaseError::UseCase(Error::NotFound)),
+    };
+
+    let subowner = ctx
+        .get_user(input.subowner_id.into_entity())
+        .await
+        .context("Failed to get a user")?;
+    let mut subowner = match subowner {
+        Some(subowner) if subowner.is_visible_to(login_user) => subowner,
+        _ => return Err(UseCaseError::UseCase(Error::SubownerNotFound)),
+    };
+
+    project
+        .set_subowner(login_user, &subowner)
+        .map_err(|err| UseCaseError::UseCase(Error::from_set_subowner_error(err)))?;
+
+    // the previous subowner has to be released before the project refers to the new one
+    previous_subowner.release_assignment();
+    ctx.store_user(previous_subowner)
+        .await
+        .context("Failed to store the previous subowner")?;
+    ctx.store_project(project.clone())
+        .await
+        .context("Failed to store a updated project")?;
+    subowner.assign_project_subowner(&project)?;
+    ctx.store_user(subowner.clone())
+        .await
+        .context("Failed to store the new subowner")?;
+
+    use_case_ensure!(
+        project.is_visible_to(login_user)
+            && owner.name().is_visible_to(login_user)
+            && owner.kana_name().is_visible_to(login_user)
+            && subowner.name().is_visible_to(login_user)
+            && subowner.kana_name().is_visible_to(login_user)
+    );
+    Ok(Project::from_entity(ProjectFromEntityInput {
+        project,
+        owner_name: owner.name().clone(),
+        owner_kana_name: owner.kana_name().clone(),
+        subowner_name: subowner.name().clone(),
+        subowner_kana_name: subowner.kana_name().clone(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{project::ProjectId, user::UserId};
+    use crate::{reassign_project_subowner, UseCaseError};
+    use sos21_domain::context::UserRepository;
+    use sos21_domain::model::user::UserAssignment;
+    use sos21_domain::test;
+
+    #[tokio::test]
+    async fn test_admin() {
+        let admin = test::model::new_admin_user();
+        let mut owner = test::model::new_general_user();
+        let mut subowner = test::model::new_general_user();
+        let project = test::model::new_general_project_with_subowner(
+            owner.id().clone(),
+            subowner.id().clone(),
+        );
+        owner.assign_project_owner(&project).unwrap();
+        subowner.assign_project_subowner(&project).unwrap();
+        let new_subowner = test::model::new_general_user();
+
+        let app = test::build_mock_app()
+            .users(vec![
+                admin.clone(),
+                owner,
+                subowner.clone(),
+                new_subowner.clone(),
+            ])
+            .projects(vec![project.clone()])
+            .build()
+            .login_as(admin)
+            .await;
+
+        let input = reassign_project_subowner::Input {
+            id: ProjectId::from_entity(project.id()),
+            subowner_id: UserId::from_entity(new_subowner.id().clone()),
+        };
+        let result = reassign_project_subowner::run(&app, input).await.unwrap();
+        assert_eq!(
+            result.subowner_id,
+            UserId::from_entity(new_subowner.id().clone())
+        );
+
+        let previous = app.get_user(subowner.id().clone()).await.unwrap().unwrap();
+        assert!(previous.assignment().is_none());
+        let new_subowner = app
+            .get_user(new_subowner.id().clone())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            new_subowner.assignment(),
+            Some(UserAssignment::ProjectSubowner(project.id()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_operator() {
+        let operator = test::model::new_operator_user();
+        let owner = test::model::new_general_user();
+        let subowner = test::model::new_general_user();
+        let project = test::model::new_general_project_with_subowner(
+            owner.id().clone(),
+            subowner.id().clone(),
+        );
+        let new_subowner = test::model::new_general_user();
+
+        let app = test::build_mock_app()
+            .users(vec![
+                operator.clone(),
+                owner,
+                subowner,
+                new_subowner.clone(),
+            ])
+            .projects(vec![project.clone()])
+            .build()
+            .login_as(operator)
+            .await;
+
+        let input = reassign_project_subowner::Input {
+            id: ProjectId::from_entity(project.id()),
+            subowner_id: UserId::from_entity(new_subowner.id().clone()),
+        };
+        assert!(matches!(
+            reassign_project_subowner::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                reassign_project_subowner::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_already_owner() {
+        let admin = test::model::new_admin_user();
+        let owner = test::model::new_general_user();
+        let subowner = test::model::new_general_user();
+        let project = test::model::new_general_project_with_subowner(
+            owner.id().clone(),
+            subowner.id().clone(),
+        );
+        let mut other = test::model::new_general_user();
+        let pending_project = test::model::new_general_pending_project(other.id().clone());
+        other
+            .assign_pending_project_owner(&pending_project)
+            .unwrap();
+
+        let app = test::build_mock_app()
+            .users(vec![admin.clone(), owner, subowner, other.clone()])
+            .projects(vec![project.clone()])
+            .pending_projects(vec![pending_project])
+            .build()
+            .login_as(admin)
+            .await;
+
+        let input = reassign_project_subowner::Input {
+            id: ProjectId::from_entity(project.id()),
+            subowner_id: UserId::from_entity(other.id().clone()),
+        };
+        assert!(matches!(
+            reassign_project_subowner::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                reassign_project_subowner::Error::AlreadyPendingProjectOwnerSubowner
+            ))
+        ));
+    }
+}
@@ -0,0 +1,158 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::user_invitation::{UserInvitation, UserInvitationId};
+
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use sos21_domain::context::{Login, UserInvitationRepository};
+use sos21_domain::model::{date_time, user_invitation};
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    NotFound,
+    AlreadyAccepted,
+    PastExpiry,
+    InsufficientPermissions,
+}
+
+impl Error {
+    fn from_set_expires_at_error(err: user_invitation::SetExpiresAtError) -> Self {
+        match err.kind() {
+            user_invitation::SetExpiresAtErrorKind::InsufficientPermissions => {
+                Error::InsufficientPermissions
+            }
+            user_invitation::SetExpiresAtErrorKind::AlreadyAccepted => Error::AlreadyAccepted,
+            user_invitation::SetExpiresAtErrorKind::PastExpiry => Error::PastExpiry,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub id: UserInvitationId,
+    /// The new expiry of the invitation, which replaces the current one.
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+// TODO: Actually send an invitation email
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<UserInvitation, Error>
+where
+    C: UserInvitationRepository + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    let result = ctx
+        .get_user_invitation(input.id.into_entity())
+        .await
+        .context("Failed to get user invitation")?;
+    let mut invitation = match result {
+        Some(invitation) if invitation.is_visible_to(login_user) => invitation,
+        _ => return Err(UseCaseError::UseCase(Error::NotFound)),
+    };
+
+    invitation
+        .set_expires_at(
+            login_user,
+            input.expires_at.map(date_time::DateTime::from_utc),
+        )
+        .map_err(|err| UseCaseError::UseCase(Error::from_set_expires_at_error(err)))?;
+
+    ctx.store_user_invitation(invitation.clone())
+        .await
+        .context("Failed to store user invitation")?;
+
+    Ok(UserInvitation::from_entity(invitation))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::user_invitation::{UserInvitationId, UserInvitationStatus};
+    use crate::{resend_user_invitation, UseCaseError};
+
+    use sos21_domain::test;
+
+    #[tokio::test]
+    async fn test_operator() {
+        let admin = test::model::new_admin_user();
+        let operator = test::model::new_operator_user();
+        let invitation = test::model::new_expired_user_invitation(
+            admin.id().clone(),
+            "example-resend@s.tsukuba.ac.jp",
+        );
+
+        let app = test::build_mock_app()
+            .users(vec![admin, operator.clone()])
+            .user_invitations(vec![invitation.clone()])
+            .build()
+            .login_as(operator)
+            .await;
+
+        let input = resend_user_invitation::Input {
+            id: UserInvitationId::from_entity(invitation.id()),
+            expires_at: None,
+        };
+        assert!(matches!(
+            resend_user_invitation::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                resend_user_invitation::Error::NotFound
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_admin_expired() {
+        let admin = test::model::new_admin_user();
+        let invitation = test::model::new_expired_user_invitation(
+            admin.id().clone(),
+            "example-resend@s.tsukuba.ac.jp",
+        );
+
+        let app = test::build_mock_app()
+            .users(vec![admin.clone()])
+            .user_invitations(vec![invitation.clone()])
+            .build()
+            .login_as(admin)
+            .await;
+
+        let expires_at = chrono::Utc::now() + chrono::Duration::days(7);
+        let input = resend_user_invitation::Input {
+            id: UserInvitationId::from_entity(invitation.id()),
+            expires_at: Some(expires_at),
+        };
+        assert!(matches!(
+            resend_user_invitation::run(&app, input).await,
+            Ok(got)
+            if got.status == UserInvitationStatus::Pending
+            && got.expires_at == Some(expires_at)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_admin_accepted() {
+        let admin = test::model::new_admin_user();
+        let user = test::model::new_general_user();
+        let mut invitation = test::model::new_committee_user_invitation(
+            admin.id().clone(),
+            user.email().clone().into_string(),
+        );
+        invitation.accept(&user).unwrap();
+
+        let app = test::build_mock_app()
+            .users(vec![admin.clone(), user])
+            .user_invitations(vec![invitation.clone()])
+            .build()
+            .login_as(admin)
+            .await;
+
+        let input = resend_user_invitation::Input {
+            id: UserInvitationId::from_entity(invitation.id()),
+            expires_at: None,
+        };
+        assert!(matches!(
+            resend_user_invitation::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                resend_user_invitation::Error::AlreadyAccepted
+            ))
+        ));
+    }
+}
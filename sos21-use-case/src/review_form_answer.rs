@@ -0,0 +1,265 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::form::FormItemId;
+use crate::model::form_answer::{FormAnswer, FormAnswerId, FormAnswerReviewStatus};
+
+use anyhow::Context;
+use sos21_domain::context::{FormAnswerRepository, FormRepository, Login};
+use sos21_domain::model::{
+    access_control::AccessControlLevel, form_answer, permissions::Permissions,
+};
+
+#[derive(Debug, Clone)]
+pub struct InputComment {
+    pub item_id: FormItemId,
+    pub body: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub answer_id: FormAnswerId,
+    pub status: FormAnswerReviewStatus,
+    pub comments: Vec<InputComment>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    FormAnswerNotFound,
+    InsufficientPermissions,
+    InvalidStatus,
+    InvalidComment(FormItemId),
+    TooManyComments,
+    DuplicatedCommentItemId(FormItemId),
+    UnknownCommentItemId(FormItemId),
+}
+
+impl Error {
+    fn from_comments_error(err: form_answer::review::FromCommentsError) -> Self {
+        match err.kind() {
+            form_answer::review::FromCommentsErrorKind::TooLong => Error::TooManyComments,
+            form_answer::review::FromCommentsErrorKind::DuplicatedItemId(id) => {
+                Error::DuplicatedCommentItemId(FormItemId::from_entity(id))
+            }
+        }
+    }
+
+    fn from_review_error(err: form_answer::ReviewError) -> Self {
+        match err.kind() {
+            form_answer::ReviewErrorKind::InsufficientPermissions => Error::InsufficientPermissions,
+            form_answer::ReviewErrorKind::InvalidStatus => Error::InvalidStatus,
+            form_answer::ReviewErrorKind::UnknownCommentItemId(id) => {
+                Error::UnknownCommentItemId(FormItemId::from_entity(id))
+            }
+        }
+    }
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<FormAnswer, Error>
+where
+    C: FormRepository + FormAnswerRepository + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    let result = ctx
+        .get_form_answer(input.answer_id.into_entity())
+        .await
+        .context("Failed to get a form answer")?;
+    let mut answer = match result {
+        Some(answer) => answer,
+        None => return Err(UseCaseError::UseCase(Error::FormAnswerNotFound)),
+    };
+
+    let form = match ctx
+        .get_form(answer.form_id())
+        .await
+        .context("Failed to get a form")?
+    {
+        Some(form) => form,
+        None => return Err(use_case_internal!("Form answer refers to a missing form")),
+    };
+
+    if !form.access_control().allows_with_permission(
+        login_user,
+        AccessControlLevel::Review,
+        Permissions::REVIEW_FORM_ANSWERS,
+    ) {
+        return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
+    }
+
+    if !answer.is_visible_to_with_form(login_user, &form) {
+        return Err(UseCaseError::UseCase(Error::FormAnswerNotFound));
+    }
+
+    let comments = input
+        .comments
+        .into_iter()
+        .map(|InputComment { item_id, body }| {
+            let body = form_answer::review::FormAnswerReviewCommentBody::from_string(body)
+                .map_err(|_| Error::InvalidComment(item_id))?;
+            Ok(form_answer::review::FormAnswerReviewComment {
+                item_id: item_id.into_entity(),
+                body,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(UseCaseError::UseCase)?;
+    let comments = form_answer::FormAnswerReviewComments::from_comments(comments)
+        .map_err(|err| UseCaseError::UseCase(Error::from_comments_error(err)))?;
+
+    answer
+        .review_by(login_user, &form, input.status.into_entity(), comments)
+        .map_err(|err| UseCaseError::from_domain(err, Error::from_review_error))?;
+
+    ctx.store_form_answer(answer.clone())
+        .await
+        .context("Failed to store a form answer")?;
+    use_case_ensure!(answer.is_visible_to_with_form(login_user, &form));
+    Ok(FormAnswer::from_entity(answer))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::form::{FormId, FormItemId};
+    use crate::model::form_answer::{FormAnswerId, FormAnswerReviewStatus};
+    use crate::model::project::ProjectId;
+    use crate::{get_project_form_answer, review_form_answer, UseCaseError};
+    use sos21_domain::model::access_control::AccessControlLevel;
+    use sos21_domain::test;
+
+    #[tokio::test]
+    async fn test_committee() {
+        let user = test::model::new_general_user();
+        let committee = test::model::new_committee_user();
+        let project = test::model::new_general_project(user.id().clone());
+        let form = test::model::new_form(committee.id().clone());
+        let answer = test::model::new_form_answer(user.id().clone(), &project, &form);
+        let item_id = FormItemId::from_entity(form.items().items().next().unwrap().id);
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), committee.clone()])
+            .projects(vec![project.clone()])
+            .forms(vec![form.clone()])
+            .answers(vec![answer.clone()])
+            .build();
+
+        let input = review_form_answer::Input {
+            answer_id: FormAnswerId::from_entity(answer.id()),
+            status: FormAnswerReviewStatus::NeedsChanges,
+            comments: vec![review_form_answer::InputComment {
+                item_id,
+                body: "please fix this".to_owned(),
+            }],
+        };
+        let committee_app = app.clone().login_as(committee.clone()).await;
+        let got = review_form_answer::run(&committee_app, input)
+            .await
+            .unwrap();
+        assert_eq!(got.review_status, FormAnswerReviewStatus::NeedsChanges);
+
+        // The project can see the status and comments of the review.
+        let user_app = app.login_as(user.clone()).await;
+        let got = get_project_form_answer::run(
+            &user_app,
+            ProjectId::from_entity(project.id()),
+            FormId::from_entity(form.id()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(got.review_status, FormAnswerReviewStatus::NeedsChanges);
+        assert_eq!(got.review_comments.len(), 1);
+        assert_eq!(got.review_comments[0].item_id, item_id);
+    }
+
+    #[tokio::test]
+    async fn test_general() {
+        let user = test::model::new_general_user();
+        let operator = test::model::new_operator_user();
+        let project = test::model::new_general_project(user.id().clone());
+        let form = test::model::new_form(operator.id().clone());
+        let answer = test::model::new_form_answer(user.id().clone(), &project, &form);
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), operator.clone()])
+            .projects(vec![project.clone()])
+            .forms(vec![form.clone()])
+            .answers(vec![answer.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let input = review_form_answer::Input {
+            answer_id: FormAnswerId::from_entity(answer.id()),
+            status: FormAnswerReviewStatus::Approved,
+            comments: Vec::new(),
+        };
+        assert!(matches!(
+            review_form_answer::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                review_form_answer::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_submitted_status() {
+        let user = test::model::new_general_user();
+        let committee = test::model::new_committee_user();
+        let project = test::model::new_general_project(user.id().clone());
+        let form = test::model::new_form(committee.id().clone());
+        let answer = test::model::new_form_answer(user.id().clone(), &project, &form);
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), committee.clone()])
+            .projects(vec![project.clone()])
+            .forms(vec![form.clone()])
+            .answers(vec![answer.clone()])
+            .build()
+            .login_as(committee.clone())
+            .await;
+
+        let input = review_form_answer::Input {
+            answer_id: FormAnswerId::from_entity(answer.id()),
+            status: FormAnswerReviewStatus::Submitted,
+            comments: Vec::new(),
+        };
+        assert!(matches!(
+            review_form_answer::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                review_form_answer::Error::InvalidStatus
+            ))
+        ));
+    }
+
+    // Checks that the normal user granted to review on the form can review its answers.
+    #[tokio::test]
+    async fn test_general_access_control() {
+        let user = test::model::new_general_user();
+        let admin = test::model::new_admin_user();
+        let other = test::model::new_general_user();
+        let project = test::model::new_general_project(other.id().clone());
+        let mut form = test::model::new_form(admin.id().clone());
+        form.set_access_control(
+            &admin,
+            test::model::new_user_access_control(user.id().clone(), AccessControlLevel::Review),
+        )
+        .unwrap();
+        let answer = test::model::new_form_answer(other.id().clone(), &project, &form);
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), admin, other])
+            .projects(vec![project])
+            .forms(vec![form])
+            .answers(vec![answer.clone()])
+            .build()
+            .login_as(user)
+            .await;
+
+        let input = review_form_answer::Input {
+            answer_id: FormAnswerId::from_entity(answer.id()),
+            status: FormAnswerReviewStatus::Approved,
+            comments: Vec::new(),
+        };
+        let got = review_form_answer::run(&app, input).await.unwrap();
+        assert_eq!(got.review_status, FormAnswerReviewStatus::Approved);
+    }
+}
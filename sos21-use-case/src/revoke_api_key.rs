@@ -0,0 +1,123 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::api_key::{ApiKey, ApiKeyId};
+
+use anyhow::Context;
+use sos21_domain::context::{ApiKeyRepository, Login};
+use sos21_domain::model::api_key;
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    NotFound,
+    AlreadyRevoked,
+    InsufficientPermissions,
+}
+
+impl Error {
+    fn from_revoke_error(err: api_key::RevokeApiKeyError) -> Self {
+        match err.kind() {
+            api_key::RevokeApiKeyErrorKind::InsufficientPermissions => {
+                Error::InsufficientPermissions
+            }
+            api_key::RevokeApiKeyErrorKind::AlreadyRevoked => Error::AlreadyRevoked,
+        }
+    }
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, key_id: ApiKeyId) -> UseCaseResult<ApiKey, Error>
+where
+    C: ApiKeyRepository + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    let key = ctx
+        .get_api_key(key_id.into_entity())
+        .await
+        .context("Failed to get an API key")?;
+    let mut key = match key {
+        Some(key) if key.is_visible_to(login_user) => key,
+        _ => return Err(UseCaseError::UseCase(Error::NotFound)),
+    };
+
+    key.revoke(login_user)
+        .map_err(|err| UseCaseError::UseCase(Error::from_revoke_error(err)))?;
+    ctx.store_api_key(key.clone())
+        .await
+        .context("Failed to store a revoked API key")?;
+
+    use_case_ensure!(key.is_visible_to(login_user));
+    Ok(ApiKey::from_entity(key))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::api_key::ApiKeyId;
+    use crate::{revoke_api_key, UseCaseError};
+    use sos21_domain::context::{login::LoginError, Login};
+    use sos21_domain::model::{api_key::ApiKeyScope, user::UserRole};
+    use sos21_domain::test;
+
+    #[tokio::test]
+    async fn test_operator() {
+        let admin = test::model::new_admin_user();
+        let operator = test::model::new_operator_user();
+        let (key, _) =
+            test::model::new_api_key(admin.id().clone(), ApiKeyScope::Role(UserRole::Committee));
+
+        let app = test::build_mock_app()
+            .users(vec![admin, operator.clone()])
+            .api_keys(vec![key.clone()])
+            .build()
+            .login_as(operator)
+            .await;
+
+        assert!(matches!(
+            revoke_api_key::run(&app, ApiKeyId::from_entity(key.id())).await,
+            Err(UseCaseError::UseCase(revoke_api_key::Error::NotFound))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_admin() {
+        let admin = test::model::new_admin_user();
+        let (key, token) =
+            test::model::new_api_key(admin.id().clone(), ApiKeyScope::Role(UserRole::Committee));
+
+        let app = test::build_mock_app()
+            .users(vec![admin.clone()])
+            .api_keys(vec![key.clone()])
+            .build();
+        let login = app.clone().login_as(admin).await;
+
+        let revoked = revoke_api_key::run(&login, ApiKeyId::from_entity(key.id()))
+            .await
+            .unwrap();
+        assert!(revoked.revoked_at.is_some());
+        assert!(matches!(
+            Login::with_api_key(app, &token).await,
+            Err(LoginError::InvalidApiKey)
+        ));
+
+        assert!(matches!(
+            revoke_api_key::run(&login, ApiKeyId::from_entity(key.id())).await,
+            Err(UseCaseError::UseCase(revoke_api_key::Error::AlreadyRevoked))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_not_found() {
+        let admin = test::model::new_admin_user();
+
+        let app = test::build_mock_app()
+            .users(vec![admin.clone()])
+            .build()
+            .login_as(admin)
+            .await;
+
+        let id = ApiKeyId::from_entity(test::model::new_api_key_id());
+        assert!(matches!(
+            revoke_api_key::run(&app, id).await,
+            Err(UseCaseError::UseCase(revoke_api_key::Error::NotFound))
+        ));
+    }
+}
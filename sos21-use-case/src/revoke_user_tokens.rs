@@ -0,0 +1,87 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::user::{User, UserId};
+
+use anyhow::Context;
+use sos21_domain::context::{Login, UserRepository};
+use sos21_domain::model::user;
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    UserNotFound,
+    InsufficientPermissions,
+}
+
+impl Error {
+    fn from_update_error(_err: user::NoUpdatePermissionError) -> Self {
+        Error::InsufficientPermissions
+    }
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, user_id: UserId) -> UseCaseResult<User, Error>
+where
+    C: UserRepository + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    let user = ctx
+        .get_user(user_id.into_entity())
+        .await
+        .context("Failed to get a user")?;
+    let mut user = match user {
+        Some(user) if user.is_visible_to(login_user) => user,
+        _ => return Err(UseCaseError::UseCase(Error::UserNotFound)),
+    };
+
+    user.revoke_tokens(login_user)
+        .map_err(|err| UseCaseError::UseCase(Error::from_update_error(err)))?;
+    ctx.store_user(user.clone())
+        .await
+        .context("Failed to store a user")?;
+    use_case_ensure!(user.is_visible_to(login_user));
+    Ok(User::from_entity(user))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::user::UserId;
+    use crate::{revoke_user_tokens, UseCaseError};
+    use sos21_domain::test;
+
+    #[tokio::test]
+    async fn test_operator() {
+        let operator = test::model::new_operator_user();
+        let user = test::model::new_general_user();
+
+        let app = test::build_mock_app()
+            .users(vec![operator.clone(), user.clone()])
+            .build()
+            .login_as(operator)
+            .await;
+
+        let user_id = UserId::from_entity(user.id().clone());
+        assert!(matches!(
+            revoke_user_tokens::run(&app, user_id).await,
+            Err(UseCaseError::UseCase(
+                revoke_user_tokens::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_admin() {
+        let admin = test::model::new_admin_user();
+        let user = test::model::new_general_user();
+
+        let app = test::build_mock_app()
+            .users(vec![admin.clone(), user.clone()])
+            .build()
+            .login_as(admin)
+            .await;
+
+        let user_id = UserId::from_entity(user.id().clone());
+        let got = revoke_user_tokens::run(&app, user_id).await.unwrap();
+        assert!(got.tokens_revoked_at.is_some());
+        assert!(got.suspension.is_none());
+    }
+}
@@ -0,0 +1,211 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::interface;
+use crate::model::pending_project::PendingProjectId;
+use crate::model::registration_form::RegistrationFormId;
+use crate::model::registration_form_answer::RegistrationFormAnswerDraft;
+
+use anyhow::Context;
+use sos21_domain::context::{
+    ConfigContext, FileRepository, FileSharingRepository, Login, PendingProjectRepository,
+    RegistrationFormAnswerRepository, RegistrationFormRepository,
+};
+use sos21_domain::model::{permissions, registration_form_answer::draft, user};
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub pending_project_id: PendingProjectId,
+    pub registration_form_id: RegistrationFormId,
+    pub items: Vec<interface::form_answer::InputFormAnswerItem>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    PendingProjectNotFound,
+    RegistrationFormNotFound,
+    AlreadyAnswered,
+    OutOfProjectCreationPeriod,
+    InvalidItems(interface::form_answer::FormAnswerItemsError),
+    InsufficientPermissions,
+}
+
+impl Error {
+    fn from_permissions_error(_err: user::RequirePermissionsError) -> Self {
+        Error::InsufficientPermissions
+    }
+
+    fn from_items_error(err: interface::form_answer::FormAnswerItemsError) -> Self {
+        Error::InvalidItems(err)
+    }
+
+    fn from_save_error(err: draft::SaveError) -> Self {
+        match err.kind() {
+            draft::SaveErrorKind::NotTargeted => Error::RegistrationFormNotFound,
+            draft::SaveErrorKind::AlreadyAnswered => Error::AlreadyAnswered,
+            draft::SaveErrorKind::OutOfProjectCreationPeriod => Error::OutOfProjectCreationPeriod,
+        }
+    }
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(
+    ctx: &Login<C>,
+    input: Input,
+) -> UseCaseResult<RegistrationFormAnswerDraft, Error>
+where
+    C: PendingProjectRepository
+        + RegistrationFormRepository
+        + RegistrationFormAnswerRepository
+        + FileRepository
+        + FileSharingRepository
+        + ConfigContext
+        + Send
+        + Sync,
+{
+    let login_user = ctx.login_user();
+
+    login_user
+        .require_permissions(
+            permissions::Permissions::ANSWER_REGISTRATION_FORMS
+                | permissions::Permissions::SHARE_FILES,
+        )
+        .map_err(|err| UseCaseError::UseCase(Error::from_permissions_error(err)))?;
+
+    let result = ctx
+        .get_pending_project(input.pending_project_id.into_entity())
+        .await
+        .context("Failed to get a pending project")?;
+    let pending_project = match result {
+        Some(result) if result.pending_project.is_visible_to(login_user) => result.pending_project,
+        _ => return Err(UseCaseError::UseCase(Error::PendingProjectNotFound)),
+    };
+
+    let result = ctx
+        .get_registration_form(input.registration_form_id.into_entity())
+        .await
+        .context("Failed to get a registration form")?;
+    let registration_form = match result {
+        Some(form) if form.is_visible_to_with_pending_project(login_user, &pending_project) => form,
+        _ => return Err(UseCaseError::UseCase(Error::RegistrationFormNotFound)),
+    };
+
+    let result = ctx
+        .get_registration_form_answer_draft(registration_form.id(), pending_project.id())
+        .await
+        .context("Failed to get a registration form answer draft")?;
+    let drafted = match &result {
+        Some(draft) => draft.file_sharings(),
+        None => &[],
+    };
+
+    let (items, file_sharings) = interface::form_answer::to_registration_form_answer_draft_items(
+        ctx,
+        &pending_project,
+        &registration_form,
+        drafted,
+        input.items,
+    )
+    .await
+    .map_err(|err| err.map_use_case(Error::from_items_error))?;
+
+    let draft = draft::RegistrationFormAnswerDraft::save(
+        ctx,
+        login_user,
+        &pending_project,
+        &registration_form,
+        items,
+        file_sharings,
+    )
+    .await
+    .map_err(|err| UseCaseError::from_domain(err, Error::from_save_error))?;
+    ctx.store_registration_form_answer_draft(draft.clone())
+        .await
+        .context("Failed to store a registration form answer draft")?;
+    use_case_ensure!(draft.is_visible_to_with_pending_project(login_user, &pending_project));
+    Ok(RegistrationFormAnswerDraft::from_entity(draft))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{
+        form::item::FormItemId, pending_project::PendingProjectId,
+        registration_form::RegistrationFormId,
+    };
+    use crate::test::interface as test_interface;
+    use crate::{
+        get_pending_project_registration_form_answer_draft, interface,
+        save_pending_project_registration_form_answer_draft, UseCaseError,
+    };
+
+    use sos21_domain::test;
+
+    #[tokio::test]
+    async fn test_save_partial() {
+        let user = test::model::new_general_user();
+        let operator = test::model::new_operator_user();
+        let pending_project = test::model::new_general_pending_project(user.id().clone());
+        let registration_form = test::model::new_registration_form(operator.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), operator.clone()])
+            .pending_projects(vec![pending_project.clone()])
+            .registration_forms(vec![registration_form.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let registration_form_id = RegistrationFormId::from_entity(registration_form.id);
+        let pending_project_id = PendingProjectId::from_entity(pending_project.id());
+        let items = registration_form
+            .items
+            .items()
+            .map(|item| interface::form_answer::InputFormAnswerItem {
+                item_id: FormItemId::from_entity(item.id),
+                body: None,
+            })
+            .collect();
+        let input = save_pending_project_registration_form_answer_draft::Input {
+            registration_form_id,
+            pending_project_id,
+            items,
+        };
+        let saved = save_pending_project_registration_form_answer_draft::run(&app, input)
+            .await
+            .unwrap();
+        assert!(saved.items.iter().all(|item| item.body.is_none()));
+
+        assert!(matches!(
+            get_pending_project_registration_form_answer_draft::run(&app, pending_project_id, registration_form_id).await,
+            Ok(draft)
+            if draft.registration_form_id == registration_form_id
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_save_other() {
+        let user = test::model::new_general_user();
+        let other = test::model::new_general_user();
+        let operator = test::model::new_operator_user();
+        let pending_project = test::model::new_general_pending_project(other.id().clone());
+        let registration_form = test::model::new_registration_form(operator.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), other.clone(), operator.clone()])
+            .pending_projects(vec![pending_project.clone()])
+            .registration_forms(vec![registration_form.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let input = save_pending_project_registration_form_answer_draft::Input {
+            registration_form_id: RegistrationFormId::from_entity(registration_form.id),
+            pending_project_id: PendingProjectId::from_entity(pending_project.id()),
+            items: test_interface::mock_input_form_answer_items(&registration_form.items),
+        };
+        assert!(matches!(
+            save_pending_project_registration_form_answer_draft::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                save_pending_project_registration_form_answer_draft::Error::RegistrationFormNotFound
+            ))
+        ));
+    }
+}
@@ -0,0 +1,235 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::interface;
+use crate::model::form::FormId;
+use crate::model::form_answer::FormAnswerDraft;
+use crate::model::project::ProjectId;
+
+use anyhow::Context;
+use sos21_domain::context::{
+    FileRepository, FileSharingRepository, FormAnswerRepository, FormRepository, Login,
+    ProjectRepository,
+};
+use sos21_domain::model::{date_time::DateTime, form_answer::draft};
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub project_id: ProjectId,
+    pub form_id: FormId,
+    pub items: Vec<interface::form_answer::InputFormAnswerItem>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    ProjectNotFound,
+    FormNotFound,
+    OutOfAnswerPeriod,
+    AlreadyAnswered,
+    InvalidItems(interface::form_answer::FormAnswerItemsError),
+}
+
+impl Error {
+    fn from_items_error(err: interface::form_answer::FormAnswerItemsError) -> Self {
+        Error::InvalidItems(err)
+    }
+
+    fn from_save_error(err: draft::SaveError) -> Self {
+        match err.kind() {
+            draft::SaveErrorKind::OutOfAnswerPeriod => Error::OutOfAnswerPeriod,
+            draft::SaveErrorKind::NotTargeted => Error::FormNotFound,
+            draft::SaveErrorKind::AlreadyAnswered => Error::AlreadyAnswered,
+        }
+    }
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<FormAnswerDraft, Error>
+where
+    C: ProjectRepository
+        + FormRepository
+        + FormAnswerRepository
+        + FileRepository
+        + FileSharingRepository
+        + Send
+        + Sync,
+{
+    let login_user = ctx.login_user();
+
+    let result = ctx
+        .get_project(input.project_id.into_entity())
+        .await
+        .context("Failed to get a project")?;
+    let project = match result {
+        Some(result) if result.project.is_visible_to(login_user) => result.project,
+        _ => return Err(UseCaseError::UseCase(Error::ProjectNotFound)),
+    };
+
+    let result = ctx
+        .get_form(input.form_id.into_entity())
+        .await
+        .context("Failed to get a form")?;
+    let form = match result {
+        Some(form) if form.is_visible_to_with_project(login_user, &project) => form,
+        _ => return Err(UseCaseError::UseCase(Error::FormNotFound)),
+    };
+
+    // NOTE: Check the answer period before the conversion not to share files uselessly
+    if !form.period().contains(DateTime::now()) {
+        return Err(UseCaseError::UseCase(Error::OutOfAnswerPeriod));
+    }
+
+    let result = ctx
+        .get_form_answer_draft(form.id(), project.id())
+        .await
+        .context("Failed to get a form answer draft")?;
+    let drafted = match &result {
+        Some(draft) => draft.file_sharings(),
+        None => &[],
+    };
+
+    let (items, file_sharings) = interface::form_answer::to_form_answer_draft_items(
+        ctx,
+        &project,
+        &form,
+        drafted,
+        input.items,
+    )
+    .await
+    .map_err(|err| err.map_use_case(Error::from_items_error))?;
+
+    let draft =
+        draft::FormAnswerDraft::save(ctx, login_user, &project, &form, items, file_sharings)
+            .await
+            .map_err(|err| UseCaseError::from_domain(err, Error::from_save_error))?;
+    ctx.store_form_answer_draft(draft.clone())
+        .await
+        .context("Failed to store a form answer draft")?;
+    use_case_ensure!(draft.is_visible_to_with_project(login_user, &project));
+    Ok(FormAnswerDraft::from_entity(draft))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{
+        form::{item::FormItemId, FormId},
+        project::ProjectId,
+    };
+    use crate::test::interface as test_interface;
+    use crate::{
+        get_project_form_answer_draft, interface, save_project_form_answer_draft, UseCaseError,
+    };
+
+    use sos21_domain::test;
+
+    #[tokio::test]
+    async fn test_save_partial() {
+        let user = test::model::new_general_user();
+        let operator = test::model::new_operator_user();
+        let project = test::model::new_general_project(user.id().clone());
+        let form = test::model::new_form(operator.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), operator.clone()])
+            .projects(vec![project.clone()])
+            .forms(vec![form.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let form_id = FormId::from_entity(form.id());
+        let project_id = ProjectId::from_entity(project.id());
+        let items: Vec<_> = form
+            .items()
+            .items()
+            .map(|item| interface::form_answer::InputFormAnswerItem {
+                item_id: FormItemId::from_entity(item.id),
+                body: None,
+            })
+            .collect();
+        let input = save_project_form_answer_draft::Input {
+            form_id,
+            project_id,
+            items,
+        };
+        save_project_form_answer_draft::run(&app, input)
+            .await
+            .unwrap();
+
+        let input = save_project_form_answer_draft::Input {
+            form_id,
+            project_id,
+            items: test_interface::mock_input_form_answer_items(form.items()),
+        };
+        let saved = save_project_form_answer_draft::run(&app, input)
+            .await
+            .unwrap();
+        assert!(saved.items.iter().all(|item| item.body.is_some()));
+
+        assert!(matches!(
+            get_project_form_answer_draft::run(&app, project_id, form_id).await,
+            Ok(draft)
+            if draft.items.len() == saved.items.len()
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_save_other() {
+        let user = test::model::new_general_user();
+        let other = test::model::new_general_user();
+        let operator = test::model::new_operator_user();
+        let project = test::model::new_general_project(other.id().clone());
+        let form = test::model::new_form(operator.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), other.clone(), operator.clone()])
+            .projects(vec![project.clone()])
+            .forms(vec![form.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let input = save_project_form_answer_draft::Input {
+            form_id: FormId::from_entity(form.id()),
+            project_id: ProjectId::from_entity(project.id()),
+            items: test_interface::mock_input_form_answer_items(form.items()),
+        };
+        assert!(matches!(
+            save_project_form_answer_draft::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                save_project_form_answer_draft::Error::ProjectNotFound
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_draft_invisible_to_committee() {
+        let user = test::model::new_general_user();
+        let operator = test::model::new_operator_user();
+        let project = test::model::new_general_project(user.id().clone());
+        let form = test::model::new_form(operator.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), operator.clone()])
+            .projects(vec![project.clone()])
+            .forms(vec![form.clone()])
+            .build();
+
+        let form_id = FormId::from_entity(form.id());
+        let project_id = ProjectId::from_entity(project.id());
+        let input = save_project_form_answer_draft::Input {
+            form_id,
+            project_id,
+            items: test_interface::mock_input_form_answer_items(form.items()),
+        };
+        save_project_form_answer_draft::run(&app.clone().login_as(user.clone()).await, input)
+            .await
+            .unwrap();
+
+        let app = app.login_as(operator.clone()).await;
+        assert!(matches!(
+            get_project_form_answer_draft::run(&app, project_id, form_id).await,
+            Err(UseCaseError::UseCase(
+                get_project_form_answer_draft::Error::FormAnswerDraftNotFound
+            ))
+        ));
+    }
+}
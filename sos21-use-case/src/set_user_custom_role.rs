@@ -0,0 +1,160 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::custom_role::CustomRoleId;
+use crate::model::user::{User, UserId};
+
+use anyhow::Context;
+use sos21_domain::context::{CustomRoleRepository, Login, UserRepository};
+use sos21_domain::model::{permissions::Permissions, user};
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub user_id: UserId,
+    /// The custom role to assign, or `None` to unassign the current one.
+    pub custom_role_id: Option<CustomRoleId>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    UserNotFound,
+    CustomRoleNotFound,
+    InsufficientPermissions,
+}
+
+impl Error {
+    fn from_permissions_error(_err: user::RequirePermissionsError) -> Self {
+        Error::InsufficientPermissions
+    }
+
+    fn from_update_error(_err: user::NoUpdatePermissionError) -> Self {
+        Error::InsufficientPermissions
+    }
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<User, Error>
+where
+    C: CustomRoleRepository + UserRepository + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    login_user
+        .require_permissions(Permissions::UPDATE_ALL_USERS | Permissions::UPDATE_CUSTOM_ROLES)
+        .map_err(|err| UseCaseError::UseCase(Error::from_permissions_error(err)))?;
+
+    let user = ctx
+        .get_user(input.user_id.into_entity())
+        .await
+        .context("Failed to get a user")?;
+    let mut user = match user {
+        Some(user) => user,
+        None => return Err(UseCaseError::UseCase(Error::UserNotFound)),
+    };
+
+    let role = if let Some(custom_role_id) = input.custom_role_id {
+        let result = ctx
+            .get_custom_role(custom_role_id.into_entity())
+            .await
+            .context("Failed to get a custom role")?;
+        match result {
+            Some(role) if role.is_visible_to(login_user) => Some(role),
+            _ => return Err(UseCaseError::UseCase(Error::CustomRoleNotFound)),
+        }
+    } else {
+        None
+    };
+
+    user.set_custom_role(login_user, role.as_ref())
+        .map_err(|err| UseCaseError::UseCase(Error::from_update_error(err)))?;
+    ctx.store_user(user.clone())
+        .await
+        .context("Failed to store a user")?;
+    use_case_ensure!(user.is_visible_to(login_user));
+    Ok(User::from_entity(user))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{custom_role::CustomRoleId, user::UserId};
+    use crate::{set_user_custom_role, UseCaseError};
+    use sos21_domain::context::UserRepository;
+    use sos21_domain::model::permissions::Permissions;
+    use sos21_domain::test;
+
+    #[tokio::test]
+    async fn test_operator() {
+        let admin = test::model::new_admin_user();
+        let operator = test::model::new_operator_user();
+        let user = test::model::new_general_user();
+        let role = test::model::new_custom_role(admin.id().clone(), Permissions::READ_ALL_USERS);
+
+        let app = test::build_mock_app()
+            .users(vec![admin, operator.clone(), user.clone()])
+            .custom_roles(vec![role.clone()])
+            .build()
+            .login_as(operator)
+            .await;
+
+        let input = set_user_custom_role::Input {
+            user_id: UserId::from_entity(user.id().clone()),
+            custom_role_id: Some(CustomRoleId::from_entity(role.id())),
+        };
+        assert!(matches!(
+            set_user_custom_role::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                set_user_custom_role::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_admin() {
+        let admin = test::model::new_admin_user();
+        let user = test::model::new_general_user();
+        let role = test::model::new_custom_role(admin.id().clone(), Permissions::READ_ALL_USERS);
+
+        let app = test::build_mock_app()
+            .users(vec![admin.clone(), user.clone()])
+            .custom_roles(vec![role.clone()])
+            .build()
+            .login_as(admin)
+            .await;
+
+        let input = set_user_custom_role::Input {
+            user_id: UserId::from_entity(user.id().clone()),
+            custom_role_id: Some(CustomRoleId::from_entity(role.id())),
+        };
+        assert!(matches!(
+            set_user_custom_role::run(&app, input).await,
+            Ok(got)
+            if got.custom_role_id == Some(CustomRoleId::from_entity(role.id()))
+        ));
+
+        let user = app.get_user(user.id().clone()).await.unwrap().unwrap();
+        assert!(user
+            .require_permissions(Permissions::READ_ALL_USERS)
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_admin_role_not_found() {
+        let admin = test::model::new_admin_user();
+        let user = test::model::new_general_user();
+
+        let app = test::build_mock_app()
+            .users(vec![admin.clone(), user.clone()])
+            .build()
+            .login_as(admin)
+            .await;
+
+        let input = set_user_custom_role::Input {
+            user_id: UserId::from_entity(user.id().clone()),
+            custom_role_id: Some(CustomRoleId::from_entity(test::model::new_custom_role_id())),
+        };
+        assert!(matches!(
+            set_user_custom_role::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                set_user_custom_role::Error::CustomRoleNotFound
+            ))
+        ));
+    }
+}
@@ -5,7 +5,7 @@ use anyhow::Context;
 use sos21_domain::context::{
     Authentication, ConfigContext, UserInvitationRepository, UserRepository,
 };
-use sos21_domain::model::{phone_number, user};
+use sos21_domain::model::{phone_number, user, user_invitation};
 
 #[derive(Debug, Clone)]
 pub enum Error {
@@ -75,13 +75,35 @@ where
     ctx.store_user(user.clone())
         .await
         .context("Failed to create a user")?;
+
+    if let Some(mut invitation) = ctx
+        .get_user_invitation_by_email(user.email())
+        .await
+        .context("Failed to get user invitation")?
+    {
+        if invitation.status() == user_invitation::UserInvitationStatus::Pending {
+            invitation
+                .accept(&user)
+                .context("Failed to accept user invitation")?;
+            ctx.store_user_invitation(invitation)
+                .await
+                .context("Failed to store user invitation")?;
+        }
+    }
+
     Ok(User::from_entity(user))
 }
 
 #[cfg(test)]
 mod tests {
     use crate::model::user::{UserCategory, UserId, UserKanaName, UserName, UserRole};
-    use crate::{signup, UseCaseError};
+    use crate::model::user_invitation::UserInvitationId;
+    use crate::{delete_user_invitation, signup, UseCaseError};
+    use sos21_domain::context::authentication::{Authentication, AuthenticationError};
+    use sos21_domain::context::UserInvitationRepository;
+    use sos21_domain::model::date_time::DateTime;
+    use sos21_domain::model::user::email::{AllowedEmailDomains, EmailDomainRule};
+    use sos21_domain::model::user_invitation::{UserInvitation, UserInvitationStatus};
     use sos21_domain::test;
 
     fn mock_input() -> signup::Input {
@@ -104,7 +126,8 @@ mod tests {
 
         let app = test::build_mock_app()
             .build()
-            .authenticate_as(user_id.clone(), email);
+            .authenticate_as(user_id.clone(), email)
+            .await;
 
         let input = mock_input();
         assert!(matches!(
@@ -123,7 +146,8 @@ mod tests {
         let app = test::build_mock_app()
             .users(vec![user.clone()])
             .build()
-            .authenticate_as(user.id().clone().0, user.email().clone().into_string());
+            .authenticate_as(user.id().clone().0, user.email().clone().into_string())
+            .await;
 
         let input = mock_input();
         assert!(matches!(
@@ -140,10 +164,11 @@ mod tests {
 
         let app = test::build_mock_app()
             .build()
-            .authenticate_as(user_id, email);
+            .authenticate_as(user_id, email)
+            .await;
 
         let input = mock_input();
-        assert!(matches!(signup::run(&app, input.clone()).await, Ok(_)));
+        assert!(signup::run(&app, input.clone()).await.is_ok());
         assert!(matches!(
             signup::run(&app, input).await,
             Err(UseCaseError::UseCase(signup::Error::AlreadySignedUp))
@@ -157,20 +182,61 @@ mod tests {
         let email = "test@s.tsukuba.ac.jp".to_string();
         let invitation =
             test::model::new_operator_user_invitation(admin.id().clone(), email.clone());
+        let invitation_id = invitation.id();
 
         let app = test::build_mock_app()
             .users(vec![admin])
             .user_invitations(vec![invitation])
             .build()
-            .authenticate_as(user_id.clone(), email);
+            .authenticate_as(user_id.clone(), email)
+            .await;
 
         let input = mock_input();
         assert!(matches!(
             signup::run(&app, input).await,
             Ok(got)
-            if got.id == UserId(user_id)
+            if got.id == UserId(user_id.clone())
             && got.role == UserRole::CommitteeOperator
         ));
+
+        let invitation = app
+            .get_user_invitation(invitation_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(invitation.status(), UserInvitationStatus::Accepted);
+        assert_eq!(invitation.acceptance().unwrap().user_id.0, user_id);
+    }
+
+    #[tokio::test]
+    async fn test_expired_invitation() {
+        let admin = test::model::new_admin_user();
+        let user_id = "test_user_id".to_string();
+        let email = "test@s.tsukuba.ac.jp".to_string();
+        let invitation =
+            test::model::new_expired_user_invitation(admin.id().clone(), email.clone());
+
+        let app = test::build_mock_app()
+            .users(vec![admin])
+            .user_invitations(vec![invitation.clone()])
+            .build()
+            .authenticate_as(user_id.clone(), email)
+            .await;
+
+        let input = mock_input();
+        assert!(matches!(
+            signup::run(&app, input).await,
+            Ok(got)
+            if got.id == UserId(user_id)
+            && got.role == UserRole::General
+        ));
+
+        let invitation = app
+            .get_user_invitation(invitation.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(invitation.status(), UserInvitationStatus::Expired);
     }
 
     #[tokio::test]
@@ -180,7 +246,8 @@ mod tests {
 
         let app = test::build_mock_app()
             .build()
-            .authenticate_as(user_id.clone(), email);
+            .authenticate_as(user_id.clone(), email)
+            .await;
 
         let input = mock_input();
         assert!(matches!(
@@ -190,4 +257,105 @@ mod tests {
             && got.role == UserRole::Administrator
         ));
     }
+
+    #[tokio::test]
+    async fn test_unlisted_domain() {
+        let app = test::build_mock_app().build();
+
+        assert!(matches!(
+            Authentication::new(
+                app,
+                "test_user_id".to_string(),
+                "test@example.com".to_string(),
+                DateTime::now(),
+            )
+            .await,
+            Err(AuthenticationError::NotUniversityEmailAddress)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_configured_domain() {
+        let domains =
+            AllowedEmailDomains::from_rules(vec![
+                EmailDomainRule::from_string("example.com").unwrap()
+            ]);
+        let app = test::build_mock_app()
+            .allowed_email_domains(domains)
+            .build();
+
+        assert!(matches!(
+            Authentication::new(
+                app.clone(),
+                "test_user_id".to_string(),
+                "test@s.tsukuba.ac.jp".to_string(),
+                DateTime::now(),
+            )
+            .await,
+            Err(AuthenticationError::NotUniversityEmailAddress)
+        ));
+
+        let app = app
+            .authenticate_as("test_user_id".to_string(), "test@example.com".to_string())
+            .await;
+        assert!(signup::run(&app, mock_input()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_unlisted_domain_invitation() {
+        let admin = test::model::new_admin_user();
+        let user_id = "test_user_id".to_string();
+        let email = "test@example.com".to_string();
+        let mut invitation =
+            test::model::new_committee_user_invitation(admin.id().clone(), email.clone())
+                .into_content();
+        invitation.allows_unlisted_domain = true;
+
+        let app = test::build_mock_app()
+            .users(vec![admin])
+            .user_invitations(vec![UserInvitation::from_content(invitation)])
+            .build()
+            .authenticate_as(user_id.clone(), email)
+            .await;
+
+        let input = mock_input();
+        assert!(matches!(
+            signup::run(&app, input).await,
+            Ok(got)
+            if got.id == UserId(user_id)
+            && got.role == UserRole::Committee
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_unlisted_domain_invitation_deleted_after_signup() {
+        let admin = test::model::new_admin_user();
+        let user_id = "test_user_id".to_string();
+        let email = "test@example.com".to_string();
+        let mut invitation =
+            test::model::new_committee_user_invitation(admin.id().clone(), email.clone())
+                .into_content();
+        invitation.allows_unlisted_domain = true;
+        let invitation = UserInvitation::from_content(invitation);
+
+        let app = test::build_mock_app()
+            .users(vec![admin.clone()])
+            .user_invitations(vec![invitation.clone()])
+            .build();
+
+        let user_app = app
+            .clone()
+            .authenticate_as(user_id.clone(), email.clone())
+            .await;
+        assert!(signup::run(&user_app, mock_input()).await.is_ok());
+
+        let admin_app = app.clone().login_as(admin).await;
+        delete_user_invitation::run(&admin_app, UserInvitationId::from_entity(invitation.id()))
+            .await
+            .unwrap();
+
+        assert!(Authentication::new(app, user_id, email, DateTime::now())
+            .await
+            .is_ok());
+    }
 }
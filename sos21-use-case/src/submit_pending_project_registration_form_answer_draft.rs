@@ -0,0 +1,228 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::interface;
+use crate::model::form::item::FormItemId;
+use crate::model::pending_project::PendingProjectId;
+use crate::model::registration_form::RegistrationFormId;
+use crate::model::registration_form_answer::RegistrationFormAnswer;
+
+use anyhow::Context;
+use sos21_domain::context::{
+    ConfigContext, FileSharingRepository, Login, PendingProjectRepository,
+    RegistrationFormAnswerRepository, RegistrationFormRepository,
+};
+use sos21_domain::model::{permissions, registration_form, user};
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub pending_project_id: PendingProjectId,
+    pub registration_form_id: RegistrationFormId,
+}
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    PendingProjectNotFound,
+    RegistrationFormNotFound,
+    RegistrationFormAnswerDraftNotFound,
+    AlreadyAnswered,
+    OutOfProjectCreationPeriod,
+    InvalidAnswer(interface::form::CheckAnswerError),
+    InsufficientPermissions,
+}
+
+impl Error {
+    fn from_permissions_error(_err: user::RequirePermissionsError) -> Self {
+        Error::InsufficientPermissions
+    }
+
+    fn from_answer_error(err: registration_form::AnswerError) -> Self {
+        match err.kind() {
+            registration_form::AnswerErrorKind::NotTargeted => Error::RegistrationFormNotFound,
+            registration_form::AnswerErrorKind::AlreadyAnswered => Error::AlreadyAnswered,
+            registration_form::AnswerErrorKind::MismatchedItemsLength => {
+                Error::InvalidAnswer(interface::form::CheckAnswerError::MismatchedItemsLength)
+            }
+            registration_form::AnswerErrorKind::MismatchedItemId { expected, got } => {
+                Error::InvalidAnswer(interface::form::CheckAnswerError::MismatchedItemId {
+                    expected: FormItemId::from_entity(expected),
+                    got: FormItemId::from_entity(got),
+                })
+            }
+            registration_form::AnswerErrorKind::InvalidItem { id, kind } => {
+                Error::InvalidAnswer(interface::form::CheckAnswerError::InvalidAnswerItem {
+                    item_id: FormItemId::from_entity(id),
+                    item_error: interface::form::to_check_answer_item_error(kind),
+                })
+            }
+            registration_form::AnswerErrorKind::OutOfProjectCreationPeriod => {
+                Error::OutOfProjectCreationPeriod
+            }
+        }
+    }
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<RegistrationFormAnswer, Error>
+where
+    C: PendingProjectRepository
+        + RegistrationFormRepository
+        + RegistrationFormAnswerRepository
+        + FileSharingRepository
+        + ConfigContext
+        + Send
+        + Sync,
+{
+    let login_user = ctx.login_user();
+
+    login_user
+        .require_permissions(permissions::Permissions::ANSWER_REGISTRATION_FORMS)
+        .map_err(|err| UseCaseError::UseCase(Error::from_permissions_error(err)))?;
+
+    let result = ctx
+        .get_pending_project(input.pending_project_id.into_entity())
+        .await
+        .context("Failed to get a pending project")?;
+    let pending_project = match result {
+        Some(result) if result.pending_project.is_visible_to(login_user) => result.pending_project,
+        _ => return Err(UseCaseError::UseCase(Error::PendingProjectNotFound)),
+    };
+
+    let result = ctx
+        .get_registration_form(input.registration_form_id.into_entity())
+        .await
+        .context("Failed to get a registration form")?;
+    let registration_form = match result {
+        Some(form) if form.is_visible_to_with_pending_project(login_user, &pending_project) => form,
+        _ => return Err(UseCaseError::UseCase(Error::RegistrationFormNotFound)),
+    };
+
+    let result = ctx
+        .get_registration_form_answer_draft(registration_form.id(), pending_project.id())
+        .await
+        .context("Failed to get a registration form answer draft")?;
+    let draft = match result {
+        Some(draft) if draft.is_visible_to_with_pending_project(login_user, &pending_project) => {
+            draft
+        }
+        _ => {
+            return Err(UseCaseError::UseCase(
+                Error::RegistrationFormAnswerDraftNotFound,
+            ))
+        }
+    };
+
+    let (answer, sharings) = draft
+        .submit(ctx, login_user, &pending_project, &registration_form)
+        .await
+        .map_err(|err| UseCaseError::from_domain(err, Error::from_answer_error))?;
+    for sharing in sharings {
+        ctx.store_file_sharing(sharing)
+            .await
+            .context("Failed to store a file sharing")?;
+    }
+    ctx.store_registration_form_answer(answer.clone())
+        .await
+        .context("Failed to store a registration form answer")?;
+    ctx.delete_registration_form_answer_draft(registration_form.id(), pending_project.id())
+        .await
+        .context("Failed to delete a registration form answer draft")?;
+    use_case_ensure!(
+        answer.is_visible_to_with_pending_project(login_user, &pending_project)
+            || answer.is_visible_to_with_registration_form(login_user, &registration_form)
+    );
+    Ok(RegistrationFormAnswer::from_entity(answer))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{
+        pending_project::PendingProjectId, registration_form::RegistrationFormId,
+        registration_form_answer::RegistrationFormAnswerRespondent,
+    };
+    use crate::test::interface as test_interface;
+    use crate::{
+        get_pending_project_registration_form_answer,
+        get_pending_project_registration_form_answer_draft,
+        save_pending_project_registration_form_answer_draft,
+        submit_pending_project_registration_form_answer_draft, UseCaseError,
+    };
+
+    use sos21_domain::test;
+
+    #[tokio::test]
+    async fn test_submit() {
+        let user = test::model::new_general_user();
+        let operator = test::model::new_operator_user();
+        let pending_project = test::model::new_general_pending_project(user.id().clone());
+        let registration_form = test::model::new_registration_form(operator.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), operator.clone()])
+            .pending_projects(vec![pending_project.clone()])
+            .registration_forms(vec![registration_form.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let registration_form_id = RegistrationFormId::from_entity(registration_form.id);
+        let pending_project_id = PendingProjectId::from_entity(pending_project.id());
+        let input = save_pending_project_registration_form_answer_draft::Input {
+            registration_form_id,
+            pending_project_id,
+            items: test_interface::mock_input_form_answer_items(&registration_form.items),
+        };
+        save_pending_project_registration_form_answer_draft::run(&app, input)
+            .await
+            .unwrap();
+
+        let input = submit_pending_project_registration_form_answer_draft::Input {
+            registration_form_id,
+            pending_project_id,
+        };
+        let got = submit_pending_project_registration_form_answer_draft::run(&app, input)
+            .await
+            .unwrap();
+        assert!(got.registration_form_id == registration_form_id);
+        assert!(
+            got.respondent == RegistrationFormAnswerRespondent::PendingProject(pending_project_id)
+        );
+
+        assert!(matches!(
+            get_pending_project_registration_form_answer::run(&app, pending_project_id, registration_form_id).await,
+            Ok(answer)
+            if answer.id == got.id
+        ));
+        assert!(matches!(
+            get_pending_project_registration_form_answer_draft::run(&app, pending_project_id, registration_form_id).await,
+            Err(UseCaseError::UseCase(
+                get_pending_project_registration_form_answer_draft::Error::RegistrationFormAnswerDraftNotFound
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_submit_without_draft() {
+        let user = test::model::new_general_user();
+        let operator = test::model::new_operator_user();
+        let pending_project = test::model::new_general_pending_project(user.id().clone());
+        let registration_form = test::model::new_registration_form(operator.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), operator.clone()])
+            .pending_projects(vec![pending_project.clone()])
+            .registration_forms(vec![registration_form.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let input = submit_pending_project_registration_form_answer_draft::Input {
+            registration_form_id: RegistrationFormId::from_entity(registration_form.id),
+            pending_project_id: PendingProjectId::from_entity(pending_project.id()),
+        };
+        assert!(matches!(
+            submit_pending_project_registration_form_answer_draft::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                submit_pending_project_registration_form_answer_draft::Error::RegistrationFormAnswerDraftNotFound
+            ))
+        ));
+    }
+}
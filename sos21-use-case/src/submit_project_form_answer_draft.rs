@@ -0,0 +1,361 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::interface;
+use crate::model::form::{FormId, FormItemId};
+use crate::model::form_answer::FormAnswer;
+use crate::model::project::ProjectId;
+
+use anyhow::Context;
+use sos21_domain::context::{
+    ConfigContext, FileSharingRepository, FormAnswerRepository, FormRepository, Login,
+    ProjectRepository,
+};
+use sos21_domain::model::form;
+
+use sos21_gateway_slack as slack;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub project_id: ProjectId,
+    pub form_id: FormId,
+}
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    ProjectNotFound,
+    FormNotFound,
+    FormAnswerDraftNotFound,
+    OutOfAnswerPeriod,
+    AlreadyAnswered,
+    InvalidAnswer(interface::form::CheckAnswerError),
+    NotificationFailed,
+}
+
+impl Error {
+    fn from_answer_error(err: form::AnswerError) -> Self {
+        match err.kind() {
+            form::AnswerErrorKind::OutOfAnswerPeriod => Error::OutOfAnswerPeriod,
+            form::AnswerErrorKind::AlreadyAnswered => Error::AlreadyAnswered,
+            form::AnswerErrorKind::NotTargeted => Error::FormNotFound,
+            form::AnswerErrorKind::MismatchedItemsLength => {
+                Error::InvalidAnswer(interface::form::CheckAnswerError::MismatchedItemsLength)
+            }
+            form::AnswerErrorKind::MismatchedItemId { expected, got } => {
+                Error::InvalidAnswer(interface::form::CheckAnswerError::MismatchedItemId {
+                    expected: FormItemId::from_entity(expected),
+                    got: FormItemId::from_entity(got),
+                })
+            }
+            form::AnswerErrorKind::InvalidItem { id, kind } => {
+                Error::InvalidAnswer(interface::form::CheckAnswerError::InvalidAnswerItem {
+                    item_id: FormItemId::from_entity(id),
+                    item_error: interface::form::to_check_answer_item_error(kind),
+                })
+            }
+        }
+    }
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<FormAnswer, Error>
+where
+    C: ProjectRepository
+        + FormRepository
+        + FormAnswerRepository
+        + FileSharingRepository
+        + ConfigContext
+        + Send
+        + Sync,
+{
+    let login_user = ctx.login_user();
+
+    let result = ctx
+        .get_project(input.project_id.into_entity())
+        .await
+        .context("Failed to get a project")?;
+    let project = match result {
+        Some(result) if result.project.is_visible_to(login_user) => result.project,
+        _ => return Err(UseCaseError::UseCase(Error::ProjectNotFound)),
+    };
+
+    let result = ctx
+        .get_form(input.form_id.into_entity())
+        .await
+        .context("Failed to get a form")?;
+    let form = match result {
+        Some(form) if form.is_visible_to_with_project(login_user, &project) => form,
+        _ => return Err(UseCaseError::UseCase(Error::FormNotFound)),
+    };
+
+    let result = ctx
+        .get_form_answer_draft(form.id(), project.id())
+        .await
+        .context("Failed to get a form answer draft")?;
+    let draft = match result {
+        Some(draft) if draft.is_visible_to_with_project(login_user, &project) => draft,
+        _ => return Err(UseCaseError::UseCase(Error::FormAnswerDraftNotFound)),
+    };
+
+    let (answer, sharings) = draft
+        .submit(ctx, login_user, &project, &form)
+        .await
+        .map_err(|err| UseCaseError::from_domain(err, Error::from_answer_error))?;
+    for sharing in sharings {
+        ctx.store_file_sharing(sharing)
+            .await
+            .context("Failed to store a file sharing")?;
+    }
+    ctx.store_form_answer(answer.clone())
+        .await
+        .context("Failed to store a form answer")?;
+    ctx.delete_form_answer_draft(form.id(), project.id())
+        .await
+        .context("Failed to delete a form answer draft")?;
+    use_case_ensure!(
+        answer.is_visible_to_with_project(login_user, &project)
+            || answer.is_visible_to_with_form(login_user, &form)
+    );
+
+    // Notify Slack
+
+    if let Some(hook) = form.answer_notification_webhook() {
+        if slack::send_form_answer_notification(hook, project.name(), form.name()).is_err() {
+            return Err(UseCaseError::UseCase(Error::NotificationFailed));
+        };
+    }
+
+    Ok(FormAnswer::from_entity(answer))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{
+        file::FileId,
+        form::{item::FormItemId, FormId},
+        form_answer::item::FormAnswerItemBody,
+        project::ProjectId,
+    };
+    use crate::test::interface as test_interface;
+    use crate::{
+        get_project_form_answer, get_project_form_answer_draft,
+        get_project_form_answer_shared_file, interface, list_user_file_sharings,
+        save_project_form_answer_draft, submit_project_form_answer_draft, UseCaseError,
+    };
+
+    use sos21_domain::model::form::item;
+    use sos21_domain::test;
+
+    #[tokio::test]
+    async fn test_submit() {
+        let user = test::model::new_general_user();
+        let operator = test::model::new_operator_user();
+        let project = test::model::new_general_project(user.id().clone());
+        let form = test::model::new_form(operator.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), operator.clone()])
+            .projects(vec![project.clone()])
+            .forms(vec![form.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let form_id = FormId::from_entity(form.id());
+        let project_id = ProjectId::from_entity(project.id());
+        let input = save_project_form_answer_draft::Input {
+            form_id,
+            project_id,
+            items: test_interface::mock_input_form_answer_items(form.items()),
+        };
+        save_project_form_answer_draft::run(&app, input)
+            .await
+            .unwrap();
+
+        let input = submit_project_form_answer_draft::Input {
+            form_id,
+            project_id,
+        };
+        let got = submit_project_form_answer_draft::run(&app, input)
+            .await
+            .unwrap();
+        assert!(got.form_id == form_id);
+        assert!(got.project_id == project_id);
+
+        assert!(matches!(
+            get_project_form_answer::run(&app, project_id, form_id).await,
+            Ok(answer)
+            if answer.id == got.id
+        ));
+        assert!(matches!(
+            get_project_form_answer_draft::run(&app, project_id, form_id).await,
+            Err(UseCaseError::UseCase(
+                get_project_form_answer_draft::Error::FormAnswerDraftNotFound
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_submit_incomplete() {
+        let user = test::model::new_general_user();
+        let operator = test::model::new_operator_user();
+        let project = test::model::new_general_project(user.id().clone());
+        let form = test::model::new_form(operator.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), operator.clone()])
+            .projects(vec![project.clone()])
+            .forms(vec![form.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let form_id = FormId::from_entity(form.id());
+        let project_id = ProjectId::from_entity(project.id());
+        let items = form
+            .items()
+            .items()
+            .map(|item| interface::form_answer::InputFormAnswerItem {
+                item_id: FormItemId::from_entity(item.id),
+                body: None,
+            })
+            .collect();
+        let input = save_project_form_answer_draft::Input {
+            form_id,
+            project_id,
+            items,
+        };
+        save_project_form_answer_draft::run(&app, input)
+            .await
+            .unwrap();
+
+        let input = submit_project_form_answer_draft::Input {
+            form_id,
+            project_id,
+        };
+        assert!(matches!(
+            submit_project_form_answer_draft::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                submit_project_form_answer_draft::Error::InvalidAnswer(_)
+            ))
+        ));
+        assert!(
+            get_project_form_answer_draft::run(&app, project_id, form_id)
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_submit_without_draft() {
+        let user = test::model::new_general_user();
+        let operator = test::model::new_operator_user();
+        let project = test::model::new_general_project(user.id().clone());
+        let form = test::model::new_form(operator.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), operator.clone()])
+            .projects(vec![project.clone()])
+            .forms(vec![form.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let input = submit_project_form_answer_draft::Input {
+            form_id: FormId::from_entity(form.id()),
+            project_id: ProjectId::from_entity(project.id()),
+        };
+        assert!(matches!(
+            submit_project_form_answer_draft::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                submit_project_form_answer_draft::Error::FormAnswerDraftNotFound
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_file_shared_on_submit() {
+        let user = test::model::new_general_user();
+        let operator = test::model::new_operator_user();
+        let project = test::model::new_general_project(user.id().clone());
+
+        let (form, item_id) = {
+            let body = item::FormItemBody::File(item::FileFormItem {
+                types: None,
+                accept_multiple_files: false,
+                is_required: true,
+            });
+            let item = test::model::new_form_item_with_body(body);
+            let item_id = item.id;
+            let items = item::FormItems::from_items(vec![item]).unwrap();
+            let form = test::model::new_form_with_items(operator.id().clone(), items);
+            (form, item_id)
+        };
+        let (file, object) = test::model::new_file(user.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone(), operator.clone()])
+            .projects(vec![project.clone()])
+            .forms(vec![form.clone()])
+            .files(vec![file.clone()])
+            .objects(vec![object])
+            .await
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let form_id = FormId::from_entity(form.id());
+        let project_id = ProjectId::from_entity(project.id());
+        let input = save_project_form_answer_draft::Input {
+            form_id,
+            project_id,
+            items: vec![interface::form_answer::InputFormAnswerItem {
+                item_id: FormItemId::from_entity(item_id),
+                body: Some(interface::form_answer::InputFormAnswerItemBody::File(vec![
+                    interface::form_answer::InputFormAnswerItemFile::File(FileId::from_entity(
+                        file.id,
+                    )),
+                ])),
+            }],
+        };
+        let draft = save_project_form_answer_draft::run(&app, input)
+            .await
+            .unwrap();
+        let sharing_id = match &draft.items[0].body {
+            Some(FormAnswerItemBody::File(sharings)) => sharings[0],
+            _ => panic!("drafted form answer item is not file"),
+        };
+
+        // saving again with the drafted sharing ID keeps the same file attached
+        let input = save_project_form_answer_draft::Input {
+            form_id,
+            project_id,
+            items: vec![interface::form_answer::InputFormAnswerItem {
+                item_id: FormItemId::from_entity(item_id),
+                body: Some(interface::form_answer::InputFormAnswerItemBody::File(vec![
+                    interface::form_answer::InputFormAnswerItemFile::Sharing(sharing_id),
+                ])),
+            }],
+        };
+        save_project_form_answer_draft::run(&app, input)
+            .await
+            .unwrap();
+        assert!(list_user_file_sharings::run(&app).await.unwrap().is_empty());
+
+        let input = submit_project_form_answer_draft::Input {
+            form_id,
+            project_id,
+        };
+        submit_project_form_answer_draft::run(&app, input)
+            .await
+            .unwrap();
+        assert_eq!(list_user_file_sharings::run(&app).await.unwrap().len(), 1);
+        assert!(matches!(
+            get_project_form_answer_shared_file::run(&app, get_project_form_answer_shared_file::Input {
+                project_id,
+                form_id,
+                sharing_id
+            }).await,
+            Ok(got)
+            if got.id == FileId::from_entity(file.id)
+        ));
+    }
+}
@@ -0,0 +1,165 @@
+use std::convert::Infallible;
+
+use crate::error::UseCaseResult;
+use crate::model::user::UserId;
+
+use anyhow::Context;
+use sos21_domain::context::{ConfigContext, UserInvitationRepository, UserRepository};
+use sos21_domain::model::user;
+
+const SUSPICIOUS_EMAIL_REASON: &str =
+    "Automatically suspended for signing in with an email address out of the allowed domains";
+
+/// Suspends the user who signed in with `email` out of the allowed domains,
+/// unless the user has signed up with the address or the address is invited as an exception.
+/// Returns whether the user is newly suspended.
+///
+/// This is not performed on behalf of any user, but run by the server on the detection of
+/// a suspicious account.
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &C, user_id: UserId, email: String) -> UseCaseResult<bool, Infallible>
+where
+    C: UserRepository + UserInvitationRepository + ConfigContext + Send + Sync,
+{
+    if user::UserEmailAddress::from_string_with_domains(email.clone(), ctx.allowed_email_domains())
+        .is_ok()
+    {
+        return Ok(false);
+    }
+
+    let user = ctx
+        .get_user(user_id.into_entity())
+        .await
+        .context("Failed to get a user")?;
+    let mut user = match user {
+        Some(user) if !user.is_suspended() => user,
+        _ => return Ok(false),
+    };
+
+    // users who have signed up with the address are exempted as in `Authentication::new`,
+    // even after the domain is removed from the allowed domains
+    if user.email().as_str() == email {
+        return Ok(false);
+    }
+
+    if let Ok(email) = user::UserEmailAddress::from_string(email) {
+        let invitation = ctx
+            .get_user_invitation_by_email(&email)
+            .await
+            .context("Failed to get user invitation")?;
+        if matches!(invitation, Some(invitation) if invitation.allows_unlisted_domain() && !invitation.is_expired())
+        {
+            return Ok(false);
+        }
+    }
+
+    let reason = user::UserSuspensionReason::from_string(SUSPICIOUS_EMAIL_REASON)
+        .context("Invalid suspension reason")?;
+    user.suspend_automatically(reason)
+        .context("Failed to suspend a user")?;
+    ctx.store_user(user)
+        .await
+        .context("Failed to store a user")?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::user::UserId;
+    use crate::suspend_suspicious_user;
+    use sos21_domain::context::UserRepository;
+    use sos21_domain::model::user::email::{AllowedEmailDomains, EmailDomainRule};
+    use sos21_domain::model::user::{User, UserEmailAddress};
+    use sos21_domain::model::user_invitation::UserInvitation;
+    use sos21_domain::test;
+
+    fn user_with_email(email: &str) -> User {
+        let user = test::model::new_general_user();
+        let mut content = user.into_content();
+        content.email = UserEmailAddress::from_string(email).unwrap();
+        User::from_content(content)
+    }
+
+    #[tokio::test]
+    async fn test_suspicious() {
+        let user = test::model::new_general_user();
+
+        let app = test::build_mock_app().users(vec![user.clone()]).build();
+
+        let user_id = UserId::from_entity(user.id().clone());
+        let suspended =
+            suspend_suspicious_user::run(&app, user_id.clone(), "test@example.com".to_owned())
+                .await
+                .unwrap();
+        assert!(suspended);
+        let stored = app.get_user(user.id().clone()).await.unwrap().unwrap();
+        assert!(stored.is_suspended());
+        assert!(stored.suspension().unwrap().suspended_by.is_none());
+
+        // already suspended
+        assert!(
+            !suspend_suspicious_user::run(&app, user_id, "test@example.com".to_owned())
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_allowed_domain() {
+        let user = test::model::new_general_user();
+
+        let app = test::build_mock_app().users(vec![user.clone()]).build();
+
+        let user_id = UserId::from_entity(user.id().clone());
+        let email = user.email().clone().into_string();
+        assert!(!suspend_suspicious_user::run(&app, user_id, email)
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_invited() {
+        let admin = test::model::new_admin_user();
+        let user = user_with_email("test@example.com");
+        let mut invitation =
+            test::model::new_committee_user_invitation(admin.id().clone(), "test@example.com")
+                .into_content();
+        invitation.allows_unlisted_domain = true;
+        let invitation = UserInvitation::from_content(invitation);
+
+        let app = test::build_mock_app()
+            .users(vec![admin, user.clone()])
+            .user_invitations(vec![invitation])
+            .build();
+
+        let user_id = UserId::from_entity(user.id().clone());
+        assert!(
+            !suspend_suspicious_user::run(&app, user_id, "test@example.com".to_owned())
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_signed_up_with_removed_domain() {
+        let user = test::model::new_general_user();
+
+        // the domain of the address has been removed from the allowed domains after signup
+        let domains =
+            AllowedEmailDomains::from_rules(vec![
+                EmailDomainRule::from_string("example.com").unwrap()
+            ]);
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .allowed_email_domains(domains)
+            .build();
+
+        let user_id = UserId::from_entity(user.id().clone());
+        let email = user.email().clone().into_string();
+        assert!(!suspend_suspicious_user::run(&app, user_id, email)
+            .await
+            .unwrap());
+        let stored = app.get_user(user.id().clone()).await.unwrap().unwrap();
+        assert!(!stored.is_suspended());
+    }
+}
@@ -0,0 +1,171 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::user::{User, UserId};
+
+use anyhow::Context;
+use sos21_domain::context::{Login, UserRepository};
+use sos21_domain::model::user;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub user_id: UserId,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    UserNotFound,
+    InvalidReason,
+    SuspendingSelf,
+    AlreadySuspended,
+    InsufficientPermissions,
+}
+
+impl Error {
+    fn from_reason_error(_err: user::suspension::ReasonError) -> Self {
+        Error::InvalidReason
+    }
+
+    fn from_suspend_error(err: user::SuspendError) -> Self {
+        match err.kind() {
+            user::SuspendErrorKind::InsufficientPermissions => Error::InsufficientPermissions,
+            user::SuspendErrorKind::SuspendingSelf => Error::SuspendingSelf,
+            user::SuspendErrorKind::AlreadySuspended => Error::AlreadySuspended,
+        }
+    }
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<User, Error>
+where
+    C: UserRepository + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    let reason = user::UserSuspensionReason::from_string(input.reason)
+        .map_err(|err| UseCaseError::UseCase(Error::from_reason_error(err)))?;
+
+    let user = ctx
+        .get_user(input.user_id.into_entity())
+        .await
+        .context("Failed to get a user")?;
+    let mut user = match user {
+        Some(user) if user.is_visible_to(login_user) => user,
+        _ => return Err(UseCaseError::UseCase(Error::UserNotFound)),
+    };
+
+    user.suspend(login_user, reason)
+        .map_err(|err| UseCaseError::UseCase(Error::from_suspend_error(err)))?;
+    ctx.store_user(user.clone())
+        .await
+        .context("Failed to store a user")?;
+    use_case_ensure!(user.is_visible_to(login_user));
+    Ok(User::from_entity(user))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::user::UserId;
+    use crate::{suspend_user, UseCaseError};
+    use sos21_domain::context::login::LoginError;
+    use sos21_domain::context::{Authentication, Login, UserRepository};
+    use sos21_domain::model::date_time::DateTime;
+    use sos21_domain::test;
+
+    fn mock_input(user_id: UserId) -> suspend_user::Input {
+        suspend_user::Input {
+            user_id,
+            reason: "spam".to_owned(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_operator() {
+        let operator = test::model::new_operator_user();
+        let user = test::model::new_general_user();
+
+        let app = test::build_mock_app()
+            .users(vec![operator.clone(), user.clone()])
+            .build()
+            .login_as(operator)
+            .await;
+
+        let input = mock_input(UserId::from_entity(user.id().clone()));
+        assert!(matches!(
+            suspend_user::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                suspend_user::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_admin() {
+        let admin = test::model::new_admin_user();
+        let user = test::model::new_general_user();
+
+        let app = test::build_mock_app()
+            .users(vec![admin.clone(), user.clone()])
+            .build();
+        let login = app.clone().login_as(admin.clone()).await;
+
+        let input = mock_input(UserId::from_entity(user.id().clone()));
+        let got = suspend_user::run(&login, input).await.unwrap();
+        let suspension = got.suspension.unwrap();
+        assert_eq!(suspension.reason, "spam");
+        assert_eq!(
+            suspension.suspended_by,
+            Some(UserId::from_entity(admin.id().clone()))
+        );
+
+        let stored = app.get_user(user.id().clone()).await.unwrap().unwrap();
+        assert!(stored.is_suspended());
+
+        let auth = Authentication::new(
+            app,
+            user.id().clone().0,
+            user.email().clone().into_string(),
+            DateTime::now(),
+        )
+        .await
+        .unwrap();
+        assert!(matches!(Login::new(auth).await, Err(LoginError::Suspended)));
+    }
+
+    #[tokio::test]
+    async fn test_self() {
+        let admin = test::model::new_admin_user();
+
+        let app = test::build_mock_app()
+            .users(vec![admin.clone()])
+            .build()
+            .login_as(admin.clone())
+            .await;
+
+        let input = mock_input(UserId::from_entity(admin.id().clone()));
+        assert!(matches!(
+            suspend_user::run(&app, input).await,
+            Err(UseCaseError::UseCase(suspend_user::Error::SuspendingSelf))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_reason() {
+        let admin = test::model::new_admin_user();
+        let user = test::model::new_general_user();
+
+        let app = test::build_mock_app()
+            .users(vec![admin.clone(), user.clone()])
+            .build()
+            .login_as(admin)
+            .await;
+
+        let input = suspend_user::Input {
+            reason: "".to_owned(),
+            ..mock_input(UserId::from_entity(user.id().clone()))
+        };
+        assert!(matches!(
+            suspend_user::run(&app, input).await,
+            Err(UseCaseError::UseCase(suspend_user::Error::InvalidReason))
+        ));
+    }
+}
@@ -39,6 +39,12 @@ pub fn mock_input_form_answer_item_body(body: &item::FormItemBody) -> InputFormA
                 })
                 .collect(),
         ),
+        item::FormItemBody::Group(item) => InputFormAnswerItemBody::Group(
+            test_model::mock_form_answer_item_group(item)
+                .rows()
+                .map(|_| item.items().map(mock_input_form_answer_item).collect())
+                .collect(),
+        ),
     }
 }
 
@@ -0,0 +1,97 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::user::{User, UserId};
+
+use anyhow::Context;
+use sos21_domain::context::{Login, UserRepository};
+use sos21_domain::model::user;
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    UserNotFound,
+    NotSuspended,
+    InsufficientPermissions,
+}
+
+impl Error {
+    fn from_unsuspend_error(err: user::UnsuspendError) -> Self {
+        match err.kind() {
+            user::UnsuspendErrorKind::InsufficientPermissions => Error::InsufficientPermissions,
+            user::UnsuspendErrorKind::NotSuspended => Error::NotSuspended,
+        }
+    }
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, user_id: UserId) -> UseCaseResult<User, Error>
+where
+    C: UserRepository + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    let user = ctx
+        .get_user(user_id.into_entity())
+        .await
+        .context("Failed to get a user")?;
+    let mut user = match user {
+        Some(user) if user.is_visible_to(login_user) => user,
+        _ => return Err(UseCaseError::UseCase(Error::UserNotFound)),
+    };
+
+    user.unsuspend(login_user)
+        .map_err(|err| UseCaseError::UseCase(Error::from_unsuspend_error(err)))?;
+    ctx.store_user(user.clone())
+        .await
+        .context("Failed to store a user")?;
+    use_case_ensure!(user.is_visible_to(login_user));
+    Ok(User::from_entity(user))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::user::UserId;
+    use crate::{unsuspend_user, UseCaseError};
+    use sos21_domain::model::user::UserSuspensionReason;
+    use sos21_domain::test;
+
+    #[tokio::test]
+    async fn test_admin() {
+        let admin = test::model::new_admin_user();
+        let mut user = test::model::new_general_user();
+        user.suspend(&admin, UserSuspensionReason::from_string("spam").unwrap())
+            .unwrap();
+
+        let app = test::build_mock_app()
+            .users(vec![admin.clone(), user.clone()])
+            .build()
+            .login_as(admin)
+            .await;
+
+        let user_id = UserId::from_entity(user.id().clone());
+        let got = unsuspend_user::run(&app, user_id.clone()).await.unwrap();
+        assert!(got.suspension.is_none());
+        // the tokens revoked on the suspension remain rejected
+        assert!(got.tokens_revoked_at.is_some());
+
+        assert!(matches!(
+            unsuspend_user::run(&app, user_id).await,
+            Err(UseCaseError::UseCase(unsuspend_user::Error::NotSuspended))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_not_found() {
+        let admin = test::model::new_admin_user();
+
+        let app = test::build_mock_app()
+            .users(vec![admin.clone()])
+            .build()
+            .login_as(admin)
+            .await;
+
+        let user_id = UserId::from_entity(test::model::new_user_id());
+        assert!(matches!(
+            unsuspend_user::run(&app, user_id).await,
+            Err(UseCaseError::UseCase(unsuspend_user::Error::UserNotFound))
+        ));
+    }
+}
@@ -0,0 +1,152 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::interface;
+use crate::model::custom_role::{CustomRole, CustomRoleId};
+
+use anyhow::Context;
+use sos21_domain::context::{CustomRoleRepository, Login, UserRepository};
+use sos21_domain::model::{custom_role, user};
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub id: CustomRoleId,
+    pub name: Option<String>,
+    pub permissions: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    NotFound,
+    InvalidName,
+    UnknownPermission(String),
+    InsufficientPermissions,
+}
+
+impl Error {
+    fn from_name_error(_err: custom_role::name::NameError) -> Self {
+        Error::InvalidName
+    }
+
+    fn from_permissions_error(err: interface::permissions::UnknownPermissionError) -> Self {
+        Error::UnknownPermission(err.name)
+    }
+
+    fn from_update_error(_err: custom_role::NoUpdatePermissionError) -> Self {
+        Error::InsufficientPermissions
+    }
+
+    fn from_update_user_error(_err: user::NoUpdatePermissionError) -> Self {
+        Error::InsufficientPermissions
+    }
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<CustomRole, Error>
+where
+    C: CustomRoleRepository + UserRepository + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    let result = ctx
+        .get_custom_role(input.id.into_entity())
+        .await
+        .context("Failed to get a custom role")?;
+    let mut role = match result {
+        Some(role) if role.is_visible_to(login_user) => role,
+        _ => return Err(UseCaseError::UseCase(Error::NotFound)),
+    };
+
+    if let Some(name) = input.name {
+        let name = custom_role::CustomRoleName::from_string(name)
+            .map_err(|err| UseCaseError::UseCase(Error::from_name_error(err)))?;
+        role.set_name(login_user, name)
+            .map_err(|err| UseCaseError::UseCase(Error::from_update_error(err)))?;
+    }
+
+    if let Some(permissions) = input.permissions {
+        let permissions = interface::permissions::to_permissions(permissions)
+            .map_err(|err| UseCaseError::UseCase(Error::from_permissions_error(err)))?;
+        role.set_permissions(login_user, permissions)
+            .map_err(|err| UseCaseError::UseCase(Error::from_update_error(err)))?;
+    }
+
+    ctx.store_custom_role(role.clone())
+        .await
+        .context("Failed to store a custom role")?;
+
+    // Refresh the permissions held by the users assigned the role
+    let users = ctx.list_users().await.context("Failed to list users")?;
+    for mut user in users {
+        if user.custom_role().map(|custom_role| custom_role.id) != Some(role.id()) {
+            continue;
+        }
+
+        user.set_custom_role(login_user, Some(&role))
+            .map_err(|err| UseCaseError::UseCase(Error::from_update_user_error(err)))?;
+        ctx.store_user(user)
+            .await
+            .context("Failed to store a user")?;
+    }
+
+    Ok(CustomRole::from_entity(role))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::custom_role::CustomRoleId;
+    use crate::{update_custom_role, UseCaseError};
+    use sos21_domain::context::UserRepository;
+    use sos21_domain::model::permissions::Permissions;
+    use sos21_domain::test;
+
+    #[tokio::test]
+    async fn test_operator() {
+        let admin = test::model::new_admin_user();
+        let operator = test::model::new_operator_user();
+        let role = test::model::new_custom_role(admin.id().clone(), Permissions::READ_ALL_USERS);
+
+        let app = test::build_mock_app()
+            .users(vec![admin, operator.clone()])
+            .custom_roles(vec![role.clone()])
+            .build()
+            .login_as(operator)
+            .await;
+
+        let input = update_custom_role::Input {
+            id: CustomRoleId::from_entity(role.id()),
+            name: None,
+            permissions: Some(vec!["READ_ALL_USERS".to_owned()]),
+        };
+        assert!(matches!(
+            update_custom_role::run(&app, input).await,
+            Err(UseCaseError::UseCase(update_custom_role::Error::NotFound))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_admin_refreshes_users() {
+        let admin = test::model::new_admin_user();
+        let role = test::model::new_custom_role(admin.id().clone(), Permissions::READ_ALL_USERS);
+        let mut user = test::model::new_general_user();
+        user.set_custom_role(&admin, Some(&role)).unwrap();
+
+        let app = test::build_mock_app()
+            .users(vec![admin.clone(), user.clone()])
+            .custom_roles(vec![role.clone()])
+            .build()
+            .login_as(admin)
+            .await;
+
+        let input = update_custom_role::Input {
+            id: CustomRoleId::from_entity(role.id()),
+            name: Some("会計".to_owned()),
+            permissions: Some(vec!["READ_ALL_PROJECTS".to_owned()]),
+        };
+        let got = update_custom_role::run(&app, input).await.unwrap();
+        assert_eq!(got.name, "会計");
+        assert_eq!(got.permissions, vec!["READ_ALL_PROJECTS"]);
+
+        let user = app.get_user(user.id().clone()).await.unwrap().unwrap();
+        assert!(user.permissions().contains(Permissions::READ_ALL_PROJECTS));
+        assert!(!user.permissions().contains(Permissions::READ_ALL_USERS));
+    }
+}
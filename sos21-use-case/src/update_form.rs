@@ -3,7 +3,7 @@ use crate::interface;
 use crate::model::form::{Form, FormCondition, FormId, FormItem};
 
 use anyhow::Context;
-use sos21_domain::context::{FormRepository, Login};
+use sos21_domain::context::{ConfigContext, FormRepository, Login};
 use sos21_domain::model::{date_time, form};
 
 #[derive(Debug, Clone)]
@@ -66,7 +66,7 @@ impl Error {
 #[tracing::instrument(skip(ctx))]
 pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<Form, Error>
 where
-    C: FormRepository + Send + Sync,
+    C: FormRepository + ConfigContext + Send + Sync,
 {
     let login_user = ctx.login_user();
 
@@ -79,6 +79,11 @@ where
         _ => return Err(UseCaseError::UseCase(Error::NotFound)),
     };
 
+    // forms in the past festivals are read-only
+    if form.festival_id() != ctx.active_festival_id() {
+        return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
+    }
+
     if date_time::DateTime::now() >= form.period().starts_at() {
         return Err(UseCaseError::UseCase(Error::AlreadyStarted));
     }
@@ -323,4 +328,72 @@ mod tests {
             ))
         ));
     }
+
+    #[tokio::test]
+    async fn test_name_past_festival() {
+        let author = test::model::new_operator_user();
+        let period = test::model::new_form_period_with_hours_from_now(1);
+        let form = test::model::new_form_with_period(author.id().clone(), period);
+        let festival = test::model::new_festival();
+
+        let app = test::build_mock_app()
+            .festivals(vec![festival.clone()])
+            .active_festival(festival.id())
+            .users(vec![author.clone()])
+            .forms(vec![form.clone()])
+            .build()
+            .login_as(author)
+            .await;
+
+        let input = update_form::Input {
+            id: FormId::from_entity(form.id()),
+            name: Some(test::model::mock_form_name().into_string()),
+            description: None,
+            starts_at: None,
+            ends_at: None,
+            items: None,
+            condition: None,
+        };
+        assert!(matches!(
+            update_form::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                update_form::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    // Checks that the edit based on the stale version fails instead of overwriting the other edit.
+    #[tokio::test]
+    async fn test_concurrent_update() {
+        use sos21_domain::context::FormRepository;
+
+        let author = test::model::new_operator_user();
+        let period = test::model::new_form_period_with_hours_from_now(1);
+        let form = test::model::new_form_with_period(author.id().clone(), period);
+
+        let app = test::build_mock_app()
+            .users(vec![author.clone()])
+            .forms(vec![form.clone()])
+            .build()
+            .login_as(author.clone())
+            .await;
+
+        let mut stale_form = app.get_form(form.id()).await.unwrap().unwrap();
+
+        let input = update_form::Input {
+            id: FormId::from_entity(form.id()),
+            name: Some(test::model::mock_form_name().into_string()),
+            description: None,
+            starts_at: None,
+            ends_at: None,
+            items: None,
+            condition: None,
+        };
+        assert!(update_form::run(&app, input).await.is_ok());
+
+        stale_form
+            .set_description(&author, test::model::mock_form_description())
+            .unwrap();
+        assert!(app.store_form(stale_form).await.is_err());
+    }
 }
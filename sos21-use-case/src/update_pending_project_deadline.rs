@@ -0,0 +1,181 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::pending_project::{PendingProject, PendingProjectId};
+
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use sos21_domain::context::{ConfigContext, Login, PendingProjectRepository};
+use sos21_domain::model::{date_time, pending_project};
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub id: PendingProjectId,
+    /// Removes the exceptional deadline with `None`.
+    pub exceptional_complete_deadline: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    NotFound,
+    NotAfterCreationPeriod,
+    InsufficientPermissions,
+}
+
+impl Error {
+    fn from_set_error(err: pending_project::SetExceptionalCompleteDeadlineError) -> Self {
+        match err.kind() {
+            pending_project::SetExceptionalCompleteDeadlineErrorKind::InsufficientPermissions => {
+                Error::InsufficientPermissions
+            }
+            pending_project::SetExceptionalCompleteDeadlineErrorKind::NotAfterCreationPeriod => {
+                Error::NotAfterCreationPeriod
+            }
+        }
+    }
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<PendingProject, Error>
+where
+    C: PendingProjectRepository + ConfigContext + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    let result = ctx
+        .get_pending_project(input.id.into_entity())
+        .await
+        .context("Failed to get a pending project")?;
+    let mut pending_project = match result {
+        Some(result) if result.pending_project.is_visible_to(login_user) => result.pending_project,
+        _ => return Err(UseCaseError::UseCase(Error::NotFound)),
+    };
+
+    let deadline = input
+        .exceptional_complete_deadline
+        .map(date_time::DateTime::from_utc);
+    pending_project
+        .set_exceptional_complete_deadline(ctx, login_user, deadline)
+        .map_err(|err| UseCaseError::UseCase(Error::from_set_error(err)))?;
+
+    ctx.store_pending_project(pending_project.clone())
+        .await
+        .context("Failed to store a updated pending project")?;
+
+    use_case_ensure!(pending_project.is_visible_to(login_user));
+    Ok(PendingProject::from_entity(pending_project))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::pending_project::PendingProjectId;
+    use crate::{update_pending_project_deadline, UseCaseError};
+    use sos21_domain::context::PendingProjectRepository;
+    use sos21_domain::model::{
+        date_time::DateTime, project::ProjectCategory,
+        project_creation_period::ProjectCreationPeriod,
+    };
+    use sos21_domain::test;
+
+    #[tokio::test]
+    async fn test_committee() {
+        let committee = test::model::new_committee_user();
+        let owner = test::model::new_general_user();
+        let pending_project = test::model::new_general_pending_project(owner.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![committee.clone(), owner])
+            .pending_projects(vec![pending_project.clone()])
+            .build()
+            .login_as(committee)
+            .await;
+
+        let input = update_pending_project_deadline::Input {
+            id: PendingProjectId::from_entity(pending_project.id()),
+            exceptional_complete_deadline: Some(chrono::Utc::now()),
+        };
+        assert!(matches!(
+            update_pending_project_deadline::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                update_pending_project_deadline::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_operator() {
+        let operator = test::model::new_operator_user();
+        let owner = test::model::new_general_user();
+        let pending_project = test::model::new_general_pending_project(owner.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![operator.clone(), owner])
+            .pending_projects(vec![pending_project.clone()])
+            .build()
+            .login_as(operator)
+            .await;
+
+        let deadline = chrono::Utc::now() + chrono::Duration::days(1);
+        let input = update_pending_project_deadline::Input {
+            id: PendingProjectId::from_entity(pending_project.id()),
+            exceptional_complete_deadline: Some(deadline),
+        };
+        let result = update_pending_project_deadline::run(&app, input)
+            .await
+            .unwrap();
+        assert_eq!(result.exceptional_complete_deadline, Some(deadline));
+
+        let stored = app
+            .get_pending_project(pending_project.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            stored
+                .pending_project
+                .exceptional_complete_deadline()
+                .as_ref()
+                .map(|deadline| deadline.utc()),
+            Some(deadline)
+        );
+
+        let input = update_pending_project_deadline::Input {
+            id: PendingProjectId::from_entity(pending_project.id()),
+            exceptional_complete_deadline: None,
+        };
+        let result = update_pending_project_deadline::run(&app, input)
+            .await
+            .unwrap();
+        assert_eq!(result.exceptional_complete_deadline, None);
+    }
+
+    #[tokio::test]
+    async fn test_operator_within_period() {
+        let operator = test::model::new_operator_user();
+        let owner = test::model::new_general_user();
+        let pending_project = test::model::new_general_pending_project(owner.id().clone());
+
+        let ends_at = chrono::Utc::now() + chrono::Duration::days(7);
+        let period = ProjectCreationPeriod::from_datetime(
+            DateTime::from_utc(chrono::Utc::now()),
+            DateTime::from_utc(ends_at),
+        )
+        .unwrap();
+        let app = test::build_mock_app()
+            .users(vec![operator.clone(), owner])
+            .pending_projects(vec![pending_project.clone()])
+            .project_creation_period_for(ProjectCategory::General, period)
+            .build()
+            .login_as(operator)
+            .await;
+
+        let input = update_pending_project_deadline::Input {
+            id: PendingProjectId::from_entity(pending_project.id()),
+            exceptional_complete_deadline: Some(ends_at - chrono::Duration::days(1)),
+        };
+        assert!(matches!(
+            update_pending_project_deadline::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                update_pending_project_deadline::Error::NotAfterCreationPeriod
+            ))
+        ));
+    }
+}
@@ -104,7 +104,10 @@ where
         .await
         .context("Failed to get a registration form answer")?;
     let mut answer = match result {
-        Some(answer) if answer.is_visible_to_with_pending_project(login_user, &pending_project) => {
+        Some(answer)
+            if answer.is_visible_to_with_pending_project(login_user, &pending_project)
+                || answer.is_visible_to_with_registration_form(login_user, &registration_form) =>
+        {
             answer
         }
         _ => return Err(UseCaseError::UseCase(Error::RegistrationFormAnswerNotFound)),
@@ -139,7 +142,10 @@ where
     ctx.store_registration_form_answer(answer.clone())
         .await
         .context("Failed to store a registration form answer")?;
-    use_case_ensure!(answer.is_visible_to_with_pending_project(login_user, &pending_project));
+    use_case_ensure!(
+        answer.is_visible_to_with_pending_project(login_user, &pending_project)
+            || answer.is_visible_to_with_registration_form(login_user, &registration_form)
+    );
     Ok(RegistrationFormAnswer::from_entity(answer))
 }
 
@@ -94,12 +94,17 @@ where
         .await
         .context("Failed to get a form answer")?;
     let mut answer = match result {
-        Some(answer) if answer.is_visible_to_with_project(login_user, &project) => answer,
+        Some(answer)
+            if answer.is_visible_to_with_project(login_user, &project)
+                || answer.is_visible_to_with_form(login_user, &form) =>
+        {
+            answer
+        }
         _ => return Err(UseCaseError::UseCase(Error::FormAnswerNotFound)),
     };
 
     // NOTE: Check the answer period before the validation for the convenience of clients
-    if !form.period().contains(date_time::DateTime::now()) {
+    if !form.period().contains(date_time::DateTime::now()) && !answer.is_reopened() {
         return Err(UseCaseError::UseCase(Error::OutOfAnswerPeriod));
     }
 
@@ -114,7 +119,10 @@ where
     ctx.store_form_answer(answer.clone())
         .await
         .context("Failed to store a form answer")?;
-    use_case_ensure!(answer.is_visible_to_with_project(login_user, &project));
+    use_case_ensure!(
+        answer.is_visible_to_with_project(login_user, &project)
+            || answer.is_visible_to_with_form(login_user, &form)
+    );
     Ok(FormAnswer::from_entity(answer))
 }
 
@@ -331,6 +339,68 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_answer_after_period_reopened_owner() {
+        use sos21_domain::model::form_answer::{FormAnswerReviewComments, FormAnswerReviewStatus};
+
+        let owner = test::model::new_general_user();
+        let project = test::model::new_general_project(owner.id().clone());
+        let operator = test::model::new_operator_user();
+
+        let (item_id, items, answer_items) = prepare_items();
+
+        let item_id = FormItemId::from_entity(item_id);
+        let form = test::model::new_form_with_items(operator.id().clone(), items);
+        let mut form_answer = test::model::new_form_answer_with_items(
+            owner.id().clone(),
+            &project,
+            &form,
+            answer_items,
+        );
+        form_answer
+            .review_by(
+                &operator,
+                &form,
+                FormAnswerReviewStatus::NeedsChanges,
+                FormAnswerReviewComments::empty(),
+            )
+            .unwrap();
+        // The answer period has ended after the review
+        let mut form_content = form.into_content();
+        form_content.period = test::model::new_form_period_to_now();
+        let form = sos21_domain::model::form::Form::from_content(form_content);
+
+        let app = test::build_mock_app()
+            .users(vec![owner.clone(), operator.clone()])
+            .projects(vec![project.clone()])
+            .forms(vec![form.clone()])
+            .answers(vec![form_answer.clone()])
+            .build()
+            .login_as(owner)
+            .await;
+
+        let answer_item = interface::form_answer::InputFormAnswerItem {
+            item_id,
+            body: Some(interface::form_answer::InputFormAnswerItemBody::Integer(
+                Some(20),
+            )),
+        };
+        let input = update_project_form_answer::Input {
+            project_id: ProjectId::from_entity(project.id()),
+            form_id: FormId::from_entity(form.id()),
+            items: vec![answer_item],
+        };
+        let answer = update_project_form_answer::run(&app, input).await.unwrap();
+        assert_eq!(
+            answer.items[0].body,
+            Some(FormAnswerItemBody::Integer(Some(20)))
+        );
+        assert_eq!(
+            answer.review_status,
+            crate::model::form_answer::FormAnswerReviewStatus::Submitted
+        );
+    }
+
     #[tokio::test]
     async fn test_answer_in_period_invalid() {
         let owner = test::model::new_general_user();
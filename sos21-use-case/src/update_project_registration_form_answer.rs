@@ -103,7 +103,12 @@ where
         .await
         .context("Failed to get a registration form answer")?;
     let mut answer = match result {
-        Some(answer) if answer.is_visible_to_with_project(login_user, &project) => answer,
+        Some(answer)
+            if answer.is_visible_to_with_project(login_user, &project)
+                || answer.is_visible_to_with_registration_form(login_user, &registration_form) =>
+        {
+            answer
+        }
         _ => return Err(UseCaseError::UseCase(Error::RegistrationFormAnswerNotFound)),
     };
 
@@ -130,7 +135,10 @@ where
     ctx.store_registration_form_answer(answer.clone())
         .await
         .context("Failed to store a registration form answer")?;
-    use_case_ensure!(answer.is_visible_to_with_project(login_user, &project));
+    use_case_ensure!(
+        answer.is_visible_to_with_project(login_user, &project)
+            || answer.is_visible_to_with_registration_form(login_user, &registration_form)
+    );
     Ok(RegistrationFormAnswer::from_entity(answer))
 }
 
@@ -0,0 +1,249 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::access_control::{AccessControlEntry, AccessControlGrantee};
+use crate::model::registration_form::RegistrationFormId;
+
+use anyhow::Context;
+use sos21_domain::context::{
+    CustomRoleRepository, Login, RegistrationFormRepository, UserRepository,
+};
+use sos21_domain::model::{access_control, registration_form};
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub registration_form_id: RegistrationFormId,
+    pub entries: Vec<AccessControlEntry>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    NotFound,
+    GranteeNotFound(AccessControlGrantee),
+    DuplicatedGrantee(AccessControlGrantee),
+    TooManyEntries,
+    InsufficientPermissions,
+}
+
+impl Error {
+    fn from_entries_error(err: access_control::FromEntriesError) -> Self {
+        match err.kind() {
+            access_control::FromEntriesErrorKind::TooLong => Error::TooManyEntries,
+            access_control::FromEntriesErrorKind::DuplicatedGrantee(grantee) => {
+                Error::DuplicatedGrantee(AccessControlGrantee::from_entity(grantee))
+            }
+        }
+    }
+
+    fn from_update_error(_err: registration_form::NoUpdatePermissionError) -> Self {
+        Error::InsufficientPermissions
+    }
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<Vec<AccessControlEntry>, Error>
+where
+    C: RegistrationFormRepository + UserRepository + CustomRoleRepository + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    let result = ctx
+        .get_registration_form(input.registration_form_id.into_entity())
+        .await
+        .context("Failed to get a registration form")?;
+    let mut registration_form = match result {
+        Some(registration_form) if registration_form.is_visible_to(login_user) => registration_form,
+        _ => return Err(UseCaseError::UseCase(Error::NotFound)),
+    };
+
+    let access_control = access_control::AccessControlList::from_entries(
+        input
+            .entries
+            .into_iter()
+            .map(AccessControlEntry::into_entity),
+    )
+    .map_err(|err| UseCaseError::UseCase(Error::from_entries_error(err)))?;
+
+    for entry in access_control.entries() {
+        let exists = match &entry.grantee {
+            access_control::AccessControlGrantee::User(user_id) => ctx
+                .get_user(user_id.clone())
+                .await
+                .context("Failed to get a user")?
+                .is_some(),
+            access_control::AccessControlGrantee::CustomRole(custom_role_id) => ctx
+                .get_custom_role(*custom_role_id)
+                .await
+                .context("Failed to get a custom role")?
+                .is_some(),
+        };
+        if !exists {
+            let grantee = AccessControlGrantee::from_entity(entry.grantee.clone());
+            return Err(UseCaseError::UseCase(Error::GranteeNotFound(grantee)));
+        }
+    }
+
+    registration_form
+        .set_access_control(login_user, access_control)
+        .map_err(|err| UseCaseError::UseCase(Error::from_update_error(err)))?;
+
+    ctx.store_registration_form(registration_form.clone())
+        .await
+        .context("Failed to store a registration form")?;
+    use_case_ensure!(registration_form.is_visible_to(login_user));
+    Ok(registration_form
+        .access_control
+        .entries()
+        .cloned()
+        .map(AccessControlEntry::from_entity)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{
+        access_control::{AccessControlEntry, AccessControlGrantee, AccessControlLevel},
+        custom_role::CustomRoleId,
+        registration_form::RegistrationFormId,
+        user::UserId,
+    };
+    use crate::{update_registration_form_access_control, UseCaseError};
+    use sos21_domain::context::RegistrationFormRepository;
+    use sos21_domain::model::{access_control as entity, permissions::Permissions};
+    use sos21_domain::test;
+
+    #[tokio::test]
+    async fn test_operator() {
+        let operator = test::model::new_operator_user();
+        let user = test::model::new_general_user();
+        let registration_form = test::model::new_registration_form(operator.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![operator.clone(), user.clone()])
+            .registration_forms(vec![registration_form.clone()])
+            .build()
+            .login_as(operator.clone())
+            .await;
+
+        let input = update_registration_form_access_control::Input {
+            registration_form_id: RegistrationFormId::from_entity(registration_form.id),
+            entries: vec![AccessControlEntry {
+                grantee: AccessControlGrantee::User(UserId::from_entity(user.id().clone())),
+                level: AccessControlLevel::Read,
+            }],
+        };
+        assert!(matches!(
+            update_registration_form_access_control::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                update_registration_form_access_control::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_admin() {
+        let admin = test::model::new_admin_user();
+        let user = test::model::new_general_user();
+        let role = test::model::new_custom_role(admin.id().clone(), Permissions::empty());
+        let registration_form = test::model::new_registration_form(admin.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![admin.clone(), user.clone()])
+            .custom_roles(vec![role.clone()])
+            .registration_forms(vec![registration_form.clone()])
+            .build()
+            .login_as(admin.clone())
+            .await;
+
+        let input = update_registration_form_access_control::Input {
+            registration_form_id: RegistrationFormId::from_entity(registration_form.id),
+            entries: vec![
+                AccessControlEntry {
+                    grantee: AccessControlGrantee::User(UserId::from_entity(user.id().clone())),
+                    level: AccessControlLevel::Review,
+                },
+                AccessControlEntry {
+                    grantee: AccessControlGrantee::CustomRole(CustomRoleId::from_entity(role.id())),
+                    level: AccessControlLevel::Read,
+                },
+            ],
+        };
+        assert!(matches!(
+            update_registration_form_access_control::run(&app, input).await,
+            Ok(got)
+            if got.len() == 2
+        ));
+
+        let registration_form = app
+            .get_registration_form(registration_form.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(registration_form
+            .access_control
+            .allows(&user, entity::AccessControlLevel::Review));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_grantee() {
+        let admin = test::model::new_admin_user();
+        let registration_form = test::model::new_registration_form(admin.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![admin.clone()])
+            .registration_forms(vec![registration_form.clone()])
+            .build()
+            .login_as(admin.clone())
+            .await;
+
+        let grantee = AccessControlGrantee::User(UserId::from_entity(test::model::new_user_id()));
+        let input = update_registration_form_access_control::Input {
+            registration_form_id: RegistrationFormId::from_entity(registration_form.id),
+            entries: vec![AccessControlEntry {
+                grantee: grantee.clone(),
+                level: AccessControlLevel::Read,
+            }],
+        };
+        assert!(matches!(
+            update_registration_form_access_control::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                update_registration_form_access_control::Error::GranteeNotFound(got)
+            ))
+            if got == grantee
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_duplicated_grantee() {
+        let admin = test::model::new_admin_user();
+        let user = test::model::new_general_user();
+        let registration_form = test::model::new_registration_form(admin.id().clone());
+
+        let app = test::build_mock_app()
+            .users(vec![admin.clone(), user.clone()])
+            .registration_forms(vec![registration_form.clone()])
+            .build()
+            .login_as(admin.clone())
+            .await;
+
+        let grantee = AccessControlGrantee::User(UserId::from_entity(user.id().clone()));
+        let input = update_registration_form_access_control::Input {
+            registration_form_id: RegistrationFormId::from_entity(registration_form.id),
+            entries: vec![
+                AccessControlEntry {
+                    grantee: grantee.clone(),
+                    level: AccessControlLevel::Read,
+                },
+                AccessControlEntry {
+                    grantee: grantee.clone(),
+                    level: AccessControlLevel::Update,
+                },
+            ],
+        };
+        assert!(matches!(
+            update_registration_form_access_control::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                update_registration_form_access_control::Error::DuplicatedGrantee(got)
+            ))
+            if got == grantee
+        ));
+    }
+}
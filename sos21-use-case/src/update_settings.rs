@@ -0,0 +1,278 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::model::festival::FestivalId;
+use crate::model::project::ProjectCategory;
+use crate::model::settings::{ProjectCreationPeriod, Settings};
+
+use anyhow::Context;
+use sos21_domain::context::{
+    ConfigContext, FestivalRepository, Login, SettingsRepository, UserRepository,
+};
+use sos21_domain::model::{permissions::Permissions, settings, user};
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub administrator_email: Option<String>,
+    pub active_festival_id: Option<FestivalId>,
+    pub project_creation_periods: Vec<(ProjectCategory, ProjectCreationPeriod)>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    InsufficientPermissions,
+    InvalidAdministratorEmail,
+    FestivalNotFound,
+    InvalidProjectCreationPeriod(ProjectCategory),
+}
+
+impl Error {
+    fn from_permissions_error(_err: user::RequirePermissionsError) -> Self {
+        Error::InsufficientPermissions
+    }
+
+    fn from_update_error(_err: settings::NoUpdatePermissionError) -> Self {
+        Error::InsufficientPermissions
+    }
+}
+
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<Settings, Error>
+where
+    C: SettingsRepository + FestivalRepository + UserRepository + ConfigContext + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    login_user
+        .require_permissions(Permissions::UPDATE_SETTINGS)
+        .map_err(|err| UseCaseError::UseCase(Error::from_permissions_error(err)))?;
+
+    let mut settings = match ctx.get_settings().await.context("Failed to get settings")? {
+        Some(settings) => settings,
+        None => settings::Settings::from_config(ctx),
+    };
+
+    if let Some(administrator_email) = input.administrator_email {
+        let administrator_email = user::UserEmailAddress::from_string_with_domains(
+            administrator_email,
+            ctx.allowed_email_domains(),
+        )
+        .map_err(|_| UseCaseError::UseCase(Error::InvalidAdministratorEmail))?;
+        settings
+            .set_administrator_email(login_user, administrator_email)
+            .map_err(|err| UseCaseError::UseCase(Error::from_update_error(err)))?;
+    }
+
+    let previous_festival_id = settings.active_festival_id();
+    if let Some(festival_id) = input.active_festival_id {
+        let festival = match ctx
+            .get_festival(festival_id.into_entity())
+            .await
+            .context("Failed to get a festival")?
+        {
+            Some(festival) if festival.is_visible_to(login_user) => festival,
+            _ => return Err(UseCaseError::UseCase(Error::FestivalNotFound)),
+        };
+        settings
+            .set_active_festival(login_user, &festival)
+            .map_err(|err| UseCaseError::UseCase(Error::from_update_error(err)))?;
+    }
+
+    for (category, period) in input.project_creation_periods {
+        let period = period
+            .into_entity()
+            .map_err(|_| UseCaseError::UseCase(Error::InvalidProjectCreationPeriod(category)))?;
+        settings
+            .set_project_creation_period(login_user, category.into_entity(), period)
+            .map_err(|err| UseCaseError::UseCase(Error::from_update_error(err)))?;
+    }
+
+    ctx.store_settings(settings.clone())
+        .await
+        .context("Failed to store settings")?;
+
+    // Users can take part in a project of the new festival,
+    // while their projects in the previous festival are kept as they are.
+    if settings.active_festival_id() != previous_festival_id {
+        let users = ctx.list_users().await.context("Failed to list users")?;
+        for mut user in users {
+            if user.assignment().is_none() {
+                continue;
+            }
+            user.release_assignment();
+            ctx.store_user(user)
+                .await
+                .context("Failed to store a user")?;
+        }
+    }
+
+    use_case_ensure!(settings.is_visible_to(login_user));
+    Ok(Settings::from_entity(settings))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::festival::FestivalId;
+    use crate::model::project::ProjectCategory;
+    use crate::model::settings::ProjectCreationPeriod;
+    use crate::model::user::UserId;
+    use crate::{get_settings, update_settings, UseCaseError};
+
+    use sos21_domain::context::UserRepository;
+    use sos21_domain::test;
+
+    #[tokio::test]
+    async fn test_operator() {
+        let operator = test::model::new_operator_user();
+
+        let app = test::build_mock_app()
+            .users(vec![operator.clone()])
+            .build()
+            .login_as(operator)
+            .await;
+
+        let input = update_settings::Input {
+            administrator_email: None,
+            active_festival_id: None,
+            project_creation_periods: vec![(ProjectCategory::Stage, ProjectCreationPeriod::Never)],
+        };
+        assert!(matches!(
+            update_settings::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                update_settings::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_admin_update() {
+        let admin = test::model::new_admin_user();
+
+        let app = test::build_mock_app()
+            .users(vec![admin.clone()])
+            .build()
+            .login_as(admin.clone())
+            .await;
+
+        let starts_at = chrono::Utc::now();
+        let period = ProjectCreationPeriod::Range {
+            starts_at,
+            ends_at: starts_at + chrono::Duration::days(1),
+        };
+        let input = update_settings::Input {
+            administrator_email: Some("admin@sohosai.com".to_owned()),
+            active_festival_id: None,
+            project_creation_periods: vec![(ProjectCategory::Stage, period)],
+        };
+        update_settings::run(&app, input).await.unwrap();
+
+        let got = get_settings::run(&app).await.unwrap();
+        assert_eq!(got.author_id, Some(UserId::from_entity(admin.id().clone())));
+        assert_eq!(got.administrator_email, "admin@sohosai.com");
+        assert!(got
+            .project_creation_periods
+            .contains(&(ProjectCategory::Stage, period)));
+    }
+
+    #[tokio::test]
+    async fn test_admin_invalid_period() {
+        let admin = test::model::new_admin_user();
+
+        let app = test::build_mock_app()
+            .users(vec![admin.clone()])
+            .build()
+            .login_as(admin)
+            .await;
+
+        let starts_at = chrono::Utc::now();
+        let period = ProjectCreationPeriod::Range {
+            starts_at,
+            ends_at: starts_at - chrono::Duration::days(1),
+        };
+        let input = update_settings::Input {
+            administrator_email: None,
+            active_festival_id: None,
+            project_creation_periods: vec![(ProjectCategory::Food, period)],
+        };
+        assert!(matches!(
+            update_settings::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                update_settings::Error::InvalidProjectCreationPeriod(ProjectCategory::Food)
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_admin_invalid_email() {
+        let admin = test::model::new_admin_user();
+
+        let app = test::build_mock_app()
+            .users(vec![admin.clone()])
+            .build()
+            .login_as(admin)
+            .await;
+
+        let input = update_settings::Input {
+            administrator_email: Some("admin@example.com".to_owned()),
+            active_festival_id: None,
+            project_creation_periods: Vec::new(),
+        };
+        assert!(matches!(
+            update_settings::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                update_settings::Error::InvalidAdministratorEmail
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_admin_switch_festival() {
+        let admin = test::model::new_admin_user();
+        let mut user = test::model::new_general_user();
+        let project = test::model::new_general_project(user.id().clone());
+        user.assign_project_owner(&project).unwrap();
+        let festival = test::model::new_festival();
+
+        let app = test::build_mock_app()
+            .users(vec![admin.clone(), user.clone()])
+            .projects(vec![project])
+            .festivals(vec![festival.clone()])
+            .build();
+        let login_app = app.clone().login_as(admin).await;
+
+        let input = update_settings::Input {
+            administrator_email: None,
+            active_festival_id: Some(FestivalId::from_entity(festival.id())),
+            project_creation_periods: Vec::new(),
+        };
+        let got = update_settings::run(&login_app, input).await.unwrap();
+        assert_eq!(
+            got.active_festival_id,
+            FestivalId::from_entity(festival.id())
+        );
+
+        let got = app.get_user(user.id().clone()).await.unwrap().unwrap();
+        assert!(got.assignment().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_admin_unknown_festival() {
+        let admin = test::model::new_admin_user();
+
+        let app = test::build_mock_app()
+            .users(vec![admin.clone()])
+            .build()
+            .login_as(admin)
+            .await;
+
+        let input = update_settings::Input {
+            administrator_email: None,
+            active_festival_id: Some(FestivalId::from_entity(test::model::new_festival_id())),
+            project_creation_periods: Vec::new(),
+        };
+        assert!(matches!(
+            update_settings::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                update_settings::Error::FestivalNotFound
+            ))
+        ));
+    }
+}
@@ -0,0 +1,289 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::interface;
+use crate::model::form::{item::FormItemId, FormItem};
+use crate::model::project::{ProjectAttribute, ProjectCategory};
+
+use anyhow::Context;
+use sos21_domain::context::{FileRepository, FileSharingRepository, Login};
+use sos21_domain::model::{form, permissions::Permissions, project};
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub items: Vec<FormItem>,
+    pub respondent: InputRespondent,
+    pub answer: Vec<interface::form_answer::InputFormAnswerItem>,
+}
+
+/// The project assumed to answer, which is referred in the conditions of the items.
+#[derive(Debug, Clone)]
+pub struct InputRespondent {
+    pub category: ProjectCategory,
+    pub attributes: Vec<ProjectAttribute>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AnswerItemCheck {
+    pub item_id: FormItemId,
+    pub is_hidden: bool,
+    pub error: Option<interface::form::CheckAnswerItemError>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    InvalidFormItems(interface::form::FormItemsError),
+    InvalidAnswerItems(interface::form_answer::FormAnswerItemsError),
+    InvalidAnswer(interface::form::CheckAnswerError),
+    DuplicatedProjectAttributes,
+    InsufficientPermissions,
+}
+
+impl Error {
+    fn from_form_items_error(err: interface::form::FormItemsError) -> Self {
+        Error::InvalidFormItems(err)
+    }
+
+    fn from_answer_items_error(err: interface::form_answer::FormAnswerItemsError) -> Self {
+        Error::InvalidAnswerItems(err)
+    }
+
+    fn from_attributes_error(_err: project::attribute::DuplicatedAttributesError) -> Self {
+        Error::DuplicatedProjectAttributes
+    }
+}
+
+/// Checks the answer to the form items without creating any form or answer.
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(ctx: &Login<C>, input: Input) -> UseCaseResult<Vec<AnswerItemCheck>, Error>
+where
+    C: FileRepository + FileSharingRepository + Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    if login_user
+        .require_permissions(Permissions::CREATE_FORMS)
+        .is_err()
+        && login_user
+            .require_permissions(Permissions::CREATE_REGISTRATION_FORMS)
+            .is_err()
+    {
+        return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
+    }
+
+    let items = interface::form::to_form_items(input.items)
+        .map_err(|err| UseCaseError::UseCase(Error::from_form_items_error(err)))?;
+    let attributes = project::ProjectAttributes::from_attributes(
+        input
+            .respondent
+            .attributes
+            .into_iter()
+            .map(ProjectAttribute::into_entity),
+    )
+    .map_err(|err| UseCaseError::UseCase(Error::from_attributes_error(err)))?;
+    let respondent = form::item::RespondentProject {
+        category: input.respondent.category.into_entity(),
+        attributes,
+    };
+    let answer = interface::form_answer::to_preview_form_answer_items(ctx, input.answer)
+        .await
+        .map_err(|err| err.map_use_case(Error::from_answer_items_error))?;
+
+    let checks = items
+        .check_answer_items(&respondent, &answer)
+        .context("Failed to check the answer")?
+        .map_err(|err| {
+            UseCaseError::UseCase(Error::InvalidAnswer(
+                interface::form::to_check_answer_error(err),
+            ))
+        })?;
+
+    Ok(checks
+        .into_iter()
+        .map(|check| AnswerItemCheck {
+            item_id: FormItemId::from_entity(check.item_id),
+            is_hidden: check.is_hidden,
+            error: check.error.map(interface::form::to_check_answer_item_error),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::interface::form::CheckAnswerItemError;
+    use crate::model::{
+        file::FileId,
+        form::{item::FormItemId, FormItem},
+        project::ProjectCategory,
+    };
+    use crate::test::interface as test_interface;
+    use crate::{interface, validate_form_answer, UseCaseError};
+
+    use sos21_domain::context::FileSharingRepository;
+    use sos21_domain::model::form::item;
+    use sos21_domain::test;
+
+    fn mock_respondent() -> validate_form_answer::InputRespondent {
+        validate_form_answer::InputRespondent {
+            category: ProjectCategory::General,
+            attributes: Vec::new(),
+        }
+    }
+
+    // Checks that the normal user cannot validate form answers.
+    #[tokio::test]
+    async fn test_general() {
+        let user = test::model::new_general_user();
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let items = test::model::new_form_items();
+        let input = validate_form_answer::Input {
+            answer: items
+                .items()
+                .map(test_interface::mock_input_form_answer_item)
+                .collect(),
+            items: items.into_items().map(FormItem::from_entity).collect(),
+            respondent: mock_respondent(),
+        };
+        assert!(matches!(
+            validate_form_answer::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                validate_form_answer::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_operator() {
+        let user = test::model::new_operator_user();
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let items = test::model::new_form_items();
+        let input = validate_form_answer::Input {
+            answer: items
+                .items()
+                .map(test_interface::mock_input_form_answer_item)
+                .collect(),
+            items: items
+                .clone()
+                .into_items()
+                .map(FormItem::from_entity)
+                .collect(),
+            respondent: mock_respondent(),
+        };
+        let got = validate_form_answer::run(&app, input).await.unwrap();
+        assert_eq!(got.len(), items.len());
+        assert!(got.iter().all(|check| check.error.is_none()));
+    }
+
+    // Checks that the items after an invalid answer are checked as well,
+    // and that the hidden items are reported.
+    #[tokio::test]
+    async fn test_operator_all_items() {
+        let user = test::model::new_operator_user();
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let radio = test::model::new_form_radio_button();
+        let item1 = test::model::new_form_item_with_body(
+            test::model::new_radio_form_item_body_with_button(radio.clone()),
+        );
+        let item2 =
+            test::model::new_form_item_with_condition(item::FormItemCondition::RadioSelected {
+                item_id: item1.id,
+                radio_id: radio.id,
+            });
+        let item3 = test::model::new_form_item();
+
+        let answer_item1 = interface::form_answer::InputFormAnswerItem {
+            item_id: FormItemId::from_entity(item1.id),
+            body: Some(interface::form_answer::InputFormAnswerItemBody::Radio(None)),
+        };
+        let answer_item2 = interface::form_answer::InputFormAnswerItem {
+            item_id: FormItemId::from_entity(item2.id),
+            body: None,
+        };
+        let answer_item3 = interface::form_answer::InputFormAnswerItem {
+            item_id: FormItemId::from_entity(item3.id),
+            body: None,
+        };
+        let input = validate_form_answer::Input {
+            items: vec![item1.clone(), item2.clone(), item3.clone()]
+                .into_iter()
+                .map(FormItem::from_entity)
+                .collect(),
+            respondent: mock_respondent(),
+            answer: vec![answer_item1, answer_item2, answer_item3],
+        };
+
+        let got = validate_form_answer::run(&app, input).await.unwrap();
+        assert_eq!(got.len(), 3);
+        assert_eq!(got[0].item_id, FormItemId::from_entity(item1.id));
+        assert!(!got[0].is_hidden);
+        assert!(matches!(
+            got[0].error,
+            Some(CheckAnswerItemError::NotAnsweredRadio)
+        ));
+        assert!(got[1].is_hidden);
+        assert!(got[1].error.is_none());
+        assert!(!got[2].is_hidden);
+        assert!(matches!(
+            got[2].error,
+            Some(CheckAnswerItemError::NotAnsweredWithoutCondition)
+        ));
+    }
+
+    // Checks that the files are not shared in validation.
+    #[tokio::test]
+    async fn test_operator_file() {
+        let user = test::model::new_operator_user();
+
+        let (file, object) = test::model::new_file(user.id().clone());
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .files(vec![file.clone()])
+            .objects(vec![object])
+            .await
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let body = item::FormItemBody::File(item::FileFormItem {
+            types: None,
+            accept_multiple_files: false,
+            is_required: true,
+        });
+        let item = test::model::new_form_item_with_body(body);
+        let answer_item = interface::form_answer::InputFormAnswerItem {
+            item_id: FormItemId::from_entity(item.id),
+            body: Some(interface::form_answer::InputFormAnswerItemBody::File(vec![
+                interface::form_answer::InputFormAnswerItemFile::File(FileId::from_entity(file.id)),
+            ])),
+        };
+        let input = validate_form_answer::Input {
+            items: vec![FormItem::from_entity(item)],
+            respondent: mock_respondent(),
+            answer: vec![answer_item],
+        };
+
+        let got = validate_form_answer::run(&app, input).await.unwrap();
+        assert!(got[0].error.is_none());
+        assert!(app
+            .list_file_sharings_by_user(user.id().clone())
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
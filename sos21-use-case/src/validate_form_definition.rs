@@ -0,0 +1,167 @@
+use crate::error::{UseCaseError, UseCaseResult};
+use crate::interface;
+use crate::model::form_definition::{FormDefinition, FormDefinitionKind};
+
+use sos21_domain::context::Login;
+use sos21_domain::model::permissions::Permissions;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub kind: FormDefinitionKind,
+    pub definition: FormDefinition,
+}
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    InsufficientPermissions,
+}
+
+/// Returns all the errors found in the definition, which is empty if the definition is valid.
+#[tracing::instrument(skip(ctx))]
+pub async fn run<C>(
+    ctx: &Login<C>,
+    input: Input,
+) -> UseCaseResult<Vec<interface::form_definition::FormDefinitionError>, Error>
+where
+    C: Send + Sync,
+{
+    let login_user = ctx.login_user();
+
+    let permissions = match input.kind {
+        FormDefinitionKind::Form => Permissions::CREATE_FORMS,
+        FormDefinitionKind::RegistrationForm => Permissions::CREATE_REGISTRATION_FORMS,
+    };
+    if login_user.require_permissions(permissions).is_err() {
+        return Err(UseCaseError::UseCase(Error::InsufficientPermissions));
+    }
+
+    match interface::form_definition::to_form_definition_content(input.kind, input.definition) {
+        Ok(_) => Ok(Vec::new()),
+        Err(errors) => Ok(errors),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::interface::{
+        form::{FormItemError, FormItemPath, FormItemsError},
+        form_definition::FormDefinitionError,
+    };
+    use crate::model::{
+        form::{item::FormItemCondition, FormItem, FormItemId},
+        form_definition::{FormDefinition, FormDefinitionKind, FormDefinitionPeriod},
+        project_query::ProjectQuery,
+    };
+    use crate::{validate_form_definition, UseCaseError};
+    use sos21_domain::{model::date_time, test};
+    use uuid::Uuid;
+
+    fn mock_definition() -> FormDefinition {
+        let period = test::model::mock_form_period_with_start(date_time::DateTime::from_utc(
+            chrono::Utc::now() + chrono::Duration::hours(1),
+        ));
+        FormDefinition {
+            name: test::model::mock_form_name().into_string(),
+            description: test::model::mock_form_description().into_string(),
+            period: Some(FormDefinitionPeriod {
+                starts_at: period.starts_at().utc(),
+                ends_at: period.ends_at().utc(),
+            }),
+            items: test::model::new_form_items()
+                .into_items()
+                .map(FormItem::from_entity)
+                .collect(),
+            query: ProjectQuery::from_entity(test::model::mock_project_query()),
+        }
+    }
+
+    // Checks that the (unprivileged) committee user cannot validate form definitions.
+    #[tokio::test]
+    async fn test_committee() {
+        let user = test::model::new_committee_user();
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let input = validate_form_definition::Input {
+            kind: FormDefinitionKind::Form,
+            definition: mock_definition(),
+        };
+        assert!(matches!(
+            validate_form_definition::run(&app, input).await,
+            Err(UseCaseError::UseCase(
+                validate_form_definition::Error::InsufficientPermissions
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_operator_valid() {
+        let user = test::model::new_operator_user();
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let input = validate_form_definition::Input {
+            kind: FormDefinitionKind::Form,
+            definition: mock_definition(),
+        };
+        let got = validate_form_definition::run(&app, input).await.unwrap();
+        assert!(got.is_empty());
+    }
+
+    // Checks that all the errors are reported with the paths to the items.
+    #[tokio::test]
+    async fn test_operator_invalid() {
+        let user = test::model::new_operator_user();
+
+        let app = test::build_mock_app()
+            .users(vec![user.clone()])
+            .build()
+            .login_as(user.clone())
+            .await;
+
+        let mut definition = mock_definition();
+        definition.name = String::new();
+        definition.period = None;
+        let mut invalid_item = FormItem::from_entity(test::model::new_form_item());
+        invalid_item.name = String::new();
+        let unknown_id = FormItemId(Uuid::new_v4());
+        let mut dangling_item = FormItem::from_entity(test::model::new_form_item());
+        dangling_item.conditions = Some(vec![vec![FormItemCondition::TextNonEmpty {
+            item_id: unknown_id,
+        }]]);
+        definition.items = vec![
+            FormItem::from_entity(test::model::new_form_item()),
+            invalid_item.clone(),
+            dangling_item.clone(),
+        ];
+
+        let input = validate_form_definition::Input {
+            kind: FormDefinitionKind::Form,
+            definition,
+        };
+        let got = validate_form_definition::run(&app, input).await.unwrap();
+        assert_eq!(got.len(), 4);
+        assert!(matches!(got[0], FormDefinitionError::InvalidName));
+        assert!(matches!(got[1], FormDefinitionError::MissingPeriod));
+        assert!(matches!(
+            &got[2],
+            FormDefinitionError::InvalidItems(err)
+            if err.path == Some(FormItemPath(vec![1]))
+                && matches!(err.error, FormItemsError::InvalidItem(id, FormItemError::InvalidName) if id == invalid_item.id)
+        ));
+        assert!(matches!(
+            &got[3],
+            FormDefinitionError::InvalidItems(err)
+            if err.path == Some(FormItemPath(vec![2]))
+                && matches!(err.error, FormItemsError::InvalidItem(id, FormItemError::UnknownItemIdInConditions(target)) if id == dangling_item.id && target == unknown_id)
+        ));
+    }
+}